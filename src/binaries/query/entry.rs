@@ -44,6 +44,7 @@ use databend_query::servers::MySQLHandler;
 use databend_query::servers::MySQLTlsConfig;
 use databend_query::servers::Server;
 use databend_query::servers::ShutdownHandle;
+use databend_query::task::TaskScheduler;
 use databend_query::GlobalServices;
 use log::info;
 
@@ -328,6 +329,7 @@ pub async fn start_services(conf: &InnerConfig) -> Result<(), MainError> {
         "    reload policy: {}",
         conf.cache.data_cache_key_reload_policy
     );
+    println!("    eviction policy: {}", conf.cache.eviction_policy);
 
     println!();
     println!(
@@ -411,6 +413,11 @@ pub async fn start_services(conf: &InnerConfig) -> Result<(), MainError> {
         start_time.elapsed().as_secs_f32()
     );
 
+    // Without cloud control, scheduled tasks are run by the query nodes themselves.
+    if conf.query.cloud_control_grpc_server_address.is_none() {
+        TaskScheduler::instance().start();
+    }
+
     if conf.background.enable {
         println!("Start background service");
         get_background_service_handler()
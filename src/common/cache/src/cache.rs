@@ -13,6 +13,7 @@
 // limitations under the License.
 
 pub mod lru;
+pub mod s3fifo;
 
 use std::borrow::Borrow;
 use std::hash::Hash;
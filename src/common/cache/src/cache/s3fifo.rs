@@ -0,0 +1,314 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A cache that evicts items with the S3-FIFO policy, see
+//! [FIFO queues are all you need for cache eviction](https://dl.acm.org/doi/10.1145/3600006.3613147).
+//!
+//! A new item is inserted into a small probationary queue. If it is accessed again before it
+//! reaches the head of the small queue, it is admitted into the main queue, otherwise it is
+//! evicted and its key is remembered in a ghost queue. An item whose key is still in the ghost
+//! queue when it is inserted again is admitted into the main queue directly.
+//!
+//! Items that are accessed only once, e.g., the ones loaded by a full table scan, are evicted
+//! from the small queue soon, without evicting the frequently accessed items in the main queue.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use databend_common_cache::{Cache, S3FifoCache};
+//!
+//! let mut cache = S3FifoCache::with_items_capacity(2);
+//!
+//! cache.insert(1, 10);
+//! cache.get(&1);
+//! cache.insert(2, 20);
+//! cache.insert(3, 30);
+//! assert!(cache.contains(&1));
+//! assert!(!cache.contains(&2));
+//! ```
+
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+use hashlink::LinkedHashMap;
+
+use crate::cache::Cache;
+use crate::mem_sized::MemSized;
+
+/// The max access frequency recorded for an item.
+const MAX_FREQ: u8 = 3;
+
+/// The small queue takes `1 / SMALL_QUEUE_RATIO` of the capacity.
+const SMALL_QUEUE_RATIO: usize = 10;
+
+#[derive(Clone)]
+struct Entry<V> {
+    value: V,
+    freq: u8,
+}
+
+/// An S3-FIFO cache.
+#[derive(Clone)]
+pub struct S3FifoCache<K: Eq + Hash + MemSized + Clone, V: MemSized> {
+    small: LinkedHashMap<K, Entry<V>>,
+    main: LinkedHashMap<K, Entry<V>>,
+    ghost: LinkedHashMap<K, ()>,
+    max_items: usize,
+    max_bytes: usize,
+    small_bytes: usize,
+    bytes: usize,
+    admitted: u64,
+    rejected: u64,
+}
+
+impl<K: Eq + Hash + MemSized + Clone, V: MemSized> S3FifoCache<K, V> {
+    /// Creates an empty cache that can hold at most `items_capacity` items.
+    pub fn with_items_capacity(items_capacity: usize) -> Self {
+        Self::new(items_capacity, usize::MAX)
+    }
+
+    /// Creates an empty cache that can hold items of at most `bytes_capacity` bytes.
+    pub fn with_bytes_capacity(bytes_capacity: usize) -> Self {
+        Self::new(usize::MAX, bytes_capacity)
+    }
+
+    fn new(max_items: usize, max_bytes: usize) -> Self {
+        S3FifoCache {
+            small: LinkedHashMap::new(),
+            main: LinkedHashMap::new(),
+            ghost: LinkedHashMap::new(),
+            max_items,
+            max_bytes,
+            small_bytes: 0,
+            bytes: 0,
+            admitted: 0,
+            rejected: 0,
+        }
+    }
+
+    /// Returns the number of items admitted into the main queue.
+    pub fn admitted(&self) -> u64 {
+        self.admitted
+    }
+
+    /// Returns the number of items evicted from the small queue without being accessed again.
+    pub fn rejected(&self) -> u64 {
+        self.rejected
+    }
+
+    /// Whether the next victim should be taken from the small queue.
+    fn evict_from_small(&self) -> bool {
+        if self.small.is_empty() {
+            return false;
+        }
+
+        self.main.is_empty()
+            || self.small_bytes >= self.max_bytes / SMALL_QUEUE_RATIO
+            || self.small.len() >= (self.max_items / SMALL_QUEUE_RATIO).max(1)
+    }
+
+    /// Evicts an item from the small queue.
+    ///
+    /// Items that have been accessed are moved to the main queue instead.
+    /// Returns `None` if all the items in the small queue are moved.
+    fn evict_small(&mut self) -> Option<(K, V)> {
+        while let Some((k, mut entry)) = self.small.pop_front() {
+            self.small_bytes -= entry.value.mem_bytes();
+
+            if entry.freq > 0 {
+                entry.freq = 0;
+                self.main.insert(k, entry);
+                self.admitted += 1;
+            } else {
+                self.bytes -= entry.value.mem_bytes();
+                self.rejected += 1;
+
+                self.ghost.insert(k.clone(), ());
+                while self.ghost.len() > self.len().max(1) {
+                    self.ghost.pop_front();
+                }
+
+                return Some((k, entry.value));
+            }
+        }
+        None
+    }
+
+    /// Evicts an item from the main queue.
+    ///
+    /// Items that have been accessed are re-inserted at the tail with decreased frequency.
+    fn evict_main(&mut self) -> Option<(K, V)> {
+        while let Some((k, mut entry)) = self.main.pop_front() {
+            if entry.freq > 0 {
+                entry.freq -= 1;
+                self.main.insert(k, entry);
+            } else {
+                self.bytes -= entry.value.mem_bytes();
+                return Some((k, entry.value));
+            }
+        }
+        None
+    }
+}
+
+impl<K: Eq + Hash + MemSized + Clone, V: MemSized> Cache<K, V> for S3FifoCache<K, V> {
+    /// Returns a reference to the value corresponding to the given key in the cache, if
+    /// any, and increases the access frequency of the item.
+    fn get<Q>(&mut self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let entry = match self.small.get_mut(k) {
+            Some(entry) => Some(entry),
+            None => self.main.get_mut(k),
+        };
+
+        entry.map(|entry| {
+            entry.freq = (entry.freq + 1).min(MAX_FREQ);
+            &entry.value
+        })
+    }
+
+    /// Returns a reference to the value corresponding to the key in the cache or `None` if it is
+    /// not present in the cache. Unlike `get`, `peek` does not update the access frequency.
+    fn peek<Q>(&self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.small
+            .get(k)
+            .or_else(|| self.main.get(k))
+            .map(|entry| &entry.value)
+    }
+
+    /// Returns the item at the head of the queue to evict from.
+    ///
+    /// Note that the item may be moved instead of evicted, if it has been accessed.
+    fn peek_by_policy(&self) -> Option<(&K, &V)> {
+        let queue = if self.evict_from_small() {
+            &self.small
+        } else {
+            &self.main
+        };
+        queue.front().map(|(k, entry)| (k, &entry.value))
+    }
+
+    /// Inserts a key-value pair into the cache. If the key already existed, the old value is
+    /// returned.
+    fn insert(&mut self, k: K, v: V) -> Option<V> {
+        let size = v.mem_bytes();
+
+        let old_val = if let Some(entry) = self.small.get_mut(&k) {
+            self.small_bytes = self.small_bytes + size - entry.value.mem_bytes();
+            self.bytes = self.bytes + size - entry.value.mem_bytes();
+            Some(std::mem::replace(&mut entry.value, v))
+        } else if let Some(entry) = self.main.get_mut(&k) {
+            self.bytes = self.bytes + size - entry.value.mem_bytes();
+            Some(std::mem::replace(&mut entry.value, v))
+        } else {
+            self.bytes += size;
+            let entry = Entry { value: v, freq: 0 };
+
+            if self.ghost.remove(&k).is_some() {
+                self.main.insert(k, entry);
+                self.admitted += 1;
+            } else {
+                self.small_bytes += size;
+                self.small.insert(k, entry);
+            }
+            None
+        };
+
+        while self.bytes > self.max_bytes || self.len() > self.max_items {
+            if self.pop_by_policy().is_none() {
+                break;
+            }
+        }
+        old_val
+    }
+
+    /// Removes the given key from the cache and returns its corresponding value.
+    fn pop<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if let Some(entry) = self.small.remove(k) {
+            self.small_bytes -= entry.value.mem_bytes();
+            self.bytes -= entry.value.mem_bytes();
+            return Some(entry.value);
+        }
+
+        self.main.remove(k).map(|entry| {
+            self.bytes -= entry.value.mem_bytes();
+            entry.value
+        })
+    }
+
+    /// Evicts an item by the S3-FIFO policy and returns it.
+    fn pop_by_policy(&mut self) -> Option<(K, V)> {
+        if self.evict_from_small() {
+            if let Some(kv) = self.evict_small() {
+                return Some(kv);
+            }
+        }
+        self.evict_main()
+    }
+
+    /// Checks if the map contains the given key.
+    fn contains<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.small.contains_key(k) || self.main.contains_key(k)
+    }
+
+    /// Returns the number of key-value pairs in the cache.
+    fn len(&self) -> usize {
+        self.small.len() + self.main.len()
+    }
+
+    /// Returns `true` if the cache contains no key-value pairs.
+    fn is_empty(&self) -> bool {
+        self.small.is_empty() && self.main.is_empty()
+    }
+
+    /// Returns the maximum bytes size of the key-value pairs the cache can hold.
+    fn bytes_capacity(&self) -> u64 {
+        self.max_bytes as u64
+    }
+
+    fn items_capacity(&self) -> u64 {
+        self.max_items as u64
+    }
+
+    /// Returns the bytes size of all the key-value pairs in the cache.
+    fn bytes_size(&self) -> u64 {
+        self.bytes as u64
+    }
+
+    /// Removes all key-value pairs from the cache.
+    fn clear(&mut self) {
+        self.small.clear();
+        self.main.clear();
+        self.ghost.clear();
+        self.small_bytes = 0;
+        self.bytes = 0;
+        self.admitted = 0;
+        self.rejected = 0;
+    }
+}
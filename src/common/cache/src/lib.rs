@@ -19,6 +19,7 @@ mod cache;
 mod mem_sized;
 
 pub use cache::lru::LruCache;
+pub use cache::s3fifo::S3FifoCache;
 pub use cache::Cache;
 pub use hashbrown::DefaultHashBuilder;
 pub use mem_sized::MemSized;
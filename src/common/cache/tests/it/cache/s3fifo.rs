@@ -0,0 +1,156 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_cache::Cache;
+use databend_common_cache::MemSized;
+use databend_common_cache::S3FifoCache;
+
+#[derive(Eq, PartialEq, Hash, Clone, Debug)]
+struct TestKey(pub i32);
+
+impl MemSized for TestKey {
+    fn mem_bytes(&self) -> usize {
+        0
+    }
+}
+
+#[derive(Eq, PartialEq, Debug)]
+struct TestValue(pub i32);
+
+impl MemSized for TestValue {
+    fn mem_bytes(&self) -> usize {
+        1
+    }
+}
+
+#[test]
+fn test_put_and_get() {
+    let mut cache = S3FifoCache::with_items_capacity(2);
+    cache.insert(TestKey(1), TestValue(10));
+    cache.insert(TestKey(2), TestValue(20));
+    assert_eq!(cache.get(&TestKey(1)), Some(&TestValue(10)));
+    assert_eq!(cache.get(&TestKey(2)), Some(&TestValue(20)));
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.bytes_size(), 2);
+
+    assert_eq!(cache.insert(TestKey(1), TestValue(11)), Some(TestValue(10)));
+    assert_eq!(cache.peek(&TestKey(1)), Some(&TestValue(11)));
+    assert_eq!(cache.len(), 2);
+}
+
+#[test]
+fn test_pop() {
+    let mut cache = S3FifoCache::with_items_capacity(2);
+    cache.insert(TestKey(1), TestValue(10));
+    cache.insert(TestKey(2), TestValue(20));
+    assert_eq!(cache.pop(&TestKey(1)), Some(TestValue(10)));
+    assert_eq!(cache.pop(&TestKey(1)), None);
+    assert!(!cache.contains(&TestKey(1)));
+    assert_eq!(cache.len(), 1);
+    assert_eq!(cache.bytes_size(), 1);
+}
+
+#[test]
+fn test_evict_not_accessed() {
+    let mut cache = S3FifoCache::with_items_capacity(2);
+    cache.insert(TestKey(1), TestValue(10));
+    cache.get(&TestKey(1));
+    cache.insert(TestKey(2), TestValue(20));
+    cache.insert(TestKey(3), TestValue(30));
+
+    assert!(cache.contains(&TestKey(1)));
+    assert!(!cache.contains(&TestKey(2)));
+    assert!(cache.contains(&TestKey(3)));
+    assert_eq!(cache.admitted(), 1);
+    assert_eq!(cache.rejected(), 1);
+}
+
+#[test]
+fn test_ghost_admission() {
+    let mut cache = S3FifoCache::with_items_capacity(10);
+    for i in 0..11 {
+        cache.insert(TestKey(i), TestValue(i));
+    }
+    assert!(!cache.contains(&TestKey(0)));
+    assert_eq!(cache.rejected(), 1);
+
+    // the evicted key is remembered, so it is admitted into the main queue directly
+    cache.insert(TestKey(0), TestValue(0));
+    assert_eq!(cache.admitted(), 1);
+    assert!(!cache.contains(&TestKey(1)));
+
+    cache.insert(TestKey(11), TestValue(11));
+    assert!(cache.contains(&TestKey(0)));
+    assert_eq!(cache.len(), 10);
+}
+
+#[test]
+fn test_scan_resistance() {
+    let mut cache = S3FifoCache::with_items_capacity(10);
+    for i in 0..5 {
+        cache.insert(TestKey(i), TestValue(i));
+        cache.get(&TestKey(i));
+    }
+
+    // a scan that reads each item only once
+    for i in 100..200 {
+        cache.insert(TestKey(i), TestValue(i));
+    }
+
+    for i in 0..5 {
+        assert_eq!(cache.get(&TestKey(i)), Some(&TestValue(i)));
+    }
+    assert_eq!(cache.len(), 10);
+    assert_eq!(cache.admitted(), 5);
+}
+
+#[test]
+fn test_bytes_capacity() {
+    let mut cache = S3FifoCache::with_bytes_capacity(10);
+    for i in 0..20 {
+        cache.insert(TestKey(i), TestValue(i));
+    }
+    assert_eq!(cache.bytes_size(), 10);
+    assert_eq!(cache.len(), 10);
+    assert_eq!(cache.bytes_capacity(), 10);
+
+    cache.clear();
+    assert!(cache.is_empty());
+    assert_eq!(cache.bytes_size(), 0);
+}
+
+#[test]
+fn test_clear() {
+    let mut cache = S3FifoCache::with_items_capacity(10);
+    for i in 0..11 {
+        cache.insert(TestKey(i), TestValue(i));
+    }
+    cache.insert(TestKey(0), TestValue(0));
+    assert_eq!(cache.admitted(), 1);
+    assert_eq!(cache.rejected(), 2);
+
+    cache.clear();
+    assert!(cache.is_empty());
+    assert_eq!(cache.bytes_size(), 0);
+    assert_eq!(cache.admitted(), 0);
+    assert_eq!(cache.rejected(), 0);
+
+    // the small queue is accounted from zero again
+    for i in 0..10 {
+        cache.insert(TestKey(i), TestValue(i));
+    }
+    assert_eq!(cache.len(), 10);
+    assert_eq!(cache.bytes_size(), 10);
+    assert_eq!(cache.rejected(), 0);
+}
@@ -157,6 +157,7 @@ build_exceptions! {
     WrongSequenceCount(1125),
     UnknownSequence(1126),
     UnknownQuery(1127),
+    UnknownRowAccessPolicy(1128),
 
     // Data Related Errors
 
@@ -288,6 +289,12 @@ build_exceptions! {
 
     CommitTableMetaError(2322),
     CreateAsDropTableWithoutDropTime(2323),
+    /// Table branch or tag errors.
+    UnknownTableRef(2324),
+    TableRefAlreadyExists(2325),
+    /// row access policy error codes
+    RowAccessPolicyAlreadyExists(2326),
+    RowAccessPolicyInUse(2327),
 
 
     // Cluster error codes.
@@ -392,6 +399,14 @@ build_exceptions! {
     UnknownProcedure(3130),
     ProcedureAlreadyExists(3131),
     IllegalProcedureFormat(3132),
+    // Task
+    UnknownTask(3140),
+    TaskAlreadyExists(3141),
+    IllegalTaskFormat(3142),
+    // Notification
+    UnknownNotification(3150),
+    NotificationAlreadyExists(3151),
+    IllegalNotificationFormat(3152),
 }
 
 // Storage errors [3001, 4000].
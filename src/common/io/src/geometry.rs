@@ -17,10 +17,12 @@ use std::str::FromStr;
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use geo::BoundingRect;
 use geo::Geometry;
 use geozero::wkb::Ewkb;
 use geozero::CoordDimensions;
 use geozero::GeozeroGeometry;
+use geozero::ToGeo;
 use geozero::ToJson;
 use geozero::ToWkb;
 use geozero::ToWkt;
@@ -50,6 +52,54 @@ pub enum Extremum {
     Min,
 }
 
+/// An axis-aligned rectangle that encloses a geometry.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub xmin: f64,
+    pub ymin: f64,
+    pub xmax: f64,
+    pub ymax: f64,
+}
+
+impl BoundingBox {
+    /// Returns `None` for an empty geometry.
+    pub fn from_ewkb(buf: &[u8]) -> Result<Option<BoundingBox>> {
+        let geom: Geometry<f64> = Ewkb(buf).to_geo().map_err(ErrorCode::from)?;
+        Ok(geom.bounding_rect().map(|rect| BoundingBox {
+            xmin: rect.min().x,
+            ymin: rect.min().y,
+            xmax: rect.max().x,
+            ymax: rect.max().y,
+        }))
+    }
+
+    pub fn merge(&self, other: &BoundingBox) -> BoundingBox {
+        BoundingBox {
+            xmin: self.xmin.min(other.xmin),
+            ymin: self.ymin.min(other.ymin),
+            xmax: self.xmax.max(other.xmax),
+            ymax: self.ymax.max(other.ymax),
+        }
+    }
+
+    pub fn intersects(&self, other: &BoundingBox) -> bool {
+        self.xmin <= other.xmax
+            && other.xmin <= self.xmax
+            && self.ymin <= other.ymax
+            && other.ymin <= self.ymax
+    }
+
+    /// Grows the box by `distance` in every direction.
+    pub fn expand(&self, distance: f64) -> BoundingBox {
+        BoundingBox {
+            xmin: self.xmin - distance,
+            ymin: self.ymin - distance,
+            xmax: self.xmax + distance,
+            ymax: self.ymax + distance,
+        }
+    }
+}
+
 impl FromStr for GeometryDataType {
     type Err = ErrorCode;
 
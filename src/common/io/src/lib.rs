@@ -43,6 +43,7 @@ pub mod geography;
 pub mod geometry;
 mod position;
 mod stat_buffer;
+mod theta_sketch;
 
 pub mod wkb;
 
@@ -57,5 +58,8 @@ pub use geometry::parse_bytes_to_ewkb;
 pub use geometry::parse_to_ewkb;
 pub use geometry::parse_to_subtype;
 pub use geometry::Axis;
+pub use geometry::BoundingBox;
 pub use geometry::Extremum;
 pub use geometry::GeometryDataType;
+pub use theta_sketch::ThetaSketch;
+pub use theta_sketch::THETA_SKETCH_DEFAULT_K;
@@ -0,0 +1,133 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+/// The default number of retained hashes, the relative error is about 1/sqrt(k).
+pub const THETA_SKETCH_DEFAULT_K: u32 = 4096;
+
+/// A KMV theta sketch, it keeps the `k` smallest 64-bit hashes of the values,
+/// all retained hashes are smaller than `theta`. The number of distinct values
+/// is estimated by `retained / (theta / 2^64)`, and sketches can be combined
+/// with set union and intersection.
+#[derive(Debug, Clone, PartialEq, BorshSerialize, BorshDeserialize)]
+pub struct ThetaSketch {
+    k: u32,
+    theta: u64,
+    // Sorted in ascending order
+    hashes: Vec<u64>,
+}
+
+impl Default for ThetaSketch {
+    fn default() -> Self {
+        Self::new(THETA_SKETCH_DEFAULT_K)
+    }
+}
+
+impl ThetaSketch {
+    pub fn new(k: u32) -> Self {
+        Self {
+            k: k.max(1),
+            theta: u64::MAX,
+            hashes: vec![],
+        }
+    }
+
+    pub fn update(&mut self, hash: u64) {
+        if hash >= self.theta {
+            return;
+        }
+        if let Err(pos) = self.hashes.binary_search(&hash) {
+            self.hashes.insert(pos, hash);
+            if self.hashes.len() > self.k as usize {
+                self.theta = self.hashes.pop().unwrap();
+            }
+        }
+    }
+
+    pub fn union(&mut self, other: &ThetaSketch) {
+        self.k = self.k.min(other.k);
+        self.theta = self.theta.min(other.theta);
+
+        let mut hashes = Vec::with_capacity(self.hashes.len() + other.hashes.len());
+        let (mut i, mut j) = (0, 0);
+        while i < self.hashes.len() || j < other.hashes.len() {
+            let hash = match (self.hashes.get(i), other.hashes.get(j)) {
+                (Some(a), Some(b)) if a == b => {
+                    i += 1;
+                    j += 1;
+                    *a
+                }
+                (Some(a), Some(b)) if a < b => {
+                    i += 1;
+                    *a
+                }
+                (Some(a), None) => {
+                    i += 1;
+                    *a
+                }
+                (_, Some(b)) => {
+                    j += 1;
+                    *b
+                }
+                (None, None) => unreachable!(),
+            };
+            if hash >= self.theta {
+                break;
+            }
+            hashes.push(hash);
+        }
+        if hashes.len() > self.k as usize {
+            self.theta = hashes[self.k as usize];
+            hashes.truncate(self.k as usize);
+        }
+        self.hashes = hashes;
+    }
+
+    pub fn intersect(&mut self, other: &ThetaSketch) {
+        self.k = self.k.min(other.k);
+        self.theta = self.theta.min(other.theta);
+        let theta = self.theta;
+        self.hashes
+            .retain(|hash| *hash < theta && other.hashes.binary_search(hash).is_ok());
+    }
+
+    pub fn estimate(&self) -> f64 {
+        if self.theta == u64::MAX {
+            self.hashes.len() as f64
+        } else {
+            self.hashes.len() as f64 * (u64::MAX as f64 / self.theta as f64)
+        }
+    }
+
+    pub fn serialize(&self, writer: &mut Vec<u8>) -> Result<()> {
+        Ok(borsh::to_writer(writer, self)?)
+    }
+
+    /// Deserializes a sketch, an empty buffer is an empty sketch.
+    pub fn deserialize(buf: &[u8]) -> Result<ThetaSketch> {
+        if buf.is_empty() {
+            return Ok(ThetaSketch::default());
+        }
+        borsh::from_slice(buf).map_err(|e| {
+            let len = buf.len();
+            ErrorCode::BadBytes(format!(
+                "fail to decode theta sketch from buffer of size {len}: {e}"
+            ))
+        })
+    }
+}
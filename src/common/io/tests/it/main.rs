@@ -24,3 +24,4 @@ mod borsh_serialization;
 mod cursor_ext;
 mod escape;
 mod serialization_format_compatability;
+mod theta_sketch;
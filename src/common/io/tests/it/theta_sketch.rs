@@ -0,0 +1,62 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_io::ThetaSketch;
+
+// Spread the values over the hash space like a real hash function does.
+fn hash(value: u64) -> u64 {
+    value.wrapping_mul(0x9E37_79B9_7F4A_7C15)
+}
+
+fn sketch(k: u32, values: impl Iterator<Item = u64>) -> ThetaSketch {
+    let mut sketch = ThetaSketch::new(k);
+    for value in values {
+        sketch.update(hash(value));
+    }
+    sketch
+}
+
+#[test]
+fn test_theta_sketch_exact() -> Result<()> {
+    let sketch = sketch(1024, (0..100).chain(0..100));
+    assert_eq!(sketch.estimate(), 100.0);
+
+    let mut buf = vec![];
+    sketch.serialize(&mut buf)?;
+    assert_eq!(ThetaSketch::deserialize(&buf)?, sketch);
+    assert_eq!(ThetaSketch::deserialize(&[])?.estimate(), 0.0);
+    assert!(ThetaSketch::deserialize(&[1, 2, 3]).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_theta_sketch_set_operations() {
+    let estimate = sketch(1024, 0..100_000).estimate();
+    assert!((estimate - 100_000.0).abs() < 10_000.0, "{estimate}");
+
+    let mut union = sketch(1024, 0..60_000);
+    union.union(&sketch(1024, 40_000..100_000));
+    let estimate = union.estimate();
+    assert!((estimate - 100_000.0).abs() < 10_000.0, "{estimate}");
+
+    let mut intersection = sketch(1024, 0..60_000);
+    intersection.intersect(&sketch(1024, 40_000..100_000));
+    let estimate = intersection.estimate();
+    assert!((estimate - 20_000.0).abs() < 5_000.0, "{estimate}");
+
+    let mut disjoint = sketch(1024, 0..10);
+    disjoint.intersect(&sketch(1024, 10..20));
+    assert_eq!(disjoint.estimate(), 0.0);
+}
@@ -73,6 +73,8 @@ pub enum Feature {
     StorageQuota(StorageQuota),
     #[serde(alias = "amend_table", alias = "AMEND_TABLE")]
     AmendTable,
+    #[serde(alias = "row_access_policy", alias = "ROW_ACCESS_POLICY")]
+    RowAccessPolicy,
     #[serde(other)]
     Unknown,
 }
@@ -119,6 +121,7 @@ impl fmt::Display for Feature {
                 write!(f, ")")
             }
             Feature::AmendTable => write!(f, "amend_table"),
+            Feature::RowAccessPolicy => write!(f, "row_access_policy"),
             Feature::Unknown => write!(f, "unknown"),
         }
     }
@@ -163,6 +166,7 @@ impl Feature {
             | (Feature::Stream, Feature::Stream)
             | (Feature::BackgroundService, Feature::BackgroundService)
             | (Feature::DataMask, Feature::DataMask)
+            | (Feature::RowAccessPolicy, Feature::RowAccessPolicy)
             | (Feature::InvertedIndex, Feature::InvertedIndex)
             | (Feature::VirtualColumn, Feature::VirtualColumn)
             | (Feature::AttacheTable, Feature::AttacheTable)
@@ -279,6 +283,10 @@ mod tests {
             Feature::DataMask,
             serde_json::from_str::<Feature>("\"DataMask\"").unwrap()
         );
+        assert_eq!(
+            Feature::RowAccessPolicy,
+            serde_json::from_str::<Feature>("\"row_access_policy\"").unwrap()
+        );
         assert_eq!(
             Feature::AggregateIndex,
             serde_json::from_str::<Feature>("\"AggregateIndex\"").unwrap()
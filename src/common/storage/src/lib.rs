@@ -48,6 +48,7 @@ pub use crate::metrics::StorageMetrics;
 pub use crate::metrics::StorageMetricsLayer;
 
 mod runtime_layer;
+mod sftp;
 
 mod column_node;
 pub use column_node::ColumnNode;
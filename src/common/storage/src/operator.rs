@@ -37,6 +37,7 @@ use databend_common_meta_app::storage::StorageObsConfig;
 use databend_common_meta_app::storage::StorageOssConfig;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_meta_app::storage::StorageS3Config;
+use databend_common_meta_app::storage::StorageWebdavConfig;
 use databend_common_meta_app::storage::StorageWebhdfsConfig;
 use databend_enterprise_storage_encryption::get_storage_encryption_handler;
 use log::warn;
@@ -48,13 +49,16 @@ use opendal::layers::LoggingLayer;
 use opendal::layers::RetryInterceptor;
 use opendal::layers::RetryLayer;
 use opendal::layers::TimeoutLayer;
+use opendal::raw::Access;
 use opendal::raw::HttpClient;
 use opendal::services;
 use opendal::Builder;
 use opendal::Operator;
+use opendal::OperatorBuilder;
 
 use crate::metrics_layer::METRICS_LAYER;
 use crate::runtime_layer::RuntimeLayer;
+use crate::sftp::SftpBackend;
 use crate::StorageConfig;
 use crate::StorageHttpClient;
 
@@ -79,6 +83,10 @@ pub fn init_operator(cfg: &StorageParams) -> Result<Operator> {
         StorageParams::Webhdfs(cfg) => build_operator(init_webhdfs_operator(cfg)?)?,
         StorageParams::Cos(cfg) => build_operator(init_cos_operator(cfg)?)?,
         StorageParams::Huggingface(cfg) => build_operator(init_huggingface_operator(cfg)?)?,
+        StorageParams::Sftp(cfg) => {
+            build_operator_from(OperatorBuilder::new(SftpBackend::try_new(cfg)?))?
+        }
+        StorageParams::Webdav(cfg) => build_operator(init_webdav_operator(cfg)?)?,
         v => {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
@@ -109,7 +117,13 @@ pub fn init_operator(cfg: &StorageParams) -> Result<Operator> {
 ///
 /// Please balance the performance and compile time.
 pub fn build_operator<B: Builder>(builder: B) -> Result<Operator> {
-    let ob = Operator::new(builder)?
+    build_operator_from(Operator::new(builder)?)
+}
+
+/// build_operator_from works like `build_operator`, but for the services implemented
+/// by databend itself.
+fn build_operator_from<A: Access>(ob: OperatorBuilder<A>) -> Result<Operator> {
+    let ob = ob
         // NOTE
         //
         // Magic happens here. We will add a layer upon original
@@ -385,6 +399,19 @@ fn init_huggingface_operator(cfg: &StorageHuggingfaceConfig) -> Result<impl Buil
     Ok(builder)
 }
 
+/// init_webdav_operator will init an opendal WebDAV operator with input config.
+fn init_webdav_operator(cfg: &StorageWebdavConfig) -> Result<impl Builder> {
+    let builder = services::Webdav::default()
+        .endpoint(&cfg.endpoint_url)
+        .root(&cfg.root)
+        .username(&cfg.username)
+        .password(&cfg.password)
+        .token(&cfg.token)
+        .http_client(HttpClient::with(StorageHttpClient::default()));
+
+    Ok(builder)
+}
+
 pub struct DatabendRetryInterceptor;
 
 impl RetryInterceptor for DatabendRetryInterceptor {
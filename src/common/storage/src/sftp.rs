@@ -0,0 +1,588 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fs::OpenOptions;
+use std::io::SeekFrom;
+use std::io::Write;
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::Path;
+use std::pin::Pin;
+use std::process::Stdio;
+use std::sync::Arc;
+
+use bytes::BytesMut;
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_meta_app::storage::StorageSftpConfig;
+use futures::StreamExt;
+use opendal::raw::normalize_root;
+use opendal::raw::oio;
+use opendal::raw::Access;
+use opendal::raw::AccessorInfo;
+use opendal::raw::OpCreateDir;
+use opendal::raw::OpDelete;
+use opendal::raw::OpList;
+use opendal::raw::OpRead;
+use opendal::raw::OpStat;
+use opendal::raw::OpWrite;
+use opendal::raw::RpCreateDir;
+use opendal::raw::RpDelete;
+use opendal::raw::RpList;
+use opendal::raw::RpRead;
+use opendal::raw::RpStat;
+use opendal::raw::RpWrite;
+use opendal::Buffer;
+use opendal::EntryMode;
+use opendal::Error;
+use opendal::ErrorKind;
+use opendal::Metadata;
+use opendal::Result;
+use opendal::Scheme;
+use openssh::KnownHosts;
+use openssh::Session;
+use openssh::SessionBuilder;
+use openssh_sftp_client::error::SftpErrorKind;
+use openssh_sftp_client::file::File;
+use openssh_sftp_client::fs::ReadDir;
+use openssh_sftp_client::metadata::MetaData;
+use openssh_sftp_client::Sftp;
+use openssh_sftp_client::SftpOptions;
+use tempfile::TempDir;
+use tempfile::TempPath;
+use tokio::io::AsyncSeekExt;
+use tokio::sync::OnceCell;
+
+/// SftpBackend accesses the files of a stage over the sftp subsystem of ssh.
+///
+/// opendal's sftp service runs ssh in batch mode and loads the private key from
+/// a path, so it can neither authenticate with a password nor with the key
+/// content that a stage stores.
+pub struct SftpBackend {
+    info: Arc<AccessorInfo>,
+    endpoint: String,
+    root: String,
+    user: String,
+    known_hosts: KnownHosts,
+    credential: SftpCredential,
+    connection: OnceCell<SftpConnection>,
+}
+
+enum SftpCredential {
+    /// Authenticate with the default identities of the ssh client.
+    Default,
+    /// The private key written to a file that only the current user can read,
+    /// the file is removed when the backend is dropped.
+    Key(TempPath),
+    Password(String),
+}
+
+struct SftpConnection {
+    sftp: Sftp,
+    // Declared after `sftp` so that the master outlives the sftp session.
+    _master: Option<SshMaster>,
+}
+
+impl SftpBackend {
+    pub fn try_new(cfg: &StorageSftpConfig) -> std::io::Result<Self> {
+        let known_hosts = match cfg.known_hosts_strategy.to_lowercase().as_str() {
+            "" | "strict" => KnownHosts::Strict,
+            "accept" => KnownHosts::Accept,
+            "add" => KnownHosts::Add,
+            v => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!("unknown known_hosts_strategy for sftp: {v}"),
+                ));
+            }
+        };
+
+        let credential = if !cfg.key.is_empty() {
+            // The file is created with mode 0600 by tempfile.
+            let mut file = tempfile::Builder::new()
+                .prefix("databend-sftp-key-")
+                .tempfile()?;
+            file.write_all(cfg.key.as_bytes())?;
+            // ssh refuses keys without a trailing newline.
+            if !cfg.key.ends_with('\n') {
+                file.write_all(b"\n")?;
+            }
+            SftpCredential::Key(file.into_temp_path())
+        } else if !cfg.password.is_empty() {
+            SftpCredential::Password(cfg.password.clone())
+        } else {
+            SftpCredential::Default
+        };
+
+        let root = normalize_root(&cfg.root);
+        let mut info = AccessorInfo::default();
+        info.set_scheme(Scheme::Sftp).set_root(&root);
+        let cap = info.full_capability_mut();
+        cap.stat = true;
+        cap.read = true;
+        cap.write = true;
+        cap.write_can_multi = true;
+        cap.write_can_empty = true;
+        cap.create_dir = true;
+        cap.delete = true;
+        cap.list = true;
+
+        Ok(SftpBackend {
+            info: info.into(),
+            endpoint: cfg.endpoint.clone(),
+            root,
+            user: cfg.user.clone(),
+            known_hosts,
+            credential,
+            connection: OnceCell::new(),
+        })
+    }
+
+    async fn sftp(&self) -> Result<&Sftp> {
+        let connection = self
+            .connection
+            .get_or_try_init(|| async {
+                let (session, master) = match &self.credential {
+                    SftpCredential::Password(password) => {
+                        let master = SshMaster::launch(
+                            &self.endpoint,
+                            &self.user,
+                            password,
+                            &self.known_hosts,
+                        )
+                        .await?;
+                        (master.session(), Some(master))
+                    }
+                    credential => {
+                        let mut builder = SessionBuilder::default();
+                        builder.known_hosts_check(self.known_hosts.clone());
+                        if !self.user.is_empty() {
+                            builder.user(self.user.clone());
+                        }
+                        if let SftpCredential::Key(path) = credential {
+                            builder.keyfile(path);
+                        }
+                        let session = builder.connect(&self.endpoint).await.map_err(|e| {
+                            Error::new(ErrorKind::Unexpected, "failed to connect to sftp server")
+                                .set_source(e)
+                        })?;
+                        (session, None)
+                    }
+                };
+
+                let sftp = Sftp::from_session(session, SftpOptions::default())
+                    .await
+                    .map_err(parse_sftp_error)?;
+                Ok::<_, Error>(SftpConnection {
+                    sftp,
+                    _master: master,
+                })
+            })
+            .await?;
+
+        Ok(&connection.sftp)
+    }
+
+    fn abs_path(&self, path: &str) -> String {
+        if path == "/" {
+            self.root.clone()
+        } else {
+            format!("{}{}", self.root, path)
+        }
+    }
+
+    async fn create_dir_all(&self, path: &str) -> Result<()> {
+        let mut fs = self.sftp().await?.fs();
+        let path = Path::new(path);
+        let mut dirs = path.ancestors().collect::<Vec<_>>();
+        dirs.reverse();
+        for dir in dirs {
+            if dir.as_os_str().is_empty() || dir == Path::new("/") {
+                continue;
+            }
+            match fs.metadata(dir).await {
+                Ok(_) => {}
+                Err(e) if is_not_found(&e) => fs.create_dir(dir).await.map_err(parse_sftp_error)?,
+                Err(e) => return Err(parse_sftp_error(e)),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Debug for SftpBackend {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_struct("SftpBackend")
+            .field("endpoint", &self.endpoint)
+            .field("root", &self.root)
+            .field("user", &self.user)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Access for SftpBackend {
+    type Reader = SftpReader;
+    type BlockingReader = ();
+    type Writer = SftpWriter;
+    type BlockingWriter = ();
+    type Lister = SftpLister;
+    type BlockingLister = ();
+
+    fn info(&self) -> Arc<AccessorInfo> {
+        self.info.clone()
+    }
+
+    async fn create_dir(&self, path: &str, _: OpCreateDir) -> Result<RpCreateDir> {
+        self.create_dir_all(&self.abs_path(path)).await?;
+        Ok(RpCreateDir::default())
+    }
+
+    async fn stat(&self, path: &str, _: OpStat) -> Result<RpStat> {
+        let mut fs = self.sftp().await?.fs();
+        let meta = fs
+            .metadata(self.abs_path(path))
+            .await
+            .map_err(parse_sftp_error)?;
+        Ok(RpStat::new(parse_metadata(&meta)))
+    }
+
+    async fn read(&self, path: &str, args: OpRead) -> Result<(RpRead, Self::Reader)> {
+        let mut file = self
+            .sftp()
+            .await?
+            .open(self.abs_path(path))
+            .await
+            .map_err(parse_sftp_error)?;
+        let range = args.range();
+        file.seek(SeekFrom::Start(range.offset()))
+            .await
+            .map_err(|e| {
+                Error::new(ErrorKind::Unexpected, "failed to seek sftp file").set_source(e)
+            })?;
+
+        Ok((RpRead::new(), SftpReader {
+            file,
+            offset: range.offset(),
+            remaining: range.size(),
+        }))
+    }
+
+    async fn write(&self, path: &str, _: OpWrite) -> Result<(RpWrite, Self::Writer)> {
+        let path = self.abs_path(path);
+        if let Some(parent) = Path::new(&path).parent() {
+            self.create_dir_all(&parent.to_string_lossy()).await?;
+        }
+
+        let file = self
+            .sftp()
+            .await?
+            .options()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&path)
+            .await
+            .map_err(parse_sftp_error)?;
+
+        Ok((RpWrite::default(), SftpWriter { file: Some(file) }))
+    }
+
+    async fn delete(&self, path: &str, _: OpDelete) -> Result<RpDelete> {
+        let mut fs = self.sftp().await?.fs();
+        let res = if path.ends_with('/') {
+            fs.remove_dir(self.abs_path(path)).await
+        } else {
+            fs.remove_file(self.abs_path(path)).await
+        };
+        match res {
+            Err(e) if !is_not_found(&e) => Err(parse_sftp_error(e)),
+            _ => Ok(RpDelete::default()),
+        }
+    }
+
+    async fn list(&self, path: &str, _: OpList) -> Result<(RpList, Self::Lister)> {
+        let mut fs = self.sftp().await?.fs();
+        let entries = match fs.open_dir(self.abs_path(path)).await {
+            Ok(dir) => Some(Box::pin(dir.read_dir())),
+            Err(e) if is_not_found(&e) => None,
+            Err(e) => return Err(parse_sftp_error(e)),
+        };
+
+        let prefix = if path == "/" { "" } else { path };
+        Ok((RpList::default(), SftpLister {
+            prefix: prefix.to_string(),
+            entries,
+        }))
+    }
+}
+
+pub struct SftpReader {
+    file: File,
+    offset: u64,
+    remaining: Option<u64>,
+}
+
+impl oio::Read for SftpReader {
+    async fn read(&mut self) -> Result<Buffer> {
+        let mut size = self.file.max_read_len();
+        if let Some(remaining) = self.remaining {
+            if remaining == 0 {
+                return Ok(Buffer::new());
+            }
+            size = size.min(remaining.try_into().unwrap_or(u32::MAX));
+        }
+
+        // `File::read` advances the offset by the requested size even if less
+        // data is returned, so the offset is tracked here.
+        self.file
+            .seek(SeekFrom::Start(self.offset))
+            .await
+            .map_err(|e| {
+                Error::new(ErrorKind::Unexpected, "failed to seek sftp file").set_source(e)
+            })?;
+        let buf = self
+            .file
+            .read(size, BytesMut::with_capacity(size as usize))
+            .await
+            .map_err(parse_sftp_error)?;
+
+        match buf {
+            Some(buf) => {
+                self.offset += buf.len() as u64;
+                if let Some(remaining) = &mut self.remaining {
+                    *remaining -= buf.len() as u64;
+                }
+                Ok(Buffer::from(buf.freeze()))
+            }
+            None => Ok(Buffer::new()),
+        }
+    }
+}
+
+pub struct SftpWriter {
+    file: Option<File>,
+}
+
+impl oio::Write for SftpWriter {
+    async fn write(&mut self, bs: Buffer) -> Result<()> {
+        let file = self
+            .file
+            .as_mut()
+            .ok_or_else(|| Error::new(ErrorKind::Unexpected, "sftp writer has been closed"))?;
+        file.write_all(&bs.to_bytes())
+            .await
+            .map_err(parse_sftp_error)
+    }
+
+    async fn close(&mut self) -> Result<()> {
+        match self.file.take() {
+            Some(file) => file.close().await.map_err(parse_sftp_error),
+            None => Ok(()),
+        }
+    }
+
+    async fn abort(&mut self) -> Result<()> {
+        Err(Error::new(
+            ErrorKind::Unsupported,
+            "sftp writer doesn't support abort",
+        ))
+    }
+}
+
+pub struct SftpLister {
+    prefix: String,
+    entries: Option<Pin<Box<ReadDir>>>,
+}
+
+impl oio::List for SftpLister {
+    async fn next(&mut self) -> Result<Option<oio::Entry>> {
+        let Some(entries) = &mut self.entries else {
+            return Ok(None);
+        };
+
+        while let Some(entry) = entries.next().await {
+            let entry = entry.map_err(parse_sftp_error)?;
+            let name = entry.filename().to_string_lossy();
+            if name == "." || name == ".." {
+                continue;
+            }
+
+            let meta = parse_metadata(&entry.metadata());
+            let path = if meta.is_dir() {
+                format!("{}{}/", self.prefix, name)
+            } else {
+                format!("{}{}", self.prefix, name)
+            };
+            return Ok(Some(oio::Entry::new(&path, meta)));
+        }
+        Ok(None)
+    }
+}
+
+/// An ssh master connection authenticated with a password.
+///
+/// ssh only reads passwords from a terminal or from an askpass program, so the
+/// master is started with an askpass script that prints the password from the
+/// environment of the master process. The password is never written to disk.
+struct SshMaster {
+    dir: TempDir,
+}
+
+impl SshMaster {
+    async fn launch(
+        endpoint: &str,
+        user: &str,
+        password: &str,
+        known_hosts: &KnownHosts,
+    ) -> Result<Self> {
+        let master = SshMaster {
+            // The directory is created with mode 0700 by tempfile.
+            dir: tempfile::Builder::new()
+                .prefix("databend-sftp-")
+                .tempdir()
+                .map_err(|e| {
+                    Error::new(
+                        ErrorKind::Unexpected,
+                        "failed to create ssh control directory",
+                    )
+                    .set_source(e)
+                })?,
+        };
+
+        let askpass = master.dir.path().join("askpass");
+        OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .mode(0o700)
+            .open(&askpass)
+            .and_then(|mut file| {
+                file.write_all(b"#!/bin/sh\nprintf '%s\\n' \"$DATABEND_SFTP_PASSWORD\"\n")
+            })
+            .map_err(|e| {
+                Error::new(ErrorKind::Unexpected, "failed to create ssh askpass script")
+                    .set_source(e)
+            })?;
+
+        let known_hosts = match known_hosts {
+            KnownHosts::Strict => "StrictHostKeyChecking=yes",
+            KnownHosts::Add => "StrictHostKeyChecking=accept-new",
+            KnownHosts::Accept => "StrictHostKeyChecking=no",
+        };
+
+        let log = master.dir.path().join("log");
+        let mut cmd = tokio::process::Command::new("ssh");
+        cmd.stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .env("SSH_ASKPASS", &askpass)
+            .env("SSH_ASKPASS_REQUIRE", "force")
+            .env("DATABEND_SFTP_PASSWORD", password)
+            .arg("-E")
+            .arg(&log)
+            .arg("-S")
+            .arg(master.control_path())
+            .arg("-M")
+            .arg("-f")
+            .arg("-N")
+            .args(["-o", "ControlPersist=yes"])
+            .args(["-o", "PubkeyAuthentication=no"])
+            .args([
+                "-o",
+                "PreferredAuthentications=password,keyboard-interactive",
+            ])
+            .args(["-o", "NumberOfPasswordPrompts=1"])
+            .args(["-o", known_hosts]);
+        if !user.is_empty() {
+            cmd.arg("-l").arg(user);
+        }
+        cmd.arg(endpoint);
+
+        // ssh forks to the background once the master is authenticated.
+        let status = cmd
+            .status()
+            .await
+            .map_err(|e| Error::new(ErrorKind::Unexpected, "failed to start ssh").set_source(e))?;
+        if !status.success() {
+            let output = std::fs::read_to_string(&log).unwrap_or_default();
+            return Err(Error::new(
+                ErrorKind::PermissionDenied,
+                "failed to connect to sftp server",
+            )
+            .with_context("output", output.trim()));
+        }
+
+        Ok(master)
+    }
+
+    fn control_path(&self) -> std::path::PathBuf {
+        self.dir.path().join("master")
+    }
+
+    fn session(&self) -> Session {
+        Session::resume(
+            self.control_path().into_boxed_path(),
+            Some(self.dir.path().join("log").into_boxed_path()),
+        )
+    }
+}
+
+impl Drop for SshMaster {
+    fn drop(&mut self) {
+        // Sessions resumed from a control socket don't stop the master on drop.
+        let _ = std::process::Command::new("ssh")
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .arg("-S")
+            .arg(self.control_path())
+            .args(["-O", "exit", "none"])
+            .status();
+    }
+}
+
+fn is_not_found(e: &openssh_sftp_client::Error) -> bool {
+    matches!(
+        e,
+        openssh_sftp_client::Error::SftpError(SftpErrorKind::NoSuchFile, _)
+    )
+}
+
+fn parse_sftp_error(e: openssh_sftp_client::Error) -> Error {
+    let kind = match &e {
+        openssh_sftp_client::Error::SftpError(SftpErrorKind::NoSuchFile, _) => ErrorKind::NotFound,
+        openssh_sftp_client::Error::SftpError(SftpErrorKind::PermDenied, _) => {
+            ErrorKind::PermissionDenied
+        }
+        _ => ErrorKind::Unexpected,
+    };
+    Error::new(kind, "sftp operation failed").set_source(e)
+}
+
+fn parse_metadata(meta: &MetaData) -> Metadata {
+    let mode = match meta.file_type() {
+        Some(file_type) if file_type.is_dir() => EntryMode::DIR,
+        Some(file_type) if file_type.is_file() => EntryMode::FILE,
+        _ => EntryMode::Unknown,
+    };
+
+    let mut metadata = Metadata::new(mode);
+    if let Some(len) = meta.len() {
+        metadata.set_content_length(len);
+    }
+    if let Some(modified) = meta.modified() {
+        metadata.set_last_modified(DateTime::<Utc>::from(modified.as_system_time()));
+    }
+    metadata
+}
@@ -30,6 +30,8 @@ pub mod meta_txn_error;
 pub mod name_id_value_api;
 pub mod name_value_api;
 pub mod reply;
+mod row_access_policy_api;
+mod row_access_policy_api_impl;
 mod schema_api;
 mod schema_api_impl;
 mod schema_api_test_suite;
@@ -44,6 +46,7 @@ mod sequence_api_impl;
 pub use background_api::BackgroundApi;
 pub use background_api_test_suite::BackgroundApiTestSuite;
 pub use data_mask_api::DatamaskApi;
+pub use row_access_policy_api::RowAccessPolicyApi;
 pub use schema_api::SchemaApi;
 pub use schema_api_test_suite::SchemaApiTestSuite;
 pub use sequence_api::SequenceApi;
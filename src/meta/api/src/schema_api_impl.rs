@@ -56,6 +56,8 @@ use databend_common_meta_app::app_error::UnknownTableId;
 use databend_common_meta_app::app_error::ViewAlreadyExists;
 use databend_common_meta_app::data_mask::MaskPolicyTableIdListIdent;
 use databend_common_meta_app::id_generator::IdGenerator;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyNameIdent;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyTableIdListIdent;
 use databend_common_meta_app::schema::catalog_id_ident::CatalogId;
 use databend_common_meta_app::schema::catalog_name_ident::CatalogNameIdentRaw;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
@@ -137,6 +139,9 @@ use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyAction;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReply;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyAction;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReply;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableCopiedFileNameIdent;
 use databend_common_meta_app::schema::TableId;
 use databend_common_meta_app::schema::TableIdHistoryIdent;
@@ -147,6 +152,7 @@ use databend_common_meta_app::schema::TableIndex;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TableNameIdent;
+use databend_common_meta_app::schema::TableRowAccessPolicy;
 use databend_common_meta_app::schema::TruncateTableReply;
 use databend_common_meta_app::schema::TruncateTableReq;
 use databend_common_meta_app::schema::UndropDatabaseReply;
@@ -1217,6 +1223,27 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
                         txn_op_put(&key_table_id_to_name, serialize_struct(&key_dbid_tbname)?), /* __fd_table_id_to_name/db_id/table_name -> DBIdTableName */
                     ]);
 
+                if let Some(pin) = &req.clone_pin {
+                    // Record the clone in the source table in the same txn, so that the
+                    // shared snapshot is protected from gc as soon as the clone exists.
+                    let key_source_id = TableId {
+                        table_id: pin.source_table_id,
+                    };
+                    let Some(seq_source_meta) = self.get_pb(&key_source_id).await? else {
+                        return Err(KVAppError::AppError(AppError::UnknownTableId(
+                            UnknownTableId::new(pin.source_table_id, "create_table: clone source"),
+                        )));
+                    };
+                    let mut source_meta = seq_source_meta.data;
+                    source_meta
+                        .clones
+                        .insert(table_id, pin.snapshot_location.clone());
+                    txn.condition
+                        .push(txn_cond_seq(&key_source_id, Eq, seq_source_meta.seq));
+                    txn.if_then
+                        .push(txn_op_put(&key_source_id, serialize_struct(&source_meta)?));
+                }
+
                 if req.as_dropped {
                     // To create the table in a "dropped" state,
                     // - we intentionally omit the tuple (key_dbid_name, table_id).
@@ -2433,6 +2460,133 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         }
     }
 
+    #[logcall::logcall]
+    #[fastrace::trace]
+    async fn set_table_row_access_policy(
+        &self,
+        req: SetTableRowAccessPolicyReq,
+    ) -> Result<SetTableRowAccessPolicyReply, KVAppError> {
+        debug!(req :? =(&req); "SchemaApi: {}", func_name!());
+        let tbid = TableId {
+            table_id: req.table_id,
+        };
+        let req_seq = req.seq;
+
+        let mut trials = txn_backoff(None, func_name!());
+        loop {
+            trials.next().unwrap()?.await;
+
+            let seq_meta = self.get_pb(&tbid).await?;
+
+            debug!(ident :% =(&tbid); "set_table_row_access_policy");
+
+            let Some(seq_meta) = seq_meta else {
+                return Err(KVAppError::AppError(AppError::UnknownTableId(
+                    UnknownTableId::new(req.table_id, "set_table_row_access_policy"),
+                )));
+            };
+
+            if req_seq.match_seq(seq_meta.seq).is_err() {
+                return Err(KVAppError::AppError(AppError::from(
+                    TableVersionMismatched::new(
+                        req.table_id,
+                        req.seq,
+                        seq_meta.seq,
+                        "set_table_row_access_policy",
+                    ),
+                )));
+            }
+
+            let mut new_table_meta = seq_meta.data;
+            let old_policy = new_table_meta
+                .row_access_policy
+                .as_ref()
+                .map(|p| p.policy.clone());
+
+            let mut txn_req = TxnRequest {
+                condition: vec![
+                    // table is not changed
+                    txn_cond_seq(&tbid, Eq, seq_meta.seq),
+                ],
+                if_then: vec![],
+                else_then: vec![],
+            };
+
+            match &req.action {
+                SetTableRowAccessPolicyAction::Set(new_policy, columns) => {
+                    if old_policy.as_ref() != Some(new_policy) {
+                        let attached = update_row_access_policy_table_ids(
+                            self,
+                            &mut txn_req,
+                            &req.tenant,
+                            new_policy,
+                            |list| {
+                                list.insert(req.table_id);
+                            },
+                        )
+                        .await?;
+                        if !attached {
+                            let name_ident =
+                                RowAccessPolicyNameIdent::new(req.tenant.clone(), new_policy);
+                            return Err(AppError::from(
+                                name_ident.unknown_error("set_table_row_access_policy"),
+                            )
+                            .into());
+                        }
+
+                        if let Some(old_policy) = &old_policy {
+                            update_row_access_policy_table_ids(
+                                self,
+                                &mut txn_req,
+                                &req.tenant,
+                                old_policy,
+                                |list| {
+                                    list.remove(&req.table_id);
+                                },
+                            )
+                            .await?;
+                        }
+                    }
+
+                    new_table_meta.row_access_policy = Some(TableRowAccessPolicy {
+                        policy: new_policy.clone(),
+                        columns: columns.clone(),
+                    });
+                }
+                SetTableRowAccessPolicyAction::Unset(policy) => {
+                    update_row_access_policy_table_ids(
+                        self,
+                        &mut txn_req,
+                        &req.tenant,
+                        policy,
+                        |list| {
+                            list.remove(&req.table_id);
+                        },
+                    )
+                    .await?;
+
+                    new_table_meta.row_access_policy = None;
+                }
+            }
+
+            txn_req.if_then.push(
+                txn_op_put(&tbid, serialize_struct(&new_table_meta)?), // tb_id -> tb_meta
+            );
+
+            let (succ, _responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                id :? =(&tbid),
+                succ = succ;
+                "set_table_row_access_policy"
+            );
+
+            if succ {
+                return Ok(SetTableRowAccessPolicyReply {});
+            }
+        }
+    }
+
     #[logcall::logcall]
     #[fastrace::trace]
     async fn create_table_index(&self, req: CreateTableIndexReq) -> Result<(), KVAppError> {
@@ -3897,6 +4051,29 @@ async fn update_mask_policy(
     Ok(())
 }
 
+/// Fetch and update the table id list of a row access policy with `f`,
+/// and fill in the txn preconditions and operations.
+///
+/// Returns false if the policy does not exist.
+async fn update_row_access_policy_table_ids(
+    kv_api: &(impl kvapi::KVApi<Error = MetaError> + ?Sized),
+    txn_req: &mut TxnRequest,
+    tenant: &Tenant,
+    policy: &str,
+    f: impl FnOnce(&mut BTreeSet<u64>),
+) -> Result<bool, KVAppError> {
+    let key = RowAccessPolicyTableIdListIdent::new(tenant.clone(), policy);
+    let Some(mut seq_list) = kv_api.get_pb(&key).await? else {
+        return Ok(false);
+    };
+
+    f(&mut seq_list.data.id_list);
+
+    txn_replace_exact(txn_req, &key, seq_list.seq, &seq_list.data)?;
+
+    Ok(true)
+}
+
 #[tonic::async_trait]
 pub(crate) trait UndropTableStrategy {
     fn table_name_ident(&self) -> &TableNameIdent;
@@ -37,6 +37,11 @@ use databend_common_meta_app::data_mask::DataMaskNameIdent;
 use databend_common_meta_app::data_mask::DatamaskMeta;
 use databend_common_meta_app::data_mask::MaskPolicyTableIdListIdent;
 use databend_common_meta_app::data_mask::MaskpolicyTableIdList;
+use databend_common_meta_app::row_access_policy::CreateRowAccessPolicyReq;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyMeta;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyNameIdent;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyTableIdList;
+use databend_common_meta_app::row_access_policy::RowAccessPolicyTableIdListIdent;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdent;
 use databend_common_meta_app::schema::database_name_ident::DatabaseNameIdentRaw;
 use databend_common_meta_app::schema::dictionary_name_ident::DictionaryNameIdent;
@@ -104,6 +109,8 @@ use databend_common_meta_app::schema::RenameTableReq;
 use databend_common_meta_app::schema::SequenceIdent;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyAction;
 use databend_common_meta_app::schema::SetTableColumnMaskPolicyReq;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyAction;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
 use databend_common_meta_app::schema::TableCopiedFileInfo;
 use databend_common_meta_app::schema::TableCopiedFileNameIdent;
 use databend_common_meta_app::schema::TableId;
@@ -114,6 +121,7 @@ use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TableNameIdent;
+use databend_common_meta_app::schema::TableRowAccessPolicy;
 use databend_common_meta_app::schema::TableStatistics;
 use databend_common_meta_app::schema::TruncateTableReq;
 use databend_common_meta_app::schema::UndropDatabaseReq;
@@ -147,6 +155,7 @@ use crate::serialize_struct;
 use crate::testing::get_kv_data;
 use crate::testing::get_kv_u64_data;
 use crate::DatamaskApi;
+use crate::RowAccessPolicyApi;
 use crate::SchemaApi;
 use crate::SequenceApi;
 use crate::DEFAULT_MGET_SIZE;
@@ -271,7 +280,12 @@ impl SchemaApiTestSuite {
     pub async fn test_single_node<B, MT>(b: B) -> anyhow::Result<()>
     where
         B: kvapi::ApiBuilder<MT>,
-        MT: kvapi::AsKVApi<Error = MetaError> + SchemaApi + DatamaskApi + SequenceApi + 'static,
+        MT: kvapi::AsKVApi<Error = MetaError>
+            + SchemaApi
+            + DatamaskApi
+            + RowAccessPolicyApi
+            + SequenceApi
+            + 'static,
     {
         let suite = SchemaApiTestSuite {};
 
@@ -305,6 +319,9 @@ impl SchemaApiTestSuite {
         suite.table_rename(&b.build().await).await?;
         suite.table_update_meta(&b.build().await).await?;
         suite.table_update_mask_policy(&b.build().await).await?;
+        suite
+            .table_update_row_access_policy(&b.build().await)
+            .await?;
         suite.table_upsert_option(&b.build().await).await?;
         suite.table_list(&b.build().await).await?;
         suite.table_list_many(&b.build().await).await?;
@@ -463,6 +480,7 @@ impl SchemaApiTestSuite {
             name_ident: db_table_name_ident.clone(),
             table_meta: table_meta(created_on),
             as_dropped: false,
+            clone_pin: None,
         };
 
         {
@@ -1472,6 +1490,7 @@ impl SchemaApiTestSuite {
 
             table_meta: table_meta(created_on),
             as_dropped: false,
+            clone_pin: None,
         };
         let resp = mt.create_table(req.clone()).await?;
         let table_id = resp.table_id;
@@ -1545,6 +1564,7 @@ impl SchemaApiTestSuite {
                 name_ident: name_ident.clone(),
                 table_meta: table_meta(created_on),
                 as_dropped: false,
+                clone_pin: None,
             };
             let res = mt.create_table(req.clone()).await?;
             table_id = res.table_id;
@@ -1631,6 +1651,7 @@ impl SchemaApiTestSuite {
 
                 table_meta: table_meta(created_on),
                 as_dropped: false,
+                clone_pin: None,
             };
             // test create table
             {
@@ -1687,6 +1708,7 @@ impl SchemaApiTestSuite {
             },
             table_meta: table_meta(created_on),
             as_dropped: false,
+            clone_pin: None,
         };
         let tb_ident_2 = {
             {
@@ -1781,6 +1803,7 @@ impl SchemaApiTestSuite {
                 },
                 table_meta: table_meta(created_on),
                 as_dropped: false,
+                clone_pin: None,
             };
 
             let old_db = mt.get_database(Self::req_get_db(&tenant, db_name)).await?;
@@ -1909,6 +1932,7 @@ impl SchemaApiTestSuite {
                 },
                 table_meta: table_meta(old_created_on),
                 as_dropped: false,
+                clone_pin: None,
             };
 
             let res = mt.create_table(req.clone()).await?;
@@ -1938,6 +1962,7 @@ impl SchemaApiTestSuite {
                 },
                 table_meta: table_meta(created_on),
                 as_dropped: false,
+                clone_pin: None,
             };
 
             let res = mt.create_table(req.clone()).await?;
@@ -1980,6 +2005,7 @@ impl SchemaApiTestSuite {
                 },
                 table_meta: tbl_meta,
                 as_dropped: true,
+                clone_pin: None,
             };
             let old_db = mt.get_database(Self::req_get_db(&tenant, db_name)).await?;
             let create_table_as_dropped_resp = mt.create_table(create_table_req.clone()).await?;
@@ -2307,6 +2333,7 @@ impl SchemaApiTestSuite {
             },
             table_meta: table_meta(created_on),
             as_dropped: false,
+            clone_pin: None,
         };
 
         info!("--- create table for rename");
@@ -2534,6 +2561,7 @@ impl SchemaApiTestSuite {
                 },
                 table_meta: table_meta(created_on),
                 as_dropped: false,
+                clone_pin: None,
             };
 
             let _tb_ident_2 = {
@@ -2836,6 +2864,7 @@ impl SchemaApiTestSuite {
                 },
                 table_meta: table_meta(created_on),
                 as_dropped: false,
+                clone_pin: None,
             };
             let _res = mt.create_table(req.clone()).await?;
 
@@ -2848,6 +2877,7 @@ impl SchemaApiTestSuite {
                 },
                 table_meta: table_meta(created_on),
                 as_dropped: false,
+                clone_pin: None,
             };
             let _res = mt.create_table(req.clone()).await?;
         }
@@ -3132,6 +3162,256 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[fastrace::trace]
+    async fn table_update_row_access_policy<
+        MT: SchemaApi + RowAccessPolicyApi + kvapi::AsKVApi<Error = MetaError>,
+    >(
+        &self,
+        mt: &MT,
+    ) -> anyhow::Result<()> {
+        let tenant_name = "tenant1";
+        let tenant = Tenant::new_or_err(tenant_name, func_name!())?;
+
+        let db_name = "db1";
+        let tbl_name = "tb1";
+        let policy_name_1 = "policy1";
+        let policy_name_2 = "policy2";
+
+        let policy_meta = |comment: &str| RowAccessPolicyMeta {
+            args: vec![("r".to_string(), "STRING".to_string())],
+            body: "r = 'eu'".to_string(),
+            comment: Some(comment.to_string()),
+            create_on: Utc::now(),
+            update_on: None,
+        };
+
+        let db_id;
+        info!("--- prepare db and table");
+        {
+            let plan = CreateDatabaseReq {
+                create_option: CreateOption::Create,
+                name_ident: DatabaseNameIdent::new(&tenant, db_name),
+                meta: DatabaseMeta::default(),
+            };
+            let res = mt.create_database(plan).await?;
+            db_id = *res.db_id;
+
+            let req = CreateTableReq {
+                create_option: CreateOption::Create,
+                name_ident: TableNameIdent::new(tenant.clone(), db_name, tbl_name),
+                table_meta: TableMeta {
+                    schema: Arc::new(TableSchema::new(vec![TableField::new(
+                        "region",
+                        TableDataType::String,
+                    )])),
+                    engine: "JSON".to_string(),
+                    ..TableMeta::default()
+                },
+                as_dropped: false,
+                clone_pin: None,
+            };
+            mt.create_table(req).await?;
+        }
+
+        info!("--- attach an unknown policy");
+        {
+            let res = mt
+                .get_table(GetTableReq::new(&tenant, db_name, tbl_name))
+                .await?;
+            let req = SetTableRowAccessPolicyReq {
+                tenant: tenant.clone(),
+                table_id: res.ident.table_id,
+                seq: MatchSeq::Exact(res.ident.seq),
+                action: SetTableRowAccessPolicyAction::Set(policy_name_1.to_string(), vec![
+                    "region".to_string(),
+                ]),
+            };
+            let got = mt.set_table_row_access_policy(req).await;
+            let err = ErrorCode::from(got.unwrap_err());
+            assert_eq!(ErrorCode::UNKNOWN_ROW_ACCESS_POLICY, err.code());
+        }
+
+        info!("--- create policies");
+        for name in [policy_name_1, policy_name_2] {
+            let req = CreateRowAccessPolicyReq {
+                create_option: CreateOption::Create,
+                name: RowAccessPolicyNameIdent::new(tenant.clone(), name),
+                row_access_policy_meta: policy_meta("before"),
+            };
+            mt.create_row_access_policy(req).await?;
+        }
+
+        let table_id;
+        info!("--- attach policy1 and check");
+        {
+            let res = mt
+                .get_table(GetTableReq::new(&tenant, db_name, tbl_name))
+                .await?;
+            table_id = res.ident.table_id;
+            let req = SetTableRowAccessPolicyReq {
+                tenant: tenant.clone(),
+                table_id,
+                seq: MatchSeq::Exact(res.ident.seq),
+                action: SetTableRowAccessPolicyAction::Set(policy_name_1.to_string(), vec![
+                    "region".to_string(),
+                ]),
+            };
+            mt.set_table_row_access_policy(req).await?;
+
+            let res = mt
+                .get_table(GetTableReq::new(&tenant, db_name, tbl_name))
+                .await?;
+            assert_eq!(
+                res.meta.row_access_policy,
+                Some(TableRowAccessPolicy {
+                    policy: policy_name_1.to_string(),
+                    columns: vec!["region".to_string()],
+                })
+            );
+
+            let id_list_key = RowAccessPolicyTableIdListIdent::new(tenant.clone(), policy_name_1);
+            let id_list: RowAccessPolicyTableIdList =
+                get_kv_data(mt.as_kv_api(), &id_list_key).await?;
+            assert_eq!(id_list.id_list, BTreeSet::from([table_id]));
+        }
+
+        info!("--- an attached policy can not be dropped");
+        {
+            let name_ident = RowAccessPolicyNameIdent::new(tenant.clone(), policy_name_1);
+            let got = mt.drop_row_access_policy(&name_ident).await;
+            let err = ErrorCode::from(got.unwrap_err());
+            assert_eq!(ErrorCode::ROW_ACCESS_POLICY_IN_USE, err.code());
+        }
+
+        info!("--- replace a policy keeps it attached");
+        {
+            let name_ident = RowAccessPolicyNameIdent::new(tenant.clone(), policy_name_1);
+            let req = CreateRowAccessPolicyReq {
+                create_option: CreateOption::CreateOrReplace,
+                name: name_ident.clone(),
+                row_access_policy_meta: policy_meta("after"),
+            };
+            mt.create_row_access_policy(req).await?;
+
+            let meta = mt.get_row_access_policy(&name_ident).await?.unwrap();
+            assert_eq!(meta.data.comment, Some("after".to_string()));
+
+            let id_list_key = RowAccessPolicyTableIdListIdent::new(tenant.clone(), policy_name_1);
+            let id_list: RowAccessPolicyTableIdList =
+                get_kv_data(mt.as_kv_api(), &id_list_key).await?;
+            assert_eq!(id_list.id_list, BTreeSet::from([table_id]));
+        }
+
+        info!("--- switch to policy2 and check");
+        {
+            let res = mt
+                .get_table(GetTableReq::new(&tenant, db_name, tbl_name))
+                .await?;
+            let req = SetTableRowAccessPolicyReq {
+                tenant: tenant.clone(),
+                table_id,
+                seq: MatchSeq::Exact(res.ident.seq),
+                action: SetTableRowAccessPolicyAction::Set(policy_name_2.to_string(), vec![
+                    "region".to_string(),
+                ]),
+            };
+            mt.set_table_row_access_policy(req).await?;
+
+            let id_list_key = RowAccessPolicyTableIdListIdent::new(tenant.clone(), policy_name_1);
+            let id_list: RowAccessPolicyTableIdList =
+                get_kv_data(mt.as_kv_api(), &id_list_key).await?;
+            assert!(id_list.id_list.is_empty());
+
+            let id_list_key = RowAccessPolicyTableIdListIdent::new(tenant.clone(), policy_name_2);
+            let id_list: RowAccessPolicyTableIdList =
+                get_kv_data(mt.as_kv_api(), &id_list_key).await?;
+            assert_eq!(id_list.id_list, BTreeSet::from([table_id]));
+        }
+
+        info!("--- detach policy2 and drop both policies");
+        {
+            let res = mt
+                .get_table(GetTableReq::new(&tenant, db_name, tbl_name))
+                .await?;
+            let req = SetTableRowAccessPolicyReq {
+                tenant: tenant.clone(),
+                table_id,
+                seq: MatchSeq::Exact(res.ident.seq),
+                action: SetTableRowAccessPolicyAction::Unset(policy_name_2.to_string()),
+            };
+            mt.set_table_row_access_policy(req).await?;
+
+            let res = mt
+                .get_table(GetTableReq::new(&tenant, db_name, tbl_name))
+                .await?;
+            assert_eq!(res.meta.row_access_policy, None);
+
+            for name in [policy_name_1, policy_name_2] {
+                let name_ident = RowAccessPolicyNameIdent::new(tenant.clone(), name);
+                let dropped = mt.drop_row_access_policy(&name_ident).await?;
+                assert!(dropped.is_some());
+
+                let id_list_key = RowAccessPolicyTableIdListIdent::new(tenant.clone(), name);
+                let id_list: Result<RowAccessPolicyTableIdList, KVAppError> =
+                    get_kv_data(mt.as_kv_api(), &id_list_key).await;
+                assert!(id_list.is_err());
+            }
+        }
+
+        info!("--- a policy attached to a dropped table is in use until the table is purged");
+        {
+            let name_ident = RowAccessPolicyNameIdent::new(tenant.clone(), policy_name_1);
+            let req = CreateRowAccessPolicyReq {
+                create_option: CreateOption::Create,
+                name: name_ident.clone(),
+                row_access_policy_meta: policy_meta("dropped table"),
+            };
+            mt.create_row_access_policy(req).await?;
+
+            let res = mt
+                .get_table(GetTableReq::new(&tenant, db_name, tbl_name))
+                .await?;
+            let req = SetTableRowAccessPolicyReq {
+                tenant: tenant.clone(),
+                table_id,
+                seq: MatchSeq::Exact(res.ident.seq),
+                action: SetTableRowAccessPolicyAction::Set(policy_name_1.to_string(), vec![
+                    "region".to_string(),
+                ]),
+            };
+            mt.set_table_row_access_policy(req).await?;
+
+            mt.drop_table_by_id(DropTableByIdReq {
+                if_exists: false,
+                tenant: tenant.clone(),
+                db_id,
+                table_name: tbl_name.to_string(),
+                tb_id: table_id,
+                engine: "JSON".to_string(),
+                session_id: "".to_string(),
+            })
+            .await?;
+
+            let got = mt.drop_row_access_policy(&name_ident).await;
+            let err = ErrorCode::from(got.unwrap_err());
+            assert_eq!(ErrorCode::ROW_ACCESS_POLICY_IN_USE, err.code());
+
+            let resp = mt
+                .get_drop_table_infos(ListDroppedTableReq::new(&tenant))
+                .await?;
+            mt.gc_drop_tables(GcDroppedTableReq {
+                tenant: tenant.clone(),
+                drop_ids: resp.drop_ids.clone(),
+            })
+            .await?;
+
+            let dropped = mt.drop_row_access_policy(&name_ident).await?;
+            assert!(dropped.is_some());
+        }
+
+        Ok(())
+    }
+
     #[fastrace::trace]
     async fn table_upsert_option<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
         let tenant_name = "tenant1";
@@ -3187,6 +3467,7 @@ impl SchemaApiTestSuite {
                 },
                 table_meta: table_meta(created_on),
                 as_dropped: false,
+                clone_pin: None,
             };
 
             let _tb_ident_2 = {
@@ -3521,6 +3802,7 @@ impl SchemaApiTestSuite {
             name_ident,
             table_meta: create_table_meta.clone(),
             as_dropped: false,
+            clone_pin: None,
         };
 
         let res = mt.create_table(req).await?;
@@ -3757,6 +4039,7 @@ impl SchemaApiTestSuite {
             name_ident: tbl_name_ident.clone(),
             table_meta: create_table_meta.clone(),
             as_dropped: false,
+            clone_pin: None,
         };
 
         let res = mt.create_table(req).await?;
@@ -3993,6 +4276,7 @@ impl SchemaApiTestSuite {
                 name_ident: tbl_name_ident.clone(),
                 table_meta: create_table_meta.clone(),
                 as_dropped: false,
+                clone_pin: None,
             };
 
             let old_db = mt.get_database(Self::req_get_db(&tenant, db_name)).await?;
@@ -4077,6 +4361,7 @@ impl SchemaApiTestSuite {
                 name_ident: TableNameIdent::new(&tenant, "db1", "tb1"),
                 table_meta: table_meta(created_on),
                 as_dropped: false,
+                clone_pin: None,
             };
             let resp = mt.create_table(req.clone()).await?;
             let db1_tb1_id = resp.table_id;
@@ -4129,6 +4414,7 @@ impl SchemaApiTestSuite {
                     name_ident: table_name.clone(),
                     table_meta: table_meta(created_on),
                     as_dropped: false,
+                    clone_pin: None,
                 };
                 let resp = mt.create_table(req.clone()).await?;
                 drop_ids_boundary.push(DroppedId::new_table(
@@ -4162,6 +4448,7 @@ impl SchemaApiTestSuite {
                     name_ident: TableNameIdent::new(&tenant, "db2", "tb2"),
                     table_meta: table_meta.clone(),
                     as_dropped: false,
+                    clone_pin: None,
                 };
                 let resp = mt.create_table(req.clone()).await?;
                 mt.drop_table_by_id(DropTableByIdReq {
@@ -4195,6 +4482,7 @@ impl SchemaApiTestSuite {
                     name_ident: TableNameIdent::new(&tenant, "db2", "tb3"),
                     table_meta: table_meta(created_on),
                     as_dropped: false,
+                    clone_pin: None,
                 };
                 let resp = mt.create_table(req.clone()).await?;
                 db2_tb3_id = resp.table_id;
@@ -4234,6 +4522,7 @@ impl SchemaApiTestSuite {
                     name_ident: TableNameIdent::new(&tenant, "db3", "tb1"),
                     table_meta: table_meta(created_on),
                     as_dropped: false,
+                    clone_pin: None,
                 };
                 let resp = mt.create_table(req.clone()).await?;
                 drop_ids_boundary.push(DroppedId::new_table(*db3_id, resp.table_id, "tb1"));
@@ -4258,6 +4547,7 @@ impl SchemaApiTestSuite {
                     name_ident: TableNameIdent::new(&tenant, "db3", "tb2"),
                     table_meta: table_meta.clone(),
                     as_dropped: false,
+                    clone_pin: None,
                 };
                 let resp = mt.create_table(req.clone()).await?;
                 drop_ids_no_boundary.push(DroppedId::new_table(*db3_id, resp.table_id, "tb2"));
@@ -4285,6 +4575,7 @@ impl SchemaApiTestSuite {
                     name_ident: TableNameIdent::new(&tenant, "db3", "tb3"),
                     table_meta: table_meta(created_on),
                     as_dropped: false,
+                    clone_pin: None,
                 };
                 let _resp = mt.create_table(req.clone()).await?;
             }
@@ -4425,6 +4716,7 @@ impl SchemaApiTestSuite {
 
                     table_meta: table_meta(created_on),
                     as_dropped: false,
+                    clone_pin: None,
                 };
                 let resp = mt.create_table(req.clone()).await?;
 
@@ -4650,6 +4942,7 @@ impl SchemaApiTestSuite {
                 name_ident: tbl_name_ident.clone(),
                 table_meta: create_table_meta.clone(),
                 as_dropped: false,
+                clone_pin: None,
             };
 
             let old_db = mt.get_database(Self::req_get_db(&tenant, db_name)).await?;
@@ -4752,6 +5045,7 @@ impl SchemaApiTestSuite {
                     name_ident: tbl_name_ident.clone(),
                     table_meta: create_table_meta.clone(),
                     as_dropped: false,
+                    clone_pin: None,
                 })
                 .await?;
             let cur_db = mt.get_database(Self::req_get_db(&tenant, db_name)).await?;
@@ -4832,6 +5126,7 @@ impl SchemaApiTestSuite {
                 name_ident: new_tbl_name_ident.clone(),
                 table_meta: create_table_meta.clone(),
                 as_dropped: false,
+                clone_pin: None,
             };
 
             let old_db = mt.get_database(Self::req_get_db(&tenant, db_name)).await?;
@@ -4983,6 +5278,7 @@ impl SchemaApiTestSuite {
             },
             table_meta: drop_table_meta(created_on),
             as_dropped: true,
+            clone_pin: None,
         };
 
         let create_table_as_dropped_resp = mt.create_table(create_table_req.clone()).await?;
@@ -5067,6 +5363,7 @@ impl SchemaApiTestSuite {
                 },
                 table_meta: table_meta(created_on),
                 as_dropped: false,
+                clone_pin: None,
             };
 
             let _ = mt.create_table(create_table_req.clone()).await?;
@@ -5099,6 +5396,7 @@ impl SchemaApiTestSuite {
                 },
                 table_meta: table_meta(created_on),
                 as_dropped: true,
+                clone_pin: None,
             };
 
             let resp = mt.create_table(create_table_req.clone()).await;
@@ -5116,6 +5414,7 @@ impl SchemaApiTestSuite {
                 },
                 table_meta: drop_table_meta(created_on),
                 as_dropped: true,
+                clone_pin: None,
             };
 
             let create_table_as_dropped_resp = mt.create_table(create_table_req.clone()).await?;
@@ -5158,6 +5457,7 @@ impl SchemaApiTestSuite {
                 },
                 table_meta: drop_table_meta(created_on),
                 as_dropped: true,
+                clone_pin: None,
             };
 
             let create_table_as_dropped_resp = mt.create_table(create_table_req.clone()).await?;
@@ -5263,6 +5563,7 @@ impl SchemaApiTestSuite {
             },
             table_meta: drop_table_meta(created_on),
             as_dropped: true,
+            clone_pin: None,
         };
 
         let concurrent_count: usize = 5;
@@ -5376,6 +5677,7 @@ impl SchemaApiTestSuite {
                 },
                 table_meta: table_meta(created_on),
                 as_dropped: false,
+                clone_pin: None,
             };
 
             let _tb_ident_2 = {
@@ -5656,6 +5958,7 @@ impl SchemaApiTestSuite {
                 name_ident: tbl_name_ident.clone(),
                 table_meta: table_meta(created_on),
                 as_dropped: false,
+                clone_pin: None,
             };
             let resp = mt.create_table(req.clone()).await?;
             table_id = resp.table_id;
@@ -5848,6 +6151,7 @@ impl SchemaApiTestSuite {
                     ..Default::default()
                 },
                 as_dropped: false,
+                clone_pin: None,
             };
 
             let tb_ids = {
@@ -5911,6 +6215,7 @@ impl SchemaApiTestSuite {
                     },
                     table_meta: table_meta.clone(),
                     as_dropped: false,
+                    clone_pin: None,
                 };
                 let resp = util.mt.create_table(req).await?;
 
@@ -5978,6 +6283,7 @@ impl SchemaApiTestSuite {
                 },
                 table_meta: table_meta(created_on),
                 as_dropped: false,
+                clone_pin: None,
             };
             let resp = mt.create_table(req.clone()).await?;
             table_id = resp.table_id;
@@ -6965,6 +7271,7 @@ impl SchemaApiTestSuite {
                         ..Default::default()
                     },
                     as_dropped: false,
+                    clone_pin: None,
                 };
                 let old_db = node_a
                     .get_database(Self::req_get_db(&tenant, db_name))
@@ -7038,6 +7345,7 @@ impl SchemaApiTestSuite {
                     ..Default::default()
                 },
                 as_dropped: false,
+                clone_pin: None,
             };
 
             let old_db = node_a
@@ -7126,6 +7434,7 @@ impl SchemaApiTestSuite {
                 name_ident: tbl_name_ident.clone(),
                 table_meta: table_meta(created_on),
                 as_dropped: false,
+                clone_pin: None,
             };
             let resp = mt.create_table(req.clone()).await?;
             table_id = resp.table_id;
@@ -7629,6 +7938,7 @@ where MT: SchemaApi + kvapi::AsKVApi<Error = MetaError>
             },
             table_meta: table_meta.clone(),
             as_dropped: false,
+            clone_pin: None,
         };
         let resp = self.mt.create_table(req.clone()).await?;
         let table_id = resp.table_id;
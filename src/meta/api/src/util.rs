@@ -335,6 +335,25 @@ pub fn txn_op_del(key: &impl kvapi::Key) -> TxnOp {
     TxnOp::delete(key.to_string_key())
 }
 
+/// Build a txn operation that atomically adds `delta` to the integer stored in a record.
+pub fn txn_op_fetch_add(key: &impl kvapi::Key, delta: i64) -> TxnOp {
+    TxnOp::fetch_add(key.to_string_key(), delta)
+}
+
+/// Build a txn operation that gets values of multiple records.
+pub fn txn_op_get_many<K: kvapi::Key>(keys: &[K]) -> TxnOp {
+    TxnOp::get_many(keys.iter().map(|k| k.to_string_key()))
+}
+
+/// Build a TxnCondition that compares the number of keys with `prefix`.
+pub fn txn_cond_keys_with_prefix(
+    prefix: impl ToString,
+    op: ConditionResult,
+    count: u64,
+) -> TxnCondition {
+    TxnCondition::match_keys_with_prefix(prefix, op, count)
+}
+
 /// Return OK if a db_id or db_meta exists by checking the seq.
 ///
 /// Otherwise returns UnknownDatabase error
@@ -21,6 +21,7 @@ use crate::background::job_ident;
 use crate::data_mask::data_mask_name_ident;
 use crate::principal::procedure_name_ident;
 use crate::principal::ProcedureIdentity;
+use crate::row_access_policy::row_access_policy_name_ident;
 use crate::schema::catalog_name_ident;
 use crate::schema::dictionary_name_ident;
 use crate::schema::index_name_ident;
@@ -426,6 +427,24 @@ impl UnknownDatamask {
     }
 }
 
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("RowAccessPolicyInUse: `{name}` is attached to {table_count} tables while `{context}`")]
+pub struct RowAccessPolicyInUse {
+    name: String,
+    table_count: usize,
+    context: String,
+}
+
+impl RowAccessPolicyInUse {
+    pub fn new(name: impl Into<String>, table_count: usize, context: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            table_count,
+            context: context.into(),
+        }
+    }
+}
+
 #[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
 #[error("UnknownDatabaseId: `{db_id}` while `{context}`")]
 pub struct UnknownDatabaseId {
@@ -1047,6 +1066,15 @@ pub enum AppError {
     #[error(transparent)]
     UnknownDataMask(#[from] UnknownError<data_mask_name_ident::Resource>),
 
+    #[error(transparent)]
+    RowAccessPolicyAlreadyExists(#[from] ExistError<row_access_policy_name_ident::Resource>),
+
+    #[error(transparent)]
+    UnknownRowAccessPolicy(#[from] UnknownError<row_access_policy_name_ident::Resource>),
+
+    #[error(transparent)]
+    RowAccessPolicyInUse(#[from] RowAccessPolicyInUse),
+
     #[error(transparent)]
     BackgroundJobAlreadyExists(#[from] ExistError<job_ident::BackgroundJobName>),
 
@@ -1432,6 +1460,15 @@ impl AppErrorMessage for UnknownDatamask {
     }
 }
 
+impl AppErrorMessage for RowAccessPolicyInUse {
+    fn message(&self) -> String {
+        format!(
+            "Row access policy '{}' is still attached to {} table(s), detach it first",
+            self.name, self.table_count
+        )
+    }
+}
+
 impl AppErrorMessage for UnmatchColumnDataType {
     fn message(&self) -> String {
         format!(
@@ -1571,6 +1608,14 @@ impl From<AppError> for ErrorCode {
             AppError::DatamaskAlreadyExists(err) => ErrorCode::DatamaskAlreadyExists(err.message()),
             AppError::UnknownDataMask(err) => ErrorCode::UnknownDatamask(err.message()),
 
+            AppError::RowAccessPolicyAlreadyExists(err) => {
+                ErrorCode::RowAccessPolicyAlreadyExists(err.message())
+            }
+            AppError::UnknownRowAccessPolicy(err) => {
+                ErrorCode::UnknownRowAccessPolicy(err.message())
+            }
+            AppError::RowAccessPolicyInUse(err) => ErrorCode::RowAccessPolicyInUse(err.message()),
+
             AppError::BackgroundJobAlreadyExists(err) => {
                 ErrorCode::BackgroundJobAlreadyExists(err.message())
             }
@@ -27,10 +27,15 @@ pub(crate) const ID_GEN_SHARE: &str = "share_id";
 pub(crate) const ID_GEN_SHARE_ENDPOINT: &str = "share_endpoint_id";
 
 pub(crate) const ID_GEN_DATA_MASK: &str = "data_mask";
+pub(crate) const ID_GEN_ROW_ACCESS_POLICY: &str = "row_access_policy";
 pub(crate) const ID_GEN_BACKGROUND_JOB: &str = "background_job";
 
 pub(crate) const ID_GEN_PROCEDURE: &str = "procedure_id";
 
+pub(crate) const ID_GEN_TASK: &str = "task_id";
+
+pub(crate) const ID_GEN_NOTIFICATION: &str = "notification_id";
+
 /// Key for resource id generator
 ///
 /// This is a special key for an application to generate unique id with kvapi::KVApi.
@@ -95,6 +100,12 @@ impl IdGenerator {
         }
     }
 
+    pub fn row_access_policy_id() -> Self {
+        Self {
+            resource: ID_GEN_ROW_ACCESS_POLICY.to_string(),
+        }
+    }
+
     pub fn table_lock_id() -> Self {
         Self {
             resource: ID_GEN_TABLE_LOCK.to_string(),
@@ -120,6 +131,20 @@ impl IdGenerator {
             resource: ID_GEN_PROCEDURE.to_string(),
         }
     }
+
+    /// Create a key for generating task id with kvapi::KVApi
+    pub fn task_id() -> Self {
+        Self {
+            resource: ID_GEN_TASK.to_string(),
+        }
+    }
+
+    /// Create a key for generating notification id with kvapi::KVApi
+    pub fn notification_id() -> Self {
+        Self {
+            resource: ID_GEN_NOTIFICATION.to_string(),
+        }
+    }
 }
 
 impl kvapi::KeyCodec for IdGenerator {
@@ -225,6 +250,16 @@ mod t {
             assert_eq!(g1, g2);
         }
 
+        // Row access policy id generator
+        {
+            let g1 = IdGenerator::row_access_policy_id();
+            let k = g1.to_string_key();
+            assert_eq!("__fd_id_gen/row_access_policy", k);
+
+            let g2 = IdGenerator::from_str_key(&k)?;
+            assert_eq!(g1, g2);
+        }
+
         {
             let g1 = IdGenerator::table_lock_id();
             let k = g1.to_string_key();
@@ -244,6 +279,26 @@ mod t {
             assert_eq!(g, t2);
         }
 
+        // Task id generator
+        {
+            let g = IdGenerator::task_id();
+            let k = g.to_string_key();
+            assert_eq!("__fd_id_gen/task_id", k);
+
+            let t2 = IdGenerator::from_str_key(&k)?;
+            assert_eq!(g, t2);
+        }
+
+        // Notification id generator
+        {
+            let g = IdGenerator::notification_id();
+            let k = g.to_string_key();
+            assert_eq!("__fd_id_gen/notification_id", k);
+
+            let t2 = IdGenerator::from_str_key(&k)?;
+            assert_eq!(g, t2);
+        }
+
         Ok(())
     }
 
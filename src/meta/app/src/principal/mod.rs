@@ -41,6 +41,9 @@ pub mod client_session;
 pub mod client_session_ident;
 pub mod connection_ident;
 pub mod network_policy_ident;
+pub mod notification;
+pub mod notification_history_ident;
+pub mod notification_ident;
 pub mod password_policy_ident;
 pub mod procedure;
 pub mod procedure_id_ident;
@@ -48,6 +51,9 @@ pub mod procedure_id_to_name;
 pub mod procedure_identity;
 pub mod procedure_name_ident;
 pub mod stage_file_ident;
+pub mod task;
+pub mod task_ident;
+pub mod task_run_ident;
 pub mod tenant_ownership_object_ident;
 pub mod tenant_user_ident;
 pub mod user_defined_file_format_ident;
@@ -60,6 +66,12 @@ pub use connection::*;
 pub use file_format::*;
 pub use network_policy::NetworkPolicy;
 pub use network_policy_ident::NetworkPolicyIdent;
+pub use notification::Notification;
+pub use notification::NotificationHistory;
+pub use notification::NotificationStatus;
+pub use notification::WebhookOptions;
+pub use notification_history_ident::NotificationHistoryIdent;
+pub use notification_ident::NotificationIdent;
 pub use ownership_info::OwnershipInfo;
 pub use ownership_object::OwnershipObject;
 pub use password_policy::PasswordPolicy;
@@ -86,6 +98,15 @@ pub use role_info::RoleInfo;
 pub use role_info::RoleInfoSerdeError;
 pub use stage_file_ident::StageFileIdent;
 pub use stage_file_path::StageFilePath;
+pub use task::ScheduleOptions;
+pub use task::ScheduleType;
+pub use task::State;
+pub use task::Status;
+pub use task::Task;
+pub use task::TaskRun;
+pub use task::WarehouseOptions;
+pub use task_ident::TaskIdent;
+pub use task_run_ident::TaskRunIdent;
 pub use tenant_ownership_object_ident::TenantOwnershipObjectIdent;
 pub use tenant_user_ident::TenantUserIdent;
 pub use udf_ident::UdfIdent;
@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Notification integrations and their delivery history used by the built-in notification sender.
+//!
+//! These are only used when the query node is not connected to cloud control,
+//! in which case the integrations live in the meta-service.
+
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+use chrono::DateTime;
+use chrono::Utc;
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct WebhookOptions {
+    pub url: String,
+    /// HTTP method used to deliver a message, `POST` if not set.
+    pub method: Option<String>,
+    /// Sent as the `Authorization` header as is.
+    pub authorization_header: Option<String>,
+    /// If set, the message body is signed with HMAC-SHA256 using this secret.
+    pub signing_secret: Option<String>,
+}
+
+/// A notification integration stored in the meta-service.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Notification {
+    pub notification_id: u64,
+    pub name: String,
+    pub enabled: bool,
+    pub webhook: WebhookOptions,
+    pub comments: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NotificationStatus {
+    Success = 0,
+    Failed = 1,
+}
+
+impl Display for NotificationStatus {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            NotificationStatus::Success => write!(f, "SUCCESS"),
+            NotificationStatus::Failed => write!(f, "FAILED"),
+        }
+    }
+}
+
+/// A record of delivering one message through a notification integration,
+/// stored in the meta-service with a TTL.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NotificationHistory {
+    pub history_id: u64,
+    pub created_at: DateTime<Utc>,
+    pub processed_at: Option<DateTime<Utc>>,
+    /// What sent the message, e.g. `task` or `send_notification`.
+    pub message_source: String,
+    pub name: String,
+    pub message: String,
+    pub status: NotificationStatus,
+    /// Number of delivery attempts, including the last one.
+    pub attempts: u32,
+    pub error_message: String,
+}
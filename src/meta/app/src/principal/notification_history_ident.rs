@@ -0,0 +1,92 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_kvapi::kvapi::KeyBuilder;
+use databend_common_meta_kvapi::kvapi::KeyCodec;
+use databend_common_meta_kvapi::kvapi::KeyError;
+use databend_common_meta_kvapi::kvapi::KeyParser;
+
+use crate::tenant_key::ident::TIdent;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct NotificationHistoryId {
+    pub name: String,
+    pub history_id: u64,
+}
+
+impl KeyCodec for NotificationHistoryId {
+    fn encode_key(&self, b: KeyBuilder) -> KeyBuilder {
+        b.push_str(&self.name).push_u64(self.history_id)
+    }
+
+    fn decode_key(parser: &mut KeyParser) -> Result<Self, KeyError>
+    where Self: Sized {
+        let name = parser.next_str()?;
+        let history_id = parser.next_u64()?;
+        Ok(Self { name, history_id })
+    }
+}
+
+/// Defines the meta-service key for a notification delivery record:
+/// `__fd_notification_history/<tenant>/<notification_name>/<history_id>`.
+pub type NotificationHistoryIdent = TIdent<Resource, NotificationHistoryId>;
+
+pub use kvapi_impl::Resource;
+
+mod kvapi_impl {
+
+    use databend_common_meta_kvapi::kvapi;
+
+    use crate::principal::notification::NotificationHistory;
+    use crate::principal::notification_history_ident::NotificationHistoryIdent;
+    use crate::tenant_key::resource::TenantResource;
+
+    pub struct Resource;
+    impl TenantResource for Resource {
+        const PREFIX: &'static str = "__fd_notification_history";
+        const TYPE: &'static str = "NotificationHistoryIdent";
+        const HAS_TENANT: bool = true;
+        type ValueType = NotificationHistory;
+    }
+
+    impl kvapi::Value for NotificationHistory {
+        type KeyType = NotificationHistoryIdent;
+        fn dependency_keys(&self, _key: &Self::KeyType) -> impl IntoIterator<Item = String> {
+            []
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use databend_common_meta_kvapi::kvapi::Key;
+
+    use crate::principal::notification_history_ident::NotificationHistoryId;
+    use crate::principal::notification_history_ident::NotificationHistoryIdent;
+    use crate::tenant::Tenant;
+
+    #[test]
+    fn test_notification_history_ident() {
+        let tenant = Tenant::new_literal("test");
+        let ident = NotificationHistoryIdent::new_generic(tenant, NotificationHistoryId {
+            name: "n1".to_string(),
+            history_id: 3,
+        });
+
+        let key = ident.to_string_key();
+        assert_eq!(key, "__fd_notification_history/test/n1/3");
+
+        assert_eq!(ident, NotificationHistoryIdent::from_str_key(&key).unwrap());
+    }
+}
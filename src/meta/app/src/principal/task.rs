@@ -0,0 +1,282 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Task definitions and run records used by the built-in task scheduler.
+//!
+//! These are only used when the query node is not connected to cloud control,
+//! in which case the task definitions live in the meta-service.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use cron::Schedule;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ScheduleType {
+    IntervalType = 0,
+    CronType = 1,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScheduleOptions {
+    pub interval: Option<i32>,
+    pub cron: Option<String>,
+    pub time_zone: Option<String>,
+    pub schedule_type: ScheduleType,
+    pub milliseconds_interval: Option<u64>,
+}
+
+impl ScheduleOptions {
+    pub fn new_interval(secs: u64, ms: u64) -> Self {
+        Self {
+            interval: Some(secs as i32),
+            cron: None,
+            time_zone: None,
+            schedule_type: ScheduleType::IntervalType,
+            // none if ms is 0, else some ms
+            milliseconds_interval: if ms == 0 { None } else { Some(ms) },
+        }
+    }
+
+    pub fn new_cron(expr: String, time_zone: Option<String>) -> Self {
+        Self {
+            interval: None,
+            cron: Some(expr),
+            time_zone,
+            schedule_type: ScheduleType::CronType,
+            milliseconds_interval: None,
+        }
+    }
+
+    /// Validate the schedule, returning a human readable reason if it can never fire.
+    pub fn validate(&self) -> Result<(), String> {
+        match self.schedule_type {
+            ScheduleType::IntervalType => {
+                if self.interval_duration().is_zero() {
+                    return Err("schedule interval must be greater than 0".to_string());
+                }
+            }
+            ScheduleType::CronType => {
+                self.cron_schedule()?;
+                self.tz()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the next time this schedule fires strictly after `after`.
+    pub fn next_after(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self.schedule_type {
+            ScheduleType::IntervalType => {
+                let interval = chrono::Duration::from_std(self.interval_duration()).ok()?;
+                Some(after + interval)
+            }
+            ScheduleType::CronType => {
+                let schedule = self.cron_schedule().ok()?;
+                let tz = self.tz().ok()?;
+                let upcoming = schedule.after(&after.with_timezone(&tz)).next()?;
+                Some(upcoming.with_timezone(&Utc))
+            }
+        }
+    }
+
+    fn interval_duration(&self) -> Duration {
+        match self.milliseconds_interval {
+            Some(ms) => Duration::from_millis(ms),
+            None => Duration::from_secs(self.interval.unwrap_or_default().max(0) as u64),
+        }
+    }
+
+    /// `CREATE TASK` accepts the classic 5-field cron syntax, while the `cron` crate
+    /// requires a leading seconds field.
+    fn cron_schedule(&self) -> Result<Schedule, String> {
+        let expr = self.cron.as_deref().unwrap_or_default().trim();
+        let expr = if expr.split_whitespace().count() == 5 {
+            format!("0 {}", expr)
+        } else {
+            expr.to_string()
+        };
+        Schedule::from_str(&expr).map_err(|e| format!("invalid cron expression '{}': {}", expr, e))
+    }
+
+    fn tz(&self) -> Result<chrono_tz::Tz, String> {
+        match &self.time_zone {
+            None => Ok(chrono_tz::UTC),
+            Some(tz) => tz
+                .parse::<chrono_tz::Tz>()
+                .map_err(|e| format!("invalid time zone '{}': {}", tz, e)),
+        }
+    }
+}
+
+impl Display for ScheduleOptions {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.schedule_type {
+            ScheduleType::IntervalType => match self.milliseconds_interval {
+                Some(ms) => write!(
+                    f,
+                    "INTERVAL {} SECOND {} MILLISECOND",
+                    self.interval.unwrap_or_default(),
+                    ms
+                ),
+                None => write!(f, "INTERVAL {} SECOND", self.interval.unwrap_or_default()),
+            },
+            ScheduleType::CronType => {
+                write!(f, "CRON {}", self.cron.as_deref().unwrap_or_default())?;
+                if let Some(tz) = &self.time_zone {
+                    write!(f, " TIMEZONE {}", tz)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct WarehouseOptions {
+    pub warehouse: Option<String>,
+    pub using_warehouse_size: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Status {
+    Suspended = 0,
+    Started = 1,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Status::Suspended => write!(f, "Suspended"),
+            Status::Started => write!(f, "Started"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum State {
+    Scheduled = 0,
+    Executing = 1,
+    Succeeded = 2,
+    Failed = 3,
+    Cancelled = 4,
+}
+
+impl Display for State {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            State::Scheduled => write!(f, "SCHEDULED"),
+            State::Executing => write!(f, "EXECUTING"),
+            State::Succeeded => write!(f, "SUCCEEDED"),
+            State::Failed => write!(f, "FAILED"),
+            State::Cancelled => write!(f, "CANCELLED"),
+        }
+    }
+}
+
+/// A task definition stored in the meta-service.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Task {
+    pub task_id: u64,
+    pub task_name: String,
+    pub query_text: String,
+    pub when_condition: Option<String>,
+    /// Names of the predecessor tasks. A task with predecessors has no schedule of its own,
+    /// it runs after all of its predecessors succeeded in the same graph run.
+    pub after: Vec<String>,
+    pub comment: Option<String>,
+    pub owner: String,
+    pub schedule_options: Option<ScheduleOptions>,
+    pub warehouse_options: Option<WarehouseOptions>,
+    pub next_scheduled_at: Option<DateTime<Utc>>,
+    pub suspend_task_after_num_failures: Option<u64>,
+    /// Number of consecutive failed runs, reset on success.
+    pub consecutive_failures: u64,
+    pub error_integration: Option<String>,
+    pub status: Status,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub last_suspended_at: Option<DateTime<Utc>>,
+    pub session_params: BTreeMap<String, String>,
+}
+
+impl Task {
+    /// A root task is triggered by its own schedule, other tasks are triggered by predecessors.
+    pub fn is_root(&self) -> bool {
+        self.after.is_empty()
+    }
+}
+
+/// A record of a single execution of a task, stored in the meta-service with a TTL.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TaskRun {
+    pub task: Task,
+    pub run_id: u64,
+    pub attempt_number: i32,
+    pub state: State,
+    pub scheduled_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub error_code: i64,
+    pub error_message: Option<String>,
+    /// The id of the root task that started this graph run.
+    pub root_task_id: u64,
+    pub query_id: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use chrono::Utc;
+
+    use crate::principal::task::ScheduleOptions;
+
+    #[test]
+    fn test_schedule_next_after() {
+        let now = Utc.with_ymd_and_hms(2024, 1, 1, 10, 0, 30).unwrap();
+
+        let interval = ScheduleOptions::new_interval(60, 0);
+        assert!(interval.validate().is_ok());
+        assert_eq!(
+            Some(Utc.with_ymd_and_hms(2024, 1, 1, 10, 1, 30).unwrap()),
+            interval.next_after(now)
+        );
+
+        let cron = ScheduleOptions::new_cron("0 12 * * *".to_string(), None);
+        assert!(cron.validate().is_ok());
+        assert_eq!(
+            Some(Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap()),
+            cron.next_after(now)
+        );
+
+        let cron =
+            ScheduleOptions::new_cron("0 12 * * *".to_string(), Some("Asia/Shanghai".to_string()));
+        assert_eq!(
+            Some(Utc.with_ymd_and_hms(2024, 1, 2, 4, 0, 0).unwrap()),
+            cron.next_after(now)
+        );
+
+        assert!(ScheduleOptions::new_interval(0, 0).validate().is_err());
+        assert!(
+            ScheduleOptions::new_cron("bad cron".to_string(), None)
+                .validate()
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_kvapi::kvapi::KeyBuilder;
+use databend_common_meta_kvapi::kvapi::KeyCodec;
+use databend_common_meta_kvapi::kvapi::KeyError;
+use databend_common_meta_kvapi::kvapi::KeyParser;
+
+use crate::tenant_key::ident::TIdent;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct TaskRunId {
+    pub task_name: String,
+    pub run_id: u64,
+}
+
+impl KeyCodec for TaskRunId {
+    fn encode_key(&self, b: KeyBuilder) -> KeyBuilder {
+        b.push_str(&self.task_name).push_u64(self.run_id)
+    }
+
+    fn decode_key(parser: &mut KeyParser) -> Result<Self, KeyError>
+    where Self: Sized {
+        let task_name = parser.next_str()?;
+        let run_id = parser.next_u64()?;
+        Ok(Self { task_name, run_id })
+    }
+}
+
+/// Defines the meta-service key for a task run record:
+/// `__fd_task_runs/<tenant>/<task_name>/<run_id>`.
+pub type TaskRunIdent = TIdent<Resource, TaskRunId>;
+
+pub use kvapi_impl::Resource;
+
+mod kvapi_impl {
+
+    use databend_common_meta_kvapi::kvapi;
+
+    use crate::principal::task::TaskRun;
+    use crate::principal::task_run_ident::TaskRunIdent;
+    use crate::tenant_key::resource::TenantResource;
+
+    pub struct Resource;
+    impl TenantResource for Resource {
+        const PREFIX: &'static str = "__fd_task_runs";
+        const TYPE: &'static str = "TaskRunIdent";
+        const HAS_TENANT: bool = true;
+        type ValueType = TaskRun;
+    }
+
+    impl kvapi::Value for TaskRun {
+        type KeyType = TaskRunIdent;
+        fn dependency_keys(&self, _key: &Self::KeyType) -> impl IntoIterator<Item = String> {
+            []
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use databend_common_meta_kvapi::kvapi::Key;
+
+    use crate::principal::task_run_ident::TaskRunId;
+    use crate::principal::task_run_ident::TaskRunIdent;
+    use crate::tenant::Tenant;
+
+    #[test]
+    fn test_task_run_ident() {
+        let tenant = Tenant::new_literal("test");
+        let ident = TaskRunIdent::new_generic(tenant, TaskRunId {
+            task_name: "t1".to_string(),
+            run_id: 3,
+        });
+
+        let key = ident.to_string_key();
+        assert_eq!(key, "__fd_task_runs/test/t1/3");
+
+        assert_eq!(ident, TaskRunIdent::from_str_key(&key).unwrap());
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub mod row_access_policy_id_ident;
+pub mod row_access_policy_name_ident;
+pub mod row_access_policy_table_id_list_ident;
+
+use std::collections::BTreeSet;
+
+use chrono::DateTime;
+use chrono::Utc;
+pub use row_access_policy_id_ident::RowAccessPolicyId;
+pub use row_access_policy_id_ident::RowAccessPolicyIdIdent;
+pub use row_access_policy_name_ident::RowAccessPolicyNameIdent;
+pub use row_access_policy_table_id_list_ident::RowAccessPolicyTableIdListIdent;
+
+use crate::schema::CreateOption;
+
+/// A row access policy is a boolean expression over its arguments.
+///
+/// When it is attached to a table, the arguments are bound to table columns and
+/// only the rows for which the expression evaluates to true are visible.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RowAccessPolicyMeta {
+    // Vec<(arg_name, arg_type)>
+    pub args: Vec<(String, String)>,
+    pub body: String,
+    pub comment: Option<String>,
+    pub create_on: DateTime<Utc>,
+    pub update_on: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateRowAccessPolicyReq {
+    pub create_option: CreateOption,
+    pub name: RowAccessPolicyNameIdent,
+    pub row_access_policy_meta: RowAccessPolicyMeta,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CreateRowAccessPolicyReply {
+    pub id: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropRowAccessPolicyReq {
+    pub if_exists: bool,
+    pub name: RowAccessPolicyNameIdent,
+}
+
+/// Ids of the tables a row access policy is attached to.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, Default, PartialEq)]
+pub struct RowAccessPolicyTableIdList {
+    pub id_list: BTreeSet<u64>,
+}
@@ -258,6 +258,8 @@ pub struct TableMeta {
     pub engine: String,
     pub engine_options: BTreeMap<String, String>,
     pub storage_params: Option<StorageParams>,
+    // The storage that blocks are moved to by the tiering policy of a fuse table, if any.
+    pub cold_storage_params: Option<StorageParams>,
     pub part_prefix: String,
     pub options: BTreeMap<String, String>,
     // The default cluster key.
@@ -277,7 +279,53 @@ pub struct TableMeta {
     // shared by share_id
     pub shared_by: BTreeSet<u64>,
     pub column_mask_policy: Option<BTreeMap<String, String>>,
+    // The row access policy attached to this table, if any.
+    pub row_access_policy: Option<TableRowAccessPolicy>,
     pub indexes: BTreeMap<String, TableIndex>,
+    // Branches and tags of a fuse table, keyed by name.
+    // The snapshots they point to are protected from gc.
+    pub refs: BTreeMap<String, SnapshotRef>,
+    // Tables cloned from this table, mapping the id of each clone to the location
+    // of the snapshot it was cloned from. As long as a clone exists, the data
+    // referenced by that snapshot is protected from gc.
+    pub clones: BTreeMap<u64, String>,
+}
+
+/// A row access policy attached to a table, with the table columns that are
+/// passed to the policy as arguments, in order.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TableRowAccessPolicy {
+    pub policy: String,
+    pub columns: Vec<String>,
+}
+
+/// The kind of a named reference to a snapshot of a fuse table.
+///
+/// Both kinds pin their snapshot from gc and can be read with `AT (BRANCH => ..)`
+/// or `AT (TAG => ..)`. A tag is fixed, while a branch can be written with
+/// `INSERT INTO`, `UPDATE` and `DELETE FROM` a table `AT (BRANCH => ..)`, which
+/// moves the branch to the new snapshot and leaves the table itself as is.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SnapshotRefType {
+    Branch = 0,
+    Tag = 1,
+}
+
+impl Display for SnapshotRefType {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            SnapshotRefType::Branch => write!(f, "BRANCH"),
+            SnapshotRefType::Tag => write!(f, "TAG"),
+        }
+    }
+}
+
+/// A named reference to a snapshot of a fuse table.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct SnapshotRef {
+    pub typ: SnapshotRefType,
+    pub snapshot_location: String,
+    pub created_on: DateTime<Utc>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
@@ -412,6 +460,7 @@ impl Default for TableMeta {
             engine: "FUSE".to_string(),
             engine_options: BTreeMap::new(),
             storage_params: None,
+            cold_storage_params: None,
             part_prefix: "".to_string(),
             options: BTreeMap::new(),
             default_cluster_key: None,
@@ -425,7 +474,10 @@ impl Default for TableMeta {
             statistics: Default::default(),
             shared_by: BTreeSet::new(),
             column_mask_policy: None,
+            row_access_policy: None,
             indexes: BTreeMap::new(),
+            refs: BTreeMap::new(),
+            clones: BTreeMap::new(),
         }
     }
 }
@@ -532,6 +584,17 @@ pub struct CreateTableReq {
     ///
     /// currently used in atomic CTAS.
     pub as_dropped: bool,
+
+    /// Set it if the table is a clone of another table: the snapshot it shares
+    /// is pinned in the source table in the same transaction that creates the table.
+    pub clone_pin: Option<TableClonePin>,
+}
+
+/// A snapshot of the source table shared by a clone, see [`TableMeta::clones`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableClonePin {
+    pub source_table_id: u64,
+    pub snapshot_location: String,
 }
 
 impl CreateTableReq {
@@ -835,6 +898,25 @@ pub struct SetTableColumnMaskPolicyReq {
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct SetTableColumnMaskPolicyReply {}
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SetTableRowAccessPolicyAction {
+    // new policy name and the columns it is applied on
+    Set(String, Vec<String>),
+    // prev policy name
+    Unset(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetTableRowAccessPolicyReq {
+    pub tenant: Tenant,
+    pub table_id: u64,
+    pub seq: MatchSeq,
+    pub action: SetTableRowAccessPolicyAction,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SetTableRowAccessPolicyReply {}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct UpsertTableOptionReply {}
 
@@ -43,6 +43,8 @@ pub enum StorageParams {
     Webhdfs(StorageWebhdfsConfig),
     Cos(StorageCosConfig),
     Huggingface(StorageHuggingfaceConfig),
+    Sftp(StorageSftpConfig),
+    Webdav(StorageWebdavConfig),
 
     /// None means this storage type is none.
     ///
@@ -77,6 +79,8 @@ impl StorageParams {
             StorageParams::Webhdfs(v) => v.endpoint_url.starts_with("https://"),
             StorageParams::Cos(v) => v.endpoint_url.starts_with("https://"),
             StorageParams::Huggingface(_) => true,
+            StorageParams::Sftp(_) => true,
+            StorageParams::Webdav(v) => v.endpoint_url.starts_with("https://"),
             StorageParams::None => false,
         }
     }
@@ -99,6 +103,8 @@ impl StorageParams {
             StorageParams::Webhdfs(v) => v.root = f(&v.root),
             StorageParams::Cos(v) => v.root = f(&v.root),
             StorageParams::Huggingface(v) => v.root = f(&v.root),
+            StorageParams::Sftp(v) => v.root = f(&v.root),
+            StorageParams::Webdav(v) => v.root = f(&v.root),
             StorageParams::None => {}
         };
 
@@ -216,6 +222,16 @@ impl Display for StorageParams {
                     v.repo_type, v.repo_id, v.root
                 )
             }
+            StorageParams::Sftp(v) => {
+                write!(
+                    f,
+                    "sftp | root={},endpoint={},user={}",
+                    v.root, v.endpoint, v.user
+                )
+            }
+            StorageParams::Webdav(v) => {
+                write!(f, "webdav | root={},endpoint={}", v.root, v.endpoint_url)
+            }
             StorageParams::None => {
                 write!(f, "none",)
             }
@@ -595,6 +611,66 @@ impl Debug for StorageHuggingfaceConfig {
     }
 }
 
+/// Config for SFTP storage backend.
+///
+/// Authenticates with the content of a private `key` or with a `password`.
+#[derive(Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageSftpConfig {
+    /// The ssh destination, looks like `ssh://host:22`.
+    pub endpoint: String,
+    pub root: String,
+    pub user: String,
+    /// The content of the private key.
+    pub key: String,
+    pub password: String,
+    /// How to check the host key of the server.
+    ///
+    /// available value: `strict`, `accept`, `add`
+    /// default value: `strict`
+    pub known_hosts_strategy: String,
+}
+
+impl Debug for StorageSftpConfig {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let mut ds = f.debug_struct("StorageSftpConfig");
+
+        ds.field("endpoint", &self.endpoint);
+        ds.field("root", &self.root);
+        ds.field("user", &self.user);
+        ds.field("key", &mask_string(&self.key, 3));
+        ds.field("password", &mask_string(&self.password, 3));
+        ds.field("known_hosts_strategy", &self.known_hosts_strategy);
+
+        ds.finish()
+    }
+}
+
+/// Config for WebDAV storage backend.
+///
+/// Authenticates with `username` and `password`, or with a bearer `token`.
+#[derive(Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageWebdavConfig {
+    pub endpoint_url: String,
+    pub root: String,
+    pub username: String,
+    pub password: String,
+    pub token: String,
+}
+
+impl Debug for StorageWebdavConfig {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let mut ds = f.debug_struct("StorageWebdavConfig");
+
+        ds.field("endpoint_url", &self.endpoint_url);
+        ds.field("root", &self.root);
+        ds.field("username", &self.username);
+        ds.field("password", &mask_string(&self.password, 3));
+        ds.field("token", &mask_string(&self.token, 3));
+
+        ds.finish()
+    }
+}
+
 /// Mask a string by "******", but keep `unmask_len` of suffix.
 ///
 /// Copied from `common-base` so that we don't need to depend on it.
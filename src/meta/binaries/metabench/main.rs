@@ -208,6 +208,7 @@ async fn benchmark_table(client: &Arc<ClientHandle>, prefix: u64, client_num: u6
             name_ident: tb_name_ident(),
             table_meta: Default::default(),
             as_dropped: false,
+            clone_pin: None,
         })
         .await;
 
@@ -251,6 +252,7 @@ async fn benchmark_table(client: &Arc<ClientHandle>, prefix: u64, client_num: u6
             name_ident: tb_name_ident(),
             table_meta: Default::default(),
             as_dropped: false,
+            clone_pin: None,
         })
         .await;
 
@@ -0,0 +1,155 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Restore meta data from continuous backups and verify the backups.
+
+use chrono::DateTime;
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_raft_store::config::RaftConfig;
+use databend_common_meta_raft_store::ondisk::DataVersion;
+use databend_meta::backup::BackupStorage;
+use databend_meta::backup::RestorePlan;
+use databend_meta::backup::VerifyReport;
+
+use crate::import;
+use crate::import_v004;
+use crate::reading;
+use crate::upgrade;
+use crate::BackupStorageArgs;
+use crate::ImportArgs;
+use crate::RestoreArgs;
+use crate::VerifyBackupArgs;
+
+/// Rebuild the meta data in `raft_dir` at a point in time, from the backups in the storage.
+pub async fn restore_data(args: &RestoreArgs) -> anyhow::Result<()> {
+    let to_time_ms = args.to_time.as_deref().map(parse_time).transpose()?;
+
+    let import_args = ImportArgs::from(args.clone());
+    let raft_dir = import_args.raft_dir.clone().unwrap_or_default();
+
+    eprintln!();
+    eprintln!("Restore:");
+    eprintln!("    Into Meta Dir: '{}'", raft_dir);
+    eprintln!("    To Time: {}", display_time(to_time_ms));
+
+    let storage = open_storage(&args.storage)?;
+    let plan = RestorePlan::build(&storage, to_time_ms).await?;
+
+    eprintln!(
+        "    From Snapshot: {}, completed at: {}",
+        plan.snapshot.data_path,
+        display_time(Some(plan.snapshot.time_ms))
+    );
+    eprintln!("    With Log Segments: {}", plan.segments.len());
+    eprintln!("    Last Log Id: {:?}", plan.last_log_id);
+
+    let version = reading::read_version(&plan.lines[0])?;
+    if version != DataVersion::V004 {
+        return Err(anyhow::anyhow!(
+            "unsupported backup data version: {:?}, expect: {:?}",
+            version,
+            DataVersion::V004
+        ));
+    }
+
+    let nodes = import::build_nodes(import_args.initial_cluster.clone(), import_args.id)?;
+
+    import::clear(&import_args)?;
+
+    let raft_config: RaftConfig = import_args.clone().into();
+    let lines = plan.lines.into_iter().map(Ok);
+    let max_log_id = import_v004::import_v004(raft_config.clone(), lines).await?;
+
+    upgrade::upgrade(&raft_config).await?;
+
+    if import_args.initial_cluster.is_empty() {
+        return Ok(());
+    }
+
+    import::init_new_cluster(&import_args, nodes, max_log_id).await?;
+    Ok(())
+}
+
+/// Check the integrity of the backups in the storage and print a report.
+pub async fn verify_backup(args: &VerifyBackupArgs) -> anyhow::Result<()> {
+    let storage = open_storage(&args.storage)?;
+    let report = VerifyReport::build(&storage).await?;
+
+    println!("Snapshots: {}", report.snapshots.len());
+    for m in report.snapshots.iter() {
+        println!(
+            "  - {} completed at: {}, last log: {:?}, records: {}",
+            m.data_path,
+            display_time(Some(m.time_ms)),
+            m.last_index(),
+            m.records
+        );
+    }
+
+    println!("LogSegments: {}", report.segments.len());
+    if let (Some(first), Some(last)) = (report.segments.first(), report.segments.last()) {
+        println!("  - logs in range [{}, {}]", first.first, last.last);
+    }
+
+    if !report.gaps.is_empty() {
+        println!("Gaps:");
+        for (start, end) in report.gaps.iter() {
+            println!("  - logs in range [{}, {}) are missing", start, end);
+        }
+    }
+
+    if !report.is_ok() {
+        println!("Errors:");
+        for e in report.errors.iter() {
+            println!("  - {}", e);
+        }
+        return Err(anyhow::anyhow!(
+            "found {} damaged backup files",
+            report.errors.len()
+        ));
+    }
+
+    println!("OK");
+    Ok(())
+}
+
+fn open_storage(args: &BackupStorageArgs) -> anyhow::Result<BackupStorage> {
+    let params = args.storage_params()?;
+    eprintln!("    Backup Storage: {}", params);
+
+    let storage = BackupStorage::new(&params)?;
+    Ok(storage)
+}
+
+/// Parse time in RFC 3339 format, e.g., `2024-01-01T00:00:00Z`, or in milliseconds since epoch.
+fn parse_time(s: &str) -> anyhow::Result<u64> {
+    if let Ok(ms) = s.parse::<u64>() {
+        return Ok(ms);
+    }
+
+    let t = DateTime::parse_from_rfc3339(s)
+        .map_err(|e| anyhow::anyhow!("invalid time: {}: {}", s, e))?;
+    Ok(t.timestamp_millis() as u64)
+}
+
+fn display_time(time_ms: Option<u64>) -> String {
+    match time_ms {
+        None => "latest".to_string(),
+        Some(ms) => match Utc.timestamp_millis_opt(ms as i64).single() {
+            Some(t) => t.to_rfc3339(),
+            None => ms.to_string(),
+        },
+    }
+}
@@ -246,7 +246,10 @@ async fn import_from_stdin_or_file(args: &ImportArgs) -> anyhow::Result<Option<L
 ///
 /// Raw config is: `<NodeId>=<raft-api-host>:<raft-api-port>[,...]`, e.g. `1=localhost:29103` or `1=localhost:29103,0.0.0.0:19191`
 /// The second part is obsolete grpc api address and will be just ignored. Databend-meta loads Grpc address from config file when starting up.
-fn build_nodes(initial_cluster: Vec<String>, id: u64) -> anyhow::Result<BTreeMap<NodeId, Node>> {
+pub(crate) fn build_nodes(
+    initial_cluster: Vec<String>,
+    id: u64,
+) -> anyhow::Result<BTreeMap<NodeId, Node>> {
     eprintln!("Initialize Cluster: id={}, {:?}", id, initial_cluster);
 
     let mut nodes = BTreeMap::new();
@@ -296,7 +299,7 @@ fn build_nodes(initial_cluster: Vec<String>, id: u64) -> anyhow::Result<BTreeMap
 }
 
 // initial_cluster format: node_id=endpoint,grpc_api_addr;
-async fn init_new_cluster(
+pub(crate) async fn init_new_cluster(
     args: &ImportArgs,
     nodes: BTreeMap<NodeId, Node>,
     max_log_id: Option<LogId>,
@@ -383,7 +386,7 @@ async fn init_new_cluster(
 }
 
 /// Clear all sled data and on-disk snapshot.
-fn clear(args: &ImportArgs) -> anyhow::Result<()> {
+pub(crate) fn clear(args: &ImportArgs) -> anyhow::Result<()> {
     eprintln!();
     eprintln!("Clear All Sled Trees Before Import:");
     let db = init_get_sled_db(args.raft_dir.clone().unwrap(), 64 * 1024 * 1024 * 1024);
@@ -17,6 +17,7 @@
 mod export_from_grpc;
 
 pub mod admin;
+pub mod backup;
 pub mod export_from_disk;
 pub mod import;
 pub mod import_v004;
@@ -31,6 +32,9 @@ use clap::CommandFactory;
 use clap::Parser;
 use clap::Subcommand;
 use databend_common_base::base::tokio;
+use databend_common_meta_app::storage::StorageFsConfig;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_meta_app::storage::StorageS3Config;
 use databend_common_meta_client::MetaGrpcClient;
 use databend_common_meta_kvapi::kvapi::KVApi;
 use databend_common_meta_raft_store::config::RaftConfig;
@@ -197,6 +201,109 @@ impl From<ImportArgs> for RaftConfig {
     }
 }
 
+/// The location of the backups uploaded by databend-meta.
+#[derive(Debug, Clone, Deserialize, Args)]
+pub struct BackupStorageArgs {
+    /// The type of the storage: `fs` or `s3`.
+    #[clap(long, default_value = "fs")]
+    pub storage_type: String,
+
+    /// The root dir of the backups, if the storage type is `fs`.
+    #[clap(long, default_value = "")]
+    pub fs_root: String,
+
+    #[clap(long, default_value = "https://s3.amazonaws.com")]
+    pub s3_endpoint_url: String,
+
+    #[clap(long, default_value = "")]
+    pub s3_region: String,
+
+    #[clap(long, default_value = "")]
+    pub s3_bucket: String,
+
+    /// The path in the bucket of the backups, if the storage type is `s3`.
+    #[clap(long, default_value = "")]
+    pub s3_root: String,
+
+    #[clap(long, default_value = "")]
+    pub s3_access_key_id: String,
+
+    #[clap(long, default_value = "")]
+    pub s3_secret_access_key: String,
+}
+
+impl BackupStorageArgs {
+    pub fn storage_params(&self) -> anyhow::Result<StorageParams> {
+        let params = match self.storage_type.as_str() {
+            "fs" => StorageParams::Fs(StorageFsConfig {
+                root: self.fs_root.clone(),
+            }),
+            "s3" => StorageParams::S3(StorageS3Config {
+                endpoint_url: self.s3_endpoint_url.clone(),
+                region: self.s3_region.clone(),
+                bucket: self.s3_bucket.clone(),
+                root: self.s3_root.clone(),
+                access_key_id: self.s3_access_key_id.clone(),
+                secret_access_key: self.s3_secret_access_key.clone(),
+                ..Default::default()
+            }),
+            t => {
+                return Err(anyhow::anyhow!(
+                    "unsupported backup storage type: {}, expect one of: fs, s3",
+                    t
+                ));
+            }
+        };
+        Ok(params)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Args)]
+pub struct RestoreArgs {
+    /// The dir to store persisted meta state, including raft logs, state machine etc.
+    #[clap(long)]
+    #[serde(alias = "kvsrv_raft_dir")]
+    pub raft_dir: Option<String>,
+
+    /// Restore to the state at this time,
+    /// in RFC 3339 format, e.g., `2024-01-01T00:00:00Z`, or in milliseconds since epoch.
+    ///
+    /// If it is not specified, restore to the latest backed up state.
+    #[clap(long)]
+    pub to_time: Option<String>,
+
+    /// initial_cluster format: node_id=endpoint,grpc_api_addr
+    #[clap(long)]
+    pub initial_cluster: Vec<String>,
+
+    /// The node id of the restored node in the new cluster.
+    #[clap(long, default_value = "0")]
+    #[serde(alias = "kvsrv_id")]
+    pub id: u64,
+
+    #[clap(flatten)]
+    #[serde(flatten)]
+    pub storage: BackupStorageArgs,
+}
+
+impl From<RestoreArgs> for ImportArgs {
+    fn from(value: RestoreArgs) -> Self {
+        ImportArgs {
+            raft_dir: value.raft_dir,
+            db: "".to_string(),
+            initial_cluster: value.initial_cluster,
+            id: value.id,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Args)]
+pub struct VerifyBackupArgs {
+    #[clap(flatten)]
+    #[serde(flatten)]
+    pub storage: BackupStorageArgs,
+}
+
 #[derive(Debug, Clone, Deserialize, Args)]
 pub struct TransferLeaderArgs {
     #[clap(long)]
@@ -323,6 +430,16 @@ impl App {
         import::import_data(args).await?;
         Ok(())
     }
+
+    async fn restore(&self, args: &RestoreArgs) -> anyhow::Result<()> {
+        backup::restore_data(args).await?;
+        Ok(())
+    }
+
+    async fn verify_backup(&self, args: &VerifyBackupArgs) -> anyhow::Result<()> {
+        backup::verify_backup(args).await?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Subcommand)]
@@ -330,6 +447,8 @@ enum CtlCommand {
     Status(StatusArgs),
     Export(ExportArgs),
     Import(ImportArgs),
+    Restore(RestoreArgs),
+    VerifyBackup(VerifyBackupArgs),
     TransferLeader(TransferLeaderArgs),
     BenchClientNumConn(BenchArgs),
 }
@@ -377,6 +496,12 @@ async fn main() -> anyhow::Result<()> {
             CtlCommand::Import(args) => {
                 app.import(args).await?;
             }
+            CtlCommand::Restore(args) => {
+                app.restore(args).await?;
+            }
+            CtlCommand::VerifyBackup(args) => {
+                app.verify_backup(args).await?;
+            }
         },
         // for backward compatibility
         None => {
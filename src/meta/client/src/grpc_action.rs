@@ -44,6 +44,7 @@ use crate::message::GetClusterStatus;
 use crate::message::GetEndpoints;
 use crate::message::MakeEstablishedClient;
 use crate::message::Streamed;
+use crate::message::StreamedRead;
 
 /// Bind a request type to its corresponding response type.
 pub trait RequestFor: Clone + fmt::Debug {
@@ -191,6 +192,14 @@ impl RequestFor for Streamed<ListKVReq> {
     type Reply = BoxStream<StreamItem>;
 }
 
+impl RequestFor for StreamedRead<MGetKVReq> {
+    type Reply = BoxStream<StreamItem>;
+}
+
+impl RequestFor for StreamedRead<ListKVReq> {
+    type Reply = BoxStream<StreamItem>;
+}
+
 impl RequestFor for UpsertKVReq {
     type Reply = UpsertKVReply;
 }
@@ -58,10 +58,12 @@ use databend_common_meta_types::protobuf::WatchRequest;
 use databend_common_meta_types::protobuf::WatchResponse;
 use databend_common_meta_types::ConnectionError;
 use databend_common_meta_types::GrpcConfig;
+use databend_common_meta_types::GrpcHelper;
 use databend_common_meta_types::MetaClientError;
 use databend_common_meta_types::MetaError;
 use databend_common_meta_types::MetaHandshakeError;
 use databend_common_meta_types::MetaNetworkError;
+use databend_common_meta_types::ReadConsistency;
 use databend_common_meta_types::TxnReply;
 use databend_common_meta_types::TxnRequest;
 use databend_common_metrics::count::Count;
@@ -602,7 +604,7 @@ impl MetaGrpcClient {
         let resp = match req {
             message::Request::StreamMGet(r) => {
                 let strm = self
-                    .kv_read_v1(MetaGrpcReadReq::MGetKV(r.into_inner()))
+                    .kv_read_v1(MetaGrpcReadReq::MGetKV(r.req), r.consistency)
                     .with_timing_threshold(
                         threshold(),
                         info_spent("MetaGrpcClient::kv_read_v1(MGetKV)"),
@@ -612,7 +614,7 @@ impl MetaGrpcClient {
             }
             message::Request::StreamList(r) => {
                 let strm = self
-                    .kv_read_v1(MetaGrpcReadReq::ListKV(r.into_inner()))
+                    .kv_read_v1(MetaGrpcReadReq::ListKV(r.req), r.consistency)
                     .with_timing_threshold(
                         threshold(),
                         info_spent("MetaGrpcClient::kv_read_v1(ListKV)"),
@@ -1112,8 +1114,12 @@ impl MetaGrpcClient {
     pub(crate) async fn kv_read_v1(
         &self,
         grpc_req: MetaGrpcReadReq,
+        consistency: ReadConsistency,
     ) -> Result<BoxStream<pb::StreamItem>, MetaError> {
-        debug!("{}::kv_read_v1 request: {:?}", self, grpc_req);
+        debug!(
+            "{}::kv_read_v1 request: {:?}, consistency: {}",
+            self, grpc_req, consistency
+        );
 
         let mut failures = vec![];
 
@@ -1127,7 +1133,8 @@ impl MetaGrpcClient {
                 .await?;
 
             let raft_req: RaftRequest = grpc_req.clone().into();
-            let req = traced_req(raft_req.clone());
+            let mut req = traced_req(raft_req.clone());
+            GrpcHelper::add_request_read_consistency(&mut req, consistency);
 
             let result = established_client
                 .kv_read_v1(req)
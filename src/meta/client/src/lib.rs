@@ -35,6 +35,7 @@ pub use grpc_client::MetaChannelManager;
 pub use grpc_client::MetaGrpcClient;
 pub use message::ClientWorkerRequest;
 pub use message::Streamed;
+pub use message::StreamedRead;
 use semver::Version;
 
 pub static METACLI_COMMIT_SEMVER: LazyLock<Version> = LazyLock::new(|| {
@@ -105,12 +106,20 @@ pub static METACLI_COMMIT_SEMVER: LazyLock<Version> = LazyLock::new(|| {
 /// - 2024-03-04: since: 1.2.361
 ///   👥 client: `MetaSpec` use `ttl`, remove `expire_at`, require 1.2.258
 ///
+/// - 2024-11-12: since 1.2.656:
+///   🖥 server: add: TxnOp fetch_add, list_range, get_many and TxnCondition key count;
+///   👥 client: require 1.2.656, an older server silently ignores these txn ops.
+///
 /// Server feature set:
 /// ```yaml
 /// server_features:
 ///   txn_delete_match_seq: ["2023-05-07", "1.1.32", ]
 ///   pb_seqv_meta:         ["2023-10-11", "1.2.153", ]
 ///   kv_read_v1:           ["2023-10-17", "1.2.163", ]
+///   txn_fetch_add:        ["2024-11-12", "1.2.656", ]
+///   txn_list_range:       ["2024-11-12", "1.2.656", ]
+///   txn_get_many:         ["2024-11-12", "1.2.656", ]
+///   txn_key_count:        ["2024-11-12", "1.2.656", ]
 /// ```
 // ------------------------------
 // The binary in the https://github.com/datafuselabs/databend/releases/tag/v1.2.258-nightly
@@ -121,7 +130,9 @@ pub static METACLI_COMMIT_SEMVER: LazyLock<Version> = LazyLock::new(|| {
 // Version: v1.2.257-nightly-188426e3e6-simd(1.75.0-nightly-2023-12-17T22:09:06.675156000Z)
 // ```
 // Skip 1.2.258 use the next 1.2.259
-pub static MIN_METASRV_SEMVER: Version = Version::new(1, 2, 259);
+//
+// Since 1.2.656 the txn ops fetch_add, list_range, get_many and the key-count condition are required.
+pub static MIN_METASRV_SEMVER: Version = Version::new(1, 2, 656);
 
 pub fn to_digit_ver(v: &Version) -> u64 {
     v.major * 1_000_000 + v.minor * 1_000 + v.patch
@@ -29,6 +29,7 @@ use databend_common_meta_types::protobuf::WatchRequest;
 use databend_common_meta_types::protobuf::WatchResponse;
 use databend_common_meta_types::MetaClientError;
 use databend_common_meta_types::MetaError;
+use databend_common_meta_types::ReadConsistency;
 use databend_common_meta_types::TxnReply;
 use databend_common_meta_types::TxnRequest;
 use fastrace::Span;
@@ -69,16 +70,37 @@ impl<T> Streamed<T> {
     pub fn into_inner(self) -> T {
         self.0
     }
+
+    /// Specify the consistency this read requires, instead of the default linearizable read.
+    pub fn with_consistency(self, consistency: ReadConsistency) -> StreamedRead<T> {
+        StreamedRead {
+            req: self.0,
+            consistency,
+        }
+    }
+}
+
+/// Mark a read RPC to return a stream, with the consistency it requires.
+#[derive(Debug, Clone)]
+pub struct StreamedRead<T> {
+    pub req: T,
+    pub consistency: ReadConsistency,
+}
+
+impl<T> From<Streamed<T>> for StreamedRead<T> {
+    fn from(v: Streamed<T>) -> Self {
+        v.with_consistency(ReadConsistency::default())
+    }
 }
 
 /// Meta-client handle-to-worker request body
 #[derive(Debug, Clone, derive_more::From)]
 pub enum Request {
     /// Get multiple KV, returning a stream.
-    StreamMGet(Streamed<MGetKVReq>),
+    StreamMGet(StreamedRead<MGetKVReq>),
 
     /// List KVs by key prefix, returning a stream.
-    StreamList(Streamed<ListKVReq>),
+    StreamList(StreamedRead<ListKVReq>),
 
     /// Update or insert KV
     Upsert(UpsertKVReq),
@@ -105,6 +127,18 @@ pub enum Request {
     GetClientInfo(GetClientInfo),
 }
 
+impl From<Streamed<MGetKVReq>> for Request {
+    fn from(v: Streamed<MGetKVReq>) -> Self {
+        Request::StreamMGet(v.into())
+    }
+}
+
+impl From<Streamed<ListKVReq>> for Request {
+    fn from(v: Streamed<ListKVReq>) -> Self {
+        Request::StreamList(v.into())
+    }
+}
+
 impl Request {
     pub fn name(&self) -> &'static str {
         match self {
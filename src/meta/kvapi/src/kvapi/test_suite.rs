@@ -29,8 +29,11 @@ use databend_common_meta_types::TxnDeleteByPrefixRequest;
 use databend_common_meta_types::TxnDeleteByPrefixResponse;
 use databend_common_meta_types::TxnDeleteRequest;
 use databend_common_meta_types::TxnDeleteResponse;
+use databend_common_meta_types::TxnFetchAddResponse;
+use databend_common_meta_types::TxnGetManyResponse;
 use databend_common_meta_types::TxnGetRequest;
 use databend_common_meta_types::TxnGetResponse;
+use databend_common_meta_types::TxnListRangeResponse;
 use databend_common_meta_types::TxnOp;
 use databend_common_meta_types::TxnOpResponse;
 use databend_common_meta_types::TxnPutResponse;
@@ -73,6 +76,10 @@ impl kvapi::TestSuite {
             .await?;
         self.kv_delete_by_prefix_transaction(&builder.build().await)
             .await?;
+        self.kv_transaction_fetch_add(&builder.build().await)
+            .await?;
+        self.kv_transaction_list_range_get_many(&builder.build().await)
+            .await?;
 
         // Run cross node test on every 2 adjacent nodes
         let mut i = 0;
@@ -944,6 +951,163 @@ impl kvapi::TestSuite {
         Ok(())
     }
 
+    /// `FetchAdd` updates an integer atomically and leaves non-integer values unchanged.
+    pub async fn kv_transaction_fetch_add<KV: kvapi::KVApi>(&self, kv: &KV) -> anyhow::Result<()> {
+        info!("--- {}", func_name!());
+
+        let fetch_add_resp = |key: &str, success, before, after, seq| TxnOpResponse {
+            response: Some(txn_op_response::Response::FetchAdd(TxnFetchAddResponse {
+                key: key.to_string(),
+                success,
+                before,
+                after,
+                seq,
+            })),
+        };
+
+        info!("--- add to absent key and then to the updated value");
+        {
+            let txn = TxnRequest::unconditional(vec![
+                TxnOp::fetch_add("cnt", 3),
+                TxnOp::fetch_add("cnt", -1),
+            ]);
+
+            let resp = kv.transaction(txn).await?;
+
+            let expected = vec![
+                fetch_add_resp("cnt", true, 0, 3, 1),
+                fetch_add_resp("cnt", true, 3, 2, 2),
+            ];
+            self.check_transaction_responses(&resp, &expected, true);
+
+            let got = kv.get_kv("cnt").await?;
+            assert_eq!(Some(SeqV::new(2, b("2"))), got);
+        }
+
+        info!("--- non-integer value is not changed");
+        {
+            kv.upsert_kv(UpsertKVReq::update("not_int", b"abc")).await?;
+
+            let txn = TxnRequest::unconditional(vec![TxnOp::fetch_add("not_int", 1)]);
+            let resp = kv.transaction(txn).await?;
+
+            let expected = vec![fetch_add_resp("not_int", false, 0, 0, 3)];
+            self.check_transaction_responses(&resp, &expected, true);
+
+            let got = kv.get_kv("not_int").await?;
+            assert_eq!(Some(SeqV::new(3, b("abc"))), got);
+        }
+
+        info!("--- overflow is not applied");
+        {
+            let txn = TxnRequest::unconditional(vec![TxnOp::fetch_add("cnt", i64::MAX)]);
+            let resp = kv.transaction(txn).await?;
+
+            let expected = vec![fetch_add_resp("cnt", false, 2, 2, 2)];
+            self.check_transaction_responses(&resp, &expected, true);
+        }
+
+        info!("--- expiration of the existing key is kept");
+        {
+            let expire_at = SeqV::<()>::now_sec() + 1000;
+            kv.upsert_kv(
+                UpsertKVReq::update("cnt_expire", b"1").with(MetaSpec::new_expire(expire_at)),
+            )
+            .await?;
+
+            let txn = TxnRequest::unconditional(vec![TxnOp::fetch_add("cnt_expire", 1)]);
+            let resp = kv.transaction(txn).await?;
+
+            let expected = vec![fetch_add_resp("cnt_expire", true, 1, 2, 5)];
+            self.check_transaction_responses(&resp, &expected, true);
+
+            let got = kv.get_kv("cnt_expire").await?;
+            assert_eq!(
+                Some(SeqV::with_meta(
+                    5,
+                    Some(KVMeta::new_expire(expire_at)),
+                    b("2")
+                )),
+                got
+            );
+        }
+
+        Ok(())
+    }
+
+    /// `ListRange`, `GetMany` and condition on the number of keys with a prefix.
+    pub async fn kv_transaction_list_range_get_many<KV: kvapi::KVApi>(
+        &self,
+        kv: &KV,
+    ) -> anyhow::Result<()> {
+        info!("--- {}", func_name!());
+
+        for key in ["r/a", "r/b", "r/c", "s/a"] {
+            kv.upsert_kv(UpsertKVReq::update(key, b"v")).await?;
+        }
+
+        let item =
+            |key: &str, seq| TxnGetResponse::new(key, Some(pb::SeqV::from(SeqV::new(seq, b("v")))));
+
+        info!("--- condition on key count is satisfied");
+        {
+            let txn = TxnRequest {
+                condition: vec![TxnCondition::match_keys_with_prefix(
+                    "r/",
+                    ConditionResult::Eq,
+                    3,
+                )],
+                if_then: vec![
+                    TxnOp::list_range("r/", Some("r/c".to_string()), 0),
+                    TxnOp::list_range("r/", None, 2),
+                    TxnOp::get_many(["r/a", "x"]),
+                ],
+                else_then: vec![],
+            };
+
+            let resp = kv.transaction(txn).await?;
+
+            let expected = vec![
+                TxnOpResponse {
+                    response: Some(txn_op_response::Response::ListRange(TxnListRangeResponse {
+                        items: vec![item("r/a", 1), item("r/b", 2)],
+                        more: false,
+                    })),
+                },
+                TxnOpResponse {
+                    response: Some(txn_op_response::Response::ListRange(TxnListRangeResponse {
+                        items: vec![item("r/a", 1), item("r/b", 2)],
+                        more: true,
+                    })),
+                },
+                TxnOpResponse {
+                    response: Some(txn_op_response::Response::GetMany(TxnGetManyResponse {
+                        values: vec![item("r/a", 1), TxnGetResponse::new("x", None)],
+                    })),
+                },
+            ];
+            self.check_transaction_responses(&resp, &expected, true);
+        }
+
+        info!("--- condition on key count is not satisfied");
+        {
+            let txn = TxnRequest {
+                condition: vec![TxnCondition::match_keys_with_prefix(
+                    "r/",
+                    ConditionResult::Gt,
+                    3,
+                )],
+                if_then: vec![TxnOp::get_many(["r/a"])],
+                else_then: vec![],
+            };
+
+            let resp = kv.transaction(txn).await?;
+            self.check_transaction_responses(&resp, &[], false);
+        }
+
+        Ok(())
+    }
+
     /// If `TxnDeleteRequest.match_seq` is not set,
     /// the delete operation will always be executed.
     pub async fn kv_transaction_delete_match_seq_none<KV: kvapi::KVApi>(
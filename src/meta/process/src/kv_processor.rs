@@ -215,6 +215,10 @@ where F: Fn(&str, Vec<u8>) -> Result<Vec<u8>, anyhow::Error>
             }
             Request::Delete(_) => {}
             Request::DeleteByPrefix(_) => {}
+            // The value of a counter is a decimal integer, not a protobuf message.
+            Request::FetchAdd(_) => {}
+            Request::ListRange(_) => {}
+            Request::GetMany(_) => {}
         }
 
         Ok(TxnOp { request: Some(req) })
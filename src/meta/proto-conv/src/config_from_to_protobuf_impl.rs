@@ -66,6 +66,12 @@ impl FromToProto for mt::storage::StorageParams {
                     mt::storage::StorageHuggingfaceConfig::from_pb(s)?,
                 ))
             }
+            Some(pb::storage_config::Storage::Sftp(s)) => Ok(mt::storage::StorageParams::Sftp(
+                mt::storage::StorageSftpConfig::from_pb(s)?,
+            )),
+            Some(pb::storage_config::Storage::Webdav(s)) => Ok(mt::storage::StorageParams::Webdav(
+                mt::storage::StorageWebdavConfig::from_pb(s)?,
+            )),
             None => Err(Incompatible {
                 reason: "StageStorage.storage cannot be None".to_string(),
             }),
@@ -101,6 +107,12 @@ impl FromToProto for mt::storage::StorageParams {
             mt::storage::StorageParams::Huggingface(v) => Ok(pb::StorageConfig {
                 storage: Some(pb::storage_config::Storage::Huggingface(v.to_pb()?)),
             }),
+            mt::storage::StorageParams::Sftp(v) => Ok(pb::StorageConfig {
+                storage: Some(pb::storage_config::Storage::Sftp(v.to_pb()?)),
+            }),
+            mt::storage::StorageParams::Webdav(v) => Ok(pb::StorageConfig {
+                storage: Some(pb::storage_config::Storage::Webdav(v.to_pb()?)),
+            }),
             others => Err(Incompatible {
                 reason: format!("stage type: {} not supported", others),
             }),
@@ -400,3 +412,69 @@ impl FromToProto for mt::storage::StorageHuggingfaceConfig {
         })
     }
 }
+
+impl FromToProto for mt::storage::StorageSftpConfig {
+    type PB = pb::SftpStorageConfig;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.version
+    }
+
+    fn from_pb(p: pb::SftpStorageConfig) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.version, p.min_reader_ver)?;
+
+        Ok(mt::storage::StorageSftpConfig {
+            endpoint: p.endpoint,
+            root: p.root,
+            user: p.user,
+            key: p.key,
+            password: p.password,
+            known_hosts_strategy: p.known_hosts_strategy,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::SftpStorageConfig, Incompatible> {
+        Ok(pb::SftpStorageConfig {
+            version: VER,
+            min_reader_ver: MIN_READER_VER,
+            endpoint: self.endpoint.clone(),
+            root: self.root.clone(),
+            user: self.user.clone(),
+            key: self.key.clone(),
+            known_hosts_strategy: self.known_hosts_strategy.clone(),
+            password: self.password.clone(),
+        })
+    }
+}
+
+impl FromToProto for mt::storage::StorageWebdavConfig {
+    type PB = pb::WebdavStorageConfig;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.version
+    }
+
+    fn from_pb(p: pb::WebdavStorageConfig) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.version, p.min_reader_ver)?;
+
+        Ok(mt::storage::StorageWebdavConfig {
+            endpoint_url: p.endpoint_url,
+            root: p.root,
+            username: p.username,
+            password: p.password,
+            token: p.token,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::WebdavStorageConfig, Incompatible> {
+        Ok(pb::WebdavStorageConfig {
+            version: VER,
+            min_reader_ver: MIN_READER_VER,
+            endpoint_url: self.endpoint_url.clone(),
+            root: self.root.clone(),
+            username: self.username.clone(),
+            password: self.password.clone(),
+            token: self.token.clone(),
+        })
+    }
+}
@@ -0,0 +1,125 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This mod is the key point about compatibility.
+//! Everytime update anything in this file, update the `VER` and let the tests pass.
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_meta_app::principal as mt;
+use databend_common_protos::pb;
+
+use crate::reader_check_msg;
+use crate::FromToProto;
+use crate::Incompatible;
+use crate::MIN_READER_VER;
+use crate::VER;
+
+impl FromToProto for mt::Notification {
+    type PB = pb::Notification;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::Notification) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let webhook = p.webhook.ok_or_else(|| Incompatible {
+            reason: "Notification.webhook can not be None".to_string(),
+        })?;
+
+        Ok(Self {
+            notification_id: p.notification_id,
+            name: p.name,
+            enabled: p.enabled,
+            webhook: mt::WebhookOptions {
+                url: webhook.url,
+                method: webhook.method,
+                authorization_header: webhook.authorization_header,
+                signing_secret: webhook.signing_secret,
+            },
+            comments: p.comments,
+            created_at: DateTime::<Utc>::from_pb(p.created_at)?,
+            updated_at: DateTime::<Utc>::from_pb(p.updated_at)?,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::Notification, Incompatible> {
+        Ok(pb::Notification {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            notification_id: self.notification_id,
+            name: self.name.clone(),
+            enabled: self.enabled,
+            webhook: Some(pb::notification::WebhookOptions {
+                url: self.webhook.url.clone(),
+                method: self.webhook.method.clone(),
+                authorization_header: self.webhook.authorization_header.clone(),
+                signing_secret: self.webhook.signing_secret.clone(),
+            }),
+            comments: self.comments.clone(),
+            created_at: self.created_at.to_pb()?,
+            updated_at: self.updated_at.to_pb()?,
+        })
+    }
+}
+
+impl FromToProto for mt::NotificationHistory {
+    type PB = pb::NotificationHistory;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::NotificationHistory) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let status = match p.status {
+            0 => mt::NotificationStatus::Success,
+            1 => mt::NotificationStatus::Failed,
+            s => {
+                return Err(Incompatible {
+                    reason: format!("NotificationStatus can not be {}", s),
+                });
+            }
+        };
+
+        Ok(Self {
+            history_id: p.history_id,
+            created_at: DateTime::<Utc>::from_pb(p.created_at)?,
+            processed_at: p.processed_at.map(DateTime::<Utc>::from_pb).transpose()?,
+            message_source: p.message_source,
+            name: p.name,
+            message: p.message,
+            status,
+            attempts: p.attempts,
+            error_message: p.error_message,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::NotificationHistory, Incompatible> {
+        Ok(pb::NotificationHistory {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            history_id: self.history_id,
+            created_at: self.created_at.to_pb()?,
+            processed_at: self.processed_at.map(|t| t.to_pb()).transpose()?,
+            message_source: self.message_source.clone(),
+            name: self.name.clone(),
+            message: self.message.clone(),
+            status: self.status as i32,
+            attempts: self.attempts,
+            error_message: self.error_message.clone(),
+        })
+    }
+}
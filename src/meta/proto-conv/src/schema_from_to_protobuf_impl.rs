@@ -16,8 +16,12 @@
 //! Everytime update anything in this file, update the `VER` and let the tests pass.
 
 use databend_common_expression as ex;
+use databend_common_expression::types::AggregateStateSignature;
+use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::TableDataType;
+use databend_common_io::prelude::bincode_deserialize_from_slice;
+use databend_common_io::prelude::bincode_serialize_into_buf;
 use databend_common_protos::pb;
 use databend_common_protos::pb::data_type::Dt;
 use databend_common_protos::pb::data_type::Dt24;
@@ -259,6 +263,42 @@ impl FromToProto for ex::TableDataType {
                     Dt24::VariantT(_) => ex::TableDataType::Variant,
                     Dt24::GeometryT(_) => ex::TableDataType::Geometry,
                     Dt24::GeographyT(_) => ex::TableDataType::Geography,
+                    Dt24::IntervalT(_) => ex::TableDataType::Interval,
+                    Dt24::TimestampTzT(_) => ex::TableDataType::TimestampTz,
+                    Dt24::TimeT(_) => ex::TableDataType::Time,
+                    Dt24::UuidT(_) => ex::TableDataType::Uuid,
+                    Dt24::EnumT(e) => {
+                        reader_check_msg(e.ver, e.min_reader_ver)?;
+
+                        let items = ex::types::EnumItems::try_create(e.items).map_err(|e| {
+                            Incompatible {
+                                reason: format!("Invalid Enum: {}", e.message()),
+                            }
+                        })?;
+                        ex::TableDataType::Enum(items)
+                    }
+                    Dt24::AggregateStateT(a) => {
+                        reader_check_msg(a.ver, a.min_reader_ver)?;
+
+                        let mut params = Vec::with_capacity(a.params.len());
+                        for x in a.params {
+                            let param =
+                                bincode_deserialize_from_slice(&x).map_err(|e| Incompatible {
+                                    reason: format!("Invalid AggregateState param: {}", e),
+                                })?;
+                            params.push(param);
+                        }
+
+                        let mut arguments = Vec::with_capacity(a.arguments.len());
+                        for x in a.arguments {
+                            let t = ex::TableDataType::from_pb(x)?;
+                            arguments.push(DataType::from(&t));
+                        }
+
+                        ex::TableDataType::AggregateState(AggregateStateSignature::new(
+                            &a.name, params, arguments,
+                        ))
+                    }
                     Dt24::DecimalT(x) => {
                         ex::TableDataType::Decimal(ex::types::decimal::DecimalDataType::from_pb(x)?)
                     }
@@ -326,6 +366,45 @@ impl FromToProto for ex::TableDataType {
             TableDataType::Variant => new_pb_dt24(Dt24::VariantT(pb::Empty {})),
             TableDataType::Geometry => new_pb_dt24(Dt24::GeometryT(pb::Empty {})),
             TableDataType::Geography => new_pb_dt24(Dt24::GeographyT(pb::Empty {})),
+            TableDataType::Interval => new_pb_dt24(Dt24::IntervalT(pb::Empty {})),
+            TableDataType::TimestampTz => new_pb_dt24(Dt24::TimestampTzT(pb::Empty {})),
+            TableDataType::Time => new_pb_dt24(Dt24::TimeT(pb::Empty {})),
+            TableDataType::Uuid => new_pb_dt24(Dt24::UuidT(pb::Empty {})),
+            TableDataType::Enum(items) => {
+                let x = pb::Enum {
+                    ver: VER,
+                    min_reader_ver: MIN_READER_VER,
+                    items: items.items().to_vec(),
+                };
+                new_pb_dt24(Dt24::EnumT(x))
+            }
+            TableDataType::AggregateState(signature) => {
+                let mut params = vec![];
+                for param in signature.params() {
+                    let mut buf = vec![];
+                    bincode_serialize_into_buf(&mut buf, param).map_err(|e| Incompatible {
+                        reason: format!("Invalid AggregateState param: {}", e),
+                    })?;
+                    params.push(buf);
+                }
+
+                let mut arguments = vec![];
+                for argument in signature.arguments() {
+                    let t = ex::infer_schema_type(argument).map_err(|e| Incompatible {
+                        reason: format!("Invalid AggregateState argument: {}", e),
+                    })?;
+                    arguments.push(t.to_pb()?);
+                }
+
+                let x = pb::AggregateState {
+                    ver: VER,
+                    min_reader_ver: MIN_READER_VER,
+                    name: signature.name().to_string(),
+                    params,
+                    arguments,
+                };
+                new_pb_dt24(Dt24::AggregateStateT(x))
+            }
         };
         Ok(x)
     }
@@ -192,6 +192,11 @@ impl FromToProto for mt::TableMeta {
             indexes.insert(name, mt::TableIndex::from_pb(index)?);
         }
 
+        let mut refs = BTreeMap::new();
+        for (name, snapshot_ref) in p.refs {
+            refs.insert(name, mt::SnapshotRef::from_pb(snapshot_ref)?);
+        }
+
         let v = Self {
             schema: Arc::new(ex::TableSchema::from_pb(schema)?),
             engine: p.engine,
@@ -200,6 +205,10 @@ impl FromToProto for mt::TableMeta {
                 Some(sp) => Some(StorageParams::from_pb(sp)?),
                 None => None,
             },
+            cold_storage_params: match p.cold_storage_params {
+                Some(sp) => Some(StorageParams::from_pb(sp)?),
+                None => None,
+            },
             part_prefix: p.part_prefix.unwrap_or("".to_string()),
             options: p.options,
             default_cluster_key: p.default_cluster_key,
@@ -225,6 +234,12 @@ impl FromToProto for mt::TableMeta {
                 Some(p.column_mask_policy)
             },
             indexes,
+            refs,
+            clones: p.clones,
+            row_access_policy: match p.row_access_policy {
+                Some(policy) => Some(mt::TableRowAccessPolicy::from_pb(policy)?),
+                None => None,
+            },
         };
         Ok(v)
     }
@@ -234,6 +249,10 @@ impl FromToProto for mt::TableMeta {
         for (name, index) in &self.indexes {
             indexes.insert(name.clone(), index.to_pb()?);
         }
+        let mut refs = BTreeMap::new();
+        for (name, snapshot_ref) in &self.refs {
+            refs.insert(name.clone(), snapshot_ref.to_pb()?);
+        }
         let p = pb::TableMeta {
             ver: VER,
             min_reader_ver: MIN_READER_VER,
@@ -244,6 +263,10 @@ impl FromToProto for mt::TableMeta {
                 Some(sp) => Some(sp.to_pb()?),
                 None => None,
             },
+            cold_storage_params: match self.cold_storage_params.clone() {
+                Some(sp) => Some(sp.to_pb()?),
+                None => None,
+            },
             part_prefix: if self.part_prefix.is_empty() {
                 None
             } else {
@@ -265,6 +288,12 @@ impl FromToProto for mt::TableMeta {
             shared_by: Vec::from_iter(self.shared_by.clone()),
             column_mask_policy: self.column_mask_policy.clone().unwrap_or_default(),
             indexes,
+            refs,
+            clones: self.clones.clone(),
+            row_access_policy: match &self.row_access_policy {
+                Some(policy) => Some(policy.to_pb()?),
+                None => None,
+            },
         };
         Ok(p)
     }
@@ -358,3 +387,67 @@ impl FromToProto for mt::TableIndex {
         Ok(p)
     }
 }
+
+impl FromToProto for mt::SnapshotRef {
+    type PB = pb::SnapshotRef;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::SnapshotRef) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let typ = match p.typ {
+            0 => mt::SnapshotRefType::Branch,
+            1 => mt::SnapshotRefType::Tag,
+            t => {
+                return Err(Incompatible {
+                    reason: format!("SnapshotRefType can not be {}", t),
+                });
+            }
+        };
+
+        let v = Self {
+            typ,
+            snapshot_location: p.snapshot_location,
+            created_on: DateTime::<Utc>::from_pb(p.created_on)?,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::SnapshotRef, Incompatible> {
+        let p = pb::SnapshotRef {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            typ: self.typ as i32,
+            snapshot_location: self.snapshot_location.clone(),
+            created_on: self.created_on.to_pb()?,
+        };
+        Ok(p)
+    }
+}
+
+impl FromToProto for mt::TableRowAccessPolicy {
+    type PB = pb::TableRowAccessPolicy;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableRowAccessPolicy) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = Self {
+            policy: p.policy,
+            columns: p.columns,
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableRowAccessPolicy, Incompatible> {
+        let p = pb::TableRowAccessPolicy {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            policy: self.policy.clone(),
+            columns: self.columns.clone(),
+        };
+        Ok(p)
+    }
+}
@@ -0,0 +1,194 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This mod is the key point about compatibility.
+//! Everytime update anything in this file, update the `VER` and let the tests pass.
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_meta_app::principal as mt;
+use databend_common_protos::pb;
+use databend_common_protos::pb::task::ScheduleOptions;
+use databend_common_protos::pb::task::WarehouseOptions;
+
+use crate::reader_check_msg;
+use crate::FromToProto;
+use crate::Incompatible;
+use crate::MIN_READER_VER;
+use crate::VER;
+
+impl FromToProto for mt::Task {
+    type PB = pb::Task;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::Task) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let status = match p.status {
+            0 => mt::Status::Suspended,
+            1 => mt::Status::Started,
+            s => {
+                return Err(Incompatible {
+                    reason: format!("Status can not be {}", s),
+                });
+            }
+        };
+
+        let schedule_options = p
+            .schedule_options
+            .map(|s| {
+                let schedule_type = match s.schedule_type {
+                    0 => mt::ScheduleType::IntervalType,
+                    1 => mt::ScheduleType::CronType,
+                    s => {
+                        return Err(Incompatible {
+                            reason: format!("ScheduleType can not be {}", s),
+                        });
+                    }
+                };
+                Ok(mt::ScheduleOptions {
+                    interval: s.interval,
+                    cron: s.cron,
+                    time_zone: s.time_zone,
+                    schedule_type,
+                    milliseconds_interval: s.milliseconds_interval,
+                })
+            })
+            .transpose()?;
+
+        let warehouse_options = p.warehouse_options.map(|w| mt::WarehouseOptions {
+            warehouse: w.warehouse,
+            using_warehouse_size: w.using_warehouse_size,
+        });
+
+        Ok(Self {
+            task_id: p.task_id,
+            task_name: p.task_name,
+            query_text: p.query_text,
+            when_condition: p.when_condition,
+            after: p.after,
+            comment: p.comment,
+            owner: p.owner,
+            schedule_options,
+            warehouse_options,
+            next_scheduled_at: p
+                .next_scheduled_at
+                .map(DateTime::<Utc>::from_pb)
+                .transpose()?,
+            suspend_task_after_num_failures: p.suspend_task_after_num_failures,
+            consecutive_failures: p.consecutive_failures,
+            error_integration: p.error_integration,
+            status,
+            created_at: DateTime::<Utc>::from_pb(p.created_at)?,
+            updated_at: DateTime::<Utc>::from_pb(p.updated_at)?,
+            last_suspended_at: p
+                .last_suspended_at
+                .map(DateTime::<Utc>::from_pb)
+                .transpose()?,
+            session_params: p.session_parameters,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::Task, Incompatible> {
+        Ok(pb::Task {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            task_id: self.task_id,
+            task_name: self.task_name.clone(),
+            query_text: self.query_text.clone(),
+            comment: self.comment.clone(),
+            owner: self.owner.clone(),
+            schedule_options: self.schedule_options.as_ref().map(|s| ScheduleOptions {
+                interval: s.interval,
+                cron: s.cron.clone(),
+                time_zone: s.time_zone.clone(),
+                schedule_type: s.schedule_type as i32,
+                milliseconds_interval: s.milliseconds_interval,
+            }),
+            warehouse_options: self.warehouse_options.as_ref().map(|w| WarehouseOptions {
+                warehouse: w.warehouse.clone(),
+                using_warehouse_size: w.using_warehouse_size.clone(),
+            }),
+            next_scheduled_at: self.next_scheduled_at.map(|t| t.to_pb()).transpose()?,
+            suspend_task_after_num_failures: self.suspend_task_after_num_failures,
+            status: self.status as i32,
+            created_at: self.created_at.to_pb()?,
+            updated_at: self.updated_at.to_pb()?,
+            last_suspended_at: self.last_suspended_at.map(|t| t.to_pb()).transpose()?,
+            after: self.after.clone(),
+            when_condition: self.when_condition.clone(),
+            session_parameters: self.session_params.clone(),
+            error_integration: self.error_integration.clone(),
+            consecutive_failures: self.consecutive_failures,
+        })
+    }
+}
+
+impl FromToProto for mt::TaskRun {
+    type PB = pb::TaskRun;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TaskRun) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let state = match p.state {
+            0 => mt::State::Scheduled,
+            1 => mt::State::Executing,
+            2 => mt::State::Succeeded,
+            3 => mt::State::Failed,
+            4 => mt::State::Cancelled,
+            s => {
+                return Err(Incompatible {
+                    reason: format!("State can not be {}", s),
+                });
+            }
+        };
+
+        Ok(Self {
+            task: mt::Task::from_pb(p.task.ok_or_else(|| Incompatible {
+                reason: "TaskRun.task can not be None".to_string(),
+            })?)?,
+            run_id: p.run_id,
+            attempt_number: p.attempt_number,
+            state,
+            scheduled_at: DateTime::<Utc>::from_pb(p.scheduled_at)?,
+            completed_at: p.completed_at.map(DateTime::<Utc>::from_pb).transpose()?,
+            error_code: p.error_code,
+            error_message: p.error_message,
+            root_task_id: p.root_task_id,
+            query_id: p.query_id,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::TaskRun, Incompatible> {
+        Ok(pb::TaskRun {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            task: Some(self.task.to_pb()?),
+            run_id: self.run_id,
+            attempt_number: self.attempt_number,
+            state: self.state as i32,
+            scheduled_at: self.scheduled_at.to_pb()?,
+            completed_at: self.completed_at.map(|t| t.to_pb()).transpose()?,
+            error_code: self.error_code,
+            error_message: self.error_message.clone(),
+            root_task_id: self.root_task_id,
+            query_id: self.query_id.clone(),
+        })
+    }
+}
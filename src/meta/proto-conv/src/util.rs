@@ -140,6 +140,17 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (108, "2024-08-29: Add: procedure.proto: ProcedureMeta and ProcedureIdentity"),
     (109, "2024-08-29: Refactor: ProcedureMeta add arg_names"),
     (110, "2024-09-18: Add: database.proto: DatabaseMeta.gc_in_progress"),
+    (111, "2024-09-20: Add: task.proto: Task and TaskRun"),
+    (112, "2024-09-21: Add: notification.proto: Notification and NotificationHistory"),
+    (113, "2024-09-22: Add: table.proto: TableMeta.refs, TableMeta.clones and SnapshotRef"),
+    (114, "2024-09-24: Add: row_access_policy.proto: RowAccessPolicyMeta, RowAccessPolicyTableIdList; table.proto: TableMeta.row_access_policy"),
+    (115, "2024-09-25: Add: config.proto: SftpStorageConfig, WebdavStorageConfig"),
+    (116, "2024-09-26: Add: table.proto: TableMeta.cold_storage_params"),
+    (117, "2024-09-27: Add: datatype.proto: DataType.interval_t"),
+    (118, "2024-09-28: Add: datatype.proto: DataType.timestamp_tz_t"),
+    (119, "2024-09-29: Add: datatype.proto: DataType.time_t, DataType.uuid_t"),
+    (120, "2024-09-30: Add: datatype.proto: DataType.enum_t"),
+    (121, "2024-10-01: Add: datatype.proto: DataType.aggregate_state_t"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
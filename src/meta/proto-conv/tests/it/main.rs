@@ -108,3 +108,14 @@ mod v107_geography_datatype;
 mod v108_procedure;
 mod v109_procedure_with_args;
 mod v110_database_meta_gc_in_progress;
+mod v111_task;
+mod v112_notification;
+mod v113_table_snapshot_refs;
+mod v114_row_access_policy;
+mod v115_sftp_webdav_storage;
+mod v116_table_cold_storage;
+mod v117_interval_datatype;
+mod v118_timestamp_tz_datatype;
+mod v119_time_uuid_datatype;
+mod v120_enum_datatype;
+mod v121_aggregate_state_datatype;
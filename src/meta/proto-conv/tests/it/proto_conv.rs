@@ -137,6 +137,7 @@ fn new_table_meta() -> mt::TableMeta {
         )),
         engine: "44".to_string(),
         storage_params: None,
+        cold_storage_params: None,
         part_prefix: "".to_string(),
         engine_options: btreemap! {s("abc") => s("def")},
         options: btreemap! {s("xyz") => s("foo")},
@@ -152,6 +153,9 @@ fn new_table_meta() -> mt::TableMeta {
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
         indexes: btreemap! {},
+        refs: btreemap! {},
+        clones: btreemap! {},
+        row_access_policy: None,
     }
 }
 
@@ -128,6 +128,7 @@ fn test_decode_v2_table_meta() -> anyhow::Result<()> {
         )),
         engine: "44".to_string(),
         storage_params: None,
+        cold_storage_params: None,
         part_prefix: "".to_string(),
         engine_options: btreemap! {s("abc") => s("def")},
         options: btreemap! {s("xyz") => s("foo")},
@@ -143,6 +144,9 @@ fn test_decode_v2_table_meta() -> anyhow::Result<()> {
         shared_by: BTreeSet::new(),
         column_mask_policy: None,
         indexes: btreemap! {},
+        refs: btreemap! {},
+        clones: btreemap! {},
+        row_access_policy: None,
     };
 
     common::test_pb_from_to(func_name!(), want())?;
@@ -133,6 +133,7 @@ fn test_decode_v12_table_meta() -> anyhow::Result<()> {
         engine: "44".to_string(),
         engine_options: btreemap! {s("abc") => s("def")},
         storage_params: Some(StorageParams::default()),
+        cold_storage_params: None,
         part_prefix: "".to_string(),
         options: btreemap! {s("xyz") => s("foo")},
         default_cluster_key: Some("(a + 2, b)".to_string()),
@@ -147,6 +148,9 @@ fn test_decode_v12_table_meta() -> anyhow::Result<()> {
         shared_by: BTreeSet::new(),
         column_mask_policy: None,
         indexes: btreemap! {},
+        refs: btreemap! {},
+        clones: btreemap! {},
+        row_access_policy: None,
     };
 
     common::test_pb_from_to(func_name!(), want())?;
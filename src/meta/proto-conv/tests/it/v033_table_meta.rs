@@ -134,6 +134,7 @@ fn test_decode_v33_table_meta() -> anyhow::Result<()> {
         )),
         engine: "44".to_string(),
         storage_params: None,
+        cold_storage_params: None,
         part_prefix: "".to_string(),
         engine_options: btreemap! {s("abc") => s("def")},
         options: btreemap! {s("xyz") => s("foo")},
@@ -149,6 +150,9 @@ fn test_decode_v33_table_meta() -> anyhow::Result<()> {
         shared_by: btreeset! {1},
         column_mask_policy: None,
         indexes: btreemap! {},
+        refs: btreemap! {},
+        clones: btreemap! {},
+        row_access_policy: None,
     };
 
     common::test_pb_from_to(func_name!(), want())?;
@@ -85,6 +85,7 @@ fn test_decode_v44_table_meta() -> anyhow::Result<()> {
         )),
         engine: "44".to_string(),
         storage_params: None,
+        cold_storage_params: None,
         part_prefix: "".to_string(),
         engine_options: btreemap! {s("abc") => s("def")},
         options: btreemap! {s("xyz") => s("foo")},
@@ -100,6 +101,9 @@ fn test_decode_v44_table_meta() -> anyhow::Result<()> {
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
         indexes: btreemap! {},
+        refs: btreemap! {},
+        clones: btreemap! {},
+        row_access_policy: None,
     };
 
     common::test_load_old(func_name!(), bytes.as_slice(), 44, want())?;
@@ -0,0 +1,113 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::principal as mt;
+use fastrace::func_name;
+use maplit::btreemap;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v111_task() -> anyhow::Result<()> {
+    let task_v111 = vec![
+        8, 11, 18, 2, 116, 49, 26, 8, 83, 69, 76, 69, 67, 84, 32, 49, 34, 7, 99, 111, 109, 109,
+        101, 110, 116, 42, 5, 114, 111, 108, 101, 49, 50, 19, 18, 10, 48, 32, 49, 50, 32, 42, 32,
+        42, 32, 42, 26, 3, 85, 84, 67, 32, 1, 58, 4, 10, 2, 119, 49, 66, 23, 50, 48, 49, 52, 45,
+        49, 49, 45, 50, 57, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 72, 3, 80, 1, 90,
+        23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84,
+        67, 98, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 57, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32,
+        85, 84, 67, 114, 2, 116, 48, 122, 5, 49, 32, 61, 32, 49, 130, 1, 15, 10, 8, 116, 105, 109,
+        101, 122, 111, 110, 101, 18, 3, 85, 84, 67, 138, 1, 7, 110, 111, 116, 105, 102, 121, 49,
+        144, 1, 2, 160, 6, 111, 168, 6, 24,
+    ];
+
+    common::test_pb_from_to(func_name!(), want_task())?;
+    common::test_load_old(func_name!(), task_v111.as_slice(), 111, want_task())
+}
+
+#[test]
+fn test_decode_v111_task_run() -> anyhow::Result<()> {
+    let task_run_v111 = vec![
+        10, 186, 1, 8, 11, 18, 2, 116, 49, 26, 8, 83, 69, 76, 69, 67, 84, 32, 49, 34, 7, 99, 111,
+        109, 109, 101, 110, 116, 42, 5, 114, 111, 108, 101, 49, 50, 19, 18, 10, 48, 32, 49, 50, 32,
+        42, 32, 42, 32, 42, 26, 3, 85, 84, 67, 32, 1, 58, 4, 10, 2, 119, 49, 66, 23, 50, 48, 49,
+        52, 45, 49, 49, 45, 50, 57, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 72, 3, 80,
+        1, 90, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32,
+        85, 84, 67, 98, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 57, 32, 49, 50, 58, 48, 48, 58, 48,
+        57, 32, 85, 84, 67, 114, 2, 116, 48, 122, 5, 49, 32, 61, 32, 49, 130, 1, 15, 10, 8, 116,
+        105, 109, 101, 122, 111, 110, 101, 18, 3, 85, 84, 67, 138, 1, 7, 110, 111, 116, 105, 102,
+        121, 49, 144, 1, 2, 160, 6, 111, 168, 6, 24, 16, 5, 24, 1, 32, 3, 42, 23, 50, 48, 49, 52,
+        45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 50, 23, 50, 48,
+        49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 49, 57, 32, 85, 84, 67, 56,
+        238, 7, 66, 7, 98, 97, 100, 32, 115, 113, 108, 72, 10, 82, 3, 113, 105, 100, 160, 6, 111,
+        168, 6, 24,
+    ];
+
+    let want = || mt::TaskRun {
+        task: want_task(),
+        run_id: 5,
+        attempt_number: 1,
+        state: mt::State::Failed,
+        scheduled_at: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        completed_at: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 19).unwrap()),
+        error_code: 1006,
+        error_message: Some("bad sql".to_string()),
+        root_task_id: 10,
+        query_id: "qid".to_string(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), task_run_v111.as_slice(), 111, want())
+}
+
+fn want_task() -> mt::Task {
+    mt::Task {
+        task_id: 11,
+        task_name: "t1".to_string(),
+        query_text: "SELECT 1".to_string(),
+        when_condition: Some("1 = 1".to_string()),
+        after: vec!["t0".to_string()],
+        comment: Some("comment".to_string()),
+        owner: "role1".to_string(),
+        schedule_options: Some(mt::ScheduleOptions::new_cron(
+            "0 12 * * *".to_string(),
+            Some("UTC".to_string()),
+        )),
+        warehouse_options: Some(mt::WarehouseOptions {
+            warehouse: Some("w1".to_string()),
+            using_warehouse_size: None,
+        }),
+        next_scheduled_at: Some(Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap()),
+        suspend_task_after_num_failures: Some(3),
+        consecutive_failures: 2,
+        error_integration: Some("notify1".to_string()),
+        status: mt::Status::Started,
+        created_at: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        updated_at: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap(),
+        last_suspended_at: None,
+        session_params: btreemap! {"timezone".to_string() => "UTC".to_string()},
+    }
+}
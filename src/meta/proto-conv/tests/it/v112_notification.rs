@@ -0,0 +1,92 @@
+// Copyright 2023 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::principal as mt;
+use fastrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v112_notification() -> anyhow::Result<()> {
+    let notification_v112 = vec![
+        8, 7, 18, 2, 110, 49, 24, 1, 34, 56, 10, 26, 104, 116, 116, 112, 58, 47, 47, 49, 50, 55,
+        46, 48, 46, 48, 46, 49, 58, 56, 48, 56, 48, 47, 104, 111, 111, 107, 18, 4, 80, 79, 83, 84,
+        26, 12, 66, 101, 97, 114, 101, 114, 32, 116, 111, 107, 101, 110, 34, 6, 115, 101, 99, 114,
+        101, 116, 42, 7, 99, 111, 109, 109, 101, 110, 116, 50, 23, 50, 48, 49, 52, 45, 49, 49, 45,
+        50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 58, 23, 50, 48, 49, 52, 45, 49,
+        49, 45, 50, 57, 32, 49, 50, 58, 48, 48, 58, 48, 57, 32, 85, 84, 67, 160, 6, 112, 168, 6,
+        24,
+    ];
+
+    let want = || mt::Notification {
+        notification_id: 7,
+        name: "n1".to_string(),
+        enabled: true,
+        webhook: mt::WebhookOptions {
+            url: "http://127.0.0.1:8080/hook".to_string(),
+            method: Some("POST".to_string()),
+            authorization_header: Some("Bearer token".to_string()),
+            signing_secret: Some("secret".to_string()),
+        },
+        comments: Some("comment".to_string()),
+        created_at: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        updated_at: Utc.with_ymd_and_hms(2014, 11, 29, 12, 0, 9).unwrap(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), notification_v112.as_slice(), 112, want())
+}
+
+#[test]
+fn test_decode_v112_notification_history() -> anyhow::Result<()> {
+    let notification_history_v112 = vec![
+        8, 3, 18, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58, 48, 57,
+        32, 85, 84, 67, 26, 23, 50, 48, 49, 52, 45, 49, 49, 45, 50, 56, 32, 49, 50, 58, 48, 48, 58,
+        49, 57, 32, 85, 84, 67, 34, 4, 116, 97, 115, 107, 42, 2, 110, 49, 50, 13, 123, 34, 116, 97,
+        115, 107, 34, 58, 34, 116, 49, 34, 125, 56, 1, 64, 3, 74, 18, 99, 111, 110, 110, 101, 99,
+        116, 105, 111, 110, 32, 114, 101, 102, 117, 115, 101, 100, 160, 6, 112, 168, 6, 24,
+    ];
+
+    let want = || mt::NotificationHistory {
+        history_id: 3,
+        created_at: Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 9).unwrap(),
+        processed_at: Some(Utc.with_ymd_and_hms(2014, 11, 28, 12, 0, 19).unwrap()),
+        message_source: "task".to_string(),
+        name: "n1".to_string(),
+        message: r#"{"task":"t1"}"#.to_string(),
+        status: mt::NotificationStatus::Failed,
+        attempts: 3,
+        error_message: "connection refused".to_string(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(
+        func_name!(),
+        notification_history_v112.as_slice(),
+        112,
+        want(),
+    )
+}
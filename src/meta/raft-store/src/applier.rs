@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::future;
 use std::io;
 use std::time::Duration;
 
@@ -39,7 +40,14 @@ use databend_common_meta_types::TxnDeleteByPrefixRequest;
 use databend_common_meta_types::TxnDeleteByPrefixResponse;
 use databend_common_meta_types::TxnDeleteRequest;
 use databend_common_meta_types::TxnDeleteResponse;
+use databend_common_meta_types::TxnFetchAddRequest;
+use databend_common_meta_types::TxnFetchAddResponse;
+use databend_common_meta_types::TxnGetManyRequest;
+use databend_common_meta_types::TxnGetManyResponse;
 use databend_common_meta_types::TxnGetRequest;
+use databend_common_meta_types::TxnGetResponse;
+use databend_common_meta_types::TxnListRangeRequest;
+use databend_common_meta_types::TxnListRangeResponse;
 use databend_common_meta_types::TxnOp;
 use databend_common_meta_types::TxnOpResponse;
 use databend_common_meta_types::TxnPutRequest;
@@ -55,6 +63,7 @@ use log::info;
 use num::FromPrimitive;
 
 use crate::sm_v003::SMV003;
+use crate::utils::prefix_right_bound;
 
 /// A helper that applies raft log `Entry` to the state machine.
 pub struct Applier<'a> {
@@ -311,10 +320,34 @@ impl<'a> Applier<'a> {
                     false
                 }
             }
+            txn_condition::Target::KeysWithPrefix(right) => {
+                // Counting one more key than `right` is enough for any comparison.
+                let max = (*right).min(TxnCondition::MAX_KEYS_WITH_PREFIX) + 1;
+                let count = self.count_keys_with_prefix(key, max).await?;
+                Self::eval_seq_condition(count, cond.expected, right)
+            }
         };
         Ok(positive)
     }
 
+    /// Count the number of keys with the `prefix`, up to `max`.
+    ///
+    /// Like the other conditions, expiration is not checked,
+    /// because expired keys are cleaned before applying a log.
+    async fn count_keys_with_prefix(&self, prefix: &str, max: u64) -> Result<u64, io::Error> {
+        let start = prefix.to_string();
+        let strm = match prefix_right_bound(prefix) {
+            Some(end) => self.sm.range_kv(start..end, max as usize).await?,
+            None => self.sm.range_kv(start.., max as usize).await?,
+        };
+
+        let keys = strm
+            .try_take_while(|(k, _)| future::ready(Ok(k.starts_with(prefix))))
+            .try_collect::<Vec<_>>()
+            .await?;
+        Ok(keys.len() as u64)
+    }
+
     fn eval_seq_condition(left: u64, op: i32, right: &u64) -> bool {
         match FromPrimitive::from_i32(op) {
             Some(ConditionResult::Eq) => left == *right,
@@ -360,6 +393,15 @@ impl<'a> Applier<'a> {
                 self.txn_execute_delete_by_prefix(delete_by_prefix, resp)
                     .await?;
             }
+            Some(txn_op::Request::FetchAdd(fetch_add)) => {
+                self.txn_execute_fetch_add(fetch_add, resp).await?;
+            }
+            Some(txn_op::Request::ListRange(list_range)) => {
+                self.txn_execute_list_range(list_range, resp).await?;
+            }
+            Some(txn_op::Request::GetMany(get_many)) => {
+                self.txn_execute_get_many(get_many, resp).await?;
+            }
             None => {}
         }
         Ok(())
@@ -456,6 +498,112 @@ impl<'a> Applier<'a> {
         Ok(())
     }
 
+    async fn txn_execute_fetch_add(
+        &mut self,
+        fetch_add: &TxnFetchAddRequest,
+        resp: &mut TxnReply,
+    ) -> Result<(), io::Error> {
+        let sv = self.sm.get_maybe_expired_kv(&fetch_add.key).await?;
+
+        let before = TxnFetchAddResponse::parse_value(sv.value().map(|v| v.as_slice()));
+        let after = before.and_then(|b| b.checked_add(fetch_add.delta));
+
+        let fetch_add_resp = match (before, after) {
+            (Some(before), Some(after)) => {
+                // Keep the expiration of the existing key, the counter only changes its value.
+                let meta = sv
+                    .meta()
+                    .and_then(|m| m.get_expire_at_ms())
+                    .map(|ms| MetaSpec::new_expire(ms / 1000));
+
+                let mut upsert =
+                    UpsertKV::update(&fetch_add.key, &TxnFetchAddResponse::encode_value(after));
+                if let Some(meta) = meta {
+                    upsert = upsert.with(meta);
+                }
+                let (_prev, result) = self.upsert_kv(&upsert).await?;
+
+                TxnFetchAddResponse {
+                    key: fetch_add.key.clone(),
+                    success: true,
+                    before,
+                    after,
+                    seq: result.seq(),
+                }
+            }
+            _ => {
+                // The value is not an integer or the sum overflows: leave it unchanged.
+                let before = before.unwrap_or_default();
+                TxnFetchAddResponse {
+                    key: fetch_add.key.clone(),
+                    success: false,
+                    before,
+                    after: before,
+                    seq: sv.seq(),
+                }
+            }
+        };
+
+        resp.responses.push(TxnOpResponse {
+            response: Some(txn_op_response::Response::FetchAdd(fetch_add_resp)),
+        });
+        Ok(())
+    }
+
+    async fn txn_execute_list_range(
+        &self,
+        list_range: &TxnListRangeRequest,
+        resp: &mut TxnReply,
+    ) -> Result<(), io::Error> {
+        let start = list_range.start.clone();
+        let limit = list_range.capped_limit() as usize;
+
+        // Read one more record to tell if there are more.
+        let mut strm = match &list_range.end {
+            Some(end) => self.sm.range_kv(start..end.clone(), limit + 1).await?,
+            None => self.sm.range_kv(start.., limit + 1).await?,
+        };
+
+        let mut items = vec![];
+        let mut more = false;
+
+        while let Some((key, seq_v)) = strm.try_next().await? {
+            if items.len() >= limit {
+                more = true;
+                break;
+            }
+            items.push(TxnGetResponse::new(key, Some(pb::SeqV::from(seq_v))));
+        }
+
+        resp.responses.push(TxnOpResponse {
+            response: Some(txn_op_response::Response::ListRange(TxnListRangeResponse {
+                items,
+                more,
+            })),
+        });
+        Ok(())
+    }
+
+    async fn txn_execute_get_many(
+        &self,
+        get_many: &TxnGetManyRequest,
+        resp: &mut TxnReply,
+    ) -> Result<(), io::Error> {
+        let mut values = Vec::with_capacity(get_many.keys.len());
+
+        for key in get_many.keys.iter() {
+            let sv = self.sm.get_maybe_expired_kv(key).await?;
+            values.push(TxnGetResponse::new(key, sv.map(pb::SeqV::from)));
+        }
+
+        resp.responses.push(TxnOpResponse {
+            response: Some(txn_op_response::Response::GetMany(TxnGetManyResponse {
+                values,
+            })),
+        });
+        Ok(())
+    }
+
     /// Before applying, list expired keys to clean.
     ///
     /// All expired keys will be removed before applying a log.
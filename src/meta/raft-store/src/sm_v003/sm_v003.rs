@@ -15,6 +15,7 @@
 use std::fmt::Debug;
 use std::future;
 use std::io;
+use std::ops::RangeBounds;
 
 use databend_common_meta_kvapi::kvapi;
 use databend_common_meta_kvapi::kvapi::KVStream;
@@ -162,6 +163,10 @@ impl SMV003 {
         let levels = self.levels_mut();
         *levels.sys_data_mut() = sys_data;
         *levels.persisted_mut() = Some(db);
+
+        if let Some(subscriber) = &self.subscriber {
+            subscriber.data_replaced(self.sys_data_ref().curr_seq());
+        }
         Ok(())
     }
 
@@ -232,6 +237,37 @@ impl SMV003 {
         Ok(strm.boxed())
     }
 
+    /// List at most `limit` kv entries in the `range`.
+    ///
+    /// It does not check expiration of the returned entries.
+    pub async fn range_kv<R>(
+        &self,
+        range: R,
+        limit: usize,
+    ) -> Result<IOResultStream<(String, SeqV)>, io::Error>
+    where
+        R: RangeBounds<String> + Send + Sync + Clone + 'static,
+    {
+        let strm = self.levels.str_map().range(range).await?;
+
+        let strm = strm
+            // Skip tombstone
+            .try_filter_map(|(k, marked)| {
+                let seqv = Into::<Option<SeqV>>::into(marked);
+                let res = seqv.map(|x| (k, x));
+                future::ready(Ok(res))
+            })
+            // Stop reading once there are enough entries
+            .take(limit);
+
+        // Make it static
+
+        let vs = strm.collect::<Vec<_>>().await;
+        let strm = futures::stream::iter(vs);
+
+        Ok(strm.boxed())
+    }
+
     pub(crate) fn update_expire_cursor(&mut self, log_time_ms: u64) {
         if log_time_ms < self.expire_cursor.time_ms {
             warn!(
@@ -14,6 +14,12 @@
 
 use databend_common_meta_types::seq_value::SeqV;
 use databend_common_meta_types::seq_value::SeqValue;
+use databend_common_meta_types::txn_op_response;
+use databend_common_meta_types::AppliedState;
+use databend_common_meta_types::Cmd;
+use databend_common_meta_types::TxnListRangeRequest;
+use databend_common_meta_types::TxnOp;
+use databend_common_meta_types::TxnRequest;
 use databend_common_meta_types::UpsertKV;
 use futures_util::TryStreamExt;
 use pretty_assertions::assert_eq;
@@ -70,6 +76,55 @@ async fn test_one_level_upsert_get_range() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_range_kv_limit() -> anyhow::Result<()> {
+    let mut sm = SMV003::default();
+
+    let mut a = sm.new_applier();
+    for key in ["a", "b", "c"] {
+        a.upsert_kv(&UpsertKV::update(key, b"v")).await?;
+    }
+
+    let got = sm
+        .range_kv(s("a").., 2)
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(got, vec![
+        (s("a"), SeqV::new(1, b("v"))),
+        (s("b"), SeqV::new(2, b("v")))
+    ]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_txn_list_range_limit_is_capped() -> anyhow::Result<()> {
+    let mut sm = SMV003::default();
+
+    let n = TxnListRangeRequest::MAX_LIMIT + 1;
+    let mut a = sm.new_applier();
+    for i in 0..n {
+        a.upsert_kv(&UpsertKV::update(format!("k/{:05}", i), b"v"))
+            .await?;
+    }
+
+    for limit in [0, n] {
+        let txn = TxnRequest::unconditional(vec![TxnOp::list_range("k/", None, limit)]);
+        let mut a = sm.new_applier();
+        let AppliedState::TxnReply(reply) = a.apply_cmd(&Cmd::Transaction(txn)).await? else {
+            panic!("expect TxnReply");
+        };
+
+        let Some(txn_op_response::Response::ListRange(resp)) = &reply.responses[0].response else {
+            panic!("expect ListRange response");
+        };
+        assert_eq!(resp.items.len() as u64, TxnListRangeRequest::MAX_LIMIT);
+        assert!(resp.more);
+    }
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_two_level_upsert_get_range() -> anyhow::Result<()> {
     // |   a/b(D) c d
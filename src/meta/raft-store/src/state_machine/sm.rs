@@ -27,6 +27,7 @@ use databend_common_meta_sled_store::SledTree;
 use databend_common_meta_sled_store::Store;
 use databend_common_meta_sled_store::TransactionSledTree;
 use databend_common_meta_stoerr::MetaStorageError;
+use databend_common_meta_types::anyerror::AnyError;
 use databend_common_meta_types::protobuf as pb;
 use databend_common_meta_types::raft_types::Entry;
 use databend_common_meta_types::raft_types::EntryPayload;
@@ -54,6 +55,10 @@ use databend_common_meta_types::TxnDeleteByPrefixRequest;
 use databend_common_meta_types::TxnDeleteByPrefixResponse;
 use databend_common_meta_types::TxnDeleteRequest;
 use databend_common_meta_types::TxnDeleteResponse;
+use databend_common_meta_types::TxnFetchAddRequest;
+use databend_common_meta_types::TxnFetchAddResponse;
+use databend_common_meta_types::TxnGetManyRequest;
+use databend_common_meta_types::TxnGetManyResponse;
 use databend_common_meta_types::TxnGetRequest;
 use databend_common_meta_types::TxnGetResponse;
 use databend_common_meta_types::TxnOp;
@@ -95,6 +100,11 @@ const TREE_STATE_MACHINE: &str = "state_machine";
 /// StateMachine subscriber trait
 pub trait StateMachineSubscriber: Debug + Sync + Send {
     fn kv_changed(&self, change: Change<Vec<u8>, String>);
+
+    /// Called when the data is replaced without emitting kv changes, e.g., a snapshot is installed.
+    ///
+    /// `curr_seq` is the last seq of the new data.
+    fn data_replaced(&self, _curr_seq: u64) {}
 }
 
 /// The state machine of the `MemStore`.
@@ -463,6 +473,14 @@ impl StateMachine {
                         return Ok(false);
                     }
                 }
+                txn_condition::Target::KeysWithPrefix(_) => {
+                    // A sled transaction can not scan keys.
+                    // Reject it rather than evaluating it to a wrong result.
+                    return Err(MetaStorageError::Unsupported(AnyError::error(format!(
+                        "sled state machine does not support condition: {}",
+                        cond
+                    ))));
+                }
             }
         };
 
@@ -615,6 +633,83 @@ impl StateMachine {
         Ok(())
     }
 
+    fn txn_execute_fetch_add_operation(
+        &self,
+        txn_tree: &mut TransactionSledTree,
+        fetch_add: &TxnFetchAddRequest,
+        resp: &mut TxnReply,
+        log_time_ms: u64,
+    ) -> Result<(), MetaStorageError> {
+        let sv = txn_tree.key_space::<GenericKV>().get(&fetch_add.key)?;
+        let sv = sv.filter(|x| !x.is_expired(log_time_ms));
+
+        let before = TxnFetchAddResponse::parse_value(sv.value().map(|v| v.as_slice()));
+        let after = before.and_then(|b| b.checked_add(fetch_add.delta));
+
+        let fetch_add_resp = match (before, after) {
+            (Some(before), Some(after)) => {
+                let upsert =
+                    UpsertKV::update(&fetch_add.key, &TxnFetchAddResponse::encode_value(after));
+                let (expired, prev, result) = Self::txn_upsert_kv(txn_tree, &upsert, log_time_ms)?;
+
+                if expired.is_some() {
+                    txn_tree.push_change(&fetch_add.key, expired, None);
+                }
+                txn_tree.push_change(&fetch_add.key, prev, result.clone());
+
+                TxnFetchAddResponse {
+                    key: fetch_add.key.clone(),
+                    success: true,
+                    before,
+                    after,
+                    seq: result.seq(),
+                }
+            }
+            _ => {
+                let before = before.unwrap_or_default();
+                TxnFetchAddResponse {
+                    key: fetch_add.key.clone(),
+                    success: false,
+                    before,
+                    after: before,
+                    seq: sv.seq(),
+                }
+            }
+        };
+
+        resp.responses.push(TxnOpResponse {
+            response: Some(txn_op_response::Response::FetchAdd(fetch_add_resp)),
+        });
+
+        Ok(())
+    }
+
+    fn txn_execute_get_many_operation(
+        &self,
+        txn_tree: &TransactionSledTree,
+        get_many: &TxnGetManyRequest,
+        resp: &mut TxnReply,
+    ) -> Result<(), MetaStorageError> {
+        let sub_tree = txn_tree.key_space::<GenericKV>();
+
+        let mut values = Vec::with_capacity(get_many.keys.len());
+        for key in get_many.keys.iter() {
+            let sv = sub_tree.get(key)?;
+            values.push(TxnGetResponse {
+                key: key.clone(),
+                value: sv.map(pb::SeqV::from),
+            });
+        }
+
+        resp.responses.push(TxnOpResponse {
+            response: Some(txn_op_response::Response::GetMany(TxnGetManyResponse {
+                values,
+            })),
+        });
+
+        Ok(())
+    }
+
     #[fastrace::trace]
     fn txn_execute_operation(
         &self,
@@ -644,6 +739,20 @@ impl StateMachine {
                     log_time_ms,
                 )?;
             }
+            Some(txn_op::Request::FetchAdd(fetch_add)) => {
+                self.txn_execute_fetch_add_operation(txn_tree, fetch_add, resp, log_time_ms)?;
+            }
+            Some(txn_op::Request::ListRange(list_range)) => {
+                // A sled transaction can not scan keys.
+                // Reject it rather than returning a wrong result.
+                return Err(MetaStorageError::Unsupported(AnyError::error(format!(
+                    "sled state machine does not support op: {}",
+                    list_range
+                ))));
+            }
+            Some(txn_op::Request::GetMany(get_many)) => {
+                self.txn_execute_get_many_operation(txn_tree, get_many, resp)?;
+            }
             None => {}
         }
 
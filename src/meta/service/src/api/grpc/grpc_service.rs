@@ -153,15 +153,19 @@ impl MetaServiceImpl {
         &self,
         request: Request<RaftRequest>,
     ) -> Result<(Option<Endpoint>, BoxStream<StreamItem>), Status> {
+        let consistency = GrpcHelper::get_request_read_consistency(&request)?;
         let req: MetaGrpcReadReq = GrpcHelper::parse_req(request)?;
 
-        info!("{}: Received ReadRequest: {:?}", func_name!(), req);
-
-        let req = ForwardRequest::new(1, req);
+        info!(
+            "{}: Received ReadRequest: {:?}, consistency: {}",
+            func_name!(),
+            req,
+            consistency
+        );
 
         let res = self
             .meta_node
-            .handle_forwardable_request::<MetaGrpcReadReq>(req.clone())
+            .handle_read_request(req.clone(), consistency)
             .log_elapsed_info(format!("ReadRequest: {:?}", req))
             .await
             .map_err(GrpcHelper::internal_err);
@@ -417,16 +421,12 @@ impl MetaService for MetaServiceImpl {
 
         let add_res = mn.add_watcher(request.into_inner(), tx).await;
 
-        match add_res {
-            Ok(watcher) => {
-                let stream = WatchStream::new(rx, watcher, mn.dispatcher_handle.clone());
-                Ok(Response::new(Box::pin(stream) as Self::WatchStream))
-            }
-            Err(e) => {
-                // TODO: test error return.
-                Err(Status::invalid_argument(e))
-            }
-        }
+        let (watcher, initial) = add_res?;
+
+        let stream = WatchStream::new(rx, watcher, mn.dispatcher_handle.clone());
+        // Send the initial values or the replayed changes before new change events.
+        let stream = futures::stream::iter(initial.into_iter().map(Ok)).chain(stream);
+        Ok(Response::new(Box::pin(stream) as Self::WatchStream))
     }
 
     async fn member_list(
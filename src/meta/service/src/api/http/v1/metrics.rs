@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use log::warn;
 use poem::web::Data;
 
 use crate::meta_service::MetaNode;
@@ -24,6 +25,11 @@ use crate::metrics::meta_metrics_to_prometheus_string;
 /// return the metrics.
 /// The response content is the same as `MetaMetrics` in metrics/meta_metrics.rs
 #[poem::handler]
-pub async fn metrics_handler(_meta_node: Data<&Arc<MetaNode>>) -> poem::Result<String> {
+pub async fn metrics_handler(meta_node: Data<&Arc<MetaNode>>) -> poem::Result<String> {
+    // Per-tenant usage is built lazily, collect it so that it is always present in the metrics.
+    if let Err(e) = meta_node.tenant_usage().await {
+        warn!("failed to collect tenant usage for metrics: {}", e);
+    }
+
     Ok(meta_metrics_to_prometheus_string())
 }
@@ -16,3 +16,4 @@ pub mod cluster_state;
 pub mod config;
 pub mod ctrl;
 pub mod metrics;
+pub mod tenants;
@@ -0,0 +1,52 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use http::StatusCode;
+use poem::web::Data;
+use poem::web::IntoResponse;
+use poem::web::Json;
+
+use crate::configs::TenantQuotaConfig;
+use crate::meta_service::tenant_quota::TenantUsage;
+use crate::meta_service::MetaNode;
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct TenantsUsageResponse {
+    /// The per-tenant limits, `0` means unlimited.
+    pub limits: TenantQuotaConfig,
+    pub tenants: BTreeMap<String, TenantUsage>,
+}
+
+/// GET /v1/tenants/usage
+///
+/// return the keys, value bytes and write rate of every tenant, and the configured limits.
+#[poem::handler]
+pub async fn tenants_usage_handler(
+    meta_node: Data<&Arc<MetaNode>>,
+) -> poem::Result<impl IntoResponse> {
+    let tenants = meta_node.tenant_usage().await.map_err(|e| {
+        poem::Error::from_string(
+            format!("failed to get tenant usage: {}", e),
+            StatusCode::INTERNAL_SERVER_ERROR,
+        )
+    })?;
+
+    Ok(Json(TenantsUsageResponse {
+        limits: meta_node.tenant_quota.limits(),
+        tenants,
+    }))
+}
@@ -77,6 +77,10 @@ impl HttpService {
                 "/v1/metrics",
                 get(super::http::v1::metrics::metrics_handler),
             )
+            .at(
+                "/v1/tenants/usage",
+                get(super::http::v1::tenants::tenants_usage_handler),
+            )
             .at("/debug/home", get(debug_home_handler))
             .at("/debug/pprof/profile", get(debug_pprof_handler));
 
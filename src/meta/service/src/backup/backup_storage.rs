@@ -0,0 +1,169 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::env;
+use std::io;
+
+use databend_common_meta_app::storage::StorageParams;
+use futures::Stream;
+use futures::TryStreamExt;
+use log::info;
+use opendal::services;
+use opendal::Operator;
+
+use crate::backup::layout::LOG_DIR;
+use crate::backup::layout::MANIFEST_DIR;
+use crate::backup::LogSegment;
+use crate::backup::SnapshotManifest;
+
+/// The size of a chunk to upload at a time.
+const WRITE_CHUNK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Read and write meta data backups in an object storage.
+///
+/// See `backup/layout.rs` for how the data is organized.
+#[derive(Clone, Debug)]
+pub struct BackupStorage {
+    op: Operator,
+}
+
+impl BackupStorage {
+    /// Create a backup storage at the location specified by `params`.
+    ///
+    /// Only `fs` and `s3` are supported.
+    pub fn new(params: &StorageParams) -> Result<Self, io::Error> {
+        let op = match params {
+            StorageParams::Fs(cfg) => {
+                let mut root = cfg.root.clone();
+                if !root.starts_with('/') {
+                    root = env::current_dir()?.join(root).display().to_string();
+                }
+                Operator::new(services::Fs::default().root(&root))?.finish()
+            }
+            StorageParams::S3(cfg) => {
+                let mut builder = services::S3::default()
+                    .endpoint(&cfg.endpoint_url)
+                    .bucket(&cfg.bucket)
+                    .root(&cfg.root)
+                    .access_key_id(&cfg.access_key_id)
+                    .secret_access_key(&cfg.secret_access_key);
+
+                if !cfg.region.is_empty() {
+                    builder = builder.region(&cfg.region);
+                } else {
+                    builder = builder.region("us-east-1");
+                }
+
+                Operator::new(builder)?.finish()
+            }
+            other => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("unsupported backup storage: {}", other),
+                ));
+            }
+        };
+
+        info!("BackupStorage created: {}", params);
+
+        Ok(Self { op })
+    }
+
+    /// List manifests of all complete snapshots, in the order of time.
+    pub async fn list_manifests(&self) -> Result<Vec<SnapshotManifest>, io::Error> {
+        let mut manifests = vec![];
+
+        for ent in self.op.list(MANIFEST_DIR).await? {
+            if !ent.name().ends_with(".json") {
+                continue;
+            }
+
+            let buf = self.op.read(ent.path()).await?;
+            let manifest: SnapshotManifest = serde_json::from_slice(&buf.to_vec())?;
+            manifests.push(manifest);
+        }
+
+        manifests.sort_by_key(|m| m.time_ms);
+        Ok(manifests)
+    }
+
+    /// List all log segments, in the order of log index.
+    pub async fn list_log_segments(&self) -> Result<Vec<LogSegment>, io::Error> {
+        let mut segments = self
+            .op
+            .list(LOG_DIR)
+            .await?
+            .iter()
+            .filter_map(|ent| LogSegment::parse(ent.name()))
+            .collect::<Vec<_>>();
+
+        segments.sort();
+        Ok(segments)
+    }
+
+    /// Write lines to `path`, returns the number of lines written.
+    pub async fn write_lines(
+        &self,
+        path: &str,
+        lines: impl Stream<Item = Result<String, io::Error>>,
+    ) -> Result<u64, io::Error> {
+        let mut writer = self.op.writer_with(path).chunk(WRITE_CHUNK_SIZE).await?;
+
+        let mut n = 0;
+        let mut lines = std::pin::pin!(lines);
+
+        while let Some(line) = lines.try_next().await? {
+            let mut buf = line.into_bytes();
+            buf.push(b'\n');
+            writer.write(buf).await?;
+            n += 1;
+        }
+
+        writer.close().await?;
+        Ok(n)
+    }
+
+    /// Read all lines in `path`.
+    pub async fn read_lines(&self, path: &str) -> Result<Vec<String>, io::Error> {
+        let buf = self.op.read(path).await?;
+        let content = String::from_utf8(buf.to_vec())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let lines = content
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(|l| l.to_string())
+            .collect();
+        Ok(lines)
+    }
+
+    /// Write the manifest to mark a snapshot as complete.
+    pub async fn write_manifest(&self, manifest: &SnapshotManifest) -> Result<(), io::Error> {
+        let buf = serde_json::to_vec(manifest)?;
+        self.op.write(&manifest.path(), buf).await?;
+        Ok(())
+    }
+
+    /// Remove a snapshot, the manifest is removed first so that it won't be seen as complete.
+    pub async fn remove_snapshot(&self, manifest: &SnapshotManifest) -> Result<(), io::Error> {
+        self.op.delete(&manifest.path()).await?;
+        self.op.delete(&manifest.data_path).await?;
+        Ok(())
+    }
+
+    pub async fn remove_log_segment(&self, segment: &LogSegment) -> Result<(), io::Error> {
+        self.op.delete(&segment.path()).await?;
+        Ok(())
+    }
+}
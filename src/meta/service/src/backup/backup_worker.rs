@@ -0,0 +1,299 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::future;
+use std::io;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyerror::AnyError;
+use databend_common_base::base::tokio::select;
+use databend_common_base::base::tokio::sync::watch;
+use databend_common_base::base::tokio::time::sleep;
+use databend_common_meta_raft_store::key_spaces::RaftStoreEntry;
+use databend_common_meta_raft_store::raft_log_v004::codec_wrapper::Cw;
+use databend_common_meta_raft_store::state_machine::StateMachineMetaKey;
+use databend_common_meta_raft_store::state_machine::StateMachineMetaValue;
+use databend_common_meta_sled_store::openraft::ServerState;
+use databend_common_meta_types::raft_types::Entry;
+use databend_common_meta_types::raft_types::LogId;
+use databend_common_meta_types::MetaStartupError;
+use databend_common_meta_types::SeqV;
+use fastrace::prelude::*;
+use futures::stream;
+use futures::TryStreamExt;
+use itertools::Itertools;
+use log::info;
+use log::warn;
+
+use crate::backup::BackupStorage;
+use crate::backup::LogSegment;
+use crate::backup::SnapshotManifest;
+use crate::configs::BackupConfig;
+use crate::meta_service::MetaNode;
+
+/// The max number of logs in a log segment.
+const MAX_SEGMENT_LOGS: u64 = 10_000;
+
+/// Continuously backs up meta data to the backup storage, when this node is the leader.
+///
+/// It uploads a full snapshot every `snapshot_interval`, and the logs committed since the last upload
+/// every `log_interval`. A new snapshot is also uploaded if the logs to upload are already purged,
+/// e.g., after leadership changes.
+pub struct BackupWorker {
+    meta_node: Arc<MetaNode>,
+    storage: BackupStorage,
+    config: BackupConfig,
+}
+
+impl BackupWorker {
+    /// Spawn a backup worker for the meta node, it quits when the meta node shuts down.
+    pub async fn spawn(
+        meta_node: Arc<MetaNode>,
+        config: &BackupConfig,
+    ) -> Result<(), MetaStartupError> {
+        info!("Start a task backing up meta data to: {}", config.storage);
+
+        let storage = BackupStorage::new(&config.storage).map_err(|e| {
+            MetaStartupError::InvalidConfig(format!("invalid backup storage: {}", e))
+        })?;
+
+        let worker = BackupWorker {
+            meta_node: meta_node.clone(),
+            storage,
+            config: config.clone(),
+        };
+
+        let running_rx = meta_node.running_rx.clone();
+        let h = databend_common_base::runtime::spawn(
+            worker
+                .run(running_rx)
+                .in_span(Span::enter_with_local_parent("meta-backup")),
+        );
+
+        {
+            let mut jh = meta_node.join_handles.lock().await;
+            jh.push(h);
+        }
+
+        Ok(())
+    }
+
+    async fn run(self, mut running_rx: watch::Receiver<()>) -> Result<(), AnyError> {
+        let interval = Duration::from_secs(self.config.log_interval);
+
+        loop {
+            select! {
+                _ = running_rx.changed() => {
+                    info!("BackupWorker received shutdown signal, quit");
+                    break;
+                }
+                _ = sleep(interval) => {}
+            }
+
+            if self.meta_node.raft.metrics().borrow().state != ServerState::Leader {
+                continue;
+            }
+
+            if let Err(e) = self.backup_once().await {
+                warn!("BackupWorker failed to backup meta data: {}", e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Upload a snapshot if it is due, otherwise upload logs committed since the last upload.
+    async fn backup_once(&self) -> Result<(), io::Error> {
+        let now_ms = SeqV::<()>::now_ms();
+
+        let manifests = self.storage.list_manifests().await?;
+        let segments = self.storage.list_log_segments().await?;
+
+        let last_snapshot = manifests.last();
+        let uploaded = std::cmp::max(
+            last_snapshot.and_then(|m| m.last_index()),
+            segments.last().map(|s| s.last),
+        );
+
+        let purged = {
+            let log = self.meta_node.sto.log.read().await;
+            log.log_state().purged().map(Cw::to_inner)
+        };
+
+        let snapshot_due = match last_snapshot {
+            None => true,
+            Some(m) => now_ms >= m.time_ms + self.config.snapshot_interval * 1000,
+        };
+
+        // The logs following the last upload are purged,
+        // the backup can only continue with a new snapshot.
+        let logs_purged = purged.map(|x| x.index) > uploaded;
+
+        if snapshot_due || logs_purged {
+            self.upload_snapshot().await?;
+            self.remove_expired(now_ms).await?;
+            return Ok(());
+        }
+
+        let last_applied = self.meta_node.raft.metrics().borrow().last_applied;
+        if let Some(last_applied) = last_applied {
+            let start = uploaded.map(|x| x + 1).unwrap_or_default();
+            self.upload_logs(start, last_applied.index + 1).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Upload a full export of the meta data, which includes only committed logs.
+    async fn upload_snapshot(&self) -> Result<SnapshotManifest, io::Error> {
+        let start_ms = SeqV::<()>::now_ms();
+        let data_path = SnapshotManifest::data_path(start_ms);
+
+        info!("BackupWorker start uploading snapshot to {}", data_path);
+
+        let mut filter = ExportFilter::default();
+        let lines = self
+            .meta_node
+            .sto
+            .inner()
+            .export()
+            .try_filter_map(|line| future::ready(filter.feed(line)));
+
+        let records = self.storage.write_lines(&data_path, lines).await?;
+
+        let manifest = SnapshotManifest {
+            time_ms: SeqV::<()>::now_ms(),
+            data_path,
+            last_applied: filter.last_applied,
+            last_log_id: filter.last_log_id,
+            records,
+        };
+        self.storage.write_manifest(&manifest).await?;
+
+        info!("BackupWorker uploaded snapshot: {:?}", manifest);
+        Ok(manifest)
+    }
+
+    /// Upload logs in range `[start, end)` in one or more log segments.
+    async fn upload_logs(&self, start: u64, end: u64) -> Result<(), io::Error> {
+        let mut first = start;
+
+        while first < end {
+            let last = std::cmp::min(first + MAX_SEGMENT_LOGS, end) - 1;
+
+            let entries = {
+                let log = self.meta_node.sto.log.read().await;
+                log.read(first, last + 1)
+                    .map_ok(|(log_id, payload)| Entry {
+                        log_id: log_id.0,
+                        payload: payload.0,
+                    })
+                    .collect::<Result<Vec<_>, _>>()?
+            };
+
+            if entries.first().map(|e| e.log_id.index) != Some(first) {
+                return Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("logs since {} are purged before uploading", first),
+                ));
+            }
+
+            let segment = LogSegment::new(first, last);
+            let lines = entries.into_iter().map(|ent| {
+                let line = serde_json::to_string(&("raft_log", RaftStoreEntry::LogEntry(ent)))?;
+                Ok::<_, io::Error>(line)
+            });
+
+            self.storage
+                .write_lines(&segment.path(), stream::iter(lines))
+                .await?;
+
+            info!("BackupWorker uploaded log segment: {:?}", segment);
+
+            first = last + 1;
+        }
+
+        Ok(())
+    }
+
+    /// Remove snapshots and logs that are not required to restore to a point in the retention period.
+    async fn remove_expired(&self, now_ms: u64) -> Result<(), io::Error> {
+        let expire_before = now_ms.saturating_sub(self.config.retention * 1000);
+
+        let manifests = self.storage.list_manifests().await?;
+
+        // The last snapshot before the retention period is kept,
+        // for restoring to the beginning of the period.
+        let keep_from = manifests
+            .iter()
+            .rposition(|m| m.time_ms <= expire_before)
+            .unwrap_or_default();
+
+        for m in &manifests[..keep_from] {
+            info!("BackupWorker remove expired snapshot: {:?}", m);
+            self.storage.remove_snapshot(m).await?;
+        }
+
+        let Some(oldest) = manifests.get(keep_from) else {
+            return Ok(());
+        };
+
+        for segment in self.storage.list_log_segments().await? {
+            if Some(segment.last) <= oldest.last_index() {
+                info!("BackupWorker remove expired log segment: {:?}", segment);
+                self.storage.remove_log_segment(&segment).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Tracks the exported data and removes logs that are not committed.
+///
+/// Logs that are not committed may be discarded by the next leader and must not be restored.
+#[derive(Default)]
+struct ExportFilter {
+    committed: Option<LogId>,
+    last_log_id: Option<LogId>,
+    last_applied: Option<LogId>,
+}
+
+impl ExportFilter {
+    fn feed(&mut self, line: String) -> Result<Option<String>, io::Error> {
+        let (_tree_name, entry): (String, RaftStoreEntry) = serde_json::from_str(&line)?;
+
+        match entry {
+            RaftStoreEntry::Committed(committed) => {
+                self.committed = committed;
+            }
+            RaftStoreEntry::LogEntry(ent) => {
+                if Some(ent.log_id) > self.committed {
+                    return Ok(None);
+                }
+                self.last_log_id = Some(ent.log_id);
+            }
+            RaftStoreEntry::StateMachineMeta {
+                key: StateMachineMetaKey::LastApplied,
+                value: StateMachineMetaValue::LogId(log_id),
+            } => {
+                self.last_applied = Some(log_id);
+            }
+            _ => {}
+        }
+
+        Ok(Some(line))
+    }
+}
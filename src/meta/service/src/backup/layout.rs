@@ -0,0 +1,101 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Layout of the backup data in the storage:
+//!
+//! ```text
+//! <root>/
+//! ├── snapshots/<start_ms>.jsonl              // Full data, in the format of `databend-metactl export`.
+//! ├── manifests/<time_ms>.json                // A snapshot is complete only when its manifest is written.
+//! └── logs/<first_index>-<last_index>.jsonl   // Committed raft-logs, one `["raft_log",{"LogEntry":...}]` per line.
+//! ```
+//!
+//! Numbers in a path are zero padded so that listing returns them in order.
+
+use databend_common_meta_types::raft_types::LogId;
+use serde::Deserialize;
+use serde::Serialize;
+
+pub(crate) const SNAPSHOT_DIR: &str = "snapshots/";
+pub(crate) const MANIFEST_DIR: &str = "manifests/";
+pub(crate) const LOG_DIR: &str = "logs/";
+
+/// Describes a complete snapshot in the backup storage.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotManifest {
+    /// The time in milliseconds when the snapshot is complete.
+    ///
+    /// All of the data in it has been written before this time.
+    pub time_ms: u64,
+
+    /// Path of the exported data.
+    pub data_path: String,
+
+    /// The last log id applied to the state machine in the snapshot.
+    pub last_applied: Option<LogId>,
+
+    /// The last log id included in the snapshot.
+    ///
+    /// Logs after it are stored in log segments.
+    pub last_log_id: Option<LogId>,
+
+    /// Number of lines in the exported data.
+    pub records: u64,
+}
+
+impl SnapshotManifest {
+    pub fn data_path(start_ms: u64) -> String {
+        format!("{}{:020}.jsonl", SNAPSHOT_DIR, start_ms)
+    }
+
+    pub fn path(&self) -> String {
+        format!("{}{:020}.json", MANIFEST_DIR, self.time_ms)
+    }
+
+    /// The index of the last log included in the snapshot.
+    pub fn last_index(&self) -> Option<u64> {
+        std::cmp::max(self.last_applied, self.last_log_id).map(|x| x.index)
+    }
+}
+
+/// A series of consecutive committed raft-logs in the backup storage, in range `[first, last]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LogSegment {
+    pub first: u64,
+    pub last: u64,
+}
+
+impl LogSegment {
+    pub fn new(first: u64, last: u64) -> Self {
+        Self { first, last }
+    }
+
+    pub fn path(&self) -> String {
+        format!("{}{:020}-{:020}.jsonl", LOG_DIR, self.first, self.last)
+    }
+
+    /// Parse a segment from the file name, e.g., `00000000000000000001-00000000000000000100.jsonl`
+    pub fn parse(name: &str) -> Option<Self> {
+        let stem = name.strip_suffix(".jsonl")?;
+        let (first, last) = stem.split_once('-')?;
+        let first = first.parse().ok()?;
+        let last = last.parse().ok()?;
+
+        if first > last {
+            return None;
+        }
+
+        Some(Self { first, last })
+    }
+}
@@ -0,0 +1,327 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::io;
+
+use databend_common_meta_raft_store::key_spaces::RaftStoreEntry;
+use databend_common_meta_types::raft_types::Entry;
+use databend_common_meta_types::raft_types::EntryPayload;
+use databend_common_meta_types::raft_types::LogId;
+use databend_common_meta_types::raft_types::Vote;
+
+use crate::backup::BackupStorage;
+use crate::backup::LogSegment;
+use crate::backup::SnapshotManifest;
+
+/// Lines in the format of `databend-metactl export` that rebuild meta data at a point in time.
+#[derive(Debug)]
+pub struct RestorePlan {
+    /// The snapshot the restore starts from.
+    pub snapshot: SnapshotManifest,
+
+    /// The log segments applied after the snapshot.
+    pub segments: Vec<LogSegment>,
+
+    /// The last log id that is restored.
+    pub last_log_id: Option<LogId>,
+
+    /// The exported data to import.
+    pub lines: Vec<String>,
+}
+
+impl RestorePlan {
+    /// Build a plan to restore meta data to the state at `to_time_ms`, or to the latest if it is `None`.
+    ///
+    /// It starts from the last snapshot completed before `to_time_ms`,
+    /// and includes logs until the first one proposed after `to_time_ms`.
+    pub async fn build(
+        storage: &BackupStorage,
+        to_time_ms: Option<u64>,
+    ) -> Result<Self, io::Error> {
+        let to_time_ms = to_time_ms.unwrap_or(u64::MAX);
+
+        let manifests = storage.list_manifests().await?;
+        let snapshot = manifests
+            .into_iter()
+            .rev()
+            .find(|m| m.time_ms <= to_time_ms)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("no backup snapshot completed before {}", to_time_ms),
+                )
+            })?;
+
+        let mut header = vec![];
+        let mut node_id = None;
+        let mut vote = None;
+        let mut purged = None;
+        let mut sm_lines = vec![];
+        let mut logs = BTreeMap::new();
+
+        for line in storage.read_lines(&snapshot.data_path).await? {
+            let (tree_name, entry): (String, RaftStoreEntry) = serde_json::from_str(&line)?;
+
+            match entry {
+                RaftStoreEntry::DataHeader { .. } => header.push(line),
+                RaftStoreEntry::NodeId(x) => node_id = x,
+                RaftStoreEntry::Vote(x) => vote = x,
+                RaftStoreEntry::Purged(x) => purged = x,
+                // Committed is rebuilt according to the logs to restore.
+                RaftStoreEntry::Committed(_) => {}
+                RaftStoreEntry::LogEntry(ent) => {
+                    logs.insert(ent.log_id.index, ent);
+                }
+                _ => {
+                    if tree_name.starts_with("state_machine/") {
+                        sm_lines.push(line);
+                    }
+                }
+            }
+        }
+
+        let base_index = snapshot.last_index();
+        let segments = storage
+            .list_log_segments()
+            .await?
+            .into_iter()
+            .filter(|seg| Some(seg.last) > base_index)
+            .collect::<Vec<_>>();
+
+        let mut used_segments = vec![];
+        for seg in segments {
+            let mut after_target = false;
+
+            for line in storage.read_lines(&seg.path()).await? {
+                let (_, entry): (String, RaftStoreEntry) = serde_json::from_str(&line)?;
+                let RaftStoreEntry::LogEntry(ent) = entry else {
+                    return Err(invalid_data(format!(
+                        "log segment {} contains non-log entry: {}",
+                        seg.path(),
+                        line
+                    )));
+                };
+
+                after_target |= proposed_time(&ent).is_some_and(|t| t > to_time_ms);
+                logs.insert(ent.log_id.index, ent);
+            }
+
+            used_segments.push(seg);
+
+            // Later segments contain only logs proposed after the target time.
+            if after_target {
+                break;
+            }
+        }
+
+        // Logs applied to the snapshot are always restored, no matter when they are proposed.
+        let applied_index = snapshot.last_applied.map(|x| x.index);
+        let purged_index = purged.map(|x| x.index);
+
+        let mut restored: Vec<Entry> = vec![];
+        for (index, ent) in logs.range(purged_index.map_or(0, |x| x + 1)..) {
+            let expected = restored.last().map(|x| x.log_id.index + 1);
+            let in_snapshot = Some(*index) <= applied_index;
+
+            if expected.is_some() && expected != Some(*index) {
+                let proposed_before_target = logs
+                    .range(index..)
+                    .any(|(_, x)| proposed_time(x).is_some_and(|t| t <= to_time_ms));
+
+                if in_snapshot || proposed_before_target {
+                    return Err(invalid_data(format!(
+                        "missing logs in range [{}, {}) in backup",
+                        expected.unwrap(),
+                        index
+                    )));
+                }
+                break;
+            }
+
+            if !in_snapshot && proposed_time(ent).is_some_and(|t| t > to_time_ms) {
+                break;
+            }
+
+            restored.push(ent.clone());
+        }
+
+        let last_log_id = std::cmp::max(restored.last().map(|x| x.log_id), snapshot.last_applied);
+
+        // The vote must not be smaller than the leader of any restored log.
+        let last_leader_vote =
+            last_log_id.map(|x| Vote::new_committed(x.leader_id.term, x.leader_id.node_id));
+        let vote = match (vote, last_leader_vote) {
+            (Some(v), Some(l)) if v >= l => Some(v),
+            (v, None) => v,
+            (_, l) => l,
+        };
+
+        let mut lines = header;
+        lines.push(encode_raft_log(&RaftStoreEntry::NodeId(node_id))?);
+        lines.push(encode_raft_log(&RaftStoreEntry::Vote(vote))?);
+        lines.push(encode_raft_log(&RaftStoreEntry::Purged(purged))?);
+        for ent in restored {
+            lines.push(encode_raft_log(&RaftStoreEntry::LogEntry(ent))?);
+        }
+        lines.push(encode_raft_log(&RaftStoreEntry::Committed(last_log_id))?);
+        lines.extend(sm_lines);
+
+        Ok(Self {
+            snapshot,
+            segments: used_segments,
+            last_log_id,
+            lines,
+        })
+    }
+}
+
+/// The result of checking the integrity of backups.
+#[derive(Debug, Default)]
+pub struct VerifyReport {
+    pub snapshots: Vec<SnapshotManifest>,
+    pub segments: Vec<LogSegment>,
+
+    /// Ranges `[start, end)` of logs that are not backed up,
+    /// points in time in these ranges can not be restored.
+    pub gaps: Vec<(u64, u64)>,
+
+    /// Damaged snapshots or log segments.
+    pub errors: Vec<String>,
+}
+
+impl VerifyReport {
+    /// Check every snapshot and log segment can be read and logs are continuous since the first snapshot.
+    pub async fn build(storage: &BackupStorage) -> Result<Self, io::Error> {
+        let mut report = VerifyReport {
+            snapshots: storage.list_manifests().await?,
+            segments: storage.list_log_segments().await?,
+            ..Default::default()
+        };
+
+        for m in report.snapshots.iter() {
+            if let Err(e) = Self::check_snapshot(storage, m).await {
+                report
+                    .errors
+                    .push(format!("snapshot {}: {}", m.data_path, e));
+            }
+        }
+
+        for seg in report.segments.iter() {
+            if let Err(e) = Self::check_log_segment(storage, seg).await {
+                report
+                    .errors
+                    .push(format!("log segment {}: {}", seg.path(), e));
+            }
+        }
+
+        if let Some(first) = report.snapshots.first() {
+            let mut next = first.last_index().map_or(0, |x| x + 1);
+
+            for seg in report.segments.iter() {
+                if seg.last < next {
+                    continue;
+                }
+                if seg.first > next {
+                    report.gaps.push((next, seg.first));
+                }
+                next = seg.last + 1;
+            }
+        }
+
+        Ok(report)
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    async fn check_snapshot(
+        storage: &BackupStorage,
+        manifest: &SnapshotManifest,
+    ) -> Result<(), io::Error> {
+        let lines = storage.read_lines(&manifest.data_path).await?;
+
+        if lines.len() as u64 != manifest.records {
+            return Err(invalid_data(format!(
+                "expect {} records, but got {}",
+                manifest.records,
+                lines.len()
+            )));
+        }
+
+        for (i, line) in lines.iter().enumerate() {
+            let (_, entry): (String, RaftStoreEntry) = serde_json::from_str(line)?;
+
+            if i == 0 && !matches!(entry, RaftStoreEntry::DataHeader { .. }) {
+                return Err(invalid_data("the first record is not a data header"));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn check_log_segment(
+        storage: &BackupStorage,
+        segment: &LogSegment,
+    ) -> Result<(), io::Error> {
+        let lines = storage.read_lines(&segment.path()).await?;
+
+        let mut expected = segment.first;
+        for line in lines.iter() {
+            let (_, entry): (String, RaftStoreEntry) = serde_json::from_str(line)?;
+
+            let RaftStoreEntry::LogEntry(ent) = entry else {
+                return Err(invalid_data(format!("non-log entry: {}", line)));
+            };
+
+            if ent.log_id.index != expected {
+                return Err(invalid_data(format!(
+                    "expect log index {}, but got {}",
+                    expected, ent.log_id.index
+                )));
+            }
+            expected += 1;
+        }
+
+        if expected != segment.last + 1 {
+            return Err(invalid_data(format!(
+                "expect {} logs, but got {}",
+                segment.last - segment.first + 1,
+                lines.len()
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// The time when a log is proposed by the leader.
+///
+/// Logs without a time, such as blank logs and membership logs, return `None`.
+fn proposed_time(ent: &Entry) -> Option<u64> {
+    match &ent.payload {
+        EntryPayload::Normal(log_entry) => log_entry.time_ms,
+        _ => None,
+    }
+}
+
+fn encode_raft_log(entry: &RaftStoreEntry) -> Result<String, io::Error> {
+    let line = serde_json::to_string(&("raft_log", entry))?;
+    Ok(line)
+}
+
+fn invalid_data(msg: impl ToString) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.to_string())
+}
@@ -14,6 +14,7 @@
 
 use std::net::SocketAddr;
 
+use databend_common_meta_app::storage::StorageParams;
 use databend_common_meta_raft_store::config::RaftConfig;
 use databend_common_meta_types::MetaStartupError;
 use databend_common_meta_types::Node;
@@ -41,6 +42,8 @@ pub struct Config {
     pub grpc_tls_server_cert: String,
     pub grpc_tls_server_key: String,
     pub raft_config: RaftConfig,
+    pub backup: BackupConfig,
+    pub tenant_quota: TenantQuotaConfig,
 }
 
 impl Default for Config {
@@ -63,6 +66,8 @@ impl Default for Config {
             grpc_tls_server_cert: "".to_string(),
             grpc_tls_server_key: "".to_string(),
             raft_config: Default::default(),
+            backup: Default::default(),
+            tenant_quota: Default::default(),
         }
     }
 }
@@ -133,3 +138,64 @@ impl Config {
         !self.grpc_tls_server_key.is_empty() && !self.grpc_tls_server_cert.is_empty()
     }
 }
+
+/// Config of continuously backing up meta data to an object storage.
+///
+/// The leader periodically uploads a full snapshot and the raft-logs committed since then,
+/// with which `databend-metactl restore` rebuilds a cluster at a chosen point in time.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+pub struct BackupConfig {
+    /// The location to upload backups to.
+    ///
+    /// `StorageParams::None` disables continuous backup.
+    pub storage: StorageParams,
+
+    /// The interval in seconds to upload a full snapshot.
+    pub snapshot_interval: u64,
+
+    /// The interval in seconds to upload the raft-logs committed since the last upload.
+    pub log_interval: u64,
+
+    /// The time in seconds that a point in time remains restorable.
+    ///
+    /// Snapshots and raft-logs no longer required to restore to a point in this period are removed.
+    pub retention: u64,
+}
+
+impl Default for BackupConfig {
+    fn default() -> Self {
+        Self {
+            storage: StorageParams::None,
+            snapshot_interval: 3600,
+            log_interval: 60,
+            retention: 7 * 24 * 3600,
+        }
+    }
+}
+
+impl BackupConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.storage != StorageParams::None
+    }
+}
+
+/// Limits applied to every tenant, enforced by the leader before proposing a write.
+///
+/// `0` means unlimited.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TenantQuotaConfig {
+    /// The max number of keys a tenant can have.
+    pub max_keys: u64,
+
+    /// The max total size in bytes of the values of a tenant.
+    pub max_value_bytes: u64,
+
+    /// The max number of write requests per second of a tenant.
+    pub max_write_qps: u64,
+}
+
+impl TenantQuotaConfig {
+    pub fn is_unlimited(&self) -> bool {
+        self.max_keys == 0 && self.max_value_bytes == 0 && self.max_write_qps == 0
+    }
+}
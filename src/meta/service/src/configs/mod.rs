@@ -15,4 +15,6 @@
 mod inner;
 mod outer_v0;
 
+pub use inner::BackupConfig;
 pub use inner::Config;
+pub use inner::TenantQuotaConfig;
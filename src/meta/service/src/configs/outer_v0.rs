@@ -17,6 +17,10 @@ use std::env;
 use clap::ArgAction;
 use clap::Args;
 use clap::Parser;
+use databend_common_meta_app::storage::StorageFsConfig;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_meta_app::storage::StorageS3Config;
+use databend_common_meta_app::storage::STORAGE_S3_DEFAULT_ENDPOINT;
 use databend_common_meta_raft_store::config::get_default_raft_advertise_host;
 use databend_common_meta_raft_store::config::RaftConfig as InnerRaftConfig;
 use databend_common_meta_types::MetaStartupError;
@@ -35,7 +39,9 @@ use serfig::collectors::from_file;
 use serfig::collectors::from_self;
 use serfig::parsers::Toml;
 
+use super::inner::BackupConfig as InnerBackupConfig;
 use super::inner::Config as InnerConfig;
+use super::inner::TenantQuotaConfig as InnerTenantQuotaConfig;
 use crate::version::METASRV_COMMIT_VERSION;
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Parser)]
@@ -130,6 +136,12 @@ pub struct Config {
 
     #[clap(flatten)]
     pub raft_config: RaftConfig,
+
+    #[clap(flatten)]
+    pub backup: BackupConfig,
+
+    #[clap(flatten)]
+    pub tenant_quota: TenantQuotaConfig,
 }
 
 impl Default for Config {
@@ -166,6 +178,8 @@ impl From<Config> for InnerConfig {
             grpc_tls_server_cert: outer.grpc_tls_server_cert,
             grpc_tls_server_key: outer.grpc_tls_server_key,
             raft_config: outer.raft_config.into(),
+            backup: outer.backup.into(),
+            tenant_quota: outer.tenant_quota.into(),
         }
     }
 }
@@ -192,6 +206,8 @@ impl From<InnerConfig> for Config {
             grpc_tls_server_cert: inner.grpc_tls_server_cert,
             grpc_tls_server_key: inner.grpc_tls_server_key,
             raft_config: inner.raft_config.into(),
+            backup: inner.backup.into(),
+            tenant_quota: inner.tenant_quota.into(),
         }
     }
 }
@@ -248,9 +264,13 @@ impl Config {
             builder = builder.collect(from_self(arg_conf));
         }
 
-        builder
+        let cfg = builder
             .build()
-            .map_err(|e| MetaStartupError::InvalidConfig(e.to_string()))
+            .map_err(|e| MetaStartupError::InvalidConfig(e.to_string()))?;
+
+        cfg.backup.check()?;
+
+        Ok(cfg)
     }
 }
 
@@ -309,6 +329,22 @@ pub struct ConfigViaEnv {
     pub sled_tree_prefix: String,
     pub sled_max_cache_size_mb: u64,
     pub cluster_name: String,
+
+    pub metasrv_backup_storage_type: String,
+    pub metasrv_backup_fs_root: String,
+    pub metasrv_backup_s3_endpoint_url: String,
+    pub metasrv_backup_s3_region: String,
+    pub metasrv_backup_s3_bucket: String,
+    pub metasrv_backup_s3_root: String,
+    pub metasrv_backup_s3_access_key_id: String,
+    pub metasrv_backup_s3_secret_access_key: String,
+    pub metasrv_backup_snapshot_interval: u64,
+    pub metasrv_backup_log_interval: u64,
+    pub metasrv_backup_retention: u64,
+
+    pub metasrv_tenant_quota_max_keys: u64,
+    pub metasrv_tenant_quota_max_value_bytes: u64,
+    pub metasrv_tenant_quota_max_write_qps: u64,
 }
 
 impl Default for ConfigViaEnv {
@@ -368,6 +404,22 @@ impl From<Config> for ConfigViaEnv {
             sled_tree_prefix: cfg.raft_config.sled_tree_prefix,
             sled_max_cache_size_mb: cfg.raft_config.sled_max_cache_size_mb,
             cluster_name: cfg.raft_config.cluster_name,
+
+            metasrv_backup_storage_type: cfg.backup.backup_storage_type,
+            metasrv_backup_fs_root: cfg.backup.backup_fs_root,
+            metasrv_backup_s3_endpoint_url: cfg.backup.backup_s3_endpoint_url,
+            metasrv_backup_s3_region: cfg.backup.backup_s3_region,
+            metasrv_backup_s3_bucket: cfg.backup.backup_s3_bucket,
+            metasrv_backup_s3_root: cfg.backup.backup_s3_root,
+            metasrv_backup_s3_access_key_id: cfg.backup.backup_s3_access_key_id,
+            metasrv_backup_s3_secret_access_key: cfg.backup.backup_s3_secret_access_key,
+            metasrv_backup_snapshot_interval: cfg.backup.backup_snapshot_interval,
+            metasrv_backup_log_interval: cfg.backup.backup_log_interval,
+            metasrv_backup_retention: cfg.backup.backup_retention,
+
+            metasrv_tenant_quota_max_keys: cfg.tenant_quota.tenant_quota_max_keys,
+            metasrv_tenant_quota_max_value_bytes: cfg.tenant_quota.tenant_quota_max_value_bytes,
+            metasrv_tenant_quota_max_write_qps: cfg.tenant_quota.tenant_quota_max_write_qps,
         }
     }
 }
@@ -412,6 +464,24 @@ impl Into<Config> for ConfigViaEnv {
             sled_max_cache_size_mb: self.sled_max_cache_size_mb,
             cluster_name: self.cluster_name,
         };
+        let backup = BackupConfig {
+            backup_storage_type: self.metasrv_backup_storage_type,
+            backup_fs_root: self.metasrv_backup_fs_root,
+            backup_s3_endpoint_url: self.metasrv_backup_s3_endpoint_url,
+            backup_s3_region: self.metasrv_backup_s3_region,
+            backup_s3_bucket: self.metasrv_backup_s3_bucket,
+            backup_s3_root: self.metasrv_backup_s3_root,
+            backup_s3_access_key_id: self.metasrv_backup_s3_access_key_id,
+            backup_s3_secret_access_key: self.metasrv_backup_s3_secret_access_key,
+            backup_snapshot_interval: self.metasrv_backup_snapshot_interval,
+            backup_log_interval: self.metasrv_backup_log_interval,
+            backup_retention: self.metasrv_backup_retention,
+        };
+        let tenant_quota = TenantQuotaConfig {
+            tenant_quota_max_keys: self.metasrv_tenant_quota_max_keys,
+            tenant_quota_max_value_bytes: self.metasrv_tenant_quota_max_value_bytes,
+            tenant_quota_max_write_qps: self.metasrv_tenant_quota_max_write_qps,
+        };
         let log_config = LogConfig {
             file: FileLogConfig {
                 file_on: self.metasrv_log_file_on,
@@ -449,6 +519,8 @@ impl Into<Config> for ConfigViaEnv {
             grpc_tls_server_cert: self.grpc_tls_server_cert,
             grpc_tls_server_key: self.grpc_tls_server_key,
             raft_config,
+            backup,
+            tenant_quota,
         }
     }
 }
@@ -685,6 +757,200 @@ impl From<InnerRaftConfig> for RaftConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct BackupConfig {
+    /// The type of the storage to upload backups to: `fs` or `s3`.
+    /// Continuous backup is disabled if it is empty.
+    #[clap(long = "backup-storage-type", default_value = "")]
+    #[serde(rename = "storage_type")]
+    pub backup_storage_type: String,
+
+    /// The root dir to store backups, if the storage type is `fs`.
+    #[clap(long = "backup-fs-root", default_value = "")]
+    #[serde(rename = "fs_root")]
+    pub backup_fs_root: String,
+
+    #[clap(long = "backup-s3-endpoint-url", default_value = STORAGE_S3_DEFAULT_ENDPOINT)]
+    #[serde(rename = "s3_endpoint_url")]
+    pub backup_s3_endpoint_url: String,
+
+    #[clap(long = "backup-s3-region", default_value = "")]
+    #[serde(rename = "s3_region")]
+    pub backup_s3_region: String,
+
+    #[clap(long = "backup-s3-bucket", default_value = "")]
+    #[serde(rename = "s3_bucket")]
+    pub backup_s3_bucket: String,
+
+    /// The path in the bucket to store backups, if the storage type is `s3`.
+    #[clap(long = "backup-s3-root", default_value = "")]
+    #[serde(rename = "s3_root")]
+    pub backup_s3_root: String,
+
+    #[clap(long = "backup-s3-access-key-id", default_value = "")]
+    #[serde(rename = "s3_access_key_id")]
+    pub backup_s3_access_key_id: String,
+
+    #[clap(long = "backup-s3-secret-access-key", default_value = "")]
+    #[serde(rename = "s3_secret_access_key")]
+    pub backup_s3_secret_access_key: String,
+
+    /// The interval in seconds to upload a full snapshot.
+    #[clap(long = "backup-snapshot-interval", default_value = "3600")]
+    #[serde(rename = "snapshot_interval")]
+    pub backup_snapshot_interval: u64,
+
+    /// The interval in seconds to upload the raft-logs committed since the last upload.
+    #[clap(long = "backup-log-interval", default_value = "60")]
+    #[serde(rename = "log_interval")]
+    pub backup_log_interval: u64,
+
+    /// The time in seconds that a point in time remains restorable. By default it is 7 days.
+    #[clap(long = "backup-retention", default_value = "604800")]
+    #[serde(rename = "retention")]
+    pub backup_retention: u64,
+}
+
+impl Default for BackupConfig {
+    fn default() -> Self {
+        InnerBackupConfig::default().into()
+    }
+}
+
+impl BackupConfig {
+    fn check(&self) -> Result<(), MetaStartupError> {
+        match self.backup_storage_type.as_str() {
+            "" | "fs" | "s3" => {}
+            t => {
+                return Err(MetaStartupError::InvalidConfig(format!(
+                    "unsupported backup storage type: {}, expect one of: fs, s3",
+                    t
+                )));
+            }
+        }
+
+        if !self.backup_storage_type.is_empty() && self.backup_log_interval == 0 {
+            return Err(MetaStartupError::InvalidConfig(
+                "backup log_interval must be greater than 0".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+impl From<BackupConfig> for InnerBackupConfig {
+    fn from(x: BackupConfig) -> InnerBackupConfig {
+        let storage = match x.backup_storage_type.as_str() {
+            "fs" => StorageParams::Fs(StorageFsConfig {
+                root: x.backup_fs_root,
+            }),
+            "s3" => StorageParams::S3(StorageS3Config {
+                endpoint_url: x.backup_s3_endpoint_url,
+                region: x.backup_s3_region,
+                bucket: x.backup_s3_bucket,
+                root: x.backup_s3_root,
+                access_key_id: x.backup_s3_access_key_id,
+                secret_access_key: x.backup_s3_secret_access_key,
+                ..Default::default()
+            }),
+            // Other types are rejected by `BackupConfig::check()`
+            _ => StorageParams::None,
+        };
+
+        InnerBackupConfig {
+            storage,
+            snapshot_interval: x.backup_snapshot_interval,
+            log_interval: x.backup_log_interval,
+            retention: x.backup_retention,
+        }
+    }
+}
+
+impl From<InnerBackupConfig> for BackupConfig {
+    fn from(inner: InnerBackupConfig) -> Self {
+        let mut outer = Self {
+            backup_storage_type: "".to_string(),
+            backup_fs_root: "".to_string(),
+            backup_s3_endpoint_url: STORAGE_S3_DEFAULT_ENDPOINT.to_string(),
+            backup_s3_region: "".to_string(),
+            backup_s3_bucket: "".to_string(),
+            backup_s3_root: "".to_string(),
+            backup_s3_access_key_id: "".to_string(),
+            backup_s3_secret_access_key: "".to_string(),
+            backup_snapshot_interval: inner.snapshot_interval,
+            backup_log_interval: inner.log_interval,
+            backup_retention: inner.retention,
+        };
+
+        match inner.storage {
+            StorageParams::Fs(fs) => {
+                outer.backup_storage_type = "fs".to_string();
+                outer.backup_fs_root = fs.root;
+            }
+            StorageParams::S3(s3) => {
+                outer.backup_storage_type = "s3".to_string();
+                outer.backup_s3_endpoint_url = s3.endpoint_url;
+                outer.backup_s3_region = s3.region;
+                outer.backup_s3_bucket = s3.bucket;
+                outer.backup_s3_root = s3.root;
+                outer.backup_s3_access_key_id = s3.access_key_id;
+                outer.backup_s3_secret_access_key = s3.secret_access_key;
+            }
+            _ => {}
+        }
+
+        outer
+    }
+}
+
+/// Per-tenant limits. `0` means unlimited.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default)]
+pub struct TenantQuotaConfig {
+    /// The max number of keys a tenant can have.
+    #[clap(long = "tenant-quota-max-keys", default_value = "0")]
+    #[serde(rename = "max_keys")]
+    pub tenant_quota_max_keys: u64,
+
+    /// The max total size in bytes of the values of a tenant.
+    #[clap(long = "tenant-quota-max-value-bytes", default_value = "0")]
+    #[serde(rename = "max_value_bytes")]
+    pub tenant_quota_max_value_bytes: u64,
+
+    /// The max number of write requests per second of a tenant.
+    #[clap(long = "tenant-quota-max-write-qps", default_value = "0")]
+    #[serde(rename = "max_write_qps")]
+    pub tenant_quota_max_write_qps: u64,
+}
+
+impl Default for TenantQuotaConfig {
+    fn default() -> Self {
+        InnerTenantQuotaConfig::default().into()
+    }
+}
+
+impl From<TenantQuotaConfig> for InnerTenantQuotaConfig {
+    fn from(x: TenantQuotaConfig) -> InnerTenantQuotaConfig {
+        InnerTenantQuotaConfig {
+            max_keys: x.tenant_quota_max_keys,
+            max_value_bytes: x.tenant_quota_max_value_bytes,
+            max_write_qps: x.tenant_quota_max_write_qps,
+        }
+    }
+}
+
+impl From<InnerTenantQuotaConfig> for TenantQuotaConfig {
+    fn from(inner: InnerTenantQuotaConfig) -> Self {
+        Self {
+            tenant_quota_max_keys: inner.max_keys,
+            tenant_quota_max_value_bytes: inner.max_value_bytes,
+            tenant_quota_max_write_qps: inner.max_write_qps,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
 #[serde(default)]
 pub struct LogConfig {
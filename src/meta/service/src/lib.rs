@@ -17,6 +17,7 @@
 #![allow(clippy::uninlined_format_args)]
 
 pub mod api;
+pub mod backup;
 pub mod configs;
 pub mod message;
 pub mod meta_service;
@@ -21,12 +21,15 @@ use databend_common_meta_kvapi::kvapi::ListKVReq;
 use databend_common_meta_kvapi::kvapi::MGetKVReply;
 use databend_common_meta_kvapi::kvapi::MGetKVReq;
 use databend_common_meta_types::protobuf::RaftRequest;
+use databend_common_meta_types::raft_types::LogId;
 use databend_common_meta_types::raft_types::NodeId;
 use databend_common_meta_types::AppliedState;
 use databend_common_meta_types::Endpoint;
 use databend_common_meta_types::LogEntry;
 use databend_common_meta_types::MetaAPIError;
 
+use crate::version::METASRV_SEMVER;
+
 #[derive(serde::Serialize, serde::Deserialize, Debug, Default, Clone, PartialEq, Eq)]
 pub struct JoinRequest {
     pub node_id: NodeId,
@@ -37,6 +40,15 @@ pub struct JoinRequest {
     pub grpc_api_addr: String,
 
     pub grpc_api_advertise_address: Option<String>,
+
+    /// The semver of the joining databend-meta.
+    ///
+    /// It is absent if the joining node is older than this field,
+    /// such a node is rejected because it is older than [`MIN_META_SEMVER`].
+    ///
+    /// [`MIN_META_SEMVER`]: crate::version::MIN_META_SEMVER
+    #[serde(default)]
+    pub version: Option<String>,
 }
 
 impl JoinRequest {
@@ -49,9 +61,15 @@ impl JoinRequest {
             node_id,
             endpoint,
             grpc_api_advertise_address: grpc_api_advertise_address.map(|x| x.to_string()),
+            version: Some(METASRV_SEMVER.to_string()),
             ..Default::default()
         }
     }
+
+    pub fn with_version(mut self, version: Option<impl ToString>) -> Self {
+        self.version = version.map(|x| x.to_string());
+        self
+    }
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -77,6 +95,9 @@ pub enum ForwardRequestBody {
 
     Write(LogEntry),
 
+    /// Get the log id up to which a follower has to apply before serving a linearizable read.
+    ReadIndex,
+
     GetKV(GetKVReq),
     MGetKV(MGetKVReq),
     ListKV(ListKVReq),
@@ -142,6 +163,7 @@ pub enum ForwardResponse {
     Join(()),
     Leave(()),
     AppliedState(AppliedState),
+    ReadIndex(Option<LogId>),
 
     GetKV(GetKVReply),
     MGetKV(MGetKVReply),
@@ -22,7 +22,9 @@ use databend_common_meta_raft_store::sm_v003::SMV003;
 use databend_common_meta_sled_store::openraft::ChangeMembers;
 use databend_common_meta_stoerr::MetaStorageError;
 use databend_common_meta_types::protobuf::StreamItem;
+use databend_common_meta_types::raft_types::CheckIsLeaderError;
 use databend_common_meta_types::raft_types::ClientWriteError;
+use databend_common_meta_types::raft_types::LogId;
 use databend_common_meta_types::raft_types::MembershipNode;
 use databend_common_meta_types::raft_types::NodeId;
 use databend_common_meta_types::raft_types::RaftError;
@@ -40,6 +42,7 @@ use log::debug;
 use log::info;
 use maplit::btreemap;
 use maplit::btreeset;
+use semver::Version;
 use tonic::codegen::BoxStream;
 
 use crate::message::ForwardRequest;
@@ -48,11 +51,13 @@ use crate::message::ForwardResponse;
 use crate::message::JoinRequest;
 use crate::message::LeaveRequest;
 use crate::meta_service::meta_node::MetaRaft;
+use crate::meta_service::tenant_quota::TenantQuota;
 use crate::meta_service::MetaNode;
 use crate::metrics::server_metrics;
 use crate::metrics::ProposalPending;
 use crate::request_handling::Handler;
 use crate::store::RaftStore;
+use crate::version::MIN_META_SEMVER;
 
 /// The container of APIs of the leader in a meta service cluster.
 ///
@@ -61,6 +66,7 @@ use crate::store::RaftStore;
 pub struct MetaLeader<'a> {
     sto: &'a RaftStore,
     raft: &'a MetaRaft,
+    tenant_quota: &'a TenantQuota,
 }
 
 #[async_trait::async_trait]
@@ -84,9 +90,14 @@ impl<'a> Handler<ForwardRequestBody> for MetaLeader<'a> {
                 Ok(ForwardResponse::Leave(()))
             }
             ForwardRequestBody::Write(entry) => {
+                self.check_tenant_quota(&entry).await?;
                 let res = self.write(entry.clone()).await?;
                 Ok(ForwardResponse::AppliedState(res))
             }
+            ForwardRequestBody::ReadIndex => {
+                let read_log_id = self.read_index().await?;
+                Ok(ForwardResponse::ReadIndex(read_log_id))
+            }
 
             ForwardRequestBody::GetKV(req) => {
                 let sm = self.get_state_machine().await;
@@ -117,45 +128,50 @@ impl<'a> Handler<MetaGrpcReadReq> for MetaLeader<'a> {
         debug!(req :? =(&req); "handle(MetaGrpcReadReq)");
 
         let sm = self.get_state_machine().await;
-        let kv_api = sm.kv_api();
+        Ok(read_state_machine(&sm, req.body).await)
+    }
+}
 
-        match req.body {
-            MetaGrpcReadReq::GetKV(req) => {
-                // safe unwrap(): Infallible
-                let got = kv_api.get_kv(&req.key).await.unwrap();
+/// Serve a read request with the local state machine.
+pub(crate) async fn read_state_machine(sm: &SMV003, req: MetaGrpcReadReq) -> BoxStream<StreamItem> {
+    let kv_api = sm.kv_api();
 
-                let item = StreamItem::from((req.key.clone(), got));
-                let strm = futures::stream::iter([Ok(item)]);
+    match req {
+        MetaGrpcReadReq::GetKV(req) => {
+            // safe unwrap(): Infallible
+            let got = kv_api.get_kv(&req.key).await.unwrap();
 
-                Ok(strm.boxed())
-            }
+            let item = StreamItem::from((req.key.clone(), got));
+            let strm = futures::stream::iter([Ok(item)]);
+
+            strm.boxed()
+        }
 
-            MetaGrpcReadReq::MGetKV(req) => {
-                // safe unwrap(): Infallible
-                let values = kv_api.mget_kv(&req.keys).await.unwrap();
+        MetaGrpcReadReq::MGetKV(req) => {
+            // safe unwrap(): Infallible
+            let values = kv_api.mget_kv(&req.keys).await.unwrap();
 
-                let kv_iter = req
-                    .keys
-                    .clone()
-                    .into_iter()
-                    .zip(values)
-                    .map(|(k, v)| Ok(StreamItem::from((k, v))));
+            let kv_iter = req
+                .keys
+                .clone()
+                .into_iter()
+                .zip(values)
+                .map(|(k, v)| Ok(StreamItem::from((k, v))));
 
-                let strm = futures::stream::iter(kv_iter);
+            let strm = futures::stream::iter(kv_iter);
 
-                Ok(strm.boxed())
-            }
+            strm.boxed()
+        }
 
-            MetaGrpcReadReq::ListKV(req) => {
-                // safe unwrap(): Infallible
-                let kvs = kv_api.prefix_list_kv(&req.prefix).await.unwrap();
+        MetaGrpcReadReq::ListKV(req) => {
+            // safe unwrap(): Infallible
+            let kvs = kv_api.prefix_list_kv(&req.prefix).await.unwrap();
 
-                let kv_iter = kvs.into_iter().map(|kv| Ok(StreamItem::from(kv)));
+            let kv_iter = kvs.into_iter().map(|kv| Ok(StreamItem::from(kv)));
 
-                let strm = futures::stream::iter(kv_iter);
+            let strm = futures::stream::iter(kv_iter);
 
-                Ok(strm.boxed())
-            }
+            strm.boxed()
         }
     }
 }
@@ -165,6 +181,7 @@ impl<'a> MetaLeader<'a> {
         MetaLeader {
             sto: &meta_node.sto,
             raft: &meta_node.raft,
+            tenant_quota: &meta_node.tenant_quota,
         }
     }
 
@@ -174,8 +191,11 @@ impl<'a> MetaLeader<'a> {
     /// - Adds the node to membership to let it become a voter.
     ///
     /// If the node is already in cluster membership, it still returns Ok.
+    /// A node older than [`MIN_META_SEMVER`] is rejected.
     #[fastrace::trace]
-    pub async fn join(&self, req: JoinRequest) -> Result<(), RaftError<ClientWriteError>> {
+    pub async fn join(&self, req: JoinRequest) -> Result<(), MetaOperationError> {
+        Self::ensure_joinable_version(&req)?;
+
         let node_id = req.node_id;
         let endpoint = req.endpoint;
         let metrics = self.raft.metrics().borrow().clone();
@@ -208,6 +228,38 @@ impl<'a> MetaLeader<'a> {
         Ok(())
     }
 
+    /// Check the version of a joining node against [`MIN_META_SEMVER`].
+    ///
+    /// Only `major.minor.patch` is compared, a nightly pre-release is not older than its release.
+    fn ensure_joinable_version(req: &JoinRequest) -> Result<(), MetaDataReadError> {
+        let version = req.version.as_deref().and_then(|v| {
+            let v = v.strip_prefix('v').unwrap_or(v);
+            Version::parse(v).ok()
+        });
+
+        let compatible = version.as_ref().is_some_and(|v| {
+            (v.major, v.minor, v.patch)
+                >= (
+                    MIN_META_SEMVER.major,
+                    MIN_META_SEMVER.minor,
+                    MIN_META_SEMVER.patch,
+                )
+        });
+
+        if compatible {
+            return Ok(());
+        }
+
+        Err(MetaDataReadError::new(
+            "join",
+            format!(
+                "node-{} version {:?} is older than the min compatible version {}",
+                req.node_id, req.version, MIN_META_SEMVER
+            ),
+            &AnyError::error("incompatible meta version"),
+        ))
+    }
+
     /// A node leave the cluster.
     ///
     /// - Remove the node from membership.
@@ -255,6 +307,26 @@ impl<'a> MetaLeader<'a> {
         Ok(())
     }
 
+    /// Check the per-tenant quota before proposing a write.
+    ///
+    /// A rejected write is not proposed and the violation is returned to the client.
+    #[fastrace::trace]
+    pub async fn check_tenant_quota(&self, entry: &LogEntry) -> Result<(), MetaOperationError> {
+        if self.tenant_quota.limits().is_unlimited() {
+            return Ok(());
+        }
+
+        let sm = self.get_state_machine().await;
+        self.tenant_quota
+            .check_write(&sm, &entry.cmd, SeqV::<()>::now_ms())
+            .await
+            .map_err(|e| {
+                MetaDataError::ReadError(MetaDataReadError::new("check_tenant_quota()", "", &e))
+            })??;
+
+        Ok(())
+    }
+
     /// Write a log through local raft node and return the states before and after applying the log.
     ///
     /// If the raft node is not a leader, it returns MetaRaftError::ForwardToLeader.
@@ -288,6 +360,15 @@ impl<'a> MetaLeader<'a> {
         }
     }
 
+    /// Returns the log id a linearizable read has to wait to be applied, after confirming
+    /// with a quorum that this node is still the leader.
+    #[fastrace::trace]
+    pub async fn read_index(&self) -> Result<Option<LogId>, RaftError<CheckIsLeaderError>> {
+        let read_log_id = self.raft.ensure_linearizable().await?;
+        debug!("read_index: {:?}", read_log_id);
+        Ok(read_log_id)
+    }
+
     /// Check if a node is allowed to leave the cluster.
     ///
     /// A cluster must have at least one node in it.
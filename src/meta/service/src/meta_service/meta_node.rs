@@ -12,8 +12,10 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::net::Ipv4Addr;
+use std::ops::Bound;
 use std::sync::atomic::AtomicI32;
 use std::sync::Arc;
 use std::time::Duration;
@@ -32,12 +34,14 @@ use databend_common_meta_client::reply_to_api_result;
 use databend_common_meta_client::RequestFor;
 use databend_common_meta_raft_store::config::RaftConfig;
 use databend_common_meta_raft_store::ondisk::DATA_VERSION;
+use databend_common_meta_raft_store::state_machine::StateMachineSubscriber;
 use databend_common_meta_sled_store::openraft;
 use databend_common_meta_sled_store::openraft::ChangeMembers;
 use databend_common_meta_stoerr::MetaStorageError;
 use databend_common_meta_types::protobuf::raft_service_client::RaftServiceClient;
 use databend_common_meta_types::protobuf::raft_service_server::RaftServiceServer;
 use databend_common_meta_types::protobuf::WatchRequest;
+use databend_common_meta_types::protobuf::WatchResponse;
 use databend_common_meta_types::raft_types::CommittedLeaderId;
 use databend_common_meta_types::raft_types::ForwardToLeader;
 use databend_common_meta_types::raft_types::LogId;
@@ -58,9 +62,12 @@ use databend_common_meta_types::MetaNetworkError;
 use databend_common_meta_types::MetaOperationError;
 use databend_common_meta_types::MetaStartupError;
 use databend_common_meta_types::Node;
+use databend_common_meta_types::SeqV;
+use databend_common_meta_types::SeqValue;
 use fastrace::func_name;
 use fastrace::prelude::*;
 use futures::channel::oneshot;
+use futures::TryStreamExt;
 use itertools::Itertools;
 use log::debug;
 use log::error;
@@ -71,7 +78,9 @@ use openraft::Config;
 use openraft::Raft;
 use openraft::ServerState;
 use openraft::SnapshotPolicy;
+use tonic::Status;
 
+use crate::backup::BackupWorker;
 use crate::configs::Config as MetaConfig;
 use crate::message::ForwardRequest;
 use crate::message::ForwardRequestBody;
@@ -82,6 +91,9 @@ use crate::meta_service::errors::grpc_error_to_network_err;
 use crate::meta_service::forwarder::MetaForwarder;
 use crate::meta_service::meta_leader::MetaLeader;
 use crate::meta_service::meta_node_status::MetaNodeStatus;
+use crate::meta_service::tenant_quota::TenantQuota;
+use crate::meta_service::tenant_quota::TenantUsage;
+use crate::meta_service::tenant_quota::UsageTrackingSubscriber;
 use crate::meta_service::RaftServiceImpl;
 use crate::metrics::server_metrics;
 use crate::network::NetworkFactory;
@@ -102,11 +114,61 @@ pub type SMStore = RaftStore;
 /// MetaRaft is a implementation of the generic Raft handling meta data R/W.
 pub type MetaRaft = Raft<TypeConfig>;
 
+/// The max number of current values read at a time by a watcher `initial_flush`.
+pub(crate) const INITIAL_FLUSH_BATCH_SIZE: usize = 1_000;
+
+/// The progress of an `initial_flush` of a range, read in several batches.
+struct InitialFlush {
+    /// The watch history index before reading the first batch.
+    history_index: u64,
+
+    /// The last key of every batch and the seq of the state machine when the batch is read.
+    batches: Vec<(String, u64)>,
+
+    /// The seq of every key that has been sent to the watcher.
+    seen: BTreeMap<String, u64>,
+}
+
+impl InitialFlush {
+    /// Check if a change replayed from the watch history is not yet reflected in the sent values.
+    ///
+    /// An update is reflected if it has a seq not greater than the seq its batch is read at.
+    /// A delete is outdated if it removes a value older than the one sent.
+    fn is_newer(&mut self, resp: &WatchResponse) -> bool {
+        let Some(event) = &resp.event else {
+            return true;
+        };
+
+        match &event.current {
+            Some(current) => {
+                let i = self.batches.partition_point(|(end, _)| end < &event.key);
+                let read_seq = self.batches.get(i).map(|(_, seq)| *seq).unwrap_or(0);
+                if current.seq <= read_seq {
+                    return false;
+                }
+                self.seen.insert(event.key.clone(), current.seq);
+                true
+            }
+            None => {
+                let prev_seq = event.prev.as_ref().map(|p| p.seq).unwrap_or(0);
+                if let Some(seen_seq) = self.seen.get(&event.key) {
+                    if prev_seq < *seen_seq {
+                        return false;
+                    }
+                }
+                self.seen.remove(&event.key);
+                true
+            }
+        }
+    }
+}
+
 /// MetaNode is the container of meta data related components and threads, such as storage, the raft node and a raft-state monitor.
 pub struct MetaNode {
     pub sto: RaftStore,
     pub dispatcher_handle: EventDispatcherHandle,
     pub raft: MetaRaft,
+    pub tenant_quota: Arc<TenantQuota>,
     pub running_tx: watch::Sender<()>,
     pub running_rx: watch::Receiver<()>,
     pub join_handles: Mutex<Vec<JoinHandle<Result<(), AnyError>>>>,
@@ -164,15 +226,24 @@ impl MetaNodeBuilder {
         let (tx, rx) = watch::channel::<()>(());
 
         let dispatcher_tx = EventDispatcher::spawn();
+        let tenant_quota = Arc::new(TenantQuota::default());
 
-        sto.get_state_machine()
-            .await
-            .set_subscriber(Box::new(DispatcherSender(dispatcher_tx.clone())));
+        {
+            let mut sm = sto.get_state_machine().await;
+            let subscriber = UsageTrackingSubscriber {
+                quota: tenant_quota.clone(),
+                inner: Box::new(DispatcherSender(dispatcher_tx.clone())),
+            };
+            // Changes applied before subscribing are not in the watch history.
+            subscriber.data_replaced(sm.sys_data_ref().curr_seq());
+            sm.set_subscriber(Box::new(subscriber));
+        }
 
         let meta_node = Arc::new(MetaNode {
             sto: sto.clone(),
             dispatcher_handle: EventDispatcherHandle::new(dispatcher_tx),
             raft: raft.clone(),
+            tenant_quota,
             running_tx: tx,
             running_rx: rx,
             join_handles: Mutex::new(Vec::new()),
@@ -738,13 +809,17 @@ impl MetaNode {
     async fn do_start(conf: &MetaConfig) -> Result<Arc<MetaNode>, MetaStartupError> {
         let raft_conf = &conf.raft_config;
 
+        let mn = MetaNode::open(raft_conf).await?;
+        mn.tenant_quota.set_limits(conf.tenant_quota.clone());
+
         if raft_conf.single {
-            let mn = MetaNode::open(raft_conf).await?;
             mn.init_cluster(conf.get_node()).await?;
-            return Ok(mn);
         }
 
-        let mn = MetaNode::open(raft_conf).await?;
+        if conf.backup.is_enabled() {
+            BackupWorker::spawn(mn.clone(), &conf.backup).await?;
+        }
+
         Ok(mn)
     }
 
@@ -950,6 +1025,9 @@ impl MetaNode {
                 MetaOperationError::DataError(d_err) => {
                     return Err(d_err.into());
                 }
+                MetaOperationError::QuotaExceeded(q_err) => {
+                    return Err(q_err.into());
+                }
             };
 
             let leader_id = to_leader.leader_id.ok_or_else(|| {
@@ -1079,6 +1157,19 @@ impl MetaNode {
         Ok(res)
     }
 
+    /// Returns the keys, value bytes and write rate of every tenant, in the local state machine.
+    ///
+    /// The write rate is only collected on the leader.
+    pub async fn tenant_usage(&self) -> Result<BTreeMap<String, TenantUsage>, MetaStorageError> {
+        let sm = self.sto.state_machine.read().await;
+        let usage = self
+            .tenant_quota
+            .usage(&sm, SeqV::<()>::now_ms())
+            .await
+            .map_err(|e| MetaStorageError::damaged(&e, || "collect tenant usage"))?;
+        Ok(usage)
+    }
+
     /// Try to get the leader from the latest metrics of the local raft node.
     /// If leader is absent, wait for an metrics update in which a leader is set.
     #[fastrace::trace]
@@ -1108,22 +1199,152 @@ impl MetaNode {
         }
     }
 
+    /// Add a watcher and returns the events to send before new change events:
+    /// the current values if `initial_flush` is requested,
+    /// or the changes since `start_seq` if it is specified.
+    ///
+    /// The current values of a range are read in batches of [`INITIAL_FLUSH_BATCH_SIZE`],
+    /// the state machine is only held while reading one batch so that applying logs is not blocked.
+    /// The changes applied meanwhile are replayed from the watch history,
+    /// except those already reflected in the values read.
     pub(crate) async fn add_watcher(
         &self,
         request: WatchRequest,
         tx: WatcherSender,
-    ) -> Result<Watcher, &'static str> {
+    ) -> Result<(Watcher, Vec<WatchResponse>), Status> {
+        if request.initial_flush && request.start_seq.is_some() {
+            return Err(Status::invalid_argument(
+                "initial_flush and start_seq can not be used together",
+            ));
+        }
+
+        let mut initial = vec![];
+        let mut flush = None;
+
+        if request.initial_flush {
+            if let Some(key_end) = request.key_end.clone().filter(|k| k > &request.key) {
+                flush = Some(
+                    self.flush_range(&request.key, key_end, &mut initial)
+                        .await?,
+                );
+            }
+        }
+
+        // Hold the state machine so that no change is applied between reading the current value
+        // and adding the watcher: a change is either reflected in the value read,
+        // or is dispatched after the watcher is added.
+        let sm = self.sto.get_state_machine().await;
+
+        if request.initial_flush && flush.is_none() {
+            let local_now_ms = SeqV::<()>::now_ms();
+            let value = sm
+                .get_maybe_expired_kv(&request.key)
+                .await
+                .map_err(|e| Status::internal(e.to_string()))?;
+
+            if let Some(value) = value.filter(|v| !v.is_expired(local_now_ms)) {
+                let mut resp = EventDispatcher::build_response(&request.key, None, Some(value));
+                resp.is_initialization = true;
+                initial.push(resp);
+            }
+        }
+
         let (resp_tx, resp_rx) = oneshot::channel();
+        let flush_index = flush.as_ref().map(|f| f.history_index);
+        self.dispatcher_handle
+            .request(move |d: &mut EventDispatcher| {
+                let add_res = d.add_watcher(request, tx, flush_index);
+                let _ = resp_tx.send(add_res);
+            });
 
-        self.dispatcher_handle.request(|d: &mut EventDispatcher| {
-            let add_res = d.add_watcher(request, tx);
-            let _ = resp_tx.send(add_res);
-        });
+        drop(sm);
 
         let recv_res = resp_rx.await;
         match recv_res {
-            Ok(add_res) => add_res,
-            Err(_e) => Err("dispatcher closed"),
+            Ok(add_res) => {
+                let (watcher, replay) = add_res?;
+                match flush {
+                    Some(mut flush) => {
+                        initial.extend(replay.into_iter().filter(|resp| flush.is_newer(resp)))
+                    }
+                    None => initial.extend(replay),
+                }
+                Ok((watcher, initial))
+            }
+            Err(_e) => Err(Status::internal("dispatcher closed")),
+        }
+    }
+
+    /// Read the current values in the range `[key, key_end)` in batches into `initial`.
+    ///
+    /// Returns where the reading starts in the watch history and the seq each batch is read at.
+    async fn flush_range(
+        &self,
+        key: &str,
+        key_end: String,
+        initial: &mut Vec<WatchResponse>,
+    ) -> Result<InitialFlush, Status> {
+        // Take the history index before reading anything:
+        // every change applied after it is replayed when the watcher is added.
+        // Changes applied before it are queued in the dispatcher before this request.
+        let (tx, rx) = oneshot::channel();
+        {
+            let _sm = self.sto.get_state_machine().await;
+            self.dispatcher_handle
+                .request(move |d: &mut EventDispatcher| {
+                    let _ = tx.send(d.history_index());
+                });
+        }
+        let history_index = rx
+            .await
+            .map_err(|_e| Status::internal("dispatcher closed"))?;
+
+        let mut flush = InitialFlush {
+            history_index,
+            batches: vec![],
+            seen: BTreeMap::new(),
+        };
+
+        let mut start = Bound::Included(key.to_string());
+        loop {
+            let (read_seq, kvs) = {
+                let sm = self.sto.get_state_machine().await;
+                let kvs = sm
+                    .range_kv(
+                        (start.clone(), Bound::Excluded(key_end.clone())),
+                        INITIAL_FLUSH_BATCH_SIZE,
+                    )
+                    .await
+                    .map_err(|e| Status::internal(e.to_string()))?
+                    .try_collect::<Vec<_>>()
+                    .await
+                    .map_err(|e| Status::internal(e.to_string()))?;
+                (sm.sys_data_ref().curr_seq(), kvs)
+            };
+
+            let batch_end = match kvs.last() {
+                Some((last_key, _)) if kvs.len() == INITIAL_FLUSH_BATCH_SIZE => last_key.clone(),
+                _ => key_end.clone(),
+            };
+
+            let local_now_ms = SeqV::<()>::now_ms();
+            for (key, value) in kvs {
+                if value.is_expired(local_now_ms) {
+                    continue;
+                }
+                flush.seen.insert(key.clone(), value.seq);
+                let mut resp = EventDispatcher::build_response(&key, None, Some(value));
+                resp.is_initialization = true;
+                initial.push(resp);
+            }
+
+            let done = batch_end == key_end;
+            start = Bound::Excluded(batch_end.clone());
+            flush.batches.push((batch_end, read_seq));
+
+            if done {
+                return Ok(flush);
+            }
         }
     }
 }
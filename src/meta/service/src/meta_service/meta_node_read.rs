@@ -0,0 +1,177 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use anyerror::AnyError;
+use databend_common_meta_client::MetaGrpcReadReq;
+use databend_common_meta_sled_store::openraft;
+use databend_common_meta_types::protobuf::StreamItem;
+use databend_common_meta_types::raft_types::LogId;
+use databend_common_meta_types::Endpoint;
+use databend_common_meta_types::MetaAPIError;
+use databend_common_meta_types::MetaDataError;
+use databend_common_meta_types::MetaDataReadError;
+use databend_common_meta_types::MetaOperationError;
+use databend_common_meta_types::ReadConsistency;
+use log::debug;
+use log::warn;
+use openraft::ServerState;
+use tonic::codegen::BoxStream;
+
+use crate::message::ForwardRequest;
+use crate::message::ForwardRequestBody;
+use crate::meta_service::meta_leader::read_state_machine;
+use crate::meta_service::MetaForwarder;
+use crate::meta_service::MetaNode;
+use crate::metrics::server_metrics;
+use crate::request_handling::Forwarder;
+
+/// The max time a non-leader waits for its state machine to apply up to the read index.
+const READ_INDEX_APPLY_TIMEOUT: Duration = Duration::from_secs(5);
+
+impl MetaNode {
+    /// Handle a read request with the consistency it requires.
+    ///
+    /// - A stale read is served with the local state machine, on any node.
+    /// - A linearizable read is served by the leader,
+    ///   or by a follower or learner after it applies logs up to the read index obtained from the leader.
+    ///   If the read index is unavailable, e.g., the leader does not support it,
+    ///   the request is forwarded to the leader.
+    ///
+    /// It returns the leader endpoint if the request is forwarded to the leader.
+    #[fastrace::trace]
+    pub async fn handle_read_request(
+        &self,
+        req: MetaGrpcReadReq,
+        consistency: ReadConsistency,
+    ) -> Result<(Option<Endpoint>, BoxStream<StreamItem>), MetaAPIError> {
+        let state = self.raft.metrics().borrow().state;
+
+        if consistency == ReadConsistency::Stale {
+            let strm = self.read_local(req).await;
+            server_metrics::incr_read_served(consistency, served_by(state));
+            return Ok((None, strm));
+        }
+
+        if state != ServerState::Leader {
+            match self.read_after_read_index(req.clone()).await {
+                Ok(strm) => {
+                    server_metrics::incr_read_served(consistency, served_by(state));
+                    return Ok((None, strm));
+                }
+                Err(e) => {
+                    warn!(
+                        "linearizable read on {:?} failed, forward it to leader; error: {}",
+                        state, e
+                    );
+                }
+            }
+        }
+
+        let res = self
+            .handle_forwardable_request(ForwardRequest::new(1, req))
+            .await?;
+
+        let by = if state == ServerState::Leader {
+            "leader"
+        } else {
+            "forwarded"
+        };
+        server_metrics::incr_read_served(consistency, by);
+
+        Ok(res)
+    }
+
+    /// Serve a read locally after the state machine applies logs up to the read index.
+    async fn read_after_read_index(
+        &self,
+        req: MetaGrpcReadReq,
+    ) -> Result<BoxStream<StreamItem>, MetaAPIError> {
+        let read_log_id = self.get_read_index().await?;
+        let want = read_log_id.map(|x| x.index);
+
+        self.raft
+            .wait(Some(READ_INDEX_APPLY_TIMEOUT))
+            .metrics(
+                |m| m.last_applied.as_ref().map(|x| x.index) >= want,
+                format!("apply up to read index {:?}", want),
+            )
+            .await
+            .map_err(|e| {
+                let read_err = MetaDataReadError::new(
+                    "wait_applied",
+                    format!("read index: {:?}", read_log_id),
+                    &e,
+                );
+                MetaDataError::from(read_err)
+            })?;
+
+        Ok(self.read_local(req).await)
+    }
+
+    /// Get the read index from the leader.
+    ///
+    /// Unlike [`MetaNode::handle_forwardable_request`], it does not retry,
+    /// the caller falls back to forwarding the read request instead.
+    async fn get_read_index(&self) -> Result<Option<LogId>, MetaAPIError> {
+        let to_leader = match self.assume_leader().await {
+            Ok(leader) => {
+                let read_log_id = leader
+                    .read_index()
+                    .await
+                    .map_err(MetaOperationError::from)?;
+                return Ok(read_log_id);
+            }
+            Err(to_leader) => to_leader,
+        };
+
+        let leader_id = to_leader.leader_id.ok_or_else(|| {
+            MetaAPIError::CanNotForward(AnyError::error("need to forward but no known leader"))
+        })?;
+
+        let req = ForwardRequest::new(1, ForwardRequestBody::ReadIndex).next()?;
+
+        let (_endpoint, resp) = MetaForwarder::new(self).forward(leader_id, req).await?;
+
+        // A leader of another version may reply with something else,
+        // the caller then forwards the read request itself.
+        let read_log_id: Option<LogId> = resp.try_into().map_err(|e| {
+            MetaAPIError::CanNotForward(AnyError::error(format!(
+                "expect ReadIndex response from leader {}, but got: {}",
+                leader_id, e
+            )))
+        })?;
+        debug!(
+            "got read index from leader {}: {:?}",
+            leader_id, read_log_id
+        );
+
+        Ok(read_log_id)
+    }
+
+    async fn read_local(&self, req: MetaGrpcReadReq) -> BoxStream<StreamItem> {
+        let sm = self.sto.state_machine.read().await;
+        read_state_machine(&sm, req).await
+    }
+}
+
+/// The role label of the node serving a read request locally.
+fn served_by(state: ServerState) -> &'static str {
+    match state {
+        ServerState::Leader => "leader",
+        ServerState::Learner => "learner",
+        _ => "follower",
+    }
+}
@@ -15,6 +15,7 @@
 mod errors;
 mod forwarder;
 mod meta_node_kv_api_impl;
+mod meta_node_read;
 
 pub(crate) mod snapshot_receiver_v1;
 
@@ -22,6 +23,7 @@ pub mod meta_leader;
 pub mod meta_node;
 pub mod meta_node_status;
 pub mod raft_service_impl;
+pub mod tenant_quota;
 
 pub use forwarder::MetaForwarder;
 pub use meta_node::MetaNode;
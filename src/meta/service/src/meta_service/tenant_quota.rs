@@ -0,0 +1,584 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::io;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::MutexGuard;
+
+use databend_common_meta_api::deserialize_struct;
+use databend_common_meta_app::schema::DBIdTableName;
+use databend_common_meta_app::schema::DatabaseNameIdentRaw;
+use databend_common_meta_raft_store::sm_v003::SMV003;
+use databend_common_meta_raft_store::state_machine::StateMachineSubscriber;
+use databend_common_meta_types::seq_value::SeqV;
+use databend_common_meta_types::seq_value::SeqValue;
+use databend_common_meta_types::txn_op::Request;
+use databend_common_meta_types::Change;
+use databend_common_meta_types::Cmd;
+use databend_common_meta_types::Operation;
+use databend_common_meta_types::QuotaKind;
+use databend_common_meta_types::TenantQuotaExceeded;
+use databend_common_meta_types::TxnOp;
+use futures::TryStreamExt;
+use log::info;
+
+use crate::configs::TenantQuotaConfig;
+use crate::metrics::server_metrics;
+
+/// Resources whose records are keyed by the id of the database they belong to.
+const DATABASE_ID_RESOURCES: &[&str] = &[
+    "__fd_database_by_id",
+    "__fd_database_id_to_name",
+    "__fd_table",
+    "__fd_table_id_list",
+];
+
+/// Resources whose records are keyed by the id of the table they belong to.
+const TABLE_ID_RESOURCES: &[&str] = &[
+    "__fd_table_by_id",
+    "__fd_table_id_to_name",
+    "__fd_table_copied_files",
+    "__fd_table_lvt",
+];
+
+/// Returns the tenant a key belongs to.
+///
+/// Tenant scoped records are stored in the form `__fd_<resource>/<tenant>/...`.
+/// Records keyed by an internal id, such as `__fd_table_by_id/<table_id>`,
+/// do not carry a tenant, see [`owner_id_of_key`].
+pub fn tenant_of_key(key: &str) -> Option<&str> {
+    let mut segments = key.split('/');
+
+    let resource = segments.next()?;
+    if !resource.starts_with("__fd_") || resource == "__fd_id_gen" {
+        return None;
+    }
+
+    let tenant = segments.next()?;
+    if tenant.is_empty() || tenant.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    Some(tenant)
+}
+
+/// The id of the database or table that a record without a tenant belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OwnerId {
+    Database(u64),
+    Table(u64),
+}
+
+/// Returns the database or table a key keyed by an internal id belongs to,
+/// such as `__fd_table_copied_files/<table_id>/<file>`.
+///
+/// Records of other ids, e.g., of indexes or catalogs, are not accounted to any tenant.
+pub fn owner_id_of_key(key: &str) -> Option<OwnerId> {
+    let mut segments = key.split('/');
+
+    let resource = segments.next()?;
+    let id = segments.next()?.parse::<u64>().ok()?;
+
+    if DATABASE_ID_RESOURCES.contains(&resource) {
+        Some(OwnerId::Database(id))
+    } else if TABLE_ID_RESOURCES.contains(&resource) {
+        Some(OwnerId::Table(id))
+    } else {
+        None
+    }
+}
+
+/// The tenants of databases and the databases of tables,
+/// learned from the `id -> name` records.
+///
+/// Ids are never reused, a mapping is kept after the records are removed,
+/// so that removing the rest of the records of a dropped table is still accounted.
+#[derive(Debug, Default)]
+struct Ownership {
+    db_tenants: BTreeMap<u64, String>,
+    table_dbs: BTreeMap<u64, u64>,
+}
+
+impl Ownership {
+    /// Learn the owner of an id if the record maps an id to its name.
+    ///
+    /// Returns whether a mapping is learned.
+    fn learn(&mut self, key: &str, value: &[u8]) -> bool {
+        let mut segments = key.split('/');
+        let resource = segments.next();
+        let Some(id) = segments.next().and_then(|x| x.parse::<u64>().ok()) else {
+            return false;
+        };
+
+        match resource {
+            Some("__fd_database_id_to_name") => {
+                let Ok(name) = deserialize_struct::<DatabaseNameIdentRaw>(value) else {
+                    return false;
+                };
+                self.db_tenants.insert(id, name.tenant_name().to_string());
+            }
+            Some("__fd_table_id_to_name") => {
+                let Ok(name) = deserialize_struct::<DBIdTableName>(value) else {
+                    return false;
+                };
+                self.table_dbs.insert(id, name.db_id);
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Returns the tenant owning a database or table, looking up `overlay` first.
+    fn tenant_of(&self, owner: OwnerId, overlay: Option<&Ownership>) -> Option<String> {
+        let layers = || overlay.into_iter().chain([self]);
+
+        let db_id = match owner {
+            OwnerId::Database(db_id) => db_id,
+            OwnerId::Table(table_id) => {
+                layers().find_map(|o| o.table_dbs.get(&table_id).copied())?
+            }
+        };
+
+        layers().find_map(|o| o.db_tenants.get(&db_id).cloned())
+    }
+}
+
+/// A change of the keys and value bytes used.
+#[derive(Clone, Copy, Debug, Default)]
+struct UsageDelta {
+    keys: i64,
+    value_bytes: i64,
+}
+
+impl UsageDelta {
+    fn add(&mut self, other: UsageDelta) {
+        self.keys += other.keys;
+        self.value_bytes += other.value_bytes;
+    }
+}
+
+/// The resources used by a tenant.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TenantUsage {
+    pub keys: u64,
+    pub value_bytes: u64,
+
+    /// The number of write requests in the current second.
+    pub write_qps: u64,
+}
+
+impl TenantUsage {
+    fn apply(&mut self, delta: UsageDelta) {
+        self.keys = self.keys.saturating_add_signed(delta.keys);
+        self.value_bytes = self.value_bytes.saturating_add_signed(delta.value_bytes);
+    }
+}
+
+/// Tracks per-tenant usage and enforces [`TenantQuotaConfig`].
+///
+/// Key and value-bytes usage is updated with every change applied to the state machine,
+/// and is rebuilt by scanning the state machine when the data is replaced, e.g., a snapshot is installed.
+///
+/// The check is done by the leader before proposing a write and is approximate:
+/// writes proposed concurrently are checked against the same usage.
+#[derive(Debug)]
+pub struct TenantQuota {
+    state: Mutex<QuotaState>,
+}
+
+#[derive(Debug)]
+struct QuotaState {
+    limits: TenantQuotaConfig,
+
+    /// Whether `usage` has to be rebuilt from the state machine.
+    stale: bool,
+
+    usage: BTreeMap<String, TenantUsage>,
+
+    ownership: Ownership,
+
+    /// Usage of databases and tables whose tenant is not known yet,
+    /// e.g., the records of a table written before its `id -> name` record.
+    pending: BTreeMap<OwnerId, UsageDelta>,
+
+    /// The number of writes of every tenant in a second: `(unix_second, count)`.
+    writes: BTreeMap<String, (u64, u64)>,
+}
+
+impl Default for TenantQuota {
+    fn default() -> Self {
+        Self {
+            state: Mutex::new(QuotaState {
+                limits: TenantQuotaConfig::default(),
+                stale: true,
+                usage: BTreeMap::new(),
+                ownership: Ownership::default(),
+                pending: BTreeMap::new(),
+                writes: BTreeMap::new(),
+            }),
+        }
+    }
+}
+
+impl TenantQuota {
+    pub fn set_limits(&self, limits: TenantQuotaConfig) {
+        info!("set tenant quota limits: {:?}", limits);
+        self.lock().limits = limits;
+    }
+
+    pub fn limits(&self) -> TenantQuotaConfig {
+        self.lock().limits.clone()
+    }
+
+    /// Returns the usage of every tenant, rebuilding it from the state machine if needed.
+    pub async fn usage(
+        &self,
+        sm: &SMV003,
+        now_ms: u64,
+    ) -> Result<BTreeMap<String, TenantUsage>, io::Error> {
+        self.ensure_usage(sm).await?;
+
+        let now_sec = now_ms / 1000;
+        let st = self.lock();
+
+        let mut usage = st.usage.clone();
+        for (tenant, (sec, count)) in st.writes.iter() {
+            if *sec == now_sec {
+                usage.entry(tenant.clone()).or_default().write_qps = *count;
+            }
+        }
+        Ok(usage)
+    }
+
+    /// Check if the write command would exceed any per-tenant limit.
+    ///
+    /// If it does not, the write is counted for rate limiting.
+    /// Both branches of a transaction are taken into account,
+    /// since which one will be executed is unknown before applying.
+    pub async fn check_write(
+        &self,
+        sm: &SMV003,
+        cmd: &Cmd,
+        now_ms: u64,
+    ) -> Result<Result<(), TenantQuotaExceeded>, io::Error> {
+        let limits = self.limits();
+
+        let writes = match cmd {
+            Cmd::UpsertKV(upsert) => match &upsert.value {
+                Operation::Update(v) => vec![Write::Put(&upsert.key, v)],
+                Operation::Delete => vec![Write::Other(&upsert.key)],
+                Operation::AsIs => vec![Write::Other(&upsert.key)],
+            },
+            Cmd::Transaction(txn) => txn
+                .if_then
+                .iter()
+                .chain(txn.else_then.iter())
+                .filter_map(Write::from_txn_op)
+                .collect(),
+            Cmd::AddNode { .. } | Cmd::RemoveNode { .. } => vec![],
+        };
+
+        // The owners of the databases and tables created by this command.
+        let mut created = Ownership::default();
+        for w in writes.iter() {
+            if let Write::Put(key, value) = w {
+                created.learn(key, value);
+            }
+        }
+
+        let has_id_keys = writes
+            .iter()
+            .any(|w| tenant_of_key(w.key()).is_none() && owner_id_of_key(w.key()).is_some());
+
+        if limits.max_keys > 0 || limits.max_value_bytes > 0 || has_id_keys {
+            self.ensure_usage(sm).await?;
+        }
+
+        let tenants = {
+            let st = self.lock();
+            writes
+                .iter()
+                .map(|w| match tenant_of_key(w.key()) {
+                    Some(tenant) => Some(tenant.to_string()),
+                    None => owner_id_of_key(w.key())
+                        .and_then(|owner| st.ownership.tenant_of(owner, Some(&created))),
+                })
+                .collect::<Vec<_>>()
+        };
+
+        // tenant -> (new keys, value bytes delta)
+        let mut growth: BTreeMap<String, (u64, i64)> = BTreeMap::new();
+        // key -> (exists, size of the value)
+        let mut current: BTreeMap<&str, (bool, u64)> = BTreeMap::new();
+
+        for (w, tenant) in writes.iter().zip(tenants) {
+            let Some(tenant) = tenant else {
+                continue;
+            };
+
+            let g = growth.entry(tenant).or_default();
+
+            let Write::Put(key, value) = w else {
+                continue;
+            };
+            let size = value.len() as u64;
+
+            if !current.contains_key(key) {
+                let seqv = sm.get_maybe_expired_kv(key).await?;
+                let size = seqv.value().map(|v| v.len() as u64).unwrap_or_default();
+                current.insert(key, (seqv.is_some(), size));
+            }
+
+            let (exists, prev_size) = current.get_mut(key).unwrap();
+            if !*exists {
+                g.0 += 1;
+                *exists = true;
+            }
+            g.1 += size as i64 - *prev_size as i64;
+            *prev_size = size;
+        }
+
+        if growth.is_empty() {
+            return Ok(Ok(()));
+        }
+
+        let now_sec = now_ms / 1000;
+        let mut st = self.lock();
+
+        for (tenant, (new_keys, bytes_delta)) in growth.iter() {
+            let usage = st.usage.get(tenant).cloned().unwrap_or_default();
+
+            if limits.max_keys > 0 && *new_keys > 0 {
+                let requested = usage.keys + new_keys;
+                if requested > limits.max_keys {
+                    return Ok(Err(Self::rejected(
+                        tenant,
+                        QuotaKind::Keys,
+                        limits.max_keys,
+                        requested,
+                    )));
+                }
+            }
+
+            if limits.max_value_bytes > 0 && *bytes_delta > 0 {
+                let requested = usage.value_bytes + *bytes_delta as u64;
+                if requested > limits.max_value_bytes {
+                    return Ok(Err(Self::rejected(
+                        tenant,
+                        QuotaKind::ValueBytes,
+                        limits.max_value_bytes,
+                        requested,
+                    )));
+                }
+            }
+
+            if limits.max_write_qps > 0 {
+                let count = match st.writes.get(tenant) {
+                    Some((sec, count)) if *sec == now_sec => *count,
+                    _ => 0,
+                };
+
+                if count + 1 > limits.max_write_qps {
+                    return Ok(Err(Self::rejected(
+                        tenant,
+                        QuotaKind::WriteQps,
+                        limits.max_write_qps,
+                        count + 1,
+                    )));
+                }
+            }
+        }
+
+        for tenant in growth.keys() {
+            let w = st.writes.entry(tenant.to_string()).or_insert((now_sec, 0));
+            if w.0 != now_sec {
+                *w = (now_sec, 0);
+            }
+            w.1 += 1;
+        }
+
+        Ok(Ok(()))
+    }
+
+    fn rejected(tenant: &str, kind: QuotaKind, limit: u64, requested: u64) -> TenantQuotaExceeded {
+        server_metrics::incr_tenant_quota_rejected(tenant, kind);
+        TenantQuotaExceeded::new(tenant, kind, limit, requested)
+    }
+
+    /// Update usage with a change applied to the state machine.
+    pub fn apply_change(&self, change: &Change<Vec<u8>, String>) {
+        let Some(key) = &change.ident else {
+            return;
+        };
+
+        let tenant = tenant_of_key(key);
+        let owner = owner_id_of_key(key);
+        if tenant.is_none() && owner.is_none() {
+            return;
+        }
+
+        let mut st = self.lock();
+        if st.stale {
+            return;
+        }
+
+        if let Some(value) = change.result.value() {
+            if st.ownership.learn(key, value) {
+                st.settle_pending();
+            }
+        }
+
+        let size = |x: &Option<SeqV>| x.value().map(|v| v.len() as i64).unwrap_or_default();
+        let delta = UsageDelta {
+            keys: change.result.is_some() as i64 - change.prev.is_some() as i64,
+            value_bytes: size(&change.result) - size(&change.prev),
+        };
+
+        match (tenant, owner) {
+            (Some(tenant), _) => st.add_usage(tenant, delta),
+            (None, Some(owner)) => match st.ownership.tenant_of(owner, None) {
+                Some(tenant) => st.add_usage(&tenant, delta),
+                None => st.pending.entry(owner).or_default().add(delta),
+            },
+            (None, None) => unreachable!(),
+        }
+    }
+
+    /// Mark the usage to be rebuilt, because the state machine data is replaced.
+    pub fn mark_stale(&self) {
+        self.lock().stale = true;
+    }
+
+    /// Rebuild the usage by scanning the state machine, if it is stale.
+    ///
+    /// No change can be applied while `sm` is held, thus the result is consistent.
+    async fn ensure_usage(&self, sm: &SMV003) -> Result<(), io::Error> {
+        if !self.lock().stale {
+            return Ok(());
+        }
+
+        let mut usage: BTreeMap<String, TenantUsage> = BTreeMap::new();
+        let mut ownership = Ownership::default();
+        let mut pending: BTreeMap<OwnerId, UsageDelta> = BTreeMap::new();
+
+        let mut strm = sm.list_kv("__fd_").await?;
+        while let Some((key, seqv)) = strm.try_next().await? {
+            ownership.learn(&key, &seqv.data);
+
+            let delta = UsageDelta {
+                keys: 1,
+                value_bytes: seqv.data.len() as i64,
+            };
+            if let Some(tenant) = tenant_of_key(&key) {
+                usage.entry(tenant.to_string()).or_default().apply(delta);
+            } else if let Some(owner) = owner_id_of_key(&key) {
+                pending.entry(owner).or_default().add(delta);
+            }
+        }
+
+        let mut st = self.lock();
+        st.usage = usage;
+        st.ownership = ownership;
+        st.pending = pending;
+        st.settle_pending();
+        st.stale = false;
+
+        info!(
+            "rebuilt usage of {} tenants, {} databases or tables without a known tenant",
+            st.usage.len(),
+            st.pending.len()
+        );
+
+        for (tenant, u) in st.usage.iter() {
+            server_metrics::set_tenant_usage(tenant, u.keys, u.value_bytes);
+        }
+
+        Ok(())
+    }
+
+    fn lock(&self) -> MutexGuard<'_, QuotaState> {
+        self.state.lock().unwrap()
+    }
+}
+
+impl QuotaState {
+    fn add_usage(&mut self, tenant: &str, delta: UsageDelta) {
+        let usage = self.usage.entry(tenant.to_string()).or_default();
+        usage.apply(delta);
+        server_metrics::set_tenant_usage(tenant, usage.keys, usage.value_bytes);
+    }
+
+    /// Account the pending usage of databases and tables whose tenant is known now.
+    fn settle_pending(&mut self) {
+        let pending = std::mem::take(&mut self.pending);
+        for (owner, delta) in pending {
+            match self.ownership.tenant_of(owner, None) {
+                Some(tenant) => self.add_usage(&tenant, delta),
+                None => {
+                    self.pending.insert(owner, delta);
+                }
+            }
+        }
+    }
+}
+
+/// A write to a key, extracted from a command.
+enum Write<'a> {
+    /// Set a key to a value.
+    Put(&'a str, &'a [u8]),
+    /// A write that does not add data, such as deleting a key or a key prefix.
+    Other(&'a str),
+}
+
+impl<'a> Write<'a> {
+    fn from_txn_op(op: &'a TxnOp) -> Option<Self> {
+        match op.request.as_ref()? {
+            Request::Put(p) => Some(Write::Put(&p.key, &p.value)),
+            Request::Delete(d) => Some(Write::Other(&d.key)),
+            Request::DeleteByPrefix(d) => Some(Write::Other(&d.prefix)),
+            // A counter is a short decimal integer, its size is negligible.
+            Request::FetchAdd(f) => Some(Write::Put(&f.key, &[])),
+            Request::Get(_) | Request::ListRange(_) | Request::GetMany(_) => None,
+        }
+    }
+
+    fn key(&self) -> &'a str {
+        match self {
+            Write::Put(k, _) => k,
+            Write::Other(k) => k,
+        }
+    }
+}
+
+/// A state machine subscriber that keeps [`TenantQuota`] up to date
+/// and forwards every event to the inner subscriber.
+#[derive(Debug)]
+pub(crate) struct UsageTrackingSubscriber {
+    pub(crate) quota: Arc<TenantQuota>,
+    pub(crate) inner: Box<dyn StateMachineSubscriber>,
+}
+
+impl StateMachineSubscriber for UsageTrackingSubscriber {
+    fn kv_changed(&self, change: Change<Vec<u8>, String>) {
+        self.quota.apply_change(&change);
+        self.inner.kv_changed(change);
+    }
+
+    fn data_replaced(&self, curr_seq: u64) {
+        self.quota.mark_stale();
+        self.inner.data_replaced(curr_seq);
+    }
+}
@@ -34,6 +34,8 @@ pub mod server_metrics {
     use std::sync::LazyLock;
 
     use databend_common_meta_types::raft_types::NodeId;
+    use databend_common_meta_types::QuotaKind;
+    use databend_common_meta_types::ReadConsistency;
     use prometheus_client::metrics::counter::Counter;
     use prometheus_client::metrics::family::Family;
     use prometheus_client::metrics::gauge::Gauge;
@@ -61,8 +63,12 @@ pub mod server_metrics {
         proposals_pending: Gauge,
         proposals_failed: Counter,
         read_failed: Counter,
+        read_served: Family<Vec<(String, String)>, Counter>,
         watchers: Gauge,
         version: Family<Vec<(String, String)>, Gauge>,
+        tenant_keys: Family<Vec<(String, String)>, Gauge>,
+        tenant_value_bytes: Family<Vec<(String, String)>, Gauge>,
+        tenant_quota_rejected: Family<Vec<(String, String)>, Counter>,
     }
 
     impl ServerMetrics {
@@ -82,8 +88,12 @@ pub mod server_metrics {
                 proposals_pending: Gauge::default(),
                 proposals_failed: Counter::default(),
                 read_failed: Counter::default(),
+                read_served: Family::default(),
                 watchers: Gauge::default(),
                 version: Family::default(),
+                tenant_keys: Family::default(),
+                tenant_value_bytes: Family::default(),
+                tenant_quota_rejected: Family::default(),
             };
 
             let mut registry = load_global_registry();
@@ -149,8 +159,28 @@ pub mod server_metrics {
                 "read failed",
                 metrics.read_failed.clone(),
             );
+            registry.register(
+                key!("read_served"),
+                "read requests served, by consistency and by the role of the serving node",
+                metrics.read_served.clone(),
+            );
             registry.register(key!("watchers"), "watchers", metrics.watchers.clone());
             registry.register(key!("version"), "version", metrics.version.clone());
+            registry.register(
+                key!("tenant_keys"),
+                "number of keys of a tenant",
+                metrics.tenant_keys.clone(),
+            );
+            registry.register(
+                key!("tenant_value_bytes"),
+                "total size in bytes of the values of a tenant",
+                metrics.tenant_value_bytes.clone(),
+            );
+            registry.register(
+                key!("tenant_quota_rejected"),
+                "writes rejected by per-tenant quota, by tenant and by the kind of limit",
+                metrics.tenant_quota_rejected.clone(),
+            );
             metrics
         }
     }
@@ -225,10 +255,43 @@ pub mod server_metrics {
         SERVER_METRICS.read_failed.inc();
     }
 
+    /// Accumulate the number of read requests by the consistency they require,
+    /// and by whom they are served: `leader`, `follower`, `learner`, or `forwarded` to the leader.
+    pub fn incr_read_served(consistency: ReadConsistency, served_by: &str) {
+        let labels = &vec![
+            ("consistency".to_string(), consistency.to_string()),
+            ("served_by".to_string(), served_by.to_string()),
+        ];
+        SERVER_METRICS.read_served.get_or_create(labels).inc();
+    }
+
     pub fn incr_watchers(cnt: i64) {
         SERVER_METRICS.watchers.inc_by(cnt);
     }
 
+    pub fn set_tenant_usage(tenant: &str, keys: u64, value_bytes: u64) {
+        let labels = &vec![("tenant".to_string(), tenant.to_string())];
+        SERVER_METRICS
+            .tenant_keys
+            .get_or_create(labels)
+            .set(keys as i64);
+        SERVER_METRICS
+            .tenant_value_bytes
+            .get_or_create(labels)
+            .set(value_bytes as i64);
+    }
+
+    pub fn incr_tenant_quota_rejected(tenant: &str, kind: QuotaKind) {
+        let labels = &vec![
+            ("tenant".to_string(), tenant.to_string()),
+            ("kind".to_string(), kind.to_string()),
+        ];
+        SERVER_METRICS
+            .tenant_quota_rejected
+            .get_or_create(labels)
+            .inc();
+    }
+
     pub fn set_version(semver: String, sha: String) {
         let labels = &vec![
             ("component".to_string(), "metasrv".to_string()),
@@ -68,7 +68,11 @@ pub static MIN_METACLI_SEMVER: Version = Version::new(0, 9, 41);
 ///
 /// - 2023-11-16: since 1.2.212:
 ///   Add install_snapshot_v1
-pub static MIN_META_SEMVER: Version = Version::new(0, 9, 41);
+///
+/// - 2024-11-12: since 1.2.656:
+///   Add txn ops fetch_add, list_range, get_many and the key-count condition.
+///   An older server ignores these ops when applying logs, it must not join the cluster.
+pub static MIN_META_SEMVER: Version = Version::new(1, 2, 656);
 
 /// Defines the feature set provided and required by raft server and client.
 ///
@@ -117,6 +121,10 @@ pub(crate) mod raft {
             del_provide(("install_snapshot", 0), "2024-05-21", (1,  2, 479)),
             del_provide(("install_snapshot", 2), "2024-07-02", (1,  2, 552)),
             add_provide(("install_snapshot", 3), "2024-07-02", (1,  2, 552)),
+            add_provide(("txn_fetch_add",    0), "2024-11-12", (1,  2, 656)),
+            add_provide(("txn_list_range",   0), "2024-11-12", (1,  2, 656)),
+            add_provide(("txn_get_many",     0), "2024-11-12", (1,  2, 656)),
+            add_provide(("txn_key_count",    0), "2024-11-12", (1,  2, 656)),
         ];
 
         /// The client features that raft server depends on.
@@ -147,7 +155,10 @@ pub(crate) mod raft {
             del_require( ("install_snapshot", 0), "2024-05-21", (1,  2, 479)),
             del_require( ("install_snapshot", 1), "2024-07-02", (1,  2, 552)),
             add_require( ("install_snapshot", 3), "2024-07-02", (1,  2, 552)),
-            
+            add_require( ("txn_fetch_add",    0), "2024-11-12", (1,  2, 656)),
+            add_require( ("txn_list_range",   0), "2024-11-12", (1,  2, 656)),
+            add_require( ("txn_get_many",     0), "2024-11-12", (1,  2, 656)),
+            add_require( ("txn_key_count",    0), "2024-11-12", (1,  2, 656)),
         ];
 
         /// Feature set provided by raft client.
@@ -0,0 +1,140 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+
+use databend_common_meta_types::Change;
+
+/// The max number of recent changes kept for resuming watchers.
+pub(crate) const WATCH_HISTORY_CAPACITY: usize = 10_000;
+
+/// Recent kv changes of the state machine, in the order they are applied.
+///
+/// The history is kept in memory by every node for the changes it applies itself:
+/// it is empty after a restart, and on another node it may cover different changes.
+/// Resuming from a seq that is not covered is rejected instead of silently missing changes.
+///
+/// Every change is tagged with a seq: an update is tagged with the seq of the new value,
+/// a delete is tagged with the last seq seen before it.
+/// A watcher that has seen changes up to seq `s` resumes by replaying changes tagged with a seq
+/// greater than `s`, and delete changes tagged with `s`.
+#[derive(Debug)]
+pub(crate) struct WatchHistory {
+    changes: VecDeque<(u64, Change<Vec<u8>, String>)>,
+
+    capacity: usize,
+
+    /// The greatest seq that has been seen.
+    last_seq: u64,
+
+    /// The smallest seq a watcher can resume from.
+    ///
+    /// Changes required by resuming from a smaller seq are compacted or are never recorded.
+    min_start_seq: u64,
+
+    /// The index of the first change in `changes`, counting every change ever pushed.
+    first_index: u64,
+}
+
+impl WatchHistory {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            changes: VecDeque::new(),
+            capacity,
+            last_seq: 0,
+            min_start_seq: 0,
+            first_index: 0,
+        }
+    }
+
+    /// The greatest seq that has been seen.
+    pub(crate) fn last_seq(&self) -> u64 {
+        self.last_seq
+    }
+
+    /// The index the next pushed change will have.
+    pub(crate) fn next_index(&self) -> u64 {
+        self.first_index + self.changes.len() as u64
+    }
+
+    /// Forget all changes because the data is replaced, e.g., by installing a snapshot.
+    ///
+    /// Changes before `curr_seq` are unknown,
+    /// thus resuming from `curr_seq` is not possible because deletes tagged with it may be lost.
+    pub(crate) fn reset(&mut self, curr_seq: u64) {
+        // Skip one index so that an index taken before the reset can not be replayed from.
+        self.first_index = self.next_index() + 1;
+        self.changes.clear();
+        self.last_seq = curr_seq;
+        self.min_start_seq = curr_seq + 1;
+    }
+
+    pub(crate) fn push(&mut self, change: Change<Vec<u8>, String>) {
+        let seq = match &change.result {
+            Some(seqv) => seqv.seq,
+            None => self.last_seq,
+        };
+        self.last_seq = std::cmp::max(self.last_seq, seq);
+        self.changes.push_back((seq, change));
+
+        while self.changes.len() > self.capacity {
+            let (seq, evicted) = self.changes.pop_front().unwrap();
+            self.first_index += 1;
+
+            // Deletes tagged with the seq of an evicted update are still kept,
+            // while an evicted delete makes resuming from its seq impossible.
+            let min_start_seq = if evicted.result.is_some() {
+                seq
+            } else {
+                seq + 1
+            };
+            self.min_start_seq = std::cmp::max(self.min_start_seq, min_start_seq);
+        }
+    }
+
+    /// Returns the changes to replay for a watcher resuming from `start_seq`,
+    /// or the smallest seq it can resume from if the required changes are compacted.
+    pub(crate) fn changes_since(
+        &self,
+        start_seq: u64,
+    ) -> Result<impl Iterator<Item = &Change<Vec<u8>, String>>, u64> {
+        if start_seq < self.min_start_seq {
+            return Err(self.min_start_seq);
+        }
+
+        let it = self
+            .changes
+            .iter()
+            .filter(move |(seq, change)| {
+                *seq > start_seq || (*seq == start_seq && change.result.is_none())
+            })
+            .map(|(_, change)| change);
+
+        Ok(it)
+    }
+
+    /// Returns the changes pushed since the change with `index`,
+    /// or `None` if some of them are compacted or the data is replaced meanwhile.
+    pub(crate) fn changes_since_index(
+        &self,
+        index: u64,
+    ) -> Option<impl Iterator<Item = &Change<Vec<u8>, String>>> {
+        if index < self.first_index {
+            return None;
+        }
+
+        let skip = (index - self.first_index) as usize;
+        Some(self.changes.iter().skip(skip).map(|(_, change)| change))
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod history;
 mod watcher_manager;
 mod watcher_stream;
 
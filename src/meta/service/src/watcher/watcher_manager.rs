@@ -25,11 +25,14 @@ use databend_common_meta_types::protobuf::Event;
 use databend_common_meta_types::protobuf::WatchRequest;
 use databend_common_meta_types::protobuf::WatchResponse;
 use databend_common_meta_types::Change;
+use databend_common_meta_types::SeqV;
 use log::info;
 use log::warn;
 use prost::Message;
 use tonic::Status;
 
+use super::history::WatchHistory;
+use super::history::WATCH_HISTORY_CAPACITY;
 use super::WatchStreamHandle;
 use crate::metrics::network_metrics;
 use crate::metrics::server_metrics;
@@ -49,6 +52,10 @@ pub(crate) enum WatchEvent {
     /// Submit a kv change event to dispatcher
     KVChange(Change<Vec<u8>, String>),
 
+    /// The state machine data is replaced without kv change events,
+    /// with the last seq of the new data.
+    DataReplaced(u64),
+
     /// Send a request to EventDispatcher.
     ///
     /// The function will be called with a mutable reference to the dispatcher.
@@ -96,6 +103,9 @@ pub struct EventDispatcher {
     watcher_range_map: RangeMap<String, WatcherId, WatchStreamHandle>,
 
     current_watcher_id: WatcherId,
+
+    /// Recent changes for replaying to a watcher that resumes from a seq.
+    history: WatchHistory,
 }
 
 impl EventDispatcher {
@@ -107,6 +117,7 @@ impl EventDispatcher {
             event_rx,
             watcher_range_map: RangeMap::new(),
             current_watcher_id: 1,
+            history: WatchHistory::new(WATCH_HISTORY_CAPACITY),
         };
 
         let _h = databend_common_base::runtime::spawn(dispatcher.main());
@@ -120,8 +131,13 @@ impl EventDispatcher {
             if let Some(event) = self.event_rx.recv().await {
                 match event {
                     WatchEvent::KVChange(kv_change) => {
+                        self.history.push(kv_change.clone());
                         self.dispatch_event(kv_change).await;
                     }
+                    WatchEvent::DataReplaced(curr_seq) => {
+                        info!("state machine data replaced, curr_seq: {}", curr_seq);
+                        self.history.reset(curr_seq);
+                    }
                     WatchEvent::Request { req } => req(&mut self),
                 }
             } else {
@@ -146,25 +162,15 @@ impl EventDispatcher {
         let mut remove_range_keys: Vec<RangeMapKey<String, WatcherId>> = vec![];
 
         for range_key_stream in set.iter() {
-            let filter = range_key_stream.1.watcher.filter_type;
-
             // filter out event
-            if (filter == FilterType::Delete && !is_delete_event)
-                || (filter == FilterType::Update && is_delete_event)
-            {
+            if !range_key_stream.1.watcher.accepts(is_delete_event) {
                 continue;
             }
 
             let watcher_id = range_key_stream.0.key;
             let stream = range_key_stream.1;
             assert_eq!(stream.watcher.id, watcher_id);
-            let resp = WatchResponse {
-                event: Some(Event {
-                    key: k.to_string(),
-                    current: current.clone().map(pb::SeqV::from),
-                    prev: prev.clone().map(pb::SeqV::from),
-                }),
-            };
+            let resp = Self::build_response(k, prev.clone(), current.clone());
 
             network_metrics::incr_sent_bytes(resp.encoded_len() as u64);
 
@@ -186,24 +192,82 @@ impl EventDispatcher {
         }
     }
 
+    pub(crate) fn build_response(
+        key: &str,
+        prev: Option<SeqV>,
+        current: Option<SeqV>,
+    ) -> WatchResponse {
+        WatchResponse {
+            event: Some(Event {
+                key: key.to_string(),
+                current: current.map(pb::SeqV::from),
+                prev: prev.map(pb::SeqV::from),
+            }),
+            is_initialization: false,
+        }
+    }
+
+    /// The history index of the next change.
+    ///
+    /// An initial flush takes it before reading the current values,
+    /// to replay the changes applied while reading, see [`Self::add_watcher`].
+    pub fn history_index(&self) -> u64 {
+        self.history.next_index()
+    }
+
+    /// Add a watcher.
+    ///
+    /// If `start_seq` is specified in the request,
+    /// it also returns the changes since `start_seq` to replay before new change events.
+    /// If `flush_index` is specified,
+    /// it returns the changes since the history index `flush_index` instead.
     #[fastrace::trace]
     pub fn add_watcher(
         &mut self,
         create: WatchRequest,
         tx: WatcherSender,
-    ) -> Result<Watcher, &'static str> {
+        flush_index: Option<u64>,
+    ) -> Result<(Watcher, Vec<WatchResponse>), Status> {
         info!("add_watcher: {:?}", create);
 
-        let range = match EventDispatcher::build_key_range(create.key.clone(), &create.key_end) {
-            Ok(range) => range,
-            Err(e) => return Err(e),
+        let range = EventDispatcher::build_key_range(create.key.clone(), &create.key_end)
+            .map_err(Status::invalid_argument)?;
+
+        let filter: FilterType = create.filter_type();
+        let watcher = Watcher::new(self.current_watcher_id + 1, filter, range.clone());
+
+        let replay = if let Some(index) = flush_index {
+            let changes = self.history.changes_since_index(index).ok_or_else(|| {
+                Status::unavailable(
+                    "too many changes are applied during the initial flush, retry later",
+                )
+            })?;
+            Self::build_replay(&watcher, changes)
+        } else if let Some(start_seq) = create.start_seq {
+            // This node has not yet applied the changes the client has seen,
+            // they would be sent again as new changes.
+            if start_seq > self.history.last_seq() {
+                return Err(Status::unavailable(format!(
+                    "this node has only applied changes up to seq {}, behind start_seq {}; retry later or on another node",
+                    self.history.last_seq(),
+                    start_seq
+                )));
+            }
+
+            let changes = self.history.changes_since(start_seq).map_err(|min_start_seq| {
+                Status::out_of_range(format!(
+                    "watch history since seq {} is compacted, the smallest seq to resume from is {}; re-watch with initial_flush instead",
+                    start_seq, min_start_seq
+                ))
+            })?;
+            Self::build_replay(&watcher, changes)
+        } else {
+            vec![]
         };
 
         self.current_watcher_id += 1;
         let watcher_id = self.current_watcher_id;
-        let filter: FilterType = create.filter_type();
 
-        let watcher = Watcher::new(watcher_id, filter, range.clone());
         let stream_handle = WatchStreamHandle::new(watcher.clone(), tx);
 
         self.watcher_range_map
@@ -211,7 +275,26 @@ impl EventDispatcher {
 
         server_metrics::incr_watchers(1);
 
-        Ok(watcher)
+        Ok((watcher, replay))
+    }
+
+    fn build_replay<'a>(
+        watcher: &Watcher,
+        changes: impl Iterator<Item = &'a Change<Vec<u8>, String>>,
+    ) -> Vec<WatchResponse> {
+        let mut replay = vec![];
+        for change in changes {
+            let key = change.ident.as_ref().unwrap();
+            if !watcher.contains_key(key) || !watcher.accepts(change.result.is_none()) {
+                continue;
+            }
+            replay.push(Self::build_response(
+                key,
+                change.prev.clone(),
+                change.result.clone(),
+            ));
+        }
+        replay
     }
 
     #[fastrace::trace]
@@ -248,4 +331,8 @@ impl StateMachineSubscriber for DispatcherSender {
     fn kv_changed(&self, change: Change<Vec<u8>, String>) {
         let _ = self.0.send(WatchEvent::KVChange(change));
     }
+
+    fn data_replaced(&self, curr_seq: u64) {
+        let _ = self.0.send(WatchEvent::DataReplaced(curr_seq));
+    }
 }
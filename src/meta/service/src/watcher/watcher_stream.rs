@@ -49,6 +49,27 @@ impl Watcher {
             key_range,
         }
     }
+
+    /// Whether `key` is in the watched range.
+    ///
+    /// An empty `key_range` means to watch only the key `key_range.start`.
+    pub fn contains_key(&self, key: &str) -> bool {
+        let (start, end) = (self.key_range.start.as_str(), self.key_range.end.as_str());
+        if start == end {
+            key == start
+        } else {
+            start <= key && key < end
+        }
+    }
+
+    /// Whether a change event passes the filter of this watcher.
+    pub fn accepts(&self, is_delete_event: bool) -> bool {
+        match self.filter_type {
+            FilterType::All => true,
+            FilterType::Update => !is_delete_event,
+            FilterType::Delete => is_delete_event,
+        }
+    }
 }
 
 /// A handle of a watching stream, for feeding messages to the stream.
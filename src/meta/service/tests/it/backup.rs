@@ -0,0 +1,210 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_app::storage::StorageFsConfig;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_meta_raft_store::key_spaces::RaftStoreEntry;
+use databend_common_meta_raft_store::ondisk::Header;
+use databend_common_meta_raft_store::ondisk::TREE_HEADER;
+use databend_common_meta_raft_store::state_machine::StateMachineMetaKey;
+use databend_common_meta_raft_store::state_machine::StateMachineMetaValue;
+use databend_common_meta_types::raft_types::new_log_id;
+use databend_common_meta_types::raft_types::Entry;
+use databend_common_meta_types::raft_types::EntryPayload;
+use databend_common_meta_types::raft_types::Vote;
+use databend_common_meta_types::Cmd;
+use databend_common_meta_types::LogEntry;
+use databend_common_meta_types::UpsertKV;
+use databend_meta::backup::BackupStorage;
+use databend_meta::backup::LogSegment;
+use databend_meta::backup::RestorePlan;
+use databend_meta::backup::SnapshotManifest;
+use databend_meta::backup::VerifyReport;
+use futures::stream;
+use pretty_assertions::assert_eq;
+use test_harness::test;
+
+use crate::testing::meta_service_test_harness;
+
+#[test(harness = meta_service_test_harness)]
+#[fastrace::trace]
+async fn test_log_segment_path() -> anyhow::Result<()> {
+    let seg = LogSegment::new(1, 100);
+    assert_eq!(
+        "logs/00000000000000000001-00000000000000000100.jsonl",
+        seg.path()
+    );
+
+    let name = seg.path().strip_prefix("logs/").unwrap().to_string();
+    assert_eq!(Some(seg), LogSegment::parse(&name));
+
+    assert_eq!(None, LogSegment::parse("1-100.json"));
+    assert_eq!(None, LogSegment::parse("100-1.jsonl"));
+    assert_eq!(None, LogSegment::parse("foo.jsonl"));
+    Ok(())
+}
+
+#[test(harness = meta_service_test_harness)]
+#[fastrace::trace]
+async fn test_restore_to_point_in_time() -> anyhow::Result<()> {
+    let d = tempfile::tempdir()?;
+    let storage = BackupStorage::new(&StorageParams::Fs(StorageFsConfig {
+        root: d.path().to_str().unwrap().to_string(),
+    }))?;
+
+    // Snapshot at 3500 ms with logs [1, 3] and the state machine applied up to 2.
+    let manifest = SnapshotManifest {
+        time_ms: 3500,
+        data_path: SnapshotManifest::data_path(3000),
+        last_applied: Some(new_log_id(1, 1, 2)),
+        last_log_id: Some(new_log_id(1, 1, 3)),
+        records: 0,
+    };
+
+    let mut lines = vec![
+        line(
+            TREE_HEADER,
+            RaftStoreEntry::new_header(Header::this_version()),
+        ),
+        line("raft_log", RaftStoreEntry::NodeId(Some(1))),
+        line(
+            "raft_log",
+            RaftStoreEntry::Vote(Some(Vote::new_committed(1, 1))),
+        ),
+        line(
+            "raft_log",
+            RaftStoreEntry::Committed(Some(new_log_id(1, 1, 3))),
+        ),
+        line("raft_log", RaftStoreEntry::Purged(None)),
+    ];
+    for i in 1..=3 {
+        lines.push(line("raft_log", RaftStoreEntry::LogEntry(entry(1, i))));
+    }
+    lines.push(line("state_machine/0", RaftStoreEntry::StateMachineMeta {
+        key: StateMachineMetaKey::LastApplied,
+        value: StateMachineMetaValue::LogId(new_log_id(1, 1, 2)),
+    }));
+
+    let records = storage
+        .write_lines(&manifest.data_path, stream::iter(lines.into_iter().map(Ok)))
+        .await?;
+    let manifest = SnapshotManifest {
+        records,
+        ..manifest
+    };
+    storage.write_manifest(&manifest).await?;
+
+    // Logs [4, 6] are proposed by a new leader at term 2.
+    let seg = LogSegment::new(4, 6);
+    let seg_lines = (4..=6).map(|i| Ok(line("raft_log", RaftStoreEntry::LogEntry(entry(2, i)))));
+    storage
+        .write_lines(&seg.path(), stream::iter(seg_lines))
+        .await?;
+
+    // Restore to the latest.
+    {
+        let plan = RestorePlan::build(&storage, None).await?;
+        assert_eq!(manifest, plan.snapshot);
+        assert_eq!(vec![seg], plan.segments);
+        assert_eq!(Some(new_log_id(2, 1, 6)), plan.last_log_id);
+        assert_eq!(vec![1, 2, 3, 4, 5, 6], restored_log_indexes(&plan));
+        assert!(plan.lines.contains(&line(
+            "raft_log",
+            RaftStoreEntry::Vote(Some(Vote::new_committed(2, 1)))
+        )));
+    }
+
+    // Restore to a point in time: logs proposed after it are not restored.
+    {
+        let plan = RestorePlan::build(&storage, Some(5500)).await?;
+        assert_eq!(Some(new_log_id(2, 1, 5)), plan.last_log_id);
+        assert_eq!(vec![1, 2, 3, 4, 5], restored_log_indexes(&plan));
+        assert!(plan.lines.contains(&line(
+            "raft_log",
+            RaftStoreEntry::Committed(Some(new_log_id(2, 1, 5)))
+        )));
+    }
+
+    // Logs applied to the snapshot are always restored.
+    {
+        let plan = RestorePlan::build(&storage, Some(3500)).await?;
+        assert_eq!(Some(new_log_id(1, 1, 3)), plan.last_log_id);
+        assert_eq!(vec![1, 2, 3], restored_log_indexes(&plan));
+    }
+
+    // No snapshot before the time.
+    {
+        let res = RestorePlan::build(&storage, Some(3000)).await;
+        assert!(res.is_err());
+    }
+
+    // Verify
+    {
+        let report = VerifyReport::build(&storage).await?;
+        assert!(report.is_ok());
+        assert_eq!(1, report.snapshots.len());
+        assert_eq!(vec![seg], report.segments);
+        assert!(report.gaps.is_empty());
+    }
+
+    // A gap in logs
+    {
+        let seg = LogSegment::new(9, 9);
+        let seg_lines = vec![Ok(line("raft_log", RaftStoreEntry::LogEntry(entry(2, 9))))];
+        storage
+            .write_lines(&seg.path(), stream::iter(seg_lines))
+            .await?;
+
+        let report = VerifyReport::build(&storage).await?;
+        assert!(report.is_ok());
+        assert_eq!(vec![(7, 9)], report.gaps);
+
+        let res = RestorePlan::build(&storage, None).await;
+        assert!(res.is_err(), "logs before the target time are missing");
+
+        let plan = RestorePlan::build(&storage, Some(6500)).await?;
+        assert_eq!(Some(new_log_id(2, 1, 6)), plan.last_log_id);
+    }
+
+    Ok(())
+}
+
+/// Build a log proposed at `index * 1000` ms.
+fn entry(term: u64, index: u64) -> Entry {
+    Entry {
+        log_id: new_log_id(term, 1, index),
+        payload: EntryPayload::Normal(LogEntry {
+            txid: None,
+            time_ms: Some(index * 1000),
+            cmd: Cmd::UpsertKV(UpsertKV::update(format!("k{}", index), b"v")),
+        }),
+    }
+}
+
+fn line(tree_name: &str, ent: RaftStoreEntry) -> String {
+    serde_json::to_string(&(tree_name, ent)).unwrap()
+}
+
+fn restored_log_indexes(plan: &RestorePlan) -> Vec<u64> {
+    plan.lines
+        .iter()
+        .filter_map(|l| {
+            let (_, ent): (String, RaftStoreEntry) = serde_json::from_str(l).unwrap();
+            match ent {
+                RaftStoreEntry::LogEntry(e) => Some(e.log_id.index),
+                _ => None,
+            }
+        })
+        .collect()
+}
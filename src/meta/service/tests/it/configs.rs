@@ -15,6 +15,8 @@
 use std::fs::File;
 use std::io::Write;
 
+use databend_common_meta_app::storage::StorageFsConfig;
+use databend_common_meta_app::storage::StorageParams;
 use databend_meta::configs::Config;
 use tempfile::tempdir;
 
@@ -65,6 +67,16 @@ join = ["j1", "j2"]
 id = 20
 sled_tree_prefix = "sled_foo"
 cluster_name = "foo_cluster"
+
+[backup]
+storage_type = "fs"
+fs_root = "backup dir"
+snapshot_interval = 600
+retention = 86400
+
+[tenant_quota]
+max_keys = 100000
+max_write_qps = 500
              "#
     )?;
 
@@ -92,6 +104,18 @@ cluster_name = "foo_cluster"
         assert_eq!(cfg.raft_config.id, 20);
         assert_eq!(cfg.raft_config.sled_tree_prefix, "sled_foo");
         assert_eq!(cfg.raft_config.cluster_name, "foo_cluster");
+        assert_eq!(
+            cfg.backup.storage,
+            StorageParams::Fs(StorageFsConfig {
+                root: "backup dir".to_string()
+            })
+        );
+        assert_eq!(cfg.backup.snapshot_interval, 600);
+        assert_eq!(cfg.backup.log_interval, 60);
+        assert_eq!(cfg.backup.retention, 86400);
+        assert_eq!(cfg.tenant_quota.max_keys, 100000);
+        assert_eq!(cfg.tenant_quota.max_value_bytes, 0);
+        assert_eq!(cfg.tenant_quota.max_write_qps, 500);
     });
 
     temp_env::with_vars(
@@ -123,5 +147,37 @@ cluster_name = "foo_cluster"
         },
     );
 
+    // Test backup config.
+    temp_env::with_vars(
+        vec![
+            (
+                "METASRV_CONFIG_FILE",
+                Some(file_path.to_str().expect("must be valid str")),
+            ),
+            ("METASRV_BACKUP_LOG_INTERVAL", Some("30")),
+        ],
+        || {
+            let cfg = Config::load_for_test().expect("load must success");
+            assert_eq!(cfg.backup.log_interval, 30);
+            assert_eq!(cfg.backup.snapshot_interval, 600);
+        },
+    );
+
+    // Test tenant quota config.
+    temp_env::with_vars(
+        vec![
+            (
+                "METASRV_CONFIG_FILE",
+                Some(file_path.to_str().expect("must be valid str")),
+            ),
+            ("METASRV_TENANT_QUOTA_MAX_VALUE_BYTES", Some("1048576")),
+        ],
+        || {
+            let cfg = Config::load_for_test().expect("load must success");
+            assert_eq!(cfg.tenant_quota.max_keys, 100000);
+            assert_eq!(cfg.tenant_quota.max_value_bytes, 1048576);
+        },
+    );
+
     Ok(())
 }
@@ -27,6 +27,7 @@ use databend_common_meta_types::protobuf as pb;
 use databend_common_meta_types::protobuf::KvMeta;
 use databend_common_meta_types::seq_value::SeqV;
 use databend_common_meta_types::MetaSpec;
+use databend_common_meta_types::ReadConsistency;
 use databend_common_meta_types::With;
 use futures::stream::StreamExt;
 use futures::TryStreamExt;
@@ -47,8 +48,8 @@ async fn test_kv_read_v1_on_leader() -> anyhow::Result<()> {
     let client = tc.grpc_client().await?;
 
     initialize_kvs(&client).await?;
-    test_streamed_mget(&client, now_sec).await?;
-    test_streamed_list(&client, now_sec).await?;
+    test_streamed_mget(&client, now_sec, ReadConsistency::Linearizable).await?;
+    test_streamed_list(&client, now_sec, ReadConsistency::Linearizable).await?;
 
     Ok(())
 }
@@ -65,16 +66,21 @@ async fn test_kv_read_v1_on_follower() -> anyhow::Result<()> {
     initialize_kvs(&client).await?;
 
     let client = tcs[1].grpc_client().await?;
-    test_streamed_mget(&client, now_sec).await?;
-    test_streamed_list(&client, now_sec).await?;
+    test_streamed_mget(&client, now_sec, ReadConsistency::Linearizable).await?;
+    test_streamed_list(&client, now_sec, ReadConsistency::Linearizable).await?;
+
+    info!("--- the follower has applied the writes, a stale read sees them too");
+    test_streamed_mget(&client, now_sec, ReadConsistency::Stale).await?;
+    test_streamed_list(&client, now_sec, ReadConsistency::Stale).await?;
 
     Ok(())
 }
 
-/// When invoke kv_read_v1() on a follower, the leader endpoint is responded in the response header.
+/// A linearizable read invoked on a follower is served by the follower,
+/// the leader endpoint is not responded and the client keeps using the follower.
 #[test(harness = meta_service_test_harness)]
 #[fastrace::trace]
-async fn test_kv_read_v1_follower_responds_leader_endpoint() -> anyhow::Result<()> {
+async fn test_kv_read_v1_follower_serves_linearizable_read() -> anyhow::Result<()> {
     let tcs = crate::tests::start_metasrv_cluster(&[0, 1, 2]).await?;
 
     let addresses = tcs
@@ -113,10 +119,10 @@ async fn test_kv_read_v1_follower_responds_leader_endpoint() -> anyhow::Result<(
         }))
         .await?;
 
-    // Current leader endpoint updated, will connect to a0.
+    // The read is not forwarded to the leader, still using a1.
     {
         let eclient = client.make_established_client().await?;
-        assert_eq!(a0(), eclient.target_endpoint(),);
+        assert_eq!(a1(), eclient.target_endpoint(),);
     }
 
     Ok(())
@@ -144,13 +150,20 @@ async fn initialize_kvs(client: &Arc<ClientHandle>) -> anyhow::Result<()> {
 }
 
 /// Test streamed mget on a grpc meta-service client
-async fn test_streamed_mget(client: &Arc<ClientHandle>, now_sec: u64) -> anyhow::Result<()> {
-    info!("--- test streamed mget");
+async fn test_streamed_mget(
+    client: &Arc<ClientHandle>,
+    now_sec: u64,
+    consistency: ReadConsistency,
+) -> anyhow::Result<()> {
+    info!("--- test streamed mget, consistency: {}", consistency);
 
     let strm = client
-        .request(Streamed(MGetKVReq {
-            keys: vec![s("a"), s("b")],
-        }))
+        .request(
+            Streamed(MGetKVReq {
+                keys: vec![s("a"), s("b")],
+            })
+            .with_consistency(consistency),
+        )
         .await?;
 
     let mut got = strm.map_err(|e| e.to_string()).collect::<Vec<_>>().await;
@@ -187,11 +200,15 @@ async fn test_streamed_mget(client: &Arc<ClientHandle>, now_sec: u64) -> anyhow:
 }
 
 /// Test streamed list on a grpc meta-service client
-async fn test_streamed_list(client: &Arc<ClientHandle>, _now_sec: u64) -> anyhow::Result<()> {
-    info!("--- test streamed list");
+async fn test_streamed_list(
+    client: &Arc<ClientHandle>,
+    _now_sec: u64,
+    consistency: ReadConsistency,
+) -> anyhow::Result<()> {
+    info!("--- test streamed list, consistency: {}", consistency);
 
     let strm = client
-        .request(Streamed(ListKVReq { prefix: s("c") }))
+        .request(Streamed(ListKVReq { prefix: s("c") }).with_consistency(consistency))
         .await?;
 
     let got = strm.map_err(|e| e.to_string()).collect::<Vec<_>>().await;
@@ -127,11 +127,7 @@ async fn test_watch() -> anyhow::Result<()> {
     let mut seq: u64 = 1;
     // 1.update some events
     {
-        let watch = WatchRequest {
-            key: "a".to_string(),
-            key_end: Some("z".to_string()),
-            filter_type: FilterType::All.into(),
-        };
+        let watch = WatchRequest::new(s("a"), Some(s("z")));
 
         let key_a = s("a");
         let key_b = s("b");
@@ -184,12 +180,8 @@ async fn test_watch() -> anyhow::Result<()> {
     // 2. test filter
     {
         let key_str = "1";
-        let watch = WatchRequest {
-            key: s(key_str),
-            key_end: None,
-            // filter only delete events
-            filter_type: FilterType::Delete.into(),
-        };
+        // filter only delete events
+        let watch = WatchRequest::new(s(key_str), None).with_filter(FilterType::Delete);
 
         let key = s(key_str);
         let val = b("old");
@@ -248,11 +240,7 @@ async fn test_watch() -> anyhow::Result<()> {
 
         let (start, end) = kvapi::prefix_to_range(watch_prefix)?;
 
-        let watch = WatchRequest {
-            key: start,
-            key_end: Some(end),
-            filter_type: FilterType::All.into(),
-        };
+        let watch = WatchRequest::new(start, Some(end));
 
         let conditions = vec![TxnCondition {
             key: txn_key.clone(),
@@ -359,11 +347,7 @@ async fn test_watch_expired_events() -> anyhow::Result<()> {
     let watch_client = make_client(&addr)?;
     let mut client_stream = {
         let (start, end) = kvapi::prefix_to_range(watch_prefix)?;
-        let watch = WatchRequest {
-            key: start,
-            key_end: Some(end),
-            filter_type: FilterType::All.into(),
-        };
+        let watch = WatchRequest::new(start, Some(end));
         watch_client.request(watch).await?
     };
 
@@ -437,6 +421,176 @@ async fn test_watch_expired_events() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test(harness = meta_service_test_harness)]
+#[fastrace::trace]
+async fn test_watch_initial_flush() -> anyhow::Result<()> {
+    // - Write some data.
+    // - Watch with initial_flush.
+    // - Assert the current values are sent before change events.
+
+    let (_tc, addr) = crate::tests::start_metasrv().await?;
+
+    let client = make_client(&addr)?;
+    client
+        .upsert_kv(UpsertKVReq::update("f_a", &b("a")))
+        .await?;
+    client
+        .upsert_kv(UpsertKVReq::update("f_b", &b("b")))
+        .await?;
+    client
+        .upsert_kv(UpsertKVReq::update("g_a", &b("a")))
+        .await?;
+
+    let (start, end) = kvapi::prefix_to_range("f_")?;
+    let watch = WatchRequest::new(start, Some(end)).with_initial_flush(true);
+    let mut watch_stream = client.request(watch).await?;
+
+    info!("--- initialization events are sent in key order");
+    {
+        let msg = watch_stream.message().await?.unwrap();
+        assert!(msg.is_initialization);
+        assert_eq!(Some(add_event("f_a", 1, "a", None)), msg.event);
+
+        let msg = watch_stream.message().await?.unwrap();
+        assert!(msg.is_initialization);
+        assert_eq!(Some(add_event("f_b", 2, "b", None)), msg.event);
+    }
+
+    info!("--- then change events");
+    {
+        client
+            .upsert_kv(UpsertKVReq::update("f_c", &b("c")))
+            .await?;
+
+        let msg = watch_stream.message().await?.unwrap();
+        assert!(!msg.is_initialization);
+        assert_eq!(Some(add_event("f_c", 4, "c", None)), msg.event);
+    }
+
+    Ok(())
+}
+
+#[test(harness = meta_service_test_harness)]
+#[fastrace::trace]
+async fn test_watch_initial_flush_in_batches() -> anyhow::Result<()> {
+    // - Write more keys than one initial flush batch.
+    // - Watch with initial_flush.
+    // - Assert every current value is sent once, in key order, before change events.
+
+    let (_tc, addr) = crate::tests::start_metasrv().await?;
+
+    let client = make_client(&addr)?;
+
+    let n = 2_500;
+    let keys = (0..n).map(|i| format!("m_{:05}", i)).collect::<Vec<_>>();
+    for chunk in keys.chunks(500) {
+        let txn =
+            TxnRequest::unconditional(chunk.iter().map(|k| TxnOp::put(k.clone(), b(k))).collect());
+        client.transaction(txn).await?;
+    }
+
+    let (start, end) = kvapi::prefix_to_range("m_")?;
+    let watch = WatchRequest::new(start, Some(end)).with_initial_flush(true);
+    let mut watch_stream = client.request(watch).await?;
+
+    for (i, key) in keys.iter().enumerate() {
+        let msg = watch_stream.message().await?.unwrap();
+        assert!(msg.is_initialization);
+        assert_eq!(Some(add_event(key, i as u64 + 1, key, None)), msg.event);
+    }
+
+    client
+        .upsert_kv(UpsertKVReq::update("m_z", &b("z")))
+        .await?;
+
+    let msg = watch_stream.message().await?.unwrap();
+    assert!(!msg.is_initialization);
+    assert_eq!(Some(add_event("m_z", n + 1, "z", None)), msg.event);
+
+    Ok(())
+}
+
+#[test(harness = meta_service_test_harness)]
+#[fastrace::trace]
+async fn test_watch_start_seq() -> anyhow::Result<()> {
+    // - Write some data.
+    // - Watch from a seq.
+    // - Assert the changes after the seq are replayed before new change events.
+    // - Assert watching from a compacted seq is rejected.
+
+    let (_tc, addr) = crate::tests::start_metasrv().await?;
+
+    let client = make_client(&addr)?;
+    client
+        .upsert_kv(UpsertKVReq::update("r_a", &b("a")))
+        .await?;
+    client
+        .upsert_kv(UpsertKVReq::update("r_b", &b("b")))
+        .await?;
+    client
+        .upsert_kv(UpsertKVReq::update("r_a", &b("a2")))
+        .await?;
+    client.upsert_kv(UpsertKVReq::delete("r_b")).await?;
+
+    let (start, end) = kvapi::prefix_to_range("r_")?;
+
+    info!("--- replay changes after seq 2");
+    {
+        let watch = WatchRequest::new(start.clone(), Some(end.clone())).with_start_seq(2);
+        let mut watch_stream = client.request(watch).await?;
+
+        let msg = watch_stream.message().await?.unwrap();
+        assert!(!msg.is_initialization);
+        assert_eq!(
+            Some(Event {
+                key: s("r_a"),
+                prev: pb_seqv(1, "a", None),
+                current: pb_seqv(3, "a2", None),
+            }),
+            msg.event
+        );
+
+        let msg = watch_stream.message().await?.unwrap();
+        assert_eq!(Some(del_event("r_b", 2, "b", None)), msg.event);
+
+        client
+            .upsert_kv(UpsertKVReq::update("r_c", &b("c")))
+            .await?;
+
+        let msg = watch_stream.message().await?.unwrap();
+        assert_eq!(Some(add_event("r_c", 4, "c", None)), msg.event);
+    }
+
+    info!("--- changes before the meta node starts are not available");
+    {
+        let watch = WatchRequest::new(start.clone(), Some(end.clone())).with_start_seq(0);
+        let res = client.request(watch).await;
+
+        let err = res.unwrap_err();
+        assert!(err.to_string().contains("compacted"), "got: {}", err);
+    }
+
+    info!("--- a seq this node has not yet applied is rejected");
+    {
+        let watch = WatchRequest::new(start.clone(), Some(end.clone())).with_start_seq(100);
+        let res = client.request(watch).await;
+
+        let err = res.unwrap_err();
+        assert!(err.to_string().contains("behind start_seq"), "got: {}", err);
+    }
+
+    info!("--- initial_flush and start_seq can not be used together");
+    {
+        let watch = WatchRequest::new(start, Some(end))
+            .with_initial_flush(true)
+            .with_start_seq(2);
+        let res = client.request(watch).await;
+        assert!(res.is_err());
+    }
+
+    Ok(())
+}
+
 #[test(harness = meta_service_test_harness)]
 #[fastrace::trace]
 async fn test_watch_stream_count() -> anyhow::Result<()> {
@@ -444,11 +598,7 @@ async fn test_watch_stream_count() -> anyhow::Result<()> {
 
     let (tc, addr) = crate::tests::start_metasrv().await?;
 
-    let watch_req = || WatchRequest {
-        key: "a".to_string(),
-        key_end: Some("z".to_string()),
-        filter_type: FilterType::All.into(),
-    };
+    let watch_req = || WatchRequest::new(s("a"), Some(s("z")));
 
     let client1 = make_client(&addr)?;
     let _watch_stream1 = client1.request(watch_req()).await?;
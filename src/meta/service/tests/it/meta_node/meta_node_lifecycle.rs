@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeSet;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -292,6 +293,59 @@ async fn test_meta_node_join_rejoin() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test(harness = meta_service_test_harness)]
+#[fastrace::trace]
+async fn test_meta_node_join_reject_old_version() -> anyhow::Result<()> {
+    // - Bring up a cluster
+    // - Join a node without version and a node older than MIN_META_SEMVER, both are rejected.
+
+    let (mut _nlog, tcs) = start_meta_node_cluster(btreeset![0], btreeset![]).await?;
+    let all = test_context_nodes(&tcs);
+
+    let leader_id = all[0].get_leader().await?.unwrap();
+    let leader = all[leader_id as usize].clone();
+
+    let node_id = 1;
+    let tc1 = MetaSrvTestContext::new(node_id);
+    let _mn1 = MetaNode::open(&tc1.config.raft_config).await?;
+
+    for version in [None, Some("1.2.655")] {
+        info!("--- join node-1 with version {:?}", version);
+
+        let mut req = join_req(
+            node_id,
+            tc1.config.raft_config.raft_api_addr().await?,
+            tc1.config.grpc_api_advertise_address(),
+            1,
+        );
+        req.body = match req.body {
+            ForwardRequestBody::Join(j) => ForwardRequestBody::Join(j.with_version(version)),
+            _ => unreachable!(),
+        };
+
+        let res = leader.handle_forwardable_request(req).await;
+        let err = res.unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("older than the min compatible version"),
+            "{}",
+            err
+        );
+    }
+
+    let voters = leader
+        .raft
+        .metrics()
+        .borrow()
+        .membership_config
+        .membership()
+        .voter_ids()
+        .collect::<BTreeSet<_>>();
+    assert_eq!(btreeset! {0}, voters);
+
+    Ok(())
+}
+
 #[test(harness = meta_service_test_harness)]
 #[fastrace::trace]
 async fn test_meta_node_join_with_state() -> anyhow::Result<()> {
@@ -0,0 +1,197 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_meta_api::serialize_struct;
+use databend_common_meta_app::schema::DBIdTableName;
+use databend_common_meta_app::schema::DatabaseNameIdentRaw;
+use databend_common_meta_types::Cmd;
+use databend_common_meta_types::LogEntry;
+use databend_common_meta_types::MetaAPIError;
+use databend_common_meta_types::QuotaKind;
+use databend_common_meta_types::TxnOp;
+use databend_common_meta_types::TxnRequest;
+use databend_common_meta_types::UpsertKV;
+use databend_meta::configs::TenantQuotaConfig;
+use databend_meta::meta_service::tenant_quota::owner_id_of_key;
+use databend_meta::meta_service::tenant_quota::tenant_of_key;
+use databend_meta::meta_service::tenant_quota::OwnerId;
+use log::info;
+use test_harness::test;
+
+use crate::testing::meta_service_test_harness;
+use crate::tests::meta_node::start_meta_node_leader;
+
+#[test]
+fn test_tenant_of_key() {
+    assert_eq!(Some("t1"), tenant_of_key("__fd_database/t1/db1"));
+    assert_eq!(Some("t1"), tenant_of_key("__fd_users/t1"));
+    assert_eq!(None, tenant_of_key("__fd_table_by_id/123"));
+    assert_eq!(None, tenant_of_key("__fd_id_gen/database_id"));
+    assert_eq!(None, tenant_of_key("__fd_database//db1"));
+    assert_eq!(None, tenant_of_key("foo/t1/bar"));
+    assert_eq!(None, tenant_of_key("__fd_database"));
+}
+
+#[test]
+fn test_owner_id_of_key() {
+    assert_eq!(
+        Some(OwnerId::Table(123)),
+        owner_id_of_key("__fd_table_copied_files/123/a.csv")
+    );
+    assert_eq!(
+        Some(OwnerId::Table(123)),
+        owner_id_of_key("__fd_table_by_id/123")
+    );
+    assert_eq!(
+        Some(OwnerId::Database(1)),
+        owner_id_of_key("__fd_table/1/tb1")
+    );
+    assert_eq!(
+        Some(OwnerId::Database(1)),
+        owner_id_of_key("__fd_database_by_id/1")
+    );
+    assert_eq!(None, owner_id_of_key("__fd_database/t1/db1"));
+    assert_eq!(None, owner_id_of_key("__fd_index_by_id/1"));
+    assert_eq!(None, owner_id_of_key("__fd_id_gen/database_id"));
+}
+
+#[test(harness = meta_service_test_harness)]
+#[fastrace::trace]
+async fn test_meta_node_tenant_quota() -> anyhow::Result<()> {
+    let (_id, tc) = start_meta_node_leader().await?;
+    let leader = tc.meta_node();
+
+    leader.tenant_quota.set_limits(TenantQuotaConfig {
+        max_keys: 2,
+        max_value_bytes: 10,
+        max_write_qps: 0,
+    });
+
+    let upsert = |key: &str, value: &str| {
+        LogEntry::new(Cmd::UpsertKV(UpsertKV::update(key, value.as_bytes())))
+    };
+
+    info!("--- write keys within the limit");
+    {
+        leader.write(upsert("__fd_database/t1/a", "12")).await?;
+        leader.write(upsert("__fd_database/t1/b", "34")).await?;
+    }
+
+    info!("--- a new key exceeds the key limit");
+    {
+        let res = leader.write(upsert("__fd_database/t1/c", "56")).await;
+        let Err(MetaAPIError::QuotaExceeded(e)) = res else {
+            panic!("expect QuotaExceeded, got: {:?}", res);
+        };
+        assert_eq!("t1", e.tenant);
+        assert_eq!(QuotaKind::Keys, e.kind);
+        assert_eq!(2, e.limit);
+        assert_eq!(3, e.requested);
+    }
+
+    info!("--- updating an existing key does not add a key, but is limited by value bytes");
+    {
+        leader.write(upsert("__fd_database/t1/a", "123456")).await?;
+
+        let res = leader.write(upsert("__fd_database/t1/b", "34567")).await;
+        let Err(MetaAPIError::QuotaExceeded(e)) = res else {
+            panic!("expect QuotaExceeded, got: {:?}", res);
+        };
+        assert_eq!(QuotaKind::ValueBytes, e.kind);
+        assert_eq!(11, e.requested);
+    }
+
+    info!("--- other tenants and keys without tenant are not affected");
+    {
+        leader.write(upsert("__fd_database/t2/a", "12")).await?;
+        leader.write(upsert("__fd_table_by_id/1", "12")).await?;
+    }
+
+    info!("--- check usage");
+    {
+        let usage = leader.tenant_usage().await?;
+        let t1 = usage.get("t1").unwrap();
+        assert_eq!(2, t1.keys);
+        assert_eq!(8, t1.value_bytes);
+
+        let t2 = usage.get("t2").unwrap();
+        assert_eq!(1, t2.keys);
+        assert_eq!(2, t2.value_bytes);
+    }
+
+    Ok(())
+}
+
+#[test(harness = meta_service_test_harness)]
+#[fastrace::trace]
+async fn test_meta_node_tenant_quota_table_id_keys() -> anyhow::Result<()> {
+    let (_id, tc) = start_meta_node_leader().await?;
+    let leader = tc.meta_node();
+
+    leader.tenant_quota.set_limits(TenantQuotaConfig {
+        max_keys: 4,
+        max_value_bytes: 0,
+        max_write_qps: 0,
+    });
+
+    let upsert =
+        |key: &str, value: Vec<u8>| LogEntry::new(Cmd::UpsertKV(UpsertKV::update(key, &value)));
+
+    info!("--- a database of t1");
+    {
+        let name = serialize_struct(&DatabaseNameIdentRaw::new("t1", "db1"))?;
+        leader
+            .write(upsert("__fd_database_id_to_name/1", name))
+            .await?;
+    }
+
+    info!("--- a table and its copied file, created in one transaction");
+    {
+        let name = serialize_struct(&DBIdTableName::new(1, "tb1"))?;
+        let txn = TxnRequest::unconditional(vec![
+            TxnOp::put("__fd_table_copied_files/2/a.csv", b"x".to_vec()),
+            TxnOp::put("__fd_table_id_to_name/2", name),
+        ]);
+        leader.write(LogEntry::new(Cmd::Transaction(txn))).await?;
+
+        let usage = leader.tenant_usage().await?;
+        assert_eq!(3, usage.get("t1").unwrap().keys);
+    }
+
+    info!("--- copied files are limited by the quota of the tenant of the table");
+    {
+        leader
+            .write(upsert("__fd_table_copied_files/2/b.csv", b"x".to_vec()))
+            .await?;
+
+        let res = leader
+            .write(upsert("__fd_table_copied_files/2/c.csv", b"x".to_vec()))
+            .await;
+        let Err(MetaAPIError::QuotaExceeded(e)) = res else {
+            panic!("expect QuotaExceeded, got: {:?}", res);
+        };
+        assert_eq!("t1", e.tenant);
+        assert_eq!(QuotaKind::Keys, e.kind);
+        assert_eq!(5, e.requested);
+    }
+
+    info!("--- keys of a table without a known tenant are not limited");
+    {
+        leader
+            .write(upsert("__fd_table_copied_files/3/a.csv", b"x".to_vec()))
+            .await?;
+    }
+
+    Ok(())
+}
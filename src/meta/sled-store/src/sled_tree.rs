@@ -191,7 +191,7 @@ impl SledTree {
                         MetaStorageError::TransactionConflict => {
                             Err(ConflictableTransactionError::Conflict)
                         }
-                        MetaStorageError::Damaged(_e) => {
+                        MetaStorageError::Damaged(_e) | MetaStorageError::Unsupported(_e) => {
                             Err(ConflictableTransactionError::Abort(meta_sto_err))
                         }
                     }
@@ -25,6 +25,10 @@ pub enum MetaStorageError {
     #[error("Data damaged: {0}")]
     Damaged(AnyError),
 
+    /// The request is not supported by this storage.
+    #[error("Unsupported: {0}")]
+    Unsupported(AnyError),
+
     // TODO(1): remove this error
     /// An internal error that inform txn to retry.
     #[error("Conflict when execute transaction, just retry")]
@@ -42,6 +46,7 @@ impl MetaStorageError {
     pub fn name(&self) -> &'static str {
         match self {
             MetaStorageError::Damaged(_) => "Damaged",
+            MetaStorageError::Unsupported(_) => "Unsupported",
             MetaStorageError::TransactionConflict => "TransactionConflict",
         }
     }
@@ -66,6 +66,18 @@ fn build_proto() {
             "TxnDeleteByPrefixRequest",
             "#[derive(Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize, deepsize::DeepSizeOf)]",
         )
+        .type_attribute(
+            "TxnFetchAddRequest",
+            "#[derive(Eq, serde::Serialize, serde::Deserialize, deepsize::DeepSizeOf)]",
+        )
+        .type_attribute(
+            "TxnListRangeRequest",
+            "#[derive(Eq, serde::Serialize, serde::Deserialize, deepsize::DeepSizeOf)]",
+        )
+        .type_attribute(
+            "TxnGetManyRequest",
+            "#[derive(Eq, serde::Serialize, serde::Deserialize, deepsize::DeepSizeOf)]",
+        )
         .type_attribute(
             "TxnCondition.ConditionResult",
             "#[derive(serde::Serialize, serde::Deserialize, num_derive::FromPrimitive, deepsize::DeepSizeOf)]",
@@ -106,6 +118,18 @@ fn build_proto() {
             "TxnDeleteByPrefixResponse",
             "#[derive(Eq, serde::Serialize, serde::Deserialize, deepsize::DeepSizeOf)]",
         )
+        .type_attribute(
+            "TxnFetchAddResponse",
+            "#[derive(Eq, serde::Serialize, serde::Deserialize, deepsize::DeepSizeOf)]",
+        )
+        .type_attribute(
+            "TxnListRangeResponse",
+            "#[derive(Eq, serde::Serialize, serde::Deserialize, deepsize::DeepSizeOf)]",
+        )
+        .type_attribute(
+            "TxnGetManyResponse",
+            "#[derive(Eq, serde::Serialize, serde::Deserialize, deepsize::DeepSizeOf)]",
+        )
         .type_attribute(
             "TxnOpResponse.response",
             "#[derive(Eq, serde::Serialize, serde::Deserialize, derive_more::TryInto, deepsize::DeepSizeOf)]",
@@ -138,6 +162,8 @@ fn build_proto() {
             "TxnPutRequest.ttl_ms",
             r#"#[serde(skip_serializing_if = "Option::is_none")]"#,
         )
+        .field_attribute("WatchRequest.initial_flush", r#"#[serde(default)]"#)
+        .field_attribute("WatchResponse.is_initialization", r#"#[serde(default)]"#)
         .compile_protos_with_config(config, &protos, &[&proto_dir])
         .unwrap();
 }
@@ -27,6 +27,7 @@ use crate::raft_types::RaftError;
 use crate::InvalidArgument;
 use crate::InvalidReply;
 use crate::MetaNetworkError;
+use crate::TenantQuotaExceeded;
 
 /// Errors raised when meta-service handling a request.
 #[derive(thiserror::Error, serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
@@ -52,6 +53,10 @@ pub enum MetaAPIError {
     /// This is used for client-side to build a remote-error when receiving server errors
     #[error(transparent)]
     RemoteError(MetaDataError),
+
+    /// A write is rejected by the leader because of a per-tenant limit.
+    #[error(transparent)]
+    QuotaExceeded(#[from] TenantQuotaExceeded),
 }
 
 impl MetaAPIError {
@@ -86,6 +91,10 @@ impl MetaAPIError {
                 MetaDataError::ChangeMembershipError(_) => true,
                 MetaDataError::ReadError(_) => false,
             },
+            MetaAPIError::QuotaExceeded(_) => {
+                // Retrying immediately does not help, let the caller decide.
+                false
+            }
         }
     }
 
@@ -96,6 +105,7 @@ impl MetaAPIError {
             MetaAPIError::NetworkError(_) => "NetworkError",
             MetaAPIError::DataError(_) => "DataError",
             MetaAPIError::RemoteError(_) => "RemoteError",
+            MetaAPIError::QuotaExceeded(_) => "QuotaExceeded",
         }
     }
 }
@@ -109,6 +119,9 @@ pub enum MetaOperationError {
 
     #[error(transparent)]
     DataError(#[from] MetaDataError),
+
+    #[error(transparent)]
+    QuotaExceeded(#[from] TenantQuotaExceeded),
 }
 
 impl From<MetaOperationError> for MetaAPIError {
@@ -116,6 +129,7 @@ impl From<MetaOperationError> for MetaAPIError {
         match e {
             MetaOperationError::ForwardToLeader(e) => e.into(),
             MetaOperationError::DataError(d) => d.into(),
+            MetaOperationError::QuotaExceeded(q) => q.into(),
         }
     }
 }
@@ -214,6 +228,9 @@ impl From<RaftError<ClientWriteError>> for MetaAPIError {
 
 impl From<MetaAPIError> for ErrorCode {
     fn from(e: MetaAPIError) -> Self {
-        ErrorCode::MetaServiceError(e.to_string())
+        match e {
+            MetaAPIError::QuotaExceeded(q) => ErrorCode::TenantQuotaExceeded(q.to_string()),
+            _ => ErrorCode::MetaServiceError(e.to_string()),
+        }
     }
 }
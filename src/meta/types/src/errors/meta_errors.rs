@@ -81,6 +81,9 @@ impl From<errors::IncompleteStream> for MetaError {
 
 impl From<MetaError> for ErrorCode {
     fn from(e: MetaError) -> Self {
-        ErrorCode::MetaServiceError(e.to_string())
+        match e {
+            MetaError::APIError(api_err) => api_err.into(),
+            _ => ErrorCode::MetaServiceError(e.to_string()),
+        }
     }
 }
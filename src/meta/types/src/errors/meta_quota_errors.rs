@@ -0,0 +1,67 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+/// The kind of a per-tenant limit enforced by meta-service.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuotaKind {
+    /// Total number of keys.
+    Keys,
+    /// Total size of values in bytes.
+    ValueBytes,
+    /// Number of write requests per second.
+    WriteQps,
+}
+
+impl QuotaKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            QuotaKind::Keys => "keys",
+            QuotaKind::ValueBytes => "value_bytes",
+            QuotaKind::WriteQps => "write_qps",
+        }
+    }
+}
+
+impl fmt::Display for QuotaKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A write request is rejected because it would exceed a per-tenant limit.
+#[derive(thiserror::Error, serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+#[error(
+    "TenantQuotaExceeded: tenant '{tenant}' exceeds {kind} limit: {limit}, requested: {requested}"
+)]
+pub struct TenantQuotaExceeded {
+    pub tenant: String,
+    pub kind: QuotaKind,
+    pub limit: u64,
+
+    /// The usage if the request were applied.
+    pub requested: u64,
+}
+
+impl TenantQuotaExceeded {
+    pub fn new(tenant: impl ToString, kind: QuotaKind, limit: u64, requested: u64) -> Self {
+        Self {
+            tenant: tenant.to_string(),
+            kind,
+            limit,
+            requested,
+        }
+    }
+}
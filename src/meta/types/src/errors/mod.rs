@@ -137,6 +137,9 @@
 //!   defined for a local request handler without forwarding. It is finally converted to `MetaApiError`.
 //!
 //! - `ForwardRPCError` is another intermediate error to wrap a result of a forwarded request.
+//!
+//! - `TenantQuotaExceeded` is returned by the leader when a write would exceed a per-tenant limit.
+//!   It is a variant of both `MetaOperationError` and `MetaApiError`.
 
 pub mod meta_api_errors;
 pub mod meta_client_errors;
@@ -144,6 +147,7 @@ pub mod meta_errors;
 pub mod meta_handshake_errors;
 pub mod meta_management_error;
 pub mod meta_network_errors;
+pub mod meta_quota_errors;
 pub mod meta_raft_errors;
 pub mod meta_startup_errors;
 pub mod rpc_errors;
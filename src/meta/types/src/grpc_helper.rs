@@ -24,8 +24,10 @@ use crate::protobuf::RaftReply;
 use crate::protobuf::RaftRequest;
 use crate::raft_types::RaftError;
 use crate::Endpoint;
+use crate::ReadConsistency;
 
 const HEADER_LEADER: &str = "x-databend-meta-leader-grpc-endpoint";
+const HEADER_READ_CONSISTENCY: &str = "x-databend-meta-read-consistency";
 // const HEADER_LEADER_BIN: &str = "x-databend-meta-leader-grpc-endpoint-bin";
 
 pub struct GrpcHelper;
@@ -85,6 +87,32 @@ impl GrpcHelper {
         }
     }
 
+    /// Specify the consistency a read request requires.
+    pub fn add_request_read_consistency<T>(
+        req: &mut tonic::Request<T>,
+        consistency: ReadConsistency,
+    ) {
+        let v = MetadataValue::from_static(consistency.as_str());
+        req.metadata_mut().insert(HEADER_READ_CONSISTENCY, v);
+    }
+
+    /// Retrieve the consistency a read request requires.
+    ///
+    /// A request without it, e.g., sent by an older client, requires linearizable read.
+    pub fn get_request_read_consistency<T>(
+        req: &tonic::Request<T>,
+    ) -> Result<ReadConsistency, tonic::Status> {
+        let Some(v) = req.metadata().get(HEADER_READ_CONSISTENCY) else {
+            return Ok(ReadConsistency::default());
+        };
+
+        let s = v.to_str().map_err(|e| {
+            tonic::Status::invalid_argument(format!("invalid read consistency: {}", e))
+        })?;
+
+        ReadConsistency::from_str(s).map_err(tonic::Status::invalid_argument)
+    }
+
     /// Retrieve leader endpoint from the reply.
     pub fn get_response_meta_leader<T>(reply: &tonic::Response<T>) -> Option<Endpoint> {
         let metadata = reply.metadata();
@@ -31,6 +31,7 @@ mod non_empty;
 mod operation;
 mod raft_snapshot_data;
 mod raft_txid;
+mod read_consistency;
 mod seq_errors;
 mod seq_num;
 mod time;
@@ -76,6 +77,8 @@ pub use errors::meta_network_errors::InvalidArgument;
 pub use errors::meta_network_errors::InvalidReply;
 pub use errors::meta_network_errors::MetaNetworkError;
 pub use errors::meta_network_errors::MetaNetworkResult;
+pub use errors::meta_quota_errors::QuotaKind;
+pub use errors::meta_quota_errors::TenantQuotaExceeded;
 pub use errors::meta_startup_errors::MetaStartupError;
 pub use errors::rpc_errors::ForwardRPCError;
 pub use eval_expire_time::EvalExpireTime;
@@ -95,8 +98,14 @@ pub use protobuf::TxnDeleteByPrefixRequest;
 pub use protobuf::TxnDeleteByPrefixResponse;
 pub use protobuf::TxnDeleteRequest;
 pub use protobuf::TxnDeleteResponse;
+pub use protobuf::TxnFetchAddRequest;
+pub use protobuf::TxnFetchAddResponse;
+pub use protobuf::TxnGetManyRequest;
+pub use protobuf::TxnGetManyResponse;
 pub use protobuf::TxnGetRequest;
 pub use protobuf::TxnGetResponse;
+pub use protobuf::TxnListRangeRequest;
+pub use protobuf::TxnListRangeResponse;
 pub use protobuf::TxnOp;
 pub use protobuf::TxnOpResponse;
 pub use protobuf::TxnPutRequest;
@@ -104,6 +113,7 @@ pub use protobuf::TxnPutResponse;
 pub use protobuf::TxnReply;
 pub use protobuf::TxnRequest;
 pub use raft_txid::RaftTxId;
+pub use read_consistency::ReadConsistency;
 pub use seq_errors::ConflictSeq;
 pub use seq_num::SeqNum;
 pub use seq_value::KVMeta;
@@ -29,8 +29,14 @@ use crate::TxnDeleteByPrefixRequest;
 use crate::TxnDeleteByPrefixResponse;
 use crate::TxnDeleteRequest;
 use crate::TxnDeleteResponse;
+use crate::TxnFetchAddRequest;
+use crate::TxnFetchAddResponse;
+use crate::TxnGetManyRequest;
+use crate::TxnGetManyResponse;
 use crate::TxnGetRequest;
 use crate::TxnGetResponse;
+use crate::TxnListRangeRequest;
+use crate::TxnListRangeResponse;
 use crate::TxnOp;
 use crate::TxnOpResponse;
 use crate::TxnPutRequest;
@@ -153,6 +159,15 @@ impl Display for txn_op::Request {
             Request::DeleteByPrefix(r) => {
                 write!(f, "DeleteByPrefix({})", r)
             }
+            Request::FetchAdd(r) => {
+                write!(f, "FetchAdd({})", r)
+            }
+            Request::ListRange(r) => {
+                write!(f, "ListRange({})", r)
+            }
+            Request::GetMany(r) => {
+                write!(f, "GetMany({})", r)
+            }
         }
     }
 }
@@ -192,6 +207,30 @@ impl Display for TxnDeleteByPrefixRequest {
     }
 }
 
+impl Display for TxnFetchAddRequest {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "FetchAdd key={} delta={}", self.key, self.delta)
+    }
+}
+
+impl Display for TxnListRangeRequest {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "ListRange [{}, {}) limit={}",
+            self.start,
+            OptionDisplay { t: &self.end },
+            self.limit
+        )
+    }
+}
+
+impl Display for TxnGetManyRequest {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "GetMany keys={}", VecDisplay::new_at_most(&self.keys, 5))
+    }
+}
+
 impl Display for Target {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
@@ -201,6 +240,9 @@ impl Display for Target {
             Target::Seq(seq) => {
                 write!(f, "seq({})", seq)
             }
+            Target::KeysWithPrefix(n) => {
+                write!(f, "keys_with_prefix({})", n)
+            }
         }
     }
 }
@@ -238,6 +280,15 @@ impl Display for Response {
             Response::DeleteByPrefix(r) => {
                 write!(f, "DeleteByPrefix: {}", r)
             }
+            Response::FetchAdd(r) => {
+                write!(f, "FetchAdd: {}", r)
+            }
+            Response::ListRange(r) => {
+                write!(f, "ListRange: {}", r)
+            }
+            Response::GetMany(r) => {
+                write!(f, "GetMany: {}", r)
+            }
         }
     }
 }
@@ -284,6 +335,37 @@ impl Display for TxnDeleteByPrefixResponse {
     }
 }
 
+impl Display for TxnFetchAddResponse {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "FetchAdd-resp: key={}, success={}, before={}, after={}, seq={}",
+            self.key, self.success, self.before, self.after, self.seq
+        )
+    }
+}
+
+impl Display for TxnListRangeResponse {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "ListRange-resp: items={}, more={}",
+            VecDisplay::new_at_most(&self.items, 5),
+            self.more
+        )
+    }
+}
+
+impl Display for TxnGetManyResponse {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "GetMany-resp: values={}",
+            VecDisplay::new_at_most(&self.values, 5)
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
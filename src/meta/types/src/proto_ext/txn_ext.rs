@@ -31,6 +31,12 @@ impl TxnRequest {
 }
 
 impl pb::TxnCondition {
+    /// The max number of keys a `KeysWithPrefix` condition counts.
+    ///
+    /// Counting stops after `MAX_KEYS_WITH_PREFIX + 1` keys, so that a condition never scans
+    /// an unbounded range when applying a raft log.
+    pub const MAX_KEYS_WITH_PREFIX: u64 = 10_000;
+
     /// Create a txn condition that checks if the `seq` matches.
     pub fn eq_seq(key: impl ToString, seq: u64) -> Self {
         Self::match_seq(key, pb::txn_condition::ConditionResult::Eq, seq)
@@ -44,6 +50,19 @@ impl pb::TxnCondition {
             target: Some(pb::txn_condition::Target::Seq(seq)),
         }
     }
+
+    /// Create a txn condition that compares the number of keys with the `prefix` to `count`.
+    pub fn match_keys_with_prefix(
+        prefix: impl ToString,
+        op: pb::txn_condition::ConditionResult,
+        count: u64,
+    ) -> Self {
+        Self {
+            key: prefix.to_string(),
+            expected: op as i32,
+            target: Some(pb::txn_condition::Target::KeysWithPrefix(count)),
+        }
+    }
 }
 
 impl pb::TxnOp {
@@ -115,6 +134,39 @@ impl pb::TxnOp {
             })),
         }
     }
+
+    /// Create a new `TxnOp` that atomically adds `delta` to the integer stored at `key`.
+    pub fn fetch_add(key: impl ToString, delta: i64) -> Self {
+        pb::TxnOp {
+            request: Some(pb::txn_op::Request::FetchAdd(pb::TxnFetchAddRequest {
+                key: key.to_string(),
+                delta,
+            })),
+        }
+    }
+
+    /// Create a new `TxnOp` that lists at most `limit` records in range `[start, end)`.
+    ///
+    /// `end == None` means unbounded.
+    /// `limit` is capped to [`pb::TxnListRangeRequest::MAX_LIMIT`], `0` means the max.
+    pub fn list_range(start: impl ToString, end: Option<String>, limit: u64) -> Self {
+        pb::TxnOp {
+            request: Some(pb::txn_op::Request::ListRange(pb::TxnListRangeRequest {
+                start: start.to_string(),
+                end,
+                limit,
+            })),
+        }
+    }
+
+    /// Create a new `TxnOp` that gets multiple records.
+    pub fn get_many(keys: impl IntoIterator<Item = impl ToString>) -> Self {
+        pb::TxnOp {
+            request: Some(pb::txn_op::Request::GetMany(pb::TxnGetManyRequest {
+                keys: keys.into_iter().map(|k| k.to_string()).collect(),
+            })),
+        }
+    }
 }
 
 impl pb::TxnOpResponse {
@@ -151,6 +203,37 @@ impl pb::TxnOpResponse {
     }
 }
 
+impl pb::TxnListRangeRequest {
+    /// The max number of records a `ListRange` returns.
+    pub const MAX_LIMIT: u64 = 1_000;
+
+    /// The number of records to return at most: `limit` capped to `MAX_LIMIT`.
+    pub fn capped_limit(&self) -> u64 {
+        if self.limit == 0 {
+            Self::MAX_LIMIT
+        } else {
+            self.limit.min(Self::MAX_LIMIT)
+        }
+    }
+}
+
+impl pb::TxnFetchAddResponse {
+    /// Parse the integer value of a record used by `FetchAdd`.
+    ///
+    /// An absent record is `0`. Returns `None` if the value is not an integer.
+    pub fn parse_value(value: Option<&[u8]>) -> Option<i64> {
+        match value {
+            None => Some(0),
+            Some(v) => std::str::from_utf8(v).ok()?.parse::<i64>().ok(),
+        }
+    }
+
+    /// Encode an integer into the value format used by `FetchAdd`.
+    pub fn encode_value(n: i64) -> Vec<u8> {
+        n.to_string().into_bytes()
+    }
+}
+
 impl pb::TxnGetResponse {
     pub fn new(key: impl ToString, value: Option<pb::SeqV>) -> Self {
         Self {
@@ -0,0 +1,46 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::protobuf as pb;
+use crate::protobuf::watch_request::FilterType;
+
+impl pb::WatchRequest {
+    /// Watch the key range `[key, key_end)`, or only `key` if `key_end` is None.
+    pub fn new(key: String, key_end: Option<String>) -> Self {
+        pb::WatchRequest {
+            key,
+            key_end,
+            filter_type: FilterType::All.into(),
+            initial_flush: false,
+            start_seq: None,
+        }
+    }
+
+    pub fn with_filter(mut self, filter_type: FilterType) -> Self {
+        self.filter_type = filter_type.into();
+        self
+    }
+
+    /// Send the current values in the range before change events.
+    pub fn with_initial_flush(mut self, initial_flush: bool) -> Self {
+        self.initial_flush = initial_flush;
+        self
+    }
+
+    /// Replay the changes after `start_seq` before new change events.
+    pub fn with_start_seq(mut self, start_seq: u64) -> Self {
+        self.start_seq = Some(start_seq);
+        self
+    }
+}
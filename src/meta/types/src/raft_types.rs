@@ -72,6 +72,7 @@ pub type ForwardToLeader = openraft::error::ForwardToLeader<TypeConfig>;
 pub type Fatal = openraft::error::Fatal<TypeConfig>;
 pub type ChangeMembershipError = openraft::error::ChangeMembershipError<TypeConfig>;
 pub type ClientWriteError = openraft::error::ClientWriteError<TypeConfig>;
+pub type CheckIsLeaderError = openraft::error::CheckIsLeaderError<TypeConfig>;
 pub type InitializeError = openraft::error::InitializeError<TypeConfig>;
 pub type StreamingError<E = openraft::error::Infallible> =
     openraft::error::StreamingError<TypeConfig, E>;
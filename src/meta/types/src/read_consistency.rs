@@ -0,0 +1,63 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The consistency a read request to meta-service requires.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ReadConsistency {
+    /// Read the data committed before the read request is received.
+    ///
+    /// It is served by the leader,
+    /// or by a follower after it applies logs up to the read index obtained from the leader.
+    #[default]
+    Linearizable,
+
+    /// Read from the local state machine of the node receiving the request,
+    /// which may lag behind the leader.
+    ///
+    /// It is served by any node, including learners, without contacting the leader.
+    Stale,
+}
+
+impl ReadConsistency {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReadConsistency::Linearizable => "linearizable",
+            ReadConsistency::Stale => "stale",
+        }
+    }
+}
+
+impl fmt::Display for ReadConsistency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for ReadConsistency {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "linearizable" => Ok(ReadConsistency::Linearizable),
+            "stale" => Ok(ReadConsistency::Stale),
+            _ => Err(format!("unknown read consistency: {}", s)),
+        }
+    }
+}
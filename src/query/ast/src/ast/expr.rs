@@ -1021,6 +1021,11 @@ pub enum TypeName {
     Variant,
     Geometry,
     Geography,
+    Interval,
+    TimestampTz,
+    Time,
+    Uuid,
+    Enum(Vec<String>),
     Nullable(Box<TypeName>),
     NotNull(Box<TypeName>),
 }
@@ -1143,6 +1148,23 @@ impl Display for TypeName {
             TypeName::Geography => {
                 write!(f, "GEOGRAPHY")?;
             }
+            TypeName::Interval => {
+                write!(f, "INTERVAL")?;
+            }
+            TypeName::TimestampTz => {
+                write!(f, "TIMESTAMP_TZ")?;
+            }
+            TypeName::Time => {
+                write!(f, "TIME")?;
+            }
+            TypeName::Uuid => {
+                write!(f, "UUID")?;
+            }
+            TypeName::Enum(items) => {
+                write!(f, "ENUM(")?;
+                write_comma_separated_list(f, items.iter().map(|item| QuotedString(item, '\'')))?;
+                write!(f, ")")?;
+            }
             TypeName::Nullable(ty) => {
                 write!(f, "{} NULL", ty)?;
             }
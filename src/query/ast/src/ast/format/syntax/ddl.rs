@@ -160,6 +160,30 @@ fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
                 RcDoc::nil()
             })
             .append(RcDoc::text(table.to_string())),
+        CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point,
+        } => RcDoc::space()
+            .append(RcDoc::text("CLONE"))
+            .append(RcDoc::space())
+            .append(if let Some(catalog) = catalog {
+                RcDoc::text(catalog.to_string()).append(RcDoc::text("."))
+            } else {
+                RcDoc::nil()
+            })
+            .append(if let Some(database) = database {
+                RcDoc::text(database.to_string()).append(RcDoc::text("."))
+            } else {
+                RcDoc::nil()
+            })
+            .append(RcDoc::text(table.to_string()))
+            .append(if let Some(travel_point) = travel_point {
+                RcDoc::text(format!(" AT {travel_point}"))
+            } else {
+                RcDoc::nil()
+            }),
     }
 }
 
@@ -274,6 +298,7 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
                         .append(RcDoc::text(name.to_string())),
                 )
                 .append(RcDoc::text(")")),
+            TimeTravelPoint::Ref { typ, name } => RcDoc::text(format!(" AT ({typ} => {name})")),
         },
         AlterTableAction::SetOptions { set_options } => {
             let mut doc = RcDoc::line();
@@ -291,6 +316,14 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
             }
             doc
         }
+        action @ AlterTableAction::CreateRef { .. } => {
+            RcDoc::line().append(RcDoc::text(action.to_string()))
+        }
+        action @ (AlterTableAction::DropRef { .. }
+        | AlterTableAction::AddRowAccessPolicy { .. }
+        | AlterTableAction::DropRowAccessPolicy { .. }) => {
+            RcDoc::line().append(RcDoc::text(action.to_string()))
+        }
     }
 }
 
@@ -418,6 +451,9 @@ pub(crate) fn pretty_create_stream(stmt: CreateStreamStmt) -> RcDoc<'static> {
                         .append(RcDoc::text(name.to_string())),
                 )
                 .append(RcDoc::text(")")),
+            Some(TimeTravelPoint::Ref { typ, name }) => {
+                RcDoc::text(format!(" AT ({typ} => {name})"))
+            }
             None => RcDoc::nil(),
         })
         .append(if !stmt.append_only {
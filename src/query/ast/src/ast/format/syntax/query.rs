@@ -469,12 +469,18 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             location,
             options,
             alias,
+            sample,
         } => RcDoc::text(location.to_string())
             .append(options.to_string())
             .append(if let Some(a) = alias {
                 RcDoc::text(format!(" AS {a}"))
             } else {
                 RcDoc::nil()
+            })
+            .append(if let Some(sample) = sample {
+                RcDoc::text(format!(" {sample}"))
+            } else {
+                RcDoc::nil()
             }),
     }
 }
@@ -28,6 +28,7 @@ use crate::ast::Hint;
 use crate::ast::Identifier;
 use crate::ast::Lambda;
 use crate::ast::SelectStageOptions;
+use crate::ast::TableRefType;
 use crate::ast::WindowDefinition;
 use crate::ParseError;
 use crate::Result;
@@ -501,6 +502,10 @@ pub enum TimeTravelPoint {
         database: Option<Identifier>,
         name: Identifier,
     },
+    Ref {
+        typ: TableRefType,
+        name: Identifier,
+    },
 }
 
 impl Display for TimeTravelPoint {
@@ -527,6 +532,9 @@ impl Display for TimeTravelPoint {
                 )?;
                 write!(f, ")")?;
             }
+            TimeTravelPoint::Ref { typ, name } => {
+                write!(f, "({typ} => {name})")?;
+            }
         }
 
         Ok(())
@@ -681,6 +689,9 @@ impl Eq for SampleRowLevel {}
 pub struct SampleConfig {
     pub row_level: Option<SampleRowLevel>,
     pub block_level: Option<f64>,
+    /// Seed of `REPEATABLE (seed)`, the same seed returns the same sample
+    /// as long as the underlying data is unchanged.
+    pub seed: Option<u64>,
 }
 
 impl SampleConfig {
@@ -715,6 +726,12 @@ impl Display for SampleConfig {
                 }
             }
         }
+        if let Some(seed) = self.seed {
+            if self.row_level.is_some() {
+                write!(f, " ")?;
+            }
+            write!(f, "REPEATABLE ({})", seed)?;
+        }
         Ok(())
     }
 }
@@ -765,6 +782,7 @@ pub enum TableReference {
         location: FileLocation,
         options: SelectStageOptions,
         alias: Option<TableAlias>,
+        sample: Option<SampleConfig>,
     },
 }
 
@@ -950,6 +968,7 @@ impl Display for TableReference {
                 location,
                 options,
                 alias,
+                sample,
             } => {
                 write!(f, "{location}")?;
                 if !options.is_empty() {
@@ -958,6 +977,9 @@ impl Display for TableReference {
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
                 }
+                if let Some(sample) = sample {
+                    write!(f, " {sample}")?;
+                }
             }
         }
         Ok(())
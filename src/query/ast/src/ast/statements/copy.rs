@@ -324,7 +324,7 @@ impl Display for Connection {
 }
 
 /// Mask a string by "******", but keep `unmask_len` of suffix.
-fn mask_string(s: &str, unmask_len: usize) -> String {
+pub(crate) fn mask_string(s: &str, unmask_len: usize) -> String {
     if s.len() <= unmask_len {
         s.to_string()
     } else {
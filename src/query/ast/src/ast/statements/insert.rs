@@ -34,6 +34,8 @@ pub struct InsertStmt {
     pub catalog: Option<Identifier>,
     pub database: Option<Identifier>,
     pub table: Identifier,
+    // The branch of the table to insert into, `AT (BRANCH => <name>)`
+    pub branch: Option<Identifier>,
     pub columns: Vec<Identifier>,
     pub source: InsertSource,
     pub overwrite: bool,
@@ -60,6 +62,9 @@ impl Display for InsertStmt {
                 .chain(&self.database)
                 .chain(Some(&self.table)),
         )?;
+        if let Some(branch) = &self.branch {
+            write!(f, " AT (BRANCH => {branch})")?;
+        }
         if !self.columns.is_empty() {
             write!(f, " (")?;
             write_comma_separated_list(f, &self.columns)?;
@@ -39,6 +39,7 @@ mod principal;
 mod priority;
 mod procedure;
 mod replace;
+mod row_access_policy;
 mod script;
 mod sequence;
 mod set;
@@ -83,6 +84,7 @@ pub use principal::*;
 pub use priority::*;
 pub use procedure::*;
 pub use replace::*;
+pub use row_access_policy::*;
 pub use script::*;
 pub use sequence::*;
 pub use set::*;
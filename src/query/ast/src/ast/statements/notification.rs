@@ -18,6 +18,8 @@ use std::fmt::Formatter;
 use derive_visitor::Drive;
 use derive_visitor::DriveMut;
 
+use crate::ast::statements::copy::mask_string;
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub struct CreateNotificationStmt {
     pub if_not_exists: bool,
@@ -52,6 +54,20 @@ pub struct NotificationWebhookOptions {
     pub url: Option<String>,
     pub method: Option<String>,
     pub authorization_header: Option<String>,
+    pub signing_secret: Option<String>,
+}
+
+impl NotificationWebhookOptions {
+    pub fn mask(&self) -> Self {
+        Self {
+            authorization_header: self
+                .authorization_header
+                .as_ref()
+                .map(|v| mask_string(v, 3)),
+            signing_secret: self.signing_secret.as_ref().map(|v| mask_string(v, 3)),
+            ..self.clone()
+        }
+    }
 }
 
 impl Display for NotificationWebhookOptions {
@@ -60,6 +76,7 @@ impl Display for NotificationWebhookOptions {
             url,
             method,
             authorization_header,
+            signing_secret,
         } = self;
         {
             write!(f, "WEBHOOK = (")?;
@@ -72,6 +89,9 @@ impl Display for NotificationWebhookOptions {
             if let Some(authorization_header) = authorization_header {
                 write!(f, " AUTHORIZATION_HEADER = '{}'", authorization_header)?;
             }
+            if let Some(signing_secret) = signing_secret {
+                write!(f, " SIGNING_SECRET = '{}'", signing_secret)?;
+            }
             write!(f, " )")?;
             Ok(())
         }
@@ -83,11 +103,13 @@ impl FromIterator<(String, String)> for NotificationWebhookOptions {
         let mut url = None;
         let mut method = None;
         let mut authorization_header = None;
+        let mut signing_secret = None;
         for (k, v) in iter {
             match k.to_uppercase().as_str() {
                 "URL" => url = Some(v),
                 "METHOD" => method = Some(v),
                 "AUTHORIZATION_HEADER" => authorization_header = Some(v),
+                "SIGNING_SECRET" => signing_secret = Some(v),
                 _ => {}
             }
         }
@@ -95,6 +117,7 @@ impl FromIterator<(String, String)> for NotificationWebhookOptions {
             url,
             method,
             authorization_header,
+            signing_secret,
         }
     }
 }
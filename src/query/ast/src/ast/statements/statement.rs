@@ -273,6 +273,11 @@ pub enum Statement {
     DropDatamaskPolicy(DropDatamaskPolicyStmt),
     DescDatamaskPolicy(DescDatamaskPolicyStmt),
 
+    // row access policy
+    CreateRowAccessPolicy(CreateRowAccessPolicyStmt),
+    DropRowAccessPolicy(DropRowAccessPolicyStmt),
+    DescRowAccessPolicy(DescRowAccessPolicyStmt),
+
     // network policy
     CreateNetworkPolicy(CreateNetworkPolicyStmt),
     AlterNetworkPolicy(AlterNetworkPolicyStmt),
@@ -373,6 +378,17 @@ impl Statement {
                 attach_clone.uri_location.connection = attach_clone.uri_location.connection.mask();
                 format!("{}", Statement::AttachTable(attach_clone))
             }
+            Statement::CreateNotification(stmt) => {
+                let mut stmt_clone = stmt.clone();
+                stmt_clone.webhook_opts = stmt_clone.webhook_opts.map(|opts| opts.mask());
+                format!("{}", Statement::CreateNotification(stmt_clone))
+            }
+            Statement::AlterNotification(stmt) => {
+                let mut stmt_clone = stmt.clone();
+                let AlterNotificationOptions::Set(set_opts) = &mut stmt_clone.options;
+                set_opts.webhook_opts = set_opts.webhook_opts.as_ref().map(|opts| opts.mask());
+                format!("{}", Statement::AlterNotification(stmt_clone))
+            }
             _ => format!("{}", self),
         }
     }
@@ -714,6 +730,9 @@ impl Display for Statement {
             Statement::CreateDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DropDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DescDatamaskPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::CreateRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DropRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
+            Statement::DescRowAccessPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::CreateNetworkPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::AlterNetworkPolicy(stmt) => write!(f, "{stmt}")?,
             Statement::DropNetworkPolicy(stmt) => write!(f, "{stmt}")?,
@@ -276,6 +276,12 @@ pub enum CreateTableSource {
         database: Option<Identifier>,
         table: Identifier,
     },
+    Clone {
+        catalog: Option<Identifier>,
+        database: Option<Identifier>,
+        table: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
 }
 
 impl Display for CreateTableSource {
@@ -298,6 +304,19 @@ impl Display for CreateTableSource {
                 write!(f, "LIKE ")?;
                 write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))
             }
+            CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                travel_point,
+            } => {
+                write!(f, "CLONE ")?;
+                write_dot_separated_list(f, catalog.iter().chain(database).chain(Some(table)))?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT {travel_point}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -429,6 +448,24 @@ pub enum AlterTableAction {
     UnsetOptions {
         targets: Vec<Identifier>,
     },
+    CreateRef {
+        typ: TableRefType,
+        if_not_exists: bool,
+        name: Identifier,
+        travel_point: Option<TimeTravelPoint>,
+    },
+    DropRef {
+        typ: TableRefType,
+        if_exists: bool,
+        name: Identifier,
+    },
+    AddRowAccessPolicy {
+        policy: Identifier,
+        columns: Vec<Identifier>,
+    },
+    DropRowAccessPolicy {
+        policy: Identifier,
+    },
 }
 
 impl Display for AlterTableAction {
@@ -498,11 +535,61 @@ impl Display for AlterTableAction {
                     write!(f, ")")?;
                 }
             }
+            AlterTableAction::CreateRef {
+                typ,
+                if_not_exists,
+                name,
+                travel_point,
+            } => {
+                write!(f, "CREATE {typ}")?;
+                if *if_not_exists {
+                    write!(f, " IF NOT EXISTS")?;
+                }
+                write!(f, " {name}")?;
+                if let Some(travel_point) = travel_point {
+                    write!(f, " AT {travel_point}")?;
+                }
+            }
+            AlterTableAction::DropRef {
+                typ,
+                if_exists,
+                name,
+            } => {
+                write!(f, "DROP {typ}")?;
+                if *if_exists {
+                    write!(f, " IF EXISTS")?;
+                }
+                write!(f, " {name}")?;
+            }
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                write!(f, "ADD ROW ACCESS POLICY {policy} ON (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")?;
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => {
+                write!(f, "DROP ROW ACCESS POLICY {policy}")?;
+            }
         };
         Ok(())
     }
 }
 
+/// Kind of a named reference to a table snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Drive, DriveMut)]
+pub enum TableRefType {
+    Branch,
+    Tag,
+}
+
+impl Display for TableRefType {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            TableRefType::Branch => write!(f, "BRANCH"),
+            TableRefType::Tag => write!(f, "TAG"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Drive, DriveMut)]
 pub enum AddColumnOption {
     End,
@@ -683,6 +770,9 @@ pub struct AnalyzeTableStmt {
     pub catalog: Option<Identifier>,
     pub database: Option<Identifier>,
     pub table: Identifier,
+    /// Groups of correlated columns to collect joint statistics for,
+    /// e.g. `COLUMNS ((country, city))`.
+    pub column_groups: Vec<Vec<Identifier>>,
 }
 
 impl Display for AnalyzeTableStmt {
@@ -695,6 +785,18 @@ impl Display for AnalyzeTableStmt {
                 .chain(&self.database)
                 .chain(Some(&self.table)),
         )?;
+        if !self.column_groups.is_empty() {
+            write!(f, " COLUMNS (")?;
+            for (i, group) in self.column_groups.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "(")?;
+                write_comma_separated_list(f, group)?;
+                write!(f, ")")?;
+            }
+            write!(f, ")")?;
+        }
 
         Ok(())
     }
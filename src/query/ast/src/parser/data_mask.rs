@@ -18,6 +18,7 @@ use nom_rule::rule;
 use crate::ast::DataMaskArg;
 use crate::ast::DataMaskPolicy;
 use crate::ast::Expr;
+use crate::ast::RowAccessPolicy;
 use crate::ast::TypeName;
 use crate::parser::common::*;
 use crate::parser::expr::*;
@@ -77,3 +78,15 @@ pub fn data_mask_policy(i: Input) -> IResult<DataMaskPolicy> {
         },
     )(i)
 }
+
+pub fn row_access_policy(i: Input) -> IResult<RowAccessPolicy> {
+    map(
+        rule! { #data_mask_args ~ #data_mask_return_type ~ "->" ~ #data_mask_body ~ ( COMMENT ~ "=" ~ #literal_string)? },
+        |(args, return_type, _, body, comment_opt)| RowAccessPolicy {
+            args,
+            return_type,
+            body,
+            comment: comment_opt.map(|opt| opt.2),
+        },
+    )(i)
+}
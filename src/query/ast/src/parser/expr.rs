@@ -1312,6 +1312,19 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
         },
     );
 
+    let interval_expr = map(
+        rule! {
+            INTERVAL ~ #consumed(literal_string)
+        },
+        |(_, (span, interval))| ExprElement::Cast {
+            expr: Box::new(Expr::Literal {
+                span: transform_span(span.tokens),
+                value: Literal::String(interval),
+            }),
+            target_type: TypeName::Interval,
+        },
+    );
+
     let is_distinct_from = map(
         rule! {
             IS ~ NOT? ~ DISTINCT ~ FROM
@@ -1360,6 +1373,7 @@ pub fn expr_element(i: Input) -> IResult<WithSpan<ExprElement>> {
                 | #date_expr : "`DATE <str_literal>`"
                 | #timestamp_expr : "`TIMESTAMP <str_literal>`"
                 | #interval : "`INTERVAL ... (YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | DOY | DOW)`"
+                | #interval_expr : "`INTERVAL <str_literal>`"
                 | #extract : "`EXTRACT((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | WEEK) FROM ...)`"
                 | #date_part : "`DATE_PART((YEAR | QUARTER | MONTH | DAY | HOUR | MINUTE | SECOND | WEEK), ...)`"
 
@@ -1713,6 +1727,14 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
     let ty_variant = value(TypeName::Variant, rule! { VARIANT | JSON });
     let ty_geometry = value(TypeName::Geometry, rule! { GEOMETRY });
     let ty_geography = value(TypeName::Geography, rule! { GEOGRAPHY });
+    let ty_interval = value(TypeName::Interval, rule! { INTERVAL });
+    let ty_timestamp_tz = value(TypeName::TimestampTz, rule! { TIMESTAMP_TZ });
+    let ty_time = value(TypeName::Time, rule! { TIME });
+    let ty_uuid = value(TypeName::Uuid, rule! { UUID });
+    let ty_enum = map(
+        rule! { ENUM ~ "(" ~ ^#comma_separated_list1(literal_string) ~ ^")" },
+        |(_, _, items, _)| TypeName::Enum(items),
+    );
     map_res(
         alt((
             rule! {
@@ -1743,6 +1765,11 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             | #ty_variant
             | #ty_geometry
             | #ty_geography
+            | #ty_interval
+            | #ty_timestamp_tz
+            | #ty_time
+            | #ty_uuid
+            | #ty_enum
             | #ty_nullable
             ) ~ #nullable? : "type name" },
         )),
@@ -34,6 +34,7 @@ use crate::parser::stage::file_location;
 use crate::parser::stage::select_stage_option;
 use crate::parser::statement::hint;
 use crate::parser::statement::set_table_option;
+use crate::parser::statement::table_ref_type;
 use crate::parser::statement::top_n;
 use crate::parser::token::*;
 use crate::parser::ErrorKind;
@@ -521,8 +522,13 @@ pub fn travel_point(i: Input) -> IResult<TimeTravelPoint> {
         },
     );
 
+    let at_ref = map(
+        rule! { "(" ~ #table_ref_type ~ "=>" ~ #ident ~ ")" },
+        |(_, typ, _, name, _)| TimeTravelPoint::Ref { typ, name },
+    );
+
     rule!(
-        #at_stream | #at_snapshot_or_ts
+        #at_stream | #at_ref | #at_snapshot_or_ts
     )(i)
 }
 
@@ -734,13 +740,14 @@ pub enum TableReferenceElement {
         location: FileLocation,
         options: Vec<SelectStageOption>,
         alias: Option<TableAlias>,
+        sample: Option<SampleConfig>,
     },
 }
 
 pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceElement>> {
     let aliased_table = map(
         rule! {
-            #dot_separated_idents_1_to_3 ~ #temporal_clause? ~ #with_options? ~ #table_alias? ~ #pivot? ~ #unpivot? ~ SAMPLE? ~ (BLOCK ~ "(" ~ #expr ~ ")")? ~ (ROW ~ "(" ~ #expr ~ ROWS? ~ ")")?
+            #dot_separated_idents_1_to_3 ~ #temporal_clause? ~ #with_options? ~ #table_alias? ~ #pivot? ~ #unpivot? ~ #table_sample
         },
         |(
             (catalog, database, table),
@@ -749,11 +756,8 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
             alias,
             pivot,
             unpivot,
-            sample,
-            sample_block_level,
-            sample_row_level,
+            table_sample,
         )| {
-            let table_sample = get_table_sample(sample, sample_block_level, sample_row_level);
             TableReferenceElement::Table {
                 catalog,
                 database,
@@ -790,17 +794,14 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
     );
     let table_function = map(
         rule! {
-            LATERAL? ~ #function_name ~ "(" ~ #comma_separated_list0(table_function_param) ~ ")" ~ #table_alias? ~ SAMPLE? ~ (BLOCK ~ "(" ~ #expr ~ ")")? ~ (ROW ~ "(" ~ #expr ~ ROWS? ~ ")")?
+            LATERAL? ~ #function_name ~ "(" ~ #comma_separated_list0(table_function_param) ~ ")" ~ #table_alias? ~ #table_sample
         },
-        |(lateral, name, _, params, _, alias, sample, level, sample_conf)| {
-            let table_sample = get_table_sample(sample, level, sample_conf);
-            TableReferenceElement::TableFunction {
-                lateral: lateral.is_some(),
-                name,
-                params,
-                alias,
-                sample: table_sample,
-            }
+        |(lateral, name, _, params, _, alias, table_sample)| TableReferenceElement::TableFunction {
+            lateral: lateral.is_some(),
+            name,
+            params,
+            alias,
+            sample: table_sample,
         },
     );
     let subquery = map(
@@ -824,9 +825,9 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
     );
     let aliased_stage = map(
         rule! {
-            #file_location ~  ( "(" ~ (#select_stage_option ~ ","?)* ~ ^")" )? ~ #table_alias?
+            #file_location ~  ( "(" ~ (#select_stage_option ~ ","?)* ~ ^")" )? ~ #table_alias? ~ #table_sample
         },
-        |(location, options, alias)| {
+        |(location, options, alias, sample)| {
             let options = options
                 .map(|(_, options, _)| options.into_iter().map(|(option, _)| option).collect())
                 .unwrap_or_default();
@@ -834,6 +835,7 @@ pub fn table_reference_element(i: Input) -> IResult<WithSpan<TableReferenceEleme
                 location,
                 alias,
                 options,
+                sample,
             }
         },
     );
@@ -886,10 +888,62 @@ fn pivot_values(i: Input) -> IResult<PivotValues> {
     ))(i)
 }
 
+// `SAMPLE [BLOCK (p)] [ROW (p [ROWS])] [REPEATABLE (seed)]`
+// or the standard `TABLESAMPLE {BERNOULLI | ROW | SYSTEM | BLOCK} (p [ROWS]) [REPEATABLE (seed)]`.
+fn table_sample(i: Input) -> IResult<Option<SampleConfig>> {
+    let row_level_tablesample = map(
+        rule! {
+            TABLESAMPLE ~ (BERNOULLI | ROW) ~ "(" ~ #expr ~ ROWS? ~ ")" ~ #sample_seed?
+        },
+        |(_, _, _, value, rows, _, seed)| {
+            let mut sample_conf = SampleConfig::default();
+            if let Expr::Literal { value, .. } = value {
+                sample_conf
+                    .set_row_level_sample(value.as_double().unwrap_or_default(), rows.is_some());
+            }
+            sample_conf.seed = seed;
+            Some(sample_conf)
+        },
+    );
+    let block_level_tablesample = map(
+        rule! {
+            TABLESAMPLE ~ (SYSTEM | BLOCK) ~ "(" ~ #expr ~ ")" ~ #sample_seed?
+        },
+        |(_, _, _, value, _, seed)| {
+            let mut sample_conf = SampleConfig::default();
+            if let Expr::Literal { value, .. } = value {
+                sample_conf.set_block_level_sample(value.as_double().unwrap_or_default());
+            }
+            sample_conf.seed = seed;
+            Some(sample_conf)
+        },
+    );
+    let sample = map(
+        rule! {
+            SAMPLE? ~ (BLOCK ~ "(" ~ #expr ~ ")")? ~ (ROW ~ "(" ~ #expr ~ ROWS? ~ ")")? ~ #sample_seed?
+        },
+        |(sample, block_level_sample, row_level_sample, seed)| {
+            get_table_sample(sample, block_level_sample, row_level_sample, seed)
+        },
+    );
+
+    alt((row_level_tablesample, block_level_tablesample, sample))(i)
+}
+
+fn sample_seed(i: Input) -> IResult<u64> {
+    map(
+        rule! {
+            REPEATABLE ~ "(" ~ #literal_u64 ~ ")"
+        },
+        |(_, _, seed, _)| seed,
+    )(i)
+}
+
 fn get_table_sample(
     sample: Option<&Token>,
     block_level_sample: Option<(&Token, &Token, Expr, &Token)>,
     row_level_sample: Option<(&Token, &Token, Expr, Option<&Token>, &Token)>,
+    seed: Option<u64>,
 ) -> Option<SampleConfig> {
     let mut default_sample_conf = SampleConfig::default();
     if sample.is_some() {
@@ -900,6 +954,7 @@ fn get_table_sample(
             default_sample_conf
                 .set_row_level_sample(value.as_double().unwrap_or_default(), rows.is_some());
         }
+        default_sample_conf.seed = seed;
         return Some(default_sample_conf);
     }
     None
@@ -997,6 +1052,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                 location,
                 options,
                 alias,
+                sample,
             } => {
                 let options = SelectStageOptions::from(options);
                 TableReference::Location {
@@ -1004,6 +1060,7 @@ impl<'a, I: Iterator<Item = WithSpan<'a, TableReferenceElement>>> PrattParser<I>
                     location,
                     options,
                     alias,
+                    sample,
                 }
             }
             _ => unreachable!(),
@@ -29,6 +29,7 @@ use crate::parser::common::*;
 use crate::parser::copy::copy_into;
 use crate::parser::copy::copy_into_table;
 use crate::parser::data_mask::data_mask_policy;
+use crate::parser::data_mask::row_access_policy;
 use crate::parser::dynamic_table::dynamic_table;
 use crate::parser::expr::subexpr;
 use crate::parser::expr::*;
@@ -274,7 +275,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
 
     let update = map(
         rule! {
-            #with? ~ UPDATE ~ #hint? ~ #table_reference_only
+            #with? ~ UPDATE ~ #hint? ~ #table_reference_with_branch
             ~ SET ~ ^#comma_separated_list1(update_expr)
             ~ ( WHERE ~ ^#expr )?
         },
@@ -921,15 +922,25 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             })
         },
     );
+    let column_group = map(
+        rule! {
+            "(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, columns, _)| columns,
+    );
     let analyze_table = map(
         rule! {
             ANALYZE ~ TABLE ~ #dot_separated_idents_1_to_3
+            ~ ( COLUMNS ~ ^"(" ~ ^#comma_separated_list1(column_group) ~ ^")" )?
         },
-        |(_, _, (catalog, database, table))| {
+        |(_, _, (catalog, database, table), opt_column_groups)| {
             Statement::AnalyzeTable(AnalyzeTableStmt {
                 catalog,
                 database,
                 table,
+                column_groups: opt_column_groups
+                    .map(|(_, _, groups, _)| groups)
+                    .unwrap_or_default(),
             })
         },
     );
@@ -1787,6 +1798,45 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
         },
     );
 
+    // row access policy
+    let create_row_access_policy = map_res(
+        rule! {
+            CREATE ~ ( OR ~ ^REPLACE )? ~ ROW ~ ACCESS ~ ^POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident ~ #row_access_policy
+        },
+        |(_, opt_or_replace, _, _, _, opt_if_not_exists, name, policy)| {
+            let create_option =
+                parse_create_option(opt_or_replace.is_some(), opt_if_not_exists.is_some())?;
+            let stmt = CreateRowAccessPolicyStmt {
+                create_option,
+                name: name.to_string(),
+                policy,
+            };
+            Ok(Statement::CreateRowAccessPolicy(stmt))
+        },
+    );
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ ^POLICY ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, _, _, opt_if_exists, name)| {
+            let stmt = DropRowAccessPolicyStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            };
+            Statement::DropRowAccessPolicy(stmt)
+        },
+    );
+    let describe_row_access_policy = map(
+        rule! {
+            ( DESC | DESCRIBE ) ~ ROW ~ ACCESS ~ ^POLICY ~ #ident
+        },
+        |(_, _, _, _, name)| {
+            Statement::DescRowAccessPolicy(DescRowAccessPolicyStmt {
+                name: name.to_string(),
+            })
+        },
+    );
+
     let create_network_policy = map_res(
         rule! {
             CREATE ~  ( OR ~ ^REPLACE )? ~ NETWORK ~ ^POLICY ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ ^#ident
@@ -2363,7 +2413,7 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #optimize_table : "`OPTIMIZE TABLE [<database>.]<table> (ALL | PURGE | COMPACT [SEGMENT])`"
             | #vacuum_table : "`VACUUM TABLE [<database>.]<table> [RETAIN number HOURS] [DRY RUN | DRY RUN SUMMARY]`"
             | #vacuum_drop_table : "`VACUUM DROP TABLE [FROM [<catalog>.]<database>] [RETAIN number HOURS] [DRY RUN | DRY RUN SUMMARY]`"
-            | #analyze_table : "`ANALYZE TABLE [<database>.]<table>`"
+            | #analyze_table : "`ANALYZE TABLE [<database>.]<table> [COLUMNS ((<column>, ...), ...)]`"
             | #exists_table : "`EXISTS TABLE [<database>.]<table>`"
             | #show_table_functions : "`SHOW TABLE_FUNCTIONS [<show_limit>]`"
         ),
@@ -2422,6 +2472,12 @@ pub fn statement_body(i: Input) -> IResult<Statement> {
             | #drop_data_mask_policy: "`DROP MASKING POLICY [IF EXISTS] mask_name`"
             | #describe_data_mask_policy: "`DESC MASKING POLICY mask_name`"
         ),
+        // row access policy
+        rule!(
+            #create_row_access_policy: "`CREATE [OR REPLACE] ROW ACCESS POLICY [IF NOT EXISTS] policy_name AS (arg1 arg_type1 [, ...]) RETURNS BOOLEAN -> expr`"
+            | #drop_row_access_policy: "`DROP ROW ACCESS POLICY [IF EXISTS] policy_name`"
+            | #describe_row_access_policy: "`DESC ROW ACCESS POLICY policy_name`"
+        ),
         rule!(
             #set_stmt : "`SET [variable] {<name> = <value> | (<name>, ...) = (<value>, ...)}`"
             | #unset_stmt : "`UNSET [variable] {<name> | (<name>, ...)}`"
@@ -2468,7 +2524,7 @@ AS
             | #create_notification : "`CREATE NOTIFICATION INTEGRATION [ IF NOT EXISTS ] <name>
     TYPE = <type>
     ENABLED = <bool>
-    [ WEBHOOK = ( url = <string_literal>, method = <string_literal>, authorization_header = <string_literal> ) ]
+    [ WEBHOOK = ( url = <string_literal>, method = <string_literal>, authorization_header = <string_literal>, signing_secret = <string_literal> ) ]
     [ COMMENT = '<string_literal>' ]`"
             | #alter_notification : "`ALTER NOTIFICATION INTEGRATION [ IF EXISTS ] <name> SET <option> = <value>`"
             | #desc_notification : "`DESC | DESCRIBE NOTIFICATION INTEGRATION <name>`"
@@ -2526,6 +2582,7 @@ pub fn insert_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement> {
             rule! {
                 #with? ~ INSERT ~ #hint? ~ ( INTO | OVERWRITE ) ~ TABLE?
                 ~ #dot_separated_idents_1_to_3
+                ~ #branch_clause?
                 ~ ( "(" ~ #comma_separated_list1(ident) ~ ")" )?
                 ~ #insert_source_parser
             },
@@ -2536,6 +2593,7 @@ pub fn insert_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement> {
                 overwrite,
                 _,
                 (catalog, database, table),
+                opt_branch,
                 opt_columns,
                 source,
             )| {
@@ -2545,6 +2603,7 @@ pub fn insert_stmt(allow_raw: bool) -> impl FnMut(Input) -> IResult<Statement> {
                     catalog,
                     database,
                     table,
+                    branch: opt_branch,
                     columns: opt_columns
                         .map(|(_, columns, _)| columns)
                         .unwrap_or_default(),
@@ -3362,13 +3421,32 @@ pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
             table,
         },
     );
+    let clone = map(
+        rule! {
+            CLONE ~ #dot_separated_idents_1_to_3 ~ ( AT ~ ^#travel_point )?
+        },
+        |(_, (catalog, database, table), opt_travel_point)| CreateTableSource::Clone {
+            catalog,
+            database,
+            table,
+            travel_point: opt_travel_point.map(|(_, p)| p),
+        },
+    );
 
     rule!(
         #columns
         | #like
+        | #clone
     )(i)
 }
 
+pub fn table_ref_type(i: Input) -> IResult<TableRefType> {
+    alt((
+        value(TableRefType::Branch, rule! { BRANCH }),
+        value(TableRefType::Tag, rule! { TAG }),
+    ))(i)
+}
+
 pub fn alter_database_action(i: Input) -> IResult<AlterDatabaseAction> {
     let mut rename_database = map(
         rule! {
@@ -3589,8 +3667,52 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
         |(_, _, targets)| AlterTableAction::UnsetOptions { targets },
     );
 
+    let create_ref = map(
+        rule! {
+            CREATE ~ #table_ref_type ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ #ident ~ ( AT ~ ^#travel_point )?
+        },
+        |(_, typ, opt_if_not_exists, name, opt_travel_point)| AlterTableAction::CreateRef {
+            typ,
+            if_not_exists: opt_if_not_exists.is_some(),
+            name,
+            travel_point: opt_travel_point.map(|(_, p)| p),
+        },
+    );
+
+    let drop_ref = map(
+        rule! {
+            DROP ~ #table_ref_type ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, typ, opt_if_exists, name)| AlterTableAction::DropRef {
+            typ,
+            if_exists: opt_if_exists.is_some(),
+            name,
+        },
+    );
+
+    let add_row_access_policy = map(
+        rule! {
+            ADD ~ ROW ~ ACCESS ~ ^POLICY ~ ^#ident ~ ^ON ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, _, policy, _, _, columns, _)| AlterTableAction::AddRowAccessPolicy {
+            policy,
+            columns,
+        },
+    );
+
+    let drop_row_access_policy = map(
+        rule! {
+            DROP ~ ROW ~ ACCESS ~ ^POLICY ~ ^#ident
+        },
+        |(_, _, _, _, policy)| AlterTableAction::DropRowAccessPolicy { policy },
+    );
+
     rule!(
-        #alter_table_cluster_key
+        #create_ref
+        | #drop_ref
+        | #add_row_access_policy
+        | #drop_row_access_policy
+        | #alter_table_cluster_key
         | #drop_table_cluster_key
         | #rename_table
         | #rename_column
@@ -4236,9 +4358,9 @@ pub fn presign_option(i: Input) -> IResult<PresignOption> {
 pub fn table_reference_with_alias(i: Input) -> IResult<TableReference> {
     map(
         consumed(rule! {
-            #dot_separated_idents_1_to_3 ~ #alias_name?
+            #dot_separated_idents_1_to_3 ~ #branch_clause? ~ #alias_name?
         }),
-        |(span, ((catalog, database, table), alias))| TableReference::Table {
+        |(span, ((catalog, database, table), branch, alias))| TableReference::Table {
             span: transform_span(span.tokens),
             catalog,
             database,
@@ -4247,7 +4369,7 @@ pub fn table_reference_with_alias(i: Input) -> IResult<TableReference> {
                 name: v,
                 columns: vec![],
             }),
-            temporal: None,
+            temporal: branch.map(branch_temporal_clause),
             with_options: None,
             pivot: None,
             unpivot: None,
@@ -4276,6 +4398,43 @@ pub fn table_reference_only(i: Input) -> IResult<TableReference> {
     )(i)
 }
 
+pub fn table_reference_with_branch(i: Input) -> IResult<TableReference> {
+    map(
+        consumed(rule! {
+            #dot_separated_idents_1_to_3 ~ #branch_clause?
+        }),
+        |(span, ((catalog, database, table), branch))| TableReference::Table {
+            span: transform_span(span.tokens),
+            catalog,
+            database,
+            table,
+            alias: None,
+            temporal: branch.map(branch_temporal_clause),
+            with_options: None,
+            pivot: None,
+            unpivot: None,
+            sample: None,
+        },
+    )(i)
+}
+
+/// `AT (BRANCH => <name>)` after the table that a DML statement writes to.
+pub fn branch_clause(i: Input) -> IResult<Identifier> {
+    map(
+        rule! {
+            AT ~ "(" ~ BRANCH ~ ^"=>" ~ ^#ident ~ ^")"
+        },
+        |(_, _, _, _, branch, _)| branch,
+    )(i)
+}
+
+fn branch_temporal_clause(name: Identifier) -> TemporalClause {
+    TemporalClause::TimeTravel(TimeTravelPoint::Ref {
+        typ: TableRefType::Branch,
+        name,
+    })
+}
+
 pub fn update_expr(i: Input) -> IResult<UpdateExpr> {
     map(rule! { ( #ident ~ "=" ~ ^#expr ) }, |(name, _, expr)| {
         UpdateExpr { name, expr }
@@ -4510,12 +4669,19 @@ pub fn notification_webhook_options(i: Input) -> IResult<NotificationWebhookOpti
         },
         |(_, _, v)| ("authorization_header".to_string(), v.to_string()),
     );
+    let signing_secret_option = map(
+        rule! {
+            SIGNING_SECRET ~ "=" ~ #literal_string
+        },
+        |(_, _, v)| ("signing_secret".to_string(), v.to_string()),
+    );
 
     map(
         rule! { ((
         #url_option
         | #method_option
-        | #auth_option) ~ ","?)* },
+        | #auth_option
+        | #signing_secret_option) ~ ","?)* },
         |opts| {
             NotificationWebhookOptions::from_iter(
                 opts.iter().map(|((k, v), _)| (k.to_uppercase(), v.clone())),
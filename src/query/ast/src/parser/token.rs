@@ -335,6 +335,8 @@ pub enum TokenKind {
     // 1. Add the keyword to token kind variants by alphabetical order.
     // 2. Search in this file to see if the new keyword is a commented out reserved keyword. If
     //    so, uncomment the keyword in the reserved list.
+    #[token("ACCESS", ignore(ascii_case))]
+    ACCESS,
     #[token("ACCOUNT", ignore(ascii_case))]
     ACCOUNT,
     #[token("ALL", ignore(ascii_case))]
@@ -411,6 +413,8 @@ pub enum TokenKind {
     BOOLEAN,
     #[token("BOTH", ignore(ascii_case))]
     BOTH,
+    #[token("BRANCH", ignore(ascii_case))]
+    BRANCH,
     #[token("BY", ignore(ascii_case))]
     BY,
     #[token("BROTLI", ignore(ascii_case))]
@@ -433,6 +437,8 @@ pub enum TokenKind {
     CENTURY,
     #[token("CHANGES", ignore(ascii_case))]
     CHANGES,
+    #[token("CLONE", ignore(ascii_case))]
+    CLONE,
     #[token("CLUSTER", ignore(ascii_case))]
     CLUSTER,
     #[token("COMMENT", ignore(ascii_case))]
@@ -577,6 +583,8 @@ pub enum TokenKind {
     ENGINE,
     #[token("ENGINES", ignore(ascii_case))]
     ENGINES,
+    #[token("ENUM", ignore(ascii_case))]
+    ENUM,
     #[token("EPOCH", ignore(ascii_case))]
     EPOCH,
     #[token("ERROR_ON_COLUMN_COUNT_MISMATCH", ignore(ascii_case))]
@@ -962,6 +970,12 @@ pub enum TokenKind {
     REVERSE,
     #[token("SAMPLE", ignore(ascii_case))]
     SAMPLE,
+    #[token("TABLESAMPLE", ignore(ascii_case))]
+    TABLESAMPLE,
+    #[token("BERNOULLI", ignore(ascii_case))]
+    BERNOULLI,
+    #[token("REPEATABLE", ignore(ascii_case))]
+    REPEATABLE,
     #[token("MERGE", ignore(ascii_case))]
     MERGE,
     #[token("MATCHED", ignore(ascii_case))]
@@ -1132,6 +1146,8 @@ pub enum TokenKind {
     TABLE,
     #[token("TABLES", ignore(ascii_case))]
     TABLES,
+    #[token("TAG", ignore(ascii_case))]
+    TAG,
     #[token("TARGET_LAG", ignore(ascii_case))]
     TARGET_LAG,
     #[token("TEXT", ignore(ascii_case))]
@@ -1152,8 +1168,12 @@ pub enum TokenKind {
     THEN,
     #[token("THURSDAY", ignore(ascii_case))]
     THURSDAY,
+    #[token("TIME", ignore(ascii_case))]
+    TIME,
     #[token("TIMESTAMP", ignore(ascii_case))]
     TIMESTAMP,
+    #[token("TIMESTAMP_TZ", ignore(ascii_case))]
+    TIMESTAMP_TZ,
     #[token("TIMEZONE_HOUR", ignore(ascii_case))]
     TIMEZONE_HOUR,
     #[token("TIMEZONE_MINUTE", ignore(ascii_case))]
@@ -1208,6 +1228,8 @@ pub enum TokenKind {
     METHOD,
     #[token("AUTHORIZATION_HEADER", ignore(ascii_case))]
     AUTHORIZATION_HEADER,
+    #[token("SIGNING_SECRET", ignore(ascii_case))]
+    SIGNING_SECRET,
     #[token("USE", ignore(ascii_case))]
     USE,
     #[token("USER", ignore(ascii_case))]
@@ -1216,6 +1238,8 @@ pub enum TokenKind {
     USERS,
     #[token("USING", ignore(ascii_case))]
     USING,
+    #[token("UUID", ignore(ascii_case))]
+    UUID,
     #[token("VACUUM", ignore(ascii_case))]
     VACUUM,
     #[token("VALUES", ignore(ascii_case))]
@@ -1695,7 +1719,7 @@ impl TokenKind {
             | TokenKind::SEMI
             | TokenKind::SAMPLE
             // | TokenKind::SYMMETRIC
-            // | TokenKind::TABLESAMPLE
+            | TokenKind::TABLESAMPLE
             | TokenKind::THEN
             | TokenKind::TRAILING
             | TokenKind::TRUE
@@ -132,6 +132,7 @@ fn test_statement() {
         r#"create table t like t2 engine = memory;"#,
         r#"create table if not exists a.b (a int) 's3://testbucket/admin/data/' connection=(aws_key_id='minioadmin' aws_secret_key='minioadmin' endpoint_url='http://127.0.0.1:9900');"#,
         r#"truncate table a;"#,
+        r#"analyze table a columns ((b, c), (d, e));"#,
         r#"truncate table "a".b;"#,
         r#"drop table a;"#,
         r#"drop table if exists a."b";"#,
@@ -228,9 +229,13 @@ fn test_statement() {
         r#"select * from numbers(1000) sample row (10 rows);"#,
         r#"select * from numbers(1000) sample block (99) row (10 rows);"#,
         r#"select * from numbers(1000) sample block (99) row (10);"#,
+        r#"select * from t tablesample bernoulli (10) repeatable (42);"#,
+        r#"select * from t tablesample system (50) repeatable (7);"#,
+        r#"select * from t sample block (50) row (10 rows) repeatable (7);"#,
         r#"insert into t (c1, c2) values (1, 2), (3, 4);"#,
         r#"insert into t (c1, c2) values (1, 2);"#,
         r#"insert into table t select * from t2;"#,
+        r#"insert into t at (branch => dev) (c1) values (1);"#,
         r#"select parse_json('{"k1": [0, 1, 2]}').k1[0];"#,
         r#"SELECT avg((number > 314)::UInt32);"#,
         r#"SELECT 1 - (2 + 3);"#,
@@ -739,6 +744,7 @@ fn test_statement() {
         // notification
         r#"CREATE NOTIFICATION INTEGRATION IF NOT EXISTS SampleNotification type = webhook enabled = true webhook = (url = 'https://example.com', method = 'GET', authorization_header = 'bearer auth')"#,
         r#"CREATE NOTIFICATION INTEGRATION SampleNotification type = webhook enabled = true webhook = (url = 'https://example.com') COMMENT = 'notify'"#,
+        r#"CREATE NOTIFICATION INTEGRATION SampleNotification type = webhook enabled = true webhook = (url = 'https://example.com', signing_secret = 'secret')"#,
         r#"ALTER NOTIFICATION INTEGRATION SampleNotification SET enabled = true"#,
         r#"ALTER NOTIFICATION INTEGRATION SampleNotification SET webhook = (url = 'https://example.com')"#,
         r#"ALTER NOTIFICATION INTEGRATION SampleNotification SET comment = '1'"#,
@@ -1227,6 +1233,11 @@ fn test_expr() {
         r#"MAP_FILTER({1:1,2:2,3:4}, (k, v) -> k > v)"#,
         r#"MAP_TRANSFORM_KEYS({1:10,2:20,3:30}, (k, v) -> k + 1)"#,
         r#"MAP_TRANSFORM_VALUES({1:10,2:20,3:30}, (k, v) -> v + 1)"#,
+        r#"INTERVAL '1 day 2 hours'"#,
+        r#"CAST(col1 AS TIMESTAMP_TZ)"#,
+        r#"CAST(col1 AS TIME)"#,
+        r#"CAST(col1 AS UUID)"#,
+        r#"CAST(col1 AS ENUM('a', 'b'))"#,
     ];
 
     for case in cases {
@@ -20,6 +20,7 @@ mod partition_statistics;
 mod projection;
 mod pruning_statistics;
 mod pushdown;
+mod sample;
 mod stream_column;
 
 pub use agg_index::*;
@@ -30,4 +31,5 @@ pub use partition_statistics::PartStatistics;
 pub use projection::Projection;
 pub use pruning_statistics::PruningStatistics;
 pub use pushdown::*;
+pub use sample::*;
 pub use stream_column::*;
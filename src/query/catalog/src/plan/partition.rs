@@ -56,6 +56,14 @@ pub trait PartInfo: Send + Sync {
     /// Used for partition distributed.
     fn hash(&self) -> u64;
 
+    /// Identifies the partition when sampling with `REPEATABLE (seed)`.
+    ///
+    /// Unlike `hash`, the key must not change across versions, partitions reading
+    /// files should return the location they read.
+    fn sample_key(&self) -> Vec<u8> {
+        self.hash().to_le_bytes().to_vec()
+    }
+
     /// Get the partition type.
     /// Default is block level.
     /// If the partition is lazy level, it should be override.
@@ -0,0 +1,93 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::Hasher;
+
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_arrow::arrow::bitmap::MutableBitmap;
+use databend_common_ast::ast::SampleRowLevel;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use rand::Rng;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+use siphasher::sip::SipHasher24;
+
+use crate::plan::PushDownInfo;
+
+/// Build the random generator of a `REPEATABLE (seed)` sample for the part identified by `key`.
+///
+/// The generator only depends on the seed and the key, so the same part is always
+/// sampled in the same way no matter which processor or node reads it.
+/// Both the hash and the generator are fixed algorithms, so the sample does not
+/// change across versions either.
+pub fn sample_rng(seed: u64, key: &[u8]) -> ChaCha8Rng {
+    let mut hasher = SipHasher24::new_with_keys(0, 0);
+    hasher.write(&seed.to_le_bytes());
+    hasher.write(key);
+    ChaCha8Rng::seed_from_u64(hasher.finish())
+}
+
+/// Row-level sample with a seed, which is evaluated by the table engine while reading.
+///
+/// The optimizer resolves the row level sample into a probability before pushing it down,
+/// see [`crate::table::Table::support_seeded_row_sample`].
+#[derive(Clone, Copy, Debug)]
+pub struct RowSampler {
+    seed: u64,
+    probability: f64,
+}
+
+impl RowSampler {
+    pub fn try_create(push_downs: &Option<PushDownInfo>) -> Option<Self> {
+        let sample = push_downs.as_ref()?.sample.as_ref()?;
+        let seed = sample.seed?;
+        match sample.row_level {
+            Some(SampleRowLevel::Probability(probability)) => Some(RowSampler {
+                seed,
+                probability: probability / 100.0,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Start sampling the rows of the part identified by `key`.
+    pub fn part_sampler(&self, key: &[u8]) -> PartRowSampler {
+        PartRowSampler {
+            probability: self.probability,
+            rng: sample_rng(self.seed, key),
+        }
+    }
+}
+
+/// Samples the rows of one part, blocks must be fed in the order they are read.
+pub struct PartRowSampler {
+    probability: f64,
+    rng: ChaCha8Rng,
+}
+
+impl PartRowSampler {
+    pub fn sample_bitmap(&mut self, num_rows: usize) -> Bitmap {
+        let mut bitmap = MutableBitmap::with_capacity(num_rows);
+        for _ in 0..num_rows {
+            bitmap.push(self.rng.gen::<f64>() < self.probability);
+        }
+        bitmap.into()
+    }
+
+    pub fn sample_block(&mut self, block: DataBlock) -> Result<DataBlock> {
+        let bitmap = self.sample_bitmap(block.num_rows());
+        block.filter_with_bitmap(&bitmap)
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_expression::ColumnId;
 use databend_common_storage::Datum;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
 
@@ -29,6 +30,16 @@ pub struct BasicColumnStatistics {
     pub null_count: u64,
 }
 
+#[derive(Clone, Debug, Default)]
+/// Joint statistics information of a group of columns
+pub struct BasicColumnGroupStatistics {
+    pub column_ids: Vec<ColumnId>,
+    // Number of distinct combinations of the column values
+    pub ndv: u64,
+    // Most common combinations of the column values, and their frequencies
+    pub most_common_values: Vec<(Vec<Datum>, f64)>,
+}
+
 impl From<ColumnStatistics> for BasicColumnStatistics {
     fn from(value: ColumnStatistics) -> Self {
         Self {
@@ -28,6 +28,7 @@ use databend_common_expression::TableSchema;
 use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use databend_common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use databend_common_io::constants::DEFAULT_BLOCK_MIN_ROWS;
+use databend_common_meta_app::schema::SnapshotRefType;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
@@ -40,6 +41,7 @@ use databend_common_storage::StorageMetrics;
 use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::table::ChangeType;
+use databend_storages_common_table_meta::table::OPT_KEY_BRANCH;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use databend_storages_common_table_meta::table_id_ranges::is_temp_table_id;
 
@@ -50,6 +52,7 @@ use crate::plan::Partitions;
 use crate::plan::PushDownInfo;
 use crate::plan::ReclusterParts;
 use crate::plan::StreamColumn;
+use crate::statistics::BasicColumnGroupStatistics;
 use crate::statistics::BasicColumnStatistics;
 use crate::table_args::TableArgs;
 use crate::table_context::AbortChecker;
@@ -445,6 +448,12 @@ pub trait Table: Sync + Send {
     fn use_own_sample_block(&self) -> bool {
         false
     }
+
+    /// Whether the table evaluates a seeded (`REPEATABLE`) row-level sample while reading,
+    /// see [`crate::plan::RowSampler`].
+    fn support_seeded_row_sample(&self) -> bool {
+        false
+    }
 }
 
 #[async_trait::async_trait]
@@ -473,6 +482,13 @@ pub trait TableExt: Table {
         let tid = table_info.ident.table_id;
         let catalog = ctx.get_catalog(table_info.catalog()).await?;
 
+        // The branch of a table handle is not stored in the meta server, keep it.
+        let mut meta = meta;
+        if let Some(branch) = table_info.options().get(OPT_KEY_BRANCH) {
+            meta.options
+                .insert(OPT_KEY_BRANCH.to_owned(), branch.clone());
+        }
+
         let table_info = TableInfo {
             ident: TableIdent::new(tid, seq),
             meta,
@@ -511,6 +527,7 @@ pub enum NavigationPoint {
     SnapshotID(String),
     TimePoint(DateTime<Utc>),
     StreamInfo(TableInfo),
+    SnapshotRef { typ: SnapshotRefType, name: String },
 }
 
 #[derive(Debug, Copy, Clone, Default)]
@@ -550,6 +567,11 @@ pub trait ColumnStatisticsProvider: Send {
     fn histogram(&self, _column_id: ColumnId) -> Option<Histogram> {
         None
     }
+
+    // returns the joint statistics of the analyzed column groups, if any.
+    fn column_groups(&self) -> &[BasicColumnGroupStatistics] {
+        &[]
+    }
 }
 
 pub struct DummyColumnStatisticsProvider;
@@ -1684,6 +1684,12 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value = "0")]
     pub cloud_control_grpc_timeout: u64,
 
+    /// Key to encrypt the secrets of notification integrations stored in the meta-service,
+    /// must be the same on all query nodes of a tenant.
+    // This will not show in system.configs, put it to mask.rs.
+    #[clap(long, value_name = "VALUE", default_value = "")]
+    pub notification_secret_key: String,
+
     #[clap(long, value_name = "VALUE", default_value = "50")]
     pub max_cached_queries_profiles: usize,
 
@@ -1775,6 +1781,7 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             udf_server_allow_insecure: self.udf_server_allow_insecure,
             cloud_control_grpc_server_address: self.cloud_control_grpc_server_address,
             cloud_control_grpc_timeout: self.cloud_control_grpc_timeout,
+            notification_secret_key: self.notification_secret_key,
             max_cached_queries_profiles: self.max_cached_queries_profiles,
             settings: self
                 .settings
@@ -1877,6 +1884,7 @@ impl From<InnerQueryConfig> for QueryConfig {
             udf_server_allow_insecure: inner.udf_server_allow_insecure,
             cloud_control_grpc_server_address: inner.cloud_control_grpc_server_address,
             cloud_control_grpc_timeout: inner.cloud_control_grpc_timeout,
+            notification_secret_key: inner.notification_secret_key,
             max_cached_queries_profiles: inner.max_cached_queries_profiles,
             settings: HashMap::new(),
         }
@@ -2856,6 +2864,28 @@ pub struct CacheConfig {
     )]
     pub table_data_deserialized_memory_ratio: u64,
 
+    /// Eviction policy of the caches
+    #[clap(
+        long = "cache-eviction-policy",
+        value_name = "VALUE",
+        value_enum,
+        default_value_t
+    )]
+    pub eviction_policy: CacheEvictionPolicy,
+
+    /// Eviction policies of specific caches, keyed by cache name, e.g.
+    ///
+    /// ```toml
+    /// [cache.eviction_policies]
+    /// disk_cache_table_data = "s3fifo"
+    /// ```
+    #[clap(skip)]
+    pub eviction_policies: BTreeMap<String, CacheEvictionPolicy>,
+
+    /// Warm up the caches after restart
+    #[clap(flatten)]
+    pub warm_up: CacheWarmUpConfig,
+
     // ----- the following options/args are all deprecated               ----
     /// Max number of cached table segment
     #[clap(long = "cache-table-meta-segment-count", value_name = "VALUE")]
@@ -2904,6 +2934,23 @@ impl Default for DiskCacheKeyReloadPolicy {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum CacheEvictionPolicy {
+    // evict the least recently used items
+    Lru,
+    // admit items into the main queue only if they are accessed again,
+    // so that items accessed only once (e.g. by scans) are evicted soon
+    #[value(name = "s3fifo")]
+    S3Fifo,
+}
+
+impl Default for CacheEvictionPolicy {
+    fn default() -> Self {
+        Self::Lru
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args, Default)]
 #[serde(default, deny_unknown_fields)]
 pub struct DiskCacheConfig {
@@ -2936,6 +2983,64 @@ pub struct DiskCacheConfig {
     pub sync_data: bool,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args)]
+#[serde(default, deny_unknown_fields)]
+pub struct CacheWarmUpConfig {
+    /// Record the hot tables, and warm up their caches after restart. Default is disabled.
+    #[clap(
+        long = "cache-warm-up-enable",
+        value_name = "VALUE",
+        default_value = "false"
+    )]
+    pub enable: bool,
+
+    /// Path of the file that the hot tables are persisted to
+    #[clap(
+        long = "cache-warm-up-path",
+        value_name = "VALUE",
+        default_value = "./.databend/_cache_warm_up"
+    )]
+    pub path: String,
+
+    /// Interval in seconds of persisting the hot tables
+    #[clap(
+        long = "cache-warm-up-persist-interval-secs",
+        value_name = "VALUE",
+        default_value = "60"
+    )]
+    pub persist_interval_secs: u64,
+
+    /// Max number of the most recently queried tables to warm up
+    #[clap(
+        long = "cache-warm-up-max-tables",
+        value_name = "VALUE",
+        default_value = "32"
+    )]
+    pub max_tables: u64,
+
+    /// Max bytes of table column data to prefetch into the table data cache
+    #[clap(
+        long = "cache-warm-up-max-column-data-bytes",
+        value_name = "VALUE",
+        default_value = "1073741824"
+    )]
+    pub max_column_data_bytes: u64,
+
+    /// Max number of concurrent reads while warming up, to throttle the background warm-up
+    #[clap(
+        long = "cache-warm-up-max-concurrency",
+        value_name = "VALUE",
+        default_value = "4"
+    )]
+    pub max_concurrency: u64,
+}
+
+impl Default for CacheWarmUpConfig {
+    fn default() -> Self {
+        super::inner::CacheWarmUpConfig::default().into()
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Args, Default)]
 #[serde(default, deny_unknown_fields)]
 pub struct SpillConfig {
@@ -3065,6 +3170,13 @@ mod cache_config_converters {
                 data_cache_key_reload_policy: value.data_cache_key_reload_policy.try_into()?,
                 table_data_deserialized_data_bytes: value.table_data_deserialized_data_bytes,
                 table_data_deserialized_memory_ratio: value.table_data_deserialized_memory_ratio,
+                eviction_policy: value.eviction_policy.into(),
+                eviction_policies: value
+                    .eviction_policies
+                    .into_iter()
+                    .map(|(name, policy)| (name, policy.into()))
+                    .collect(),
+                warm_up: value.warm_up.into(),
             })
         }
     }
@@ -3092,6 +3204,13 @@ mod cache_config_converters {
                 disk_cache_config: value.disk_cache_config.into(),
                 table_data_deserialized_data_bytes: value.table_data_deserialized_data_bytes,
                 table_data_deserialized_memory_ratio: value.table_data_deserialized_memory_ratio,
+                eviction_policy: value.eviction_policy.into(),
+                eviction_policies: value
+                    .eviction_policies
+                    .into_iter()
+                    .map(|(name, policy)| (name, policy.into()))
+                    .collect(),
+                warm_up: value.warm_up.into(),
                 table_meta_segment_count: None,
             }
         }
@@ -3153,6 +3272,32 @@ mod cache_config_converters {
         }
     }
 
+    impl From<CacheWarmUpConfig> for inner::CacheWarmUpConfig {
+        fn from(value: CacheWarmUpConfig) -> Self {
+            Self {
+                enable: value.enable,
+                path: value.path,
+                persist_interval_secs: value.persist_interval_secs,
+                max_tables: value.max_tables,
+                max_column_data_bytes: value.max_column_data_bytes,
+                max_concurrency: value.max_concurrency,
+            }
+        }
+    }
+
+    impl From<inner::CacheWarmUpConfig> for CacheWarmUpConfig {
+        fn from(value: inner::CacheWarmUpConfig) -> Self {
+            Self {
+                enable: value.enable,
+                path: value.path,
+                persist_interval_secs: value.persist_interval_secs,
+                max_tables: value.max_tables,
+                max_column_data_bytes: value.max_column_data_bytes,
+                max_concurrency: value.max_concurrency,
+            }
+        }
+    }
+
     impl TryFrom<CacheStorageTypeConfig> for inner::CacheStorageTypeConfig {
         type Error = ErrorCode;
         fn try_from(value: CacheStorageTypeConfig) -> std::result::Result<Self, Self::Error> {
@@ -3190,4 +3335,22 @@ mod cache_config_converters {
             }
         }
     }
+
+    impl From<CacheEvictionPolicy> for inner::CacheEvictionPolicy {
+        fn from(value: CacheEvictionPolicy) -> Self {
+            match value {
+                CacheEvictionPolicy::Lru => inner::CacheEvictionPolicy::Lru,
+                CacheEvictionPolicy::S3Fifo => inner::CacheEvictionPolicy::S3Fifo,
+            }
+        }
+    }
+
+    impl From<inner::CacheEvictionPolicy> for CacheEvictionPolicy {
+        fn from(value: inner::CacheEvictionPolicy) -> Self {
+            match value {
+                inner::CacheEvictionPolicy::Lru => CacheEvictionPolicy::Lru,
+                inner::CacheEvictionPolicy::S3Fifo => CacheEvictionPolicy::S3Fifo,
+            }
+        }
+    }
 }
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::ffi::OsString;
 use std::fmt;
@@ -245,6 +246,8 @@ pub struct QueryConfig {
 
     pub cloud_control_grpc_server_address: Option<String>,
     pub cloud_control_grpc_timeout: u64,
+    /// Key to encrypt the secrets of notification integrations stored in the meta-service.
+    pub notification_secret_key: String,
     pub max_cached_queries_profiles: usize,
     pub settings: HashMap<String, UserSettingValue>,
 }
@@ -320,6 +323,7 @@ impl Default for QueryConfig {
             udf_server_allow_insecure: false,
             cloud_control_grpc_server_address: None,
             cloud_control_grpc_timeout: 0,
+            notification_secret_key: "".to_string(),
             data_retention_time_in_days_max: 90,
             max_cached_queries_profiles: 50,
             settings: HashMap::new(),
@@ -343,6 +347,7 @@ impl QueryConfig {
             .clone()
             .map(|s| mask_string(&s, 3));
         sanitized.openai_api_key = mask_string(&self.openai_api_key, 3);
+        sanitized.notification_secret_key = mask_string(&self.notification_secret_key, 3);
         sanitized
     }
 }
@@ -610,6 +615,26 @@ pub struct CacheConfig {
     /// Only if query nodes have plenty of un-utilized memory, the working set can be fitted into,
     /// and the access pattern will benefit from caching, consider enabled this cache.
     pub table_data_deserialized_memory_ratio: u64,
+
+    /// Eviction policy of the caches
+    pub eviction_policy: CacheEvictionPolicy,
+
+    /// Eviction policies of specific caches, keyed by cache name.
+    /// Caches not listed here use `eviction_policy`.
+    pub eviction_policies: BTreeMap<String, CacheEvictionPolicy>,
+
+    /// Warm up the caches after restart
+    pub warm_up: CacheWarmUpConfig,
+}
+
+impl CacheConfig {
+    /// Returns the eviction policy of the cache with the given name.
+    pub fn eviction_policy_of(&self, name: &str) -> CacheEvictionPolicy {
+        self.eviction_policies
+            .get(name)
+            .copied()
+            .unwrap_or(self.eviction_policy)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -657,6 +682,30 @@ impl Display for DiskCacheKeyReloadPolicy {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CacheEvictionPolicy {
+    // evict the least recently used items
+    Lru,
+    // admit items into the main queue only if they are accessed again,
+    // so that items accessed only once (e.g. by scans) are evicted soon
+    S3Fifo,
+}
+
+impl Default for CacheEvictionPolicy {
+    fn default() -> Self {
+        Self::Lru
+    }
+}
+
+impl Display for CacheEvictionPolicy {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            CacheEvictionPolicy::Lru => write!(f, "lru"),
+            CacheEvictionPolicy::S3Fifo => write!(f, "s3fifo"),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct DiskCacheConfig {
     /// Max bytes of cached raw table data. Default 20GB, set it to 0 to disable it.
@@ -682,6 +731,40 @@ impl Default for DiskCacheConfig {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CacheWarmUpConfig {
+    /// Record the hot tables, and warm up their caches after restart. Default is disabled.
+    pub enable: bool,
+
+    /// Path of the file that the hot tables are persisted to
+    pub path: String,
+
+    /// Interval in seconds of persisting the hot tables
+    pub persist_interval_secs: u64,
+
+    /// Max number of the most recently queried tables to warm up
+    pub max_tables: u64,
+
+    /// Max bytes of table column data to prefetch into the table data cache
+    pub max_column_data_bytes: u64,
+
+    /// Max number of concurrent reads while warming up, to throttle the background warm-up
+    pub max_concurrency: u64,
+}
+
+impl Default for CacheWarmUpConfig {
+    fn default() -> Self {
+        Self {
+            enable: false,
+            path: "./.databend/_cache_warm_up".to_owned(),
+            persist_interval_secs: 60,
+            max_tables: 32,
+            max_column_data_bytes: 1073741824,
+            max_concurrency: 4,
+        }
+    }
+}
+
 impl Default for CacheConfig {
     fn default() -> Self {
         Self {
@@ -704,6 +787,9 @@ impl Default for CacheConfig {
             data_cache_key_reload_policy: Default::default(),
             table_data_deserialized_data_bytes: 0,
             table_data_deserialized_memory_ratio: 0,
+            eviction_policy: Default::default(),
+            eviction_policies: BTreeMap::new(),
+            warm_up: Default::default(),
         }
     }
 }
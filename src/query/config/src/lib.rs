@@ -46,7 +46,9 @@ pub use databend_common_base::version::DATABEND_GIT_SHA;
 pub use databend_common_base::version::DATABEND_SEMVER;
 pub use global::GlobalConfig;
 pub use inner::CacheConfig;
+pub use inner::CacheEvictionPolicy;
 pub use inner::CacheStorageTypeConfig as CacheStorageTypeInnerConfig;
+pub use inner::CacheWarmUpConfig;
 pub use inner::CatalogConfig;
 pub use inner::CatalogHiveConfig;
 pub use inner::DiskCacheKeyReloadPolicy;
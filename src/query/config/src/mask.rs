@@ -71,6 +71,9 @@ impl QueryConfig {
         // Mask OpenAI API key
         masked_config.openai_api_key = mask_sensitive_field(&self.openai_api_key);
 
+        // Mask the key of notification secrets
+        masked_config.notification_secret_key = mask_sensitive_field(&self.notification_secret_key);
+
         masked_config
     }
 }
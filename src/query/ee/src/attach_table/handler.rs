@@ -91,6 +91,7 @@ impl AttachTableHandler for RealAttachTableHandler {
             },
             table_meta,
             as_dropped: false,
+            clone_pin: None,
         };
 
         Ok(req)
@@ -20,6 +20,7 @@ pub mod enterprise_services;
 pub mod fail_safe;
 pub mod inverted_index;
 pub mod license;
+pub mod row_access_policy;
 pub mod storage_encryption;
 pub mod storage_quota;
 pub mod storages;
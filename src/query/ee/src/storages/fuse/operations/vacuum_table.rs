@@ -29,6 +29,7 @@ use databend_common_storages_fuse::io::SnapshotsIO;
 use databend_common_storages_fuse::io::TableMetaLocationGenerator;
 use databend_common_storages_fuse::FuseTable;
 use databend_storages_common_cache::LoadParams;
+use databend_storages_common_table_meta::meta::cold_tier_location;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 
 use crate::storages::fuse::get_snapshot_referenced_segments;
@@ -128,10 +129,20 @@ pub async fn get_snapshot_referenced_files(
     segments_vec.into_iter().for_each(|(location, _)| {
         segments.insert(location);
     });
+    let mut blocks = locations_referenced.block_location;
+    let mut blocks_index = locations_referenced.bloom_location;
+
+    // 3. Files reachable from the snapshots pinned by branches, tags and clones,
+    // dropped clones have already been released by the purge of the snapshots.
+    let protected = fuse_table.read_protected_locations(ctx, false).await?;
+    segments.extend(protected.segments);
+    blocks.extend(protected.blocks);
+    blocks_index.extend(protected.blooms);
+
     Ok(Some(SnapshotReferencedFiles {
         segments,
-        blocks: locations_referenced.block_location,
-        blocks_index: locations_referenced.bloom_location,
+        blocks,
+        blocks_index,
     }))
 }
 
@@ -151,6 +162,35 @@ async fn get_orphan_files_to_be_purged(
         .await
 }
 
+// return orphan block files to be purged, from both the hot and the cold storage.
+// Blocks in the cold storage are returned as tiered locations, so that they are
+// purged from the storage they are stored in, e.g. the cold copies written by a
+// tiering whose commit failed.
+#[async_backtrace::framed]
+async fn get_orphan_block_files_to_be_purged(
+    fuse_table: &FuseTable,
+    prefix: &str,
+    referenced_files: HashSet<String>,
+    retention_time: DateTime<Utc>,
+) -> Result<Vec<String>> {
+    let mut orphan_files = fuse_table
+        .list_files(prefix.to_string(), |location, modified| {
+            modified <= retention_time && !referenced_files.contains(&location)
+        })
+        .await?;
+    let cold_orphan_files = fuse_table
+        .list_cold_files(prefix.to_string(), |path, modified| {
+            modified <= retention_time && !referenced_files.contains(&cold_tier_location(&path))
+        })
+        .await?;
+    orphan_files.extend(
+        cold_orphan_files
+            .into_iter()
+            .map(|p| cold_tier_location(&p)),
+    );
+    Ok(orphan_files)
+}
+
 #[async_backtrace::framed]
 pub async fn do_gc_orphan_files(
     fuse_table: &FuseTable,
@@ -208,7 +248,7 @@ pub async fn do_gc_orphan_files(
 
     // 3. Purge orphan block files.
     // 3.1 Get orphan block files to be purged
-    let block_locations_to_be_purged = get_orphan_files_to_be_purged(
+    let block_locations_to_be_purged = get_orphan_block_files_to_be_purged(
         fuse_table,
         location_gen.block_location_prefix(),
         referenced_files.blocks,
@@ -225,7 +265,7 @@ pub async fn do_gc_orphan_files(
     // 3.2 Delete all the orphan block files to be purged
     let purged_file_num = block_locations_to_be_purged.len();
     fuse_table
-        .try_purge_location_files(
+        .try_purge_block_files(
             ctx.clone(),
             HashSet::from_iter(block_locations_to_be_purged.into_iter()),
         )
@@ -316,7 +356,7 @@ pub async fn do_dry_run_orphan_files(
     }
 
     // 3. Get purge orphan block files.
-    let block_locations_to_be_purged = get_orphan_files_to_be_purged(
+    let block_locations_to_be_purged = get_orphan_block_files_to_be_purged(
         fuse_table,
         location_gen.block_location_prefix(),
         referenced_files.blocks,
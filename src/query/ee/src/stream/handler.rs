@@ -147,6 +147,7 @@ impl StreamHandler for RealStreamHandler {
                 ..Default::default()
             },
             as_dropped: false,
+            clone_pin: None,
         };
 
         catalog.create_table(req).await
@@ -16,6 +16,7 @@ use std::fmt::Debug;
 use std::sync::Arc;
 use std::time::Duration;
 
+use chrono::Utc;
 use databend_common_base::base::tokio;
 use databend_common_catalog::table_context::CheckAbort;
 use databend_common_exception::ErrorCode;
@@ -24,7 +25,9 @@ use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_storage::DataOperator;
+use databend_common_storages_fuse::FuseTable;
 use databend_common_storages_fuse::TableContext;
+use databend_enterprise_query::storages::fuse::do_vacuum;
 use databend_enterprise_query::storages::fuse::operations::vacuum_drop_tables::do_vacuum_drop_table;
 use databend_enterprise_query::storages::fuse::operations::vacuum_drop_tables::vacuum_drop_tables_by_table_info;
 use databend_enterprise_query::storages::fuse::operations::vacuum_temporary_files::do_vacuum_temporary_files;
@@ -38,6 +41,7 @@ use opendal::raw::OpStat;
 use opendal::raw::RpStat;
 use opendal::EntryMode;
 use opendal::Metadata;
+use opendal::Operator;
 use opendal::OperatorBuilder;
 
 #[tokio::test(flavor = "multi_thread")]
@@ -533,3 +537,57 @@ async fn test_remove_files_in_batch_do_not_swallow_errors() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_do_vacuum_cold_tier_orphan_blocks() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    let cold_dir = tempfile::tempdir()?;
+
+    let qry = format!(
+        "create table {db}.t(a int) cluster by (a) tiering_cold_location = 'fs://{}/' tiering_cluster_key_max = '100'",
+        cold_dir.path().display()
+    );
+    fixture.execute_command(&qry).await?;
+    fixture
+        .execute_command(&format!("insert into {db}.t values (1), (2)"))
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let table_ctx: Arc<dyn TableContext> = ctx.clone();
+    let stale_table = ctx
+        .get_table(fixture.default_catalog_name().as_str(), &db, "t")
+        .await?;
+
+    // the tiering conflicts with the truncation, its commit fails after the
+    // block has been copied to the cold storage
+    fixture
+        .execute_command(&format!("truncate table {db}.t"))
+        .await?;
+    let fuse_table = FuseTable::try_from_table(stale_table.as_ref())?;
+    assert!(fuse_table.do_tiering(&table_ctx).await.is_err());
+
+    let cold_operator = fuse_table.get_cold_operator().unwrap();
+    assert_eq!(count_files(&cold_operator).await?, 1);
+
+    let ctx = fixture.new_query_ctx().await?;
+    let table = ctx
+        .get_table(fixture.default_catalog_name().as_str(), &db, "t")
+        .await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    do_vacuum(fuse_table, ctx, Utc::now(), false).await?;
+
+    assert_eq!(
+        count_files(&cold_operator).await?,
+        0,
+        "do_vacuum: orphan blocks in the cold storage should be purged"
+    );
+
+    Ok(())
+}
+
+async fn count_files(operator: &Operator) -> Result<usize> {
+    let entries = operator.list_with("/").recursive(true).await?;
+    Ok(entries.iter().filter(|e| e.metadata().is_file()).count())
+}
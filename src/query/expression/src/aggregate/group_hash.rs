@@ -18,6 +18,7 @@ use databend_common_base::base::OrderedFloat;
 use databend_common_exception::Result;
 use ethnum::i256;
 
+use crate::types::AggregateStateColumn;
 use crate::types::AnyType;
 use crate::types::ArgType;
 use crate::types::BinaryType;
@@ -29,14 +30,21 @@ use crate::types::DecimalColumn;
 use crate::types::DecimalDataType;
 use crate::types::DecimalScalar;
 use crate::types::DecimalType;
+use crate::types::EnumColumn;
 use crate::types::GeographyType;
 use crate::types::GeometryType;
+use crate::types::Interval;
+use crate::types::IntervalType;
 use crate::types::NumberColumn;
 use crate::types::NumberDataType;
 use crate::types::NumberScalar;
 use crate::types::NumberType;
 use crate::types::StringType;
+use crate::types::TimeType;
 use crate::types::TimestampType;
+use crate::types::TimestampTz;
+use crate::types::TimestampTzType;
+use crate::types::UuidType;
 use crate::types::ValueType;
 use crate::types::VariantType;
 use crate::visitor::ValueVisitor;
@@ -81,7 +89,18 @@ pub fn combine_group_hash_column<const IS_FIRST: bool>(c: &Column, values: &mut
         DataType::Boolean => combine_group_hash_type_column::<IS_FIRST, BooleanType>(c, values),
         DataType::Timestamp => combine_group_hash_type_column::<IS_FIRST, TimestampType>(c, values),
         DataType::Date => combine_group_hash_type_column::<IS_FIRST, DateType>(c, values),
+        DataType::Time => combine_group_hash_type_column::<IS_FIRST, TimeType>(c, values),
+        DataType::Uuid => combine_group_hash_type_column::<IS_FIRST, UuidType>(c, values),
+        DataType::Enum(_) => {
+            // The items are the same within a column, so only the codes are hashed.
+            let codes = Column::Number(NumberColumn::UInt16(c.as_enum().unwrap().codes.clone()));
+            combine_group_hash_type_column::<IS_FIRST, NumberType<u16>>(&codes, values)
+        }
         DataType::Binary => combine_group_hash_string_column::<IS_FIRST, BinaryType>(c, values),
+        DataType::AggregateState(_) => {
+            let states = Column::Binary(c.as_aggregate_state().unwrap().states.clone());
+            combine_group_hash_string_column::<IS_FIRST, BinaryType>(&states, values)
+        }
         DataType::String => combine_group_hash_string_column::<IS_FIRST, StringType>(c, values),
         DataType::Bitmap => combine_group_hash_string_column::<IS_FIRST, BitmapType>(c, values),
         DataType::Variant => combine_group_hash_string_column::<IS_FIRST, VariantType>(c, values),
@@ -89,6 +108,10 @@ pub fn combine_group_hash_column<const IS_FIRST: bool>(c: &Column, values: &mut
         DataType::Geography => {
             combine_group_hash_string_column::<IS_FIRST, GeographyType>(c, values)
         }
+        DataType::Interval => combine_group_hash_type_column::<IS_FIRST, IntervalType>(c, values),
+        DataType::TimestampTz => {
+            combine_group_hash_type_column::<IS_FIRST, TimestampTzType>(c, values)
+        }
         DataType::Nullable(_) => {
             let col = c.as_nullable().unwrap();
             if IS_FIRST {
@@ -208,6 +231,12 @@ where I: Index
             Scalar::Variant(v) => v.agg_hash(),
             Scalar::Geometry(v) => v.agg_hash(),
             Scalar::Geography(v) => v.0.agg_hash(),
+            Scalar::Interval(v) => v.agg_hash(),
+            Scalar::TimestampTz(v) => v.agg_hash(),
+            Scalar::Time(v) => v.agg_hash(),
+            Scalar::Uuid(v) => v.agg_hash(),
+            Scalar::Enum(v, _) => v.agg_hash(),
+            Scalar::AggregateState(v, _) => v.agg_hash(),
             v => v.as_ref().agg_hash(),
         };
         self.visit_indices(|_| hash)
@@ -242,6 +271,18 @@ where I: Index
         self.visit_number(buffer)
     }
 
+    fn visit_time(&mut self, buffer: Buffer<i64>) -> Result<()> {
+        self.visit_number(buffer)
+    }
+
+    fn visit_uuid(&mut self, buffer: Buffer<u128>) -> Result<()> {
+        self.visit_indices(|i| buffer[i.to_usize()].agg_hash())
+    }
+
+    fn visit_enum(&mut self, column: EnumColumn) -> Result<()> {
+        self.visit_indices(|i| column.codes[i.to_usize()].agg_hash())
+    }
+
     fn visit_any_decimal(&mut self, column: DecimalColumn) -> Result<()> {
         with_decimal_type!(|DECIMAL_TYPE| match column {
             DecimalColumn::DECIMAL_TYPE(buffer, _) => {
@@ -263,6 +304,10 @@ where I: Index
         self.visit_binary(column)
     }
 
+    fn visit_aggregate_state(&mut self, column: AggregateStateColumn) -> Result<()> {
+        self.visit_binary(column.states)
+    }
+
     fn visit_string(&mut self, column: crate::types::StringColumn) -> Result<()> {
         self.visit_indices(|i| column.index(i.to_usize()).unwrap().as_bytes().agg_hash())
     }
@@ -282,6 +327,14 @@ where I: Index
         self.visit_binary(column.0)
     }
 
+    fn visit_interval(&mut self, buffer: Buffer<Interval>) -> Result<()> {
+        self.visit_indices(|i| buffer[i.to_usize()].agg_hash())
+    }
+
+    fn visit_timestamp_tz(&mut self, buffer: Buffer<TimestampTz>) -> Result<()> {
+        self.visit_indices(|i| buffer[i.to_usize()].agg_hash())
+    }
+
     fn visit_nullable(&mut self, column: Box<crate::types::NullableColumn<AnyType>>) -> Result<()> {
         let indices = self
             .indices
@@ -429,12 +482,30 @@ impl AggHash for i128 {
     }
 }
 
+impl AggHash for u128 {
+    fn agg_hash(&self) -> u64 {
+        self.to_le_bytes().agg_hash()
+    }
+}
+
 impl AggHash for i256 {
     fn agg_hash(&self) -> u64 {
         self.to_le_bytes().agg_hash()
     }
 }
 
+impl AggHash for Interval {
+    fn agg_hash(&self) -> u64 {
+        self.to_i128().agg_hash()
+    }
+}
+
+impl AggHash for TimestampTz {
+    fn agg_hash(&self) -> u64 {
+        self.micros.agg_hash()
+    }
+}
+
 impl AggHash for OrderedFloat<f32> {
     #[inline(always)]
     fn agg_hash(&self) -> u64 {
@@ -27,11 +27,13 @@ use crate::types::decimal::DecimalType;
 use crate::types::nullable::NullableColumn;
 use crate::types::string::StringColumn;
 use crate::types::string::StringColumnBuilder;
+use crate::types::AggregateStateColumn;
 use crate::types::ArgType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
 use crate::types::DecimalSize;
+use crate::types::EnumColumn;
 use crate::types::NumberDataType;
 use crate::types::NumberType;
 use crate::types::TimestampType;
@@ -246,7 +248,16 @@ impl Payload {
             },
             DataType::Timestamp => self.flush_type_column::<TimestampType>(col_offset, state),
             DataType::Date => self.flush_type_column::<DateType>(col_offset, state),
+            DataType::Enum(items) => {
+                let codes = (0..len)
+                    .map(|idx| unsafe { read::<u16>(state.addresses[idx].add(col_offset) as _) })
+                    .collect::<Vec<_>>();
+                Column::Enum(EnumColumn::new(codes.into(), items))
+            }
             DataType::Binary => Column::Binary(self.flush_binary_column(col_offset, state)),
+            DataType::AggregateState(signature) => Column::AggregateState(
+                AggregateStateColumn::new(self.flush_binary_column(col_offset, state), signature),
+            ),
             DataType::String => Column::String(self.flush_string_column(col_offset, state)),
             DataType::Bitmap => Column::Bitmap(self.flush_binary_column(col_offset, state)),
             DataType::Variant => Column::Variant(self.flush_binary_column(col_offset, state)),
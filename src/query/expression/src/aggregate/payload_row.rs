@@ -53,15 +53,23 @@ pub fn rowformat_size(data_type: &DataType) -> usize {
         },
         DataType::Timestamp => 8,
         DataType::Date => 4,
+        DataType::Enum(_) => 2,
         // use address instead
         DataType::Binary
+        | DataType::AggregateState(_)
         | DataType::String
         | DataType::Bitmap
         | DataType::Variant
         | DataType::Geometry
         | DataType::Geography => 4 + 8, // u32 len + address
         DataType::Nullable(x) => rowformat_size(x),
-        DataType::Array(_) | DataType::Map(_) | DataType::Tuple(_) => 4 + 8,
+        DataType::Array(_)
+        | DataType::Map(_)
+        | DataType::Tuple(_)
+        | DataType::Interval
+        | DataType::TimestampTz
+        | DataType::Time
+        | DataType::Uuid => 4 + 8,
         DataType::Generic(_) => unreachable!(),
     }
 }
@@ -120,6 +128,16 @@ pub unsafe fn serialize_column_to_rowformat(
                 );
             }
         }
+        Column::AggregateState(c) => {
+            for index in select_vector.iter().take(rows).copied() {
+                let data = arena.alloc_slice_copy(c.index_unchecked(index));
+                store(&(data.len() as u32), address[index].add(offset) as *mut u8);
+                store(
+                    &(data.as_ptr() as u64),
+                    address[index].add(offset + 4) as *mut u8,
+                );
+            }
+        }
         Column::String(v) => {
             for index in select_vector.iter().take(rows).copied() {
                 let data = arena.alloc_str(v.index_unchecked(index));
@@ -140,6 +158,11 @@ pub unsafe fn serialize_column_to_rowformat(
                 store(&buffer[index], address[index].add(offset) as *mut u8);
             }
         }
+        Column::Enum(c) => {
+            for index in select_vector.iter().take(rows).copied() {
+                store(&c.codes[index], address[index].add(offset) as *mut u8);
+            }
+        }
         Column::Nullable(c) => serialize_column_to_rowformat(
             arena,
             &c.column,
@@ -304,6 +327,21 @@ pub unsafe fn row_match_column(
             no_match,
             no_match_count,
         ),
+        Column::Enum(c) => {
+            let codes = Column::Number(NumberColumn::UInt16(c.codes.clone()));
+            row_match_column_type::<NumberType<u16>>(
+                &codes,
+                validity,
+                address,
+                select_vector,
+                temp_vector,
+                count,
+                validity_offset,
+                col_offset,
+                no_match,
+                no_match_count,
+            )
+        }
         Column::String(v) => row_match_string_column(
             v,
             validity,
@@ -330,6 +368,18 @@ pub unsafe fn row_match_column(
                 no_match_count,
             )
         }
+        Column::AggregateState(c) => row_match_binary_column(
+            &c.states,
+            validity,
+            address,
+            select_vector,
+            temp_vector,
+            count,
+            validity_offset,
+            col_offset,
+            no_match,
+            no_match_count,
+        ),
         Column::Nullable(_) => unreachable!("nullable is unwrapped"),
         other => row_match_generic_column(
             other,
@@ -25,10 +25,15 @@ use databend_common_arrow::arrow::types::Offset;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 
+use super::ARROW_EXT_TYPE_AGGREGATE_STATE;
 use super::ARROW_EXT_TYPE_BITMAP;
 use super::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use super::ARROW_EXT_TYPE_EMPTY_MAP;
+use super::ARROW_EXT_TYPE_ENUM;
 use super::ARROW_EXT_TYPE_GEOMETRY;
+use super::ARROW_EXT_TYPE_INTERVAL;
+use super::ARROW_EXT_TYPE_TIMESTAMP_TZ;
+use super::ARROW_EXT_TYPE_UUID;
 use super::ARROW_EXT_TYPE_VARIANT;
 use crate::types::array::ArrayColumn;
 use crate::types::binary::BinaryColumn;
@@ -38,11 +43,17 @@ use crate::types::geography::GeographyColumn;
 use crate::types::nullable::NullableColumn;
 use crate::types::string::StringColumn;
 use crate::types::string::StringColumnBuilder;
+use crate::types::AggregateStateColumn;
+use crate::types::AggregateStateSignature;
 use crate::types::DataType;
 use crate::types::DecimalDataType;
 use crate::types::DecimalSize;
+use crate::types::EnumColumn;
+use crate::types::EnumItems;
+use crate::types::Interval;
 use crate::types::NumberColumn;
 use crate::types::NumberDataType;
+use crate::types::TimestampTz;
 use crate::types::F32;
 use crate::types::F64;
 use crate::with_number_type;
@@ -130,6 +141,7 @@ fn arrow_type_to_table_type(ty: &ArrowDataType, is_nullable: bool) -> Result<Tab
 
         ArrowDataType::Timestamp(_, _) => TableDataType::Timestamp,
         ArrowDataType::Date32 | ArrowDataType::Date64 => TableDataType::Date,
+        ArrowDataType::Time32(_) | ArrowDataType::Time64(_) => TableDataType::Time,
         ArrowDataType::Map(f, _) => {
             let inner_ty = arrow_type_to_table_type(&f.data_type, f.is_nullable)?;
             TableDataType::Map(Box::new(inner_ty))
@@ -146,12 +158,39 @@ fn arrow_type_to_table_type(ty: &ArrowDataType, is_nullable: bool) -> Result<Tab
                 fields_type,
             }
         }
-        ArrowDataType::Extension(custom_name, data_type, _) => match custom_name.as_str() {
+        ArrowDataType::Extension(custom_name, data_type, metadata) => match custom_name.as_str() {
             ARROW_EXT_TYPE_EMPTY_ARRAY => TableDataType::EmptyArray,
             ARROW_EXT_TYPE_EMPTY_MAP => TableDataType::EmptyMap,
             ARROW_EXT_TYPE_BITMAP => TableDataType::Bitmap,
             ARROW_EXT_TYPE_VARIANT => TableDataType::Variant,
             ARROW_EXT_TYPE_GEOMETRY => TableDataType::Geometry,
+            ARROW_EXT_TYPE_INTERVAL => TableDataType::Interval,
+            ARROW_EXT_TYPE_TIMESTAMP_TZ => TableDataType::TimestampTz,
+            ARROW_EXT_TYPE_UUID => TableDataType::Uuid,
+            ARROW_EXT_TYPE_ENUM => {
+                let items = metadata
+                    .as_ref()
+                    .and_then(|metadata| serde_json::from_str::<Vec<String>>(metadata).ok())
+                    .ok_or_else(|| {
+                        ErrorCode::UnknownFormat(format!(
+                            "invalid items of arrow enum type: {metadata:?}"
+                        ))
+                    })?;
+                TableDataType::Enum(EnumItems::try_create(items)?)
+            }
+            ARROW_EXT_TYPE_AGGREGATE_STATE => {
+                let signature = metadata
+                    .as_ref()
+                    .and_then(|metadata| {
+                        serde_json::from_str::<AggregateStateSignature>(metadata).ok()
+                    })
+                    .ok_or_else(|| {
+                        ErrorCode::UnknownFormat(format!(
+                            "invalid signature of arrow aggregate state type: {metadata:?}"
+                        ))
+                    })?;
+                TableDataType::AggregateState(signature)
+            }
             _ => arrow_type_to_table_type(data_type, is_nullable)?,
         },
         _ => {
@@ -506,6 +545,54 @@ impl Column {
                         .values()
                         .clone(),
                 ),
+                (DataType::Time, ArrowDataType::Time32(unit)) => {
+                    let values = arrow_col
+                        .as_any()
+                        .downcast_ref::<databend_common_arrow::arrow::array::Int32Array>()
+                        .expect("fail to read `Time` from arrow: array should be `Int32Array`")
+                        .values();
+                    let scale = match unit {
+                        TimeUnit::Second => 1_000_000,
+                        _ => 1_000,
+                    };
+                    Column::Time(values.iter().map(|x| *x as i64 * scale).collect())
+                }
+                (DataType::Time, ArrowDataType::Time64(unit)) => {
+                    let values = arrow_col
+                        .as_any()
+                        .downcast_ref::<databend_common_arrow::arrow::array::Int64Array>()
+                        .expect("fail to read `Time` from arrow: array should be `Int64Array`")
+                        .values();
+                    match unit {
+                        TimeUnit::Nanosecond => {
+                            Column::Time(values.iter().map(|x| x / 1_000).collect())
+                        }
+                        _ => Column::Time(values.clone()),
+                    }
+                }
+                (DataType::Uuid, ArrowDataType::FixedSizeBinary(16)) => {
+                    let arrow_col = arrow_col
+                        .as_any()
+                        .downcast_ref::<FixedSizeBinaryArray>()
+                        .expect(
+                            "fail to read `Uuid` from arrow: array should be `FixedSizeBinaryArray`",
+                        );
+                    Column::Uuid(
+                        arrow_col
+                            .values()
+                            .chunks_exact(16)
+                            .map(|v| u128::from_be_bytes(v.try_into().unwrap()))
+                            .collect(),
+                    )
+                }
+                (DataType::Enum(items), ArrowDataType::UInt16) => {
+                    let codes = arrow_col
+                        .as_any()
+                        .downcast_ref::<databend_common_arrow::arrow::array::UInt16Array>()
+                        .expect("fail to read `Enum` from arrow: array should be `UInt16Array`")
+                        .values();
+                    Column::Enum(EnumColumn::new(codes.clone(), items.clone()))
+                }
                 (
                     DataType::Variant,
                     ArrowDataType::Extension(name, box ArrowDataType::Binary, None),
@@ -650,6 +737,24 @@ impl Column {
                         offsets,
                     })
                 }
+                (DataType::AggregateState(signature), ArrowDataType::LargeBinary) => {
+                    let arrow_col = arrow_col
+                        .as_any()
+                        .downcast_ref::<databend_common_arrow::arrow::array::BinaryArray<i64>>()
+                        .expect(
+                            "fail to read `AggregateState` from arrow: array should be `BinaryArray<i64>`",
+                        );
+                    let offsets = unsafe {
+                        std::mem::transmute::<Buffer<i64>, Buffer<u64>>(
+                            arrow_col.offsets().clone().into_inner(),
+                        )
+                    };
+                    let states = BinaryColumn {
+                        data: arrow_col.values().clone(),
+                        offsets,
+                    };
+                    Column::AggregateState(AggregateStateColumn::new(states, signature.clone()))
+                }
                 (DataType::Bitmap, ArrowDataType::LargeBinary) => {
                     let arrow_col = arrow_col
                         .as_any()
@@ -723,6 +828,36 @@ impl Column {
                         offsets,
                     }))
                 }
+                (DataType::Interval, ArrowDataType::Decimal(_, _)) => {
+                    let arrow_col = arrow_col
+                        .as_any()
+                        .downcast_ref::<databend_common_arrow::arrow::array::PrimitiveArray<i128>>()
+                        .expect(
+                            "fail to read `Interval` from arrow: array should be `PrimitiveArray<i128>`",
+                        );
+                    Column::Interval(
+                        arrow_col
+                            .values()
+                            .iter()
+                            .map(|v| Interval::from_i128(*v))
+                            .collect(),
+                    )
+                }
+                (DataType::TimestampTz, ArrowDataType::Decimal(_, _)) => {
+                    let arrow_col = arrow_col
+                        .as_any()
+                        .downcast_ref::<databend_common_arrow::arrow::array::PrimitiveArray<i128>>()
+                        .expect(
+                            "fail to read `TimestampTz` from arrow: array should be `PrimitiveArray<i128>`",
+                        );
+                    Column::TimestampTz(
+                        arrow_col
+                            .values()
+                            .iter()
+                            .map(|v| TimestampTz::from_i128(*v))
+                            .collect(),
+                    )
+                }
                 (data_type, ArrowDataType::Extension(_, arrow_type, _)) => {
                     from_arrow_with_arrow_type(arrow_col, arrow_type, data_type)?
                 }
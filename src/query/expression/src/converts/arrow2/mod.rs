@@ -21,5 +21,10 @@ pub const ARROW_EXT_TYPE_VARIANT: &str = "Variant";
 pub const ARROW_EXT_TYPE_BITMAP: &str = "Bitmap";
 pub const ARROW_EXT_TYPE_GEOMETRY: &str = "Geometry";
 pub const ARROW_EXT_TYPE_GEOGRAPHY: &str = "Geography";
+pub const ARROW_EXT_TYPE_INTERVAL: &str = "Interval";
+pub const ARROW_EXT_TYPE_TIMESTAMP_TZ: &str = "TimestampTz";
+pub const ARROW_EXT_TYPE_UUID: &str = "Uuid";
+pub const ARROW_EXT_TYPE_ENUM: &str = "Enum";
+pub const ARROW_EXT_TYPE_AGGREGATE_STATE: &str = "AggregateState";
 
 pub use to::set_validities;
@@ -20,14 +20,20 @@ use databend_common_arrow::arrow::datatypes::Schema as ArrowSchema;
 use databend_common_arrow::arrow::datatypes::TimeUnit;
 use databend_common_arrow::arrow::offset::OffsetsBuffer;
 
+use super::ARROW_EXT_TYPE_AGGREGATE_STATE;
 use super::ARROW_EXT_TYPE_BITMAP;
 use super::ARROW_EXT_TYPE_EMPTY_ARRAY;
 use super::ARROW_EXT_TYPE_EMPTY_MAP;
+use super::ARROW_EXT_TYPE_ENUM;
 use super::ARROW_EXT_TYPE_GEOGRAPHY;
 use super::ARROW_EXT_TYPE_GEOMETRY;
+use super::ARROW_EXT_TYPE_INTERVAL;
+use super::ARROW_EXT_TYPE_TIMESTAMP_TZ;
+use super::ARROW_EXT_TYPE_UUID;
 use super::ARROW_EXT_TYPE_VARIANT;
 use crate::types::decimal::DecimalColumn;
 use crate::types::geography::GeographyColumn;
+use crate::types::AggregateStateColumn;
 use crate::types::DecimalDataType;
 use crate::types::NumberColumn;
 use crate::types::NumberDataType;
@@ -105,6 +111,7 @@ fn table_type_to_arrow_type(ty: &TableDataType) -> ArrowDataType {
         }
         TableDataType::Timestamp => ArrowDataType::Timestamp(TimeUnit::Microsecond, None),
         TableDataType::Date => ArrowDataType::Date32,
+        TableDataType::Time => ArrowDataType::Time64(TimeUnit::Microsecond),
         TableDataType::Nullable(ty) => table_type_to_arrow_type(ty.as_ref()),
         TableDataType::Array(ty) => {
             let arrow_ty = table_type_to_arrow_type(ty.as_ref());
@@ -171,6 +178,36 @@ fn table_type_to_arrow_type(ty: &TableDataType) -> ArrowDataType {
             Box::new(ArrowDataType::LargeBinary),
             None,
         ),
+        // Months, days and microseconds packed into a single i128.
+        TableDataType::Interval => ArrowDataType::Extension(
+            ARROW_EXT_TYPE_INTERVAL.to_string(),
+            Box::new(ArrowDataType::Decimal(38, 0)),
+            None,
+        ),
+        // UTC microseconds and the offset in seconds packed into a single i128.
+        TableDataType::TimestampTz => ArrowDataType::Extension(
+            ARROW_EXT_TYPE_TIMESTAMP_TZ.to_string(),
+            Box::new(ArrowDataType::Decimal(38, 0)),
+            None,
+        ),
+        // The 16 bytes of the UUID in network order, as Parquet stores it.
+        TableDataType::Uuid => ArrowDataType::Extension(
+            ARROW_EXT_TYPE_UUID.to_string(),
+            Box::new(ArrowDataType::FixedSizeBinary(16)),
+            None,
+        ),
+        // The codes, the items are kept in the metadata as a JSON array.
+        TableDataType::Enum(items) => ArrowDataType::Extension(
+            ARROW_EXT_TYPE_ENUM.to_string(),
+            Box::new(ArrowDataType::UInt16),
+            Some(serde_json::to_string(items.items()).unwrap()),
+        ),
+        // The serialized states, the signature is kept in the metadata as JSON.
+        TableDataType::AggregateState(signature) => ArrowDataType::Extension(
+            ARROW_EXT_TYPE_AGGREGATE_STATE.to_string(),
+            Box::new(ArrowDataType::LargeBinary),
+            Some(serde_json::to_string(signature).unwrap()),
+        ),
     }
 }
 
@@ -332,6 +369,49 @@ impl Column {
                 )
                 .unwrap(),
             ),
+            Column::Time(col) => Box::new(
+                databend_common_arrow::arrow::array::PrimitiveArray::<i64>::try_new(
+                    arrow_type,
+                    col.clone(),
+                    None,
+                )
+                .unwrap(),
+            ),
+            Column::Uuid(col) => Box::new(
+                databend_common_arrow::arrow::array::FixedSizeBinaryArray::try_new(
+                    arrow_type,
+                    col.iter()
+                        .flat_map(|v| v.to_be_bytes())
+                        .collect::<Vec<u8>>()
+                        .into(),
+                    None,
+                )
+                .unwrap(),
+            ),
+            Column::Enum(col) => Box::new(
+                databend_common_arrow::arrow::array::PrimitiveArray::<u16>::try_new(
+                    arrow_type,
+                    col.codes.clone(),
+                    None,
+                )
+                .unwrap(),
+            ),
+            Column::Interval(col) => Box::new(
+                databend_common_arrow::arrow::array::PrimitiveArray::<i128>::try_new(
+                    arrow_type,
+                    col.iter().map(|v| v.to_i128()).collect(),
+                    None,
+                )
+                .unwrap(),
+            ),
+            Column::TimestampTz(col) => Box::new(
+                databend_common_arrow::arrow::array::PrimitiveArray::<i128>::try_new(
+                    arrow_type,
+                    col.iter().map(|v| v.to_i128()).collect(),
+                    None,
+                )
+                .unwrap(),
+            ),
             Column::Array(col) => {
                 let offsets: Buffer<i64> =
                     col.offsets.iter().map(|offset| *offset as i64).collect();
@@ -389,7 +469,8 @@ impl Column {
             | Column::Bitmap(col)
             | Column::Variant(col)
             | Column::Geometry(col)
-            | Column::Geography(GeographyColumn(col)) => {
+            | Column::Geography(GeographyColumn(col))
+            | Column::AggregateState(AggregateStateColumn { states: col, .. }) => {
                 let offsets: Buffer<i64> =
                     col.offsets().iter().map(|offset| *offset as i64).collect();
                 Box::new(
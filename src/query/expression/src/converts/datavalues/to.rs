@@ -56,7 +56,13 @@ pub fn scalar_to_datavalue(scalar: &Scalar) -> DataValue {
         | Scalar::Binary(_)
         | Scalar::Map(_)
         | Scalar::Bitmap(_)
-        | Scalar::Geography(_) => {
+        | Scalar::Geography(_)
+        | Scalar::Interval(_)
+        | Scalar::TimestampTz(_)
+        | Scalar::Time(_)
+        | Scalar::Uuid(_)
+        | Scalar::Enum(_, _)
+        | Scalar::AggregateState(_, _) => {
             unimplemented!()
         }
     }
@@ -176,7 +176,15 @@ impl From<Scalar> for LegacyScalar {
             Scalar::Timestamp(ts) => LegacyScalar::Timestamp(ts),
             Scalar::Date(date) => LegacyScalar::Date(date),
             Scalar::Boolean(b) => LegacyScalar::Boolean(b),
-            Scalar::Binary(_) | Scalar::Geometry(_) | Scalar::Geography(_) => unreachable!(),
+            Scalar::Binary(_)
+            | Scalar::Geometry(_)
+            | Scalar::Geography(_)
+            | Scalar::Interval(_)
+            | Scalar::TimestampTz(_)
+            | Scalar::Time(_)
+            | Scalar::Uuid(_)
+            | Scalar::Enum(_, _)
+            | Scalar::AggregateState(_, _) => unreachable!(),
             Scalar::String(string) => LegacyScalar::String(string.as_bytes().to_vec()),
             Scalar::Array(column) => LegacyScalar::Array(column.into()),
             Scalar::Map(column) => LegacyScalar::Map(column.into()),
@@ -196,7 +204,15 @@ impl From<Column> for LegacyColumn {
             Column::Number(num_col) => LegacyColumn::Number(num_col),
             Column::Decimal(dec_col) => LegacyColumn::Decimal(dec_col),
             Column::Boolean(bmp) => LegacyColumn::Boolean(bmp),
-            Column::Binary(_) | Column::Geometry(_) | Column::Geography(_) => unreachable!(),
+            Column::Binary(_)
+            | Column::Geometry(_)
+            | Column::Geography(_)
+            | Column::Interval(_)
+            | Column::TimestampTz(_)
+            | Column::Time(_)
+            | Column::Uuid(_)
+            | Column::Enum(_)
+            | Column::AggregateState(_) => unreachable!(),
             Column::String(str_col) => {
                 LegacyColumn::String(LegacyBinaryColumn::from(BinaryColumn::from(str_col)))
             }
@@ -39,6 +39,8 @@ pub enum IndexScalar {
     Tuple(Vec<IndexScalar>),
     BinaryV2(Vec<u8>),
     Variant(Vec<u8>),
+    Time(i64),
+    Uuid(u128),
 }
 
 impl TryFrom<IndexScalar> for Scalar {
@@ -51,6 +53,8 @@ impl TryFrom<IndexScalar> for Scalar {
             IndexScalar::Decimal(dec_scalar) => Scalar::Decimal(dec_scalar),
             IndexScalar::Timestamp(ts) => Scalar::Timestamp(ts),
             IndexScalar::Date(date) => Scalar::Date(date),
+            IndexScalar::Time(time) => Scalar::Time(time),
+            IndexScalar::Uuid(uuid) => Scalar::Uuid(uuid),
             IndexScalar::Boolean(b) => Scalar::Boolean(b),
             IndexScalar::String(s) => Scalar::String(String::from_utf8(s).map_err(|e| {
                 ErrorCode::InvalidUtf8String(format!("invalid utf8 data for string type: {}", e))
@@ -77,6 +81,8 @@ impl TryFrom<Scalar> for IndexScalar {
             Scalar::Decimal(dec_scalar) => IndexScalar::Decimal(dec_scalar),
             Scalar::Timestamp(ts) => IndexScalar::Timestamp(ts),
             Scalar::Date(date) => IndexScalar::Date(date),
+            Scalar::Time(time) => IndexScalar::Time(time),
+            Scalar::Uuid(uuid) => IndexScalar::Uuid(uuid),
             Scalar::Boolean(b) => IndexScalar::Boolean(b),
             Scalar::String(string) => IndexScalar::String(string.as_bytes().to_vec()),
             Scalar::Binary(s) => IndexScalar::BinaryV2(s),
@@ -93,6 +99,10 @@ impl TryFrom<Scalar> for IndexScalar {
             | Scalar::Bitmap(_)
             | Scalar::Geometry(_)
             | Scalar::Geography(_)
+            | Scalar::Interval(_)
+            | Scalar::TimestampTz(_)
+            | Scalar::Enum(_, _)
+            | Scalar::AggregateState(_, _)
             | Scalar::EmptyArray
             | Scalar::EmptyMap => return Err(ErrorCode::Unimplemented("Unsupported scalar type")),
         })
@@ -1015,6 +1015,12 @@ impl<'a> Evaluator<'a> {
                 Scalar::Number(NumberScalar::Int64(ty.precision() as _)),
                 Scalar::Number(NumberScalar::Int64(ty.scale() as _)),
             ]
+        } else if let DataType::Enum(items) = dest_type.remove_nullable() {
+            items
+                .items()
+                .iter()
+                .map(|item| Scalar::String(item.clone()))
+                .collect()
         } else {
             vec![]
         };
@@ -2393,6 +2399,12 @@ impl<'a, Index: ColumnIndex> ConstantFolder<'a, Index> {
                 Scalar::Number(NumberScalar::Int64(ty.precision() as _)),
                 Scalar::Number(NumberScalar::Int64(ty.scale() as _)),
             ]
+        } else if let DataType::Enum(items) = dest_type.remove_nullable() {
+            items
+                .items()
+                .iter()
+                .map(|item| Scalar::String(item.clone()))
+                .collect()
         } else {
             vec![]
         };
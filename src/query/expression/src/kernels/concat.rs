@@ -30,15 +30,21 @@ use crate::types::decimal::DecimalColumn;
 use crate::types::map::KvColumnBuilder;
 use crate::types::nullable::NullableColumn;
 use crate::types::number::NumberColumn;
+use crate::types::AggregateStateColumn;
 use crate::types::AnyType;
 use crate::types::ArrayType;
 use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
 use crate::types::DecimalType;
+use crate::types::EnumColumn;
+use crate::types::IntervalType;
 use crate::types::MapType;
 use crate::types::NumberType;
+use crate::types::TimeType;
 use crate::types::TimestampType;
+use crate::types::TimestampTzType;
+use crate::types::UuidType;
 use crate::types::ValueType;
 use crate::with_decimal_mapped_type;
 use crate::with_number_mapped_type;
@@ -167,6 +173,52 @@ impl Column {
                 );
                 Column::Date(buffer)
             }
+            Column::Interval(_) => {
+                let buffer = Self::concat_primitive_types(
+                    columns.map(|col| IntervalType::try_downcast_column(&col).unwrap()),
+                    capacity,
+                );
+                Column::Interval(buffer)
+            }
+            Column::TimestampTz(_) => {
+                let buffer = Self::concat_primitive_types(
+                    columns.map(|col| TimestampTzType::try_downcast_column(&col).unwrap()),
+                    capacity,
+                );
+                Column::TimestampTz(buffer)
+            }
+            Column::Time(_) => {
+                let buffer = Self::concat_primitive_types(
+                    columns.map(|col| TimeType::try_downcast_column(&col).unwrap()),
+                    capacity,
+                );
+                Column::Time(buffer)
+            }
+            Column::Uuid(_) => {
+                let buffer = Self::concat_primitive_types(
+                    columns.map(|col| UuidType::try_downcast_column(&col).unwrap()),
+                    capacity,
+                );
+                Column::Uuid(buffer)
+            }
+            Column::Enum(col) => {
+                let buffer = Self::concat_primitive_types(
+                    columns.map(|col| col.into_enum().unwrap().codes),
+                    capacity,
+                );
+                Column::Enum(EnumColumn::new(buffer, col.items))
+            }
+            Column::AggregateState(col) => {
+                let states = Self::concat_use_grows(
+                    columns.map(|col| Column::Binary(col.into_aggregate_state().unwrap().states)),
+                    DataType::Binary,
+                    capacity,
+                );
+                Column::AggregateState(AggregateStateColumn::new(
+                    states.into_binary().unwrap(),
+                    col.signature,
+                ))
+            }
             Column::Array(col) => {
                 let mut offsets = Vec::with_capacity(capacity + 1);
                 offsets.push(0);
@@ -254,6 +254,23 @@ impl<'a> ValueVisitor for FilterVisitor<'a> {
         Ok(())
     }
 
+    fn visit_enum(&mut self, column: EnumColumn) -> Result<()> {
+        let codes = self.filter_primitive_types(column.codes);
+        self.result = Some(Value::Column(Column::Enum(EnumColumn::new(
+            codes,
+            column.items,
+        ))));
+        Ok(())
+    }
+
+    fn visit_aggregate_state(&mut self, column: AggregateStateColumn) -> Result<()> {
+        let states = self.filter_binary_types(&column.states);
+        self.result = Some(Value::Column(Column::AggregateState(
+            AggregateStateColumn::new(states, column.signature),
+        )));
+        Ok(())
+    }
+
     fn visit_decimal<T: crate::types::Decimal>(
         &mut self,
         buffer: Buffer<T>,
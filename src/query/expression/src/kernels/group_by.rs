@@ -64,9 +64,11 @@ impl DataBlock {
         for hash_key_type in hash_key_types {
             let not_null_type = hash_key_type.remove_nullable();
 
+            // ENUM values are grouped by their codes
             if not_null_type.is_numeric()
                 || not_null_type.is_date_or_date_time()
                 || not_null_type.is_decimal()
+                || not_null_type.is_enum()
             {
                 group_key_len += not_null_type.numeric_byte_size().unwrap();
 
@@ -588,6 +588,16 @@ pub fn fixed_hash(
                 }
             })
         }
+        Column::Enum(c) => {
+            let codes = Column::Number(NumberColumn::UInt16(c.codes.clone()));
+            fixed_hash(
+                &codes,
+                &DataType::Number(NumberDataType::UInt16),
+                ptr,
+                step,
+                nulls,
+            )?;
+        }
         _ => {
             return Err(ErrorCode::BadDataValueType(format!(
                 "Unsupported apply fn fixed_hash operation for column: {:?}",
@@ -70,6 +70,13 @@ pub unsafe fn serialize_column_binary(column: &Column, row: usize, row_space: &m
             row_space.store_value_uncheckd(&(len as u64));
             row_space.extend_from_slice_unchecked(value);
         }
+        Column::AggregateState(v) => {
+            let value = unsafe { v.index_unchecked(row) };
+            let len = value.len();
+
+            row_space.store_value_uncheckd(&(len as u64));
+            row_space.extend_from_slice_unchecked(value);
+        }
         Column::Geography(v) => {
             let value = unsafe { v.index_unchecked(row) };
             let value = borsh::to_vec(&value.0).unwrap();
@@ -87,6 +94,19 @@ pub unsafe fn serialize_column_binary(column: &Column, row: usize, row_space: &m
         }
         Column::Timestamp(v) => row_space.store_value_uncheckd(&v[row]),
         Column::Date(v) => row_space.store_value_uncheckd(&v[row]),
+        Column::Time(v) => row_space.store_value_uncheckd(&v[row]),
+        Column::Uuid(v) => row_space.store_value_uncheckd(&v[row]),
+        Column::Enum(v) => row_space.store_value_uncheckd(&v.codes[row]),
+        Column::Interval(v) => {
+            let value = &v[row];
+            row_space.store_value_uncheckd(&value.months);
+            row_space.store_value_uncheckd(&value.days);
+            row_space.store_value_uncheckd(&value.micros);
+        }
+        Column::TimestampTz(v) => {
+            // Keys of the same instant must be equal whatever the offset is.
+            row_space.store_value_uncheckd(&v[row].micros);
+        }
         Column::Array(array) | Column::Map(array) => {
             let data = array.index(row).unwrap();
             row_space.store_value_uncheckd(&(data.len() as u64));
@@ -21,6 +21,7 @@ use databend_common_exception::Result;
 use memchr::memchr;
 
 use crate::types::AnyType;
+use crate::types::EnumColumn;
 use crate::types::NullableColumn;
 use crate::types::Number;
 use crate::types::StringColumn;
@@ -296,6 +297,11 @@ impl ValueVisitor for SortCompare {
         self.visit_number(buffer)
     }
 
+    // The codes are ordered as the items are declared
+    fn visit_enum(&mut self, column: EnumColumn) -> Result<()> {
+        self.visit_number(column.codes)
+    }
+
     fn visit_string(&mut self, column: StringColumn) -> Result<()> {
         assert!(column.len() == self.rows);
         self.generic_sort(
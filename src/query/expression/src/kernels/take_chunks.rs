@@ -400,6 +400,55 @@ impl Column {
                 let builder = GeographyType::create_builder(result_size, &[]);
                 Self::take_block_value_types::<GeographyType>(columns, builder, indices)
             }
+            Column::Interval(_) => {
+                let builder = IntervalType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<IntervalType>(columns, builder, indices)
+            }
+            Column::TimestampTz(_) => {
+                let builder = TimestampTzType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<TimestampTzType>(columns, builder, indices)
+            }
+            Column::Time(_) => {
+                let builder = TimeType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<TimeType>(columns, builder, indices)
+            }
+            Column::Uuid(_) => {
+                let builder = UuidType::create_builder(result_size, &[]);
+                Self::take_block_value_types::<UuidType>(columns, builder, indices)
+            }
+            Column::Enum(column) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| &col.as_enum().unwrap().codes)
+                    .collect_vec();
+                let mut builder = Vec::with_capacity(result_size);
+                for &(block_index, row, times) in indices {
+                    let val = unsafe { columns[block_index as usize].get_unchecked(row as usize) };
+                    for _ in 0..times {
+                        builder.push(*val);
+                    }
+                }
+                Column::Enum(EnumColumn::new(builder.into(), column.items.clone()))
+            }
+            Column::AggregateState(column) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| &col.as_aggregate_state().unwrap().states)
+                    .collect_vec();
+                let mut builder = BinaryColumnBuilder::with_capacity(result_size, 0);
+                for &(block_index, row, times) in indices {
+                    let val =
+                        unsafe { columns[block_index as usize].index_unchecked(row as usize) };
+                    for _ in 0..times {
+                        builder.put_slice(val);
+                        builder.commit_row();
+                    }
+                }
+                Column::AggregateState(AggregateStateColumn::new(
+                    builder.build(),
+                    column.signature.clone(),
+                ))
+            }
         }
     }
 
@@ -622,6 +671,48 @@ impl Column {
                     .collect_vec();
                 ColumnVec::Geography(columns)
             }
+            Column::Interval(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| IntervalType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::Interval(columns)
+            }
+            Column::TimestampTz(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| TimestampTzType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::TimestampTz(columns)
+            }
+            Column::Time(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| TimeType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::Time(columns)
+            }
+            Column::Uuid(_) => {
+                let columns = columns
+                    .iter()
+                    .map(|col| UuidType::try_downcast_column(col).unwrap())
+                    .collect_vec();
+                ColumnVec::Uuid(columns)
+            }
+            Column::Enum(column) => {
+                let codes = columns
+                    .iter()
+                    .map(|col| col.as_enum().unwrap().codes.clone())
+                    .collect_vec();
+                ColumnVec::Enum(codes, column.items.clone())
+            }
+            Column::AggregateState(column) => {
+                let states = columns
+                    .iter()
+                    .map(|col| col.as_aggregate_state().unwrap().states.clone())
+                    .collect_vec();
+                ColumnVec::AggregateState(states, column.signature.clone())
+            }
         }
     }
 
@@ -764,6 +855,30 @@ impl Column {
                     &columns, indices,
                 )))
             }
+            ColumnVec::Interval(columns) => {
+                let builder = Self::take_block_vec_primitive_types(columns, indices);
+                Column::Interval(builder.into())
+            }
+            ColumnVec::TimestampTz(columns) => {
+                let builder = Self::take_block_vec_primitive_types(columns, indices);
+                Column::TimestampTz(builder.into())
+            }
+            ColumnVec::Time(columns) => {
+                let builder = Self::take_block_vec_primitive_types(columns, indices);
+                Column::Time(builder.into())
+            }
+            ColumnVec::Uuid(columns) => {
+                let builder = Self::take_block_vec_primitive_types(columns, indices);
+                Column::Uuid(builder.into())
+            }
+            ColumnVec::Enum(columns, items) => {
+                let builder = Self::take_block_vec_primitive_types(columns, indices);
+                Column::Enum(EnumColumn::new(builder.into(), items.clone()))
+            }
+            ColumnVec::AggregateState(columns, signature) => {
+                let states = Self::take_block_vec_binary_types(columns, indices);
+                Column::AggregateState(AggregateStateColumn::new(states, signature.clone()))
+            }
         }
     }
 
@@ -25,6 +25,7 @@ use crate::types::string::StringColumn;
 use crate::types::*;
 use crate::visitor::ValueVisitor;
 use crate::BlockEntry;
+use crate::Column;
 use crate::ColumnBuilder;
 use crate::DataBlock;
 use crate::Value;
@@ -143,6 +144,23 @@ impl<'a> ValueVisitor for TakeCompactVisitor<'a> {
         Ok(())
     }
 
+    fn visit_enum(&mut self, column: EnumColumn) -> Result<()> {
+        let codes = self.take_primitive_types(column.codes);
+        self.result = Some(Value::Column(Column::Enum(EnumColumn::new(
+            codes,
+            column.items,
+        ))));
+        Ok(())
+    }
+
+    fn visit_aggregate_state(&mut self, column: AggregateStateColumn) -> Result<()> {
+        let states = self.take_binary_types(&column.states);
+        self.result = Some(Value::Column(Column::AggregateState(
+            AggregateStateColumn::new(states, column.signature),
+        )));
+        Ok(())
+    }
+
     fn visit_decimal<T: crate::types::Decimal>(
         &mut self,
         buffer: Buffer<T>,
@@ -32,10 +32,13 @@ use crate::types::BooleanType;
 use crate::types::DataType;
 use crate::types::DateType;
 use crate::types::DecimalDataType;
+use crate::types::EnumItems;
 use crate::types::NumberDataType;
 use crate::types::NumberType;
 use crate::types::StringType;
+use crate::types::TimeType;
 use crate::types::TimestampType;
+use crate::types::UuidType;
 use crate::types::ValueType;
 use crate::with_decimal_type;
 use crate::with_number_type;
@@ -99,6 +102,10 @@ pub enum Domain {
     String(StringDomain),
     Timestamp(SimpleDomain<i64>),
     Date(SimpleDomain<i32>),
+    Time(SimpleDomain<i64>),
+    Uuid(SimpleDomain<u128>),
+    /// The domain of the codes of an ENUM.
+    Enum(SimpleDomain<u16>, EnumItems),
     Nullable(NullableDomain<AnyType>),
     /// `Array(None)` means that the array is empty, thus there is no inner domain information.
     Array(Option<Box<Domain>>),
@@ -177,6 +184,15 @@ impl Domain {
             },
             DataType::Timestamp => Domain::Timestamp(TimestampType::full_domain()),
             DataType::Date => Domain::Date(DateType::full_domain()),
+            DataType::Time => Domain::Time(TimeType::full_domain()),
+            DataType::Uuid => Domain::Uuid(UuidType::full_domain()),
+            DataType::Enum(items) => Domain::Enum(
+                SimpleDomain {
+                    min: 0,
+                    max: items.max_code(),
+                },
+                items.clone(),
+            ),
             DataType::Null => Domain::Nullable(NullableDomain {
                 has_null: true,
                 value: None,
@@ -196,7 +212,10 @@ impl Domain {
             | DataType::Bitmap
             | DataType::Variant
             | DataType::Geometry
-            | DataType::Geography => Domain::Undefined,
+            | DataType::Geography
+            | DataType::Interval
+            | DataType::TimestampTz
+            | DataType::AggregateState(_) => Domain::Undefined,
             DataType::Generic(_) => unreachable!(),
         }
     }
@@ -248,6 +267,21 @@ impl Domain {
                 min: this.min.min(other.min),
                 max: this.max.max(other.max),
             }),
+            (Domain::Time(this), Domain::Time(other)) => Domain::Time(SimpleDomain {
+                min: this.min.min(other.min),
+                max: this.max.max(other.max),
+            }),
+            (Domain::Uuid(this), Domain::Uuid(other)) => Domain::Uuid(SimpleDomain {
+                min: this.min.min(other.min),
+                max: this.max.max(other.max),
+            }),
+            (Domain::Enum(this, items), Domain::Enum(other, _)) => Domain::Enum(
+                SimpleDomain {
+                    min: this.min.min(other.min),
+                    max: this.max.max(other.max),
+                },
+                items.clone(),
+            ),
             (
                 Domain::Nullable(NullableDomain {
                     has_null: true,
@@ -385,6 +419,11 @@ impl Domain {
                 Some(Scalar::Timestamp(*min))
             }
             Domain::Date(SimpleDomain { min, max }) if min == max => Some(Scalar::Date(*min)),
+            Domain::Time(SimpleDomain { min, max }) if min == max => Some(Scalar::Time(*min)),
+            Domain::Uuid(SimpleDomain { min, max }) if min == max => Some(Scalar::Uuid(*min)),
+            Domain::Enum(SimpleDomain { min, max }, items) if min == max => {
+                Some(Scalar::Enum(*min, items.clone()))
+            }
             Domain::Nullable(NullableDomain {
                 has_null: true,
                 value: None,
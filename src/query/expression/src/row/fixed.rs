@@ -17,6 +17,8 @@ use ethnum::i256;
 
 use super::row_converter::null_sentinel;
 use crate::types::binary::BinaryColumnBuilder;
+use crate::types::Interval;
+use crate::types::TimestampTz;
 use crate::types::F32;
 use crate::types::F64;
 
@@ -75,6 +77,7 @@ encode_unsigned!(1, u8);
 encode_unsigned!(2, u16);
 encode_unsigned!(4, u32);
 encode_unsigned!(8, u64);
+encode_unsigned!(16, u128);
 
 impl FixedLengthEncoding for F32 {
     type Encoded = [u8; 4];
@@ -98,6 +101,29 @@ impl FixedLengthEncoding for F64 {
     }
 }
 
+impl FixedLengthEncoding for Interval {
+    type Encoded = [u8; 24];
+
+    // Keep the same order as `Interval::cmp`: the normalized length first,
+    // then months and days to break ties.
+    fn encode(self) -> [u8; 24] {
+        let mut b = [0; 24];
+        b[..16].copy_from_slice(&self.total_micros().encode());
+        b[16..20].copy_from_slice(&self.months.encode());
+        b[20..].copy_from_slice(&self.days.encode());
+        b
+    }
+}
+
+impl FixedLengthEncoding for TimestampTz {
+    type Encoded = [u8; 8];
+
+    // Same as `TimestampTz::cmp`: only the UTC instant is compared.
+    fn encode(self) -> [u8; 8] {
+        self.micros.encode()
+    }
+}
+
 pub fn encode<T, I>(
     out: &mut BinaryColumnBuilder,
     iter: I,
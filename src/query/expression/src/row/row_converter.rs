@@ -26,8 +26,10 @@ use crate::types::binary::BinaryColumnBuilder;
 use crate::types::decimal::DecimalColumn;
 use crate::types::DataType;
 use crate::types::DecimalDataType;
+use crate::types::Interval;
 use crate::types::NumberColumn;
 use crate::types::NumberDataType;
+use crate::types::TimestampTz;
 use crate::with_decimal_type;
 use crate::with_number_mapped_type;
 use crate::with_number_type;
@@ -62,6 +64,7 @@ impl RowConverter {
             | DataType::EmptyMap
             | DataType::Map(_)
             | DataType::Bitmap
+            | DataType::AggregateState(_)
             | DataType::Tuple(_)
             | DataType::Generic(_) => false,
             DataType::Nullable(inner) => Self::support_data_type(inner.as_ref()),
@@ -120,6 +123,21 @@ impl RowConverter {
                 DataType::Date => lengths
                     .iter_mut()
                     .for_each(|x| *x += i32::ENCODED_LEN as u64),
+                DataType::Interval => lengths
+                    .iter_mut()
+                    .for_each(|x| *x += Interval::ENCODED_LEN as u64),
+                DataType::TimestampTz => lengths
+                    .iter_mut()
+                    .for_each(|x| *x += TimestampTz::ENCODED_LEN as u64),
+                DataType::Time => lengths
+                    .iter_mut()
+                    .for_each(|x| *x += i64::ENCODED_LEN as u64),
+                DataType::Uuid => lengths
+                    .iter_mut()
+                    .for_each(|x| *x += u128::ENCODED_LEN as u64),
+                DataType::Enum(_) => lengths
+                    .iter_mut()
+                    .for_each(|x| *x += u16::ENCODED_LEN as u64),
                 DataType::Binary => {
                     let col = col.remove_nullable();
                     if all_null {
@@ -251,6 +269,15 @@ fn encode_column(out: &mut BinaryColumnBuilder, column: &Column, asc: bool, null
         }
         Column::Timestamp(col) => fixed::encode(out, col, validity, asc, nulls_first),
         Column::Date(col) => fixed::encode(out, col, validity, asc, nulls_first),
+        Column::Interval(col) => {
+            fixed::encode(out, col.iter().copied(), validity, asc, nulls_first)
+        }
+        Column::TimestampTz(col) => {
+            fixed::encode(out, col.iter().copied(), validity, asc, nulls_first)
+        }
+        Column::Time(col) => fixed::encode(out, col, validity, asc, nulls_first),
+        Column::Uuid(col) => fixed::encode(out, col, validity, asc, nulls_first),
+        Column::Enum(col) => fixed::encode(out, &col.codes, validity, asc, nulls_first),
         Column::Binary(col) => variable::encode(out, col.iter(), validity, asc, nulls_first),
         Column::String(col) => variable::encode(
             out,
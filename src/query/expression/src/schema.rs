@@ -27,7 +27,9 @@ use serde::Serialize;
 
 use crate::display::display_tuple_field_name;
 use crate::types::decimal::DecimalDataType;
+use crate::types::AggregateStateSignature;
 use crate::types::DataType;
+use crate::types::EnumItems;
 use crate::types::NumberDataType;
 use crate::BlockMetaInfo;
 use crate::BlockMetaInfoDowncast;
@@ -208,6 +210,12 @@ pub enum TableDataType {
     Variant,
     Geometry,
     Geography,
+    Interval,
+    TimestampTz,
+    Time,
+    Uuid,
+    Enum(EnumItems),
+    AggregateState(AggregateStateSignature),
 }
 
 impl DataSchema {
@@ -1199,6 +1207,12 @@ impl From<&TableDataType> for DataType {
             TableDataType::Variant => DataType::Variant,
             TableDataType::Geometry => DataType::Geometry,
             TableDataType::Geography => DataType::Geography,
+            TableDataType::Interval => DataType::Interval,
+            TableDataType::TimestampTz => DataType::TimestampTz,
+            TableDataType::Time => DataType::Time,
+            TableDataType::Uuid => DataType::Uuid,
+            TableDataType::Enum(items) => DataType::Enum(items.clone()),
+            TableDataType::AggregateState(signature) => DataType::AggregateState(signature.clone()),
         }
     }
 }
@@ -1302,6 +1316,11 @@ impl TableDataType {
                 NumberDataType::Float64 => "DOUBLE".to_string(),
             },
             TableDataType::String => "VARCHAR".to_string(),
+            TableDataType::TimestampTz => "TIMESTAMP_TZ".to_string(),
+            TableDataType::Enum(items) => format!("ENUM({items})"),
+            TableDataType::AggregateState(signature) => {
+                format!("AGGREGATE_STATE({})", signature.sql_name())
+            }
             TableDataType::Nullable(inner_ty) => format!("{} NULL", inner_ty.sql_name()),
             _ => self.to_string().to_uppercase(),
         }
@@ -1439,6 +1458,12 @@ pub fn infer_schema_type(data_type: &DataType) -> Result<TableDataType> {
         DataType::Variant => Ok(TableDataType::Variant),
         DataType::Geometry => Ok(TableDataType::Geometry),
         DataType::Geography => Ok(TableDataType::Geography),
+        DataType::Interval => Ok(TableDataType::Interval),
+        DataType::TimestampTz => Ok(TableDataType::TimestampTz),
+        DataType::Time => Ok(TableDataType::Time),
+        DataType::Uuid => Ok(TableDataType::Uuid),
+        DataType::Enum(items) => Ok(TableDataType::Enum(items.clone())),
+        DataType::AggregateState(signature) => Ok(TableDataType::AggregateState(signature.clone())),
         DataType::Tuple(fields) => {
             let fields_type = fields
                 .iter()
@@ -174,6 +174,12 @@ pub fn check_cast<Index: ColumnIndex>(
                     Scalar::Number(NumberScalar::Int64(ty.precision() as _)),
                     Scalar::Number(NumberScalar::Int64(ty.scale() as _)),
                 ]
+            } else if let DataType::Enum(items) = dest_type.remove_nullable() {
+                items
+                    .items()
+                    .iter()
+                    .map(|item| Scalar::String(item.clone()))
+                    .collect()
             } else {
                 vec![]
             };
@@ -630,6 +636,11 @@ pub fn common_super_type(
         }
         (DataType::String, decimal_ty @ DataType::Decimal(_))
         | (decimal_ty @ DataType::Decimal(_), DataType::String) => Some(decimal_ty),
+        // a string may hold values that are not items of the enum, and enums with
+        // different items can only be compared by their items
+        (DataType::String, DataType::Enum(_))
+        | (DataType::Enum(_), DataType::String)
+        | (DataType::Enum(_), DataType::Enum(_)) => Some(DataType::String),
         (DataType::Decimal(a), DataType::Decimal(b)) => {
             let scale = a.scale().max(b.scale());
             let mut precision = a.leading_digits().max(b.leading_digits()) + scale;
@@ -700,11 +711,16 @@ pub fn get_simple_cast_function(
 ) -> Option<String> {
     let function_name = if dest_type.is_decimal() {
         "to_decimal".to_owned()
+    } else if dest_type.remove_nullable().is_enum() {
+        // the items of ENUM are passed as the params
+        "to_enum".to_owned()
     } else if src_type.remove_nullable() == DataType::String
         && dest_type.remove_nullable() == DataType::Variant
     {
         // parse JSON string to variant instead of cast
         "parse_json".to_owned()
+    } else if dest_type.remove_nullable() == DataType::TimestampTz {
+        "to_timestamp_tz".to_owned()
     } else {
         format!("to_{}", dest_type.to_string().to_lowercase())
     };
@@ -737,6 +753,11 @@ pub const ALL_SIMPLE_CAST_FUNCTIONS: &[&str] = &[
     "to_decimal",
     "to_bitmap",
     "to_geometry",
+    "to_interval",
+    "to_timestamp_tz",
+    "to_time",
+    "to_uuid",
+    "to_enum",
     "parse_json",
 ];
 
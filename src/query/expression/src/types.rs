@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod aggregate_state;
 pub mod any;
 pub mod array;
 pub mod binary;
@@ -21,16 +22,21 @@ pub mod date;
 pub mod decimal;
 pub mod empty_array;
 pub mod empty_map;
+pub mod enumeration;
 pub mod generic;
 pub mod geography;
 pub mod geometry;
+pub mod interval;
 pub mod map;
 pub mod null;
 pub mod nullable;
 pub mod number;
 pub mod number_class;
 pub mod string;
+pub mod time;
 pub mod timestamp;
+pub mod timestamp_tz;
+pub mod uuid;
 pub mod variant;
 
 use std::cmp::Ordering;
@@ -43,6 +49,9 @@ use enum_as_inner::EnumAsInner;
 use serde::Deserialize;
 use serde::Serialize;
 
+pub use self::aggregate_state::AggregateStateColumn;
+pub use self::aggregate_state::AggregateStateColumnBuilder;
+pub use self::aggregate_state::AggregateStateSignature;
 pub use self::any::AnyType;
 pub use self::array::ArrayColumn;
 pub use self::array::ArrayType;
@@ -54,10 +63,15 @@ pub use self::date::DateType;
 pub use self::decimal::*;
 pub use self::empty_array::EmptyArrayType;
 pub use self::empty_map::EmptyMapType;
+pub use self::enumeration::EnumColumn;
+pub use self::enumeration::EnumColumnBuilder;
+pub use self::enumeration::EnumItems;
 pub use self::generic::GenericType;
 pub use self::geography::GeographyColumn;
 pub use self::geography::GeographyType;
 pub use self::geometry::GeometryType;
+pub use self::interval::Interval;
+pub use self::interval::IntervalType;
 pub use self::map::MapType;
 pub use self::null::NullType;
 pub use self::nullable::NullableColumn;
@@ -66,7 +80,11 @@ pub use self::number::*;
 pub use self::number_class::*;
 pub use self::string::StringColumn;
 pub use self::string::StringType;
+pub use self::time::TimeType;
 pub use self::timestamp::TimestampType;
+pub use self::timestamp_tz::TimestampTz;
+pub use self::timestamp_tz::TimestampTzType;
+pub use self::uuid::UuidType;
 pub use self::variant::VariantType;
 use crate::property::Domain;
 use crate::values::Column;
@@ -96,6 +114,12 @@ pub enum DataType {
     Variant,
     Geometry,
     Geography,
+    Interval,
+    TimestampTz,
+    Time,
+    Uuid,
+    Enum(EnumItems),
+    AggregateState(AggregateStateSignature),
 
     // Used internally for generic types
     Generic(usize),
@@ -150,7 +174,13 @@ impl DataType {
             | DataType::Bitmap
             | DataType::Variant
             | DataType::Geometry
-            | DataType::Geography => false,
+            | DataType::Geography
+            | DataType::Interval
+            | DataType::TimestampTz
+            | DataType::Time
+            | DataType::Uuid
+            | DataType::Enum(_)
+            | DataType::AggregateState(_) => false,
             DataType::Nullable(ty) => ty.has_generic(),
             DataType::Array(ty) => ty.has_generic(),
             DataType::Map(ty) => ty.has_generic(),
@@ -175,6 +205,12 @@ impl DataType {
             | DataType::Variant
             | DataType::Geometry
             | DataType::Geography
+            | DataType::Interval
+            | DataType::TimestampTz
+            | DataType::Time
+            | DataType::Uuid
+            | DataType::Enum(_)
+            | DataType::AggregateState(_)
             | DataType::Generic(_) => false,
             DataType::Nullable(box DataType::Nullable(_) | box DataType::Null) => true,
             DataType::Nullable(ty) => ty.has_nested_nullable(),
@@ -244,9 +280,9 @@ impl DataType {
             DataType::Number(NumberDataType::UInt8) | DataType::Number(NumberDataType::Int8) => {
                 Ok(1)
             }
-            DataType::Number(NumberDataType::UInt16) | DataType::Number(NumberDataType::Int16) => {
-                Ok(2)
-            }
+            DataType::Number(NumberDataType::UInt16)
+            | DataType::Number(NumberDataType::Int16)
+            | DataType::Enum(_) => Ok(2),
             DataType::Date
             | DataType::Number(NumberDataType::UInt32)
             | DataType::Number(NumberDataType::Float32)
@@ -288,6 +324,11 @@ impl DataType {
                 NumberDataType::Float64 => "DOUBLE".to_string(),
             },
             DataType::String => "VARCHAR".to_string(),
+            DataType::TimestampTz => "TIMESTAMP_TZ".to_string(),
+            DataType::Enum(items) => format!("ENUM({items})"),
+            DataType::AggregateState(signature) => {
+                format!("AGGREGATE_STATE({})", signature.sql_name())
+            }
             DataType::Nullable(inner_ty) => format!("{} NULL", inner_ty.sql_name()),
             _ => self.to_string().to_uppercase(),
         }
@@ -0,0 +1,272 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::ops::Range;
+use std::sync::Arc;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::types::binary::BinaryColumn;
+use crate::types::binary::BinaryColumnBuilder;
+use crate::types::binary::BinaryIterator;
+use crate::types::DataType;
+use crate::Scalar;
+
+#[derive(Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+struct AggregateStateSignatureInner {
+    name: String,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+}
+
+/// The aggregate function that produces the states of an AGGREGATE_STATE
+/// type, states are only merged by the same function.
+#[derive(Clone)]
+pub struct AggregateStateSignature(Arc<AggregateStateSignatureInner>);
+
+impl AggregateStateSignature {
+    pub fn new(name: &str, params: Vec<Scalar>, arguments: Vec<DataType>) -> Self {
+        AggregateStateSignature(Arc::new(AggregateStateSignatureInner {
+            name: name.to_lowercase(),
+            params,
+            arguments,
+        }))
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    pub fn params(&self) -> &[Scalar] {
+        &self.0.params
+    }
+
+    pub fn arguments(&self) -> &[DataType] {
+        &self.0.arguments
+    }
+
+    /// The arguments of `AGGREGATE_STATE(...)` in SQL.
+    pub fn sql_name(&self) -> String {
+        let mut name = self.function_name();
+        for argument in self.arguments() {
+            name.push_str(", ");
+            name.push_str(&argument.sql_name());
+        }
+        name
+    }
+
+    /// The aggregate function with its params, e.g. `quantile_tdigest(0.5)`.
+    pub fn function_name(&self) -> String {
+        if self.params().is_empty() {
+            return self.name().to_string();
+        }
+        let params = self
+            .params()
+            .iter()
+            .map(|param| param.to_string())
+            .collect::<Vec<_>>();
+        format!("{}({})", self.name(), params.join(", "))
+    }
+}
+
+impl PartialEq for AggregateStateSignature {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0) || self.0 == other.0
+    }
+}
+
+impl Eq for AggregateStateSignature {}
+
+impl Hash for AggregateStateSignature {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl Debug for AggregateStateSignature {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Displays the signature as the arguments of `AggregateState(...)`, e.g.
+/// `quantile_tdigest(0.5), Float64`.
+impl Display for AggregateStateSignature {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.function_name())?;
+        for argument in self.arguments() {
+            write!(f, ", {argument}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for AggregateStateSignature {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for AggregateStateSignature {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        AggregateStateSignatureInner::deserialize(deserializer)
+            .map(|inner| AggregateStateSignature(Arc::new(inner)))
+    }
+}
+
+impl BorshSerialize for AggregateStateSignature {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let bytes = serde_json::to_vec(self)?;
+        BorshSerialize::serialize(&bytes, writer)
+    }
+}
+
+impl BorshDeserialize for AggregateStateSignature {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let bytes: Vec<u8> = BorshDeserialize::deserialize_reader(reader)?;
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+}
+
+/// A column of serialized aggregate states of the same function.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AggregateStateColumn {
+    pub states: BinaryColumn,
+    pub signature: AggregateStateSignature,
+}
+
+impl AggregateStateColumn {
+    pub fn new(states: BinaryColumn, signature: AggregateStateSignature) -> Self {
+        AggregateStateColumn { states, signature }
+    }
+
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.states.len() == 0
+    }
+
+    pub fn index(&self, index: usize) -> Option<&[u8]> {
+        self.states.index(index)
+    }
+
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]*
+    pub unsafe fn index_unchecked(&self, index: usize) -> &[u8] {
+        self.states.index_unchecked(index)
+    }
+
+    pub fn slice(&self, range: Range<usize>) -> Self {
+        AggregateStateColumn {
+            states: self.states.slice(range),
+            signature: self.signature.clone(),
+        }
+    }
+
+    pub fn iter(&self) -> BinaryIterator<'_> {
+        self.states.iter()
+    }
+
+    pub fn memory_size(&self) -> usize {
+        self.states.memory_size()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AggregateStateColumnBuilder {
+    pub states: BinaryColumnBuilder,
+    pub signature: AggregateStateSignature,
+}
+
+impl AggregateStateColumnBuilder {
+    pub fn with_capacity(signature: AggregateStateSignature, capacity: usize) -> Self {
+        AggregateStateColumnBuilder {
+            states: BinaryColumnBuilder::with_capacity(capacity, 0),
+            signature,
+        }
+    }
+
+    pub fn from_column(col: AggregateStateColumn) -> Self {
+        AggregateStateColumnBuilder {
+            states: BinaryColumnBuilder::from_column(col.states),
+            signature: col.signature,
+        }
+    }
+
+    pub fn repeat(signature: AggregateStateSignature, state: &[u8], n: usize) -> Self {
+        AggregateStateColumnBuilder {
+            states: BinaryColumnBuilder::repeat(state, n),
+            signature,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.states.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.states.len() == 0
+    }
+
+    pub fn memory_size(&self) -> usize {
+        self.states.memory_size()
+    }
+
+    pub fn push(&mut self, state: &[u8]) {
+        self.states.put_slice(state);
+        self.states.commit_row();
+    }
+
+    pub fn push_repeat(&mut self, state: &[u8], n: usize) {
+        self.states.push_repeat(state, n);
+    }
+
+    /// The default value is an empty state.
+    pub fn push_default(&mut self) {
+        self.states.commit_row();
+    }
+
+    pub fn append_column(&mut self, other: &AggregateStateColumn) {
+        debug_assert_eq!(self.signature, other.signature);
+        self.states.append_column(&other.states);
+    }
+
+    pub fn pop(&mut self) -> Option<Vec<u8>> {
+        self.states.pop()
+    }
+
+    pub fn build(self) -> AggregateStateColumn {
+        AggregateStateColumn {
+            states: self.states.build(),
+            signature: self.signature,
+        }
+    }
+
+    pub fn build_scalar(self) -> Vec<u8> {
+        self.states.build_scalar()
+    }
+}
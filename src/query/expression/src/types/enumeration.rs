@@ -0,0 +1,301 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::ops::Range;
+use std::sync::Arc;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_arrow::arrow::buffer::Buffer;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+use crate::utils::arrow::buffer_into_mut;
+
+/// The maximum number of items of an ENUM type, the codes are `u16`.
+pub const MAX_ENUM_ITEMS: usize = u16::MAX as usize + 1;
+
+struct EnumItemsInner {
+    items: Vec<String>,
+    codes: HashMap<String, u16>,
+}
+
+/// The items of an ENUM type. A value is kept as the code of its item, which
+/// is the position of the item, so the values are ordered as the items are
+/// declared.
+#[derive(Clone)]
+pub struct EnumItems(Arc<EnumItemsInner>);
+
+impl EnumItems {
+    pub fn try_create(items: Vec<String>) -> Result<Self> {
+        if items.is_empty() {
+            return Err(ErrorCode::BadArguments("ENUM must have at least one item"));
+        }
+        if items.len() > MAX_ENUM_ITEMS {
+            return Err(ErrorCode::BadArguments(format!(
+                "ENUM can have at most {MAX_ENUM_ITEMS} items, but got {}",
+                items.len()
+            )));
+        }
+        let items = Self::new_unchecked(items);
+        if items.0.codes.len() != items.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "ENUM items must be unique, but got Enum({items})"
+            )));
+        }
+        Ok(items)
+    }
+
+    /// Creates the items without checking them, used when the items come from
+    /// a type that has been checked.
+    pub fn new_unchecked(items: Vec<String>) -> Self {
+        let codes = items
+            .iter()
+            .enumerate()
+            .map(|(code, item)| (item.clone(), code as u16))
+            .collect();
+        EnumItems(Arc::new(EnumItemsInner { items, codes }))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.items.is_empty()
+    }
+
+    pub fn items(&self) -> &[String] {
+        &self.0.items
+    }
+
+    pub fn get(&self, code: u16) -> Option<&str> {
+        self.0.items.get(code as usize).map(|item| item.as_str())
+    }
+
+    /// Returns the item of a code, codes are only created from the items so
+    /// a code is always valid.
+    pub fn item(&self, code: u16) -> &str {
+        self.get(code).unwrap_or_default()
+    }
+
+    pub fn code_of(&self, item: &str) -> Option<u16> {
+        self.0.codes.get(item).copied()
+    }
+
+    pub fn max_code(&self) -> u16 {
+        (self.len() - 1) as u16
+    }
+}
+
+impl PartialEq for EnumItems {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0) || self.0.items == other.0.items
+    }
+}
+
+impl Eq for EnumItems {}
+
+impl Hash for EnumItems {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.items.hash(state);
+    }
+}
+
+impl Debug for EnumItems {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        f.debug_list().entries(self.0.items.iter()).finish()
+    }
+}
+
+/// Displays the items as the arguments of `Enum(...)`.
+impl Display for EnumItems {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        for (i, item) in self.0.items.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "'{}'", item.replace('\\', "\\\\").replace('\'', "\\'"))?;
+        }
+        Ok(())
+    }
+}
+
+impl Serialize for EnumItems {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        self.0.items.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for EnumItems {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        Vec::<String>::deserialize(deserializer).map(EnumItems::new_unchecked)
+    }
+}
+
+impl BorshSerialize for EnumItems {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        BorshSerialize::serialize(&self.0.items, writer)
+    }
+}
+
+impl BorshDeserialize for EnumItems {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        Vec::<String>::deserialize_reader(reader).map(EnumItems::new_unchecked)
+    }
+}
+
+/// A column of ENUM values, only the codes are kept in the column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumColumn {
+    pub codes: Buffer<u16>,
+    pub items: EnumItems,
+}
+
+impl EnumColumn {
+    pub fn new(codes: Buffer<u16>, items: EnumItems) -> Self {
+        EnumColumn { codes, items }
+    }
+
+    pub fn len(&self) -> usize {
+        self.codes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.codes.is_empty()
+    }
+
+    pub fn index(&self, index: usize) -> Option<u16> {
+        self.codes.get(index).cloned()
+    }
+
+    /// # Safety
+    ///
+    /// Calling this method with an out-of-bounds index is *[undefined behavior]*
+    pub unsafe fn index_unchecked(&self, index: usize) -> u16 {
+        debug_assert!(index < self.codes.len());
+
+        *self.codes.get_unchecked(index)
+    }
+
+    pub fn slice(&self, range: Range<usize>) -> Self {
+        EnumColumn {
+            codes: self
+                .codes
+                .clone()
+                .sliced(range.start, range.end - range.start),
+            items: self.items.clone(),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str> + '_ {
+        self.codes.iter().map(|code| self.items.item(*code))
+    }
+
+    pub fn memory_size(&self) -> usize {
+        self.codes.len() * 2
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnumColumnBuilder {
+    pub codes: Vec<u16>,
+    pub items: EnumItems,
+}
+
+impl EnumColumnBuilder {
+    pub fn with_capacity(items: EnumItems, capacity: usize) -> Self {
+        EnumColumnBuilder {
+            codes: Vec::with_capacity(capacity),
+            items,
+        }
+    }
+
+    pub fn from_column(col: EnumColumn) -> Self {
+        EnumColumnBuilder {
+            codes: buffer_into_mut(col.codes),
+            items: col.items,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.codes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.codes.is_empty()
+    }
+
+    pub fn memory_size(&self) -> usize {
+        self.codes.len() * 2
+    }
+
+    pub fn push(&mut self, code: u16) {
+        self.codes.push(code);
+    }
+
+    pub fn push_repeat(&mut self, code: u16, n: usize) {
+        self.codes.resize(self.codes.len() + n, code);
+    }
+
+    /// The default value is the first item.
+    pub fn push_default(&mut self) {
+        self.codes.push(0);
+    }
+
+    pub fn append_column(&mut self, other: &EnumColumn) {
+        debug_assert_eq!(self.items, other.items);
+        self.codes.extend_from_slice(&other.codes);
+    }
+
+    pub fn pop(&mut self) -> Option<u16> {
+        self.codes.pop()
+    }
+
+    pub fn build(self) -> EnumColumn {
+        EnumColumn {
+            codes: self.codes.into(),
+            items: self.items,
+        }
+    }
+
+    pub fn build_scalar(self) -> u16 {
+        assert_eq!(self.codes.len(), 1);
+        self.codes[0]
+    }
+}
+
+/// Converts a string to the code of its item.
+pub fn string_to_enum(items: &EnumItems, s: impl AsRef<[u8]>) -> Result<u16> {
+    let s = s.as_ref();
+    std::str::from_utf8(s)
+        .ok()
+        .and_then(|s| items.code_of(s))
+        .ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "'{}' is not an item of Enum({items})",
+                String::from_utf8_lossy(s)
+            ))
+        })
+}
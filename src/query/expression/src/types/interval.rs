@@ -0,0 +1,477 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::ops::Range;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_arrow::arrow::buffer::Buffer;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::property::Domain;
+use crate::types::timestamp::MICROS_PER_SEC;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::DecimalSize;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+pub const MICROS_PER_MINUTE: i64 = 60 * MICROS_PER_SEC;
+pub const MICROS_PER_HOUR: i64 = 60 * MICROS_PER_MINUTE;
+pub const MICROS_PER_DAY: i64 = 24 * MICROS_PER_HOUR;
+/// Number of days a month is worth when intervals are compared or summed up.
+pub const DAYS_PER_MONTH: i64 = 30;
+
+/// A span of time made of three independent parts.
+///
+/// Months and days are kept apart from the sub-day part because their length
+/// depends on the calendar: `1 month` added to `2024-01-31` is `2024-02-29`,
+/// and `1 day` across a daylight saving change is not always 24 hours.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    BorshSerialize,
+    BorshDeserialize,
+)]
+pub struct Interval {
+    pub months: i32,
+    pub days: i32,
+    pub micros: i64,
+}
+
+impl Interval {
+    pub const fn new(months: i32, days: i32, micros: i64) -> Self {
+        Interval {
+            months,
+            days,
+            micros,
+        }
+    }
+
+    pub const fn from_months(months: i32) -> Self {
+        Interval::new(months, 0, 0)
+    }
+
+    pub const fn from_days(days: i32) -> Self {
+        Interval::new(0, days, 0)
+    }
+
+    pub const fn from_micros(micros: i64) -> Self {
+        Interval::new(0, 0, micros)
+    }
+
+    /// Length of the interval in microseconds, counting a month as 30 days
+    /// and a day as 24 hours.
+    pub fn total_micros(&self) -> i128 {
+        (self.months as i128 * DAYS_PER_MONTH as i128 + self.days as i128) * MICROS_PER_DAY as i128
+            + self.micros as i128
+    }
+
+    pub fn checked_add(&self, other: &Interval) -> Option<Interval> {
+        Some(Interval {
+            months: self.months.checked_add(other.months)?,
+            days: self.days.checked_add(other.days)?,
+            micros: self.micros.checked_add(other.micros)?,
+        })
+    }
+
+    pub fn checked_sub(&self, other: &Interval) -> Option<Interval> {
+        Some(Interval {
+            months: self.months.checked_sub(other.months)?,
+            days: self.days.checked_sub(other.days)?,
+            micros: self.micros.checked_sub(other.micros)?,
+        })
+    }
+
+    pub fn checked_neg(&self) -> Option<Interval> {
+        Some(Interval {
+            months: self.months.checked_neg()?,
+            days: self.days.checked_neg()?,
+            micros: self.micros.checked_neg()?,
+        })
+    }
+
+    pub fn checked_mul(&self, factor: i64) -> Option<Interval> {
+        Some(Interval {
+            months: i32::try_from((self.months as i64).checked_mul(factor)?).ok()?,
+            days: i32::try_from((self.days as i64).checked_mul(factor)?).ok()?,
+            micros: self.micros.checked_mul(factor)?,
+        })
+    }
+
+    /// Divide every part of the interval, carrying the remainder of months
+    /// into days and the remainder of days into microseconds.
+    pub fn checked_div(&self, divisor: i64) -> Option<Interval> {
+        if divisor == 0 {
+            return None;
+        }
+        let months = self.months as i64 / divisor;
+        let days = self.days as i64 + (self.months as i64 % divisor) * DAYS_PER_MONTH;
+        let micros = self.micros as i128 + (days % divisor) as i128 * MICROS_PER_DAY as i128;
+        Some(Interval {
+            months: i32::try_from(months).ok()?,
+            days: i32::try_from(days / divisor).ok()?,
+            micros: i64::try_from(micros / divisor as i128).ok()?,
+        })
+    }
+
+    /// Pack the interval into a single `i128`, used by the arrow and parquet
+    /// representation of interval columns.
+    pub fn to_i128(&self) -> i128 {
+        ((self.months as i128) << 96)
+            | (((self.days as u32) as i128) << 64)
+            | ((self.micros as u64) as i128)
+    }
+
+    pub fn from_i128(value: i128) -> Self {
+        Interval {
+            months: (value >> 96) as i32,
+            days: (value >> 64) as i32,
+            micros: value as i64,
+        }
+    }
+}
+
+impl PartialOrd for Interval {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Interval {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.total_micros()
+            .cmp(&other.total_micros())
+            .then_with(|| self.months.cmp(&other.months))
+            .then_with(|| self.days.cmp(&other.days))
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut parts = vec![];
+        let years = self.months / 12;
+        let months = self.months % 12;
+        let plural = |n: i64| if n.abs() == 1 { "" } else { "s" };
+        if years != 0 {
+            parts.push(format!("{} year{}", years, plural(years as i64)));
+        }
+        if months != 0 {
+            parts.push(format!("{} month{}", months, plural(months as i64)));
+        }
+        if self.days != 0 {
+            parts.push(format!("{} day{}", self.days, plural(self.days as i64)));
+        }
+        if self.micros != 0 || parts.is_empty() {
+            let sign = if self.micros < 0 { "-" } else { "" };
+            let micros = self.micros.unsigned_abs();
+            let secs = micros / MICROS_PER_SEC as u64;
+            let frac = micros % MICROS_PER_SEC as u64;
+            let mut time = format!(
+                "{sign}{:02}:{:02}:{:02}",
+                secs / 3600,
+                secs / 60 % 60,
+                secs % 60
+            );
+            if frac != 0 {
+                let frac = format!("{:06}", frac);
+                time.push('.');
+                time.push_str(frac.trim_end_matches('0'));
+            }
+            parts.push(time);
+        }
+        write!(f, "{}", parts.join(" "))
+    }
+}
+
+/// Parse an interval from its textual form.
+///
+/// The input is a sequence of `<quantity> <unit>` pairs optionally followed
+/// by a `[-]HH:MM:SS[.ffffff]` time part, for example `1 year 2 months`,
+/// `-3 days 04:05:06.5` or `90 minutes`. This is the same form `Display`
+/// produces.
+pub fn string_to_interval(s: &str) -> Result<Interval> {
+    let bad = || ErrorCode::BadArguments(format!("invalid interval '{s}'"));
+    let overflow = || ErrorCode::Overflow(format!("interval '{s}' is out of range"));
+
+    let mut interval = Interval::default();
+    let mut tokens = s.split_whitespace().peekable();
+    if tokens.peek().is_none() {
+        return Err(bad());
+    }
+    while let Some(token) = tokens.next() {
+        if token.contains(':') {
+            let micros = parse_time_part(token).ok_or_else(bad)?;
+            interval.micros = interval.micros.checked_add(micros).ok_or_else(overflow)?;
+            continue;
+        }
+
+        // Accept both `3 days` and `3days`.
+        let split = token
+            .find(|c: char| c.is_ascii_alphabetic())
+            .unwrap_or(token.len());
+        let (number, unit) = token.split_at(split);
+        let unit = if unit.is_empty() {
+            tokens.next().ok_or_else(bad)?
+        } else {
+            unit
+        };
+        let number: f64 = number.parse().map_err(|_| bad())?;
+
+        let from_micros = |factor: i64| Interval::from_micros((number * factor as f64) as i64);
+        let part = match unit.to_ascii_lowercase().as_str() {
+            "year" | "years" | "y" | "yr" | "yrs" => {
+                Interval::from_months(whole(number * 12.0).ok_or_else(bad)?)
+            }
+            "quarter" | "quarters" | "q" => {
+                Interval::from_months(whole(number * 3.0).ok_or_else(bad)?)
+            }
+            "month" | "months" | "mon" | "mons" => {
+                Interval::from_months(whole(number).ok_or_else(bad)?)
+            }
+            "week" | "weeks" | "w" => Interval::from_days(whole(number * 7.0).ok_or_else(bad)?),
+            "day" | "days" | "d" => Interval::from_days(whole(number).ok_or_else(bad)?),
+            "hour" | "hours" | "h" | "hr" | "hrs" => from_micros(MICROS_PER_HOUR),
+            "minute" | "minutes" | "min" | "mins" | "m" => from_micros(MICROS_PER_MINUTE),
+            "second" | "seconds" | "sec" | "secs" | "s" => from_micros(MICROS_PER_SEC),
+            "millisecond" | "milliseconds" | "ms" => from_micros(1000),
+            "microsecond" | "microseconds" | "us" => from_micros(1),
+            _ => return Err(bad()),
+        };
+        interval = interval.checked_add(&part).ok_or_else(overflow)?;
+    }
+    Ok(interval)
+}
+
+/// Convert a quantity of months or days, which can't carry a fraction.
+fn whole(number: f64) -> Option<i32> {
+    if number.fract() != 0.0 || number < i32::MIN as f64 || number > i32::MAX as f64 {
+        return None;
+    }
+    Some(number as i32)
+}
+
+fn parse_time_part(token: &str) -> Option<i64> {
+    let (negative, token) = match token.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, token.strip_prefix('+').unwrap_or(token)),
+    };
+    let mut fields = token.split(':');
+    let hours: i64 = fields.next()?.parse().ok()?;
+    let minutes: i64 = fields.next()?.parse().ok()?;
+    let seconds = fields.next().unwrap_or("0");
+    if fields.next().is_some() || !(0..60).contains(&minutes) {
+        return None;
+    }
+    let (seconds, frac) = seconds.split_once('.').unwrap_or((seconds, ""));
+    let seconds: i64 = seconds.parse().ok()?;
+    if !(0..60).contains(&seconds) || frac.len() > 6 || !frac.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let frac: i64 = if frac.is_empty() {
+        0
+    } else {
+        format!("{frac:0<6}").parse().ok()?
+    };
+    let micros = hours
+        .checked_mul(MICROS_PER_HOUR)?
+        .checked_add(minutes * MICROS_PER_MINUTE + seconds * MICROS_PER_SEC + frac)?;
+    Some(if negative { -micros } else { micros })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntervalType;
+
+impl ValueType for IntervalType {
+    type Scalar = Interval;
+    type ScalarRef<'a> = Interval;
+    type Column = Buffer<Interval>;
+    type Domain = ();
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, Interval>>;
+    type ColumnBuilder = Vec<Interval>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: Interval) -> Interval {
+        long
+    }
+
+    fn to_owned_scalar(scalar: Self::ScalarRef<'_>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref(scalar: &Self::Scalar) -> Self::ScalarRef<'_> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::Interval(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        match col {
+            Column::Interval(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
+        if domain.is_undefined() {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn try_downcast_builder(builder: &mut ColumnBuilder) -> Option<&mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_owned_builder(builder: ColumnBuilder) -> Option<Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::Interval(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_upcast_column_builder(
+        builder: Self::ColumnBuilder,
+        _decimal_size: Option<DecimalSize>,
+    ) -> Option<ColumnBuilder> {
+        Some(ColumnBuilder::Interval(builder))
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::Interval(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::Interval(col)
+    }
+
+    fn upcast_domain(_domain: Self::Domain) -> Domain {
+        Domain::Undefined
+    }
+
+    fn column_len(col: &Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column(col: &Self::Column, index: usize) -> Option<Self::ScalarRef<'_>> {
+        col.get(index).cloned()
+    }
+
+    #[inline(always)]
+    unsafe fn index_column_unchecked(col: &Self::Column, index: usize) -> Self::ScalarRef<'_> {
+        debug_assert!(index < col.len());
+
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column(col: &Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column(col: &Self::Column) -> Self::ColumnIterator<'_> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_item_repeat(builder: &mut Self::ColumnBuilder, item: Self::ScalarRef<'_>, n: usize) {
+        builder.resize(builder.len() + n, item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+
+    #[inline(always)]
+    fn compare(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> Ordering {
+        left.cmp(&right)
+    }
+}
+
+impl ArgType for IntervalType {
+    fn data_type() -> DataType {
+        DataType::Interval
+    }
+
+    fn full_domain() -> Self::Domain {}
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
@@ -0,0 +1,399 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::ops::Range;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use chrono::DateTime;
+use chrono::Offset;
+use chrono_tz::Tz;
+use databend_common_arrow::arrow::buffer::Buffer;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::property::Domain;
+use crate::types::timestamp::string_to_timestamp;
+use crate::types::timestamp::MICROS_PER_SEC;
+use crate::types::timestamp::TIMESTAMP_FORMAT;
+use crate::types::ArgType;
+use crate::types::DataType;
+use crate::types::DecimalSize;
+use crate::types::GenericMap;
+use crate::types::ValueType;
+use crate::utils::arrow::buffer_into_mut;
+use crate::utils::date_helper::DateConverter;
+use crate::values::Column;
+use crate::values::Scalar;
+use crate::ColumnBuilder;
+use crate::ScalarRef;
+
+/// Largest UTC offset accepted, in seconds. Real-world offsets range from
+/// `-12:00` to `+14:00`.
+pub const MAX_OFFSET_SECS: i32 = 18 * 3600;
+
+/// A point in time together with the UTC offset it was written in.
+///
+/// The instant is kept in UTC so that values recorded in different regions
+/// compare and sort by the moment they refer to, while the offset lets the
+/// value be rendered back in its original local time.
+///
+/// Equality, ordering and hashing only look at the instant, so the same
+/// moment written with different offsets is one value for `=`, `GROUP BY`,
+/// `DISTINCT` and joins.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, BorshSerialize, BorshDeserialize)]
+pub struct TimestampTz {
+    /// Microseconds since `1970-01-01 00:00:00 UTC`.
+    pub micros: i64,
+    /// Offset from UTC in seconds, positive east of Greenwich.
+    pub offset: i32,
+}
+
+impl TimestampTz {
+    pub const fn new(micros: i64, offset: i32) -> Self {
+        TimestampTz { micros, offset }
+    }
+
+    /// Build a value from a UTC timestamp, taking the offset that `tz` has at
+    /// that instant.
+    pub fn from_timestamp(micros: i64, tz: Tz) -> Self {
+        let offset = micros.to_timestamp(tz).offset().fix().local_minus_utc();
+        TimestampTz { micros, offset }
+    }
+
+    /// Microseconds since `1970-01-01 00:00:00` in the local time of the
+    /// stored offset.
+    pub fn local_micros(&self) -> i64 {
+        self.micros
+            .saturating_add(self.offset as i64 * MICROS_PER_SEC)
+    }
+
+    /// Same instant, expressed with another offset.
+    pub fn with_offset(&self, offset: i32) -> Self {
+        TimestampTz {
+            micros: self.micros,
+            offset,
+        }
+    }
+
+    /// Pack the value into a single `i128`, used by the arrow and parquet
+    /// representation of timestamp_tz columns.
+    pub fn to_i128(&self) -> i128 {
+        ((self.micros as i128) << 32) | ((self.offset as u32) as i128)
+    }
+
+    pub fn from_i128(value: i128) -> Self {
+        TimestampTz {
+            micros: (value >> 32) as i64,
+            offset: value as i32,
+        }
+    }
+}
+
+impl PartialEq for TimestampTz {
+    fn eq(&self, other: &Self) -> bool {
+        self.micros == other.micros
+    }
+}
+
+impl Eq for TimestampTz {}
+
+impl Hash for TimestampTz {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.micros.hash(state)
+    }
+}
+
+impl PartialOrd for TimestampTz {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TimestampTz {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.micros.cmp(&other.micros)
+    }
+}
+
+impl Display for TimestampTz {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let local = self.local_micros().to_timestamp(Tz::UTC);
+        let sign = if self.offset < 0 { '-' } else { '+' };
+        let offset = self.offset.unsigned_abs();
+        write!(
+            f,
+            "{} {sign}{:02}:{:02}",
+            local.format(TIMESTAMP_FORMAT),
+            offset / 3600,
+            offset / 60 % 60
+        )
+    }
+}
+
+/// Parse a timestamp with an optional ISO-8601 UTC offset.
+///
+/// The offset may be written as `Z`, `+HH`, `+HHMM` or `+HH:MM`, directly
+/// after the time or separated by a space, for example
+/// `2024-01-01T10:00:00+08:00` or `2024-01-01 10:00:00.5 -0530`. A value
+/// without an offset is taken as local time in `tz`.
+pub fn string_to_timestamp_tz(
+    s: impl AsRef<[u8]>,
+    tz: Tz,
+    enable_dst_hour_fix: bool,
+) -> Result<TimestampTz> {
+    let s = std::str::from_utf8(s.as_ref())
+        .map_err(|_| ErrorCode::BadBytes("invalid utf-8 in timestamp_tz"))?
+        .trim();
+    let bad = || ErrorCode::BadArguments(format!("invalid timestamp_tz '{s}'"));
+
+    match split_offset(s) {
+        Some((local, offset)) => {
+            let offset = parse_offset(offset).ok_or_else(bad)?;
+            let local = string_to_timestamp(local.trim_end(), Tz::UTC, false)?;
+            let micros = local
+                .timestamp_micros()
+                .checked_sub(offset as i64 * MICROS_PER_SEC)
+                .ok_or_else(bad)?;
+            Ok(TimestampTz { micros, offset })
+        }
+        None => {
+            let dt = string_to_timestamp(s, tz, enable_dst_hour_fix)?;
+            Ok(datetime_to_timestamp_tz(&dt))
+        }
+    }
+}
+
+pub fn datetime_to_timestamp_tz(dt: &DateTime<Tz>) -> TimestampTz {
+    TimestampTz {
+        micros: dt.timestamp_micros(),
+        offset: dt.offset().fix().local_minus_utc(),
+    }
+}
+
+/// Split a trailing UTC offset from the date and time part. The date part is
+/// always `YYYY-MM-DD`, so a sign can only start an offset after it.
+fn split_offset(s: &str) -> Option<(&str, &str)> {
+    if s.len() <= 10 {
+        return None;
+    }
+    if let Some(local) = s.strip_suffix(['Z', 'z']) {
+        return Some((local, "Z"));
+    }
+    let pos = s[10..].rfind(['+', '-'])? + 10;
+    Some((&s[..pos], &s[pos..]))
+}
+
+/// Parse `Z`, `+HH`, `+HHMM` or `+HH:MM` into seconds east of UTC.
+fn parse_offset(s: &str) -> Option<i32> {
+    if s == "Z" {
+        return Some(0);
+    }
+    let (sign, rest) = match s.as_bytes().first()? {
+        b'+' => (1, &s[1..]),
+        b'-' => (-1, &s[1..]),
+        _ => return None,
+    };
+    if !rest.bytes().all(|b| b.is_ascii_digit() || b == b':') {
+        return None;
+    }
+    let (hours, minutes) = match (rest.len(), rest.split_once(':')) {
+        (5, Some((h, m))) if h.len() == 2 => (h, m),
+        (4, None) => rest.split_at(2),
+        (2, None) => (rest, "00"),
+        _ => return None,
+    };
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = minutes.parse().ok()?;
+    let offset = hours * 3600 + minutes * 60;
+    if minutes >= 60 || offset > MAX_OFFSET_SECS {
+        return None;
+    }
+    Some(sign * offset)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimestampTzType;
+
+impl ValueType for TimestampTzType {
+    type Scalar = TimestampTz;
+    type ScalarRef<'a> = TimestampTz;
+    type Column = Buffer<TimestampTz>;
+    type Domain = ();
+    type ColumnIterator<'a> = std::iter::Cloned<std::slice::Iter<'a, TimestampTz>>;
+    type ColumnBuilder = Vec<TimestampTz>;
+
+    #[inline]
+    fn upcast_gat<'short, 'long: 'short>(long: TimestampTz) -> TimestampTz {
+        long
+    }
+
+    fn to_owned_scalar(scalar: Self::ScalarRef<'_>) -> Self::Scalar {
+        scalar
+    }
+
+    fn to_scalar_ref(scalar: &Self::Scalar) -> Self::ScalarRef<'_> {
+        *scalar
+    }
+
+    fn try_downcast_scalar<'a>(scalar: &'a ScalarRef) -> Option<Self::ScalarRef<'a>> {
+        match scalar {
+            ScalarRef::TimestampTz(scalar) => Some(*scalar),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_column(col: &Column) -> Option<Self::Column> {
+        match col {
+            Column::TimestampTz(column) => Some(column.clone()),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
+        if domain.is_undefined() {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    fn try_downcast_builder(builder: &mut ColumnBuilder) -> Option<&mut Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::TimestampTz(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_downcast_owned_builder(builder: ColumnBuilder) -> Option<Self::ColumnBuilder> {
+        match builder {
+            ColumnBuilder::TimestampTz(builder) => Some(builder),
+            _ => None,
+        }
+    }
+
+    fn try_upcast_column_builder(
+        builder: Self::ColumnBuilder,
+        _decimal_size: Option<DecimalSize>,
+    ) -> Option<ColumnBuilder> {
+        Some(ColumnBuilder::TimestampTz(builder))
+    }
+
+    fn upcast_scalar(scalar: Self::Scalar) -> Scalar {
+        Scalar::TimestampTz(scalar)
+    }
+
+    fn upcast_column(col: Self::Column) -> Column {
+        Column::TimestampTz(col)
+    }
+
+    fn upcast_domain(_domain: Self::Domain) -> Domain {
+        Domain::Undefined
+    }
+
+    fn column_len(col: &Self::Column) -> usize {
+        col.len()
+    }
+
+    fn index_column(col: &Self::Column, index: usize) -> Option<Self::ScalarRef<'_>> {
+        col.get(index).cloned()
+    }
+
+    #[inline(always)]
+    unsafe fn index_column_unchecked(col: &Self::Column, index: usize) -> Self::ScalarRef<'_> {
+        debug_assert!(index < col.len());
+
+        *col.get_unchecked(index)
+    }
+
+    fn slice_column(col: &Self::Column, range: Range<usize>) -> Self::Column {
+        col.clone().sliced(range.start, range.end - range.start)
+    }
+
+    fn iter_column(col: &Self::Column) -> Self::ColumnIterator<'_> {
+        col.iter().cloned()
+    }
+
+    fn column_to_builder(col: Self::Column) -> Self::ColumnBuilder {
+        buffer_into_mut(col)
+    }
+
+    fn builder_len(builder: &Self::ColumnBuilder) -> usize {
+        builder.len()
+    }
+
+    fn push_item(builder: &mut Self::ColumnBuilder, item: Self::Scalar) {
+        builder.push(item);
+    }
+
+    fn push_item_repeat(builder: &mut Self::ColumnBuilder, item: Self::ScalarRef<'_>, n: usize) {
+        builder.resize(builder.len() + n, item);
+    }
+
+    fn push_default(builder: &mut Self::ColumnBuilder) {
+        builder.push(Self::Scalar::default());
+    }
+
+    fn append_column(builder: &mut Self::ColumnBuilder, other: &Self::Column) {
+        builder.extend_from_slice(other);
+    }
+
+    fn build_column(builder: Self::ColumnBuilder) -> Self::Column {
+        builder.into()
+    }
+
+    fn build_scalar(builder: Self::ColumnBuilder) -> Self::Scalar {
+        assert_eq!(builder.len(), 1);
+        builder[0]
+    }
+
+    #[inline(always)]
+    fn compare(left: Self::ScalarRef<'_>, right: Self::ScalarRef<'_>) -> Ordering {
+        left.cmp(&right)
+    }
+}
+
+impl ArgType for TimestampTzType {
+    fn data_type() -> DataType {
+        DataType::TimestampTz
+    }
+
+    fn full_domain() -> Self::Domain {}
+
+    fn create_builder(capacity: usize, _generics: &GenericMap) -> Self::ColumnBuilder {
+        Vec::with_capacity(capacity)
+    }
+
+    fn column_from_vec(vec: Vec<Self::Scalar>, _generics: &GenericMap) -> Self::Column {
+        vec.into()
+    }
+
+    fn column_from_iter(iter: impl Iterator<Item = Self::Scalar>, _: &GenericMap) -> Self::Column {
+        iter.collect()
+    }
+
+    fn column_from_ref_iter<'a>(
+        iter: impl Iterator<Item = Self::ScalarRef<'a>>,
+        _: &GenericMap,
+    ) -> Self::Column {
+        iter.collect()
+    }
+}
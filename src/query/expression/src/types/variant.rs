@@ -228,10 +228,17 @@ pub fn cast_scalar_to_variant(scalar: ScalarRef, tz: TzLUT, buf: &mut Vec<u8>) {
         },
         ScalarRef::Decimal(x) => x.to_float64().into(),
         ScalarRef::Boolean(b) => jsonb::Value::Bool(b),
-        ScalarRef::Binary(s) => jsonb::Value::String(hex::encode_upper(s).into()),
+        ScalarRef::Binary(s) | ScalarRef::AggregateState(s, _) => {
+            jsonb::Value::String(hex::encode_upper(s).into())
+        }
         ScalarRef::String(s) => jsonb::Value::String(s.into()),
         ScalarRef::Timestamp(ts) => timestamp_to_string(ts, inner_tz).to_string().into(),
         ScalarRef::Date(d) => date_to_string(d, inner_tz).to_string().into(),
+        ScalarRef::Interval(i) => i.to_string().into(),
+        ScalarRef::TimestampTz(i) => i.to_string().into(),
+        ScalarRef::Time(t) => time_to_string(t).to_string().into(),
+        ScalarRef::Uuid(u) => uuid_to_string(u).to_string().into(),
+        ScalarRef::Enum(code, items) => jsonb::Value::String(items.item(code).into()),
         ScalarRef::Array(col) => {
             let items = cast_scalars_to_variants(col.iter(), tz);
             jsonb::build_array(items.iter(), buf).expect("failed to build jsonb array");
@@ -180,6 +180,17 @@ impl<'a> Debug for ScalarRef<'a> {
                 let ewkt = v.to_ewkt().unwrap_or_else(|e| format!("Invalid data: {e}"));
                 write!(f, "{ewkt:?}")
             }
+            ScalarRef::Interval(i) => write!(f, "{:?}", i.to_string()),
+            ScalarRef::TimestampTz(i) => write!(f, "{:?}", i.to_string()),
+            ScalarRef::Time(t) => write!(f, "{:?}", time_to_string(*t).to_string()),
+            ScalarRef::Uuid(u) => write!(f, "{:?}", uuid_to_string(*u).to_string()),
+            ScalarRef::Enum(code, items) => write!(f, "{:?}", items.item(*code)),
+            ScalarRef::AggregateState(s, _) => {
+                for c in *s {
+                    write!(f, "{:02X}", c)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -205,6 +216,12 @@ impl Debug for Column {
             Column::Variant(col) => write!(f, "{col:?}"),
             Column::Geometry(col) => write!(f, "{col:?}"),
             Column::Geography(col) => write!(f, "{col:?}"),
+            Column::Interval(col) => write!(f, "{col:?}"),
+            Column::TimestampTz(col) => write!(f, "{col:?}"),
+            Column::Time(col) => write!(f, "{col:?}"),
+            Column::Uuid(col) => write!(f, "{col:?}"),
+            Column::Enum(col) => write!(f, "{col:?}"),
+            Column::AggregateState(col) => write!(f, "{col:?}"),
         }
     }
 }
@@ -278,6 +295,17 @@ impl<'a> Display for ScalarRef<'a> {
                     .unwrap_or_else(|x| format!("GeozeroError: {:?}", x));
                 write!(f, "'{geom}'")
             }
+            ScalarRef::Interval(i) => write!(f, "'{i}'"),
+            ScalarRef::TimestampTz(i) => write!(f, "'{i}'"),
+            ScalarRef::Time(t) => write!(f, "'{}'", time_to_string(*t)),
+            ScalarRef::Uuid(u) => write!(f, "'{}'", uuid_to_string(*u)),
+            ScalarRef::Enum(code, items) => write!(f, "'{}'", items.item(*code)),
+            ScalarRef::AggregateState(s, _) => {
+                for c in *s {
+                    write!(f, "{c:02X}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -540,6 +568,12 @@ impl Display for DataType {
             DataType::Variant => write!(f, "Variant"),
             DataType::Geometry => write!(f, "Geometry"),
             DataType::Geography => write!(f, "Geography"),
+            DataType::Interval => write!(f, "Interval"),
+            DataType::TimestampTz => write!(f, "TimestampTz"),
+            DataType::Time => write!(f, "Time"),
+            DataType::Uuid => write!(f, "Uuid"),
+            DataType::Enum(items) => write!(f, "Enum({items})"),
+            DataType::AggregateState(signature) => write!(f, "AggregateState({signature})"),
             DataType::Generic(index) => write!(f, "T{index}"),
         }
     }
@@ -589,6 +623,12 @@ impl Display for TableDataType {
             TableDataType::Variant => write!(f, "Variant"),
             TableDataType::Geometry => write!(f, "Geometry"),
             TableDataType::Geography => write!(f, "Geography"),
+            TableDataType::Interval => write!(f, "Interval"),
+            TableDataType::TimestampTz => write!(f, "TimestampTz"),
+            TableDataType::Time => write!(f, "Time"),
+            TableDataType::Uuid => write!(f, "Uuid"),
+            TableDataType::Enum(items) => write!(f, "Enum({items})"),
+            TableDataType::AggregateState(signature) => write!(f, "AggregateState({signature})"),
         }
     }
 }
@@ -1053,6 +1093,9 @@ impl Display for Domain {
             Domain::String(domain) => write!(f, "{domain}"),
             Domain::Timestamp(domain) => write!(f, "{domain}"),
             Domain::Date(domain) => write!(f, "{domain}"),
+            Domain::Time(domain) => write!(f, "{domain}"),
+            Domain::Uuid(domain) => write!(f, "{domain}"),
+            Domain::Enum(domain, _) => write!(f, "{domain}"),
             Domain::Nullable(domain) => write!(f, "{domain}"),
             Domain::Array(None) => write!(f, "[]"),
             Domain::Array(Some(domain)) => write!(f, "[{domain}]"),
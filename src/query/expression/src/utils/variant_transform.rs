@@ -41,6 +41,12 @@ pub fn contains_variant(data_type: &DataType) -> bool {
         | DataType::Bitmap
         | DataType::Geometry
         | DataType::Geography
+        | DataType::Interval
+        | DataType::TimestampTz
+        | DataType::Time
+        | DataType::Uuid
+        | DataType::Enum(_)
+        | DataType::AggregateState(_)
         | DataType::Generic(_) => false,
         DataType::Nullable(ty) => contains_variant(ty.as_ref()),
         DataType::Array(ty) => contains_variant(ty.as_ref()),
@@ -82,7 +88,13 @@ fn transform_scalar(scalar: ScalarRef<'_>, decode: bool) -> Result<Scalar> {
         | ScalarRef::String(_)
         | ScalarRef::Bitmap(_)
         | ScalarRef::Geometry(_)
-        | ScalarRef::Geography(_) => scalar.to_owned(),
+        | ScalarRef::Geography(_)
+        | ScalarRef::Interval(_)
+        | ScalarRef::TimestampTz(_)
+        | ScalarRef::Time(_)
+        | ScalarRef::Uuid(_)
+        | ScalarRef::Enum(_, _)
+        | ScalarRef::AggregateState(_, _) => scalar.to_owned(),
         ScalarRef::Array(col) => Scalar::Array(transform_column(&col, decode)?),
         ScalarRef::Map(col) => Scalar::Map(transform_column(&col, decode)?),
         ScalarRef::Tuple(scalars) => {
@@ -79,6 +79,22 @@ pub trait ValueVisitor {
         self.visit_typed_column::<DateType>(buffer)
     }
 
+    fn visit_time(&mut self, buffer: Buffer<i64>) -> Result<()> {
+        self.visit_typed_column::<TimeType>(buffer)
+    }
+
+    fn visit_uuid(&mut self, buffer: Buffer<u128>) -> Result<()> {
+        self.visit_typed_column::<UuidType>(buffer)
+    }
+
+    fn visit_enum(&mut self, column: EnumColumn) -> Result<()> {
+        self.visit_typed_column::<AnyType>(Column::Enum(column))
+    }
+
+    fn visit_aggregate_state(&mut self, column: AggregateStateColumn) -> Result<()> {
+        self.visit_typed_column::<AnyType>(Column::AggregateState(column))
+    }
+
     fn visit_array(&mut self, column: Box<ArrayColumn<AnyType>>) -> Result<()> {
         self.visit_typed_column::<AnyType>(Column::Array(column))
     }
@@ -111,6 +127,14 @@ pub trait ValueVisitor {
         self.visit_typed_column::<GeographyType>(column)
     }
 
+    fn visit_interval(&mut self, buffer: Buffer<Interval>) -> Result<()> {
+        self.visit_typed_column::<IntervalType>(buffer)
+    }
+
+    fn visit_timestamp_tz(&mut self, buffer: Buffer<TimestampTz>) -> Result<()> {
+        self.visit_typed_column::<TimestampTzType>(buffer)
+    }
+
     fn visit_typed_column<T: ValueType>(&mut self, column: <T as ValueType>::Column) -> Result<()>;
 
     fn visit_value(&mut self, value: Value<AnyType>) -> Result<()> {
@@ -140,6 +164,12 @@ pub trait ValueVisitor {
             Column::Variant(column) => self.visit_variant(column),
             Column::Geometry(column) => self.visit_geometry(column),
             Column::Geography(column) => self.visit_geography(column),
+            Column::Interval(buffer) => self.visit_interval(buffer),
+            Column::TimestampTz(buffer) => self.visit_timestamp_tz(buffer),
+            Column::Time(buffer) => self.visit_time(buffer),
+            Column::Uuid(buffer) => self.visit_uuid(buffer),
+            Column::Enum(column) => self.visit_enum(column),
+            Column::AggregateState(column) => self.visit_aggregate_state(column),
         }
     }
 }
@@ -66,6 +66,9 @@ use crate::types::geography::GeographyColumn;
 use crate::types::geography::GeographyRef;
 use crate::types::geometry::compare_geometry;
 use crate::types::geometry::GeometryType;
+use crate::types::interval::Interval;
+use crate::types::interval::IntervalType;
+use crate::types::interval::MICROS_PER_DAY;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableColumnBuilder;
 use crate::types::nullable::NullableColumnVec;
@@ -78,9 +81,15 @@ use crate::types::number::F32;
 use crate::types::number::F64;
 use crate::types::string::StringColumn;
 use crate::types::string::StringDomain;
+use crate::types::time::TimeType;
+use crate::types::time::TIME_MAX;
+use crate::types::time::TIME_MIN;
 use crate::types::timestamp::clamp_timestamp;
 use crate::types::timestamp::TIMESTAMP_MAX;
 use crate::types::timestamp::TIMESTAMP_MIN;
+use crate::types::timestamp_tz::TimestampTz;
+use crate::types::timestamp_tz::TimestampTzType;
+use crate::types::uuid::UuidType;
 use crate::types::variant::JSONB_NULL;
 use crate::types::*;
 use crate::utils::arrow::append_bitmap;
@@ -129,6 +138,12 @@ pub enum Scalar {
     Variant(Vec<u8>),
     Geometry(Vec<u8>),
     Geography(Geography),
+    Interval(Interval),
+    TimestampTz(TimestampTz),
+    Time(i64),
+    Uuid(u128),
+    Enum(u16, EnumItems),
+    AggregateState(Vec<u8>, AggregateStateSignature),
 }
 
 #[derive(Clone, Default, Eq, EnumAsInner)]
@@ -151,6 +166,12 @@ pub enum ScalarRef<'a> {
     Variant(&'a [u8]),
     Geometry(&'a [u8]),
     Geography(GeographyRef<'a>),
+    Interval(Interval),
+    TimestampTz(TimestampTz),
+    Time(i64),
+    Uuid(u128),
+    Enum(u16, &'a EnumItems),
+    AggregateState(&'a [u8], &'a AggregateStateSignature),
 }
 
 #[derive(Clone, EnumAsInner)]
@@ -173,6 +194,12 @@ pub enum Column {
     Variant(BinaryColumn),
     Geometry(BinaryColumn),
     Geography(GeographyColumn),
+    Interval(Buffer<Interval>),
+    TimestampTz(Buffer<TimestampTz>),
+    Time(Buffer<i64>),
+    Uuid(Buffer<u128>),
+    Enum(EnumColumn),
+    AggregateState(AggregateStateColumn),
 }
 
 #[derive(Clone, EnumAsInner, Debug, PartialEq)]
@@ -195,6 +222,12 @@ pub enum ColumnVec {
     Variant(Vec<BinaryColumn>),
     Geometry(Vec<BinaryColumn>),
     Geography(Vec<GeographyColumn>),
+    Interval(Vec<Buffer<Interval>>),
+    TimestampTz(Vec<Buffer<TimestampTz>>),
+    Time(Vec<Buffer<i64>>),
+    Uuid(Vec<Buffer<u128>>),
+    Enum(Vec<Buffer<u16>>, EnumItems),
+    AggregateState(Vec<BinaryColumn>, AggregateStateSignature),
 }
 
 #[derive(Debug, Clone, EnumAsInner)]
@@ -217,6 +250,12 @@ pub enum ColumnBuilder {
     Variant(BinaryColumnBuilder),
     Geometry(BinaryColumnBuilder),
     Geography(BinaryColumnBuilder),
+    Interval(Vec<Interval>),
+    TimestampTz(Vec<TimestampTz>),
+    Time(Vec<i64>),
+    Uuid(Vec<u128>),
+    Enum(EnumColumnBuilder),
+    AggregateState(AggregateStateColumnBuilder),
 }
 
 impl<'a, T: ValueType> ValueRef<'a, T> {
@@ -373,6 +412,12 @@ impl Scalar {
             Scalar::Variant(s) => ScalarRef::Variant(s.as_slice()),
             Scalar::Geometry(s) => ScalarRef::Geometry(s.as_slice()),
             Scalar::Geography(g) => ScalarRef::Geography(g.as_ref()),
+            Scalar::Interval(i) => ScalarRef::Interval(*i),
+            Scalar::TimestampTz(i) => ScalarRef::TimestampTz(*i),
+            Scalar::Time(i) => ScalarRef::Time(*i),
+            Scalar::Uuid(i) => ScalarRef::Uuid(*i),
+            Scalar::Enum(i, items) => ScalarRef::Enum(*i, items),
+            Scalar::AggregateState(s, signature) => ScalarRef::AggregateState(s, signature),
         }
     }
 
@@ -415,6 +460,14 @@ impl Scalar {
             DataType::Variant => Scalar::Variant(vec![]),
             DataType::Geometry => Scalar::Geometry(vec![]),
             DataType::Geography => Scalar::Geography(Geography::default()),
+            DataType::Interval => Scalar::Interval(Interval::default()),
+            DataType::TimestampTz => Scalar::TimestampTz(TimestampTz::default()),
+            DataType::Time => Scalar::Time(0),
+            DataType::Uuid => Scalar::Uuid(0),
+            DataType::Enum(items) => Scalar::Enum(0, items.clone()),
+            DataType::AggregateState(signature) => {
+                Scalar::AggregateState(vec![], signature.clone())
+            }
 
             _ => unimplemented!(),
         }
@@ -435,7 +488,13 @@ impl Scalar {
             | Scalar::Bitmap(_)
             | Scalar::Variant(_)
             | Scalar::Geometry(_)
-            | Scalar::Geography(_) => false,
+            | Scalar::Geography(_)
+            | Scalar::Interval(_)
+            | Scalar::TimestampTz(_)
+            | Scalar::Time(_)
+            | Scalar::Uuid(_)
+            | Scalar::Enum(_, _)
+            | Scalar::AggregateState(_, _) => false,
             Scalar::Array(_) | Scalar::Map(_) | Scalar::Tuple(_) => true,
         }
     }
@@ -490,6 +549,14 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Variant(s) => Scalar::Variant(s.to_vec()),
             ScalarRef::Geometry(s) => Scalar::Geometry(s.to_vec()),
             ScalarRef::Geography(s) => Scalar::Geography(s.to_owned()),
+            ScalarRef::Interval(i) => Scalar::Interval(*i),
+            ScalarRef::TimestampTz(i) => Scalar::TimestampTz(*i),
+            ScalarRef::Time(i) => Scalar::Time(*i),
+            ScalarRef::Uuid(i) => Scalar::Uuid(*i),
+            ScalarRef::Enum(i, items) => Scalar::Enum(*i, (*items).clone()),
+            ScalarRef::AggregateState(s, signature) => {
+                Scalar::AggregateState(s.to_vec(), (*signature).clone())
+            }
         }
     }
 
@@ -527,6 +594,11 @@ impl<'a> ScalarRef<'a> {
             }),
             ScalarRef::Timestamp(t) => Domain::Timestamp(SimpleDomain { min: *t, max: *t }),
             ScalarRef::Date(d) => Domain::Date(SimpleDomain { min: *d, max: *d }),
+            ScalarRef::Time(t) => Domain::Time(SimpleDomain { min: *t, max: *t }),
+            ScalarRef::Uuid(u) => Domain::Uuid(SimpleDomain { min: *u, max: *u }),
+            ScalarRef::Enum(i, items) => {
+                Domain::Enum(SimpleDomain { min: *i, max: *i }, (*items).clone())
+            }
             ScalarRef::Array(array) => {
                 if array.len() == 0 {
                     Domain::Array(None)
@@ -555,7 +627,10 @@ impl<'a> ScalarRef<'a> {
             | ScalarRef::Bitmap(_)
             | ScalarRef::Variant(_)
             | ScalarRef::Geometry(_)
-            | ScalarRef::Geography(_) => Domain::Undefined,
+            | ScalarRef::Geography(_)
+            | ScalarRef::Interval(_)
+            | ScalarRef::TimestampTz(_)
+            | ScalarRef::AggregateState(_, _) => Domain::Undefined,
         }
     }
 
@@ -586,6 +661,12 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Variant(buf) => buf.len(),
             ScalarRef::Geometry(buf) => buf.len(),
             ScalarRef::Geography(s) => s.0.len(),
+            ScalarRef::Interval(_) => 16,
+            ScalarRef::TimestampTz(_) => 16,
+            ScalarRef::Time(_) => 8,
+            ScalarRef::Uuid(_) => 16,
+            ScalarRef::Enum(_, _) => 2,
+            ScalarRef::AggregateState(s, _) => s.len(),
         }
     }
 
@@ -620,6 +701,14 @@ impl<'a> ScalarRef<'a> {
             ScalarRef::Variant(_) => DataType::Variant,
             ScalarRef::Geometry(_) => DataType::Geometry,
             ScalarRef::Geography(_) => DataType::Geography,
+            ScalarRef::Interval(_) => DataType::Interval,
+            ScalarRef::TimestampTz(_) => DataType::TimestampTz,
+            ScalarRef::Time(_) => DataType::Time,
+            ScalarRef::Uuid(_) => DataType::Uuid,
+            ScalarRef::Enum(_, items) => DataType::Enum((*items).clone()),
+            ScalarRef::AggregateState(_, signature) => {
+                DataType::AggregateState((*signature).clone())
+            }
         }
     }
 
@@ -686,6 +775,17 @@ impl<'a> ScalarRef<'a> {
             (ScalarRef::Variant(_), ScalarRef::Variant(_)) => Some(DataType::Variant),
             (ScalarRef::Geometry(_), ScalarRef::Geometry(_)) => Some(DataType::Geometry),
             (ScalarRef::Geography(_), ScalarRef::Geography(_)) => Some(DataType::Geography),
+            (ScalarRef::Interval(_), ScalarRef::Interval(_)) => Some(DataType::Interval),
+            (ScalarRef::TimestampTz(_), ScalarRef::TimestampTz(_)) => Some(DataType::TimestampTz),
+            (ScalarRef::Time(_), ScalarRef::Time(_)) => Some(DataType::Time),
+            (ScalarRef::Uuid(_), ScalarRef::Uuid(_)) => Some(DataType::Uuid),
+            (ScalarRef::Enum(_, items1), ScalarRef::Enum(_, items2)) if items1 == items2 => {
+                Some(DataType::Enum((*items1).clone()))
+            }
+            (
+                ScalarRef::AggregateState(_, signature1),
+                ScalarRef::AggregateState(_, signature2),
+            ) if signature1 == signature2 => Some(DataType::AggregateState((*signature1).clone())),
             _ => None,
         }
     }
@@ -709,6 +809,14 @@ impl<'a> ScalarRef<'a> {
                 (ScalarRef::Variant(_), DataType::Variant) => true,
                 (ScalarRef::Geometry(_), DataType::Geometry) => true,
                 (ScalarRef::Geography(_), DataType::Geography) => true,
+                (ScalarRef::Interval(_), DataType::Interval) => true,
+                (ScalarRef::TimestampTz(_), DataType::TimestampTz) => true,
+                (ScalarRef::Time(_), DataType::Time) => true,
+                (ScalarRef::Uuid(_), DataType::Uuid) => true,
+                (ScalarRef::Enum(_, items), DataType::Enum(ty)) => *items == &ty,
+                (ScalarRef::AggregateState(_, signature), DataType::AggregateState(ty)) => {
+                    *signature == &ty
+                }
                 (ScalarRef::Array(val), DataType::Array(ty)) => val.data_type() == *ty,
                 (ScalarRef::Map(val), DataType::Map(ty)) => val.data_type() == *ty,
                 (ScalarRef::Tuple(val), DataType::Tuple(ty)) => {
@@ -747,6 +855,18 @@ impl PartialOrd for Scalar {
             }
             (Scalar::Geometry(g1), Scalar::Geometry(g2)) => compare_geometry(g1, g2),
             (Scalar::Geography(g1), Scalar::Geography(g2)) => g1.partial_cmp(g2),
+            (Scalar::Interval(i1), Scalar::Interval(i2)) => i1.partial_cmp(i2),
+            (Scalar::TimestampTz(i1), Scalar::TimestampTz(i2)) => i1.partial_cmp(i2),
+            (Scalar::Time(i1), Scalar::Time(i2)) => i1.partial_cmp(i2),
+            (Scalar::Uuid(i1), Scalar::Uuid(i2)) => i1.partial_cmp(i2),
+            (Scalar::Enum(i1, items1), Scalar::Enum(i2, items2)) if items1 == items2 => {
+                i1.partial_cmp(i2)
+            }
+            (Scalar::AggregateState(s1, signature1), Scalar::AggregateState(s2, signature2))
+                if signature1 == signature2 =>
+            {
+                s1.partial_cmp(s2)
+            }
             _ => None,
         }
     }
@@ -784,6 +904,17 @@ impl<'a, 'b> PartialOrd<ScalarRef<'b>> for ScalarRef<'a> {
             (ScalarRef::Variant(v1), ScalarRef::Variant(v2)) => jsonb::compare(v1, v2).ok(),
             (ScalarRef::Geometry(g1), ScalarRef::Geometry(g2)) => compare_geometry(g1, g2),
             (ScalarRef::Geography(g1), ScalarRef::Geography(g2)) => g1.partial_cmp(g2),
+            (ScalarRef::Interval(i1), ScalarRef::Interval(i2)) => i1.partial_cmp(i2),
+            (ScalarRef::TimestampTz(i1), ScalarRef::TimestampTz(i2)) => i1.partial_cmp(i2),
+            (ScalarRef::Time(i1), ScalarRef::Time(i2)) => i1.partial_cmp(i2),
+            (ScalarRef::Uuid(i1), ScalarRef::Uuid(i2)) => i1.partial_cmp(i2),
+            (ScalarRef::Enum(i1, items1), ScalarRef::Enum(i2, items2)) if items1 == items2 => {
+                i1.partial_cmp(i2)
+            }
+            (
+                ScalarRef::AggregateState(s1, signature1),
+                ScalarRef::AggregateState(s2, signature2),
+            ) if signature1 == signature2 => s1.partial_cmp(s2),
 
             // By default, null is biggest in pgsql
             (ScalarRef::Null, _) => Some(Ordering::Greater),
@@ -839,6 +970,12 @@ impl Hash for ScalarRef<'_> {
             ScalarRef::Variant(v) => v.hash(state),
             ScalarRef::Geometry(v) => v.hash(state),
             ScalarRef::Geography(v) => v.hash(state),
+            ScalarRef::Interval(v) => v.hash(state),
+            ScalarRef::TimestampTz(v) => v.hash(state),
+            ScalarRef::Time(v) => v.hash(state),
+            ScalarRef::Uuid(v) => v.hash(state),
+            ScalarRef::Enum(v, _) => v.hash(state),
+            ScalarRef::AggregateState(v, _) => v.hash(state),
         }
     }
 }
@@ -868,6 +1005,16 @@ impl PartialOrd for Column {
                 col1.iter().partial_cmp(col2.iter())
             }
             (Column::Date(col1), Column::Date(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::Time(col1), Column::Time(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::Uuid(col1), Column::Uuid(col2)) => col1.iter().partial_cmp(col2.iter()),
+            (Column::Enum(col1), Column::Enum(col2)) if col1.items == col2.items => {
+                col1.codes.iter().partial_cmp(col2.codes.iter())
+            }
+            (Column::AggregateState(col1), Column::AggregateState(col2))
+                if col1.signature == col2.signature =>
+            {
+                col1.iter().partial_cmp(col2.iter())
+            }
             (Column::Array(col1), Column::Array(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Map(col1), Column::Map(col2)) => col1.iter().partial_cmp(col2.iter()),
             (Column::Bitmap(col1), Column::Bitmap(col2)) => col1.iter().partial_cmp(col2.iter()),
@@ -884,6 +1031,12 @@ impl PartialOrd for Column {
             (Column::Geography(col1), Column::Geography(col2)) => {
                 col1.iter().partial_cmp(col2.iter())
             }
+            (Column::Interval(col1), Column::Interval(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
+            (Column::TimestampTz(col1), Column::TimestampTz(col2)) => {
+                col1.iter().partial_cmp(col2.iter())
+            }
             (a, b) => {
                 if a.len() != b.len() {
                     a.len().partial_cmp(&b.len())
@@ -934,6 +1087,12 @@ impl Column {
             Column::Variant(col) => col.len(),
             Column::Geometry(col) => col.len(),
             Column::Geography(col) => col.len(),
+            Column::Interval(col) => col.len(),
+            Column::TimestampTz(col) => col.len(),
+            Column::Time(col) => col.len(),
+            Column::Uuid(col) => col.len(),
+            Column::Enum(col) => col.len(),
+            Column::AggregateState(col) => col.len(),
         }
     }
 
@@ -962,6 +1121,14 @@ impl Column {
             Column::Variant(col) => Some(ScalarRef::Variant(col.index(index)?)),
             Column::Geometry(col) => Some(ScalarRef::Geometry(col.index(index)?)),
             Column::Geography(col) => Some(ScalarRef::Geography(col.index(index)?)),
+            Column::Interval(col) => Some(ScalarRef::Interval(col.get(index).cloned()?)),
+            Column::TimestampTz(col) => Some(ScalarRef::TimestampTz(col.get(index).cloned()?)),
+            Column::Time(col) => Some(ScalarRef::Time(col.get(index).cloned()?)),
+            Column::Uuid(col) => Some(ScalarRef::Uuid(col.get(index).cloned()?)),
+            Column::Enum(col) => Some(ScalarRef::Enum(col.index(index)?, &col.items)),
+            Column::AggregateState(col) => {
+                Some(ScalarRef::AggregateState(col.index(index)?, &col.signature))
+            }
         }
     }
 
@@ -993,6 +1160,14 @@ impl Column {
             Column::Variant(col) => ScalarRef::Variant(col.index_unchecked(index)),
             Column::Geometry(col) => ScalarRef::Geometry(col.index_unchecked(index)),
             Column::Geography(col) => ScalarRef::Geography(col.index_unchecked(index)),
+            Column::Interval(col) => ScalarRef::Interval(*col.get_unchecked(index)),
+            Column::TimestampTz(col) => ScalarRef::TimestampTz(*col.get_unchecked(index)),
+            Column::Time(col) => ScalarRef::Time(*col.get_unchecked(index)),
+            Column::Uuid(col) => ScalarRef::Uuid(*col.get_unchecked(index)),
+            Column::Enum(col) => ScalarRef::Enum(col.index_unchecked(index), &col.items),
+            Column::AggregateState(col) => {
+                ScalarRef::AggregateState(col.index_unchecked(index), &col.signature)
+            }
         }
     }
 
@@ -1032,6 +1207,14 @@ impl Column {
             Column::Date(col) => {
                 Column::Date(col.clone().sliced(range.start, range.end - range.start))
             }
+            Column::Time(col) => {
+                Column::Time(col.clone().sliced(range.start, range.end - range.start))
+            }
+            Column::Uuid(col) => {
+                Column::Uuid(col.clone().sliced(range.start, range.end - range.start))
+            }
+            Column::Enum(col) => Column::Enum(col.slice(range)),
+            Column::AggregateState(col) => Column::AggregateState(col.slice(range)),
             Column::Array(col) => Column::Array(Box::new(col.slice(range))),
             Column::Map(col) => Column::Map(Box::new(col.slice(range))),
             Column::Bitmap(col) => Column::Bitmap(col.slice(range)),
@@ -1045,6 +1228,12 @@ impl Column {
             Column::Variant(col) => Column::Variant(col.slice(range)),
             Column::Geometry(col) => Column::Geometry(col.slice(range)),
             Column::Geography(col) => Column::Geography(col.slice(range)),
+            Column::Interval(col) => {
+                Column::Interval(col.clone().sliced(range.start, range.end - range.start))
+            }
+            Column::TimestampTz(col) => {
+                Column::TimestampTz(col.clone().sliced(range.start, range.end - range.start))
+            }
         }
     }
 
@@ -1094,6 +1283,30 @@ impl Column {
                     max: *max,
                 })
             }
+            Column::Time(col) => {
+                let (min, max) = col.iter().minmax().into_option().unwrap();
+                Domain::Time(SimpleDomain {
+                    min: *min,
+                    max: *max,
+                })
+            }
+            Column::Uuid(col) => {
+                let (min, max) = col.iter().minmax().into_option().unwrap();
+                Domain::Uuid(SimpleDomain {
+                    min: *min,
+                    max: *max,
+                })
+            }
+            Column::Enum(col) => {
+                let (min, max) = col.codes.iter().minmax().into_option().unwrap();
+                Domain::Enum(
+                    SimpleDomain {
+                        min: *min,
+                        max: *max,
+                    },
+                    col.items.clone(),
+                )
+            }
             Column::Array(col) => {
                 if col.len() == 0 || col.values.len() == 0 {
                     Domain::Array(None)
@@ -1125,7 +1338,10 @@ impl Column {
             | Column::Bitmap(_)
             | Column::Variant(_)
             | Column::Geometry(_)
-            | Column::Geography(_) => Domain::Undefined,
+            | Column::Geography(_)
+            | Column::Interval(_)
+            | Column::TimestampTz(_)
+            | Column::AggregateState(_) => Domain::Undefined,
         }
     }
 
@@ -1166,6 +1382,12 @@ impl Column {
             Column::Variant(_) => DataType::Variant,
             Column::Geometry(_) => DataType::Geometry,
             Column::Geography(_) => DataType::Geography,
+            Column::Interval(_) => DataType::Interval,
+            Column::TimestampTz(_) => DataType::TimestampTz,
+            Column::Time(_) => DataType::Time,
+            Column::Uuid(_) => DataType::Uuid,
+            Column::Enum(col) => DataType::Enum(col.items.clone()),
+            Column::AggregateState(col) => DataType::AggregateState(col.signature.clone()),
         }
     }
 
@@ -1176,6 +1398,7 @@ impl Column {
             Column::Geometry(x) => x.check_valid(),
             Column::Geography(x) => x.check_valid(),
             Column::Bitmap(x) => x.check_valid(),
+            Column::AggregateState(x) => x.states.check_valid(),
             Column::Map(x) => {
                 for y in x.iter() {
                     y.check_valid()?;
@@ -1280,6 +1503,29 @@ impl Column {
                     .map(|_| rng.gen_range(DATE_MIN..=DATE_MAX))
                     .collect::<Vec<i32>>(),
             ),
+            DataType::Time => TimeType::from_data(
+                (0..len)
+                    .map(|_| rng.gen_range(TIME_MIN..=TIME_MAX))
+                    .collect::<Vec<i64>>(),
+            ),
+            DataType::Uuid => {
+                UuidType::from_data((0..len).map(|_| rng.gen::<u128>()).collect::<Vec<u128>>())
+            }
+            DataType::Enum(items) => Column::Enum(EnumColumn::new(
+                (0..len)
+                    .map(|_| rng.gen_range(0..=items.max_code()))
+                    .collect::<Vec<u16>>()
+                    .into(),
+                items.clone(),
+            )),
+            DataType::AggregateState(signature) => {
+                Column::AggregateState(AggregateStateColumn::new(
+                    Column::random(&DataType::Binary, len, seed)
+                        .into_binary()
+                        .unwrap(),
+                    signature.clone(),
+                ))
+            }
             DataType::Nullable(ty) => NullableColumn::new_column(
                 Column::random(ty, len, seed),
                 Bitmap::from((0..len).map(|_| rng.gen_bool(0.5)).collect::<Vec<bool>>()),
@@ -1363,6 +1609,27 @@ impl Column {
                 }
                 Column::Geography(GeographyColumn(builder.build()))
             }
+            DataType::Interval => IntervalType::from_data(
+                (0..len)
+                    .map(|_| {
+                        Interval::new(
+                            rng.gen_range(-1200..=1200),
+                            rng.gen_range(-365..=365),
+                            rng.gen_range(-MICROS_PER_DAY..=MICROS_PER_DAY),
+                        )
+                    })
+                    .collect::<Vec<Interval>>(),
+            ),
+            DataType::TimestampTz => TimestampTzType::from_data(
+                (0..len)
+                    .map(|_| {
+                        TimestampTz::new(
+                            rng.gen_range(TIMESTAMP_MIN..=TIMESTAMP_MAX),
+                            rng.gen_range(-14 * 4..=14 * 4) * 15 * 60,
+                        )
+                    })
+                    .collect::<Vec<TimestampTz>>(),
+            ),
             DataType::Generic(_) => unreachable!(),
         }
     }
@@ -1421,6 +1688,12 @@ impl Column {
             Column::Variant(col) => col.memory_size(),
             Column::Geometry(col) => col.memory_size(),
             Column::Geography(col) => GeographyType::column_memory_size(col),
+            Column::Interval(col) => col.len() * 16,
+            Column::TimestampTz(col) => col.len() * 16,
+            Column::Time(col) => col.len() * 8,
+            Column::Uuid(col) => col.len() * 16,
+            Column::Enum(col) => col.memory_size(),
+            Column::AggregateState(col) => col.memory_size(),
         }
     }
 
@@ -1440,6 +1713,12 @@ impl Column {
             Column::Decimal(DecimalColumn::Decimal128(col, _)) => col.len() * 16,
             Column::Decimal(DecimalColumn::Decimal256(col, _)) => col.len() * 32,
             Column::Geography(col) => GeographyType::column_memory_size(col),
+            Column::Interval(col) => col.len() * 16,
+            Column::TimestampTz(col) => col.len() * 16,
+            Column::Time(col) => col.len() * 8,
+            Column::Uuid(col) => col.len() * 16,
+            Column::Enum(col) => col.memory_size(),
+            Column::AggregateState(col) => col.memory_size(),
             Column::Boolean(c) => c.len(),
             // 8 * len + size of bytes
             Column::Binary(col)
@@ -1557,6 +1836,14 @@ impl ColumnBuilder {
             Column::Geography(col) => {
                 ColumnBuilder::Geography(GeographyType::column_to_builder(col))
             }
+            Column::Interval(col) => ColumnBuilder::Interval(buffer_into_mut(col)),
+            Column::TimestampTz(col) => ColumnBuilder::TimestampTz(buffer_into_mut(col)),
+            Column::Time(col) => ColumnBuilder::Time(buffer_into_mut(col)),
+            Column::Uuid(col) => ColumnBuilder::Uuid(buffer_into_mut(col)),
+            Column::Enum(col) => ColumnBuilder::Enum(EnumColumnBuilder::from_column(col)),
+            Column::AggregateState(col) => {
+                ColumnBuilder::AggregateState(AggregateStateColumnBuilder::from_column(col))
+            }
         }
     }
 
@@ -1617,6 +1904,17 @@ impl ColumnBuilder {
             ScalarRef::Geography(s) => {
                 ColumnBuilder::Geography(BinaryColumnBuilder::repeat(s.0, n))
             }
+            ScalarRef::Interval(i) => ColumnBuilder::Interval(vec![*i; n]),
+            ScalarRef::TimestampTz(i) => ColumnBuilder::TimestampTz(vec![*i; n]),
+            ScalarRef::Time(i) => ColumnBuilder::Time(vec![*i; n]),
+            ScalarRef::Uuid(i) => ColumnBuilder::Uuid(vec![*i; n]),
+            ScalarRef::Enum(i, items) => ColumnBuilder::Enum(EnumColumnBuilder {
+                codes: vec![*i; n],
+                items: (*items).clone(),
+            }),
+            ScalarRef::AggregateState(s, signature) => ColumnBuilder::AggregateState(
+                AggregateStateColumnBuilder::repeat((*signature).clone(), s, n),
+            ),
         }
     }
 
@@ -1640,6 +1938,12 @@ impl ColumnBuilder {
             ColumnBuilder::Variant(builder) => builder.len(),
             ColumnBuilder::Geometry(builder) => builder.len(),
             ColumnBuilder::Geography(builder) => builder.len(),
+            ColumnBuilder::Interval(builder) => builder.len(),
+            ColumnBuilder::TimestampTz(builder) => builder.len(),
+            ColumnBuilder::Time(builder) => builder.len(),
+            ColumnBuilder::Uuid(builder) => builder.len(),
+            ColumnBuilder::Enum(builder) => builder.len(),
+            ColumnBuilder::AggregateState(builder) => builder.len(),
         }
     }
 
@@ -1677,6 +1981,12 @@ impl ColumnBuilder {
             ColumnBuilder::Variant(col) => col.data.len() + col.offsets.len() * 8,
             ColumnBuilder::Geometry(col) => col.data.len() + col.offsets.len() * 8,
             ColumnBuilder::Geography(builder) => builder.memory_size(),
+            ColumnBuilder::Interval(col) => col.len() * 16,
+            ColumnBuilder::TimestampTz(col) => col.len() * 16,
+            ColumnBuilder::Time(col) => col.len() * 8,
+            ColumnBuilder::Uuid(col) => col.len() * 16,
+            ColumnBuilder::Enum(col) => col.memory_size(),
+            ColumnBuilder::AggregateState(col) => col.memory_size(),
         }
     }
 
@@ -1713,6 +2023,14 @@ impl ColumnBuilder {
             ColumnBuilder::Variant(_) => DataType::Variant,
             ColumnBuilder::Geometry(_) => DataType::Geometry,
             ColumnBuilder::Geography(_) => DataType::Geography,
+            ColumnBuilder::Interval(_) => DataType::Interval,
+            ColumnBuilder::TimestampTz(_) => DataType::TimestampTz,
+            ColumnBuilder::Time(_) => DataType::Time,
+            ColumnBuilder::Uuid(_) => DataType::Uuid,
+            ColumnBuilder::Enum(builder) => DataType::Enum(builder.items.clone()),
+            ColumnBuilder::AggregateState(builder) => {
+                DataType::AggregateState(builder.signature.clone())
+            }
         }
     }
 
@@ -1795,6 +2113,16 @@ impl ColumnBuilder {
                     data_capacity,
                 ))
             }
+            DataType::Interval => ColumnBuilder::Interval(Vec::with_capacity(capacity)),
+            DataType::TimestampTz => ColumnBuilder::TimestampTz(Vec::with_capacity(capacity)),
+            DataType::Time => ColumnBuilder::Time(Vec::with_capacity(capacity)),
+            DataType::Uuid => ColumnBuilder::Uuid(Vec::with_capacity(capacity)),
+            DataType::Enum(items) => {
+                ColumnBuilder::Enum(EnumColumnBuilder::with_capacity(items.clone(), capacity))
+            }
+            DataType::AggregateState(signature) => ColumnBuilder::AggregateState(
+                AggregateStateColumnBuilder::with_capacity(signature.clone(), capacity),
+            ),
             DataType::Generic(_) => {
                 unreachable!("unable to initialize column builder for generic type")
             }
@@ -1833,6 +2161,20 @@ impl ColumnBuilder {
             DataType::Geography => {
                 ColumnBuilder::Geography(BinaryColumnBuilder::repeat_default(len))
             }
+            DataType::Interval => ColumnBuilder::Interval(vec![Interval::default(); len]),
+            DataType::TimestampTz => ColumnBuilder::TimestampTz(vec![TimestampTz::default(); len]),
+            DataType::Time => ColumnBuilder::Time(vec![0; len]),
+            DataType::Uuid => ColumnBuilder::Uuid(vec![0; len]),
+            DataType::Enum(items) => ColumnBuilder::Enum(EnumColumnBuilder {
+                codes: vec![0; len],
+                items: items.clone(),
+            }),
+            DataType::AggregateState(signature) => {
+                ColumnBuilder::AggregateState(AggregateStateColumnBuilder {
+                    states: BinaryColumnBuilder::repeat_default(len),
+                    signature: signature.clone(),
+                })
+            }
 
             DataType::Array(ty) => ColumnBuilder::Array(Box::new(ArrayColumnBuilder {
                 builder: Self::with_capacity(ty, 0),
@@ -1880,6 +2222,16 @@ impl ColumnBuilder {
             (ColumnBuilder::Date(builder), ScalarRef::Date(value)) => {
                 DateType::push_item(builder, value)
             }
+            (ColumnBuilder::Time(builder), ScalarRef::Time(value)) => {
+                TimeType::push_item(builder, value)
+            }
+            (ColumnBuilder::Uuid(builder), ScalarRef::Uuid(value)) => {
+                UuidType::push_item(builder, value)
+            }
+            (ColumnBuilder::Enum(builder), ScalarRef::Enum(value, _)) => builder.push(value),
+            (ColumnBuilder::AggregateState(builder), ScalarRef::AggregateState(value, _)) => {
+                builder.push(value)
+            }
             (ColumnBuilder::Array(builder), ScalarRef::Array(value)) => {
                 ArrayType::push_item(builder, value);
             }
@@ -1910,6 +2262,12 @@ impl ColumnBuilder {
             (ColumnBuilder::Geography(builder), ScalarRef::Geography(value)) => {
                 GeographyType::push_item(builder, value);
             }
+            (ColumnBuilder::Interval(builder), ScalarRef::Interval(value)) => {
+                IntervalType::push_item(builder, value);
+            }
+            (ColumnBuilder::TimestampTz(builder), ScalarRef::TimestampTz(value)) => {
+                TimestampTzType::push_item(builder, value);
+            }
             (builder, scalar) => unreachable!("unable to push {scalar:?} to {builder:?}"),
         }
     }
@@ -1935,6 +2293,18 @@ impl ColumnBuilder {
             (ColumnBuilder::Date(builder), ScalarRef::Date(value)) => {
                 DateType::push_item_repeat(builder, *value, n);
             }
+            (ColumnBuilder::Time(builder), ScalarRef::Time(value)) => {
+                TimeType::push_item_repeat(builder, *value, n);
+            }
+            (ColumnBuilder::Uuid(builder), ScalarRef::Uuid(value)) => {
+                UuidType::push_item_repeat(builder, *value, n);
+            }
+            (ColumnBuilder::Enum(builder), ScalarRef::Enum(value, _)) => {
+                builder.push_repeat(*value, n);
+            }
+            (ColumnBuilder::AggregateState(builder), ScalarRef::AggregateState(value, _)) => {
+                builder.push_repeat(value, n);
+            }
             (ColumnBuilder::Binary(builder), ScalarRef::Binary(value)) => {
                 BinaryType::push_item_repeat(builder, *value, n);
             }
@@ -1969,6 +2339,12 @@ impl ColumnBuilder {
             (ColumnBuilder::Geography(builder), ScalarRef::Geography(value)) => {
                 GeographyType::push_item_repeat(builder, *value, n);
             }
+            (ColumnBuilder::Interval(builder), ScalarRef::Interval(value)) => {
+                IntervalType::push_item_repeat(builder, *value, n);
+            }
+            (ColumnBuilder::TimestampTz(builder), ScalarRef::TimestampTz(value)) => {
+                TimestampTzType::push_item_repeat(builder, *value, n);
+            }
             (builder, scalar) => unreachable!("unable to push {scalar:?} to {builder:?}"),
         };
     }
@@ -2000,6 +2376,12 @@ impl ColumnBuilder {
             }
             ColumnBuilder::Geometry(builder) => builder.commit_row(),
             ColumnBuilder::Geography(builder) => builder.commit_row(),
+            ColumnBuilder::Interval(builder) => builder.push(Interval::default()),
+            ColumnBuilder::TimestampTz(builder) => builder.push(TimestampTz::default()),
+            ColumnBuilder::Time(builder) => builder.push(0),
+            ColumnBuilder::Uuid(builder) => builder.push(0),
+            ColumnBuilder::Enum(builder) => builder.push_default(),
+            ColumnBuilder::AggregateState(builder) => builder.push_default(),
         }
     }
 
@@ -2056,10 +2438,38 @@ impl ColumnBuilder {
                 clamp_timestamp(&mut value);
                 builder.push(value);
             }
+            ColumnBuilder::Interval(builder) => {
+                let months: i32 = reader.read_scalar()?;
+                let days: i32 = reader.read_scalar()?;
+                let micros: i64 = reader.read_scalar()?;
+                builder.push(Interval::new(months, days, micros));
+            }
+            ColumnBuilder::TimestampTz(builder) => {
+                // Only the instant is serialized, the value is rebuilt in UTC.
+                let micros: i64 = reader.read_scalar()?;
+                builder.push(TimestampTz::new(micros, 0));
+            }
             ColumnBuilder::Date(builder) => {
                 let value: i32 = reader.read_scalar()?;
                 builder.push(value);
             }
+            ColumnBuilder::Time(builder) => {
+                let value: i64 = reader.read_scalar()?;
+                builder.push(value);
+            }
+            ColumnBuilder::Uuid(builder) => builder.push(i128::de_binary(reader) as u128),
+            ColumnBuilder::Enum(builder) => {
+                let code: u16 = reader.read_scalar()?;
+                builder.push(code);
+            }
+            ColumnBuilder::AggregateState(builder) => {
+                let builder = &mut builder.states;
+                let offset = reader.read_scalar::<u64>()? as usize;
+                builder.data.resize(offset + builder.data.len(), 0);
+                let last = *builder.offsets.last().unwrap() as usize;
+                reader.read_exact(&mut builder.data[last..last + offset])?;
+                builder.commit_row();
+            }
             ColumnBuilder::Array(builder) => {
                 let len = reader.read_scalar::<u64>()?;
                 for _ in 0..len {
@@ -2158,6 +2568,23 @@ impl ColumnBuilder {
                     builder.push(value);
                 }
             }
+            ColumnBuilder::Interval(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    let months: i32 = reader.read_scalar()?;
+                    let days: i32 = reader.read_scalar()?;
+                    let micros: i64 = reader.read_scalar()?;
+                    builder.push(Interval::new(months, days, micros));
+                }
+            }
+            ColumnBuilder::TimestampTz(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    let micros: i64 = reader.read_scalar()?;
+                    let offset: i32 = reader.read_scalar()?;
+                    builder.push(TimestampTz::new(micros, offset));
+                }
+            }
             ColumnBuilder::Date(builder) => {
                 for row in 0..rows {
                     let mut reader = &reader[step * row..];
@@ -2165,6 +2592,32 @@ impl ColumnBuilder {
                     builder.push(value);
                 }
             }
+            ColumnBuilder::Time(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    let value: i64 = reader.read_scalar()?;
+                    builder.push(value);
+                }
+            }
+            ColumnBuilder::Uuid(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    builder.push(i128::de_binary(&mut reader) as u128);
+                }
+            }
+            ColumnBuilder::Enum(builder) => {
+                for row in 0..rows {
+                    let mut reader = &reader[step * row..];
+                    let code: u16 = reader.read_scalar()?;
+                    builder.push(code);
+                }
+            }
+            ColumnBuilder::AggregateState(builder) => {
+                for row in 0..rows {
+                    let reader = &reader[step * row..];
+                    builder.push(reader);
+                }
+            }
             ColumnBuilder::Array(builder) => {
                 for row in 0..rows {
                     let mut reader = &reader[step * row..];
@@ -2259,6 +2712,16 @@ impl ColumnBuilder {
             ColumnBuilder::Geography(builder) => {
                 builder.pop().map(Geography).map(Scalar::Geography)
             }
+            ColumnBuilder::Interval(builder) => builder.pop().map(Scalar::Interval),
+            ColumnBuilder::TimestampTz(builder) => builder.pop().map(Scalar::TimestampTz),
+            ColumnBuilder::Time(builder) => builder.pop().map(Scalar::Time),
+            ColumnBuilder::Uuid(builder) => builder.pop().map(Scalar::Uuid),
+            ColumnBuilder::Enum(builder) => builder
+                .pop()
+                .map(|code| Scalar::Enum(code, builder.items.clone())),
+            ColumnBuilder::AggregateState(builder) => builder
+                .pop()
+                .map(|state| Scalar::AggregateState(state, builder.signature.clone())),
         }
     }
 
@@ -2297,6 +2760,24 @@ impl ColumnBuilder {
             (ColumnBuilder::Geography(builder), Column::Geography(other)) => {
                 GeographyType::append_column(builder, other);
             }
+            (ColumnBuilder::Interval(builder), Column::Interval(other)) => {
+                builder.extend_from_slice(other);
+            }
+            (ColumnBuilder::TimestampTz(builder), Column::TimestampTz(other)) => {
+                builder.extend_from_slice(other);
+            }
+            (ColumnBuilder::Time(builder), Column::Time(other)) => {
+                builder.extend_from_slice(other);
+            }
+            (ColumnBuilder::Uuid(builder), Column::Uuid(other)) => {
+                builder.extend_from_slice(other);
+            }
+            (ColumnBuilder::Enum(builder), Column::Enum(other)) => {
+                builder.append_column(other);
+            }
+            (ColumnBuilder::AggregateState(builder), Column::AggregateState(other)) => {
+                builder.append_column(other);
+            }
             (ColumnBuilder::Timestamp(builder), Column::Timestamp(other)) => {
                 builder.extend_from_slice(other);
             }
@@ -2353,6 +2834,12 @@ impl ColumnBuilder {
             ColumnBuilder::Variant(b) => Column::Variant(VariantType::build_column(b)),
             ColumnBuilder::Geometry(b) => Column::Geometry(GeometryType::build_column(b)),
             ColumnBuilder::Geography(b) => Column::Geography(GeographyType::build_column(b)),
+            ColumnBuilder::Interval(b) => Column::Interval(IntervalType::build_column(b)),
+            ColumnBuilder::TimestampTz(b) => Column::TimestampTz(TimestampTzType::build_column(b)),
+            ColumnBuilder::Time(b) => Column::Time(TimeType::build_column(b)),
+            ColumnBuilder::Uuid(b) => Column::Uuid(UuidType::build_column(b)),
+            ColumnBuilder::Enum(b) => Column::Enum(b.build()),
+            ColumnBuilder::AggregateState(b) => Column::AggregateState(b.build()),
         }
     }
 
@@ -2383,6 +2870,18 @@ impl ColumnBuilder {
             ColumnBuilder::Variant(b) => Scalar::Variant(VariantType::build_scalar(b)),
             ColumnBuilder::Geometry(b) => Scalar::Geometry(GeometryType::build_scalar(b)),
             ColumnBuilder::Geography(b) => Scalar::Geography(GeographyType::build_scalar(b)),
+            ColumnBuilder::Interval(b) => Scalar::Interval(IntervalType::build_scalar(b)),
+            ColumnBuilder::TimestampTz(b) => Scalar::TimestampTz(TimestampTzType::build_scalar(b)),
+            ColumnBuilder::Time(b) => Scalar::Time(TimeType::build_scalar(b)),
+            ColumnBuilder::Uuid(b) => Scalar::Uuid(UuidType::build_scalar(b)),
+            ColumnBuilder::Enum(b) => {
+                let items = b.items.clone();
+                Scalar::Enum(b.build_scalar(), items)
+            }
+            ColumnBuilder::AggregateState(b) => {
+                let signature = b.signature.clone();
+                Scalar::AggregateState(b.build_scalar(), signature)
+            }
         }
     }
 }
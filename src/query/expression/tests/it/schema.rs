@@ -671,3 +671,105 @@ fn test_geography_as_arrow() {
     let got = Column::from_arrow(&*arr, &col.data_type()).unwrap();
     assert_eq!(col, got)
 }
+
+#[test]
+fn test_interval_as_arrow() {
+    use databend_common_expression::types::Interval;
+    use databend_common_expression::Column;
+
+    let col = Column::Interval(
+        vec![
+            Interval::new(14, 0, 0),
+            Interval::new(0, -3, 1_000_000),
+            Interval::new(-1, 2, -5),
+        ]
+        .into(),
+    );
+
+    let arr = col.as_arrow();
+    let got = Column::from_arrow(&*arr, &col.data_type()).unwrap();
+    assert_eq!(col, got)
+}
+
+#[test]
+fn test_timestamp_tz_as_arrow() {
+    use databend_common_expression::types::TimestampTz;
+    use databend_common_expression::Column;
+
+    let col = Column::TimestampTz(
+        vec![
+            TimestampTz::new(0, 0),
+            TimestampTz::new(1_704_074_400_000_000, 8 * 3600),
+            TimestampTz::new(-1_000_000, -(5 * 3600 + 30 * 60)),
+        ]
+        .into(),
+    );
+
+    let arr = col.as_arrow();
+    let got = Column::from_arrow(&*arr, &col.data_type()).unwrap();
+    assert_eq!(col, got)
+}
+
+#[test]
+fn test_time_uuid_as_arrow() {
+    use databend_common_expression::Column;
+
+    let cols = [
+        Column::Time(vec![0, 37_230_500_000, 86_399_999_999].into()),
+        Column::Uuid(vec![0, 1, u128::MAX, 0xa0eebc99_9c0b_4ef8_bb6d_6bb9bd380a11].into()),
+    ];
+    for col in cols {
+        let arr = col.as_arrow();
+        let got = Column::from_arrow(&*arr, &col.data_type()).unwrap();
+        assert_eq!(col, got)
+    }
+}
+
+#[test]
+fn test_enum_as_arrow() {
+    use databend_common_expression::types::EnumColumn;
+    use databend_common_expression::types::EnumItems;
+    use databend_common_expression::Column;
+
+    let items =
+        EnumItems::try_create(vec!["a".to_string(), "b".to_string(), "c".to_string()]).unwrap();
+    let col = Column::Enum(EnumColumn::new(vec![0, 2, 1, 0].into(), items.clone()));
+
+    let arr = col.as_arrow();
+    let got = Column::from_arrow(&*arr, &col.data_type()).unwrap();
+    assert_eq!(col, got);
+
+    let field = TableField::new("e", TableDataType::Enum(items));
+    let arrow_field = ArrowField::from(&field);
+    let got = TableField::try_from(&arrow_field).unwrap();
+    assert_eq!(field.data_type(), got.data_type());
+}
+
+#[test]
+fn test_aggregate_state_as_arrow() {
+    use databend_common_expression::types::AggregateStateColumnBuilder;
+    use databend_common_expression::types::AggregateStateSignature;
+    use databend_common_expression::types::DataType;
+    use databend_common_expression::types::NumberScalar;
+    use databend_common_expression::Column;
+
+    let signature = AggregateStateSignature::new(
+        "quantile_tdigest",
+        vec![Scalar::Number(NumberScalar::Float64(0.5.into()))],
+        vec![DataType::Number(NumberDataType::UInt64)],
+    );
+    let mut builder = AggregateStateColumnBuilder::with_capacity(signature.clone(), 3);
+    builder.push(b"state1");
+    builder.push_default();
+    builder.push(b"state3");
+    let col = Column::AggregateState(builder.build());
+
+    let arr = col.as_arrow();
+    let got = Column::from_arrow(&*arr, &col.data_type()).unwrap();
+    assert_eq!(col, got);
+
+    let field = TableField::new("s", TableDataType::AggregateState(signature));
+    let arrow_field = ArrowField::from(&field);
+    let got = TableField::try_from(&arrow_field).unwrap();
+    assert_eq!(field.data_type(), got.data_type());
+}
@@ -16,7 +16,17 @@ use arrow_schema::Schema;
 use chrono_tz::Tz;
 use databend_common_expression::arrow::deserialize_column;
 use databend_common_expression::arrow::serialize_column;
+use databend_common_expression::types::enumeration::string_to_enum;
+use databend_common_expression::types::interval::string_to_interval;
+use databend_common_expression::types::time::string_to_time;
+use databend_common_expression::types::time::time_to_string;
 use databend_common_expression::types::timestamp::timestamp_to_string;
+use databend_common_expression::types::timestamp_tz::string_to_timestamp_tz;
+use databend_common_expression::types::uuid::string_to_uuid;
+use databend_common_expression::types::uuid::uuid_to_string;
+use databend_common_expression::types::EnumItems;
+use databend_common_expression::types::Interval;
+use databend_common_expression::types::TimestampTz;
 use databend_common_expression::DataField;
 use databend_common_expression::DataSchema;
 
@@ -35,6 +45,134 @@ fn test_timestamp_to_string_formats() {
     );
 }
 
+#[test]
+fn test_interval_to_string() {
+    let cases = [
+        (
+            "1 year 2 months",
+            Interval::new(14, 0, 0),
+            "1 year 2 months",
+        ),
+        ("3days", Interval::new(0, 3, 0), "3 days"),
+        (
+            "1 week 90 minutes",
+            Interval::new(0, 7, 5_400_000_000),
+            "7 days 01:30:00",
+        ),
+        (
+            "-1 day 04:05:06.5",
+            Interval::new(0, -1, 14_706_500_000),
+            "-1 day 04:05:06.5",
+        ),
+        ("-00:00:01", Interval::new(0, 0, -1_000_000), "-00:00:01"),
+        ("0 seconds", Interval::new(0, 0, 0), "00:00:00"),
+    ];
+    for (input, expected, display) in cases {
+        let interval = string_to_interval(input).unwrap();
+        assert_eq!(interval, expected, "{input}");
+        assert_eq!(interval.to_string(), display);
+        assert_eq!(string_to_interval(display).unwrap(), interval);
+    }
+
+    for input in ["", "1", "1 fortnight", "1.5 months", "10:99:00"] {
+        assert!(string_to_interval(input).is_err(), "{input}");
+    }
+}
+
+#[test]
+fn test_timestamp_tz_to_string() {
+    let tz: Tz = "Asia/Shanghai".parse().unwrap();
+    let cases = [
+        (
+            "2024-01-01T10:00:00+08:00",
+            TimestampTz::new(1_704_074_400_000_000, 8 * 3600),
+            "2024-01-01 10:00:00.000000 +08:00",
+        ),
+        (
+            "2024-01-01 02:00:00Z",
+            TimestampTz::new(1_704_074_400_000_000, 0),
+            "2024-01-01 02:00:00.000000 +00:00",
+        ),
+        (
+            "2024-01-01 10:00:00.5-0530",
+            TimestampTz::new(1_704_123_000_500_000, -(5 * 3600 + 30 * 60)),
+            "2024-01-01 10:00:00.500000 -05:30",
+        ),
+        (
+            "2024-01-01 10:00:00",
+            TimestampTz::new(1_704_074_400_000_000, 8 * 3600),
+            "2024-01-01 10:00:00.000000 +08:00",
+        ),
+    ];
+    for (input, expected, display) in cases {
+        let ts = string_to_timestamp_tz(input, tz, false).unwrap();
+        assert_eq!(ts, expected, "{input}");
+        assert_eq!(ts.offset, expected.offset, "{input}");
+        assert_eq!(ts.to_string(), display);
+        assert_eq!(string_to_timestamp_tz(display, Tz::UTC, false).unwrap(), ts);
+        let packed = TimestampTz::from_i128(ts.to_i128());
+        assert_eq!((packed.micros, packed.offset), (ts.micros, ts.offset));
+    }
+
+    for input in ["", "2024-01-01 10:00:00+25:00", "2024-01-01 10:00:00+8x"] {
+        assert!(string_to_timestamp_tz(input, tz, false).is_err(), "{input}");
+    }
+}
+
+#[test]
+fn test_time_to_string() {
+    let cases = [
+        ("00:00:00", 0, "00:00:00.000000"),
+        ("08:15", 29_700_000_000, "08:15:00.000000"),
+        ("10:20:30.5", 37_230_500_000, "10:20:30.500000"),
+        (" 23:59:59.999999 ", 86_399_999_999, "23:59:59.999999"),
+    ];
+    for (input, expected, display) in cases {
+        let time = string_to_time(input).unwrap();
+        assert_eq!(time, expected, "{input}");
+        assert_eq!(time_to_string(time).to_string(), display);
+        assert_eq!(string_to_time(display).unwrap(), time);
+    }
+
+    for input in ["", "24:00:00", "10:61:00", "10:20:30+08:00"] {
+        assert!(string_to_time(input).is_err(), "{input}");
+    }
+}
+
+#[test]
+fn test_uuid_to_string() {
+    let expected = 0xa0eebc99_9c0b_4ef8_bb6d_6bb9bd380a11_u128;
+    let display = "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11";
+    for input in [
+        "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11",
+        "A0EEBC99-9C0B-4EF8-BB6D-6BB9BD380A11",
+        "a0eebc999c0b4ef8bb6d6bb9bd380a11",
+        "{a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11}",
+        "urn:uuid:a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11",
+    ] {
+        let uuid = string_to_uuid(input).unwrap();
+        assert_eq!(uuid, expected, "{input}");
+        assert_eq!(uuid_to_string(uuid).to_string(), display);
+    }
+
+    for input in ["", "a0eebc99-9c0b", "g0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11"] {
+        assert!(string_to_uuid(input).is_err(), "{input}");
+    }
+}
+
+#[test]
+fn test_enum_items() {
+    let items = EnumItems::try_create(vec!["a".to_string(), "it's".to_string()]).unwrap();
+    assert_eq!(items.to_string(), "'a', 'it\\'s'");
+    assert_eq!(string_to_enum(&items, "a").unwrap(), 0);
+    assert_eq!(string_to_enum(&items, "it's").unwrap(), 1);
+    assert_eq!(items.item(1), "it's");
+    assert!(string_to_enum(&items, "b").is_err());
+
+    assert!(EnumItems::try_create(vec![]).is_err());
+    assert!(EnumItems::try_create(vec!["a".to_string(), "a".to_string()]).is_err());
+}
+
 #[test]
 fn test_convert_types() {
     let all_types = get_all_test_data_types();
@@ -33,12 +33,20 @@ use databend_common_expression::types::date::clamp_date;
 use databend_common_expression::types::decimal::Decimal;
 use databend_common_expression::types::decimal::DecimalColumnBuilder;
 use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::enumeration::string_to_enum;
+use databend_common_expression::types::interval::string_to_interval;
 use databend_common_expression::types::nullable::NullableColumnBuilder;
 use databend_common_expression::types::number::Number;
 use databend_common_expression::types::string::StringColumnBuilder;
+use databend_common_expression::types::time::string_to_time;
 use databend_common_expression::types::timestamp::clamp_timestamp;
+use databend_common_expression::types::timestamp_tz::string_to_timestamp_tz;
+use databend_common_expression::types::uuid::string_to_uuid;
 use databend_common_expression::types::AnyType;
+use databend_common_expression::types::EnumColumnBuilder;
+use databend_common_expression::types::Interval;
 use databend_common_expression::types::NumberColumnBuilder;
+use databend_common_expression::types::TimestampTz;
 use databend_common_expression::with_decimal_type;
 use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::ColumnBuilder;
@@ -144,6 +152,11 @@ impl FastFieldDecoderValues {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, reader, positions),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, reader, positions),
+            ColumnBuilder::Interval(c) => self.read_interval(c, reader, positions),
+            ColumnBuilder::TimestampTz(c) => self.read_timestamp_tz(c, reader, positions),
+            ColumnBuilder::Time(c) => self.read_time(c, reader, positions),
+            ColumnBuilder::Uuid(c) => self.read_uuid(c, reader, positions),
+            ColumnBuilder::Enum(c) => self.read_enum(c, reader, positions),
             ColumnBuilder::String(c) => self.read_string(c, reader, positions),
             ColumnBuilder::Array(c) => self.read_array(c, reader, positions),
             ColumnBuilder::Map(c) => self.read_map(c, reader, positions),
@@ -153,6 +166,9 @@ impl FastFieldDecoderValues {
             ColumnBuilder::Geometry(c) => self.read_geometry(c, reader, positions),
             ColumnBuilder::Geography(c) => self.read_geography(c, reader, positions),
             ColumnBuilder::Binary(_) => Err(ErrorCode::Unimplemented("binary literal")),
+            ColumnBuilder::AggregateState(_) => {
+                Err(ErrorCode::Unimplemented("aggregate state literal"))
+            }
             ColumnBuilder::EmptyArray { .. } | ColumnBuilder::EmptyMap { .. } => {
                 Err(ErrorCode::Unimplemented("empty array/map literal"))
             }
@@ -327,6 +343,73 @@ impl FastFieldDecoderValues {
         Ok(())
     }
 
+    fn read_interval<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<Interval>,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, positions)?;
+        let data = buf
+            .to_str()
+            .map_err(|_| ErrorCode::BadBytes("Incorrect interval value, not utf8"))?;
+        column.push(string_to_interval(data)?);
+        Ok(())
+    }
+
+    fn read_timestamp_tz<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<TimestampTz>,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, positions)?;
+        column.push(string_to_timestamp_tz(
+            &buf,
+            self.common_settings().timezone,
+            self.common_settings().enable_dst_hour_fix,
+        )?);
+        Ok(())
+    }
+
+    fn read_time<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<i64>,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, positions)?;
+        column.push(string_to_time(&buf)?);
+        Ok(())
+    }
+
+    fn read_uuid<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<u128>,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, positions)?;
+        column.push(string_to_uuid(&buf)?);
+        Ok(())
+    }
+
+    fn read_enum<R: AsRef<[u8]>>(
+        &self,
+        column: &mut EnumColumnBuilder,
+        reader: &mut Cursor<R>,
+        positions: &mut VecDeque<usize>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf, positions)?;
+        column.push(string_to_enum(&column.items, &buf)?);
+        Ok(())
+    }
+
     fn read_array<R: AsRef<[u8]>>(
         &self,
         column: &mut ArrayColumnBuilder<AnyType>,
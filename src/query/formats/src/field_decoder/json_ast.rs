@@ -27,12 +27,20 @@ use databend_common_expression::types::date::clamp_date;
 use databend_common_expression::types::decimal::Decimal;
 use databend_common_expression::types::decimal::DecimalColumnBuilder;
 use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::enumeration::string_to_enum;
+use databend_common_expression::types::interval::string_to_interval;
 use databend_common_expression::types::nullable::NullableColumnBuilder;
 use databend_common_expression::types::number::Number;
 use databend_common_expression::types::string::StringColumnBuilder;
+use databend_common_expression::types::time::string_to_time;
 use databend_common_expression::types::timestamp::clamp_timestamp;
+use databend_common_expression::types::timestamp_tz::string_to_timestamp_tz;
+use databend_common_expression::types::uuid::string_to_uuid;
 use databend_common_expression::types::AnyType;
+use databend_common_expression::types::EnumColumnBuilder;
+use databend_common_expression::types::Interval;
 use databend_common_expression::types::NumberColumnBuilder;
+use databend_common_expression::types::TimestampTz;
 use databend_common_expression::with_decimal_type;
 use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::ColumnBuilder;
@@ -96,7 +104,15 @@ impl FieldJsonAstDecoder {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, value),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, value),
+            ColumnBuilder::Interval(c) => self.read_interval(c, value),
+            ColumnBuilder::TimestampTz(c) => self.read_timestamp_tz(c, value),
+            ColumnBuilder::Time(c) => self.read_time(c, value),
+            ColumnBuilder::Uuid(c) => self.read_uuid(c, value),
+            ColumnBuilder::Enum(c) => self.read_enum(c, value),
             ColumnBuilder::Binary(_c) => unimplemented!("binary literal is not supported"),
+            ColumnBuilder::AggregateState(_) => Err(ErrorCode::Unimplemented(
+                "aggregate state literal is not supported",
+            )),
             ColumnBuilder::String(c) => self.read_string(c, value),
             ColumnBuilder::Array(c) => self.read_array(c, value),
             ColumnBuilder::Map(c) => self.read_map(c, value),
@@ -312,6 +328,60 @@ impl FieldJsonAstDecoder {
         }
     }
 
+    fn read_interval(&self, column: &mut Vec<Interval>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                column.push(string_to_interval(v)?);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect interval value")),
+        }
+    }
+
+    fn read_timestamp_tz(&self, column: &mut Vec<TimestampTz>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                column.push(string_to_timestamp_tz(
+                    v,
+                    self.timezone,
+                    self.enable_dst_hour_fix,
+                )?);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect timestamp_tz value")),
+        }
+    }
+
+    fn read_time(&self, column: &mut Vec<i64>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                column.push(string_to_time(v)?);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect time value")),
+        }
+    }
+
+    fn read_uuid(&self, column: &mut Vec<u128>, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                column.push(string_to_uuid(v)?);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect uuid value")),
+        }
+    }
+
+    fn read_enum(&self, column: &mut EnumColumnBuilder, value: &Value) -> Result<()> {
+        match value {
+            Value::String(v) => {
+                column.push(string_to_enum(&column.items, v)?);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes("Incorrect enum value")),
+        }
+    }
+
     fn read_bitmap(&self, column: &mut BinaryColumnBuilder, value: &Value) -> Result<()> {
         match value {
             Value::String(v) => {
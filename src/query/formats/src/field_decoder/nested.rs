@@ -28,12 +28,20 @@ use databend_common_expression::types::date::clamp_date;
 use databend_common_expression::types::decimal::Decimal;
 use databend_common_expression::types::decimal::DecimalColumnBuilder;
 use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::enumeration::string_to_enum;
+use databend_common_expression::types::interval::string_to_interval;
 use databend_common_expression::types::nullable::NullableColumnBuilder;
 use databend_common_expression::types::number::Number;
 use databend_common_expression::types::string::StringColumnBuilder;
+use databend_common_expression::types::time::string_to_time;
 use databend_common_expression::types::timestamp::clamp_timestamp;
+use databend_common_expression::types::timestamp_tz::string_to_timestamp_tz;
+use databend_common_expression::types::uuid::string_to_uuid;
 use databend_common_expression::types::AnyType;
+use databend_common_expression::types::EnumColumnBuilder;
+use databend_common_expression::types::Interval;
 use databend_common_expression::types::NumberColumnBuilder;
+use databend_common_expression::types::TimestampTz;
 use databend_common_expression::with_decimal_type;
 use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::ColumnBuilder;
@@ -128,7 +136,13 @@ impl NestedValues {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, reader),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, reader),
+            ColumnBuilder::Interval(c) => self.read_interval(c, reader),
+            ColumnBuilder::TimestampTz(c) => self.read_timestamp_tz(c, reader),
+            ColumnBuilder::Time(c) => self.read_time(c, reader),
+            ColumnBuilder::Uuid(c) => self.read_uuid(c, reader),
+            ColumnBuilder::Enum(c) => self.read_enum(c, reader),
             ColumnBuilder::Binary(c) => self.read_binary(c, reader),
+            ColumnBuilder::AggregateState(c) => self.read_binary(&mut c.states, reader),
             ColumnBuilder::String(c) => self.read_string(c, reader),
             ColumnBuilder::Array(c) => self.read_array(c, reader),
             ColumnBuilder::Map(c) => self.read_map(c, reader),
@@ -290,6 +304,68 @@ impl NestedValues {
         Ok(())
     }
 
+    fn read_interval<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<Interval>,
+        reader: &mut Cursor<R>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf)?;
+        let data = buf
+            .to_str()
+            .map_err(|_| ErrorCode::BadBytes("Incorrect interval value, not utf8"))?;
+        column.push(string_to_interval(data)?);
+        Ok(())
+    }
+
+    fn read_timestamp_tz<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<TimestampTz>,
+        reader: &mut Cursor<R>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf)?;
+        column.push(string_to_timestamp_tz(
+            &buf,
+            self.common_settings().timezone,
+            self.common_settings().enable_dst_hour_fix,
+        )?);
+        Ok(())
+    }
+
+    fn read_time<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<i64>,
+        reader: &mut Cursor<R>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf)?;
+        column.push(string_to_time(&buf)?);
+        Ok(())
+    }
+
+    fn read_uuid<R: AsRef<[u8]>>(
+        &self,
+        column: &mut Vec<u128>,
+        reader: &mut Cursor<R>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf)?;
+        column.push(string_to_uuid(&buf)?);
+        Ok(())
+    }
+
+    fn read_enum<R: AsRef<[u8]>>(
+        &self,
+        column: &mut EnumColumnBuilder,
+        reader: &mut Cursor<R>,
+    ) -> Result<()> {
+        let mut buf = Vec::new();
+        self.read_string_inner(reader, &mut buf)?;
+        column.push(string_to_enum(&column.items, &buf)?);
+        Ok(())
+    }
+
     fn read_bitmap<R: AsRef<[u8]>>(
         &self,
         column: &mut BinaryColumnBuilder,
@@ -27,11 +27,20 @@ use databend_common_expression::types::date::clamp_date;
 use databend_common_expression::types::decimal::Decimal;
 use databend_common_expression::types::decimal::DecimalColumnBuilder;
 use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::enumeration::string_to_enum;
+use databend_common_expression::types::interval::string_to_interval;
 use databend_common_expression::types::nullable::NullableColumnBuilder;
+use databend_common_expression::types::time::string_to_time;
 use databend_common_expression::types::timestamp::clamp_timestamp;
+use databend_common_expression::types::timestamp_tz::string_to_timestamp_tz;
+use databend_common_expression::types::uuid::string_to_uuid;
+use databend_common_expression::types::AggregateStateColumnBuilder;
 use databend_common_expression::types::AnyType;
+use databend_common_expression::types::EnumColumnBuilder;
+use databend_common_expression::types::Interval;
 use databend_common_expression::types::Number;
 use databend_common_expression::types::NumberColumnBuilder;
+use databend_common_expression::types::TimestampTz;
 use databend_common_expression::with_decimal_type;
 use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::ColumnBuilder;
@@ -117,7 +126,8 @@ impl SeparatedTextDecoder {
                 *len += 1;
                 Ok(())
             }
-            ColumnBuilder::Binary(c) => {
+            ColumnBuilder::Binary(c)
+            | ColumnBuilder::AggregateState(AggregateStateColumnBuilder { states: c, .. }) => {
                 let data = decode_binary(data, self.common_settings().binary_format)?;
                 c.put_slice(&data);
                 c.commit_row();
@@ -143,6 +153,11 @@ impl SeparatedTextDecoder {
             }),
             ColumnBuilder::Date(c) => self.read_date(c, data),
             ColumnBuilder::Timestamp(c) => self.read_timestamp(c, data),
+            ColumnBuilder::Interval(c) => self.read_interval(c, data),
+            ColumnBuilder::TimestampTz(c) => self.read_timestamp_tz(c, data),
+            ColumnBuilder::Time(c) => self.read_time(c, data),
+            ColumnBuilder::Uuid(c) => self.read_uuid(c, data),
+            ColumnBuilder::Enum(c) => self.read_enum(c, data),
             ColumnBuilder::Array(c) => self.read_array(c, data),
             ColumnBuilder::Map(c) => self.read_map(c, data),
             ColumnBuilder::Bitmap(c) => self.read_bitmap(c, data),
@@ -291,6 +306,40 @@ impl SeparatedTextDecoder {
         Ok(())
     }
 
+    fn read_interval(&self, column: &mut Vec<Interval>, data: &[u8]) -> Result<()> {
+        let data = data
+            .to_str()
+            .map_err(|_| ErrorCode::BadBytes("Incorrect interval value, not utf8"))?;
+        column.push(string_to_interval(data)?);
+        Ok(())
+    }
+
+    /// Values carry their own ISO-8601 offset, e.g. `2024-01-01T10:00:00+08:00`;
+    /// values without one are read in the session timezone.
+    fn read_timestamp_tz(&self, column: &mut Vec<TimestampTz>, data: &[u8]) -> Result<()> {
+        column.push(string_to_timestamp_tz(
+            data,
+            self.common_settings().timezone,
+            self.common_settings().enable_dst_hour_fix,
+        )?);
+        Ok(())
+    }
+
+    fn read_time(&self, column: &mut Vec<i64>, data: &[u8]) -> Result<()> {
+        column.push(string_to_time(data)?);
+        Ok(())
+    }
+
+    fn read_uuid(&self, column: &mut Vec<u128>, data: &[u8]) -> Result<()> {
+        column.push(string_to_uuid(data)?);
+        Ok(())
+    }
+
+    fn read_enum(&self, column: &mut EnumColumnBuilder, data: &[u8]) -> Result<()> {
+        column.push(string_to_enum(&column.items, data)?);
+        Ok(())
+    }
+
     fn read_bitmap(&self, column: &mut BinaryColumnBuilder, data: &[u8]) -> Result<()> {
         let rb = parse_bitmap(data)?;
         rb.serialize_into(&mut column.data).unwrap();
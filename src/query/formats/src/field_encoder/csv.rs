@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use databend_common_expression::types::nullable::NullableColumn;
+use databend_common_expression::types::AggregateStateColumn;
 use databend_common_expression::types::ValueType;
 use databend_common_expression::Column;
 use databend_common_io::constants::FALSE_BYTES_LOWER;
@@ -125,7 +126,7 @@ impl FieldEncoderCSV {
         match &column {
             Column::Nullable(box c) => self.write_nullable(c, row_index, out_buf),
 
-            Column::Binary(c) => {
+            Column::Binary(c) | Column::AggregateState(AggregateStateColumn { states: c, .. }) => {
                 let buf = unsafe { c.index_unchecked(row_index) };
                 let encoded = encode_binary(buf, self.simple.common_settings.binary_format);
                 out_buf.extend_from_slice(&encoded);
@@ -134,8 +135,20 @@ impl FieldEncoderCSV {
                 let buf = unsafe { c.index_unchecked(row_index) };
                 self.string_formatter.write_string(buf.as_bytes(), out_buf);
             }
+            Column::Enum(c) => {
+                let code = unsafe { c.index_unchecked(row_index) };
+                let item = c.items.item(code);
+                self.string_formatter.write_string(item.as_bytes(), out_buf);
+            }
 
-            Column::Date(..) | Column::Timestamp(..) | Column::Bitmap(..) | Column::Variant(..) => {
+            Column::Date(..)
+            | Column::Timestamp(..)
+            | Column::Interval(..)
+            | Column::TimestampTz(..)
+            | Column::Time(..)
+            | Column::Uuid(..)
+            | Column::Bitmap(..)
+            | Column::Variant(..) => {
                 let mut buf = Vec::new();
                 self.simple.write_field(column, row_index, &mut buf, false);
                 self.string_formatter.write_string(&buf, out_buf);
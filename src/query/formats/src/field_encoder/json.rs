@@ -14,6 +14,7 @@
 
 use databend_common_expression::types::array::ArrayColumn;
 use databend_common_expression::types::nullable::NullableColumn;
+use databend_common_expression::types::AggregateStateColumn;
 use databend_common_expression::types::ValueType;
 use databend_common_expression::Column;
 use databend_common_io::constants::FALSE_BYTES_LOWER;
@@ -60,7 +61,7 @@ impl FieldEncoderJSON {
         match &column {
             Column::Nullable(box c) => self.write_nullable(c, row_index, out_buf),
 
-            Column::Binary(c) => {
+            Column::Binary(c) | Column::AggregateState(AggregateStateColumn { states: c, .. }) => {
                 let buf = unsafe { c.index_unchecked(row_index) };
                 self.write_string(buf, out_buf);
             }
@@ -68,8 +69,18 @@ impl FieldEncoderJSON {
                 let buf = unsafe { c.index_unchecked(row_index) };
                 self.write_string(buf.as_bytes(), out_buf);
             }
+            Column::Enum(c) => {
+                let code = unsafe { c.index_unchecked(row_index) };
+                self.write_string(c.items.item(code).as_bytes(), out_buf);
+            }
 
-            Column::Date(..) | Column::Timestamp(..) | Column::Bitmap(..) => {
+            Column::Date(..)
+            | Column::Timestamp(..)
+            | Column::Interval(..)
+            | Column::TimestampTz(..)
+            | Column::Time(..)
+            | Column::Uuid(..)
+            | Column::Bitmap(..) => {
                 let mut buf = Vec::new();
                 self.simple.write_field(column, row_index, &mut buf, false);
                 self.write_string(&buf, out_buf);
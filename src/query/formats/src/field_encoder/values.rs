@@ -24,8 +24,13 @@ use databend_common_expression::types::decimal::DecimalColumn;
 use databend_common_expression::types::geography::GeographyColumn;
 use databend_common_expression::types::nullable::NullableColumn;
 use databend_common_expression::types::string::StringColumn;
+use databend_common_expression::types::time::time_to_string;
 use databend_common_expression::types::timestamp::timestamp_to_string;
+use databend_common_expression::types::uuid::uuid_to_string;
+use databend_common_expression::types::EnumColumn;
+use databend_common_expression::types::Interval;
 use databend_common_expression::types::NumberColumn;
+use databend_common_expression::types::TimestampTz;
 use databend_common_expression::types::ValueType;
 use databend_common_expression::Column;
 use databend_common_io::constants::FALSE_BYTES_NUM;
@@ -139,9 +144,15 @@ impl FieldEncoderValues {
             Column::Nullable(box c) => self.write_nullable(c, row_index, out_buf, in_nested),
 
             Column::Binary(c) => self.write_binary(c, row_index, out_buf),
+            Column::AggregateState(c) => self.write_binary(&c.states, row_index, out_buf),
             Column::String(c) => self.write_string(c, row_index, out_buf, in_nested),
             Column::Date(c) => self.write_date(c, row_index, out_buf, in_nested),
             Column::Timestamp(c) => self.write_timestamp(c, row_index, out_buf, in_nested),
+            Column::Interval(c) => self.write_interval(c, row_index, out_buf, in_nested),
+            Column::TimestampTz(c) => self.write_timestamp_tz(c, row_index, out_buf, in_nested),
+            Column::Time(c) => self.write_time(c, row_index, out_buf, in_nested),
+            Column::Uuid(c) => self.write_uuid(c, row_index, out_buf, in_nested),
+            Column::Enum(c) => self.write_enum(c, row_index, out_buf, in_nested),
             Column::Bitmap(b) => self.write_bitmap(b, row_index, out_buf, in_nested),
             Column::Variant(c) => self.write_variant(c, row_index, out_buf, in_nested),
             Column::Geometry(c) => self.write_geometry(c, row_index, out_buf, in_nested),
@@ -277,6 +288,65 @@ impl FieldEncoderValues {
         self.write_string_inner(s.as_bytes(), out_buf, in_nested);
     }
 
+    fn write_interval(
+        &self,
+        column: &Buffer<Interval>,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        in_nested: bool,
+    ) {
+        let v = unsafe { column.get_unchecked(row_index) };
+        let s = v.to_string();
+        self.write_string_inner(s.as_bytes(), out_buf, in_nested);
+    }
+
+    fn write_timestamp_tz(
+        &self,
+        column: &Buffer<TimestampTz>,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        in_nested: bool,
+    ) {
+        let v = unsafe { column.get_unchecked(row_index) };
+        let s = v.to_string();
+        self.write_string_inner(s.as_bytes(), out_buf, in_nested);
+    }
+
+    fn write_time(
+        &self,
+        column: &Buffer<i64>,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        in_nested: bool,
+    ) {
+        let v = unsafe { column.get_unchecked(row_index) };
+        let s = time_to_string(*v).to_string();
+        self.write_string_inner(s.as_bytes(), out_buf, in_nested);
+    }
+
+    fn write_uuid(
+        &self,
+        column: &Buffer<u128>,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        in_nested: bool,
+    ) {
+        let v = unsafe { column.get_unchecked(row_index) };
+        let s = uuid_to_string(*v).to_string();
+        self.write_string_inner(s.as_bytes(), out_buf, in_nested);
+    }
+
+    fn write_enum(
+        &self,
+        column: &EnumColumn,
+        row_index: usize,
+        out_buf: &mut Vec<u8>,
+        in_nested: bool,
+    ) {
+        let code = unsafe { column.index_unchecked(row_index) };
+        self.write_string_inner(column.items.item(code).as_bytes(), out_buf, in_nested);
+    }
+
     fn write_bitmap(
         &self,
         _column: &BinaryColumn,
@@ -14,6 +14,8 @@
 
 use databend_common_expression::date_helper::DateConverter;
 use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::time::time_to_string;
+use databend_common_expression::types::uuid::uuid_to_string;
 use databend_common_expression::DataBlock;
 use databend_common_expression::ScalarRef;
 use databend_common_expression::TableSchemaRef;
@@ -101,9 +103,16 @@ fn scalar_to_json(s: ScalarRef<'_>, format: &FormatSettings) -> JsonValue {
             let dt = DateConverter::to_timestamp(&v, format.timezone);
             serde_json::to_value(dt.format("%Y-%m-%d %H:%M:%S").to_string()).unwrap()
         }
+        ScalarRef::Interval(v) => JsonValue::String(v.to_string()),
+        ScalarRef::TimestampTz(v) => JsonValue::String(v.to_string()),
+        ScalarRef::Time(v) => JsonValue::String(time_to_string(v).to_string()),
+        ScalarRef::Uuid(v) => JsonValue::String(uuid_to_string(v).to_string()),
+        ScalarRef::Enum(code, items) => JsonValue::String(items.item(code).to_string()),
         ScalarRef::EmptyArray => JsonValue::Array(vec![]),
         ScalarRef::EmptyMap => JsonValue::Object(JsonMap::new()),
-        ScalarRef::Binary(x) => JsonValue::String(hex::encode_upper(x)),
+        ScalarRef::Binary(x) | ScalarRef::AggregateState(x, _) => {
+            JsonValue::String(hex::encode_upper(x))
+        }
         ScalarRef::String(x) => JsonValue::String(x.to_string()),
         ScalarRef::Array(x) => {
             let vals = x
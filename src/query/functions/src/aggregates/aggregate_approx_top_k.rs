@@ -0,0 +1,283 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::AggregateFunctionRef;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+
+use super::FunctionData;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregate_unary::UnaryState;
+use crate::aggregates::assert_variadic_arguments;
+use crate::aggregates::AggregateUnaryFunction;
+
+const DEFAULT_K: usize = 10;
+// The number of counters kept for each of the top k values, more counters
+// make the counts of the top k values more accurate.
+const COUNTERS_PER_K: u64 = 3;
+
+struct ApproxTopKData {
+    pub k: usize,
+    pub data_type: DataType,
+}
+
+impl FunctionData for ApproxTopKData {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[derive(Clone, BorshSerialize, BorshDeserialize)]
+struct TopKCounter {
+    value: Scalar,
+    count: u64,
+    // Upper bound of the overestimation of `count`
+    error: u64,
+}
+
+/// The Space-Saving summary of the most frequent values, see "Efficient
+/// Computation of Frequent and Top-k Elements in Data Streams" (Metwally et al.).
+/// Two summaries are merged as described in "Mergeable Summaries" (Agarwal et al.).
+#[derive(Default)]
+pub struct ApproxTopKState {
+    capacity: u64,
+    counters: Vec<TopKCounter>,
+    index: HashMap<Scalar, usize>,
+}
+
+impl ApproxTopKState {
+    fn from_counters(capacity: u64, counters: Vec<TopKCounter>) -> Self {
+        let index = counters
+            .iter()
+            .enumerate()
+            .map(|(i, counter)| (counter.value.clone(), i))
+            .collect();
+        Self {
+            capacity,
+            counters,
+            index,
+        }
+    }
+
+    fn is_full(&self) -> bool {
+        self.counters.len() as u64 >= self.capacity
+    }
+
+    // The count of any value that is not tracked by a full summary is at most
+    // the minimum count.
+    fn min_count(&self) -> u64 {
+        if self.is_full() {
+            self.counters.iter().map(|c| c.count).min().unwrap_or(0)
+        } else {
+            0
+        }
+    }
+
+    fn insert(&mut self, value: Scalar) {
+        if let Some(i) = self.index.get(&value) {
+            self.counters[*i].count += 1;
+            return;
+        }
+        if !self.is_full() {
+            self.index.insert(value.clone(), self.counters.len());
+            self.counters.push(TopKCounter {
+                value,
+                count: 1,
+                error: 0,
+            });
+            return;
+        }
+
+        // Replace the value with the minimum count
+        let (i, _) = self
+            .counters
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, c)| c.count)
+            .unwrap();
+        let counter = &mut self.counters[i];
+        self.index.remove(&counter.value);
+        self.index.insert(value.clone(), i);
+        counter.value = value;
+        counter.error = counter.count;
+        counter.count += 1;
+    }
+
+    fn sorted_counters(&self) -> Vec<&TopKCounter> {
+        let mut counters = self.counters.iter().collect::<Vec<_>>();
+        counters.sort_by(|a, b| b.count.cmp(&a.count));
+        counters
+    }
+}
+
+impl BorshSerialize for ApproxTopKState {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        BorshSerialize::serialize(&self.capacity, writer)?;
+        BorshSerialize::serialize(&self.counters, writer)
+    }
+}
+
+impl BorshDeserialize for ApproxTopKState {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let capacity = u64::deserialize_reader(reader)?;
+        let counters = Vec::<TopKCounter>::deserialize_reader(reader)?;
+        Ok(Self::from_counters(capacity, counters))
+    }
+}
+
+impl UnaryState<AnyType, AnyType> for ApproxTopKState {
+    fn add(
+        &mut self,
+        other: ScalarRef<'_>,
+        function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        if self.capacity == 0 {
+            let data = unsafe {
+                function_data
+                    .unwrap()
+                    .as_any()
+                    .downcast_ref_unchecked::<ApproxTopKData>()
+            };
+            self.capacity = data.k as u64 * COUNTERS_PER_K;
+        }
+        self.insert(other.to_owned());
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        if rhs.counters.is_empty() {
+            return Ok(());
+        }
+        let capacity = self.capacity.max(rhs.capacity);
+        let self_min = self.min_count();
+        let rhs_min = rhs.min_count();
+
+        let mut counters = Vec::with_capacity(self.counters.len() + rhs.counters.len());
+        for counter in self.counters.iter() {
+            let (count, error) = match rhs.index.get(&counter.value) {
+                Some(i) => (rhs.counters[*i].count, rhs.counters[*i].error),
+                None => (rhs_min, rhs_min),
+            };
+            counters.push(TopKCounter {
+                value: counter.value.clone(),
+                count: counter.count + count,
+                error: counter.error + error,
+            });
+        }
+        for counter in rhs.counters.iter() {
+            if !self.index.contains_key(&counter.value) {
+                counters.push(TopKCounter {
+                    value: counter.value.clone(),
+                    count: counter.count + self_min,
+                    error: counter.error + self_min,
+                });
+            }
+        }
+        counters.sort_by(|a, b| b.count.cmp(&a.count));
+        counters.truncate(capacity as usize);
+
+        *self = Self::from_counters(capacity, counters);
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut ColumnBuilder,
+        function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        let data = unsafe {
+            function_data
+                .unwrap()
+                .as_any()
+                .downcast_ref_unchecked::<ApproxTopKData>()
+        };
+
+        let counters = self.sorted_counters();
+        let len = counters.len().min(data.k);
+        let item_type = top_k_item_type(&data.data_type);
+        let mut items = ColumnBuilder::with_capacity(&item_type, len);
+        for counter in counters.into_iter().take(len) {
+            items.push(ScalarRef::Tuple(vec![
+                counter.value.as_ref(),
+                ScalarRef::Number(NumberScalar::UInt64(counter.count)),
+            ]));
+        }
+        builder.push(ScalarRef::Array(items.build()));
+        Ok(())
+    }
+}
+
+// Each item is a tuple of the value and its approximate count.
+fn top_k_item_type(data_type: &DataType) -> DataType {
+    DataType::Tuple(vec![
+        data_type.clone(),
+        DataType::Number(NumberDataType::UInt64),
+    ])
+}
+
+pub fn try_create_aggregate_approx_top_k_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_variadic_arguments(display_name, arguments.len(), (1, 2))?;
+
+    let data_type = arguments[0].clone();
+    let k = get_k(&params, display_name)?;
+    let return_type = DataType::Array(Box::new(top_k_item_type(&data_type)));
+
+    let func = AggregateUnaryFunction::<ApproxTopKState, AnyType, AnyType>::try_create(
+        display_name,
+        return_type,
+        params,
+        data_type.clone(),
+    )
+    .with_function_data(Box::new(ApproxTopKData { k, data_type }))
+    .with_need_drop(true);
+    Ok(Arc::new(func))
+}
+
+pub fn aggregate_approx_top_k_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_approx_top_k_function))
+}
+
+fn get_k(params: &[Scalar], display_name: &str) -> Result<usize> {
+    if params.len() != 1 {
+        return Ok(DEFAULT_K);
+    }
+    if let Scalar::Number(number) = params[0] {
+        if let Some(number) = number.integer_to_i128() {
+            if number > 0 && number <= u32::MAX as i128 {
+                return Ok(number as usize);
+            }
+        }
+    }
+    Err(ErrorCode::BadDataValueType(format!(
+        "The k of aggregate function {} must be positive int",
+        display_name
+    )))
+}
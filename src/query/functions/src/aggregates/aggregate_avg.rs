@@ -201,6 +201,46 @@ where
     }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Default)]
+struct IntervalAvgState {
+    pub value: Interval,
+    pub count: u64,
+}
+
+impl UnaryState<IntervalType, IntervalType> for IntervalAvgState {
+    fn add(&mut self, other: Interval, _function_data: Option<&dyn FunctionData>) -> Result<()> {
+        self.count += 1;
+        self.value = self.value.checked_add(&other).ok_or_else(|| {
+            ErrorCode::Overflow(format!("Interval overflow: {} + {}", self.value, other))
+        })?;
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        self.count += rhs.count;
+        self.value = self.value.checked_add(&rhs.value).ok_or_else(|| {
+            ErrorCode::Overflow(format!("Interval overflow: {} + {}", self.value, rhs.value))
+        })?;
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut Vec<Interval>,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        // `count` is never zero here, empty groups are handled by the null adaptor.
+        let value = self.value.checked_div(self.count as i64).ok_or_else(|| {
+            ErrorCode::Overflow(format!(
+                "Interval overflow: {} / {}",
+                self.value, self.count
+            ))
+        })?;
+        builder.push(value);
+        Ok(())
+    }
+}
+
 pub fn try_create_aggregate_avg_function(
     display_name: &str,
     params: Vec<Scalar>,
@@ -291,6 +331,14 @@ pub fn try_create_aggregate_avg_function(
                 Ok(Arc::new(func))
             }
         }
+        DataType::Interval => {
+            AggregateUnaryFunction::<IntervalAvgState, IntervalType, IntervalType>::try_create_unary(
+                display_name,
+                IntervalType::data_type(),
+                params,
+                arguments[0].clone(),
+            )
+        }
         _ => Err(ErrorCode::BadDataValueType(format!(
             "{} does not support type '{:?}'",
             display_name, arguments[0]
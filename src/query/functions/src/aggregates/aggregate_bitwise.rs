@@ -0,0 +1,183 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::nullable::NullableColumnBuilder;
+use databend_common_expression::types::number::*;
+use databend_common_expression::types::*;
+use databend_common_expression::with_integer_mapped_type;
+use databend_common_expression::AggregateFunctionRef;
+use databend_common_expression::Scalar;
+use num_traits::AsPrimitive;
+
+use super::assert_unary_arguments;
+use super::FunctionData;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregate_unary::AggregateUnaryFunction;
+use crate::aggregates::aggregate_unary::UnaryState;
+
+const BIT_AND: u8 = 0;
+const BIT_OR: u8 = 1;
+const BIT_XOR: u8 = 2;
+
+/// Folds the bits of the values with `OP`, integers are sign-extended to 64
+/// bits and booleans are 0 or 1, so the result is truncated back losslessly.
+/// The value is `None` until the first non-NULL value is aggregated.
+#[derive(Default, BorshSerialize, BorshDeserialize)]
+pub struct BitwiseState<const OP: u8> {
+    pub value: Option<u64>,
+}
+
+impl<const OP: u8> BitwiseState<OP> {
+    #[inline(always)]
+    fn apply(&mut self, other: u64) {
+        self.value = Some(match self.value {
+            None => other,
+            Some(value) => match OP {
+                BIT_AND => value & other,
+                BIT_OR => value | other,
+                _ => value ^ other,
+            },
+        });
+    }
+}
+
+impl<N, const OP: u8> UnaryState<NumberType<N>, NullableType<NumberType<N>>> for BitwiseState<OP>
+where
+    N: Number + AsPrimitive<u64>,
+    u64: AsPrimitive<N>,
+{
+    fn add(&mut self, other: N, _function_data: Option<&dyn FunctionData>) -> Result<()> {
+        self.apply(other.as_());
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        if let Some(value) = rhs.value {
+            self.apply(value);
+        }
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut NullableColumnBuilder<NumberType<N>>,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        match self.value {
+            Some(value) => builder.push(value.as_()),
+            None => builder.push_null(),
+        }
+        Ok(())
+    }
+}
+
+impl<const OP: u8> UnaryState<BooleanType, NullableType<BooleanType>> for BitwiseState<OP> {
+    fn add(&mut self, other: bool, _function_data: Option<&dyn FunctionData>) -> Result<()> {
+        self.apply(other as u64);
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        if let Some(value) = rhs.value {
+            self.apply(value);
+        }
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut NullableColumnBuilder<BooleanType>,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        match self.value {
+            Some(value) => builder.push(value != 0),
+            None => builder.push_null(),
+        }
+        Ok(())
+    }
+}
+
+pub fn try_create_aggregate_bitwise_function<const OP: u8>(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    with_integer_mapped_type!(|NUM| match &arguments[0] {
+        DataType::Number(NumberDataType::NUM) => {
+            let return_type = arguments[0].wrap_nullable();
+            AggregateUnaryFunction::<
+                BitwiseState<OP>,
+                NumberType<NUM>,
+                NullableType<NumberType<NUM>>,
+            >::try_create_unary(display_name, return_type, params, arguments[0].clone())
+        }
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}'",
+            display_name, arguments[0]
+        ))),
+    })
+}
+
+pub fn try_create_aggregate_bool_function<const OP: u8>(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    match &arguments[0] {
+        DataType::Boolean => {
+            let return_type = arguments[0].wrap_nullable();
+            AggregateUnaryFunction::<
+                BitwiseState<OP>,
+                BooleanType,
+                NullableType<BooleanType>,
+            >::try_create_unary(display_name, return_type, params, arguments[0].clone())
+        }
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "{} does not support type '{:?}'",
+            display_name, arguments[0]
+        ))),
+    }
+}
+
+pub fn aggregate_bit_and_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_bitwise_function::<BIT_AND>,
+    ))
+}
+
+pub fn aggregate_bit_or_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_bitwise_function::<BIT_OR>))
+}
+
+pub fn aggregate_bit_xor_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_bitwise_function::<BIT_XOR>,
+    ))
+}
+
+pub fn aggregate_bool_and_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_bool_function::<BIT_AND>))
+}
+
+pub fn aggregate_bool_or_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_bool_function::<BIT_OR>))
+}
@@ -0,0 +1,176 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::sync::Arc;
+
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::AggregateStateColumn;
+use databend_common_expression::types::DataType;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::InputColumns;
+use databend_common_expression::Scalar;
+
+use super::AggregateFunctionFactory;
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionCreator;
+use crate::aggregates::aggregate_function_factory::CombinatorDescription;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+/// The `_merge` combinator merges the states produced by the `_state`
+/// combinator of the same aggregate function and returns the final result.
+///
+/// The signature of the states is checked against the function when it is
+/// created, and the state of the nested function is used as its own state.
+#[derive(Clone)]
+pub struct AggregateMergeCombinator {
+    name: String,
+    nested: AggregateFunctionRef,
+}
+
+impl AggregateMergeCombinator {
+    pub fn try_create(
+        nested_name: &str,
+        params: Vec<Scalar>,
+        arguments: Vec<DataType>,
+        _nested_creator: &AggregateFunctionCreator,
+    ) -> Result<AggregateFunctionRef> {
+        let name = format!("MergeCombinator({nested_name})");
+        if arguments.len() != 1 {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "{name} expect to have one argument, but got {}",
+                arguments.len()
+            )));
+        }
+
+        let signature = match &arguments[0] {
+            DataType::AggregateState(signature) => signature,
+            other => {
+                return Err(ErrorCode::BadArguments(format!(
+                    "The argument of {name} must be an aggregate state produced by {nested_name}_state, but got {other}"
+                )));
+            }
+        };
+        if signature.name() != nested_name || signature.params() != params.as_slice() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Cannot merge the aggregate state of {} with {name}",
+                signature.function_name()
+            )));
+        }
+
+        let nested = AggregateFunctionFactory::instance().get(
+            nested_name,
+            params,
+            signature.arguments().to_vec(),
+        )?;
+
+        Ok(Arc::new(AggregateMergeCombinator { name, nested }))
+    }
+
+    pub fn combinator_desc() -> CombinatorDescription {
+        CombinatorDescription::creator(Box::new(Self::try_create))
+    }
+
+    fn merge_row(&self, place: StateAddr, states: &AggregateStateColumn, row: usize) -> Result<()> {
+        let mut reader = states.index(row).unwrap();
+        self.nested.merge(place, &mut reader)
+    }
+}
+
+impl AggregateFunction for AggregateMergeCombinator {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        self.nested.return_type()
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        self.nested.init_state(place);
+    }
+
+    fn state_layout(&self) -> Layout {
+        self.nested.state_layout()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: InputColumns,
+        validity: Option<&Bitmap>,
+        input_rows: usize,
+    ) -> Result<()> {
+        let states = columns[0].as_aggregate_state().unwrap();
+        for row in 0..input_rows {
+            if validity.map_or(true, |validity| validity.get_bit(row)) {
+                self.merge_row(place, states, row)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: InputColumns,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let states = columns[0].as_aggregate_state().unwrap();
+        for (row, place) in places.iter().enumerate() {
+            self.merge_row(place.next(offset), states, row)?;
+        }
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: InputColumns, row: usize) -> Result<()> {
+        let states = columns[0].as_aggregate_state().unwrap();
+        self.merge_row(place, states, row)
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        self.nested.serialize(place, writer)
+    }
+
+    fn merge(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        self.nested.merge(place, reader)
+    }
+
+    fn merge_states(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        self.nested.merge_states(place, rhs)
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        self.nested.merge_result(place, builder)
+    }
+
+    fn need_manual_drop_state(&self) -> bool {
+        self.nested.need_manual_drop_state()
+    }
+
+    unsafe fn drop_state(&self, place: StateAddr) {
+        self.nested.drop_state(place);
+    }
+}
+
+impl fmt::Display for AggregateMergeCombinator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
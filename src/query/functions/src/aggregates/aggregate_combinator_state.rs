@@ -18,6 +18,7 @@ use std::sync::Arc;
 
 use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_exception::Result;
+use databend_common_expression::types::AggregateStateSignature;
 use databend_common_expression::types::DataType;
 use databend_common_expression::ColumnBuilder;
 use databend_common_expression::InputColumns;
@@ -30,10 +31,13 @@ use crate::aggregates::aggregate_function_factory::CombinatorDescription;
 use crate::aggregates::AggregateFunction;
 use crate::aggregates::AggregateFunctionRef;
 
+/// The `_state` combinator returns the serialized state of the aggregate
+/// function as an `AGGREGATE_STATE` carrying the signature of the function.
 #[derive(Clone)]
 pub struct AggregateStateCombinator {
     name: String,
     nested: AggregateFunctionRef,
+    signature: AggregateStateSignature,
 }
 
 impl AggregateStateCombinator {
@@ -51,9 +55,15 @@ impl AggregateStateCombinator {
 
         let name = format!("StateCombinator({nested_name}, {arg_name})");
 
+        let signature =
+            AggregateStateSignature::new(nested_name, params.clone(), arguments.clone());
         let nested = AggregateFunctionFactory::instance().get(nested_name, params, arguments)?;
 
-        Ok(Arc::new(AggregateStateCombinator { name, nested }))
+        Ok(Arc::new(AggregateStateCombinator {
+            name,
+            nested,
+            signature,
+        }))
     }
 
     pub fn combinator_desc() -> CombinatorDescription {
@@ -67,7 +77,7 @@ impl AggregateFunction for AggregateStateCombinator {
     }
 
     fn return_type(&self) -> Result<DataType> {
-        Ok(DataType::Binary)
+        Ok(DataType::AggregateState(self.signature.clone()))
     }
 
     fn init_state(&self, place: StateAddr) {
@@ -120,9 +130,9 @@ impl AggregateFunction for AggregateStateCombinator {
     }
 
     fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
-        let str_builder = builder.as_binary_mut().unwrap();
-        self.serialize(place, &mut str_builder.data)?;
-        str_builder.commit_row();
+        let builder = builder.as_aggregate_state_mut().unwrap();
+        self.serialize(place, &mut builder.states.data)?;
+        builder.states.commit_row();
         Ok(())
     }
 
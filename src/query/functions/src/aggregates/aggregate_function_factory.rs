@@ -186,14 +186,18 @@ impl AggregateFunctionFactory {
         }
 
         if !arguments.is_empty() && arguments.iter().any(|f| f.is_nullable_or_null()) {
-            let (new_params, new_arguments) = match name.to_lowercase().strip_suffix(STATE_SUFFIX) {
-                Some(_) => (params.clone(), arguments.clone()),
-                None => {
-                    let new_params = AggregateFunctionCombinatorNull::transform_params(&params)?;
-                    let new_arguments =
-                        AggregateFunctionCombinatorNull::transform_arguments(&arguments)?;
-                    (new_params, new_arguments)
-                }
+            let lowercase_name = name.to_lowercase();
+            // The `_state` combinator keeps the nullable arguments, but functions
+            // like `theta_sketch_state` are not combinators.
+            let is_state_combinator = lowercase_name.ends_with(STATE_SUFFIX)
+                && !self.case_insensitive_desc.contains_key(&lowercase_name);
+            let (new_params, new_arguments) = if is_state_combinator {
+                (params.clone(), arguments.clone())
+            } else {
+                let new_params = AggregateFunctionCombinatorNull::transform_params(&params)?;
+                let new_arguments =
+                    AggregateFunctionCombinatorNull::transform_arguments(&arguments)?;
+                (new_params, new_arguments)
             };
 
             let nested = self.get_impl(name, new_params, new_arguments, &mut features)?;
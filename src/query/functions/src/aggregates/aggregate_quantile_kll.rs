@@ -0,0 +1,239 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::*;
+use databend_common_expression::types::*;
+use databend_common_expression::with_number_mapped_type;
+use databend_common_expression::AggregateFunctionRef;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use num_traits::AsPrimitive;
+
+use super::FunctionData;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregate_quantile_cont::get_levels;
+use crate::aggregates::aggregate_quantile_tdigest::MEDIAN;
+use crate::aggregates::aggregate_quantile_tdigest::QUANTILE;
+use crate::aggregates::aggregate_unary::UnaryState;
+use crate::aggregates::assert_params;
+use crate::aggregates::assert_unary_arguments;
+use crate::aggregates::AggregateUnaryFunction;
+
+// The capacity of the top compactor, the rank error is about 1.65 / k.
+const KLL_K: usize = 200;
+// The capacity of a compactor is 2/3 of the capacity of the compactor above it.
+const KLL_C: f64 = 2.0 / 3.0;
+const KLL_MIN_CAPACITY: usize = 2;
+
+struct QuantileKllData {
+    pub levels: Vec<f64>,
+}
+
+impl FunctionData for QuantileKllData {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// The KLL quantile sketch, see "Optimal Quantile Approximation in Streams"
+/// (Karnin, Lang and Liberty). An item in the compactor of level `h` stands
+/// for `2^h` values, a full compactor is sorted and half of its items are
+/// promoted to the next level.
+#[derive(Default, BorshSerialize, BorshDeserialize)]
+pub struct QuantileKllState {
+    compactors: Vec<Vec<f64>>,
+    // Alternates which half of a compactor is promoted, so that the results
+    // are deterministic and unbiased.
+    promote_odd: bool,
+}
+
+impl QuantileKllState {
+    fn capacity(&self, level: usize) -> usize {
+        let depth = self.compactors.len() - level - 1;
+        let capacity = (KLL_K as f64 * KLL_C.powi(depth as i32)).ceil() as usize;
+        capacity.max(KLL_MIN_CAPACITY)
+    }
+
+    fn size(&self) -> usize {
+        self.compactors.iter().map(|c| c.len()).sum()
+    }
+
+    fn max_size(&self) -> usize {
+        (0..self.compactors.len()).map(|l| self.capacity(l)).sum()
+    }
+
+    fn insert(&mut self, value: f64) {
+        if self.compactors.is_empty() {
+            self.compactors.push(vec![]);
+        }
+        self.compactors[0].push(value);
+        self.compress();
+    }
+
+    fn compress(&mut self) {
+        while self.size() > self.max_size() {
+            let level = (0..self.compactors.len())
+                .find(|l| self.compactors[*l].len() >= self.capacity(*l))
+                .unwrap();
+            if level + 1 == self.compactors.len() {
+                self.compactors.push(vec![]);
+            }
+
+            let mut items = std::mem::take(&mut self.compactors[level]);
+            items.sort_by(|a, b| a.total_cmp(b));
+            if items.len() % 2 == 1 {
+                self.compactors[level].push(items.pop().unwrap());
+            }
+            let offset = self.promote_odd as usize;
+            self.promote_odd = !self.promote_odd;
+            self.compactors[level + 1].extend(items.into_iter().skip(offset).step_by(2));
+        }
+    }
+
+    fn quantile(&self, level: f64) -> f64 {
+        let mut items = self
+            .compactors
+            .iter()
+            .enumerate()
+            .flat_map(|(h, c)| c.iter().map(move |v| (*v, 1u64 << h)))
+            .collect::<Vec<_>>();
+        if items.is_empty() {
+            return 0f64;
+        }
+        items.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        let total_weight = items.iter().map(|(_, w)| w).sum::<u64>();
+        let rank = (level * total_weight as f64).max(1.0);
+        let mut weight = 0;
+        for (value, w) in items.iter() {
+            weight += w;
+            if weight as f64 >= rank {
+                return *value;
+            }
+        }
+        items.last().unwrap().0
+    }
+}
+
+impl<T> UnaryState<T, AnyType> for QuantileKllState
+where
+    T: ValueType + Sync + Send,
+    T::Scalar: AsPrimitive<f64>,
+{
+    fn add(
+        &mut self,
+        other: T::ScalarRef<'_>,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        self.insert(T::to_owned_scalar(other).as_());
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        for (level, compactor) in rhs.compactors.iter().enumerate() {
+            if level == self.compactors.len() {
+                self.compactors.push(vec![]);
+            }
+            self.compactors[level].extend_from_slice(compactor);
+        }
+        self.compress();
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut ColumnBuilder,
+        function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        let kll_data = unsafe {
+            function_data
+                .unwrap()
+                .as_any()
+                .downcast_ref_unchecked::<QuantileKllData>()
+        };
+
+        if kll_data.levels.len() > 1 {
+            let builder = match builder {
+                ColumnBuilder::Array(box b) => b,
+                _ => unreachable!(),
+            };
+            kll_data.levels.iter().for_each(|level| {
+                let q = self.quantile(*level);
+                builder.put_item(ScalarRef::Number(NumberScalar::Float64(q.into())))
+            });
+            builder.commit_row();
+        } else {
+            let builder = NumberType::<F64>::try_downcast_builder(builder).unwrap();
+            let q = self.quantile(kll_data.levels[0]);
+            builder.push(q.into());
+        }
+        Ok(())
+    }
+}
+
+pub fn try_create_aggregate_quantile_kll_function<const TYPE: u8>(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    if TYPE == MEDIAN {
+        assert_params(display_name, params.len(), 0)?;
+    }
+
+    assert_unary_arguments(display_name, arguments.len())?;
+    let levels = get_levels(&params)?;
+    let return_type = if levels.len() > 1 {
+        DataType::Array(Box::new(DataType::Number(NumberDataType::Float64)))
+    } else {
+        DataType::Number(NumberDataType::Float64)
+    };
+
+    with_number_mapped_type!(|NUM_TYPE| match &arguments[0] {
+        DataType::Number(NumberDataType::NUM_TYPE) => {
+            let func = AggregateUnaryFunction::<
+                QuantileKllState,
+                NumberType<NUM_TYPE>,
+                AnyType,
+            >::try_create(display_name, return_type, params, arguments[0].clone())
+            .with_function_data(Box::new(QuantileKllData { levels }))
+            .with_need_drop(true);
+            Ok(Arc::new(func))
+        }
+
+        _ => Err(ErrorCode::BadDataValueType(format!(
+            "{} just support numeric type, but got '{:?}'",
+            display_name, arguments[0]
+        ))),
+    })
+}
+
+pub fn aggregate_quantile_kll_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_quantile_kll_function::<QUANTILE>,
+    ))
+}
+
+pub fn aggregate_median_kll_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_quantile_kll_function::<MEDIAN>,
+    ))
+}
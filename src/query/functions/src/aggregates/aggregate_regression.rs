@@ -0,0 +1,419 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::alloc::Layout;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::number::Number;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberType;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::with_number_mapped_type;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::InputColumns;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use num_traits::AsPrimitive;
+
+use super::borsh_deserialize_state;
+use super::borsh_serialize_state;
+use super::StateAddr;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionFeatures;
+use crate::aggregates::aggregator_common::assert_binary_arguments;
+use crate::aggregates::AggregateFunction;
+use crate::aggregates::AggregateFunctionRef;
+
+/// Means, second moments and co-moment of the (y, x) pairs, updated with
+/// Welford's algorithm and merged with the pairwise formula of Chan et al.
+#[derive(Default, BorshSerialize, BorshDeserialize)]
+pub struct AggregateRegressionState {
+    pub count: u64,
+    pub x_mean: f64,
+    pub y_mean: f64,
+    pub x_m2: f64,
+    pub y_m2: f64,
+    pub co_moments: f64,
+}
+
+impl AggregateRegressionState {
+    #[inline(always)]
+    fn add(&mut self, y: f64, x: f64) {
+        self.count += 1;
+        let n = self.count as f64;
+
+        let x_delta = x - self.x_mean;
+        let y_delta = y - self.y_mean;
+        self.x_mean += x_delta / n;
+        self.y_mean += y_delta / n;
+
+        self.x_m2 += x_delta * (x - self.x_mean);
+        self.y_m2 += y_delta * (y - self.y_mean);
+        self.co_moments += x_delta * (y - self.y_mean);
+    }
+
+    #[inline(always)]
+    fn merge(&mut self, other: &Self) {
+        if other.count == 0 {
+            return;
+        }
+        if self.count == 0 {
+            *self = Self { ..*other };
+            return;
+        }
+
+        let total = self.count + other.count;
+        let factor = self.count as f64 * other.count as f64 / total as f64;
+        let x_delta = other.x_mean - self.x_mean;
+        let y_delta = other.y_mean - self.y_mean;
+
+        self.x_m2 += other.x_m2 + x_delta * x_delta * factor;
+        self.y_m2 += other.y_m2 + y_delta * y_delta * factor;
+        self.co_moments += other.co_moments + x_delta * y_delta * factor;
+
+        self.x_mean += x_delta * other.count as f64 / total as f64;
+        self.y_mean += y_delta * other.count as f64 / total as f64;
+        self.count = total;
+    }
+}
+
+#[derive(Clone)]
+pub struct AggregateRegressionFunction<T0, T1, R> {
+    display_name: String,
+    _t0: PhantomData<T0>,
+    _t1: PhantomData<T1>,
+    _r: PhantomData<R>,
+}
+
+impl<T0, T1, R> AggregateFunction for AggregateRegressionFunction<T0, T1, R>
+where
+    T0: Number + AsPrimitive<f64>,
+    T1: Number + AsPrimitive<f64>,
+    R: AggregateRegression,
+{
+    fn name(&self) -> &str {
+        R::name()
+    }
+
+    fn return_type(&self) -> Result<DataType> {
+        Ok(R::return_type())
+    }
+
+    fn init_state(&self, place: StateAddr) {
+        place.write(AggregateRegressionState::default);
+    }
+
+    fn state_layout(&self) -> Layout {
+        Layout::new::<AggregateRegressionState>()
+    }
+
+    fn accumulate(
+        &self,
+        place: StateAddr,
+        columns: InputColumns,
+        validity: Option<&Bitmap>,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let state = place.get::<AggregateRegressionState>();
+        let left = NumberType::<T0>::try_downcast_column(&columns[0]).unwrap();
+        let right = NumberType::<T1>::try_downcast_column(&columns[1]).unwrap();
+
+        match validity {
+            Some(bitmap) => {
+                left.iter().zip(right.iter()).zip(bitmap.iter()).for_each(
+                    |((left_val, right_val), valid)| {
+                        if valid {
+                            state.add(left_val.as_(), right_val.as_());
+                        }
+                    },
+                );
+            }
+            None => {
+                left.iter()
+                    .zip(right.iter())
+                    .for_each(|(left_val, right_val)| {
+                        state.add(left_val.as_(), right_val.as_());
+                    });
+            }
+        }
+        Ok(())
+    }
+
+    fn accumulate_keys(
+        &self,
+        places: &[StateAddr],
+        offset: usize,
+        columns: InputColumns,
+        _input_rows: usize,
+    ) -> Result<()> {
+        let left = NumberType::<T0>::try_downcast_column(&columns[0]).unwrap();
+        let right = NumberType::<T1>::try_downcast_column(&columns[1]).unwrap();
+
+        left.iter().zip(right.iter()).zip(places.iter()).for_each(
+            |((left_val, right_val), place)| {
+                let place = place.next(offset);
+                let state = place.get::<AggregateRegressionState>();
+                state.add(left_val.as_(), right_val.as_());
+            },
+        );
+        Ok(())
+    }
+
+    fn accumulate_row(&self, place: StateAddr, columns: InputColumns, row: usize) -> Result<()> {
+        let left = NumberType::<T0>::try_downcast_column(&columns[0]).unwrap();
+        let right = NumberType::<T1>::try_downcast_column(&columns[1]).unwrap();
+
+        let left_val = unsafe { left.get_unchecked(row) };
+        let right_val = unsafe { right.get_unchecked(row) };
+
+        let state = place.get::<AggregateRegressionState>();
+        state.add(left_val.as_(), right_val.as_());
+        Ok(())
+    }
+
+    fn serialize(&self, place: StateAddr, writer: &mut Vec<u8>) -> Result<()> {
+        let state = place.get::<AggregateRegressionState>();
+        borsh_serialize_state(writer, state)
+    }
+
+    fn merge(&self, place: StateAddr, reader: &mut &[u8]) -> Result<()> {
+        let state = place.get::<AggregateRegressionState>();
+        let rhs: AggregateRegressionState = borsh_deserialize_state(reader)?;
+        state.merge(&rhs);
+        Ok(())
+    }
+
+    fn merge_states(&self, place: StateAddr, rhs: StateAddr) -> Result<()> {
+        let state = place.get::<AggregateRegressionState>();
+        let other = rhs.get::<AggregateRegressionState>();
+        state.merge(other);
+        Ok(())
+    }
+
+    fn merge_result(&self, place: StateAddr, builder: &mut ColumnBuilder) -> Result<()> {
+        let state = place.get::<AggregateRegressionState>();
+        R::merge_result(state, builder);
+        Ok(())
+    }
+}
+
+impl<T0, T1, R> fmt::Display for AggregateRegressionFunction<T0, T1, R> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.display_name)
+    }
+}
+
+impl<T0, T1, R> AggregateRegressionFunction<T0, T1, R>
+where
+    T0: Number + AsPrimitive<f64>,
+    T1: Number + AsPrimitive<f64>,
+    R: AggregateRegression,
+{
+    pub fn try_create(
+        display_name: &str,
+        _arguments: Vec<DataType>,
+    ) -> Result<AggregateFunctionRef> {
+        Ok(Arc::new(Self {
+            display_name: display_name.to_string(),
+            _t0: PhantomData,
+            _t1: PhantomData,
+            _r: PhantomData,
+        }))
+    }
+}
+
+/// The arguments are `(y, x)`, `y` is the dependent variable and `x` is the
+/// independent variable, pairs with a NULL value are ignored.
+pub fn try_create_aggregate_regression<R: AggregateRegression>(
+    display_name: &str,
+    _params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_binary_arguments(display_name, arguments.len())?;
+
+    with_number_mapped_type!(|NUM_TYPE0| match &arguments[0] {
+        DataType::Number(NumberDataType::NUM_TYPE0) =>
+            with_number_mapped_type!(|NUM_TYPE1| match &arguments[1] {
+                DataType::Number(NumberDataType::NUM_TYPE1) => {
+                    return AggregateRegressionFunction::<NUM_TYPE0, NUM_TYPE1, R>::try_create(
+                        display_name,
+                        arguments,
+                    );
+                }
+                _ => (),
+            }),
+        _ => (),
+    });
+
+    Err(ErrorCode::BadDataValueType(format!(
+        "Expected number data type, but got {:?}",
+        arguments
+    )))
+}
+
+pub trait AggregateRegression: Send + Sync + 'static {
+    fn name() -> &'static str;
+
+    fn return_type() -> DataType {
+        DataType::Number(NumberDataType::Float64).wrap_nullable()
+    }
+
+    /// Returns `None` if the result is undefined for the aggregated pairs.
+    fn apply(state: &AggregateRegressionState) -> Option<f64>;
+
+    fn merge_result(state: &AggregateRegressionState, builder: &mut ColumnBuilder) {
+        match Self::apply(state) {
+            Some(value) => builder.push(ScalarRef::Number(NumberScalar::Float64(value.into()))),
+            None => builder.push(ScalarRef::Null),
+        }
+    }
+}
+
+macro_rules! impl_aggregate_regression {
+    ($ty: ident, $name: expr, $desc: ident, |$state: ident| $apply: expr) => {
+        struct $ty;
+
+        impl AggregateRegression for $ty {
+            fn name() -> &'static str {
+                $name
+            }
+
+            fn apply($state: &AggregateRegressionState) -> Option<f64> {
+                $apply
+            }
+        }
+
+        pub fn $desc() -> AggregateFunctionDescription {
+            AggregateFunctionDescription::creator(Box::new(try_create_aggregate_regression::<$ty>))
+        }
+    };
+}
+
+impl_aggregate_regression!(
+    AggregateCorrImpl,
+    "AggregateCorrFunction",
+    aggregate_corr_function_desc,
+    |state| {
+        let denominator = (state.x_m2 * state.y_m2).sqrt();
+        (state.count > 0 && denominator != 0.0).then(|| state.co_moments / denominator)
+    }
+);
+
+impl_aggregate_regression!(
+    AggregateRegrSlopeImpl,
+    "AggregateRegrSlopeFunction",
+    aggregate_regr_slope_function_desc,
+    |state| (state.count > 0 && state.x_m2 != 0.0).then(|| state.co_moments / state.x_m2)
+);
+
+impl_aggregate_regression!(
+    AggregateRegrInterceptImpl,
+    "AggregateRegrInterceptFunction",
+    aggregate_regr_intercept_function_desc,
+    |state| {
+        (state.count > 0 && state.x_m2 != 0.0)
+            .then(|| state.y_mean - state.co_moments / state.x_m2 * state.x_mean)
+    }
+);
+
+impl_aggregate_regression!(
+    AggregateRegrR2Impl,
+    "AggregateRegrR2Function",
+    aggregate_regr_r2_function_desc,
+    |state| {
+        if state.count == 0 || state.x_m2 == 0.0 {
+            None
+        } else if state.y_m2 == 0.0 {
+            Some(1.0)
+        } else {
+            Some(state.co_moments * state.co_moments / (state.x_m2 * state.y_m2))
+        }
+    }
+);
+
+impl_aggregate_regression!(
+    AggregateRegrAvgxImpl,
+    "AggregateRegrAvgxFunction",
+    aggregate_regr_avgx_function_desc,
+    |state| (state.count > 0).then_some(state.x_mean)
+);
+
+impl_aggregate_regression!(
+    AggregateRegrAvgyImpl,
+    "AggregateRegrAvgyFunction",
+    aggregate_regr_avgy_function_desc,
+    |state| (state.count > 0).then_some(state.y_mean)
+);
+
+impl_aggregate_regression!(
+    AggregateRegrSxxImpl,
+    "AggregateRegrSxxFunction",
+    aggregate_regr_sxx_function_desc,
+    |state| (state.count > 0).then_some(state.x_m2)
+);
+
+impl_aggregate_regression!(
+    AggregateRegrSyyImpl,
+    "AggregateRegrSyyFunction",
+    aggregate_regr_syy_function_desc,
+    |state| (state.count > 0).then_some(state.y_m2)
+);
+
+impl_aggregate_regression!(
+    AggregateRegrSxyImpl,
+    "AggregateRegrSxyFunction",
+    aggregate_regr_sxy_function_desc,
+    |state| (state.count > 0).then_some(state.co_moments)
+);
+
+// The number of non-NULL pairs, which is zero rather than NULL for empty input.
+struct AggregateRegrCountImpl;
+
+impl AggregateRegression for AggregateRegrCountImpl {
+    fn name() -> &'static str {
+        "AggregateRegrCountFunction"
+    }
+
+    fn return_type() -> DataType {
+        DataType::Number(NumberDataType::UInt64)
+    }
+
+    fn apply(state: &AggregateRegressionState) -> Option<f64> {
+        Some(state.count as f64)
+    }
+
+    fn merge_result(state: &AggregateRegressionState, builder: &mut ColumnBuilder) {
+        builder.push(ScalarRef::Number(NumberScalar::UInt64(state.count)));
+    }
+}
+
+pub fn aggregate_regr_count_function_desc() -> AggregateFunctionDescription {
+    let features = AggregateFunctionFeatures {
+        returns_default_when_only_null: true,
+        ..Default::default()
+    };
+    AggregateFunctionDescription::creator_with_features(
+        Box::new(try_create_aggregate_regression::<AggregateRegrCountImpl>),
+        features,
+    )
+}
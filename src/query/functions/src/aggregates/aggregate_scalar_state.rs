@@ -55,6 +55,8 @@ macro_rules! with_simple_no_number_no_string_mapped_type {
                 EmptyArray => EmptyArrayType,
                 EmptyMap => EmptyMapType,
                 Date => DateType,
+                Time => TimeType,
+                Uuid => UuidType,
             ],
             $($tail)*
         }
@@ -0,0 +1,136 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::geometry::GeometryType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::ValueType;
+use databend_common_expression::AggregateFunctionRef;
+use databend_common_expression::Scalar;
+use geos::Geom;
+use geos::Geometry;
+use geozero::wkb::Ewkb;
+use geozero::CoordDimensions;
+use geozero::GeozeroGeometry;
+use geozero::ToGeos;
+use geozero::ToWkb;
+
+use super::FunctionData;
+use super::UnaryState;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::assert_unary_arguments;
+use crate::aggregates::AggregateUnaryFunction;
+
+/// Number of buffered geometries that triggers folding them into a single union.
+const UNION_BATCH_SIZE: usize = 1024;
+
+/// Keeps the input geometries as EWKB and unions them in batches, so the
+/// state stays bounded without paying a GEOS union for every row.
+#[derive(BorshSerialize, BorshDeserialize, Default)]
+pub struct STUnionAggState {
+    geometries: Vec<Vec<u8>>,
+}
+
+impl STUnionAggState {
+    fn compact(&mut self) -> Result<()> {
+        if self.geometries.len() > 1 {
+            let union = union_geometries(&self.geometries)?;
+            self.geometries = vec![union];
+        }
+        Ok(())
+    }
+}
+
+impl UnaryState<GeometryType, GeometryType> for STUnionAggState {
+    fn add(&mut self, other: &[u8], _function_data: Option<&dyn FunctionData>) -> Result<()> {
+        self.geometries.push(other.to_vec());
+        if self.geometries.len() >= UNION_BATCH_SIZE {
+            self.compact()?;
+        }
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        self.geometries.extend(rhs.geometries.iter().cloned());
+        if self.geometries.len() >= UNION_BATCH_SIZE {
+            self.compact()?;
+        }
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut <GeometryType as ValueType>::ColumnBuilder,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        self.compact()?;
+        match self.geometries.first() {
+            Some(union) => GeometryType::push_item(builder, union.as_slice()),
+            None => GeometryType::push_default(builder),
+        }
+        Ok(())
+    }
+}
+
+fn union_geometries(geometries: &[Vec<u8>]) -> Result<Vec<u8>> {
+    let geos = geometries
+        .iter()
+        .map(|ewkb| {
+            Ewkb(ewkb)
+                .to_geos()
+                .map_err(|e| ErrorCode::GeometryError(e.to_string()))
+        })
+        .collect::<Result<Vec<Geometry>>>()?;
+    let srid = geos[0].srid();
+    if geos.iter().any(|geo| geo.srid() != srid) {
+        return Err(ErrorCode::GeometryError("Srid does not match!"));
+    }
+    Geometry::create_geometry_collection(geos)
+        .and_then(|collection| collection.unary_union())
+        .map_err(|e| ErrorCode::GeometryError(e.to_string()))?
+        .to_ewkb(CoordDimensions::xy(), srid)
+        .map_err(ErrorCode::from)
+}
+
+pub fn try_create_aggregate_st_union_agg_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+    if arguments[0] != DataType::Geometry {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "The argument of aggregate function {} must be geometry",
+            display_name
+        )));
+    }
+
+    let func = AggregateUnaryFunction::<STUnionAggState, GeometryType, GeometryType>::try_create(
+        display_name,
+        DataType::Geometry,
+        params,
+        arguments[0].clone(),
+    )
+    .with_need_drop(true);
+    Ok(Arc::new(func))
+}
+
+pub fn aggregate_st_union_agg_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(try_create_aggregate_st_union_agg_function))
+}
@@ -211,6 +211,33 @@ where
     }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Default)]
+pub struct IntervalSumState {
+    pub value: Interval,
+}
+
+impl UnaryState<IntervalType, IntervalType> for IntervalSumState {
+    fn add(&mut self, other: Interval, _function_data: Option<&dyn FunctionData>) -> Result<()> {
+        self.value = self.value.checked_add(&other).ok_or_else(|| {
+            ErrorCode::Overflow(format!("Interval overflow: {} + {}", self.value, other))
+        })?;
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        self.add(rhs.value, None)
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut Vec<Interval>,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        builder.push(self.value);
+        Ok(())
+    }
+}
+
 pub fn try_create_aggregate_sum_function(
     display_name: &str,
     params: Vec<Scalar>,
@@ -291,6 +318,14 @@ pub fn try_create_aggregate_sum_function(
                 )
             }
         }
+        DataType::Interval => {
+            AggregateUnaryFunction::<IntervalSumState, IntervalType, IntervalType>::try_create_unary(
+                display_name,
+                IntervalType::data_type(),
+                params,
+                arguments[0].clone(),
+            )
+        }
         _ => Err(ErrorCode::BadDataValueType(format!(
             "{} does not support type '{:?}'",
             display_name, arguments[0]
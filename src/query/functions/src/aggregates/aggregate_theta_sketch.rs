@@ -0,0 +1,223 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::sync::Arc;
+
+use borsh::BorshDeserialize;
+use borsh::BorshSerialize;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::binary::BinaryColumnBuilder;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::types::BinaryType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::AggregateFunctionRef;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_io::ThetaSketch;
+use twox_hash::XxHash64;
+
+use super::FunctionData;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionDescription;
+use crate::aggregates::aggregate_function_factory::AggregateFunctionFeatures;
+use crate::aggregates::aggregate_unary::UnaryState;
+use crate::aggregates::assert_unary_arguments;
+use crate::aggregates::AggregateUnaryFunction;
+
+// The hashes are persisted within the sketches, so the seed must never change.
+const THETA_SKETCH_HASH_SEED: u64 = 0;
+
+/// Builds a theta sketch from the hashes of the values.
+impl UnaryState<AnyType, BinaryType> for ThetaSketch {
+    fn add(
+        &mut self,
+        other: ScalarRef<'_>,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        let mut hasher = XxHash64::with_seed(THETA_SKETCH_HASH_SEED);
+        other.hash(&mut hasher);
+        self.update(hasher.finish());
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        self.union(rhs);
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut BinaryColumnBuilder,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        self.serialize(&mut builder.data)?;
+        builder.commit_row();
+        Ok(())
+    }
+}
+
+/// Unions the serialized theta sketches.
+impl UnaryState<BinaryType, BinaryType> for ThetaSketch {
+    fn add(&mut self, other: &[u8], _function_data: Option<&dyn FunctionData>) -> Result<()> {
+        self.union(&ThetaSketch::deserialize(other)?);
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        self.union(rhs);
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut BinaryColumnBuilder,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        self.serialize(&mut builder.data)?;
+        builder.commit_row();
+        Ok(())
+    }
+}
+
+/// Intersects the serialized theta sketches, `None` until the first sketch
+/// is aggregated.
+#[derive(Default, BorshSerialize, BorshDeserialize)]
+pub struct ThetaSketchIntersectState {
+    sketch: Option<ThetaSketch>,
+}
+
+impl ThetaSketchIntersectState {
+    fn intersect(&mut self, other: &ThetaSketch) {
+        match &mut self.sketch {
+            Some(sketch) => sketch.intersect(other),
+            None => self.sketch = Some(other.clone()),
+        }
+    }
+}
+
+impl UnaryState<BinaryType, BinaryType> for ThetaSketchIntersectState {
+    fn add(&mut self, other: &[u8], _function_data: Option<&dyn FunctionData>) -> Result<()> {
+        self.intersect(&ThetaSketch::deserialize(other)?);
+        Ok(())
+    }
+
+    fn merge(&mut self, rhs: &Self) -> Result<()> {
+        if let Some(sketch) = &rhs.sketch {
+            self.intersect(sketch);
+        }
+        Ok(())
+    }
+
+    fn merge_result(
+        &mut self,
+        builder: &mut BinaryColumnBuilder,
+        _function_data: Option<&dyn FunctionData>,
+    ) -> Result<()> {
+        self.sketch
+            .get_or_insert_with(ThetaSketch::default)
+            .serialize(&mut builder.data)?;
+        builder.commit_row();
+        Ok(())
+    }
+}
+
+pub fn try_create_aggregate_theta_sketch_state_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+
+    let func = AggregateUnaryFunction::<ThetaSketch, AnyType, BinaryType>::try_create(
+        display_name,
+        DataType::Binary,
+        params,
+        arguments[0].clone(),
+    )
+    .with_need_drop(true);
+    Ok(Arc::new(func))
+}
+
+pub fn try_create_aggregate_theta_sketch_union_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+    check_sketch_argument(display_name, &arguments[0])?;
+
+    let func = AggregateUnaryFunction::<ThetaSketch, BinaryType, BinaryType>::try_create(
+        display_name,
+        DataType::Binary,
+        params,
+        arguments[0].clone(),
+    )
+    .with_need_drop(true);
+    Ok(Arc::new(func))
+}
+
+pub fn try_create_aggregate_theta_sketch_intersect_function(
+    display_name: &str,
+    params: Vec<Scalar>,
+    arguments: Vec<DataType>,
+) -> Result<AggregateFunctionRef> {
+    assert_unary_arguments(display_name, arguments.len())?;
+    check_sketch_argument(display_name, &arguments[0])?;
+
+    let func =
+        AggregateUnaryFunction::<ThetaSketchIntersectState, BinaryType, BinaryType>::try_create(
+            display_name,
+            DataType::Binary,
+            params,
+            arguments[0].clone(),
+        )
+        .with_need_drop(true);
+    Ok(Arc::new(func))
+}
+
+fn check_sketch_argument(display_name: &str, argument: &DataType) -> Result<()> {
+    if argument != &DataType::Binary {
+        return Err(ErrorCode::BadDataValueType(format!(
+            "The argument of aggregate function {} must be a theta sketch of binary type, but got '{:?}'",
+            display_name, argument
+        )));
+    }
+    Ok(())
+}
+
+pub fn aggregate_theta_sketch_state_function_desc() -> AggregateFunctionDescription {
+    // The sketch of no values is an empty sketch
+    let features = AggregateFunctionFeatures {
+        returns_default_when_only_null: true,
+        ..Default::default()
+    };
+    AggregateFunctionDescription::creator_with_features(
+        Box::new(try_create_aggregate_theta_sketch_state_function),
+        features,
+    )
+}
+
+pub fn aggregate_theta_sketch_union_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_theta_sketch_union_function,
+    ))
+}
+
+pub fn aggregate_theta_sketch_intersect_function_desc() -> AggregateFunctionDescription {
+    AggregateFunctionDescription::creator(Box::new(
+        try_create_aggregate_theta_sketch_intersect_function,
+    ))
+}
@@ -25,6 +25,7 @@ use super::aggregate_bitmap::aggregate_bitmap_union_function_desc;
 use super::aggregate_bitmap::aggregate_bitmap_xor_count_function_desc;
 use super::aggregate_combinator_distinct::aggregate_combinator_distinct_desc;
 use super::aggregate_combinator_distinct::aggregate_combinator_uniq_desc;
+use super::aggregate_combinator_merge::AggregateMergeCombinator;
 use super::aggregate_combinator_state::AggregateStateCombinator;
 use super::aggregate_covariance::aggregate_covariance_population_desc;
 use super::aggregate_covariance::aggregate_covariance_sample_desc;
@@ -38,24 +39,46 @@ use super::aggregate_window_funnel::aggregate_window_funnel_function_desc;
 use super::AggregateCountFunction;
 use super::AggregateFunctionFactory;
 use super::AggregateIfCombinator;
+use crate::aggregates::aggregate_approx_top_k_function_desc;
 use crate::aggregates::aggregate_array_agg_function_desc;
 use crate::aggregates::aggregate_array_moving_avg_function_desc;
 use crate::aggregates::aggregate_array_moving_sum_function_desc;
+use crate::aggregates::aggregate_bit_and_function_desc;
+use crate::aggregates::aggregate_bit_or_function_desc;
+use crate::aggregates::aggregate_bit_xor_function_desc;
+use crate::aggregates::aggregate_bool_and_function_desc;
+use crate::aggregates::aggregate_bool_or_function_desc;
+use crate::aggregates::aggregate_corr_function_desc;
 use crate::aggregates::aggregate_histogram_function_desc;
 use crate::aggregates::aggregate_json_array_agg_function_desc;
 use crate::aggregates::aggregate_json_object_agg_function_desc;
 use crate::aggregates::aggregate_kurtosis_function_desc;
 use crate::aggregates::aggregate_median_function_desc;
+use crate::aggregates::aggregate_median_kll_function_desc;
 use crate::aggregates::aggregate_median_tdigest_function_desc;
 use crate::aggregates::aggregate_median_tdigest_weighted_function_desc;
 use crate::aggregates::aggregate_quantile_cont_function_desc;
 use crate::aggregates::aggregate_quantile_disc_function_desc;
+use crate::aggregates::aggregate_quantile_kll_function_desc;
 use crate::aggregates::aggregate_quantile_tdigest_function_desc;
 use crate::aggregates::aggregate_quantile_tdigest_weighted_function_desc;
+use crate::aggregates::aggregate_regr_avgx_function_desc;
+use crate::aggregates::aggregate_regr_avgy_function_desc;
+use crate::aggregates::aggregate_regr_count_function_desc;
+use crate::aggregates::aggregate_regr_intercept_function_desc;
+use crate::aggregates::aggregate_regr_r2_function_desc;
+use crate::aggregates::aggregate_regr_slope_function_desc;
+use crate::aggregates::aggregate_regr_sxx_function_desc;
+use crate::aggregates::aggregate_regr_sxy_function_desc;
+use crate::aggregates::aggregate_regr_syy_function_desc;
 use crate::aggregates::aggregate_retention_function_desc;
 use crate::aggregates::aggregate_skewness_function_desc;
+use crate::aggregates::aggregate_st_union_agg_function_desc;
 use crate::aggregates::aggregate_string_agg_function_desc;
 use crate::aggregates::aggregate_sum_function_desc;
+use crate::aggregates::aggregate_theta_sketch_intersect_function_desc;
+use crate::aggregates::aggregate_theta_sketch_state_function_desc;
+use crate::aggregates::aggregate_theta_sketch_union_function_desc;
 
 pub struct Aggregators;
 
@@ -75,6 +98,16 @@ impl Aggregators {
 
         factory.register("covar_samp", aggregate_covariance_sample_desc());
         factory.register("covar_pop", aggregate_covariance_population_desc());
+        factory.register("corr", aggregate_corr_function_desc());
+        factory.register("regr_slope", aggregate_regr_slope_function_desc());
+        factory.register("regr_intercept", aggregate_regr_intercept_function_desc());
+        factory.register("regr_r2", aggregate_regr_r2_function_desc());
+        factory.register("regr_count", aggregate_regr_count_function_desc());
+        factory.register("regr_avgx", aggregate_regr_avgx_function_desc());
+        factory.register("regr_avgy", aggregate_regr_avgy_function_desc());
+        factory.register("regr_sxx", aggregate_regr_sxx_function_desc());
+        factory.register("regr_syy", aggregate_regr_syy_function_desc());
+        factory.register("regr_sxy", aggregate_regr_sxy_function_desc());
         factory.register("stddev_samp", aggregate_stddev_samp_function_desc());
         factory.register("stddev_pop", aggregate_stddev_pop_function_desc());
         factory.register("stddev", aggregate_stddev_samp_function_desc());
@@ -96,11 +129,26 @@ impl Aggregators {
             "median_tdigest_weighted",
             aggregate_median_tdigest_weighted_function_desc(),
         );
+        factory.register("quantile_kll", aggregate_quantile_kll_function_desc());
+        factory.register("median_kll", aggregate_median_kll_function_desc());
         factory.register("window_funnel", aggregate_window_funnel_function_desc());
         factory.register(
             "approx_count_distinct",
             aggregate_approx_count_distinct_function_desc(),
         );
+        factory.register("approx_top_k", aggregate_approx_top_k_function_desc());
+        factory.register(
+            "theta_sketch_state",
+            aggregate_theta_sketch_state_function_desc(),
+        );
+        factory.register(
+            "theta_sketch_union",
+            aggregate_theta_sketch_union_function_desc(),
+        );
+        factory.register(
+            "theta_sketch_intersect",
+            aggregate_theta_sketch_intersect_function_desc(),
+        );
         factory.register("retention", aggregate_retention_function_desc());
         factory.register("array_agg", aggregate_array_agg_function_desc());
         factory.register("list", aggregate_array_agg_function_desc());
@@ -118,6 +166,12 @@ impl Aggregators {
         factory.register("skewness", aggregate_skewness_function_desc());
         factory.register("string_agg", aggregate_string_agg_function_desc());
 
+        factory.register("bool_and", aggregate_bool_and_function_desc());
+        factory.register("bool_or", aggregate_bool_or_function_desc());
+        factory.register("bit_and", aggregate_bit_and_function_desc());
+        factory.register("bit_or", aggregate_bit_or_function_desc());
+        factory.register("bit_xor", aggregate_bit_xor_function_desc());
+
         factory.register(
             "bitmap_and_count",
             aggregate_bitmap_and_count_function_desc(),
@@ -144,11 +198,14 @@ impl Aggregators {
         factory.register("histogram", aggregate_histogram_function_desc());
 
         factory.register("mode", aggregate_mode_function_desc());
+
+        factory.register("st_union_agg", aggregate_st_union_agg_function_desc());
     }
 
     pub fn register_combinator(factory: &mut AggregateFunctionFactory) {
         factory.register_combinator("_if", AggregateIfCombinator::combinator_desc());
         factory.register_combinator("_distinct", aggregate_combinator_distinct_desc());
         factory.register_combinator("_state", AggregateStateCombinator::combinator_desc());
+        factory.register_combinator("_merge", AggregateMergeCombinator::combinator_desc());
     }
 }
@@ -18,13 +18,16 @@ mod aggregate_function_factory;
 
 mod adaptors;
 mod aggregate_approx_count_distinct;
+mod aggregate_approx_top_k;
 mod aggregate_arg_min_max;
 mod aggregate_array_agg;
 mod aggregate_array_moving;
 mod aggregate_avg;
 mod aggregate_bitmap;
+mod aggregate_bitwise;
 mod aggregate_combinator_distinct;
 mod aggregate_combinator_if;
+mod aggregate_combinator_merge;
 mod aggregate_combinator_state;
 mod aggregate_covariance;
 mod aggregate_distinct_state;
@@ -37,25 +40,32 @@ mod aggregate_mode;
 mod aggregate_null_result;
 mod aggregate_quantile_cont;
 mod aggregate_quantile_disc;
+mod aggregate_quantile_kll;
 mod aggregate_quantile_tdigest;
 mod aggregate_quantile_tdigest_weighted;
+mod aggregate_regression;
 mod aggregate_retention;
 mod aggregate_scalar_state;
 mod aggregate_skewness;
+mod aggregate_st_union_agg;
 mod aggregate_stddev;
 mod aggregate_string_agg;
 mod aggregate_sum;
+mod aggregate_theta_sketch;
 mod aggregate_unary;
 mod aggregate_window_funnel;
 mod aggregator;
 mod aggregator_common;
 
 pub use adaptors::*;
+pub use aggregate_approx_top_k::*;
 pub use aggregate_arg_min_max::AggregateArgMinMaxFunction;
 pub use aggregate_array_agg::*;
 pub use aggregate_array_moving::*;
+pub use aggregate_bitwise::*;
 pub use aggregate_combinator_distinct::AggregateDistinctCombinator;
 pub use aggregate_combinator_if::AggregateIfCombinator;
+pub use aggregate_combinator_merge::AggregateMergeCombinator;
 pub use aggregate_count::AggregateCountFunction;
 pub use aggregate_covariance::AggregateCovarianceFunction;
 pub use aggregate_function::*;
@@ -69,12 +79,16 @@ pub use aggregate_mode::*;
 pub use aggregate_null_result::AggregateNullResultFunction;
 pub use aggregate_quantile_cont::*;
 pub use aggregate_quantile_disc::*;
+pub use aggregate_quantile_kll::*;
 pub use aggregate_quantile_tdigest::*;
 pub use aggregate_quantile_tdigest_weighted::*;
+pub use aggregate_regression::*;
 pub use aggregate_retention::*;
 pub use aggregate_skewness::*;
+pub use aggregate_st_union_agg::*;
 pub use aggregate_string_agg::*;
 pub use aggregate_sum::*;
+pub use aggregate_theta_sketch::*;
 pub use aggregate_unary::*;
 pub use aggregator::Aggregators;
 pub use aggregator_common::*;
@@ -158,6 +158,8 @@ macro_rules! register_simple_domain_type_cmp {
     };
 }
 
+pub(crate) use register_simple_domain_type_cmp;
+
 fn register_string_cmp(registry: &mut FunctionRegistry) {
     registry.register_passthrough_nullable_2_arg::<StringType, StringType, BooleanType, _, _>(
         "eq",
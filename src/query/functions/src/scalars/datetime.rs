@@ -48,10 +48,13 @@ use databend_common_expression::types::timestamp::MICROS_PER_SEC;
 use databend_common_expression::types::DateType;
 use databend_common_expression::types::Float64Type;
 use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::IntervalType;
 use databend_common_expression::types::NullableType;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::StringType;
 use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::TimestampTz;
+use databend_common_expression::types::TimestampTzType;
 use databend_common_expression::types::F64;
 use databend_common_expression::utils::date_helper::*;
 use databend_common_expression::vectorize_1_arg;
@@ -68,6 +71,8 @@ use databend_common_io::cursor_ext::unwrap_local_time;
 use dtparse::parse;
 use num_traits::AsPrimitive;
 
+use crate::scalars::interval::timestamp_diff_interval;
+
 pub fn register(registry: &mut FunctionRegistry) {
     // cast(xx AS timestamp)
     // to_timestamp(xx)
@@ -188,6 +193,36 @@ fn register_convert_timezone(registry: &mut FunctionRegistry) {
             },
         ),
     );
+
+    // The instant is kept, only the offset changes to the one the target
+    // timezone has at that instant.
+    registry
+        .register_passthrough_nullable_2_arg::<StringType, TimestampTzType, TimestampTzType, _, _>(
+            "convert_timezone",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<StringType, TimestampTzType, TimestampTzType>(
+                |target_tz, src_timestamp, output, ctx| {
+                    if let Some(validity) = &ctx.validity {
+                        if !validity.get_bit(output.len()) {
+                            output.push(TimestampTz::default());
+                            return;
+                        }
+                    }
+                    match target_tz.parse::<Tz>() {
+                        Ok(t_tz) => {
+                            output.push(TimestampTz::from_timestamp(src_timestamp.micros, t_tz))
+                        }
+                        Err(e) => {
+                            ctx.set_error(
+                                output.len(),
+                                format!("cannot parse target `timezone`. {}", e),
+                            );
+                            output.push(TimestampTz::default());
+                        }
+                    }
+                },
+            ),
+        );
 }
 
 fn register_string_to_timestamp(registry: &mut FunctionRegistry) {
@@ -1246,23 +1281,10 @@ fn register_diff_functions(registry: &mut FunctionRegistry) {
         |a, b, _| a - b,
     );
 
-    registry.register_2_arg::<TimestampType, TimestampType, Int64Type, _, _>(
+    registry.register_2_arg::<TimestampType, TimestampType, IntervalType, _, _>(
         "minus",
-        |_, lhs, rhs| {
-            (|| {
-                let lm = lhs.max;
-                let ln = lhs.min;
-                let rm = rhs.max;
-                let rn = rhs.min;
-
-                Some(FunctionDomain::Domain(SimpleDomain::<i64> {
-                    min: ln.checked_sub(rm)?,
-                    max: lm.checked_sub(rn)?,
-                }))
-            })()
-            .unwrap_or(FunctionDomain::Full)
-        },
-        |a, b, _| a - b,
+        |_, _, _| FunctionDomain::Full,
+        |a, b, _| timestamp_diff_interval(a, b),
     );
 
     registry.register_passthrough_nullable_2_arg::<DateType, DateType, Float64Type, _, _>(
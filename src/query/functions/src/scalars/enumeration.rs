@@ -0,0 +1,333 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_expression::types::enumeration::string_to_enum;
+use databend_common_expression::types::string::StringColumnBuilder;
+use databend_common_expression::types::*;
+use databend_common_expression::vectorize_2_arg;
+use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::Column;
+use databend_common_expression::Domain;
+use databend_common_expression::EvalContext;
+use databend_common_expression::Function;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionEval;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::FunctionSignature;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::SimpleDomainCmp;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+
+// The enums are compared by their codes. A string argument is looked up in the items of
+// the enum instead of being casted, so `enum_col = 'a'` never decodes the column, and a
+// string that is not an item equals none of the codes. Only the order of such a string is
+// undefined, `lt`, `lte`, `gt` and `gte` fail on it. Enums with different items are only
+// compared for equality, by mapping the codes of one side to the other.
+macro_rules! register_enum_cmp_op {
+    ($registry: expr, $name: expr, $op: ident, $domain_op: ident, $unknown: expr) => {
+        $registry.register_function_factory($name, |_, args_type| {
+            if args_type.len() != 2 {
+                return None;
+            }
+            let unknown: Option<bool> = $unknown;
+
+            let has_nullable = args_type.iter().any(|x| x.is_nullable_or_null());
+            let args_type: Vec<DataType> = args_type.iter().map(|x| x.remove_nullable()).collect();
+
+            let function = match (&args_type[0], &args_type[1]) {
+                (DataType::Enum(a), DataType::Enum(b)) if a == b => Function {
+                    signature: FunctionSignature {
+                        name: $name.to_string(),
+                        args_type: args_type.clone(),
+                        return_type: DataType::Boolean,
+                    },
+                    eval: FunctionEval::Scalar {
+                        calc_domain: Box::new(|_, d| match (&d[0], &d[1]) {
+                            (Domain::Enum(d1, _), Domain::Enum(d2, _)) => {
+                                d1.$domain_op(d2).map(Domain::Boolean)
+                            }
+                            _ => unreachable!(),
+                        }),
+                        eval: Box::new(|args, ctx| {
+                            let cmp = vectorize_2_arg::<UInt16Type, UInt16Type, BooleanType>(
+                                |a, b, _| a.cmp(&b).$op(),
+                            );
+                            cmp(enum_codes(&args[0]), enum_codes(&args[1]), ctx).upcast()
+                        }),
+                    },
+                },
+                (DataType::Enum(a), DataType::Enum(b)) if unknown.is_some() => {
+                    // the codes of the items of the right enum in the left one
+                    let codes = b
+                        .items()
+                        .iter()
+                        .map(|item| a.code_of(item))
+                        .collect::<Vec<_>>();
+                    Function {
+                        signature: FunctionSignature {
+                            name: $name.to_string(),
+                            args_type: args_type.clone(),
+                            return_type: DataType::Boolean,
+                        },
+                        eval: FunctionEval::Scalar {
+                            calc_domain: Box::new(|_, _| FunctionDomain::Full),
+                            eval: Box::new(move |args, ctx| {
+                                let cmp = vectorize_2_arg::<UInt16Type, UInt16Type, BooleanType>(
+                                    |a, b, _| match codes[b as usize] {
+                                        Some(b) => a.cmp(&b).$op(),
+                                        None => unknown.unwrap(),
+                                    },
+                                );
+                                cmp(enum_codes(&args[0]), enum_codes(&args[1]), ctx).upcast()
+                            }),
+                        },
+                    }
+                }
+                (DataType::Enum(items), DataType::String)
+                | (DataType::String, DataType::Enum(items)) => {
+                    let items = items.clone();
+                    let enum_first = args_type[0].is_enum();
+                    Function {
+                        signature: FunctionSignature {
+                            name: $name.to_string(),
+                            args_type: args_type.clone(),
+                            return_type: DataType::Boolean,
+                        },
+                        eval: FunctionEval::Scalar {
+                            calc_domain: Box::new(move |_, _| match unknown {
+                                Some(_) => FunctionDomain::Full,
+                                None => FunctionDomain::MayThrow,
+                            }),
+                            eval: Box::new(move |args, ctx| {
+                                if enum_first {
+                                    cmp_enum_with_string(
+                                        &args[0],
+                                        &args[1],
+                                        &items,
+                                        unknown,
+                                        |code, other| code.cmp(&other).$op(),
+                                        ctx,
+                                    )
+                                } else {
+                                    cmp_enum_with_string(
+                                        &args[1],
+                                        &args[0],
+                                        &items,
+                                        unknown,
+                                        |code, other| other.cmp(&code).$op(),
+                                        ctx,
+                                    )
+                                }
+                            }),
+                        },
+                    }
+                }
+                _ => return None,
+            };
+
+            if has_nullable {
+                Some(Arc::new(function.passthrough_nullable()))
+            } else {
+                Some(Arc::new(function))
+            }
+        });
+    };
+}
+
+pub fn register(registry: &mut FunctionRegistry) {
+    register_to_enum(registry);
+    register_enum_to_string(registry);
+
+    register_enum_cmp_op!(registry, "eq", is_eq, domain_eq, Some(false));
+    register_enum_cmp_op!(registry, "noteq", is_ne, domain_noteq, Some(true));
+    register_enum_cmp_op!(registry, "lt", is_lt, domain_lt, None);
+    register_enum_cmp_op!(registry, "lte", is_le, domain_lte, None);
+    register_enum_cmp_op!(registry, "gt", is_gt, domain_gt, None);
+    register_enum_cmp_op!(registry, "gte", is_ge, domain_gte, None);
+}
+
+// string or enum to enum, the items of the enum are the params
+fn register_to_enum(registry: &mut FunctionRegistry) {
+    let factory = |params: &[Scalar], args_type: &[DataType]| {
+        if args_type.len() != 1 {
+            return None;
+        }
+
+        let from_type = args_type[0].remove_nullable();
+        if !matches!(from_type, DataType::String | DataType::Enum(_)) {
+            return None;
+        }
+
+        let items = params
+            .iter()
+            .map(|param| param.as_string().cloned())
+            .collect::<Option<Vec<_>>>()?;
+        let items = EnumItems::try_create(items).ok()?;
+
+        Some(Function {
+            signature: FunctionSignature {
+                name: "to_enum".to_string(),
+                args_type: vec![from_type],
+                return_type: DataType::Enum(items.clone()),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+                eval: Box::new(move |args, ctx| convert_to_enum(&args[0], &items, ctx)),
+            },
+        })
+    };
+
+    registry.register_function_factory("to_enum", move |params, args_type| {
+        Some(Arc::new(factory(params, args_type)?))
+    });
+    registry.register_function_factory("to_enum", move |params, args_type| {
+        let f = factory(params, args_type)?;
+        Some(Arc::new(f.passthrough_nullable()))
+    });
+    registry.register_function_factory("try_to_enum", move |params, args_type| {
+        let mut f = factory(params, args_type)?;
+        f.signature.name = "try_to_enum".to_string();
+        Some(Arc::new(f.error_to_null()))
+    });
+    registry.register_function_factory("try_to_enum", move |params, args_type| {
+        let mut f = factory(params, args_type)?;
+        f.signature.name = "try_to_enum".to_string();
+        Some(Arc::new(f.error_to_null().passthrough_nullable()))
+    });
+}
+
+fn convert_to_enum(
+    arg: &ValueRef<AnyType>,
+    items: &EnumItems,
+    ctx: &mut EvalContext,
+) -> Value<AnyType> {
+    let to_code = |s: &str, row: usize, ctx: &mut EvalContext| match string_to_enum(items, s) {
+        Ok(code) => code,
+        Err(err) => {
+            ctx.set_error(row, err.message());
+            0
+        }
+    };
+
+    match arg {
+        ValueRef::Scalar(ScalarRef::String(s)) => {
+            Value::Scalar(Scalar::Enum(to_code(s, 0, ctx), items.clone()))
+        }
+        ValueRef::Scalar(ScalarRef::Enum(code, from_items)) => Value::Scalar(Scalar::Enum(
+            to_code(from_items.item(*code), 0, ctx),
+            items.clone(),
+        )),
+        ValueRef::Column(Column::String(col)) => {
+            let mut builder = EnumColumnBuilder::with_capacity(items.clone(), col.len());
+            for (row, s) in col.iter().enumerate() {
+                builder.push(to_code(s, row, ctx));
+            }
+            Value::Column(Column::Enum(builder.build()))
+        }
+        ValueRef::Column(Column::Enum(col)) => {
+            let mut builder = EnumColumnBuilder::with_capacity(items.clone(), col.len());
+            for (row, s) in col.iter().enumerate() {
+                builder.push(to_code(s, row, ctx));
+            }
+            Value::Column(Column::Enum(builder.build()))
+        }
+        _ => unreachable!("to_enum only accepts string or enum arguments"),
+    }
+}
+
+// enum to string, the codes are decoded into their items
+fn register_enum_to_string(registry: &mut FunctionRegistry) {
+    let factory = |_params: &[Scalar], args_type: &[DataType]| {
+        if args_type.len() != 1 {
+            return None;
+        }
+
+        let arg_type = args_type[0].remove_nullable();
+        if !arg_type.is_enum() {
+            return None;
+        }
+
+        let function = Function {
+            signature: FunctionSignature {
+                name: "to_string".to_string(),
+                args_type: vec![arg_type],
+                return_type: StringType::data_type(),
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::Full),
+                eval: Box::new(|args, _| match &args[0] {
+                    ValueRef::Scalar(ScalarRef::Enum(code, items)) => {
+                        Value::Scalar(Scalar::String(items.item(*code).to_string()))
+                    }
+                    ValueRef::Column(Column::Enum(col)) => {
+                        let mut builder = StringColumnBuilder::with_capacity(col.len());
+                        for item in col.iter() {
+                            builder.put_str(item);
+                            builder.commit_row();
+                        }
+                        Value::Column(Column::String(builder.build()))
+                    }
+                    _ => unreachable!("to_string only accepts enum arguments"),
+                }),
+            },
+        };
+
+        if args_type[0].is_nullable() {
+            Some(Arc::new(function.passthrough_nullable()))
+        } else {
+            Some(Arc::new(function))
+        }
+    };
+    registry.register_function_factory("to_string", factory);
+}
+
+/// Returns the codes of an enum argument.
+fn enum_codes(arg: &ValueRef<AnyType>) -> ValueRef<UInt16Type> {
+    match arg {
+        ValueRef::Scalar(ScalarRef::Enum(code, _)) => ValueRef::Scalar(*code),
+        ValueRef::Column(Column::Enum(col)) => ValueRef::Column(col.codes.clone()),
+        _ => unreachable!("expect enum arguments"),
+    }
+}
+
+/// Compares the codes of an enum argument with the codes of the items in a string argument,
+/// a string that is not an item compares as `unknown`, or fails if it is `None`.
+fn cmp_enum_with_string(
+    enum_arg: &ValueRef<AnyType>,
+    string_arg: &ValueRef<AnyType>,
+    items: &EnumItems,
+    unknown: Option<bool>,
+    cmp: impl Fn(u16, u16) -> bool + Copy + Send + Sync,
+    ctx: &mut EvalContext,
+) -> Value<AnyType> {
+    let string_arg = string_arg.try_downcast::<StringType>().unwrap();
+    let eval = vectorize_with_builder_2_arg::<UInt16Type, StringType, BooleanType>(
+        |code, s, builder, ctx| {
+            let result = match (string_to_enum(items, s), unknown) {
+                (Ok(other), _) => cmp(code, other),
+                (Err(_), Some(result)) => result,
+                (Err(err), None) => {
+                    ctx.set_error(builder.len(), err.message());
+                    false
+                }
+            };
+            builder.push(result);
+        },
+    );
+    eval(enum_codes(enum_arg), string_arg, ctx).upcast()
+}
@@ -12,13 +12,29 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::geography::GeographyRef;
 use databend_common_expression::types::*;
 use databend_common_expression::vectorize_with_builder_1_arg;
 use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::vectorize_with_builder_3_arg;
 use databend_common_expression::FunctionDomain;
 use databend_common_expression::FunctionRegistry;
 use databend_common_io::geography::geography_from_ewkt;
 use databend_common_io::wkb::make_point;
+use geo::GeodesicArea;
+use geo::GeodesicDistance;
+use geo::GeodesicLength;
+use geo::Point;
+use geos::Geom;
+use geozero::wkb::Ewkb;
+use geozero::ToGeo;
+
+use super::geometry::ewkb_to_geos;
+use super::geometry::geos_error;
+use super::geometry::perimeter;
+use super::geometry::GeosPredicate;
 
 pub fn register(registry: &mut FunctionRegistry) {
     // aliases
@@ -69,4 +85,158 @@ pub fn register(registry: &mut FunctionRegistry) {
             builder.commit_row();
         }),
     );
+
+    // Edges are treated as straight lines in longitude/latitude space.
+    let predicates: [(&str, GeosPredicate); 4] = [
+        ("st_intersects", |l, r| l.intersects(r)),
+        ("st_within", |l, r| l.within(r)),
+        ("st_covers", |l, r| l.covers(r)),
+        ("st_contains", |l, r| l.contains(r)),
+    ];
+    for (name, predicate) in predicates {
+        registry
+            .register_passthrough_nullable_2_arg::<GeographyType, GeographyType, BooleanType, _, _>(
+                name,
+                |_, _, _| FunctionDomain::MayThrow,
+                vectorize_with_builder_2_arg::<GeographyType, GeographyType, BooleanType>(
+                    move |l_geography, r_geography, builder, ctx| {
+                        if let Some(validity) = &ctx.validity {
+                            if !validity.get_bit(builder.len()) {
+                                builder.push(false);
+                                return;
+                            }
+                        }
+
+                        match geography_predicate(l_geography, r_geography, predicate) {
+                            Ok(res) => builder.push(res),
+                            Err(e) => {
+                                ctx.set_error(builder.len(), e.to_string());
+                                builder.push(false);
+                            }
+                        }
+                    },
+                ),
+            );
+    }
+
+    // Geodesic distance in meters on the WGS84 ellipsoid.
+    registry
+        .register_passthrough_nullable_2_arg::<GeographyType, GeographyType, NumberType<F64>, _, _>(
+            "st_distance",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<GeographyType, GeographyType, NumberType<F64>>(
+                |l_geography, r_geography, builder, ctx| {
+                    if let Some(validity) = &ctx.validity {
+                        if !validity.get_bit(builder.len()) {
+                            builder.push(F64::from(0_f64));
+                            return;
+                        }
+                    }
+
+                    match geodesic_distance(l_geography, r_geography) {
+                        Ok(distance) => builder.push(format!("{:.9}", distance).parse().unwrap()),
+                        Err(e) => {
+                            ctx.set_error(builder.len(), e.to_string());
+                            builder.push(F64::from(0_f64));
+                        }
+                    }
+                },
+            ),
+        );
+
+    registry.register_passthrough_nullable_3_arg::<GeographyType, GeographyType, NumberType<F64>, BooleanType, _, _>(
+        "st_dwithin",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<GeographyType, GeographyType, NumberType<F64>, BooleanType>(
+            |l_geography, r_geography, distance, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.push(false);
+                        return;
+                    }
+                }
+
+                match geodesic_distance(l_geography, r_geography) {
+                    Ok(d) => builder.push(d <= *distance),
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e.to_string());
+                        builder.push(false);
+                    }
+                }
+            },
+        ),
+    );
+
+    let measures: [(&str, fn(&geo::Geometry) -> f64); 3] = [
+        // Square meters.
+        ("st_area", |geo| geo.geodesic_area_unsigned()),
+        // Meters.
+        ("st_perimeter", |geo| {
+            perimeter(geo, &|ring: &geo::LineString| ring.geodesic_length())
+        }),
+        ("st_length", geodesic_length),
+    ];
+    for (name, measure) in measures {
+        registry.register_passthrough_nullable_1_arg::<GeographyType, NumberType<F64>, _, _>(
+            name,
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_1_arg::<GeographyType, NumberType<F64>>(
+                move |geography, builder, ctx| {
+                    if let Some(validity) = &ctx.validity {
+                        if !validity.get_bit(builder.len()) {
+                            builder.push(F64::from(0_f64));
+                            return;
+                        }
+                    }
+
+                    match Ewkb(geography.0).to_geo() {
+                        Ok(geo) => builder.push(format!("{:.9}", measure(&geo)).parse().unwrap()),
+                        Err(e) => {
+                            ctx.set_error(
+                                builder.len(),
+                                ErrorCode::GeometryError(e.to_string()).to_string(),
+                            );
+                            builder.push(F64::from(0_f64));
+                        }
+                    }
+                },
+            ),
+        );
+    }
+}
+
+/// Every GEOGRAPHY is in WGS84, so unlike GEOMETRY the SRIDs are not compared.
+fn geography_predicate(
+    l_geography: GeographyRef,
+    r_geography: GeographyRef,
+    predicate: GeosPredicate,
+) -> Result<bool> {
+    let l_geos = ewkb_to_geos(l_geography.0)?;
+    let r_geos = ewkb_to_geos(r_geography.0)?;
+    predicate(&l_geos, &r_geos).map_err(geos_error)
+}
+
+fn geodesic_distance(l_geography: GeographyRef, r_geography: GeographyRef) -> Result<f64> {
+    let to_point = |geography: GeographyRef| -> Result<Point> {
+        Ewkb(geography.0)
+            .to_geo()
+            .map_err(ErrorCode::from)?
+            .try_into()
+            .map_err(|_| {
+                ErrorCode::GeometryError("geodesic distance is only supported between points")
+            })
+    };
+    Ok(to_point(l_geography)?.geodesic_distance(&to_point(r_geography)?))
+}
+
+fn geodesic_length(geo: &geo::Geometry) -> f64 {
+    match geo {
+        geo::Geometry::Line(line) => line.geodesic_length(),
+        geo::Geometry::LineString(line_string) => line_string.geodesic_length(),
+        geo::Geometry::MultiLineString(multi_line_string) => multi_line_string.geodesic_length(),
+        geo::Geometry::GeometryCollection(collection) => {
+            collection.iter().map(geodesic_length).sum()
+        }
+        _ => 0.0,
+    }
 }
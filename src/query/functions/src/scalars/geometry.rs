@@ -52,6 +52,8 @@ use geohash::encode;
 use geos::geo_types;
 use geos::geo_types::Coord;
 use geos::geo_types::LineString;
+use geos::GResult;
+use geos::Geom;
 use geos::Geometry;
 use geozero::geojson::GeoJson;
 use geozero::wkb::Ewkb;
@@ -1573,6 +1575,281 @@ pub fn register(registry: &mut FunctionRegistry) {
             },
         ),
     );
+
+    let predicates: [(&str, GeosPredicate); 3] = [
+        ("st_intersects", |l, r| l.intersects(r)),
+        ("st_within", |l, r| l.within(r)),
+        ("st_covers", |l, r| l.covers(r)),
+    ];
+    for (name, predicate) in predicates {
+        registry
+            .register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+                name,
+                |_, _, _| FunctionDomain::MayThrow,
+                vectorize_with_builder_2_arg::<GeometryType, GeometryType, BooleanType>(
+                    move |l_geometry, r_geometry, builder, ctx| {
+                        if let Some(validity) = &ctx.validity {
+                            if !validity.get_bit(builder.len()) {
+                                builder.push(false);
+                                return;
+                            }
+                        }
+
+                        match geos_predicate(l_geometry, r_geometry, predicate) {
+                            Ok(res) => builder.push(res),
+                            Err(e) => {
+                                ctx.set_error(builder.len(), e.to_string());
+                                builder.push(false);
+                            }
+                        }
+                    },
+                ),
+            );
+    }
+
+    registry.register_passthrough_nullable_3_arg::<GeometryType, GeometryType, NumberType<F64>, BooleanType, _, _>(
+        "st_dwithin",
+        |_, _, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_3_arg::<GeometryType, GeometryType, NumberType<F64>, BooleanType>(
+            |l_geometry, r_geometry, distance, builder, ctx| {
+                if let Some(validity) = &ctx.validity {
+                    if !validity.get_bit(builder.len()) {
+                        builder.push(false);
+                        return;
+                    }
+                }
+
+                match geos_predicate(l_geometry, r_geometry, |l, r| {
+                    l.distance(r).map(|d| d <= *distance)
+                }) {
+                    Ok(res) => builder.push(res),
+                    Err(e) => {
+                        ctx.set_error(builder.len(), e.to_string());
+                        builder.push(false);
+                    }
+                }
+            },
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, NumberType<F64>, _, _>(
+        "st_area",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, NumberType<F64>>(|geometry, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.push(F64::from(0_f64));
+                    return;
+                }
+            }
+
+            match ewkb_to_geos(geometry).and_then(|geos| geos.area().map_err(geos_error)) {
+                Ok(area) => builder.push(format!("{:.9}", area).parse().unwrap()),
+                Err(e) => {
+                    ctx.set_error(builder.len(), e.to_string());
+                    builder.push(F64::from(0_f64));
+                }
+            }
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, NumberType<F64>, _, _>(
+        "st_perimeter",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<GeometryType, NumberType<F64>>(|geometry, builder, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(builder.len()) {
+                    builder.push(F64::from(0_f64));
+                    return;
+                }
+            }
+
+            match Ewkb(geometry).to_geo() {
+                Ok(geo) => {
+                    let perimeter = perimeter(&geo, &|ring: &LineString| ring.euclidean_length());
+                    builder.push(format!("{:.9}", perimeter).parse().unwrap());
+                }
+                Err(e) => {
+                    ctx.set_error(
+                        builder.len(),
+                        ErrorCode::GeometryError(e.to_string()).to_string(),
+                    );
+                    builder.push(F64::from(0_f64));
+                }
+            }
+        }),
+    );
+
+    let unary_operations: [(&str, GeosUnaryOperation); 2] = [
+        ("st_centroid", |g| g.get_centroid()),
+        ("st_envelope", |g| g.envelope()),
+    ];
+    for (name, operation) in unary_operations {
+        registry.register_passthrough_nullable_1_arg::<GeometryType, GeometryType, _, _>(
+            name,
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_1_arg::<GeometryType, GeometryType>(
+                move |geometry, builder, ctx| {
+                    if let Some(validity) = &ctx.validity {
+                        if !validity.get_bit(builder.len()) {
+                            builder.commit_row();
+                            return;
+                        }
+                    }
+
+                    match geos_unary_operation(geometry, operation) {
+                        Ok(data) => builder.put_slice(data.as_slice()),
+                        Err(e) => ctx.set_error(builder.len(), e.to_string()),
+                    }
+                    builder.commit_row();
+                },
+            ),
+        );
+    }
+
+    let parameterized_operations: [(&str, GeosParameterizedOperation); 2] = [
+        // Approximate quarter circles with 8 segments, the same as PostGIS.
+        ("st_buffer", |g, distance| g.buffer(distance, 8)),
+        // Douglas-Peucker simplification.
+        ("st_simplify", |g, tolerance| g.simplify(tolerance)),
+    ];
+    for (name, operation) in parameterized_operations {
+        registry.register_passthrough_nullable_2_arg::<GeometryType, NumberType<F64>, GeometryType, _, _>(
+            name,
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<GeometryType, NumberType<F64>, GeometryType>(
+                move |geometry, param, builder, ctx| {
+                    if let Some(validity) = &ctx.validity {
+                        if !validity.get_bit(builder.len()) {
+                            builder.commit_row();
+                            return;
+                        }
+                    }
+
+                    match geos_unary_operation(geometry, |g| operation(g, *param)) {
+                        Ok(data) => builder.put_slice(data.as_slice()),
+                        Err(e) => ctx.set_error(builder.len(), e.to_string()),
+                    }
+                    builder.commit_row();
+                },
+            ),
+        );
+    }
+
+    let binary_operations: [(&str, GeosBinaryOperation); 3] = [
+        ("st_union", |l, r| l.union(r)),
+        ("st_intersection", |l, r| l.intersection(r)),
+        ("st_difference", |l, r| l.difference(r)),
+    ];
+    for (name, operation) in binary_operations {
+        registry
+            .register_passthrough_nullable_2_arg::<GeometryType, GeometryType, GeometryType, _, _>(
+                name,
+                |_, _, _| FunctionDomain::MayThrow,
+                vectorize_with_builder_2_arg::<GeometryType, GeometryType, GeometryType>(
+                    move |l_geometry, r_geometry, builder, ctx| {
+                        if let Some(validity) = &ctx.validity {
+                            if !validity.get_bit(builder.len()) {
+                                builder.commit_row();
+                                return;
+                            }
+                        }
+
+                        match geos_binary_operation(l_geometry, r_geometry, operation) {
+                            Ok(data) => builder.put_slice(data.as_slice()),
+                            Err(e) => ctx.set_error(builder.len(), e.to_string()),
+                        }
+                        builder.commit_row();
+                    },
+                ),
+            );
+    }
+}
+
+pub(crate) type GeosPredicate = fn(&Geometry, &Geometry) -> GResult<bool>;
+type GeosUnaryOperation = fn(&Geometry) -> GResult<Geometry>;
+type GeosParameterizedOperation = fn(&Geometry, f64) -> GResult<Geometry>;
+type GeosBinaryOperation = fn(&Geometry, &Geometry) -> GResult<Geometry>;
+
+pub(crate) fn ewkb_to_geos(buf: &[u8]) -> databend_common_exception::Result<Geometry> {
+    Ewkb(buf)
+        .to_geos()
+        .map_err(|e| ErrorCode::GeometryError(e.to_string()))
+}
+
+pub(crate) fn geos_error(e: geos::Error) -> ErrorCode {
+    ErrorCode::GeometryError(e.to_string())
+}
+
+/// Decodes two geometries that must share the same SRID.
+fn ewkb_to_geos_pair(
+    l_geometry: &[u8],
+    r_geometry: &[u8],
+) -> databend_common_exception::Result<(Geometry, Geometry, Option<i32>)> {
+    let geos = vec![ewkb_to_geos(l_geometry)?, ewkb_to_geos(r_geometry)?];
+    let srid = get_shared_srid(&geos).map_err(ErrorCode::GeometryError)?;
+    let mut geos = geos.into_iter();
+    Ok((geos.next().unwrap(), geos.next().unwrap(), srid))
+}
+
+fn geos_predicate(
+    l_geometry: &[u8],
+    r_geometry: &[u8],
+    predicate: impl FnOnce(&Geometry, &Geometry) -> GResult<bool>,
+) -> databend_common_exception::Result<bool> {
+    let (l_geos, r_geos, _) = ewkb_to_geos_pair(l_geometry, r_geometry)?;
+    predicate(&l_geos, &r_geos).map_err(geos_error)
+}
+
+fn geos_unary_operation(
+    geometry: &[u8],
+    operation: impl FnOnce(&Geometry) -> GResult<Geometry>,
+) -> databend_common_exception::Result<Vec<u8>> {
+    let geos = ewkb_to_geos(geometry)?;
+    let srid = geos.srid();
+    operation(&geos)
+        .map_err(geos_error)?
+        .to_ewkb(CoordDimensions::xy(), srid)
+        .map_err(ErrorCode::from)
+}
+
+fn geos_binary_operation(
+    l_geometry: &[u8],
+    r_geometry: &[u8],
+    operation: impl FnOnce(&Geometry, &Geometry) -> GResult<Geometry>,
+) -> databend_common_exception::Result<Vec<u8>> {
+    let (l_geos, r_geos, srid) = ewkb_to_geos_pair(l_geometry, r_geometry)?;
+    operation(&l_geos, &r_geos)
+        .map_err(geos_error)?
+        .to_ewkb(CoordDimensions::xy(), srid)
+        .map_err(ErrorCode::from)
+}
+
+/// Sums the length of all polygon rings, lines and points have no perimeter.
+pub(crate) fn perimeter(
+    geom: &geo_types::Geometry,
+    ring_length: &impl Fn(&LineString) -> f64,
+) -> f64 {
+    match geom {
+        geo_types::Geometry::Polygon(polygon) => polygon_perimeter(polygon, ring_length),
+        geo_types::Geometry::MultiPolygon(multi_polygon) => multi_polygon
+            .iter()
+            .map(|polygon| polygon_perimeter(polygon, ring_length))
+            .sum(),
+        geo_types::Geometry::Rect(rect) => polygon_perimeter(&rect.to_polygon(), ring_length),
+        geo_types::Geometry::Triangle(triangle) => {
+            polygon_perimeter(&triangle.to_polygon(), ring_length)
+        }
+        geo_types::Geometry::GeometryCollection(collection) => collection
+            .iter()
+            .map(|geom| perimeter(geom, ring_length))
+            .sum(),
+        _ => 0.0,
+    }
+}
+
+fn polygon_perimeter(polygon: &Polygon, ring_length: &impl Fn(&LineString) -> f64) -> f64 {
+    ring_length(polygon.exterior()) + polygon.interiors().iter().map(ring_length).sum::<f64>()
 }
 
 fn st_transform_impl(
@@ -30,7 +30,9 @@ use databend_common_expression::types::NumberClass;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimeType;
 use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UuidType;
 use databend_common_expression::types::VariantType;
 use databend_common_expression::types::ALL_INTEGER_TYPES;
 use databend_common_expression::types::ALL_NUMBER_CLASSES;
@@ -57,6 +59,8 @@ pub fn register(registry: &mut FunctionRegistry) {
     register_simple_domain_type_hash::<StringType>(registry);
     register_simple_domain_type_hash::<DateType>(registry);
     register_simple_domain_type_hash::<TimestampType>(registry);
+    register_simple_domain_type_hash::<TimeType>(registry);
+    register_simple_domain_type_hash::<UuidType>(registry);
     register_simple_domain_type_hash::<BooleanType>(registry);
     register_simple_domain_type_hash::<BitmapType>(registry);
 
@@ -289,7 +293,8 @@ macro_rules! for_all_integer_types{
             { u16 },
             { u32 },
             { u64 },
-            { i128 }
+            { i128 },
+            { u128 }
         }
     };
 }
@@ -0,0 +1,360 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use databend_common_expression::error_to_null;
+use databend_common_expression::types::interval::string_to_interval;
+use databend_common_expression::types::interval::MICROS_PER_DAY;
+use databend_common_expression::types::interval::MICROS_PER_HOUR;
+use databend_common_expression::types::interval::MICROS_PER_MINUTE;
+use databend_common_expression::types::nullable::NullableDomain;
+use databend_common_expression::types::string::StringDomain;
+use databend_common_expression::types::timestamp::clamp_timestamp;
+use databend_common_expression::types::timestamp::MICROS_PER_SEC;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::types::Interval;
+use databend_common_expression::types::IntervalType;
+use databend_common_expression::types::NullableType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::utils::date_helper::EvalDaysImpl;
+use databend_common_expression::utils::date_helper::EvalMonthsImpl;
+use databend_common_expression::utils::date_helper::TzLUT;
+use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::EvalContext;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS interval)
+    // to_interval(xx)
+    register_string_to_interval(registry);
+    register_interval_to_string(registry);
+
+    // `INTERVAL 3 DAY` is resolved to `to_days(3)`
+    register_unit_to_interval(registry);
+
+    register_interval_arith(registry);
+    register_timestamp_interval_arith(registry);
+    register_interval_cmp(registry);
+}
+
+fn register_string_to_interval(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "to_interval",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_interval,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, IntervalType, _, _>(
+        "try_to_interval",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_interval),
+    );
+
+    fn eval_string_to_interval(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<IntervalType> {
+        vectorize_with_builder_1_arg::<StringType, IntervalType>(|val, output, ctx| {
+            match string_to_interval(val) {
+                Ok(interval) => output.push(interval),
+                Err(e) => {
+                    ctx.set_error(
+                        output.len(),
+                        format!("cannot parse to type `INTERVAL`. {}", e.message()),
+                    );
+                    output.push(Interval::default());
+                }
+            }
+        })(val, ctx)
+    }
+}
+
+fn register_interval_to_string(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<IntervalType, StringType>(|val, output, _| {
+            write!(output.row_buffer, "{}", val).unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_combine_nullable_1_arg::<IntervalType, StringType, _, _>(
+        "try_to_string",
+        |_, _| {
+            FunctionDomain::Domain(NullableDomain {
+                has_null: false,
+                value: Some(Box::new(StringDomain {
+                    min: "".to_string(),
+                    max: None,
+                })),
+            })
+        },
+        vectorize_with_builder_1_arg::<IntervalType, NullableType<StringType>>(|val, output, _| {
+            write!(output.builder.row_buffer, "{}", val).unwrap();
+            output.builder.commit_row();
+            output.validity.push(true);
+        }),
+    );
+}
+
+fn register_unit_to_interval(registry: &mut FunctionRegistry) {
+    fn register_unit(
+        registry: &mut FunctionRegistry,
+        name: &str,
+        to_interval: fn(i64) -> Option<Interval>,
+    ) {
+        registry.register_passthrough_nullable_1_arg::<Int64Type, IntervalType, _, _>(
+            name,
+            |_, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_1_arg::<Int64Type, IntervalType>(move |val, output, ctx| {
+                match to_interval(val) {
+                    Some(interval) => output.push(interval),
+                    None => {
+                        ctx.set_error(output.len(), "interval out of range");
+                        output.push(Interval::default());
+                    }
+                }
+            }),
+        );
+    }
+
+    register_unit(registry, "to_years", |n| {
+        Some(Interval::from_months(
+            i32::try_from(n.checked_mul(12)?).ok()?,
+        ))
+    });
+    register_unit(registry, "to_quarters", |n| {
+        Some(Interval::from_months(
+            i32::try_from(n.checked_mul(3)?).ok()?,
+        ))
+    });
+    register_unit(registry, "to_months", |n| {
+        Some(Interval::from_months(i32::try_from(n).ok()?))
+    });
+    register_unit(registry, "to_weeks", |n| {
+        Some(Interval::from_days(i32::try_from(n.checked_mul(7)?).ok()?))
+    });
+    register_unit(registry, "to_days", |n| {
+        Some(Interval::from_days(i32::try_from(n).ok()?))
+    });
+    register_unit(registry, "to_hours", |n| {
+        Some(Interval::from_micros(n.checked_mul(MICROS_PER_HOUR)?))
+    });
+    register_unit(registry, "to_minutes", |n| {
+        Some(Interval::from_micros(n.checked_mul(MICROS_PER_MINUTE)?))
+    });
+    register_unit(registry, "to_seconds", |n| {
+        Some(Interval::from_micros(n.checked_mul(MICROS_PER_SEC)?))
+    });
+}
+
+fn register_interval_arith(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "plus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |a, b, output, ctx| push_checked(a.checked_add(&b), output, ctx),
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, IntervalType, IntervalType>(
+            |a, b, output, ctx| push_checked(a.checked_sub(&b), output, ctx),
+        ),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<IntervalType, IntervalType, _, _>(
+        "minus",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<IntervalType, IntervalType>(|a, output, ctx| {
+            push_checked(a.checked_neg(), output, ctx)
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, Int64Type, IntervalType, _, _>(
+        "multiply",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, Int64Type, IntervalType>(
+            |a, b, output, ctx| push_checked(a.checked_mul(b), output, ctx),
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<Int64Type, IntervalType, IntervalType, _, _>(
+        "multiply",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<Int64Type, IntervalType, IntervalType>(
+            |a, b, output, ctx| push_checked(b.checked_mul(a), output, ctx),
+        ),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<IntervalType, Int64Type, IntervalType, _, _>(
+        "divide",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, Int64Type, IntervalType>(
+            |a, b, output, ctx| {
+                if b == 0 {
+                    ctx.set_error(output.len(), "divided by zero");
+                    output.push(Interval::default());
+                } else {
+                    push_checked(a.checked_div(b), output, ctx)
+                }
+            },
+        ),
+    );
+
+    // `avg(interval)` is rewritten to `sum(interval) / count(interval)`.
+    registry.register_passthrough_nullable_2_arg::<IntervalType, UInt64Type, IntervalType, _, _>(
+        "divide",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_2_arg::<IntervalType, UInt64Type, IntervalType>(
+            |a, b, output, ctx| match i64::try_from(b) {
+                Ok(0) => {
+                    ctx.set_error(output.len(), "divided by zero");
+                    output.push(Interval::default());
+                }
+                Ok(b) => push_checked(a.checked_div(b), output, ctx),
+                Err(_) => push_checked(Some(Interval::default()), output, ctx),
+            },
+        ),
+    );
+
+    fn push_checked(res: Option<Interval>, output: &mut Vec<Interval>, ctx: &mut EvalContext) {
+        match res {
+            Some(interval) => output.push(interval),
+            None => {
+                ctx.set_error(output.len(), "interval out of range");
+                output.push(Interval::default());
+            }
+        }
+    }
+}
+
+fn register_timestamp_interval_arith(registry: &mut FunctionRegistry) {
+    // `date + interval` auto casts the date to a timestamp, the same as
+    // `timestamp + interval` in PostgreSQL.
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "plus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, output, ctx| {
+                    push_timestamp(add_interval(ts, interval, ctx.func_ctx.tz), output, ctx)
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<IntervalType, TimestampType, TimestampType, _, _>(
+            "plus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<IntervalType, TimestampType, TimestampType>(
+                |interval, ts, output, ctx| {
+                    push_timestamp(add_interval(ts, interval, ctx.func_ctx.tz), output, ctx)
+                },
+            ),
+        );
+
+    registry
+        .register_passthrough_nullable_2_arg::<TimestampType, IntervalType, TimestampType, _, _>(
+            "minus",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_with_builder_2_arg::<TimestampType, IntervalType, TimestampType>(
+                |ts, interval, output, ctx| {
+                    let res = interval
+                        .checked_neg()
+                        .ok_or_else(|| "interval out of range".to_string())
+                        .and_then(|interval| add_interval(ts, interval, ctx.func_ctx.tz));
+                    push_timestamp(res, output, ctx)
+                },
+            ),
+        );
+
+    fn push_timestamp(res: Result<i64, String>, output: &mut Vec<i64>, ctx: &mut EvalContext) {
+        match res {
+            Ok(ts) => output.push(ts),
+            Err(e) => {
+                ctx.set_error(output.len(), e);
+                output.push(0);
+            }
+        }
+    }
+}
+
+/// Add the calendar parts first, so that `2024-01-31 + INTERVAL '1 month 1 day'`
+/// lands on `2024-03-01` like in PostgreSQL.
+fn add_interval(ts: i64, interval: Interval, tz: TzLUT) -> Result<i64, String> {
+    let mut ts = ts;
+    if interval.months != 0 {
+        ts = EvalMonthsImpl::eval_timestamp(ts, tz, interval.months)?;
+    }
+    if interval.days != 0 {
+        ts = EvalDaysImpl::eval_timestamp(ts, interval.days);
+    }
+    let mut ts = ts
+        .checked_add(interval.micros)
+        .ok_or_else(|| "timestamp out of range".to_string())?;
+    clamp_timestamp(&mut ts);
+    Ok(ts)
+}
+
+/// The difference of two timestamps, split into whole days and the rest.
+pub fn timestamp_diff_interval(lhs: i64, rhs: i64) -> Interval {
+    let diff = lhs as i128 - rhs as i128;
+    let days = diff / MICROS_PER_DAY as i128;
+    let micros = diff % MICROS_PER_DAY as i128;
+    Interval::new(0, days as i32, micros as i64)
+}
+
+fn register_interval_cmp(registry: &mut FunctionRegistry) {
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "eq",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs == rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "noteq",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs != rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "gt",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs > rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "gte",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs >= rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "lt",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs < rhs,
+    );
+    registry.register_2_arg::<IntervalType, IntervalType, BooleanType, _, _>(
+        "lte",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs <= rhs,
+    );
+}
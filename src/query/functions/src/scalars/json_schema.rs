@@ -0,0 +1,483 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use jsonb::Number;
+use jsonb::Object;
+use jsonb::Value;
+use regex::Regex;
+
+const JSON_TYPES: [&str; 7] = [
+    "null", "boolean", "object", "array", "number", "integer", "string",
+];
+
+/// A compiled JSON schema, it supports the validation keywords of JSON Schema
+/// draft 2020-12 (and the tuple form of `items` of draft 7), references and
+/// formats are not supported.
+pub enum JsonSchema<'a> {
+    Bool(bool),
+    Object(Box<SchemaObject<'a>>),
+}
+
+#[derive(Default)]
+pub struct SchemaObject<'a> {
+    types: Option<Vec<String>>,
+    enum_values: Option<Vec<Value<'a>>>,
+    const_value: Option<Value<'a>>,
+
+    properties: BTreeMap<String, JsonSchema<'a>>,
+    required: Vec<String>,
+    additional_properties: Option<JsonSchema<'a>>,
+    min_properties: Option<usize>,
+    max_properties: Option<usize>,
+
+    prefix_items: Vec<JsonSchema<'a>>,
+    items: Option<JsonSchema<'a>>,
+    additional_items: Option<JsonSchema<'a>>,
+    min_items: Option<usize>,
+    max_items: Option<usize>,
+    unique_items: bool,
+
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    pattern: Option<Regex>,
+
+    minimum: Option<f64>,
+    maximum: Option<f64>,
+    exclusive_minimum: Option<f64>,
+    exclusive_maximum: Option<f64>,
+    multiple_of: Option<f64>,
+
+    all_of: Vec<JsonSchema<'a>>,
+    any_of: Option<Vec<JsonSchema<'a>>>,
+    one_of: Option<Vec<JsonSchema<'a>>>,
+    not: Option<JsonSchema<'a>>,
+}
+
+impl<'a> JsonSchema<'a> {
+    pub fn try_create(schema: &Value<'a>) -> Result<JsonSchema<'a>, String> {
+        match schema {
+            Value::Bool(v) => Ok(JsonSchema::Bool(*v)),
+            Value::Object(obj) => Ok(JsonSchema::Object(Box::new(SchemaObject::try_create(obj)?))),
+            _ => Err("Invalid JSON schema, the schema must be an object or a boolean".to_string()),
+        }
+    }
+
+    /// Validates the value against the schema, each error is prefixed with
+    /// the JSON Path of the invalid value, no errors means the value is valid.
+    pub fn validate(&self, value: &Value) -> Vec<String> {
+        let mut errors = vec![];
+        self.validate_at(value, "$", &mut errors);
+        errors
+    }
+
+    fn is_valid(&self, value: &Value) -> bool {
+        self.validate(value).is_empty()
+    }
+
+    fn validate_at(&self, value: &Value, location: &str, errors: &mut Vec<String>) {
+        match self {
+            JsonSchema::Bool(true) => {}
+            JsonSchema::Bool(false) => errors.push(format!("{location}: value is not allowed")),
+            JsonSchema::Object(schema) => schema.validate_at(value, location, errors),
+        }
+    }
+}
+
+impl<'a> SchemaObject<'a> {
+    fn try_create(obj: &Object<'a>) -> Result<SchemaObject<'a>, String> {
+        let mut schema = SchemaObject::default();
+        for (keyword, value) in obj.iter() {
+            let keyword = keyword.as_str();
+            match keyword {
+                "type" => {
+                    let types = match value {
+                        Value::String(v) => vec![v.to_string()],
+                        Value::Array(_) => keyword_strings(keyword, value)?,
+                        _ => return Err(invalid_keyword(keyword)),
+                    };
+                    if let Some(v) = types.iter().find(|v| !JSON_TYPES.contains(&v.as_str())) {
+                        return Err(format!("Invalid JSON schema, unknown type '{v}'"));
+                    }
+                    schema.types = Some(types);
+                }
+                "enum" => match value {
+                    Value::Array(values) => schema.enum_values = Some(values.clone()),
+                    _ => return Err(invalid_keyword(keyword)),
+                },
+                "const" => schema.const_value = Some(value.clone()),
+                "properties" => match value {
+                    Value::Object(properties) => {
+                        for (name, property) in properties.iter() {
+                            schema
+                                .properties
+                                .insert(name.clone(), JsonSchema::try_create(property)?);
+                        }
+                    }
+                    _ => return Err(invalid_keyword(keyword)),
+                },
+                "required" => schema.required = keyword_strings(keyword, value)?,
+                "additionalProperties" => {
+                    schema.additional_properties = Some(JsonSchema::try_create(value)?)
+                }
+                "minProperties" => schema.min_properties = Some(keyword_usize(keyword, value)?),
+                "maxProperties" => schema.max_properties = Some(keyword_usize(keyword, value)?),
+                "prefixItems" => schema.prefix_items = keyword_schemas(keyword, value)?,
+                "items" => match value {
+                    Value::Array(_) => schema.prefix_items = keyword_schemas(keyword, value)?,
+                    _ => schema.items = Some(JsonSchema::try_create(value)?),
+                },
+                "additionalItems" => schema.additional_items = Some(JsonSchema::try_create(value)?),
+                "minItems" => schema.min_items = Some(keyword_usize(keyword, value)?),
+                "maxItems" => schema.max_items = Some(keyword_usize(keyword, value)?),
+                "uniqueItems" => match value {
+                    Value::Bool(v) => schema.unique_items = *v,
+                    _ => return Err(invalid_keyword(keyword)),
+                },
+                "minLength" => schema.min_length = Some(keyword_usize(keyword, value)?),
+                "maxLength" => schema.max_length = Some(keyword_usize(keyword, value)?),
+                "pattern" => match value {
+                    Value::String(pattern) => {
+                        let regex = Regex::new(pattern).map_err(|e| {
+                            format!("Invalid JSON schema, invalid pattern '{pattern}': {e}")
+                        })?;
+                        schema.pattern = Some(regex);
+                    }
+                    _ => return Err(invalid_keyword(keyword)),
+                },
+                "minimum" => schema.minimum = Some(keyword_f64(keyword, value)?),
+                "maximum" => schema.maximum = Some(keyword_f64(keyword, value)?),
+                "exclusiveMinimum" => schema.exclusive_minimum = Some(keyword_f64(keyword, value)?),
+                "exclusiveMaximum" => schema.exclusive_maximum = Some(keyword_f64(keyword, value)?),
+                "multipleOf" => {
+                    let v = keyword_f64(keyword, value)?;
+                    if v <= 0.0 {
+                        return Err(invalid_keyword(keyword));
+                    }
+                    schema.multiple_of = Some(v);
+                }
+                "allOf" => schema.all_of = keyword_schemas(keyword, value)?,
+                "anyOf" => schema.any_of = Some(keyword_schemas(keyword, value)?),
+                "oneOf" => schema.one_of = Some(keyword_schemas(keyword, value)?),
+                "not" => schema.not = Some(JsonSchema::try_create(value)?),
+                "$ref" | "$dynamicRef" | "$recursiveRef" => {
+                    return Err(format!(
+                        "Invalid JSON schema, keyword '{keyword}' is not supported"
+                    ));
+                }
+                // Annotations and unknown keywords are ignored
+                _ => {}
+            }
+        }
+        Ok(schema)
+    }
+
+    fn validate_at(&self, value: &Value, location: &str, errors: &mut Vec<String>) {
+        if let Some(types) = &self.types {
+            if !types.iter().any(|t| is_json_type(value, t)) {
+                errors.push(format!(
+                    "{location}: expected type {}, but got {}",
+                    types.join(" or "),
+                    json_type_name(value)
+                ));
+            }
+        }
+        if let Some(values) = &self.enum_values {
+            if !values.iter().any(|v| json_equals(v, value)) {
+                errors.push(format!("{location}: value is not one of the enum values"));
+            }
+        }
+        if let Some(v) = &self.const_value {
+            if !json_equals(v, value) {
+                errors.push(format!("{location}: value is not equal to the const value"));
+            }
+        }
+
+        match value {
+            Value::Object(obj) => self.validate_object(obj, location, errors),
+            Value::Array(items) => self.validate_array(items, location, errors),
+            Value::String(v) => self.validate_string(v, location, errors),
+            Value::Number(v) => self.validate_number(v, location, errors),
+            _ => {}
+        }
+
+        for schema in self.all_of.iter() {
+            schema.validate_at(value, location, errors);
+        }
+        if let Some(any_of) = &self.any_of {
+            if !any_of.iter().any(|schema| schema.is_valid(value)) {
+                errors.push(format!(
+                    "{location}: value does not match any of the schemas in anyOf"
+                ));
+            }
+        }
+        if let Some(one_of) = &self.one_of {
+            let matched = one_of
+                .iter()
+                .filter(|schema| schema.is_valid(value))
+                .count();
+            if matched != 1 {
+                errors.push(format!(
+                    "{location}: value matches {matched} schemas in oneOf, but expected exactly one"
+                ));
+            }
+        }
+        if let Some(not) = &self.not {
+            if not.is_valid(value) {
+                errors.push(format!(
+                    "{location}: value must not match the schema in not"
+                ));
+            }
+        }
+    }
+
+    fn validate_object(&self, obj: &Object, location: &str, errors: &mut Vec<String>) {
+        for name in self.required.iter() {
+            if !obj.contains_key(name) {
+                errors.push(format!("{location}: missing required property '{name}'"));
+            }
+        }
+        for (name, value) in obj.iter() {
+            let schema = match self.properties.get(name) {
+                Some(schema) => schema,
+                None => match &self.additional_properties {
+                    Some(JsonSchema::Bool(false)) => {
+                        errors.push(format!(
+                            "{location}: additional property '{name}' is not allowed"
+                        ));
+                        continue;
+                    }
+                    Some(schema) => schema,
+                    None => continue,
+                },
+            };
+            schema.validate_at(value, &property_location(location, name), errors);
+        }
+        if let Some(min) = self.min_properties {
+            if obj.len() < min {
+                errors.push(format!(
+                    "{location}: expected at least {min} properties, but got {}",
+                    obj.len()
+                ));
+            }
+        }
+        if let Some(max) = self.max_properties {
+            if obj.len() > max {
+                errors.push(format!(
+                    "{location}: expected at most {max} properties, but got {}",
+                    obj.len()
+                ));
+            }
+        }
+    }
+
+    fn validate_array(&self, items: &[Value], location: &str, errors: &mut Vec<String>) {
+        for (i, item) in items.iter().enumerate() {
+            let schema = match self.prefix_items.get(i) {
+                Some(schema) => Some(schema),
+                None => self.items.as_ref().or(self.additional_items.as_ref()),
+            };
+            if let Some(schema) = schema {
+                schema.validate_at(item, &format!("{location}[{i}]"), errors);
+            }
+        }
+        if let Some(min) = self.min_items {
+            if items.len() < min {
+                errors.push(format!(
+                    "{location}: expected at least {min} items, but got {}",
+                    items.len()
+                ));
+            }
+        }
+        if let Some(max) = self.max_items {
+            if items.len() > max {
+                errors.push(format!(
+                    "{location}: expected at most {max} items, but got {}",
+                    items.len()
+                ));
+            }
+        }
+        if self.unique_items {
+            let duplicated = items
+                .iter()
+                .enumerate()
+                .any(|(i, a)| items[i + 1..].iter().any(|b| json_equals(a, b)));
+            if duplicated {
+                errors.push(format!("{location}: items are not unique"));
+            }
+        }
+    }
+
+    fn validate_string(&self, v: &str, location: &str, errors: &mut Vec<String>) {
+        let len = v.chars().count();
+        if let Some(min) = self.min_length {
+            if len < min {
+                errors.push(format!(
+                    "{location}: expected at least {min} characters, but got {len}"
+                ));
+            }
+        }
+        if let Some(max) = self.max_length {
+            if len > max {
+                errors.push(format!(
+                    "{location}: expected at most {max} characters, but got {len}"
+                ));
+            }
+        }
+        if let Some(pattern) = &self.pattern {
+            if !pattern.is_match(v) {
+                errors.push(format!(
+                    "{location}: string does not match pattern '{}'",
+                    pattern.as_str()
+                ));
+            }
+        }
+    }
+
+    fn validate_number(&self, v: &Number, location: &str, errors: &mut Vec<String>) {
+        let Some(v) = v.as_f64() else {
+            return;
+        };
+        if let Some(min) = self.minimum {
+            if v < min {
+                errors.push(format!("{location}: {v} is less than the minimum {min}"));
+            }
+        }
+        if let Some(max) = self.maximum {
+            if v > max {
+                errors.push(format!("{location}: {v} is greater than the maximum {max}"));
+            }
+        }
+        if let Some(min) = self.exclusive_minimum {
+            if v <= min {
+                errors.push(format!(
+                    "{location}: {v} is less than or equal to the exclusive minimum {min}"
+                ));
+            }
+        }
+        if let Some(max) = self.exclusive_maximum {
+            if v >= max {
+                errors.push(format!(
+                    "{location}: {v} is greater than or equal to the exclusive maximum {max}"
+                ));
+            }
+        }
+        if let Some(m) = self.multiple_of {
+            let quotient = v / m;
+            if (quotient - quotient.round()).abs() > 1e-9 {
+                errors.push(format!("{location}: {v} is not a multiple of {m}"));
+            }
+        }
+    }
+}
+
+fn invalid_keyword(keyword: &str) -> String {
+    format!("Invalid JSON schema, invalid value of keyword '{keyword}'")
+}
+
+fn keyword_usize(keyword: &str, value: &Value) -> Result<usize, String> {
+    match value {
+        Value::Number(v) => match v.as_f64() {
+            Some(v) if v >= 0.0 && v.fract() == 0.0 => Ok(v as usize),
+            _ => Err(invalid_keyword(keyword)),
+        },
+        _ => Err(invalid_keyword(keyword)),
+    }
+}
+
+fn keyword_f64(keyword: &str, value: &Value) -> Result<f64, String> {
+    match value {
+        Value::Number(v) => v.as_f64().ok_or_else(|| invalid_keyword(keyword)),
+        _ => Err(invalid_keyword(keyword)),
+    }
+}
+
+fn keyword_strings(keyword: &str, value: &Value) -> Result<Vec<String>, String> {
+    match value {
+        Value::Array(values) => values
+            .iter()
+            .map(|v| match v {
+                Value::String(v) => Ok(v.to_string()),
+                _ => Err(invalid_keyword(keyword)),
+            })
+            .collect(),
+        _ => Err(invalid_keyword(keyword)),
+    }
+}
+
+fn keyword_schemas<'a>(keyword: &str, value: &Value<'a>) -> Result<Vec<JsonSchema<'a>>, String> {
+    match value {
+        Value::Array(values) if !values.is_empty() => {
+            values.iter().map(JsonSchema::try_create).collect()
+        }
+        _ => Err(invalid_keyword(keyword)),
+    }
+}
+
+fn property_location(location: &str, name: &str) -> String {
+    if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        format!("{location}.{name}")
+    } else {
+        format!("{location}[{name:?}]")
+    }
+}
+
+fn is_integer(v: &Number) -> bool {
+    v.as_f64().map(|v| v.fract() == 0.0).unwrap_or(false)
+}
+
+fn is_json_type(value: &Value, json_type: &str) -> bool {
+    match (json_type, value) {
+        ("null", Value::Null)
+        | ("boolean", Value::Bool(_))
+        | ("string", Value::String(_))
+        | ("number", Value::Number(_))
+        | ("array", Value::Array(_))
+        | ("object", Value::Object(_)) => true,
+        ("integer", Value::Number(v)) => is_integer(v),
+        _ => false,
+    }
+}
+
+fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::String(_) => "string",
+        Value::Number(v) if is_integer(v) => "integer",
+        Value::Number(_) => "number",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+// Numbers are equal if they have the same mathematical value, like `1` and `1.0`.
+fn json_equals(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Null, Value::Null) => true,
+        (Value::Bool(a), Value::Bool(b)) => a == b,
+        (Value::String(a), Value::String(b)) => a == b,
+        (Value::Number(a), Value::Number(b)) => a.as_f64() == b.as_f64(),
+        (Value::Array(a), Value::Array(b)) => {
+            a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| json_equals(a, b))
+        }
+        (Value::Object(a), Value::Object(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .all(|(k, v)| b.get(k).map(|w| json_equals(v, w)).unwrap_or(false))
+        }
+        _ => false,
+    }
+}
@@ -24,18 +24,24 @@ mod comparison;
 mod control;
 mod datetime;
 mod decimal;
+mod enumeration;
 mod geo;
 mod geo_h3;
 mod geography;
 mod geometry;
 mod hash;
 mod hilbert;
+mod interval;
+mod json_schema;
 mod map;
 mod math;
 mod other;
 mod string;
 mod string_multi_args;
+mod time;
+mod timestamp_tz;
 mod tuple;
+mod uuid;
 mod variant;
 mod vector;
 
@@ -66,4 +72,9 @@ pub fn register(registry: &mut FunctionRegistry) {
     geometry::register(registry);
     geography::register(registry);
     hilbert::register(registry);
+    interval::register(registry);
+    timestamp_tz::register(registry);
+    time::register(registry);
+    uuid::register(registry);
+    enumeration::register(registry);
 }
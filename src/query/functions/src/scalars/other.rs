@@ -12,7 +12,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::io::Write;
 use std::net::Ipv4Addr;
 use std::sync::Arc;
 use std::time::Duration;
@@ -22,17 +21,17 @@ use databend_common_base::base::convert_number_size;
 use databend_common_base::base::uuid::Uuid;
 use databend_common_base::base::OrderedFloat;
 use databend_common_expression::error_to_null;
-use databend_common_expression::types::binary::BinaryColumnBuilder;
 use databend_common_expression::types::boolean::BooleanDomain;
 use databend_common_expression::types::nullable::NullableColumn;
 use databend_common_expression::types::number::Float32Type;
 use databend_common_expression::types::number::Float64Type;
 use databend_common_expression::types::number::Int64Type;
 use databend_common_expression::types::number::UInt32Type;
+use databend_common_expression::types::number::UInt64Type;
 use databend_common_expression::types::number::UInt8Type;
 use databend_common_expression::types::number::F64;
-use databend_common_expression::types::string::StringColumn;
 use databend_common_expression::types::ArgType;
+use databend_common_expression::types::BinaryType;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::DateType;
 use databend_common_expression::types::GenericType;
@@ -45,6 +44,7 @@ use databend_common_expression::types::NumberType;
 use databend_common_expression::types::SimpleDomain;
 use databend_common_expression::types::StringType;
 use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UuidType;
 use databend_common_expression::types::ValueType;
 use databend_common_expression::vectorize_with_builder_1_arg;
 use databend_common_expression::vectorize_with_builder_2_arg;
@@ -62,6 +62,7 @@ use databend_common_expression::ScalarRef;
 use databend_common_expression::Value;
 use databend_common_expression::ValueRef;
 use databend_common_io::number::FmtCacheEntry;
+use databend_common_io::ThetaSketch;
 use rand::Rng;
 use rand::SeedableRng;
 
@@ -78,6 +79,7 @@ pub fn register(registry: &mut FunctionRegistry) {
     register_run_diff(registry);
     register_grouping(registry);
     register_num_to_char(registry);
+    register_theta_sketch(registry);
 
     registry.properties.insert(
         "rand".to_string(),
@@ -227,20 +229,15 @@ pub fn register(registry: &mut FunctionRegistry) {
             |val, _| val.to_owned(),
         );
 
-    registry.register_0_arg_core::<StringType, _, _>(
+    registry.register_0_arg_core::<UuidType, _, _>(
         "gen_random_uuid",
         |_| FunctionDomain::Full,
         |ctx| {
-            let mut builder = BinaryColumnBuilder::with_capacity(ctx.num_rows, 0);
-
-            for _ in 0..ctx.num_rows {
-                let value = Uuid::now_v7();
-                write!(&mut builder.data, "{}", value).unwrap();
-                builder.commit_row();
-            }
-
-            let col = StringColumn::try_from(builder.build()).unwrap();
-            Value::Column(col)
+            Value::Column(
+                (0..ctx.num_rows)
+                    .map(|_| Uuid::now_v7().as_u128())
+                    .collect(),
+            )
         },
     );
 }
@@ -385,6 +382,28 @@ fn register_grouping(registry: &mut FunctionRegistry) {
     })
 }
 
+fn register_theta_sketch(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<BinaryType, UInt64Type, _, _>(
+        "theta_sketch_estimate",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_with_builder_1_arg::<BinaryType, UInt64Type>(|sketch, output, ctx| {
+            if let Some(validity) = &ctx.validity {
+                if !validity.get_bit(output.len()) {
+                    output.push(0);
+                    return;
+                }
+            }
+            match ThetaSketch::deserialize(sketch) {
+                Ok(sketch) => output.push(sketch.estimate().round() as u64),
+                Err(e) => {
+                    ctx.set_error(output.len(), e.to_string());
+                    output.push(0);
+                }
+            }
+        }),
+    );
+}
+
 fn register_num_to_char(registry: &mut FunctionRegistry) {
     registry.register_passthrough_nullable_2_arg::<Int64Type, StringType, StringType, _, _>(
         "to_char",
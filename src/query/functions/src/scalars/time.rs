@@ -0,0 +1,132 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use databend_common_expression::date_helper::DateConverter;
+use databend_common_expression::error_to_null;
+use databend_common_expression::types::nullable::NullableDomain;
+use databend_common_expression::types::string::StringDomain;
+use databend_common_expression::types::time::naive_time_to_micros;
+use databend_common_expression::types::time::string_to_time;
+use databend_common_expression::types::time::time_to_string;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::NullableType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimeType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::EvalContext;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::SimpleDomainCmp;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+
+use super::comparison::register_simple_domain_type_cmp;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS time)
+    // to_time(xx)
+    register_string_to_time(registry);
+    register_timestamp_to_time(registry);
+    register_time_to_string(registry);
+
+    register_time_cmp(registry);
+}
+
+fn register_string_to_time(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, TimeType, _, _>(
+        "to_time",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_time,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, TimeType, _, _>(
+        "try_to_time",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_time),
+    );
+
+    fn eval_string_to_time(val: ValueRef<StringType>, ctx: &mut EvalContext) -> Value<TimeType> {
+        vectorize_with_builder_1_arg::<StringType, TimeType>(
+            |val, output, ctx| match string_to_time(val) {
+                Ok(time) => output.push(time),
+                Err(e) => {
+                    ctx.set_error(
+                        output.len(),
+                        format!("cannot parse to type `TIME`. {}", e.message()),
+                    );
+                    output.push(0);
+                }
+            },
+        )(val, ctx)
+    }
+}
+
+fn register_timestamp_to_time(registry: &mut FunctionRegistry) {
+    // The time of day is taken in the session `timezone`.
+    registry.register_passthrough_nullable_1_arg::<TimestampType, TimeType, _, _>(
+        "to_time",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<TimestampType, TimeType>(|val, output, ctx| {
+            output.push(naive_time_to_micros(
+                &val.to_timestamp(ctx.func_ctx.tz.tz).time(),
+            ))
+        }),
+    );
+    registry.register_combine_nullable_1_arg::<TimestampType, TimeType, _, _>(
+        "try_to_time",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<TimestampType, NullableType<TimeType>>(
+            |val, output, ctx| {
+                output.push(naive_time_to_micros(
+                    &val.to_timestamp(ctx.func_ctx.tz.tz).time(),
+                ))
+            },
+        ),
+    );
+}
+
+fn register_time_to_string(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<TimeType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<TimeType, StringType>(|val, output, _| {
+            write!(output.row_buffer, "{}", time_to_string(val)).unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_combine_nullable_1_arg::<TimeType, StringType, _, _>(
+        "try_to_string",
+        |_, _| {
+            FunctionDomain::Domain(NullableDomain {
+                has_null: false,
+                value: Some(Box::new(StringDomain {
+                    min: "".to_string(),
+                    max: None,
+                })),
+            })
+        },
+        vectorize_with_builder_1_arg::<TimeType, NullableType<StringType>>(|val, output, _| {
+            write!(output.builder.row_buffer, "{}", time_to_string(val)).unwrap();
+            output.builder.commit_row();
+            output.validity.push(true);
+        }),
+    );
+}
+
+fn register_time_cmp(registry: &mut FunctionRegistry) {
+    register_simple_domain_type_cmp!(registry, TimeType);
+}
@@ -0,0 +1,173 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use databend_common_expression::error_to_null;
+use databend_common_expression::types::nullable::NullableDomain;
+use databend_common_expression::types::string::StringDomain;
+use databend_common_expression::types::timestamp_tz::string_to_timestamp_tz;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::NullableType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::TimestampTz;
+use databend_common_expression::types::TimestampTzType;
+use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::EvalContext;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS timestamp_tz)
+    // to_timestamp_tz(xx)
+    register_string_to_timestamp_tz(registry);
+    register_timestamp_to_timestamp_tz(registry);
+    register_timestamp_tz_to_string(registry);
+
+    // Values are compared by the instant they refer to, whatever their offset.
+    register_timestamp_tz_cmp(registry);
+}
+
+fn register_string_to_timestamp_tz(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, TimestampTzType, _, _>(
+        "to_timestamp_tz",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_timestamp_tz,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, TimestampTzType, _, _>(
+        "try_to_timestamp_tz",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_timestamp_tz),
+    );
+
+    fn eval_string_to_timestamp_tz(
+        val: ValueRef<StringType>,
+        ctx: &mut EvalContext,
+    ) -> Value<TimestampTzType> {
+        vectorize_with_builder_1_arg::<StringType, TimestampTzType>(|val, output, ctx| {
+            match string_to_timestamp_tz(val, ctx.func_ctx.tz.tz, ctx.func_ctx.enable_dst_hour_fix)
+            {
+                Ok(ts) => output.push(ts),
+                Err(e) => {
+                    ctx.set_error(
+                        output.len(),
+                        format!("cannot parse to type `TIMESTAMP_TZ`. {}", e.message()),
+                    );
+                    output.push(TimestampTz::default());
+                }
+            }
+        })(val, ctx)
+    }
+}
+
+fn register_timestamp_to_timestamp_tz(registry: &mut FunctionRegistry) {
+    // A plain timestamp takes the offset of the session `timezone`.
+    registry.register_passthrough_nullable_1_arg::<TimestampType, TimestampTzType, _, _>(
+        "to_timestamp_tz",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<TimestampType, TimestampTzType>(|val, output, ctx| {
+            output.push(TimestampTz::from_timestamp(val, ctx.func_ctx.tz.tz))
+        }),
+    );
+    registry.register_combine_nullable_1_arg::<TimestampType, TimestampTzType, _, _>(
+        "try_to_timestamp_tz",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<TimestampType, NullableType<TimestampTzType>>(
+            |val, output, ctx| output.push(TimestampTz::from_timestamp(val, ctx.func_ctx.tz.tz)),
+        ),
+    );
+
+    // Dropping the offset keeps the instant.
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, TimestampType, _, _>(
+        "to_timestamp",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<TimestampTzType, TimestampType>(|val, output, _| {
+            output.push(val.micros)
+        }),
+    );
+    registry.register_combine_nullable_1_arg::<TimestampTzType, TimestampType, _, _>(
+        "try_to_timestamp",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<TimestampTzType, NullableType<TimestampType>>(
+            |val, output, _| output.push(val.micros),
+        ),
+    );
+}
+
+fn register_timestamp_tz_to_string(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<TimestampTzType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<TimestampTzType, StringType>(|val, output, _| {
+            write!(output.row_buffer, "{}", val).unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_combine_nullable_1_arg::<TimestampTzType, StringType, _, _>(
+        "try_to_string",
+        |_, _| {
+            FunctionDomain::Domain(NullableDomain {
+                has_null: false,
+                value: Some(Box::new(StringDomain {
+                    min: "".to_string(),
+                    max: None,
+                })),
+            })
+        },
+        vectorize_with_builder_1_arg::<TimestampTzType, NullableType<StringType>>(
+            |val, output, _| {
+                write!(output.builder.row_buffer, "{}", val).unwrap();
+                output.builder.commit_row();
+                output.validity.push(true);
+            },
+        ),
+    );
+}
+
+fn register_timestamp_tz_cmp(registry: &mut FunctionRegistry) {
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "eq",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs.micros == rhs.micros,
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "noteq",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs.micros != rhs.micros,
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "gt",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs.micros > rhs.micros,
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "gte",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs.micros >= rhs.micros,
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "lt",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs.micros < rhs.micros,
+    );
+    registry.register_2_arg::<TimestampTzType, TimestampTzType, BooleanType, _, _>(
+        "lte",
+        |_, _, _| FunctionDomain::Full,
+        |lhs, rhs, _| lhs.micros <= rhs.micros,
+    );
+}
@@ -0,0 +1,124 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+
+use databend_common_base::base::uuid::Uuid;
+use databend_common_expression::error_to_null;
+use databend_common_expression::types::nullable::NullableDomain;
+use databend_common_expression::types::string::StringDomain;
+use databend_common_expression::types::uuid::string_to_uuid;
+use databend_common_expression::types::uuid::uuid_to_string;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::NullableType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UuidType;
+use databend_common_expression::vectorize_with_builder_1_arg;
+use databend_common_expression::EvalContext;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionProperty;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::SimpleDomainCmp;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+
+use super::comparison::register_simple_domain_type_cmp;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    // cast(xx AS uuid)
+    // to_uuid(xx)
+    register_string_to_uuid(registry);
+    register_uuid_to_string(registry);
+
+    register_uuid_cmp(registry);
+
+    registry.properties.insert(
+        "uuid_v7".to_string(),
+        FunctionProperty::default().non_deterministic(),
+    );
+
+    // Time-ordered UUIDs, so values generated later sort after earlier ones.
+    registry.register_0_arg_core::<UuidType, _, _>(
+        "uuid_v7",
+        |_| FunctionDomain::Full,
+        |ctx| {
+            Value::Column(
+                (0..ctx.num_rows)
+                    .map(|_| Uuid::now_v7().as_u128())
+                    .collect(),
+            )
+        },
+    );
+}
+
+fn register_string_to_uuid(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<StringType, UuidType, _, _>(
+        "to_uuid",
+        |_, _| FunctionDomain::MayThrow,
+        eval_string_to_uuid,
+    );
+    registry.register_combine_nullable_1_arg::<StringType, UuidType, _, _>(
+        "try_to_uuid",
+        |_, _| FunctionDomain::Full,
+        error_to_null(eval_string_to_uuid),
+    );
+
+    fn eval_string_to_uuid(val: ValueRef<StringType>, ctx: &mut EvalContext) -> Value<UuidType> {
+        vectorize_with_builder_1_arg::<StringType, UuidType>(
+            |val, output, ctx| match string_to_uuid(val) {
+                Ok(uuid) => output.push(uuid),
+                Err(e) => {
+                    ctx.set_error(
+                        output.len(),
+                        format!("cannot parse to type `UUID`. {}", e.message()),
+                    );
+                    output.push(0);
+                }
+            },
+        )(val, ctx)
+    }
+}
+
+fn register_uuid_to_string(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_1_arg::<UuidType, StringType, _, _>(
+        "to_string",
+        |_, _| FunctionDomain::Full,
+        vectorize_with_builder_1_arg::<UuidType, StringType>(|val, output, _| {
+            write!(output.row_buffer, "{}", uuid_to_string(val)).unwrap();
+            output.commit_row();
+        }),
+    );
+
+    registry.register_combine_nullable_1_arg::<UuidType, StringType, _, _>(
+        "try_to_string",
+        |_, _| {
+            FunctionDomain::Domain(NullableDomain {
+                has_null: false,
+                value: Some(Box::new(StringDomain {
+                    min: "".to_string(),
+                    max: None,
+                })),
+            })
+        },
+        vectorize_with_builder_1_arg::<UuidType, NullableType<StringType>>(|val, output, _| {
+            write!(output.builder.row_buffer, "{}", uuid_to_string(val)).unwrap();
+            output.builder.commit_row();
+            output.validity.push(true);
+        }),
+    );
+}
+
+fn register_uuid_cmp(registry: &mut FunctionRegistry) {
+    register_simple_domain_type_cmp!(registry, UuidType);
+}
@@ -23,6 +23,7 @@ use chrono::Datelike;
 use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_arrow::arrow::bitmap::MutableBitmap;
 use databend_common_arrow::arrow::temporal_conversions::EPOCH_DAYS_FROM_CE;
+use databend_common_expression::types::array::ArrayColumnBuilder;
 use databend_common_expression::types::binary::BinaryColumnBuilder;
 use databend_common_expression::types::date::string_to_date;
 use databend_common_expression::types::nullable::NullableColumn;
@@ -44,6 +45,7 @@ use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::StringType;
 use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::ValueType;
 use databend_common_expression::types::VariantType;
 use databend_common_expression::types::ALL_NUMERICS_TYPES;
 use databend_common_expression::vectorize_1_arg;
@@ -97,7 +99,10 @@ use jsonb::is_null;
 use jsonb::is_object;
 use jsonb::is_string;
 use jsonb::jsonpath::parse_json_path;
+use jsonb::jsonpath::ArrayIndex;
+use jsonb::jsonpath::Index;
 use jsonb::jsonpath::JsonPath;
+use jsonb::jsonpath::Path;
 use jsonb::keypath::parse_key_paths;
 use jsonb::object_keys;
 use jsonb::parse_value;
@@ -113,6 +118,8 @@ use jsonb::to_string;
 use jsonb::to_u64;
 use jsonb::type_of;
 
+use super::json_schema::JsonSchema;
+
 pub fn register(registry: &mut FunctionRegistry) {
     registry.register_aliases("json_object_keys", &["object_keys"]);
     registry.register_aliases("to_string", &["json_to_string"]);
@@ -1745,6 +1752,110 @@ pub fn register(registry: &mut FunctionRegistry) {
             },
         }))
     });
+
+    for (name, mode) in [
+        ("json_set", JsonModifyMode::Set),
+        ("json_replace", JsonModifyMode::Replace),
+    ] {
+        registry.register_function_factory(name, move |_, args_type| {
+            if args_type.len() != 3 {
+                return None;
+            }
+            if (args_type[0].remove_nullable() != DataType::Variant
+                && args_type[0] != DataType::Null)
+                || (args_type[1].remove_nullable() != DataType::String
+                    && args_type[1] != DataType::Null)
+            {
+                return None;
+            }
+            let is_nullable =
+                args_type[0].is_nullable_or_null() || args_type[1].is_nullable_or_null();
+            let return_type = if is_nullable {
+                DataType::Nullable(Box::new(DataType::Variant))
+            } else {
+                DataType::Variant
+            };
+            Some(Arc::new(Function {
+                signature: FunctionSignature {
+                    name: name.to_string(),
+                    args_type: args_type.to_vec(),
+                    return_type,
+                },
+                eval: FunctionEval::Scalar {
+                    calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+                    eval: Box::new(move |args, ctx| {
+                        json_modify_by_path_fn(args, ctx, mode, is_nullable)
+                    }),
+                },
+            }))
+        });
+    }
+
+    registry.register_function_factory("json_remove", |_, args_type| {
+        if args_type.len() < 2 {
+            return None;
+        }
+        if args_type[0].remove_nullable() != DataType::Variant && args_type[0] != DataType::Null {
+            return None;
+        }
+        for arg_type in args_type.iter().skip(1) {
+            if arg_type.remove_nullable() != DataType::String && *arg_type != DataType::Null {
+                return None;
+            }
+        }
+        let is_nullable = args_type
+            .iter()
+            .any(|arg_type| arg_type.is_nullable_or_null());
+        let return_type = if is_nullable {
+            DataType::Nullable(Box::new(DataType::Variant))
+        } else {
+            DataType::Variant
+        };
+        Some(Arc::new(Function {
+            signature: FunctionSignature {
+                name: "json_remove".to_string(),
+                args_type: args_type.to_vec(),
+                return_type,
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+                eval: Box::new(move |args, ctx| {
+                    json_modify_by_path_fn(args, ctx, JsonModifyMode::Remove, is_nullable)
+                }),
+            },
+        }))
+    });
+
+    registry.register_function_factory("json_validate_schema", |_, args_type| {
+        if args_type.len() != 2 {
+            return None;
+        }
+        if (args_type[0].remove_nullable() != DataType::Variant && args_type[0] != DataType::Null)
+            || (args_type[1].remove_nullable() != DataType::Variant
+                && args_type[1].remove_nullable() != DataType::String
+                && args_type[1] != DataType::Null)
+        {
+            return None;
+        }
+        let is_nullable = args_type[0].is_nullable_or_null() || args_type[1].is_nullable_or_null();
+        let return_type = DataType::Array(Box::new(DataType::String));
+        let return_type = if is_nullable {
+            DataType::Nullable(Box::new(return_type))
+        } else {
+            return_type
+        };
+        Some(Arc::new(Function {
+            signature: FunctionSignature {
+                name: "json_validate_schema".to_string(),
+                args_type: args_type.to_vec(),
+                return_type,
+            },
+            eval: FunctionEval::Scalar {
+                calc_domain: Box::new(|_, _| FunctionDomain::MayThrow),
+                eval: Box::new(move |args, ctx| json_validate_schema_fn(args, ctx, is_nullable)),
+            },
+        }))
+    });
 }
 
 fn json_array_fn(args: &[ValueRef<AnyType>], ctx: &mut EvalContext) -> Value<AnyType> {
@@ -2293,6 +2404,327 @@ fn json_object_pick_or_delete_fn(
     }
 }
 
+#[derive(Clone, Copy)]
+enum JsonModifyMode {
+    // Replaces the existing value or inserts a new value
+    Set,
+    // Only replaces the existing value
+    Replace,
+    Remove,
+}
+
+/// A step of the JSON Path that addresses a single value.
+enum JsonPathStep<'a> {
+    Key(&'a str),
+    Index(&'a Index),
+}
+
+fn json_modify_by_path_fn(
+    args: &[ValueRef<AnyType>],
+    ctx: &mut EvalContext,
+    mode: JsonModifyMode,
+    is_nullable: bool,
+) -> Value<AnyType> {
+    let len_opt = args.iter().find_map(|arg| match arg {
+        ValueRef::Column(col) => Some(col.len()),
+        _ => None,
+    });
+    let len = len_opt.unwrap_or(1);
+    let path_args = match mode {
+        JsonModifyMode::Remove => &args[1..],
+        _ => &args[1..2],
+    };
+    let mut validity = MutableBitmap::with_capacity(len);
+    let mut builder = BinaryColumnBuilder::with_capacity(len, len * 50);
+    let mut paths = Vec::with_capacity(path_args.len());
+    let mut new_val_buf = vec![];
+    for idx in 0..len {
+        let value = match &args[0] {
+            ValueRef::Scalar(scalar) => scalar.clone(),
+            ValueRef::Column(col) => unsafe { col.index_unchecked(idx) },
+        };
+        paths.clear();
+        for arg in path_args {
+            let path = match arg {
+                ValueRef::Scalar(scalar) => scalar.clone(),
+                ValueRef::Column(col) => unsafe { col.index_unchecked(idx) },
+            };
+            if let ScalarRef::String(path) = path {
+                paths.push(path);
+            }
+        }
+        let new_val = match mode {
+            JsonModifyMode::Remove => None,
+            _ => {
+                let new_val = match &args[2] {
+                    ValueRef::Scalar(scalar) => scalar.clone(),
+                    ValueRef::Column(col) => unsafe { col.index_unchecked(idx) },
+                };
+                // if the new value is not a json value, cast it to json.
+                new_val_buf.clear();
+                cast_scalar_to_variant(new_val, ctx.func_ctx.tz, &mut new_val_buf);
+                Some(new_val_buf.as_slice())
+            }
+        };
+        match value {
+            // The result is NULL if the value or any of the paths is NULL
+            ScalarRef::Variant(value) if paths.len() == path_args.len() => {
+                match json_modify_by_paths(value, &paths, new_val, mode, &mut builder.data) {
+                    Ok(()) => validity.push(true),
+                    Err(err) => {
+                        ctx.set_error(builder.len(), err);
+                        validity.push(false);
+                    }
+                }
+            }
+            _ => validity.push(false),
+        }
+        builder.commit_row();
+    }
+    if is_nullable {
+        let validity: Bitmap = validity.into();
+        match len_opt {
+            Some(_) => {
+                Value::Column(Column::Variant(builder.build())).wrap_nullable(Some(validity))
+            }
+            None => {
+                if !validity.get_bit(0) {
+                    Value::Scalar(Scalar::Null)
+                } else {
+                    Value::Scalar(Scalar::Variant(builder.build_scalar()))
+                }
+            }
+        }
+    } else {
+        match len_opt {
+            Some(_) => Value::Column(Column::Variant(builder.build())),
+            None => Value::Scalar(Scalar::Variant(builder.build_scalar())),
+        }
+    }
+}
+
+// Decodes the JSONB value into a tree of values that borrows the strings from
+// the buffer, modifies the tree by the paths in order and encodes it back.
+fn json_modify_by_paths<'a>(
+    value: &'a [u8],
+    paths: &[&str],
+    new_val: Option<&'a [u8]>,
+    mode: JsonModifyMode,
+    buf: &mut Vec<u8>,
+) -> Result<(), String> {
+    let mut json = jsonb::from_slice(value)
+        .map_err(|_| format!("Invalid JSONB value '0x{}'", hex::encode(value)))?;
+    let new_val = match new_val {
+        Some(new_val) => Some(
+            jsonb::from_slice(new_val)
+                .map_err(|_| format!("Invalid JSONB value '0x{}'", hex::encode(new_val)))?,
+        ),
+        None => None,
+    };
+    for path in paths {
+        let json_path =
+            parse_json_path(path.as_bytes()).map_err(|_| format!("Invalid JSON Path '{path}'"))?;
+        let steps = json_path_steps(&json_path)
+            .ok_or_else(|| format!("JSON Path '{path}' must address a single value"))?;
+        match (mode, &new_val) {
+            (JsonModifyMode::Set, Some(new_val)) => {
+                set_by_json_path(&mut json, &steps, new_val.clone(), true)
+            }
+            (JsonModifyMode::Replace, Some(new_val)) => {
+                set_by_json_path(&mut json, &steps, new_val.clone(), false)
+            }
+            (JsonModifyMode::Remove, _) => {
+                if steps.is_empty() {
+                    return Err(format!(
+                        "JSON Path '{path}' can't remove the root of the JSON value"
+                    ));
+                }
+                remove_by_json_path(&mut json, &steps);
+            }
+            _ => unreachable!(),
+        }
+    }
+    json.write_to_vec(buf);
+    Ok(())
+}
+
+// Only the paths that consist of names and single array indices are supported,
+// like `$.a.b[2]`, wildcards, slices and filters can't be modified.
+fn json_path_steps<'a>(json_path: &'a JsonPath<'a>) -> Option<Vec<JsonPathStep<'a>>> {
+    let mut steps = Vec::with_capacity(json_path.paths.len());
+    for (i, path) in json_path.paths.iter().enumerate() {
+        match path {
+            Path::Root if i == 0 => {}
+            Path::DotField(name) | Path::ColonField(name) | Path::ObjectField(name) => {
+                steps.push(JsonPathStep::Key(name.as_ref()))
+            }
+            Path::ArrayIndices(indices) if indices.len() == 1 => match &indices[0] {
+                ArrayIndex::Index(index) => steps.push(JsonPathStep::Index(index)),
+                ArrayIndex::Slice(_) => return None,
+            },
+            _ => return None,
+        }
+    }
+    Some(steps)
+}
+
+// Returns the position of the index in an array of `len` items, the position
+// can be greater than or equal to `len` when a new item is appended.
+fn json_array_position(index: &Index, len: usize) -> Option<usize> {
+    let pos = match index {
+        Index::Index(i) => *i as i64,
+        Index::LastIndex(i) => len as i64 - 1 + *i as i64,
+    };
+    (pos >= 0).then_some(pos as usize)
+}
+
+// If the value doesn't exist and `insert` is true, the new value is inserted
+// into the parent object, or appended to the parent array like MySQL.
+fn set_by_json_path<'a>(
+    json: &mut jsonb::Value<'a>,
+    steps: &[JsonPathStep],
+    new_val: jsonb::Value<'a>,
+    insert: bool,
+) {
+    let Some((step, rest)) = steps.split_first() else {
+        *json = new_val;
+        return;
+    };
+    match (step, json) {
+        (JsonPathStep::Key(key), jsonb::Value::Object(obj)) => {
+            if let Some(child) = obj.get_mut(*key) {
+                set_by_json_path(child, rest, new_val, insert);
+            } else if insert && rest.is_empty() {
+                obj.insert(key.to_string(), new_val);
+            }
+        }
+        (JsonPathStep::Index(index), jsonb::Value::Array(items)) => {
+            match json_array_position(index, items.len()) {
+                Some(pos) if pos < items.len() => {
+                    set_by_json_path(&mut items[pos], rest, new_val, insert)
+                }
+                Some(_) if insert && rest.is_empty() => items.push(new_val),
+                _ => {}
+            }
+        }
+        _ => {}
+    }
+}
+
+fn remove_by_json_path(json: &mut jsonb::Value, steps: &[JsonPathStep]) {
+    let Some((step, rest)) = steps.split_first() else {
+        return;
+    };
+    match (step, json) {
+        (JsonPathStep::Key(key), jsonb::Value::Object(obj)) => {
+            if rest.is_empty() {
+                obj.remove(*key);
+            } else if let Some(child) = obj.get_mut(*key) {
+                remove_by_json_path(child, rest);
+            }
+        }
+        (JsonPathStep::Index(index), jsonb::Value::Array(items)) => {
+            if let Some(pos) = json_array_position(index, items.len()) {
+                if pos < items.len() {
+                    if rest.is_empty() {
+                        items.remove(pos);
+                    } else {
+                        remove_by_json_path(&mut items[pos], rest);
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn json_validate_schema_fn(
+    args: &[ValueRef<AnyType>],
+    ctx: &mut EvalContext,
+    is_nullable: bool,
+) -> Value<AnyType> {
+    let len_opt = args.iter().find_map(|arg| match arg {
+        ValueRef::Column(col) => Some(col.len()),
+        _ => None,
+    });
+    let len = len_opt.unwrap_or(1);
+    // The schema is usually a constant, compile it only once.
+    let scalar_schema = match &args[1] {
+        ValueRef::Scalar(scalar) => Some(compile_json_schema(scalar.clone())),
+        _ => None,
+    };
+    let mut validity = MutableBitmap::with_capacity(len);
+    let mut builder = ArrayColumnBuilder::<StringType>::with_capacity(len, 0, &[]);
+    for idx in 0..len {
+        let value = match &args[0] {
+            ValueRef::Scalar(scalar) => scalar.clone(),
+            ValueRef::Column(col) => unsafe { col.index_unchecked(idx) },
+        };
+        let row_schema;
+        let schema = match (&scalar_schema, &args[1]) {
+            (Some(schema), _) => schema,
+            (None, ValueRef::Column(col)) => {
+                row_schema = compile_json_schema(unsafe { col.index_unchecked(idx) });
+                &row_schema
+            }
+            (None, ValueRef::Scalar(_)) => unreachable!(),
+        };
+        match (value, schema) {
+            (ScalarRef::Variant(value), Some(Ok(schema))) => match jsonb::from_slice(value) {
+                Ok(value) => {
+                    for error in schema.validate(&value) {
+                        builder.put_item(&error);
+                    }
+                    validity.push(true);
+                }
+                Err(_) => {
+                    ctx.set_error(
+                        builder.len(),
+                        format!("Invalid JSONB value '0x{}'", hex::encode(value)),
+                    );
+                    validity.push(false);
+                }
+            },
+            (_, Some(Err(err))) => {
+                ctx.set_error(builder.len(), err.as_str());
+                validity.push(false);
+            }
+            _ => validity.push(false),
+        }
+        builder.commit_row();
+    }
+    let value = match len_opt {
+        Some(_) => Value::Column(ArrayType::<StringType>::upcast_column(builder.build())),
+        None => Value::Scalar(ArrayType::<StringType>::upcast_scalar(
+            builder.build_scalar(),
+        )),
+    };
+    if is_nullable {
+        let validity: Bitmap = validity.into();
+        match value {
+            Value::Column(col) => Value::Column(col).wrap_nullable(Some(validity)),
+            Value::Scalar(_) if !validity.get_bit(0) => Value::Scalar(Scalar::Null),
+            scalar => scalar,
+        }
+    } else {
+        value
+    }
+}
+
+// The schema can be a JSON value or a JSON string, returns `None` if it's NULL.
+fn compile_json_schema(schema: ScalarRef) -> Option<Result<JsonSchema, String>> {
+    let schema = match schema {
+        ScalarRef::Variant(buf) => {
+            jsonb::from_slice(buf).map_err(|e| format!("Invalid JSON schema: {e}"))
+        }
+        ScalarRef::String(s) => {
+            parse_value(s.as_bytes()).map_err(|e| format!("Invalid JSON schema: {e}"))
+        }
+        _ => return None,
+    };
+    Some(schema.and_then(|schema| JsonSchema::try_create(&schema)))
+}
+
 // Extract string for string type, other types convert to JSON string.
 fn cast_to_string(v: &[u8]) -> String {
     match to_str(v) {
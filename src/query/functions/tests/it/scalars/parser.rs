@@ -31,6 +31,7 @@ use databend_common_expression::types::decimal::DecimalDataType;
 use databend_common_expression::types::decimal::DecimalScalar;
 use databend_common_expression::types::decimal::DecimalSize;
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::EnumItems;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberScalar;
 use databend_common_expression::ConstantFolder;
@@ -626,6 +627,13 @@ fn transform_data_type(target_type: databend_common_ast::ast::TypeName) -> DataT
         databend_common_ast::ast::TypeName::Variant => DataType::Variant,
         databend_common_ast::ast::TypeName::Geometry => DataType::Geometry,
         databend_common_ast::ast::TypeName::Geography => DataType::Geography,
+        databend_common_ast::ast::TypeName::Interval => DataType::Interval,
+        databend_common_ast::ast::TypeName::TimestampTz => DataType::TimestampTz,
+        databend_common_ast::ast::TypeName::Time => DataType::Time,
+        databend_common_ast::ast::TypeName::Uuid => DataType::Uuid,
+        databend_common_ast::ast::TypeName::Enum(items) => {
+            DataType::Enum(EnumItems::try_create(items).unwrap())
+        }
         databend_common_ast::ast::TypeName::NotNull(inner_type) => transform_data_type(*inner_type),
     }
 }
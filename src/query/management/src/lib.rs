@@ -18,12 +18,14 @@ mod cluster;
 mod connection;
 mod file_format;
 mod network_policy;
+mod notification;
 mod password_policy;
 mod quota;
 mod role;
 mod serde;
 mod setting;
 mod stage;
+mod task;
 pub mod udf;
 mod user;
 
@@ -37,6 +39,7 @@ pub use cluster::ClusterMgr;
 pub use connection::ConnectionMgr;
 pub use file_format::FileFormatMgr;
 pub use network_policy::NetworkPolicyMgr;
+pub use notification::NotificationMgr;
 pub use password_policy::PasswordPolicyMgr;
 pub use procedure::ProcedureMgr;
 pub use quota::QuotaApi;
@@ -50,5 +53,7 @@ pub use setting::SettingApi;
 pub use setting::SettingMgr;
 pub use stage::StageApi;
 pub use stage::StageMgr;
+pub use task::TaskMgr;
+pub use task::TASK_SCHEDULER_LEASE_KEY_PREFIX;
 pub use user::UserApi;
 pub use user::UserMgr;
@@ -0,0 +1,161 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_api::crud::CrudMgr;
+use databend_common_meta_api::fetch_id;
+use databend_common_meta_api::kv_pb_api::KVPbApi;
+use databend_common_meta_api::kv_pb_api::UpsertPB;
+use databend_common_meta_app::id_generator::IdGenerator;
+use databend_common_meta_app::principal::notification_history_ident::NotificationHistoryId;
+use databend_common_meta_app::principal::notification_ident;
+use databend_common_meta_app::principal::Notification;
+use databend_common_meta_app::principal::NotificationHistory;
+use databend_common_meta_app::principal::NotificationHistoryIdent;
+use databend_common_meta_app::principal::NotificationIdent;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_kvapi::kvapi::DirName;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaError;
+use databend_common_meta_types::SeqV;
+use databend_common_meta_types::With;
+use futures::TryStreamExt;
+
+/// Manages notification integrations and their delivery history of one tenant.
+///
+/// It is used by the built-in notification sender when cloud control is not configured.
+pub struct NotificationMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    tenant: Tenant,
+}
+
+impl NotificationMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &Tenant) -> Self {
+        NotificationMgr {
+            kv_api,
+            tenant: tenant.clone(),
+        }
+    }
+
+    fn crud(&self) -> CrudMgr<notification_ident::Resource> {
+        CrudMgr::create(self.kv_api.clone(), &self.tenant)
+    }
+
+    fn history_ident(&self, name: &str, history_id: u64) -> NotificationHistoryIdent {
+        NotificationHistoryIdent::new_generic(self.tenant.clone(), NotificationHistoryId {
+            name: name.to_string(),
+            history_id,
+        })
+    }
+
+    /// Create a notification integration, a new id is assigned to it.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn create_notification(
+        &self,
+        mut notification: Notification,
+        create_option: &CreateOption,
+    ) -> Result<()> {
+        if let CreateOption::CreateIfNotExists = create_option {
+            if self.get_notification(&notification.name).await?.is_some() {
+                return Ok(());
+            }
+        }
+
+        notification.notification_id =
+            fetch_id(self.kv_api.as_ref(), IdGenerator::notification_id()).await?;
+        self.crud().add(notification, create_option).await?;
+        Ok(())
+    }
+
+    /// Replace a notification integration if its seq matches `seq`, returns the new seq.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn update_notification(
+        &self,
+        notification: Notification,
+        seq: MatchSeq,
+    ) -> Result<u64> {
+        let seq = self.crud().update(notification, seq).await?;
+        Ok(seq)
+    }
+
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn get_notification(&self, name: &str) -> Result<Option<SeqV<Notification>>> {
+        let ident = NotificationIdent::new(self.tenant.clone(), name);
+        let res = self.kv_api.get_pb(&ident).await?;
+        Ok(res)
+    }
+
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn list_notifications(&self) -> Result<Vec<Notification>> {
+        let notifications = self.crud().list().await?;
+        Ok(notifications)
+    }
+
+    /// Drop a notification integration and returns the dropped one, or `None` if it does not exist.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn drop_notification(&self, name: &str) -> Result<Option<Notification>> {
+        let ident = NotificationIdent::new(self.tenant.clone(), name);
+        let res = self.kv_api.upsert_pb(&UpsertPB::delete(ident)).await?;
+        Ok(res.prev.map(|x| x.data))
+    }
+
+    /// Record a delivery. A new history id is assigned to it if it is 0.
+    /// The record is removed by meta-service after `ttl`.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn add_history(&self, mut history: NotificationHistory, ttl: Duration) -> Result<()> {
+        if history.history_id == 0 {
+            history.history_id =
+                fetch_id(self.kv_api.as_ref(), IdGenerator::notification_id()).await?;
+        }
+        let ident = self.history_ident(&history.name, history.history_id);
+        let upsert = UpsertPB::update(ident, history)
+            .with(MatchSeq::GE(0))
+            .with_ttl(ttl);
+        self.kv_api.upsert_pb(&upsert).await?;
+        Ok(())
+    }
+
+    /// List the delivery history of one integration, or of all integrations if `name` is `None`.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn list_histories(&self, name: Option<&str>) -> Result<Vec<NotificationHistory>> {
+        let dir = match name {
+            Some(name) => DirName::new(self.history_ident(name, 0)),
+            None => DirName::new_with_level(self.history_ident("dummy", 0), 2),
+        };
+        let histories = self.kv_api.list_pb_values(&dir).await?;
+        let histories = histories.try_collect().await?;
+        Ok(histories)
+    }
+
+    pub fn illegal_notification(name: &str, reason: impl ToString) -> ErrorCode {
+        ErrorCode::IllegalNotificationFormat(format!(
+            "notification '{}': {}",
+            name,
+            reason.to_string()
+        ))
+    }
+}
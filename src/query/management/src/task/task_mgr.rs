@@ -0,0 +1,262 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_base::base::escape_for_key;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_api::crud::CrudMgr;
+use databend_common_meta_api::fetch_id;
+use databend_common_meta_api::kv_pb_api::KVPbApi;
+use databend_common_meta_api::kv_pb_api::UpsertPB;
+use databend_common_meta_app::id_generator::IdGenerator;
+use databend_common_meta_app::principal::task_ident;
+use databend_common_meta_app::principal::task_run_ident::TaskRunId;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskIdent;
+use databend_common_meta_app::principal::TaskRun;
+use databend_common_meta_app::principal::TaskRunIdent;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_kvapi::kvapi::DirName;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MetaError;
+use databend_common_meta_types::MetaSpec;
+use databend_common_meta_types::Operation;
+use databend_common_meta_types::SeqV;
+use databend_common_meta_types::With;
+use futures::TryStreamExt;
+
+pub static TASK_SCHEDULER_LEASE_KEY_PREFIX: &str = "__fd_task_scheduler_lease";
+
+/// Manages task definitions, task run history and the scheduler lease of one tenant.
+///
+/// It is used by the built-in task scheduler when cloud control is not configured.
+pub struct TaskMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    tenant: Tenant,
+}
+
+impl TaskMgr {
+    pub fn create(kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>, tenant: &Tenant) -> Self {
+        TaskMgr {
+            kv_api,
+            tenant: tenant.clone(),
+        }
+    }
+
+    fn crud(&self) -> CrudMgr<task_ident::Resource> {
+        CrudMgr::create(self.kv_api.clone(), &self.tenant)
+    }
+
+    fn run_ident(&self, task_name: &str, run_id: u64) -> TaskRunIdent {
+        TaskRunIdent::new_generic(self.tenant.clone(), TaskRunId {
+            task_name: task_name.to_string(),
+            run_id,
+        })
+    }
+
+    fn lease_key(&self) -> Result<String> {
+        Ok(format!(
+            "{}/{}",
+            TASK_SCHEDULER_LEASE_KEY_PREFIX,
+            escape_for_key(self.tenant.tenant_name())?
+        ))
+    }
+
+    /// Create a task, a new task id is assigned to it.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn create_task(&self, mut task: Task, create_option: &CreateOption) -> Result<()> {
+        if let CreateOption::CreateIfNotExists = create_option {
+            if self.get_task(&task.task_name).await?.is_some() {
+                return Ok(());
+            }
+        }
+
+        task.task_id = fetch_id(self.kv_api.as_ref(), IdGenerator::task_id()).await?;
+        self.crud().add(task, create_option).await?;
+        Ok(())
+    }
+
+    /// Replace a task if its seq matches `seq`, returns the new seq.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn update_task(&self, task: Task, seq: MatchSeq) -> Result<u64> {
+        let seq = self.crud().update(task, seq).await?;
+        Ok(seq)
+    }
+
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn get_task(&self, name: &str) -> Result<Option<SeqV<Task>>> {
+        let ident = TaskIdent::new(self.tenant.clone(), name);
+        let res = self.kv_api.get_pb(&ident).await?;
+        Ok(res)
+    }
+
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn list_tasks(&self) -> Result<Vec<Task>> {
+        let tasks = self.crud().list().await?;
+        Ok(tasks)
+    }
+
+    /// Returns the names of the tasks that run after the task `name`.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn list_dependents(&self, name: &str) -> Result<Vec<String>> {
+        let tasks = self.list_tasks().await?;
+        let dependents = tasks
+            .into_iter()
+            .filter(|t| t.after.iter().any(|p| p == name))
+            .map(|t| t.task_name)
+            .collect();
+        Ok(dependents)
+    }
+
+    /// Check that letting the task `name` run after the tasks `after` does not form a cycle.
+    ///
+    /// A task in a cycle never runs, because none of the tasks in it can be the first to finish.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn ensure_acyclic(&self, name: &str, after: &[String]) -> Result<()> {
+        let mut graph = self
+            .list_tasks()
+            .await?
+            .into_iter()
+            .map(|t| (t.task_name, t.after))
+            .collect::<HashMap<_, _>>();
+        graph.insert(name.to_string(), after.to_vec());
+
+        let mut visited = HashSet::new();
+        let mut stack = after.iter().collect::<Vec<_>>();
+        while let Some(predecessor) = stack.pop() {
+            if predecessor == name {
+                return Err(Self::illegal_task(
+                    name,
+                    "its predecessors form a cycle that runs after itself",
+                ));
+            }
+            if visited.insert(predecessor) {
+                if let Some(next) = graph.get(predecessor) {
+                    stack.extend(next.iter());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Drop a task and returns the dropped one, or `None` if it does not exist.
+    ///
+    /// The run history of the task is kept until it expires.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn drop_task(&self, name: &str) -> Result<Option<Task>> {
+        let ident = TaskIdent::new(self.tenant.clone(), name);
+        let res = self.kv_api.upsert_pb(&UpsertPB::delete(ident)).await?;
+        Ok(res.prev.map(|x| x.data))
+    }
+
+    /// Record a task run. The record is removed by meta-service after `ttl`.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn upsert_task_run(&self, run: &TaskRun, ttl: Duration) -> Result<()> {
+        let ident = self.run_ident(&run.task.task_name, run.run_id);
+        let upsert = UpsertPB::update(ident, run.clone())
+            .with(MatchSeq::GE(0))
+            .with_ttl(ttl);
+        self.kv_api.upsert_pb(&upsert).await?;
+        Ok(())
+    }
+
+    /// List the run history of one task, or of all tasks if `task_name` is `None`.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn list_task_runs(&self, task_name: Option<&str>) -> Result<Vec<TaskRun>> {
+        let dir = match task_name {
+            Some(name) => DirName::new(self.run_ident(name, 0)),
+            None => DirName::new_with_level(self.run_ident("dummy", 0), 2),
+        };
+        let runs = self.kv_api.list_pb_values(&dir).await?;
+        let runs = runs.try_collect().await?;
+        Ok(runs)
+    }
+
+    /// Generate a unique id for a task run.
+    #[async_backtrace::framed]
+    pub async fn next_run_id(&self) -> Result<u64> {
+        let id = fetch_id(self.kv_api.as_ref(), IdGenerator::task_id()).await?;
+        Ok(id)
+    }
+
+    /// Try to become, or stay, the only node that runs scheduled tasks of this tenant.
+    ///
+    /// The lease is a key with a TTL holding the id of the owner node.
+    /// The owner extends it by calling this method again before it expires,
+    /// other nodes can only take over after the lease expired.
+    /// Returns `true` if `node_id` holds the lease after this call.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn acquire_scheduler_lease(&self, node_id: &str, ttl: Duration) -> Result<bool> {
+        let key = self.lease_key()?;
+        let seq = match self.kv_api.get_kv(&key).await? {
+            None => MatchSeq::Exact(0),
+            Some(seq_v) if seq_v.data == node_id.as_bytes() => MatchSeq::Exact(seq_v.seq),
+            Some(_) => return Ok(false),
+        };
+
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                seq,
+                Operation::Update(node_id.as_bytes().to_vec()),
+                Some(MetaSpec::new_ttl(ttl)),
+            ))
+            .await?;
+
+        Ok(res.is_changed())
+    }
+
+    /// Give up the lease if it is held by `node_id`, so that another node can take over at once.
+    #[async_backtrace::framed]
+    #[fastrace::trace]
+    pub async fn release_scheduler_lease(&self, node_id: &str) -> Result<()> {
+        let key = self.lease_key()?;
+        if let Some(seq_v) = self.kv_api.get_kv(&key).await? {
+            if seq_v.data == node_id.as_bytes() {
+                self.kv_api
+                    .upsert_kv(UpsertKVReq::new(
+                        &key,
+                        MatchSeq::Exact(seq_v.seq),
+                        Operation::Delete,
+                        None,
+                    ))
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn illegal_task(name: &str, reason: impl ToString) -> ErrorCode {
+        ErrorCode::IllegalTaskFormat(format!("task '{}': {}", name, reason.to_string()))
+    }
+}
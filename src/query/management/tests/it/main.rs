@@ -15,9 +15,11 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod cluster;
+mod notification;
 mod quota;
 mod role;
 mod setting;
 mod stage;
+mod task;
 mod udf;
 mod user;
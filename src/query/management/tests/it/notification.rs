@@ -0,0 +1,118 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_management::*;
+use databend_common_meta_app::principal::Notification;
+use databend_common_meta_app::principal::NotificationHistory;
+use databend_common_meta_app::principal::NotificationStatus;
+use databend_common_meta_app::principal::WebhookOptions;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_embedded::MetaEmbedded;
+use databend_common_meta_types::MatchSeq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_notification_crud() -> Result<()> {
+    let (_kv_api, notification_api) = new_notification_api().await?;
+
+    notification_api
+        .create_notification(create_test_notification("n1"), &CreateOption::Create)
+        .await?;
+
+    let res = notification_api
+        .create_notification(create_test_notification("n1"), &CreateOption::Create)
+        .await;
+    assert!(res.is_err());
+    notification_api
+        .create_notification(
+            create_test_notification("n1"),
+            &CreateOption::CreateIfNotExists,
+        )
+        .await?;
+
+    let n1 = notification_api.get_notification("n1").await?.unwrap();
+    assert_ne!(0, n1.data.notification_id);
+
+    let mut notification = n1.data.clone();
+    notification.enabled = false;
+    notification_api
+        .update_notification(notification, MatchSeq::Exact(n1.seq))
+        .await?;
+    let got = notification_api.get_notification("n1").await?.unwrap();
+    assert!(!got.data.enabled);
+    assert_eq!(1, notification_api.list_notifications().await?.len());
+
+    assert!(notification_api.drop_notification("n1").await?.is_some());
+    assert!(notification_api.drop_notification("n1").await?.is_none());
+    assert!(notification_api.list_notifications().await?.is_empty());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_notification_history() -> Result<()> {
+    let (_kv_api, notification_api) = new_notification_api().await?;
+
+    for name in ["n1", "n1", "n10"] {
+        let history = NotificationHistory {
+            history_id: 0,
+            created_at: Utc::now(),
+            processed_at: Some(Utc::now()),
+            message_source: "task".to_string(),
+            name: name.to_string(),
+            message: "{}".to_string(),
+            status: NotificationStatus::Success,
+            attempts: 1,
+            error_message: "".to_string(),
+        };
+        notification_api
+            .add_history(history, Duration::from_secs(60))
+            .await?;
+    }
+
+    assert_eq!(2, notification_api.list_histories(Some("n1")).await?.len());
+    assert_eq!(1, notification_api.list_histories(Some("n10")).await?.len());
+    assert_eq!(3, notification_api.list_histories(None).await?.len());
+
+    Ok(())
+}
+
+fn create_test_notification(name: &str) -> Notification {
+    Notification {
+        notification_id: 0,
+        name: name.to_string(),
+        enabled: true,
+        webhook: WebhookOptions {
+            url: "http://127.0.0.1:8080/hook".to_string(),
+            method: None,
+            authorization_header: None,
+            signing_secret: Some("secret".to_string()),
+        },
+        comments: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+async fn new_notification_api() -> Result<(Arc<MetaEmbedded>, NotificationMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = NotificationMgr::create(test_api.clone(), &Tenant::new_literal("admin"));
+    Ok((test_api, mgr))
+}
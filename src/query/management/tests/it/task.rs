@@ -0,0 +1,188 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::Utc;
+use databend_common_base::base::tokio;
+use databend_common_exception::Result;
+use databend_common_management::*;
+use databend_common_meta_app::principal::ScheduleOptions;
+use databend_common_meta_app::principal::State;
+use databend_common_meta_app::principal::Status;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskRun;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_embedded::MetaEmbedded;
+use databend_common_meta_types::MatchSeq;
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_task_crud() -> Result<()> {
+    let (_kv_api, task_api) = new_task_api().await?;
+
+    task_api
+        .create_task(create_test_task("t1"), &CreateOption::Create)
+        .await?;
+    task_api
+        .create_task(create_test_task("t2"), &CreateOption::Create)
+        .await?;
+
+    // Create an existing task.
+    let res = task_api
+        .create_task(create_test_task("t1"), &CreateOption::Create)
+        .await;
+    assert!(res.is_err());
+    task_api
+        .create_task(create_test_task("t1"), &CreateOption::CreateIfNotExists)
+        .await?;
+
+    let t1 = task_api.get_task("t1").await?.unwrap();
+    let t2 = task_api.get_task("t2").await?.unwrap();
+    assert_ne!(t1.data.task_id, t2.data.task_id);
+    assert_eq!(2, task_api.list_tasks().await?.len());
+
+    let mut task = t1.data.clone();
+    task.status = Status::Suspended;
+    task_api.update_task(task, MatchSeq::Exact(t1.seq)).await?;
+    let got = task_api.get_task("t1").await?.unwrap();
+    assert_eq!(Status::Suspended, got.data.status);
+
+    // Stale seq does not update.
+    let res = task_api.update_task(t1.data, MatchSeq::Exact(t1.seq)).await;
+    assert!(res.is_err());
+
+    let dropped = task_api.drop_task("t1").await?;
+    assert!(dropped.is_some());
+    assert!(task_api.drop_task("t1").await?.is_none());
+    assert_eq!(1, task_api.list_tasks().await?.len());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_task_runs() -> Result<()> {
+    let (_kv_api, task_api) = new_task_api().await?;
+
+    let t1 = create_test_task("t1");
+    let t10 = create_test_task("t10");
+    for (i, task) in [&t1, &t1, &t10].into_iter().enumerate() {
+        let run = TaskRun {
+            task: task.clone(),
+            run_id: i as u64 + 1,
+            attempt_number: 0,
+            state: State::Succeeded,
+            scheduled_at: Utc::now(),
+            completed_at: Some(Utc::now()),
+            error_code: 0,
+            error_message: None,
+            root_task_id: task.task_id,
+            query_id: format!("q{}", i),
+        };
+        task_api
+            .upsert_task_run(&run, Duration::from_secs(60))
+            .await?;
+    }
+
+    assert_eq!(2, task_api.list_task_runs(Some("t1")).await?.len());
+    assert_eq!(1, task_api.list_task_runs(Some("t10")).await?.len());
+    assert_eq!(3, task_api.list_task_runs(None).await?.len());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_task_scheduler_lease() -> Result<()> {
+    let (_kv_api, task_api) = new_task_api().await?;
+    let ttl = Duration::from_secs(60);
+
+    assert!(task_api.acquire_scheduler_lease("n1", ttl).await?);
+    assert!(!task_api.acquire_scheduler_lease("n2", ttl).await?);
+    // The owner can extend the lease.
+    assert!(task_api.acquire_scheduler_lease("n1", ttl).await?);
+
+    // Only the owner can release the lease.
+    task_api.release_scheduler_lease("n2").await?;
+    assert!(!task_api.acquire_scheduler_lease("n2", ttl).await?);
+
+    task_api.release_scheduler_lease("n1").await?;
+    assert!(task_api.acquire_scheduler_lease("n2", ttl).await?);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn test_task_dag() -> Result<()> {
+    let (_kv_api, task_api) = new_task_api().await?;
+
+    // a <- b <- c
+    let mut b = create_test_task("b");
+    b.after = vec!["a".to_string()];
+    let mut c = create_test_task("c");
+    c.after = vec!["b".to_string()];
+    for task in [create_test_task("a"), b, c] {
+        task_api.create_task(task, &CreateOption::Create).await?;
+    }
+
+    assert_eq!(vec!["b".to_string()], task_api.list_dependents("a").await?);
+    assert!(task_api.list_dependents("c").await?.is_empty());
+
+    // a task can not run after itself, directly or through other tasks.
+    let after = |names: &[&str]| names.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+    assert!(task_api.ensure_acyclic("a", &after(&["a"])).await.is_err());
+    assert!(task_api.ensure_acyclic("a", &after(&["b"])).await.is_err());
+    assert!(task_api.ensure_acyclic("a", &after(&["c"])).await.is_err());
+    assert!(
+        task_api
+            .ensure_acyclic("b", &after(&["a", "c"]))
+            .await
+            .is_err()
+    );
+
+    // a diamond is not a cycle.
+    task_api.ensure_acyclic("c", &after(&["a", "b"])).await?;
+    task_api.ensure_acyclic("d", &after(&["b", "c"])).await?;
+
+    Ok(())
+}
+
+fn create_test_task(name: &str) -> Task {
+    Task {
+        task_id: 0,
+        task_name: name.to_string(),
+        query_text: "SELECT 1".to_string(),
+        when_condition: None,
+        after: vec![],
+        comment: None,
+        owner: "account_admin".to_string(),
+        schedule_options: Some(ScheduleOptions::new_interval(60, 0)),
+        warehouse_options: None,
+        next_scheduled_at: None,
+        suspend_task_after_num_failures: None,
+        consecutive_failures: 0,
+        error_integration: None,
+        status: Status::Started,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+        last_suspended_at: None,
+        session_params: Default::default(),
+    }
+}
+
+async fn new_task_api() -> Result<(Arc<MetaEmbedded>, TaskMgr)> {
+    let test_api = Arc::new(MetaEmbedded::new_temp().await?);
+    let mgr = TaskMgr::create(test_api.clone(), &Tenant::new_literal("admin"));
+    Ok((test_api, mgr))
+}
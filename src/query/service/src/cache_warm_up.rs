@@ -0,0 +1,135 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::base::tokio::time::sleep;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::catalog::CatalogManager;
+use databend_common_config::CacheWarmUpConfig;
+use databend_common_config::InnerConfig;
+use databend_common_exception::Result;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_storages_fuse::FuseTable;
+use databend_storages_common_cache::CacheManager;
+use databend_storages_common_cache::CacheWarmUpRecorder;
+use databend_storages_common_cache::HotTable;
+use databend_storages_common_session::SessionState;
+use log::info;
+use log::warn;
+
+const HOT_TABLES_FILE_NAME: &str = "hot_tables.json";
+
+/// Warms up the caches of the tables that were queried recently before the query node restarts.
+///
+/// The recently queried tables are recorded by [`CacheWarmUpRecorder`], and persisted to a
+/// local file periodically. After restart, the snapshots, segments, bloom indexes and the
+/// data of the hot columns of these tables are loaded into the caches in the background.
+pub struct CacheWarmUp;
+
+impl CacheWarmUp {
+    pub fn init(config: &InnerConfig) -> Result<()> {
+        let Some(recorder) = CacheManager::instance().get_warm_up_recorder() else {
+            return Ok(());
+        };
+
+        let tenant = config.query.tenant_id.clone();
+        let warm_up_config = config.cache.warm_up.clone();
+        let path = PathBuf::from(&warm_up_config.path)
+            .join(tenant.tenant_name())
+            .join(HOT_TABLES_FILE_NAME);
+
+        let hot_tables = CacheWarmUpRecorder::load(&path).unwrap_or_else(|e| {
+            warn!("failed to load hot tables from {:?}: {}", path, e);
+            vec![]
+        });
+        recorder.restore(hot_tables.clone());
+
+        GlobalIORuntime::instance().spawn(async move {
+            Self::warm_up(&tenant, hot_tables, &warm_up_config).await;
+
+            let interval = Duration::from_secs(warm_up_config.persist_interval_secs.max(1));
+            loop {
+                sleep(interval).await;
+                if let Err(e) = recorder.persist(&path) {
+                    warn!("failed to persist hot tables to {:?}: {}", path, e);
+                }
+            }
+        });
+        Ok(())
+    }
+
+    async fn warm_up(tenant: &Tenant, hot_tables: Vec<HotTable>, config: &CacheWarmUpConfig) {
+        let start = Instant::now();
+        let mut remaining_bytes = config.max_column_data_bytes;
+
+        for hot_table in hot_tables.iter().take(config.max_tables as usize) {
+            match Self::warm_up_table(tenant, hot_table, remaining_bytes, config).await {
+                Ok(column_data_bytes) => {
+                    remaining_bytes = remaining_bytes.saturating_sub(column_data_bytes);
+                }
+                Err(e) => {
+                    warn!(
+                        "failed to warm up caches of table {}.{}.{}: {}",
+                        hot_table.catalog, hot_table.database, hot_table.table, e
+                    );
+                }
+            }
+        }
+
+        info!(
+            "cache warm-up of {} tables done, {} bytes of column data prefetched, time used: {:?}",
+            hot_tables.len().min(config.max_tables as usize),
+            config.max_column_data_bytes - remaining_bytes,
+            start.elapsed()
+        );
+    }
+
+    async fn warm_up_table(
+        tenant: &Tenant,
+        hot_table: &HotTable,
+        max_column_data_bytes: u64,
+        config: &CacheWarmUpConfig,
+    ) -> Result<u64> {
+        let catalog = CatalogManager::instance()
+            .get_catalog(
+                tenant.tenant_name(),
+                &hot_table.catalog,
+                SessionState::default(),
+            )
+            .await?;
+        let table = catalog
+            .get_table(tenant, &hot_table.database, &hot_table.table)
+            .await?;
+        let Ok(fuse_table) = FuseTable::try_from_table(table.as_ref()) else {
+            return Ok(0);
+        };
+
+        let stats = fuse_table
+            .warm_up_caches(
+                &hot_table.column_ids,
+                max_column_data_bytes,
+                config.max_concurrency as usize,
+            )
+            .await?;
+        info!(
+            "warmed up caches of table {}.{}.{}: {:?}",
+            hot_table.catalog, hot_table.database, hot_table.table, stats
+        );
+        Ok(stats.column_data_bytes)
+    }
+}
@@ -41,6 +41,7 @@ use databend_storages_common_cache::TempDirManager;
 use crate::auth::AuthMgr;
 use crate::builtin::BuiltinUDFs;
 use crate::builtin::BuiltinUsers;
+use crate::cache_warm_up::CacheWarmUp;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::ClusterDiscovery;
 use crate::locks::LockManager;
@@ -51,6 +52,7 @@ use crate::servers::http::v1::ClientSessionManager;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::QueriesQueueManager;
 use crate::sessions::SessionManager;
+use crate::task::TaskScheduler;
 
 pub struct GlobalServices;
 
@@ -151,9 +153,12 @@ impl GlobalServices {
             config.query.tenant_id.tenant_name().to_string(),
         )?;
         TempDirManager::init(&config.spill, config.query.tenant_id.tenant_name())?;
+        CacheWarmUp::init(config)?;
 
         if let Some(addr) = config.query.cloud_control_grpc_server_address.clone() {
             CloudControlApiProvider::init(addr, config.query.cloud_control_grpc_timeout).await?;
+        } else {
+            TaskScheduler::init(config)?;
         }
 
         ProfilesLogQueue::init(config.query.max_cached_queries_profiles);
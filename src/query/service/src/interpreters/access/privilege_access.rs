@@ -86,7 +86,7 @@ const SYSTEM_TABLES_ALLOW_LIST: [&str; 21] = [
 ];
 
 // table functions that need `Super` privilege
-const SYSTEM_TABLE_FUNCTIONS: [&str; 1] = ["fuse_amend"];
+const SYSTEM_TABLE_FUNCTIONS: [&str; 2] = ["fuse_amend", "fuse_tiering"];
 
 impl PrivilegeAccess {
     pub fn create(ctx: Arc<QueryContext>) -> Box<dyn AccessChecker> {
@@ -941,6 +941,9 @@ impl AccessChecker for PrivilegeAccess {
                 if let Some(query) = &plan.as_select {
                     self.check(ctx, query).await?;
                 }
+                if let Some(source) = &plan.clone_source {
+                    self.validate_table_access(&source.catalog, &source.database, &source.table, UserPrivilegeType::Select, false, false).await?;
+                }
             }
             Plan::DropTable(plan) => {
                 // For attach table
@@ -981,6 +984,20 @@ impl AccessChecker for PrivilegeAccess {
             Plan::ModifyTableComment(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
             }
+            Plan::CreateTableRef(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
+            }
+            Plan::DropTableRef(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
+            }
+            Plan::AddTableRowAccessPolicy(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?;
+                self.validate_access(&GrantObject::Global, UserPrivilegeType::CreateDataMask, false, false).await?
+            }
+            Plan::DropTableRowAccessPolicy(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?;
+                self.validate_access(&GrantObject::Global, UserPrivilegeType::CreateDataMask, false, false).await?
+            }
             Plan::DropTableColumn(plan) => {
                 self.validate_table_access(&plan.catalog, &plan.database, &plan.table, UserPrivilegeType::Alter, false, false).await?
             }
@@ -1230,6 +1247,7 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::DropNotification(_)
             | Plan::DescNotification(_)
             | Plan::AlterNotification(_)
+            | Plan::SendNotification(_)
             | Plan::DescUser(_)
             | Plan::CreateTask(_)   // TODO: need to build ownership info for task
             | Plan::ShowTasks(_)    // TODO: need to build ownership info for task
@@ -1242,7 +1260,10 @@ impl AccessChecker for PrivilegeAccess {
                 self.validate_access(&GrantObject::Global, UserPrivilegeType::Super, false, false)
                     .await?;
             }
-            Plan::CreateDatamaskPolicy(_) | Plan::DropDatamaskPolicy(_) => {
+            Plan::CreateDatamaskPolicy(_)
+            | Plan::DropDatamaskPolicy(_)
+            | Plan::CreateRowAccessPolicy(_)
+            | Plan::DropRowAccessPolicy(_) => {
                 self.validate_access(
                     &GrantObject::Global,
                     UserPrivilegeType::CreateDataMask,
@@ -1267,6 +1288,7 @@ impl AccessChecker for PrivilegeAccess {
             // just used in clickhouse-sqlalchemy, no need to check
             Plan::ExistsTable(_) => {}
             Plan::DescDatamaskPolicy(_) => {}
+            Plan::DescRowAccessPolicy(_) => {}
             Plan::Begin => {}
             Plan::ExecuteImmediate(_)
             | Plan::CallProcedure(_)
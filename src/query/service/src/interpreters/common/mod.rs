@@ -30,6 +30,7 @@ pub use stream::dml_build_update_stream_req;
 pub use stream::query_build_update_stream_req;
 pub use table::check_referenced_computed_columns;
 pub use task::get_task_client_config;
+pub use task::make_local_schedule_options;
 pub use task::make_schedule_options;
 pub use task::make_warehouse_options;
 pub use util::check_deduplicate_label;
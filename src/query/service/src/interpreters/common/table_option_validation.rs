@@ -29,6 +29,8 @@ use databend_common_storages_fuse::FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use databend_common_storages_fuse::FUSE_OPT_KEY_ROW_PER_PAGE;
+use databend_common_storages_fuse::FUSE_OPT_KEY_TIERING_AFTER_DAYS;
+use databend_common_storages_fuse::FUSE_OPT_KEY_TIERING_CLUSTER_KEY_MAX;
 use databend_storages_common_index::BloomIndex;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
@@ -42,6 +44,8 @@ use databend_storages_common_table_meta::table::OPT_KEY_RANDOM_SEED;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_TIERING_COLD_CONNECTION_NAME;
+use databend_storages_common_table_meta::table::OPT_KEY_TIERING_COLD_LOCATION;
 use log::error;
 
 /// Table option keys that can occur in 'create table statement'.
@@ -53,6 +57,8 @@ pub static CREATE_TABLE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
     r.insert(FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS);
+    r.insert(FUSE_OPT_KEY_TIERING_AFTER_DAYS);
+    r.insert(FUSE_OPT_KEY_TIERING_CLUSTER_KEY_MAX);
 
     r.insert(OPT_KEY_BLOOM_INDEX_COLUMNS);
     r.insert(OPT_KEY_TABLE_COMPRESSION);
@@ -66,6 +72,8 @@ pub static CREATE_TABLE_OPTIONS: LazyLock<HashSet<&'static str>> = LazyLock::new
 
     r.insert(OPT_KEY_LOCATION);
     r.insert(OPT_KEY_CONNECTION_NAME);
+    r.insert(OPT_KEY_TIERING_COLD_LOCATION);
+    r.insert(OPT_KEY_TIERING_COLD_CONNECTION_NAME);
 
     r.insert(OPT_KEY_RANDOM_SEED);
 
@@ -82,6 +90,8 @@ pub static UNSET_TABLE_OPTIONS_WHITE_LIST: LazyLock<HashSet<&'static str>> = Laz
     r.insert(FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD);
     r.insert(FUSE_OPT_KEY_ROW_AVG_DEPTH_THRESHOLD);
     r.insert(FUSE_OPT_KEY_DATA_RETENTION_PERIOD_IN_HOURS);
+    r.insert(FUSE_OPT_KEY_TIERING_AFTER_DAYS);
+    r.insert(FUSE_OPT_KEY_TIERING_CLUSTER_KEY_MAX);
     r
 });
 
@@ -176,3 +186,27 @@ pub fn is_valid_random_seed(
     }
     Ok(())
 }
+
+/// Checks the options of the tiering policy, which can only be set if the table
+/// has a cold storage.
+pub fn is_valid_tiering_options(
+    options: &BTreeMap<String, String>,
+    has_cold_storage: bool,
+) -> databend_common_exception::Result<()> {
+    if let Some(value) = options.get(FUSE_OPT_KEY_TIERING_AFTER_DAYS) {
+        let days = value.parse::<u64>()?;
+        if days == 0 {
+            return Err(ErrorCode::TableOptionInvalid(format!(
+                "invalid {FUSE_OPT_KEY_TIERING_AFTER_DAYS} option, it should be greater than 0"
+            )));
+        }
+    }
+    let has_policy = options.contains_key(FUSE_OPT_KEY_TIERING_AFTER_DAYS)
+        || options.contains_key(FUSE_OPT_KEY_TIERING_CLUSTER_KEY_MAX);
+    if has_policy && !has_cold_storage {
+        return Err(ErrorCode::TableOptionInvalid(format!(
+            "tiering policy requires table option {OPT_KEY_TIERING_COLD_LOCATION}"
+        )));
+    }
+    Ok(())
+}
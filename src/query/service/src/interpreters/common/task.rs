@@ -22,6 +22,8 @@ use databend_common_cloud_control::client_config::build_client_config;
 use databend_common_cloud_control::client_config::ClientConfig;
 use databend_common_cloud_control::pb::schedule_options::ScheduleType;
 use databend_common_exception::Result;
+use databend_common_management::TaskMgr;
+use databend_common_meta_app::principal;
 
 use crate::sessions::QueryContext;
 
@@ -65,6 +67,24 @@ pub fn make_warehouse_options(
     ret
 }
 
+/// Build the schedule of a task managed by the built-in task scheduler.
+pub fn make_local_schedule_options(
+    task_name: &str,
+    opt: ScheduleOptions,
+) -> Result<principal::ScheduleOptions> {
+    let opt = match opt {
+        ScheduleOptions::IntervalSecs(secs, ms) => {
+            principal::ScheduleOptions::new_interval(secs, ms)
+        }
+        ScheduleOptions::CronExpression(expr, timezone) => {
+            principal::ScheduleOptions::new_cron(expr, timezone)
+        }
+    };
+    opt.validate()
+        .map_err(|reason| TaskMgr::illegal_task(task_name, reason))?;
+    Ok(opt)
+}
+
 pub fn get_task_client_config(ctx: Arc<QueryContext>, timeout: Duration) -> Result<ClientConfig> {
     let tenant = ctx.get_tenant();
     let user = ctx.get_current_user()?.identity().display().to_string();
@@ -15,10 +15,13 @@
 use std::sync::Arc;
 
 use databend_common_catalog::lock::LockTableOption;
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::TableExt;
+use databend_common_catalog::table::TimeNavigation;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataSchema;
+use databend_common_meta_app::schema::SnapshotRefType;
 use databend_common_pipeline_sources::AsyncSourcer;
 use databend_common_sql::executor::physical_plans::DistributedInsertSelect;
 use databend_common_sql::executor::physical_plans::MutationKind;
@@ -103,6 +106,20 @@ impl Interpreter for InsertInterpreter {
         // check mutability
         table.check_mutable()?;
 
+        // the rows of `INSERT INTO t AT (BRANCH => ..)` are committed to the branch only.
+        let table = match &self.plan.branch {
+            Some(branch) => {
+                let navigation = TimeNavigation::TimeTravel(NavigationPoint::SnapshotRef {
+                    typ: SnapshotRefType::Branch,
+                    name: branch.clone(),
+                });
+                table
+                    .navigate_to(&navigation, self.ctx.clone().get_abort_checker())
+                    .await?
+            }
+            None => table,
+        };
+
         let mut build_res = PipelineBuildResult::create();
 
         match &self.plan.source {
@@ -215,8 +232,8 @@ impl Interpreter for InsertInterpreter {
                     unsafe { self.ctx.get_settings().get_deduplicate_label()? },
                 )?;
 
-                //  Execute the hook operator.
-                {
+                //  Execute the hook operator, the hooks work on the table, not on a branch.
+                if self.plan.branch.is_none() {
                     let hook_operator = HookOperator::create(
                         self.ctx.clone(),
                         self.plan.catalog.clone(),
@@ -243,8 +260,8 @@ impl Interpreter for InsertInterpreter {
             unsafe { self.ctx.get_settings().get_deduplicate_label()? },
         )?;
 
-        //  Execute the hook operator.
-        {
+        //  Execute the hook operator, the hooks work on the table, not on a branch.
+        if self.plan.branch.is_none() {
             let hook_operator = HookOperator::create(
                 self.ctx.clone(),
                 self.plan.catalog.clone(),
@@ -18,7 +18,9 @@ use databend_common_base::base::ProgressValues;
 use databend_common_catalog::lock::LockTableOption;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::TableExt;
+use databend_common_catalog::table::TimeNavigation;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::UInt32Type;
@@ -26,6 +28,7 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::FromData;
 use databend_common_expression::SendableDataBlockStream;
+use databend_common_meta_app::schema::SnapshotRefType;
 use databend_common_sql::binder::MutationStrategy;
 use databend_common_sql::binder::MutationType;
 use databend_common_sql::executor::physical_plans::create_push_down_filters;
@@ -89,14 +92,7 @@ impl Interpreter for MutationInterpreter {
 
         let mutation: Mutation = self.s_expr.plan().clone().try_into()?;
 
-        let table = self
-            .ctx
-            .get_table(
-                &mutation.catalog_name,
-                &mutation.database_name,
-                &mutation.table_name,
-            )
-            .await?;
+        let table = self.get_target_table(&mutation).await?;
         // Check if the table supports mutation.
         table.check_mutable()?;
         let fuse_table = table.as_any().downcast_ref::<FuseTable>().ok_or_else(|| {
@@ -145,11 +141,40 @@ impl Interpreter for MutationInterpreter {
 }
 
 impl MutationInterpreter {
+    // The table that is mutated, or the branch of it that is mutated.
+    async fn get_target_table(&self, mutation: &Mutation) -> Result<Arc<dyn Table>> {
+        let table = self
+            .ctx
+            .get_table(
+                &mutation.catalog_name,
+                &mutation.database_name,
+                &mutation.table_name,
+            )
+            .await?;
+        match &mutation.branch {
+            Some(branch) => {
+                let navigation = TimeNavigation::TimeTravel(NavigationPoint::SnapshotRef {
+                    typ: SnapshotRefType::Branch,
+                    name: branch.clone(),
+                });
+                table
+                    .navigate_to(&navigation, self.ctx.clone().get_abort_checker())
+                    .await
+            }
+            None => Ok(table),
+        }
+    }
+
     pub async fn execute_hook(
         &self,
         mutation: &databend_common_sql::plans::Mutation,
         build_res: &mut PipelineBuildResult,
     ) {
+        // The hooks work on the table, not on a branch of it.
+        if mutation.branch.is_some() {
+            return;
+        }
+
         let hook_lock_opt = if mutation.lock_guard.is_some() {
             LockTableOption::NoLock
         } else {
@@ -208,14 +233,7 @@ impl MutationInterpreter {
         let mutation_build_info = if let Some(mutation_build_info) = mutation_build_info {
             mutation_build_info
         } else {
-            let table = self
-                .ctx
-                .get_table(
-                    &mutation.catalog_name,
-                    &mutation.database_name,
-                    &mutation.table_name,
-                )
-                .await?;
+            let table = self.get_target_table(mutation).await?;
 
             // Check if the table supports DataMutation.
             table.check_mutable()?;
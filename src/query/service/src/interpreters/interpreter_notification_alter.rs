@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
 use databend_common_ast::ast::AlterNotificationOptions;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_cloud_control::client_config::make_request;
@@ -22,10 +23,14 @@ use databend_common_cloud_control::pb::AlterNotificationRequest;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::WebhookOptions;
+use databend_common_meta_types::MatchSeq;
 use databend_common_sql::plans::AlterNotificationPlan;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_notification_client_config;
 use crate::interpreters::Interpreter;
+use crate::notification::encrypt_webhook_secrets;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 
@@ -72,6 +77,60 @@ impl AlterNotificationInterpreter {
             }
         }
     }
+
+    /// Alter the notification integration stored in the meta-service.
+    #[async_backtrace::framed]
+    async fn alter_local(&self) -> Result<()> {
+        let notification_api = UserApiProvider::instance().notification_api(&self.plan.tenant);
+        let Some(seq_v) = notification_api.get_notification(&self.plan.name).await? else {
+            if self.plan.if_exists {
+                return Ok(());
+            }
+            return Err(ErrorCode::UnknownNotification(format!(
+                "Unknown notification '{}'",
+                self.plan.name
+            )));
+        };
+
+        let mut notification = seq_v.data;
+        match &self.plan.options {
+            AlterNotificationOptions::Set(set_options) => {
+                if let Some(enabled) = set_options.enabled {
+                    notification.enabled = enabled;
+                }
+                if let Some(webhook_opts) = &set_options.webhook_opts {
+                    if let Some(url) = &webhook_opts.url {
+                        notification.webhook.url = url.clone();
+                    }
+                    if webhook_opts.method.is_some() {
+                        notification.webhook.method = webhook_opts.method.clone();
+                    }
+                    // Only the secrets being set are encrypted, the others are kept as stored.
+                    let mut secrets = WebhookOptions {
+                        authorization_header: webhook_opts.authorization_header.clone(),
+                        signing_secret: webhook_opts.signing_secret.clone(),
+                        ..Default::default()
+                    };
+                    encrypt_webhook_secrets(&mut secrets)?;
+                    if secrets.authorization_header.is_some() {
+                        notification.webhook.authorization_header = secrets.authorization_header;
+                    }
+                    if secrets.signing_secret.is_some() {
+                        notification.webhook.signing_secret = secrets.signing_secret;
+                    }
+                }
+                if set_options.comments.is_some() {
+                    notification.comments = set_options.comments.clone();
+                }
+            }
+        }
+        notification.updated_at = Utc::now();
+
+        notification_api
+            .update_notification(notification, MatchSeq::Exact(seq_v.seq))
+            .await?;
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -89,9 +148,8 @@ impl Interpreter for AlterNotificationInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot create notification without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            self.alter_local().await?;
+            return Ok(PipelineBuildResult::create());
         }
         let cloud_api = CloudControlApiProvider::instance();
         let notify_client = cloud_api.get_notification_client();
@@ -14,17 +14,23 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
 use databend_common_cloud_control::client_config::make_request;
 use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::notification_utils::get_notification_type;
 use databend_common_cloud_control::pb::CreateNotificationRequest;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_management::NotificationMgr;
+use databend_common_meta_app::principal::Notification;
+use databend_common_meta_app::principal::WebhookOptions;
+use databend_common_meta_app::schema::CreateOption;
 use databend_common_sql::plans::CreateNotificationPlan;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_notification_client_config;
 use crate::interpreters::Interpreter;
+use crate::notification::encrypt_webhook_secrets;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
 
@@ -69,6 +75,40 @@ impl CreateNotificationInterpreter {
                 .unwrap_or_default(),
         }
     }
+
+    fn build_notification(&self) -> Result<Notification> {
+        let plan = &self.plan;
+        let webhook_opts = plan.webhook_opts.clone();
+        let url = match webhook_opts.as_ref().and_then(|x| x.url.clone()) {
+            Some(url) if !url.is_empty() => url,
+            _ => {
+                return Err(NotificationMgr::illegal_notification(
+                    &plan.name,
+                    "webhook url is required",
+                ));
+            }
+        };
+        let mut webhook = WebhookOptions {
+            url,
+            method: webhook_opts.as_ref().and_then(|x| x.method.clone()),
+            authorization_header: webhook_opts
+                .as_ref()
+                .and_then(|x| x.authorization_header.clone()),
+            signing_secret: webhook_opts.and_then(|x| x.signing_secret),
+        };
+        encrypt_webhook_secrets(&mut webhook)?;
+
+        let now = Utc::now();
+        Ok(Notification {
+            notification_id: 0,
+            name: plan.name.clone(),
+            enabled: plan.enabled,
+            webhook,
+            comments: plan.comments.clone(),
+            created_at: now,
+            updated_at: now,
+        })
+    }
 }
 
 #[async_trait::async_trait]
@@ -86,9 +126,16 @@ impl Interpreter for CreateNotificationInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot create notification without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            let create_option = if self.plan.if_not_exists {
+                CreateOption::CreateIfNotExists
+            } else {
+                CreateOption::Create
+            };
+            let notification_api = UserApiProvider::instance().notification_api(&self.plan.tenant);
+            notification_api
+                .create_notification(self.build_notification()?, &create_option)
+                .await?;
+            return Ok(PipelineBuildResult::create());
         }
         let cloud_api = CloudControlApiProvider::instance();
         let notify_client = cloud_api.get_notification_client();
@@ -21,7 +21,9 @@ use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_sql::plans::DescNotificationPlan;
+use databend_common_storages_system::notification_to_pb;
 use databend_common_storages_system::parse_notifications_to_datablock;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_notification_client_config;
 use crate::interpreters::Interpreter;
@@ -65,9 +67,15 @@ impl Interpreter for DescNotificationInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot describe notification without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            let notification_api = UserApiProvider::instance().notification_api(&self.plan.tenant);
+            let Some(seq_v) = notification_api.get_notification(&self.plan.name).await? else {
+                return Err(ErrorCode::UnknownNotification(format!(
+                    "Unknown notification '{}'",
+                    self.plan.name
+                )));
+            };
+            let result = parse_notifications_to_datablock(vec![notification_to_pb(seq_v.data)])?;
+            return PipelineBuildResult::from_blocks(vec![result]);
         }
         let cloud_api = CloudControlApiProvider::instance();
         let notification_cli = cloud_api.get_notification_client();
@@ -21,6 +21,7 @@ use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_sql::plans::DropNotificationPlan;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_notification_client_config;
 use crate::interpreters::Interpreter;
@@ -65,9 +66,15 @@ impl Interpreter for DropNotificationInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot drop notification without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            let notification_api = UserApiProvider::instance().notification_api(&self.plan.tenant);
+            let dropped = notification_api.drop_notification(&self.plan.name).await?;
+            if dropped.is_none() && !self.plan.if_exists {
+                return Err(ErrorCode::UnknownNotification(format!(
+                    "Unknown notification '{}'",
+                    self.plan.name
+                )));
+            }
+            return Ok(PipelineBuildResult::create());
         }
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_notification_client();
@@ -0,0 +1,61 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_config::GlobalConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_sql::plans::SendNotificationPlan;
+
+use crate::interpreters::Interpreter;
+use crate::notification::NotificationSender;
+use crate::pipelines::PipelineBuildResult;
+
+#[derive(Debug)]
+pub struct SendNotificationInterpreter {
+    plan: SendNotificationPlan,
+}
+
+impl SendNotificationInterpreter {
+    pub fn try_create(plan: SendNotificationPlan) -> Result<Self> {
+        Ok(SendNotificationInterpreter { plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for SendNotificationInterpreter {
+    fn name(&self) -> &str {
+        "SendNotificationInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        false
+    }
+
+    #[fastrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let config = GlobalConfig::instance();
+        if config.query.cloud_control_grpc_server_address.is_some() {
+            return Err(ErrorCode::Unimplemented(
+                "send_notification is not supported when cloud control is enabled",
+            ));
+        }
+
+        let sender = NotificationSender::create(&self.plan.tenant)?;
+        sender
+            .send(&self.plan.name, "send_notification", &self.plan.message)
+            .await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -15,9 +15,12 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use async_channel::Receiver;
 use chrono::Utc;
 use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_sql::executor::physical_plans::AggregateExpand;
 use databend_common_sql::executor::physical_plans::AggregateFinal;
@@ -35,6 +38,7 @@ use databend_common_sql::Planner;
 use databend_common_storage::DEFAULT_HISTOGRAM_BUCKETS;
 use databend_common_storages_factory::NavigationPoint;
 use databend_common_storages_factory::Table;
+use databend_common_storages_fuse::operations::ColumnGroupAnalyzeInfo;
 use databend_common_storages_fuse::operations::HistogramInfoSink;
 use databend_common_storages_fuse::FuseTable;
 use databend_storages_common_index::Index;
@@ -49,6 +53,9 @@ use crate::schedulers::build_query_pipeline_without_render_result_set;
 use crate::sessions::QueryContext;
 use crate::sessions::TableContext;
 
+/// Max number of the most common value combinations kept for a column group.
+const MOST_COMMON_VALUES_LIMIT: usize = 100;
+
 pub struct AnalyzeTableInterpreter {
     ctx: Arc<QueryContext>,
     plan: AnalyzeTablePlan,
@@ -80,6 +87,41 @@ impl AnalyzeTableInterpreter {
         };
         Ok((select_plan, bind_context))
     }
+
+    // Build the pipeline of the sql as a source pipeline of `build_res`, the result blocks are sent
+    // to the returned receiver.
+    async fn build_sink_pipeline(
+        &self,
+        sql: String,
+        build_res: &mut PipelineBuildResult,
+    ) -> Result<Receiver<DataBlock>> {
+        let (mut plan, bind_context) = self.plan_sql(sql).await?;
+        if !self.ctx.get_cluster().is_empty() {
+            plan = remove_exchange(plan);
+        }
+        let mut sink_build_res = build_query_pipeline(
+            &QueryContext::create_from(self.ctx.clone()),
+            &bind_context.columns,
+            &plan,
+            false,
+        )
+        .await?;
+        let (tx, rx) = async_channel::unbounded();
+        sink_build_res.main_pipeline.add_sink(|input_port| {
+            Ok(ProcessorPtr::create(HistogramInfoSink::create(
+                Some(tx.clone()),
+                input_port.clone(),
+            )))
+        })?;
+
+        build_res
+            .sources_pipelines
+            .push(sink_build_res.main_pipeline.finalize());
+        build_res
+            .sources_pipelines
+            .extend(sink_build_res.sources_pipelines);
+        Ok(rx)
+    }
 }
 
 #[async_trait::async_trait]
@@ -166,6 +208,57 @@ impl Interpreter for AnalyzeTableInterpreter {
                 .filter(|f| RangeIndex::supported_type(&f.data_type().into()))
                 .map(|f| (f.column_id(), f.name.clone()))
                 .collect();
+            let column_name = |column_id: &u32| {
+                index_cols
+                    .iter()
+                    .find(|c| c.0 == *column_id)
+                    .map(|c| c.1.clone())
+            };
+
+            // The column groups analyzed before are collected again, unless some of
+            // their columns have been dropped.
+            let mut column_groups: Vec<Vec<u32>> = table_statistics
+                .as_ref()
+                .map(|s| {
+                    s.column_groups
+                        .iter()
+                        .map(|group| group.column_ids.clone())
+                        .filter(|column_ids| column_ids.iter().all(|id| column_name(id).is_some()))
+                        .collect()
+                })
+                .unwrap_or_default();
+            let mut has_new_group = false;
+            for group in &plan.column_groups {
+                let mut column_ids = Vec::with_capacity(group.len());
+                for name in group {
+                    let field = schema.field_with_name(name)?;
+                    if column_name(&field.column_id()).is_none() {
+                        return Err(ErrorCode::SemanticError(format!(
+                            "column {} of type {} doesn't support column group statistics",
+                            name,
+                            field.data_type()
+                        )));
+                    }
+                    column_ids.push(field.column_id());
+                }
+                if !column_groups.contains(&column_ids) {
+                    column_groups.push(column_ids);
+                    has_new_group = true;
+                }
+            }
+            // The joint ndv of a new column group can't be collected incrementally.
+            let (is_full, temporal_str) = if has_new_group && !is_full {
+                (
+                    true,
+                    format!("AT (snapshot => '{}')", snapshot.snapshot_id.simple()),
+                )
+            } else {
+                (is_full, temporal_str)
+            };
+            let column_group_names = column_groups
+                .iter()
+                .map(|column_ids| column_ids.iter().filter_map(column_name).join(", "))
+                .collect::<Vec<_>>();
 
             // 0.01625 --> 12 buckets --> 4K size per column
             // 1.04 / math.sqrt(1<<12) --> 0.01625
@@ -179,9 +272,18 @@ impl Interpreter for AnalyzeTableInterpreter {
                     )
                 })
                 .join(", ");
+            let group_ndv_select_expr = column_group_names
+                .iter()
+                .enumerate()
+                .map(|(i, columns)| {
+                    format!(
+                        ", approx_count_distinct_state({DISTINCT_ERROR_RATE})(({columns})) as group_ndv_{i}"
+                    )
+                })
+                .join("");
 
             let sql = format!(
-                "SELECT {ndv_select_expr}, {is_full} as is_full from {}.{} {temporal_str}",
+                "SELECT {ndv_select_expr}{group_ndv_select_expr}, {is_full} as is_full from {}.{} {temporal_str}",
                 plan.database, plan.table,
             );
 
@@ -223,34 +325,38 @@ impl Interpreter for AnalyzeTableInterpreter {
                     .collect::<Vec<_>>();
                 for (sql, (col_id, _)) in histogram_sqls.into_iter().zip(index_cols.iter()) {
                     info!("Analyze histogram via sql {:?}", sql);
-                    let (mut histogram_plan, bind_context) = self.plan_sql(sql).await?;
-                    if !self.ctx.get_cluster().is_empty() {
-                        histogram_plan = remove_exchange(histogram_plan);
-                    }
-                    let mut histogram_build_res = build_query_pipeline(
-                        &QueryContext::create_from(self.ctx.clone()),
-                        &bind_context.columns,
-                        &histogram_plan,
-                        false,
-                    )
-                    .await?;
-                    let (tx, rx) = async_channel::unbounded();
-                    histogram_build_res.main_pipeline.add_sink(|input_port| {
-                        Ok(ProcessorPtr::create(HistogramInfoSink::create(
-                            Some(tx.clone()),
-                            input_port.clone(),
-                        )))
-                    })?;
-
-                    build_res
-                        .sources_pipelines
-                        .push(histogram_build_res.main_pipeline.finalize());
-                    build_res
-                        .sources_pipelines
-                        .extend(histogram_build_res.sources_pipelines);
+                    let rx = self.build_sink_pipeline(sql, &mut build_res).await?;
                     histogram_info_receivers.insert(*col_id, rx);
                 }
             }
+
+            // Collecting the most common values needs an extra aggregation per column group,
+            // so it's controlled by the setting `enable_analyze_most_common_values`(default is open).
+            // The most common values are always collected from the whole snapshot.
+            let enable_most_common_values = self
+                .ctx
+                .get_settings()
+                .get_enable_analyze_most_common_values()?;
+            let mut column_group_infos = Vec::with_capacity(column_groups.len());
+            for (column_ids, columns) in column_groups.into_iter().zip(column_group_names.iter()) {
+                let mut rx = None;
+                if enable_most_common_values {
+                    let sql = format!(
+                        "SELECT {columns}, COUNT() AS count FROM {}.{} AT (snapshot => '{}') \
+                        GROUP BY {columns} ORDER BY count DESC LIMIT {MOST_COMMON_VALUES_LIMIT}",
+                        plan.database,
+                        plan.table,
+                        snapshot.snapshot_id.simple(),
+                    );
+                    info!("Analyze most common values via sql {:?}", sql);
+                    rx = Some(self.build_sink_pipeline(sql, &mut build_res).await?);
+                }
+                column_group_infos.push(ColumnGroupAnalyzeInfo {
+                    column_ids,
+                    row_count: snapshot.summary.row_count,
+                    most_common_values_receiver: rx,
+                });
+            }
             FuseTable::do_analyze(
                 self.ctx.clone(),
                 bind_context.output_schema(),
@@ -260,6 +366,7 @@ impl Interpreter for AnalyzeTableInterpreter {
                 snapshot.snapshot_id,
                 &mut build_res.main_pipeline,
                 histogram_info_receivers,
+                column_group_infos,
             )?;
             return Ok(build_res);
         }
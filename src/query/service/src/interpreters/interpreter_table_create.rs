@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use chrono::Utc;
 use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_catalog::table::Table;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -31,6 +32,7 @@ use databend_common_meta_app::principal::OwnershipObject;
 use databend_common_meta_app::schema::CommitTableMetaReq;
 use databend_common_meta_app::schema::CreateOption;
 use databend_common_meta_app::schema::CreateTableReq;
+use databend_common_meta_app::schema::TableClonePin;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
@@ -39,9 +41,11 @@ use databend_common_meta_app::schema::TableStatistics;
 use databend_common_meta_types::MatchSeq;
 use databend_common_pipeline_core::ExecutionInfo;
 use databend_common_sql::field_default_value;
+use databend_common_sql::plans::CloneTableSource;
 use databend_common_sql::plans::CreateTablePlan;
 use databend_common_storages_fuse::io::MetaReaders;
 use databend_common_storages_fuse::FuseStorageFormat;
+use databend_common_storages_fuse::FuseTable;
 use databend_common_users::RoleCacheManager;
 use databend_common_users::UserApiProvider;
 use databend_enterprise_attach_table::get_attach_table_handler;
@@ -55,6 +59,7 @@ use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
 use log::error;
 use log::info;
+use uuid::Uuid;
 
 use crate::interpreters::common::table_option_validation::is_valid_block_per_segment;
 use crate::interpreters::common::table_option_validation::is_valid_bloom_index_columns;
@@ -63,6 +68,7 @@ use crate::interpreters::common::table_option_validation::is_valid_create_opt;
 use crate::interpreters::common::table_option_validation::is_valid_data_retention_period;
 use crate::interpreters::common::table_option_validation::is_valid_random_seed;
 use crate::interpreters::common::table_option_validation::is_valid_row_per_block;
+use crate::interpreters::common::table_option_validation::is_valid_tiering_options;
 use crate::interpreters::InsertInterpreter;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -151,6 +157,10 @@ impl Interpreter for CreateTableInterpreter {
             }
         }
 
+        if let Some(clone_source) = &self.plan.clone_source {
+            return self.create_table_clone(clone_source).await;
+        }
+
         match &self.plan.as_select {
             Some(select_plan_node) => self.create_table_as_select(select_plan_node.clone()).await,
             None => self.create_table().await,
@@ -233,6 +243,7 @@ impl CreateTableInterpreter {
             overwrite: false,
             source: InsertInputSource::SelectPlan(select_plan),
             table_info: Some(table_info),
+            branch: None,
         };
 
         let mut pipeline = InsertInterpreter::try_create(self.ctx.clone(), insert_plan)?
@@ -289,6 +300,151 @@ impl CreateTableInterpreter {
         Ok(pipeline)
     }
 
+    /// Create a table which shares the segments of a snapshot of the source table,
+    /// without copying any data.
+    ///
+    /// Like `CREATE TABLE AS SELECT`, the table is created as dropped first, and becomes
+    /// visible once its first snapshot has been committed.
+    #[async_backtrace::framed]
+    async fn create_table_clone(&self, source: &CloneTableSource) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+
+        // 1. Locate the snapshot of the source table to be shared.
+        let table = self
+            .ctx
+            .get_table(&source.catalog, &source.database, &source.table)
+            .await?;
+        let source_table = FuseTable::try_from_table(table.as_ref())?;
+        let source_table = match &source.point {
+            Some(point) => {
+                source_table
+                    .navigate_to_point(point, self.ctx.clone().get_abort_checker())
+                    .await?
+            }
+            None => Arc::new(source_table.clone()),
+        };
+        let source_snapshot_location = source_table.snapshot_loc().await?;
+        let source_snapshot = source_table.read_table_snapshot().await?;
+
+        // 2. Create the clone as a dropped table, and pin the shared snapshot in the source
+        // table in the same transaction. The snapshot is protected from the gc of the source
+        // table as long as the clone exists.
+        // The statistics are the ones of the shared snapshot, which is not the current
+        // snapshot of the source table if the clone is created `AT` a point.
+        let statistics = source_snapshot
+            .as_ref()
+            .map(|snapshot| TableStatistics {
+                number_of_rows: snapshot.summary.row_count,
+                data_bytes: snapshot.summary.uncompressed_byte_size,
+                compressed_data_bytes: snapshot.summary.compressed_byte_size,
+                index_data_bytes: snapshot.summary.index_size,
+                number_of_segments: Some(snapshot.segments.len() as u64),
+                number_of_blocks: Some(snapshot.summary.block_count),
+            })
+            .unwrap_or_default();
+        let mut req = self.build_request(Some(statistics))?;
+        let schema = source_table.schema();
+        if req.table_meta.field_comments.len() != schema.num_fields() {
+            req.table_meta.field_comments = vec!["".to_string(); schema.num_fields()];
+        }
+        req.table_meta.schema = schema;
+        req.as_dropped = true;
+        req.table_meta.drop_on = Some(Utc::now());
+        req.clone_pin = source_snapshot_location
+            .as_ref()
+            .map(|location| TableClonePin {
+                source_table_id: source_table.get_id(),
+                snapshot_location: location.clone(),
+            });
+        let reply = catalog.create_table(req.clone()).await?;
+        if !reply.new_table && self.plan.create_option != CreateOption::CreateOrReplace {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let table_id = reply.table_id;
+        let table_id_seq = reply
+            .table_id_seq
+            .expect("internal error: table_id_seq must have been set. CLONE(replace) of table");
+        let db_id = reply.db_id;
+
+        if let (Some(location), Some(snapshot)) = (source_snapshot_location, source_snapshot) {
+            // 3. A gc of the source table which started before the pin may have purged
+            // the snapshot in the meantime.
+            if !source_table.get_operator().exists(&location).await? {
+                return Err(ErrorCode::StorageNotFound(format!(
+                    "snapshot {} of the clone source {} has been purged",
+                    location,
+                    source_table.get_table_info().desc
+                )));
+            }
+
+            // 4. Commit the first snapshot of the clone, which shares the segments of the source.
+            let table_info = TableInfo::new(
+                &self.plan.database,
+                &self.plan.table,
+                TableIdent::new(table_id, table_id_seq),
+                req.table_meta.clone(),
+            );
+            let clone_table = FuseTable::do_create(table_info.clone())?;
+            let new_snapshot = TableSnapshot::new(
+                Uuid::new_v4(),
+                Some(table_id_seq),
+                &snapshot.timestamp,
+                None,
+                snapshot.schema.clone(),
+                snapshot.summary.clone(),
+                snapshot.segments.clone(),
+                clone_table.cluster_key_meta(),
+                snapshot.table_statistics_location.clone(),
+            );
+            FuseTable::commit_to_meta_server(
+                self.ctx.as_ref(),
+                &table_info,
+                clone_table.meta_location_generator(),
+                new_snapshot,
+                None,
+                &None,
+                clone_table.get_operator_ref(),
+            )
+            .await?;
+        }
+
+        if !req.table_meta.options.contains_key(OPT_KEY_TEMP_PREFIX) {
+            // grant the ownership of the table to the current role.
+            if let Some(current_role) = self.ctx.get_current_role() {
+                let role_api = UserApiProvider::instance().role_api(&tenant);
+                role_api
+                    .grant_ownership(
+                        &OwnershipObject::Table {
+                            catalog_name: self.plan.catalog.clone(),
+                            db_id,
+                            table_id,
+                        },
+                        &current_role.name,
+                    )
+                    .await?;
+                RoleCacheManager::instance().invalidate_cache(&tenant);
+            }
+        }
+
+        // 5. Make the clone visible.
+        let req = CommitTableMetaReq {
+            name_ident: TableNameIdent {
+                tenant,
+                db_name: self.plan.database.clone(),
+                table_name: self.plan.table.clone(),
+            },
+            db_id,
+            table_id,
+            prev_table_id: reply.prev_table_id,
+            orphan_table_name: reply.orphan_table_name.clone(),
+        };
+        catalog.commit_table_meta(req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+
     #[async_backtrace::framed]
     async fn create_table(&self) -> Result<PipelineBuildResult> {
         let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
@@ -379,6 +535,7 @@ impl CreateTableInterpreter {
             schema: schema.clone(),
             engine: self.plan.engine.to_string(),
             storage_params: self.plan.storage_params.clone(),
+            cold_storage_params: self.plan.cold_storage_params.clone(),
             options,
             engine_options: self.plan.engine_options.clone(),
             default_cluster_key: None,
@@ -399,6 +556,11 @@ impl CreateTableInterpreter {
         is_valid_random_seed(&table_meta.options)?;
         // check table level data_retention_period_in_hours
         is_valid_data_retention_period(&table_meta.options)?;
+        // check tiering policy
+        is_valid_tiering_options(
+            &table_meta.options,
+            table_meta.cold_storage_params.is_some(),
+        )?;
 
         for table_option in table_meta.options.iter() {
             let key = table_option.0.to_lowercase();
@@ -423,6 +585,7 @@ impl CreateTableInterpreter {
             },
             table_meta,
             as_dropped: false,
+            clone_pin: None,
         };
 
         Ok(req)
@@ -0,0 +1,110 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::SnapshotRef;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::CreateTableRefPlan;
+use databend_common_storages_fuse::FuseTable;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateTableRefInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateTableRefPlan,
+}
+
+impl CreateTableRefInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateTableRefPlan) -> Result<Self> {
+        Ok(CreateTableRefInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateTableRefInterpreter {
+    fn name(&self) -> &str {
+        "CreateTableRefInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
+        let table = catalog
+            .get_table(&self.plan.tenant, &self.plan.database, &self.plan.table)
+            .await?;
+        // check mutability
+        table.check_mutable()?;
+
+        let table_info = table.get_table_info();
+        if table_info.meta.refs.contains_key(&self.plan.name) {
+            return if self.plan.if_not_exists {
+                Ok(PipelineBuildResult::create())
+            } else {
+                Err(ErrorCode::TableRefAlreadyExists(format!(
+                    "Branch or tag '{}' of table '{}' already exists",
+                    self.plan.name, table_info.desc
+                )))
+            };
+        }
+
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let snapshot_location = match &self.plan.point {
+            Some(point) => {
+                fuse_table
+                    .navigate_to_point(point, self.ctx.clone().get_abort_checker())
+                    .await?
+                    .snapshot_loc()
+                    .await?
+            }
+            None => fuse_table.snapshot_loc().await?,
+        };
+        let Some(snapshot_location) = snapshot_location else {
+            return Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                "Can not create {} '{}' of empty table '{}'",
+                self.plan.typ, self.plan.name, table_info.desc
+            )));
+        };
+
+        let mut new_table_meta = table_info.meta.clone();
+        new_table_meta
+            .refs
+            .insert(self.plan.name.clone(), SnapshotRef {
+                typ: self.plan.typ,
+                snapshot_location,
+                created_on: Utc::now(),
+            });
+
+        let req = UpdateTableMetaReq {
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            new_table_meta,
+        };
+        catalog.update_single_table_meta(req, table_info).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_license::license::Feature;
+use databend_common_license::license_manager::LicenseManagerSwitch;
+use databend_common_meta_app::schema::DatabaseType;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyAction;
+use databend_common_meta_app::schema::SetTableRowAccessPolicyReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::AddTableRowAccessPolicyPlan;
+use databend_common_sql::resolve_type_name_by_str;
+use databend_common_storages_stream::stream_table::STREAM_ENGINE;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_common_users::UserApiProvider;
+use databend_enterprise_row_access_policy_feature::get_row_access_policy_handler;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct AddTableRowAccessPolicyInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AddTableRowAccessPolicyPlan,
+}
+
+impl AddTableRowAccessPolicyInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AddTableRowAccessPolicyPlan) -> Result<Self> {
+        Ok(AddTableRowAccessPolicyInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AddTableRowAccessPolicyInterpreter {
+    fn name(&self) -> &str {
+        "AddTableRowAccessPolicyInterpreter"
+    }
+
+    fn is_ddl(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        LicenseManagerSwitch::instance()
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::RowAccessPolicy)?;
+
+        let catalog = self.ctx.get_catalog(self.plan.catalog.as_str()).await?;
+        let table = catalog
+            .get_table(&self.plan.tenant, &self.plan.database, &self.plan.table)
+            .await?;
+        // check mutability
+        table.check_mutable()?;
+
+        let table_info = table.get_table_info();
+        let engine = table.engine();
+        if table.is_temp()
+            || matches!(engine, VIEW_ENGINE | STREAM_ENGINE)
+            || table_info.db_type != DatabaseType::NormalDB
+        {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "Table '{}' doesn't support row access policy",
+                table_info.desc
+            )));
+        }
+
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let policy = get_row_access_policy_handler()
+            .get_row_access_policy(meta_api, &self.plan.tenant, self.plan.policy.clone())
+            .await?;
+        if policy.args.len() != self.plan.columns.len() {
+            return Err(ErrorCode::SemanticError(format!(
+                "Row access policy '{}' takes {} arguments, but {} columns are given",
+                self.plan.policy,
+                policy.args.len(),
+                self.plan.columns.len()
+            )));
+        }
+
+        // check if the column types match to the argument types
+        let schema = table.schema();
+        for ((arg_name, arg_type), column) in policy.args.iter().zip(self.plan.columns.iter()) {
+            let field = schema.field_with_name(column)?;
+            let arg_type = resolve_type_name_by_str(arg_type, true)?;
+            if field.data_type().remove_nullable() != arg_type {
+                return Err(ErrorCode::UnmatchColumnDataType(format!(
+                    "Column '{}' data type {} does not match to the argument '{}' type {} of row access policy '{}'",
+                    column,
+                    field.data_type(),
+                    arg_name,
+                    arg_type,
+                    self.plan.policy,
+                )));
+            }
+        }
+
+        let req = SetTableRowAccessPolicyReq {
+            tenant: self.plan.tenant.clone(),
+            table_id: table_info.ident.table_id,
+            seq: MatchSeq::Exact(table_info.ident.seq),
+            action: SetTableRowAccessPolicyAction::Set(
+                self.plan.policy.clone(),
+                self.plan.columns.clone(),
+            ),
+        };
+        catalog.set_table_row_access_policy(req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
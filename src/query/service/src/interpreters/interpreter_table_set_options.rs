@@ -28,6 +28,8 @@ use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_FORMAT;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_TIERING_COLD_CONNECTION_NAME;
+use databend_storages_common_table_meta::table::OPT_KEY_TIERING_COLD_LOCATION;
 use log::error;
 
 use crate::interpreters::common::table_option_validation::is_valid_block_per_segment;
@@ -35,6 +37,7 @@ use crate::interpreters::common::table_option_validation::is_valid_bloom_index_c
 use crate::interpreters::common::table_option_validation::is_valid_create_opt;
 use crate::interpreters::common::table_option_validation::is_valid_data_retention_period;
 use crate::interpreters::common::table_option_validation::is_valid_row_per_block;
+use crate::interpreters::common::table_option_validation::is_valid_tiering_options;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
@@ -101,6 +104,18 @@ impl Interpreter for SetOptionsInterpreter {
                 OPT_KEY_CLUSTER_TYPE
             )));
         }
+        for key in [
+            OPT_KEY_TIERING_COLD_LOCATION,
+            OPT_KEY_TIERING_COLD_CONNECTION_NAME,
+        ] {
+            if self.plan.set_options.contains_key(key) {
+                error!("{}", &error_str);
+                return Err(ErrorCode::TableOptionInvalid(format!(
+                    "can't change {} for alter table statement",
+                    key
+                )));
+            }
+        }
         for table_option in self.plan.set_options.iter() {
             let key = table_option.0.to_lowercase();
             if !is_valid_create_opt(&key) {
@@ -136,6 +151,11 @@ impl Interpreter for SetOptionsInterpreter {
 
         // check bloom_index_columns.
         is_valid_bloom_index_columns(&self.plan.set_options, table.schema())?;
+        // check tiering policy
+        is_valid_tiering_options(
+            &self.plan.set_options,
+            table.get_table_info().meta.cold_storage_params.is_some(),
+        )?;
 
         let req = UpsertTableOptionReq {
             table_id: table.get_id(),
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
 use databend_common_ast::ast::AlterTaskOptions;
 use databend_common_ast::ast::TaskSql;
 use databend_common_catalog::table_context::TableContext;
@@ -26,9 +27,15 @@ use databend_common_cloud_control::pb::WarehouseOptions;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_management::TaskMgr;
+use databend_common_meta_app::principal;
+use databend_common_meta_app::principal::Status;
+use databend_common_meta_types::MatchSeq;
 use databend_common_sql::plans::AlterTaskPlan;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_task_client_config;
+use crate::interpreters::common::make_local_schedule_options;
 use crate::interpreters::common::make_schedule_options;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -137,6 +144,114 @@ impl AlterTaskInterpreter {
         }
         req
     }
+
+    /// Alter a task stored in meta-service, which is run by the built-in task scheduler.
+    async fn alter_local_task(&self) -> Result<()> {
+        let plan = self.plan.clone();
+        let task_api = UserApiProvider::instance().task_api(&plan.tenant);
+        let Some(seq_v) = task_api.get_task(&plan.task_name).await? else {
+            if plan.if_exists {
+                return Ok(());
+            }
+            return Err(ErrorCode::UnknownTask(format!(
+                "Unknown task '{}'",
+                plan.task_name
+            )));
+        };
+
+        let mut task = seq_v.data;
+        let now = Utc::now();
+        match plan.alter_options {
+            AlterTaskOptions::Resume => {
+                if task.status != Status::Started {
+                    task.status = Status::Started;
+                    task.consecutive_failures = 0;
+                    // The schedule starts over from now.
+                    task.next_scheduled_at = None;
+                }
+            }
+            AlterTaskOptions::Suspend => {
+                if task.status != Status::Suspended {
+                    task.status = Status::Suspended;
+                    task.last_suspended_at = Some(now);
+                }
+            }
+            AlterTaskOptions::Set {
+                schedule,
+                comments,
+                warehouse,
+                suspend_task_after_num_failures,
+                error_integration,
+                session_parameters,
+            } => {
+                if let Some(schedule) = schedule {
+                    task.schedule_options =
+                        Some(make_local_schedule_options(&task.task_name, schedule)?);
+                    task.next_scheduled_at = None;
+                }
+                if let Some(comments) = comments {
+                    task.comment = Some(comments);
+                }
+                if let Some(warehouse) = warehouse {
+                    task.warehouse_options = Some(principal::WarehouseOptions {
+                        warehouse: Some(warehouse),
+                        using_warehouse_size: None,
+                    });
+                }
+                if let Some(num) = suspend_task_after_num_failures {
+                    task.suspend_task_after_num_failures = Some(num);
+                }
+                if let Some(error_integration) = error_integration {
+                    task.error_integration = Some(error_integration);
+                }
+                if let Some(session_parameters) = session_parameters {
+                    task.session_params = session_parameters;
+                }
+            }
+            AlterTaskOptions::Unset { warehouse } => {
+                if warehouse {
+                    task.warehouse_options = None;
+                }
+            }
+            AlterTaskOptions::ModifyAs(sql) => {
+                task.query_text = format!("{}", sql);
+            }
+            AlterTaskOptions::ModifyWhen(expr) => {
+                task.when_condition = Some(expr.to_string());
+            }
+            AlterTaskOptions::AddAfter(tasks) => {
+                for predecessor in tasks {
+                    if predecessor == task.task_name {
+                        return Err(TaskMgr::illegal_task(
+                            &task.task_name,
+                            "a task can not run after itself",
+                        ));
+                    }
+                    if task_api.get_task(&predecessor).await?.is_none() {
+                        return Err(ErrorCode::UnknownTask(format!(
+                            "Unknown predecessor task '{}' of task '{}'",
+                            predecessor, task.task_name
+                        )));
+                    }
+                    if !task.after.contains(&predecessor) {
+                        task.after.push(predecessor);
+                    }
+                }
+                task_api
+                    .ensure_acyclic(&task.task_name, &task.after)
+                    .await?;
+            }
+            AlterTaskOptions::RemoveAfter(tasks) => {
+                task.after.retain(|t| !tasks.contains(t));
+            }
+        }
+        task.updated_at = now;
+
+        task_api
+            .update_task(task, MatchSeq::Exact(seq_v.seq))
+            .await?;
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
@@ -154,9 +269,8 @@ impl Interpreter for AlterTaskInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot alter task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            self.alter_local_task().await?;
+            return Ok(PipelineBuildResult::create());
         }
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
 use databend_common_ast::ast::TaskSql;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_cloud_control::client_config::make_request;
@@ -23,9 +24,15 @@ use databend_common_cloud_control::pb::CreateTaskRequest;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::Status;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::WarehouseOptions;
+use databend_common_meta_app::schema::CreateOption;
 use databend_common_sql::plans::CreateTaskPlan;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_task_client_config;
+use crate::interpreters::common::make_local_schedule_options;
 use crate::interpreters::common::make_schedule_options;
 use crate::interpreters::common::make_warehouse_options;
 use crate::interpreters::Interpreter;
@@ -83,6 +90,66 @@ impl CreateTaskInterpreter {
         }
         req
     }
+
+    /// Create the task in meta-service, it is run by the built-in task scheduler.
+    async fn create_local_task(&self) -> Result<()> {
+        let plan = self.plan.clone();
+        let task_api = UserApiProvider::instance().task_api(&plan.tenant);
+        for predecessor in &plan.after {
+            if task_api.get_task(predecessor).await?.is_none() {
+                return Err(ErrorCode::UnknownTask(format!(
+                    "Unknown predecessor task '{}' of task '{}'",
+                    predecessor, plan.task_name
+                )));
+            }
+        }
+        task_api
+            .ensure_acyclic(&plan.task_name, &plan.after)
+            .await?;
+
+        let schedule_options = plan
+            .schedule_opts
+            .map(|opt| make_local_schedule_options(&plan.task_name, opt))
+            .transpose()?;
+        let owner = self
+            .ctx
+            .get_current_role()
+            .unwrap_or_default()
+            .identity()
+            .to_string();
+        let now = Utc::now();
+        let task = Task {
+            task_id: 0,
+            task_name: plan.task_name,
+            query_text: format!("{}", plan.sql),
+            when_condition: plan.when_condition,
+            after: plan.after,
+            comment: plan.comment,
+            owner,
+            schedule_options,
+            warehouse_options: Some(WarehouseOptions {
+                warehouse: plan.warehouse_opts.warehouse,
+                using_warehouse_size: None,
+            }),
+            next_scheduled_at: None,
+            suspend_task_after_num_failures: plan.suspend_task_after_num_failures,
+            consecutive_failures: 0,
+            error_integration: plan.error_integration,
+            // A task is created suspended, it starts running after `ALTER TASK RESUME`.
+            status: Status::Suspended,
+            created_at: now,
+            updated_at: now,
+            last_suspended_at: None,
+            session_params: plan.session_parameters,
+        };
+
+        let create_option = if plan.if_not_exists {
+            CreateOption::CreateIfNotExists
+        } else {
+            CreateOption::Create
+        };
+        task_api.create_task(task, &create_option).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -100,9 +167,8 @@ impl Interpreter for CreateTaskInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot create task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            self.create_local_task().await?;
+            return Ok(PipelineBuildResult::create());
         }
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
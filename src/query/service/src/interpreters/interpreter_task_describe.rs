@@ -22,6 +22,8 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_sql::plans::DescribeTaskPlan;
 use databend_common_storages_system::parse_tasks_to_datablock;
+use databend_common_storages_system::task_to_pb;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_task_client_config;
 use crate::interpreters::Interpreter;
@@ -66,9 +68,15 @@ impl Interpreter for DescribeTaskInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot describe task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            let task_api = UserApiProvider::instance().task_api(&self.plan.tenant);
+            let Some(task) = task_api.get_task(&self.plan.task_name).await? else {
+                return Err(ErrorCode::UnknownTask(format!(
+                    "Unknown task '{}'",
+                    self.plan.task_name
+                )));
+            };
+            let result = parse_tasks_to_datablock(vec![task_to_pb(task.data)])?;
+            return PipelineBuildResult::from_blocks(vec![result]);
         }
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
@@ -20,7 +20,9 @@ use databend_common_cloud_control::pb::DropTaskRequest;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_management::TaskMgr;
 use databend_common_sql::plans::DropTaskPlan;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_task_client_config;
 use crate::interpreters::Interpreter;
@@ -65,9 +67,26 @@ impl Interpreter for DropTaskInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot drop task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            let task_api = UserApiProvider::instance().task_api(&self.plan.tenant);
+            // A dependent task would never run again once its predecessor is gone.
+            let dependents = task_api.list_dependents(&self.plan.task_name).await?;
+            if !dependents.is_empty() {
+                return Err(TaskMgr::illegal_task(
+                    &self.plan.task_name,
+                    format!(
+                        "tasks '{}' run after it, remove it from their predecessors first",
+                        dependents.join("', '")
+                    ),
+                ));
+            }
+            let dropped = task_api.drop_task(&self.plan.task_name).await?;
+            if dropped.is_none() && !self.plan.if_exists {
+                return Err(ErrorCode::UnknownTask(format!(
+                    "Unknown task '{}'",
+                    self.plan.task_name
+                )));
+            }
+            return Ok(PipelineBuildResult::create());
         }
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
@@ -169,9 +169,35 @@ impl Interpreter for VacuumDropTablesInterpreter {
             info!("view ids excluded from purging data: {:?}", view_ids);
         }
 
+        // Data of tables which still have live clones is shared with the clones,
+        // keep both the data and the metadata until the clones are gone.
+        // Clones dropped before the retention time are purged as well.
+        let mut shared_table_ids = HashSet::new();
+        for table in tables.iter() {
+            for clone_id in table.get_table_info().meta.clones.keys() {
+                let clone_meta = catalog.get_table_meta_by_id(*clone_id).await?;
+                if clone_meta.is_some_and(|m| m.data.drop_on.map_or(true, |t| t > retention_time)) {
+                    shared_table_ids.insert(table.get_id());
+                    break;
+                }
+            }
+        }
+        let tables = if shared_table_ids.is_empty() {
+            tables
+        } else {
+            info!(
+                "tables excluded from purging data, still referenced by clones: {:?}",
+                shared_table_ids
+            );
+            tables
+                .into_iter()
+                .filter(|tbl| !shared_table_ids.contains(&tbl.get_id()))
+                .collect()
+        };
+
         let handler = get_vacuum_handler();
         let threads_nums = self.ctx.get_settings().get_max_threads()? as usize;
-        let (files_opt, failed_tables) = handler
+        let (files_opt, mut failed_tables) = handler
             .do_vacuum_drop_tables(
                 threads_nums,
                 tables,
@@ -182,6 +208,7 @@ impl Interpreter for VacuumDropTablesInterpreter {
                 },
             )
             .await?;
+        failed_tables.extend(shared_table_ids);
 
         let failed_db_ids = failed_tables
             .iter()
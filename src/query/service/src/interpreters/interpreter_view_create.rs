@@ -119,6 +119,7 @@ impl Interpreter for CreateViewInterpreter {
                 ..Default::default()
             },
             as_dropped: false,
+            clone_pin: None,
         };
         catalog.create_table(plan).await?;
 
@@ -63,6 +63,7 @@ mod interpreter_notification_alter;
 mod interpreter_notification_create;
 mod interpreter_notification_desc;
 mod interpreter_notification_drop;
+mod interpreter_notification_send;
 mod interpreter_optimize_compact_block;
 mod interpreter_optimize_compact_segment;
 mod interpreter_optimize_purge;
@@ -84,6 +85,9 @@ mod interpreter_role_revoke;
 mod interpreter_role_set;
 mod interpreter_role_set_secondary;
 mod interpreter_role_show;
+mod interpreter_row_access_policy_create;
+mod interpreter_row_access_policy_desc;
+mod interpreter_row_access_policy_drop;
 mod interpreter_select;
 mod interpreter_sequence_create;
 mod interpreter_sequence_drop;
@@ -105,9 +109,13 @@ mod interpreter_table_index_refresh;
 mod interpreter_table_modify_column;
 mod interpreter_table_modify_comment;
 mod interpreter_table_recluster;
+mod interpreter_table_ref_create;
+mod interpreter_table_ref_drop;
 mod interpreter_table_rename;
 mod interpreter_table_rename_column;
 mod interpreter_table_revert;
+mod interpreter_table_row_access_policy_add;
+mod interpreter_table_row_access_policy_drop;
 mod interpreter_table_set_options;
 mod interpreter_table_show_create;
 mod interpreter_table_truncate;
@@ -195,6 +203,9 @@ pub use interpreter_role_grant::GrantRoleInterpreter;
 pub use interpreter_role_revoke::RevokeRoleInterpreter;
 pub use interpreter_role_set::SetRoleInterpreter;
 pub use interpreter_role_set_secondary::SetSecondaryRolesInterpreter;
+pub use interpreter_row_access_policy_create::CreateRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_desc::DescRowAccessPolicyInterpreter;
+pub use interpreter_row_access_policy_drop::DropRowAccessPolicyInterpreter;
 pub use interpreter_select::SelectInterpreter;
 pub use interpreter_sequence_create::CreateSequenceInterpreter;
 pub use interpreter_sequence_drop::DropSequenceInterpreter;
@@ -216,8 +227,12 @@ pub use interpreter_table_index_refresh::RefreshTableIndexInterpreter;
 pub use interpreter_table_modify_column::ModifyTableColumnInterpreter;
 pub use interpreter_table_modify_comment::ModifyTableCommentInterpreter;
 pub use interpreter_table_recluster::ReclusterTableInterpreter;
+pub use interpreter_table_ref_create::CreateTableRefInterpreter;
+pub use interpreter_table_ref_drop::DropTableRefInterpreter;
 pub use interpreter_table_rename::RenameTableInterpreter;
 pub use interpreter_table_rename_column::RenameTableColumnInterpreter;
+pub use interpreter_table_row_access_policy_add::AddTableRowAccessPolicyInterpreter;
+pub use interpreter_table_row_access_policy_drop::DropTableRowAccessPolicyInterpreter;
 pub use interpreter_table_show_create::ShowCreateQuerySettings;
 pub use interpreter_table_show_create::ShowCreateTableInterpreter;
 pub use interpreter_table_truncate::TruncateTableInterpreter;
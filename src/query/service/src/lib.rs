@@ -43,12 +43,14 @@
 extern crate core;
 
 pub mod auth;
+pub mod cache_warm_up;
 pub mod catalogs;
 pub mod clusters;
 pub mod databases;
 pub mod interpreters;
 pub mod local;
 pub mod locks;
+pub mod notification;
 pub mod pipelines;
 pub mod schedulers;
 pub mod servers;
@@ -56,6 +58,7 @@ pub mod sessions;
 pub mod spillers;
 pub mod stream;
 pub mod table_functions;
+pub mod task;
 pub mod test_kits;
 
 mod builtin;
@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use aes_gcm::aead::Aead;
+use aes_gcm::aead::KeyInit;
+use aes_gcm::Aes256Gcm;
+use aes_gcm::Nonce;
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
+use databend_common_config::GlobalConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::WebhookOptions;
+use rand::RngCore;
+use sha2::Digest;
+use sha2::Sha256;
+
+const NONCE_LEN: usize = 12;
+
+/// Encrypts the secrets of a webhook, i.e. the authorization header and the signing secret,
+/// before the webhook is stored in the meta-service.
+///
+/// The secrets are encrypted with AES-256-GCM, using the `notification_secret_key`
+/// of the query config as the key.
+pub fn encrypt_webhook_secrets(webhook: &mut WebhookOptions) -> Result<()> {
+    if webhook.authorization_header.is_none() && webhook.signing_secret.is_none() {
+        return Ok(());
+    }
+    let cipher = secret_cipher()?;
+    for secret in [
+        &mut webhook.authorization_header,
+        &mut webhook.signing_secret,
+    ] {
+        if let Some(plain) = secret {
+            *plain = encrypt_secret(&cipher, plain)?;
+        }
+    }
+    Ok(())
+}
+
+/// Decrypts the secrets of a webhook read from the meta-service.
+pub fn decrypt_webhook_secrets(webhook: &mut WebhookOptions) -> Result<()> {
+    if webhook.authorization_header.is_none() && webhook.signing_secret.is_none() {
+        return Ok(());
+    }
+    let cipher = secret_cipher()?;
+    for secret in [
+        &mut webhook.authorization_header,
+        &mut webhook.signing_secret,
+    ] {
+        if let Some(encrypted) = secret {
+            *encrypted = decrypt_secret(&cipher, encrypted)?;
+        }
+    }
+    Ok(())
+}
+
+fn secret_cipher() -> Result<Aes256Gcm> {
+    let key = &GlobalConfig::instance().query.notification_secret_key;
+    if key.is_empty() {
+        return Err(ErrorCode::InvalidConfig(
+            "notification_secret_key is required to store the secrets of notifications, please set it in the query config",
+        ));
+    }
+    Ok(Aes256Gcm::new(&Sha256::digest(key.as_bytes())))
+}
+
+// Returns base64 of `nonce || ciphertext`.
+fn encrypt_secret(cipher: &Aes256Gcm, plain: &str) -> Result<String> {
+    let mut nonce = [0u8; NONCE_LEN];
+    rand::thread_rng().fill_bytes(&mut nonce);
+    let encrypted = cipher
+        .encrypt(Nonce::from_slice(&nonce), plain.as_bytes())
+        .map_err(|e| ErrorCode::Internal(format!("failed to encrypt secret: {}", e)))?;
+
+    let mut data = nonce.to_vec();
+    data.extend(encrypted);
+    Ok(BASE64_STANDARD.encode(data))
+}
+
+fn decrypt_secret(cipher: &Aes256Gcm, encrypted: &str) -> Result<String> {
+    let invalid = || {
+        ErrorCode::InvalidConfig(
+            "failed to decrypt the secret of notification, notification_secret_key may have been changed",
+        )
+    };
+    let data = BASE64_STANDARD.decode(encrypted).map_err(|_| invalid())?;
+    if data.len() < NONCE_LEN {
+        return Err(invalid());
+    }
+    let (nonce, encrypted) = data.split_at(NONCE_LEN);
+    let plain = cipher
+        .decrypt(Nonce::from_slice(nonce), encrypted)
+        .map_err(|_| invalid())?;
+    String::from_utf8(plain).map_err(|_| invalid())
+}
@@ -0,0 +1,223 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use chrono::Utc;
+use databend_common_base::base::tokio::time::sleep;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::NotificationMgr;
+use databend_common_meta_app::principal::Notification;
+use databend_common_meta_app::principal::NotificationHistory;
+use databend_common_meta_app::principal::NotificationStatus;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_users::UserApiProvider;
+use hmac::Hmac;
+use hmac::Mac;
+use log::warn;
+use reqwest::header::AUTHORIZATION;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::Method;
+use reqwest::StatusCode;
+use sha2::Sha256;
+
+use crate::notification::decrypt_webhook_secrets;
+
+/// Header carrying the unix timestamp (in seconds) the message was signed at.
+pub const SIGNATURE_TIMESTAMP_HEADER: &str = "X-Databend-Timestamp";
+
+/// Header carrying the `sha256=<hex>` HMAC signature of the message.
+pub const SIGNATURE_HEADER: &str = "X-Databend-Signature";
+
+/// Max number of delivery attempts of a single message.
+const MAX_ATTEMPTS: u32 = 3;
+
+/// Delay before the first retry, doubled for every following retry.
+const INITIAL_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Notification histories are kept in meta-service for 7 days.
+const NOTIFICATION_HISTORY_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Returns the signature of a webhook message, `sha256=<hex>`.
+///
+/// The signed payload is `{timestamp}.{body}`, so that a receiver can reject replayed messages.
+pub fn sign_message(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC can take key of any size");
+    mac.update(format!("{}.{}", timestamp, body).as_bytes());
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Delivers messages through the webhook notification integrations stored in the meta-service.
+///
+/// This is used when the query node is not connected to cloud control.
+/// Every delivery is recorded in the notification history.
+pub struct NotificationSender {
+    tenant: Tenant,
+    client: reqwest::Client,
+}
+
+impl NotificationSender {
+    pub fn create(tenant: &Tenant) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| ErrorCode::Internal(format!("failed to build http client: {}", e)))?;
+        Ok(Self {
+            tenant: tenant.clone(),
+            client,
+        })
+    }
+
+    fn notification_api(&self) -> NotificationMgr {
+        UserApiProvider::instance().notification_api(&self.tenant)
+    }
+
+    /// Send `message` through the notification integration `name`, retrying on transient errors.
+    ///
+    /// `message_source` tells what sent the message, it is recorded in the notification history.
+    #[async_backtrace::framed]
+    pub async fn send(&self, name: &str, message_source: &str, message: &str) -> Result<()> {
+        let notification_api = self.notification_api();
+        let Some(seq_v) = notification_api.get_notification(name).await? else {
+            return Err(ErrorCode::UnknownNotification(format!(
+                "Unknown notification '{}'",
+                name
+            )));
+        };
+        let mut notification = seq_v.data;
+        if !notification.enabled {
+            return Err(NotificationMgr::illegal_notification(
+                name,
+                "notification is disabled",
+            ));
+        }
+
+        let mut history = NotificationHistory {
+            history_id: 0,
+            created_at: Utc::now(),
+            processed_at: None,
+            message_source: message_source.to_string(),
+            name: name.to_string(),
+            message: message.to_string(),
+            status: NotificationStatus::Success,
+            attempts: 0,
+            error_message: "".to_string(),
+        };
+
+        let res = match decrypt_webhook_secrets(&mut notification.webhook) {
+            Ok(()) => {
+                self.deliver(&notification, message, &mut history.attempts)
+                    .await
+            }
+            Err(cause) => Err(cause),
+        };
+        history.processed_at = Some(Utc::now());
+        if let Err(cause) = &res {
+            history.status = NotificationStatus::Failed;
+            history.error_message = cause.message();
+        }
+        notification_api
+            .add_history(history, NOTIFICATION_HISTORY_TTL)
+            .await?;
+        res
+    }
+
+    #[async_backtrace::framed]
+    async fn deliver(
+        &self,
+        notification: &Notification,
+        message: &str,
+        attempts: &mut u32,
+    ) -> Result<()> {
+        let mut delay = INITIAL_RETRY_DELAY;
+        loop {
+            *attempts += 1;
+            match self.post(notification, message).await {
+                Ok(()) => return Ok(()),
+                Err((cause, retryable)) => {
+                    if !retryable || *attempts >= MAX_ATTEMPTS {
+                        return Err(cause);
+                    }
+                    warn!(
+                        "failed to deliver notification {}, attempt {}: {}, retry in {:?}",
+                        notification.name, attempts, cause, delay
+                    );
+                    sleep(delay).await;
+                    delay *= 2;
+                }
+            }
+        }
+    }
+
+    /// Make a single delivery attempt. On error, also returns whether it is worth retrying.
+    #[async_backtrace::framed]
+    async fn post(
+        &self,
+        notification: &Notification,
+        message: &str,
+    ) -> std::result::Result<(), (ErrorCode, bool)> {
+        let webhook = &notification.webhook;
+        let method = webhook.method.as_deref().unwrap_or("POST");
+        let method = Method::from_bytes(method.to_uppercase().as_bytes()).map_err(|_| {
+            let cause = NotificationMgr::illegal_notification(
+                &notification.name,
+                format!("invalid webhook method '{}'", method),
+            );
+            (cause, false)
+        })?;
+
+        let content_type = match serde_json::from_str::<serde_json::Value>(message) {
+            Ok(_) => "application/json",
+            Err(_) => "text/plain; charset=utf-8",
+        };
+        let mut request = self
+            .client
+            .request(method, &webhook.url)
+            .header(CONTENT_TYPE, content_type)
+            .body(message.to_string());
+        if let Some(authorization) = &webhook.authorization_header {
+            request = request.header(AUTHORIZATION, authorization);
+        }
+        if let Some(secret) = &webhook.signing_secret {
+            let timestamp = Utc::now().timestamp();
+            request = request
+                .header(SIGNATURE_TIMESTAMP_HEADER, timestamp.to_string())
+                .header(SIGNATURE_HEADER, sign_message(secret, timestamp, message));
+        }
+
+        let response = request.send().await.map_err(|e| {
+            let cause = ErrorCode::Internal(format!(
+                "failed to send notification {} to {}: {}",
+                notification.name, webhook.url, e
+            ));
+            (cause, true)
+        })?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        let retryable = status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS;
+        let body = response.text().await.unwrap_or_default();
+        let cause = ErrorCode::Internal(format!(
+            "webhook of notification {} responded with {}: {}",
+            notification.name, status, body
+        ));
+        Err((cause, retryable))
+    }
+}
@@ -41,11 +41,17 @@ impl PipelineBuilder {
     pub(crate) fn build_table_scan(&mut self, scan: &TableScan) -> Result<()> {
         let table = self.ctx.build_table_from_source_plan(&scan.source)?;
         self.ctx.set_partitions(scan.source.parts.clone())?;
+
+        // Large scans bypass the table data caches, so they won't evict the hot data.
+        let max_scan_bytes = self
+            .settings
+            .get_table_data_cache_admission_max_scan_bytes()?;
+        let put_cache = max_scan_bytes == 0 || scan.source.statistics.read_bytes <= max_scan_bytes;
         table.read_data(
             self.ctx.clone(),
             &scan.source,
             &mut self.main_pipeline,
-            true,
+            put_cache,
         )?;
 
         // Fill internal columns if needed.
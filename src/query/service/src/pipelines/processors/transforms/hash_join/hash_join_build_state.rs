@@ -892,8 +892,10 @@ impl HashJoinBuildState {
         build_key: &Expr,
         probe_key: &Expr<String>,
     ) -> Result<()> {
+        // ENUM keys are hashed by their codes, as they are in the hash table.
         if !build_key.data_type().remove_nullable().is_numeric()
             && !build_key.data_type().remove_nullable().is_string()
+            && !build_key.data_type().remove_nullable().is_enum()
         {
             return Ok(());
         }
@@ -16,10 +16,12 @@ mod ie_join_state;
 mod ie_join_util;
 mod merge_join_state;
 mod range_join_state;
+mod spatial_join_state;
 mod transform_range_join;
 
 pub(crate) use ie_join_state::IEJoinState;
 pub(crate) use ie_join_util::*;
 pub use range_join_state::RangeJoinState;
+pub(crate) use spatial_join_state::SpatialJoinState;
 pub use transform_range_join::TransformRangeJoinLeft;
 pub use transform_range_join::TransformRangeJoinRight;
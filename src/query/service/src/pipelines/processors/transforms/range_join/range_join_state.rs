@@ -36,6 +36,7 @@ use parking_lot::RwLock;
 
 use crate::pipelines::executor::WatchNotify;
 use crate::pipelines::processors::transforms::range_join::IEJoinState;
+use crate::pipelines::processors::transforms::range_join::SpatialJoinState;
 use crate::sessions::QueryContext;
 
 pub struct RangeJoinState {
@@ -61,6 +62,8 @@ pub struct RangeJoinState {
     pub(crate) finished_tasks: AtomicU64,
     // IEJoin state
     pub(crate) ie_join_state: Option<IEJoinState>,
+    // Spatial join state
+    pub(crate) spatial_join_state: Option<SpatialJoinState>,
 }
 
 impl RangeJoinState {
@@ -70,6 +73,11 @@ impl RangeJoinState {
         } else {
             None
         };
+        let spatial_join_state = if matches!(range_join.range_join_type, RangeJoinType::Spatial) {
+            Some(SpatialJoinState::default())
+        } else {
+            None
+        };
 
         Self {
             ctx,
@@ -88,6 +96,7 @@ impl RangeJoinState {
             row_offset: RwLock::new(vec![]),
             finished_tasks: AtomicU64::new(0),
             ie_join_state,
+            spatial_join_state,
         }
     }
 
@@ -172,6 +181,9 @@ impl RangeJoinState {
     }
 
     pub(crate) fn partition(&self) -> Result<()> {
+        if let Some(spatial_join_state) = &self.spatial_join_state {
+            return self.spatial_partition(spatial_join_state);
+        }
         let max_threads = self.ctx.get_settings().get_max_threads()? as usize;
         let left_table = self.left_table.read();
         // Right table is bigger than left table
@@ -0,0 +1,155 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::BlockRowIndex;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Evaluator;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::ScalarRef;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_io::BoundingBox;
+use parking_lot::RwLock;
+use rstar::primitives::GeomWithData;
+use rstar::primitives::Rectangle;
+use rstar::RTree;
+use rstar::AABB;
+
+use crate::pipelines::processors::transforms::range_join::filter_block;
+use crate::pipelines::processors::transforms::range_join::RangeJoinState;
+
+// Bounding box of a left table row, with its (block index, row index)
+type RowBoundingBox = GeomWithData<Rectangle<[f64; 2]>, (u32, u32)>;
+
+#[derive(Default)]
+pub struct SpatialJoinState {
+    // R-tree over the bounding boxes of the left table rows
+    rtree: RwLock<RTree<RowBoundingBox>>,
+}
+
+impl RangeJoinState {
+    pub(crate) fn spatial_partition(&self, spatial_join_state: &SpatialJoinState) -> Result<()> {
+        let left_table = self.left_table.read();
+        let right_table = self.right_table.read();
+
+        let mut entries = vec![];
+        for (block_idx, left_block) in left_table.iter().enumerate() {
+            let bounding_boxes = bounding_boxes(left_block, &self.conditions[0].left_expr)?;
+            for (row_idx, bbox) in bounding_boxes.into_iter().enumerate() {
+                if let Some(bbox) = bbox {
+                    entries.push(RowBoundingBox::new(
+                        Rectangle::from_corners([bbox.xmin, bbox.ymin], [bbox.xmax, bbox.ymax]),
+                        (block_idx as u32, row_idx as u32),
+                    ));
+                }
+            }
+        }
+        *spatial_join_state.rtree.write() = RTree::bulk_load(entries);
+
+        // Each task probes the R-tree with one right table block
+        let mut tasks = self.tasks.write();
+        for right_idx in 0..right_table.len() {
+            tasks.push((0, right_idx));
+        }
+        Ok(())
+    }
+
+    pub fn spatial_join(&self, task_id: usize) -> Result<Vec<DataBlock>> {
+        let block_size = self.ctx.get_settings().get_max_block_size()? as usize;
+        let tasks = self.tasks.read();
+        let (_, right_idx) = tasks[task_id];
+        let spatial_join_state = self.spatial_join_state.as_ref().unwrap();
+        let rtree = spatial_join_state.rtree.read();
+        let left_table = self.left_table.read();
+        let right_table = self.right_table.read();
+
+        let condition = &self.conditions[0];
+        let distance = condition.distance.unwrap_or(0.0);
+        let right_bounding_boxes = bounding_boxes(&right_table[right_idx], &condition.right_expr)?;
+
+        let mut result_blocks = vec![];
+        let mut left_buffer = Vec::with_capacity(block_size);
+        let mut right_buffer = Vec::with_capacity(block_size);
+        for (row_idx, bbox) in right_bounding_boxes.into_iter().enumerate() {
+            let Some(bbox) = bbox else {
+                continue;
+            };
+            let bbox = bbox.expand(distance);
+            let envelope = AABB::from_corners([bbox.xmin, bbox.ymin], [bbox.xmax, bbox.ymax]);
+            for entry in rtree.locate_in_envelope_intersecting(&envelope) {
+                let (left_block_idx, left_row_idx) = entry.data;
+                left_buffer.push((left_block_idx, left_row_idx, 1));
+                right_buffer.push((0, row_idx as u32, 1));
+            }
+            if left_buffer.len() >= block_size {
+                result_blocks.push(self.spatial_join_block(
+                    &left_table,
+                    &right_table[right_idx..right_idx + 1],
+                    &left_buffer,
+                    &right_buffer,
+                )?);
+                left_buffer.clear();
+                right_buffer.clear();
+            }
+        }
+        if !left_buffer.is_empty() {
+            result_blocks.push(self.spatial_join_block(
+                &left_table,
+                &right_table[right_idx..right_idx + 1],
+                &left_buffer,
+                &right_buffer,
+            )?);
+        }
+        Ok(result_blocks)
+    }
+
+    // Build the candidate pairs and keep those matching the exact conditions.
+    fn spatial_join_block(
+        &self,
+        left_table: &[DataBlock],
+        right_table: &[DataBlock],
+        left_indices: &[BlockRowIndex],
+        right_indices: &[BlockRowIndex],
+    ) -> Result<DataBlock> {
+        let mut result_block = DataBlock::take_blocks(left_table, left_indices, left_indices.len());
+        let right_result_block =
+            DataBlock::take_blocks(right_table, right_indices, right_indices.len());
+        for col in right_result_block.columns() {
+            result_block.add_column(col.clone());
+        }
+        for filter in self.other_conditions.iter() {
+            result_block = filter_block(result_block, filter)?;
+        }
+        Ok(result_block)
+    }
+}
+
+// Bounding box of each row, `None` for NULL or empty geometries.
+fn bounding_boxes(block: &DataBlock, expr: &RemoteExpr) -> Result<Vec<Option<BoundingBox>>> {
+    let func_ctx = FunctionContext::default();
+    let evaluator = Evaluator::new(block, &func_ctx, &BUILTIN_FUNCTIONS);
+    let expr = expr.as_expr(&BUILTIN_FUNCTIONS);
+    let column = evaluator
+        .run(&expr)?
+        .convert_to_full_column(expr.data_type(), block.num_rows());
+    column
+        .iter()
+        .map(|value| match value {
+            ScalarRef::Geometry(ewkb) => BoundingBox::from_ewkb(ewkb),
+            _ => Ok(None),
+        })
+        .collect()
+}
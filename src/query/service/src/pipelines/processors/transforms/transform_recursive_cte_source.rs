@@ -301,11 +301,13 @@ async fn create_memory_table_for_cte_scan(
                 engine: Engine::Memory,
                 engine_options: Default::default(),
                 storage_params: None,
+                cold_storage_params: None,
                 options: Default::default(),
                 field_comments: vec![],
                 cluster_key: None,
                 as_select: None,
                 inverted_indexes: None,
+                clone_source: None,
             };
             let create_table_interpreter =
                 CreateTableInterpreter::try_create(ctx.clone(), create_table_plan)?;
@@ -190,6 +190,12 @@ impl<'a, W: AsyncWrite + Send + Unpin> DFQueryResultWriter<'a, W> {
                 DataType::Variant => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
                 DataType::Geometry => Ok(ColumnType::MYSQL_TYPE_GEOMETRY),
                 DataType::Geography => Ok(ColumnType::MYSQL_TYPE_GEOMETRY),
+                DataType::Interval => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                DataType::TimestampTz => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                DataType::Time => Ok(ColumnType::MYSQL_TYPE_TIME),
+                DataType::Uuid => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                DataType::Enum(_) => Ok(ColumnType::MYSQL_TYPE_VARCHAR),
+                DataType::AggregateState(_) => Ok(ColumnType::MYSQL_TYPE_BLOB),
                 DataType::Decimal(_) => Ok(ColumnType::MYSQL_TYPE_DECIMAL),
                 _ => Err(ErrorCode::Unimplemented(format!(
                     "Unsupported column type:{:?}",
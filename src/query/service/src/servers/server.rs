@@ -32,6 +32,7 @@ use tokio_stream::wrappers::TcpListenerStream;
 
 use crate::clusters::ClusterDiscovery;
 use crate::sessions::SessionManager;
+use crate::task::TaskScheduler;
 
 pub type ListeningStream = Abortable<TcpListenerStream>;
 
@@ -71,6 +72,9 @@ impl ShutdownHandle {
     #[async_backtrace::framed]
     pub async fn shutdown(&mut self, mut signal: SignalStream, timeout: Option<Duration>) {
         self.shutdown_services(true).await;
+        if let Some(scheduler) = TaskScheduler::try_instance() {
+            scheduler.shutdown().await;
+        }
         ClusterDiscovery::instance()
             .unregister_to_metastore(&mut signal)
             .await;
@@ -25,6 +25,7 @@ use databend_common_storages_fuse::table_functions::FuseBlockFunc;
 use databend_common_storages_fuse::table_functions::FuseColumnFunc;
 use databend_common_storages_fuse::table_functions::FuseEncodingFunc;
 use databend_common_storages_fuse::table_functions::FuseStatisticsFunc;
+use databend_common_storages_fuse::table_functions::FuseTieringTable;
 use databend_common_storages_fuse::table_functions::FuseTimeTravelSizeFunc;
 use databend_common_storages_fuse::table_functions::FuseVacuumTemporaryTable;
 use databend_common_storages_fuse::table_functions::TableFunctionTemplate;
@@ -138,6 +139,14 @@ impl TableFunctionFactory {
             ),
         );
 
+        creators.insert(
+            "fuse_tiering".to_string(),
+            (
+                next_id(),
+                Arc::new(TableFunctionTemplate::<FuseTieringTable>::create),
+            ),
+        );
+
         creators.insert(
             "fuse_segment".to_string(),
             (
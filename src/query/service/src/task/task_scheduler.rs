@@ -0,0 +1,431 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_base::base::tokio::time::sleep;
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_common_management::TaskMgr;
+use databend_common_meta_app::principal::State;
+use databend_common_meta_app::principal::Status;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskRun;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::tenant::Tenant;
+use databend_common_meta_types::MatchSeq;
+use databend_common_users::UserApiProvider;
+use futures_util::TryStreamExt;
+use log::error;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+
+use crate::interpreters::interpreter_plan_sql;
+use crate::interpreters::InterpreterFactory;
+use crate::notification::NotificationSender;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+/// How often the scheduler checks for due tasks.
+const TICK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The lease must outlive a few ticks, so that a slow tick does not hand over the lease.
+const LEASE_TTL: Duration = Duration::from_secs(30);
+
+/// Task runs are kept in meta-service for 7 days.
+const TASK_RUN_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Runs scheduled tasks when the query node is not connected to cloud control.
+///
+/// Every node of a tenant runs the scheduler loop, but only the node holding the
+/// scheduler lease in meta-service triggers scheduled tasks. When the lease holder
+/// goes away, another node takes over after the lease expired.
+///
+/// A root task is triggered by its schedule. When it succeeds, the tasks declared
+/// `AFTER` it are run once all of their predecessors succeeded in the same graph run.
+pub struct TaskScheduler {
+    tenant: Tenant,
+    node_id: String,
+    /// Root task ids whose task graph is running on this node.
+    running: Mutex<HashSet<u64>>,
+    started: AtomicBool,
+    stopped: AtomicBool,
+}
+
+impl TaskScheduler {
+    pub fn init(conf: &InnerConfig) -> Result<()> {
+        let scheduler = TaskScheduler {
+            tenant: conf.query.tenant_id.clone(),
+            node_id: conf.query.node_id.clone(),
+            running: Mutex::new(HashSet::new()),
+            started: AtomicBool::new(false),
+            stopped: AtomicBool::new(false),
+        };
+        GlobalInstance::set(Arc::new(scheduler));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<TaskScheduler> {
+        GlobalInstance::get()
+    }
+
+    /// The scheduler is only registered when tasks are not run by cloud control.
+    pub fn try_instance() -> Option<Arc<TaskScheduler>> {
+        GlobalInstance::try_get()
+    }
+
+    /// Start the scheduler loop in background.
+    pub fn start(self: &Arc<Self>) {
+        self.started.store(true, Ordering::SeqCst);
+        let scheduler = self.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            info!("task scheduler started on node {}", scheduler.node_id);
+            while !scheduler.stopped.load(Ordering::SeqCst) {
+                if let Err(cause) = scheduler.tick().await {
+                    warn!("task scheduler tick failed: {:?}", cause);
+                }
+                sleep(TICK_INTERVAL).await;
+            }
+            info!("task scheduler stopped on node {}", scheduler.node_id);
+        });
+    }
+
+    /// Stop the scheduler loop and give up the scheduler lease,
+    /// so that another node takes over without waiting for the lease to expire.
+    #[async_backtrace::framed]
+    pub async fn shutdown(&self) {
+        if !self.started.load(Ordering::SeqCst) || self.stopped.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        if let Err(cause) = self.task_api().release_scheduler_lease(&self.node_id).await {
+            warn!("failed to release task scheduler lease: {:?}", cause);
+        }
+    }
+
+    /// Run a task and the tasks after it at once, as `EXECUTE TASK` does.
+    pub async fn execute_task(self: &Arc<Self>, task_name: &str) -> Result<()> {
+        let task = self
+            .task_api()
+            .get_task(task_name)
+            .await?
+            .ok_or_else(|| ErrorCode::UnknownTask(format!("Unknown task '{}'", task_name)))?;
+        self.spawn_task_graph(task.data, Utc::now());
+        Ok(())
+    }
+
+    fn task_api(&self) -> TaskMgr {
+        UserApiProvider::instance().task_api(&self.tenant)
+    }
+
+    #[async_backtrace::framed]
+    async fn tick(self: &Arc<Self>) -> Result<()> {
+        let task_api = self.task_api();
+        let tasks = task_api.list_tasks().await?;
+        if !tasks.iter().any(is_scheduled) {
+            return Ok(());
+        }
+
+        if self.stopped.load(Ordering::SeqCst)
+            || !task_api
+                .acquire_scheduler_lease(&self.node_id, LEASE_TTL)
+                .await?
+        {
+            return Ok(());
+        }
+
+        let now = Utc::now();
+        for task in tasks.iter().filter(|t| is_scheduled(t)) {
+            if task.next_scheduled_at.is_some_and(|t| t > now) {
+                continue;
+            }
+
+            // Re-read the task and move its next schedule time forward with a CAS,
+            // so that a task is never triggered twice for the same schedule time.
+            let Some(seq_v) = task_api.get_task(&task.task_name).await? else {
+                continue;
+            };
+            let mut task = seq_v.data;
+            let scheduled_at = task.next_scheduled_at;
+            task.next_scheduled_at = task
+                .schedule_options
+                .as_ref()
+                .and_then(|s| s.next_after(now));
+            if let Err(cause) = task_api
+                .update_task(task.clone(), MatchSeq::Exact(seq_v.seq))
+                .await
+            {
+                warn!(
+                    "failed to update next schedule time of task {}: {:?}",
+                    task.task_name, cause
+                );
+                continue;
+            }
+
+            // A newly created task only gets its first schedule time.
+            if let Some(scheduled_at) = scheduled_at {
+                self.spawn_task_graph(task, scheduled_at);
+            }
+        }
+        Ok(())
+    }
+
+    fn spawn_task_graph(self: &Arc<Self>, root: Task, scheduled_at: DateTime<Utc>) {
+        if !self.running.lock().insert(root.task_id) {
+            info!(
+                "skip task {}, the previous run is still executing",
+                root.task_name
+            );
+            return;
+        }
+
+        let scheduler = self.clone();
+        GlobalIORuntime::instance().spawn(async move {
+            let root_task_id = root.task_id;
+            if let Err(cause) = scheduler.run_task_graph(root, scheduled_at).await {
+                error!("task graph {} failed: {:?}", root_task_id, cause);
+            }
+            scheduler.running.lock().remove(&root_task_id);
+        });
+    }
+
+    #[async_backtrace::framed]
+    async fn run_task_graph(&self, root: Task, scheduled_at: DateTime<Utc>) -> Result<()> {
+        let root_task_id = root.task_id;
+        if self.run_task(&root, root_task_id, scheduled_at).await? != State::Succeeded {
+            return Ok(());
+        }
+
+        let mut succeeded = HashSet::from([root.task_name.clone()]);
+        let mut visited = succeeded.clone();
+        loop {
+            // Re-list the tasks, a graph run may take long and the graph may be altered meanwhile.
+            let ready = self
+                .task_api()
+                .list_tasks()
+                .await?
+                .into_iter()
+                .filter(|t| !t.is_root() && t.status == Status::Started)
+                .filter(|t| !visited.contains(&t.task_name))
+                .filter(|t| t.after.iter().all(|p| succeeded.contains(p)))
+                .collect::<Vec<_>>();
+            if ready.is_empty() {
+                return Ok(());
+            }
+
+            for task in ready {
+                visited.insert(task.task_name.clone());
+                if self.run_task(&task, root_task_id, Utc::now()).await? == State::Succeeded {
+                    succeeded.insert(task.task_name.clone());
+                }
+            }
+        }
+    }
+
+    /// Run a single task, record the run in the task history and returns its final state.
+    #[async_backtrace::framed]
+    async fn run_task(
+        &self,
+        task: &Task,
+        root_task_id: u64,
+        scheduled_at: DateTime<Utc>,
+    ) -> Result<State> {
+        let task_api = self.task_api();
+        let mut run = TaskRun {
+            task: task.clone(),
+            run_id: task_api.next_run_id().await?,
+            attempt_number: 1,
+            state: State::Executing,
+            scheduled_at,
+            completed_at: None,
+            error_code: 0,
+            error_message: None,
+            root_task_id,
+            query_id: "".to_string(),
+        };
+        task_api.upsert_task_run(&run, TASK_RUN_TTL).await?;
+
+        let res = match self.create_context(task).await {
+            Ok(ctx) => {
+                run.query_id = ctx.get_id();
+                execute_task_sql(ctx, task).await
+            }
+            Err(cause) => Err(cause),
+        };
+
+        run.state = match res {
+            Ok(true) => State::Succeeded,
+            Ok(false) => State::Cancelled,
+            Err(cause) => {
+                warn!("task {} failed: {:?}", task.task_name, cause);
+                run.error_code = cause.code() as i64;
+                run.error_message = Some(cause.message());
+                State::Failed
+            }
+        };
+        run.completed_at = Some(Utc::now());
+        task_api.upsert_task_run(&run, TASK_RUN_TTL).await?;
+
+        self.update_failures(&task.task_name, run.state).await?;
+        if run.state == State::Failed {
+            self.notify_failure(&run).await;
+        }
+        Ok(run.state)
+    }
+
+    /// Send the failure of a task run to the `ERROR_INTEGRATION` of the task, if any.
+    #[async_backtrace::framed]
+    async fn notify_failure(&self, run: &TaskRun) {
+        let Some(integration) = &run.task.error_integration else {
+            return;
+        };
+
+        let message = serde_json::json!({
+            "version": "1.0",
+            "message_source": "task",
+            "task_name": run.task.task_name,
+            "task_id": run.task.task_id,
+            "run_id": run.run_id,
+            "root_task_id": run.root_task_id,
+            "query_id": run.query_id,
+            "scheduled_at": run.scheduled_at.to_rfc3339(),
+            "error_code": run.error_code,
+            "error_message": run.error_message,
+        });
+        let res = match NotificationSender::create(&self.tenant) {
+            Ok(sender) => sender.send(integration, "task", &message.to_string()).await,
+            Err(cause) => Err(cause),
+        };
+        if let Err(cause) = res {
+            error!(
+                "failed to notify {} about failed task {}: {:?}",
+                integration, run.task.task_name, cause
+            );
+        }
+    }
+
+    /// Track consecutive failures, and suspend the task once it failed
+    /// `SUSPEND_TASK_AFTER_NUM_FAILURES` times in a row.
+    #[async_backtrace::framed]
+    async fn update_failures(&self, task_name: &str, state: State) -> Result<()> {
+        let task_api = self.task_api();
+        let Some(seq_v) = task_api.get_task(task_name).await? else {
+            return Ok(());
+        };
+
+        let mut task = seq_v.data;
+        match state {
+            State::Succeeded if task.consecutive_failures != 0 => {
+                task.consecutive_failures = 0;
+            }
+            State::Failed => {
+                task.consecutive_failures += 1;
+                if task
+                    .suspend_task_after_num_failures
+                    .is_some_and(|n| n > 0 && task.consecutive_failures >= n)
+                {
+                    info!(
+                        "suspend task {} after {} consecutive failures",
+                        task.task_name, task.consecutive_failures
+                    );
+                    task.status = Status::Suspended;
+                    task.last_suspended_at = Some(Utc::now());
+                }
+            }
+            _ => return Ok(()),
+        }
+
+        task_api
+            .update_task(task, MatchSeq::Exact(seq_v.seq))
+            .await?;
+        Ok(())
+    }
+
+    /// Create a query context that runs as the owner role of the task.
+    #[async_backtrace::framed]
+    async fn create_context(&self, task: &Task) -> Result<Arc<QueryContext>> {
+        let session_manager = SessionManager::instance();
+        let session = session_manager.create_session(SessionType::Dummy).await?;
+        let session = session_manager.register_session(session)?;
+
+        let user = UserInfo::new_no_auth(
+            &format!("{}-task-scheduler", self.tenant.tenant_name()),
+            "0.0.0.0",
+        );
+        session
+            .set_authed_user(user, Some(task.owner.clone()))
+            .await?;
+
+        let settings = session.get_settings();
+        for (k, v) in &task.session_params {
+            settings.set_setting(k.clone(), v.clone())?;
+        }
+
+        session.create_query_context().await
+    }
+}
+
+fn is_scheduled(task: &Task) -> bool {
+    task.is_root() && task.status == Status::Started && task.schedule_options.is_some()
+}
+
+/// Run the SQL of a task, returns `false` if it is skipped because its `WHEN` condition is false.
+#[async_backtrace::framed]
+async fn execute_task_sql(ctx: Arc<QueryContext>, task: &Task) -> Result<bool> {
+    if let Some(condition) = &task.when_condition {
+        let blocks = execute_sql(ctx.clone(), &format!("SELECT {}", condition)).await?;
+        let satisfied = blocks.iter().find(|b| b.num_rows() > 0).is_some_and(|b| {
+            matches!(
+                b.get_by_offset(0).value.index(0),
+                Some(ScalarRef::Boolean(true))
+            )
+        });
+        if !satisfied {
+            return Ok(false);
+        }
+    }
+
+    // A script block is stored as `BEGIN ... END;`.
+    let sql = if task.query_text.trim_start().starts_with("BEGIN") {
+        format!("EXECUTE IMMEDIATE $$\n{}\n$$", task.query_text)
+    } else {
+        task.query_text.clone()
+    };
+    execute_sql(ctx, &sql).await?;
+    Ok(true)
+}
+
+#[async_backtrace::framed]
+async fn execute_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<Vec<DataBlock>> {
+    // Use interpreter_plan_sql, so that the query log is written if an error occurs.
+    let (plan, _, _) = interpreter_plan_sql(ctx.clone(), sql, true).await?;
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let stream = interpreter.execute(ctx).await?;
+    stream.try_collect().await
+}
@@ -107,6 +107,11 @@ ADDRESS = 'https://databend.com';"
         self
     }
 
+    pub fn notification_secret_key(mut self, value: impl Into<String>) -> ConfigBuilder {
+        self.conf.query.notification_secret_key = value.into();
+        self
+    }
+
     pub fn add_user(mut self, _user_name: &str, user: UserConfig) -> ConfigBuilder {
         let users = vec![user];
         self.conf.query.builtin = BuiltInConfig {
@@ -337,6 +337,7 @@ impl TestFixture {
             engine: Engine::Fuse,
             engine_options: Default::default(),
             storage_params: None,
+            cold_storage_params: None,
             options: [
                 // database id is required for FUSE
                 (OPT_KEY_DATABASE_ID.to_owned(), "1".to_owned()),
@@ -346,6 +347,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: Some("(id)".to_string()),
             inverted_indexes: None,
+            clone_source: None,
         }
     }
 
@@ -361,6 +363,7 @@ impl TestFixture {
             engine: Engine::Fuse,
             engine_options: Default::default(),
             storage_params: None,
+            cold_storage_params: None,
             options: [
                 // database id is required for FUSE
                 (OPT_KEY_DATABASE_ID.to_owned(), "1".to_owned()),
@@ -370,6 +373,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: None,
             inverted_indexes: None,
+            clone_source: None,
         }
     }
 
@@ -396,6 +400,7 @@ impl TestFixture {
             engine: Engine::Fuse,
             engine_options: Default::default(),
             storage_params: None,
+            cold_storage_params: None,
             options: [
                 // database id is required for FUSE
                 (OPT_KEY_DATABASE_ID.to_owned(), "1".to_owned()),
@@ -405,6 +410,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: None,
             inverted_indexes: None,
+            clone_source: None,
         }
     }
 
@@ -431,6 +437,7 @@ impl TestFixture {
             engine: Engine::Fuse,
             engine_options: Default::default(),
             storage_params: None,
+            cold_storage_params: None,
             options: [
                 // database id is required for FUSE
                 (OPT_KEY_DATABASE_ID.to_owned(), "1".to_owned()),
@@ -440,6 +447,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: None,
             inverted_indexes: None,
+            clone_source: None,
         }
     }
 
@@ -475,6 +483,7 @@ impl TestFixture {
             engine: Engine::Fuse,
             engine_options: Default::default(),
             storage_params: None,
+            cold_storage_params: None,
             options: [
                 // database id is required for FUSE
                 (OPT_KEY_DATABASE_ID.to_owned(), "1".to_owned()),
@@ -484,6 +493,7 @@ impl TestFixture {
             as_select: None,
             cluster_key: None,
             inverted_indexes: None,
+            clone_source: None,
         }
     }
 
@@ -167,6 +167,7 @@ async fn test_catalogs_table() -> Result<()> {
                 ..TableMeta::default()
             },
             as_dropped: false,
+            clone_pin: None,
         };
         let res = catalog.create_table(req.clone()).await;
         assert!(res.is_ok());
@@ -28,6 +28,7 @@ mod databases;
 mod distributed;
 mod frame;
 mod metrics;
+mod notification;
 mod parquet_rs;
 mod pipelines;
 mod servers;
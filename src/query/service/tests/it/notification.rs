@@ -0,0 +1,246 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::Utc;
+use databend_common_base::base::tokio;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::Notification;
+use databend_common_meta_app::principal::NotificationStatus;
+use databend_common_meta_app::principal::WebhookOptions;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_users::UserApiProvider;
+use databend_query::notification::decrypt_webhook_secrets;
+use databend_query::notification::encrypt_webhook_secrets;
+use databend_query::notification::sign_message;
+use databend_query::notification::NotificationSender;
+use databend_query::test_kits::*;
+use wiremock::matchers::header;
+use wiremock::matchers::header_exists;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+
+async fn setup_fixture() -> Result<TestFixture> {
+    let config = ConfigBuilder::create()
+        .notification_secret_key("test_key")
+        .config();
+    TestFixture::setup_with_config(&config).await
+}
+
+fn webhook_notification(
+    name: &str,
+    url: String,
+    signing_secret: Option<&str>,
+) -> Result<Notification> {
+    let mut webhook = WebhookOptions {
+        url,
+        method: None,
+        authorization_header: Some("Bearer token".to_string()),
+        signing_secret: signing_secret.map(|s| s.to_string()),
+    };
+    encrypt_webhook_secrets(&mut webhook)?;
+    Ok(Notification {
+        notification_id: 0,
+        name: name.to_string(),
+        enabled: true,
+        webhook,
+        comments: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    })
+}
+
+#[test]
+fn test_sign_message() {
+    // echo -n '1700000000.{"a":1}' | openssl dgst -sha256 -hmac secret
+    assert_eq!(
+        "sha256=49f24e537407743fa4a0242bb63b94b9a47ee99cbbe071ccd8a22550ae411686",
+        sign_message("secret", 1700000000, r#"{"a":1}"#)
+    );
+    assert_ne!(
+        sign_message("secret", 1700000000, "msg"),
+        sign_message("secret", 1700000001, "msg")
+    );
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_send_notification_retry() -> Result<()> {
+    let fixture = setup_fixture().await?;
+    let tenant = fixture.default_tenant();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .and(header("authorization", "Bearer token"))
+        .and(header("content-type", "application/json"))
+        .and(header_exists("x-databend-timestamp"))
+        .and(header_exists("x-databend-signature"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let notification_api = UserApiProvider::instance().notification_api(&tenant);
+    let url = format!("{}/hook", mock_server.uri());
+    notification_api
+        .create_notification(
+            webhook_notification("hook", url, Some("secret"))?,
+            &CreateOption::Create,
+        )
+        .await?;
+
+    let sender = NotificationSender::create(&tenant)?;
+    let message = r#"{"text":"hello"}"#;
+    sender.send("hook", "test", message).await?;
+
+    let requests = mock_server.received_requests().await.unwrap();
+    let last = requests.last().unwrap();
+    let timestamp = last.headers["x-databend-timestamp"].to_str().unwrap();
+    assert_eq!(
+        sign_message("secret", timestamp.parse().unwrap(), message),
+        last.headers["x-databend-signature"].to_str().unwrap()
+    );
+
+    let histories = notification_api.list_histories(Some("hook")).await?;
+    assert_eq!(1, histories.len());
+    assert_eq!(NotificationStatus::Success, histories[0].status);
+    assert_eq!(2, histories[0].attempts);
+    assert_eq!("test", histories[0].message_source);
+    assert_eq!(message, histories[0].message);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_send_notification_failure() -> Result<()> {
+    let fixture = setup_fixture().await?;
+    let tenant = fixture.default_tenant();
+
+    // A client error is not retried.
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .respond_with(ResponseTemplate::new(400))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let notification_api = UserApiProvider::instance().notification_api(&tenant);
+    let url = format!("{}/hook", mock_server.uri());
+    notification_api
+        .create_notification(
+            webhook_notification("hook", url, None)?,
+            &CreateOption::Create,
+        )
+        .await?;
+
+    let sender = NotificationSender::create(&tenant)?;
+    assert!(sender.send("hook", "test", "hello").await.is_err());
+
+    let histories = notification_api.list_histories(Some("hook")).await?;
+    assert_eq!(1, histories.len());
+    assert_eq!(NotificationStatus::Failed, histories[0].status);
+    assert_eq!(1, histories[0].attempts);
+    assert!(histories[0].error_message.contains("400"));
+
+    let res = sender.send("unknown", "test", "hello").await;
+    assert_eq!(ErrorCode::UNKNOWN_NOTIFICATION, res.unwrap_err().code());
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_call_send_notification() -> Result<()> {
+    let fixture = setup_fixture().await?;
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/hook"))
+        .and(header("authorization", "Bearer token"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let url = format!("{}/hook", mock_server.uri());
+    fixture
+        .execute_command(&format!(
+            "CREATE NOTIFICATION INTEGRATION hook TYPE = webhook ENABLED = true \
+             WEBHOOK = (url = '{url}', authorization_header = 'Bearer token')"
+        ))
+        .await?;
+    fixture
+        .execute_command("CALL system$send_notification('hook', 'hello')")
+        .await?;
+
+    // sending a notification is not a table function that a query could scan
+    let res = fixture
+        .execute_command("SELECT * FROM send_notification('hook', 'hello')")
+        .await;
+    assert!(res.is_err());
+
+    let histories = UserApiProvider::instance()
+        .notification_api(&fixture.default_tenant())
+        .list_histories(Some("hook"))
+        .await?;
+    assert_eq!(1, histories.len());
+    assert_eq!(NotificationStatus::Success, histories[0].status);
+    assert_eq!("send_notification", histories[0].message_source);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_notification_secrets_encrypted() -> Result<()> {
+    let _fixture = setup_fixture().await?;
+
+    let mut webhook = WebhookOptions {
+        url: "http://localhost/hook".to_string(),
+        method: None,
+        authorization_header: Some("Bearer token".to_string()),
+        signing_secret: None,
+    };
+    encrypt_webhook_secrets(&mut webhook)?;
+    let encrypted = webhook.authorization_header.clone().unwrap();
+    assert!(!encrypted.contains("token"));
+    assert_eq!(None, webhook.signing_secret);
+
+    // the same secret is encrypted with a different nonce every time
+    let mut other = webhook.clone();
+    other.authorization_header = Some("Bearer token".to_string());
+    encrypt_webhook_secrets(&mut other)?;
+    assert_ne!(Some(encrypted), other.authorization_header);
+
+    decrypt_webhook_secrets(&mut webhook)?;
+    assert_eq!(
+        Some("Bearer token".to_string()),
+        webhook.authorization_header
+    );
+
+    let mut tampered = other.clone();
+    tampered.authorization_header = Some("AAAAAAAAAAAAAAAAAAAAAAAA".to_string());
+    assert!(decrypt_webhook_secrets(&mut tampered).is_err());
+
+    Ok(())
+}
@@ -74,6 +74,7 @@ fn create_table_plan(fixture: &TestFixture, format: &str) -> CreateTablePlan {
         engine: Engine::Fuse,
         engine_options: Default::default(),
         storage_params: None,
+        cold_storage_params: None,
         options: [
             // database id is required for FUSE
             (OPT_KEY_DATABASE_ID.to_owned(), "1".to_owned()),
@@ -84,6 +85,7 @@ fn create_table_plan(fixture: &TestFixture, format: &str) -> CreateTablePlan {
         as_select: None,
         cluster_key: None,
         inverted_indexes: None,
+        clone_source: None,
     }
 }
 
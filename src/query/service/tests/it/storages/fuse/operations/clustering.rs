@@ -48,6 +48,7 @@ async fn test_fuse_alter_table_cluster_key() -> databend_common_exception::Resul
         engine: Engine::Fuse,
         engine_options: Default::default(),
         storage_params: None,
+        cold_storage_params: None,
         options: [
             // database id is required for FUSE
             (OPT_KEY_DATABASE_ID.to_owned(), "1".to_owned()),
@@ -57,6 +58,7 @@ async fn test_fuse_alter_table_cluster_key() -> databend_common_exception::Resul
         as_select: None,
         cluster_key: None,
         inverted_indexes: None,
+        clone_source: None,
     };
 
     // create test table
@@ -25,6 +25,7 @@ use databend_query::test_kits::*;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::Versioned;
+use opendal::Operator;
 use uuid::Uuid;
 
 use crate::storages::fuse::operations::mutation::compact_segment;
@@ -355,3 +356,61 @@ async fn test_fuse_purge_older_version() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_purge_cold_tier_blocks() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+    let db = fixture.default_db_name();
+    let cold_dir = tempfile::tempdir()?;
+
+    let qry = format!(
+        "create table {db}.t(a int) cluster by (a) tiering_cold_location = 'fs://{}/' tiering_cluster_key_max = '100'",
+        cold_dir.path().display()
+    );
+    fixture.execute_command(&qry).await?;
+    fixture
+        .execute_command(&format!("insert into {db}.t values (1), (2)"))
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let table_ctx: Arc<dyn TableContext> = ctx.clone();
+    let table = ctx
+        .get_table(fixture.default_catalog_name().as_str(), &db, "t")
+        .await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let stats = fuse_table.do_tiering(&table_ctx).await?;
+    assert_eq!(stats.blocks, 1);
+
+    let cold_operator = fuse_table.get_cold_operator().unwrap();
+    assert_eq!(count_files(&cold_operator).await?, 1);
+
+    // the moved block is no longer referenced by the last snapshot
+    fixture
+        .execute_command(&format!("truncate table {db}.t"))
+        .await?;
+
+    let ctx = fixture.new_query_ctx().await?;
+    let table_ctx: Arc<dyn TableContext> = ctx.clone();
+    let table = ctx
+        .get_table(fixture.default_catalog_name().as_str(), &db, "t")
+        .await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let snapshot_files = fuse_table.list_snapshot_files().await?;
+    fuse_table
+        .do_purge(&table_ctx, snapshot_files, None, true, false)
+        .await?;
+
+    assert_eq!(
+        count_files(&cold_operator).await?,
+        0,
+        "do_gc: blocks in the cold storage should be purged"
+    );
+
+    Ok(())
+}
+
+async fn count_files(operator: &Operator) -> Result<usize> {
+    let entries = operator.list_with("/").recursive(true).await?;
+    Ok(entries.iter().filter(|e| e.metadata().is_file()).count())
+}
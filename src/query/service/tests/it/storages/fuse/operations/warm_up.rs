@@ -0,0 +1,47 @@
+//  Copyright 2021 Datafuse Labs.
+//
+//  Licensed under the Apache License, Version 2.0 (the "License");
+//  you may not use this file except in compliance with the License.
+//  You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+//  Unless required by applicable law or agreed to in writing, software
+//  distributed under the License is distributed on an "AS IS" BASIS,
+//  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+//  See the License for the specific language governing permissions and
+//  limitations under the License.
+
+use std::collections::BTreeSet;
+
+use databend_common_base::base::tokio;
+use databend_common_catalog::table::Table;
+use databend_common_exception::Result;
+use databend_common_storages_fuse::FuseTable;
+use databend_query::test_kits::*;
+
+use crate::storages::fuse::utils::do_insertions;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_fuse_warm_up_caches() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    fixture.create_default_database().await?;
+
+    // 1 segment and 1 block left after the insert overwrite
+    do_insertions(&fixture).await?;
+
+    let table = fixture.latest_default_table().await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let column_ids = table
+        .schema()
+        .to_leaf_column_ids()
+        .into_iter()
+        .collect::<BTreeSet<_>>();
+
+    let stats = fuse_table.warm_up_caches(&column_ids, 0, 2).await?;
+    assert_eq!(stats.segments, 1);
+    assert_eq!(stats.blocks, 1);
+    assert_eq!(stats.column_data_bytes, 0);
+
+    Ok(())
+}
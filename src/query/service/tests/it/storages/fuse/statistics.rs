@@ -18,6 +18,7 @@ use std::sync::Arc;
 use chrono::Utc;
 use databend_common_base::base::tokio;
 use databend_common_expression::type_check::check;
+use databend_common_expression::types::geometry::GeometryType;
 use databend_common_expression::types::number::Int32Type;
 use databend_common_expression::types::number::NumberScalar;
 use databend_common_expression::types::DataType;
@@ -37,6 +38,7 @@ use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_functions::aggregates::eval_aggr;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_io::parse_to_ewkb;
 use databend_common_sql::evaluator::BlockOperator;
 use databend_common_storages_fuse::statistics::reducers::reduce_block_metas;
 use databend_common_storages_fuse::statistics::Trim;
@@ -141,6 +143,68 @@ fn test_ft_tuple_stats_block_stats() -> databend_common_exception::Result<()> {
     Ok(())
 }
 
+#[test]
+fn test_ft_geometry_stats_bounding_box() -> databend_common_exception::Result<()> {
+    let schema = Arc::new(TableSchema::new(vec![TableField::new(
+        "a",
+        TableDataType::Nullable(Box::new(TableDataType::Geometry)),
+    )]));
+
+    let blocks = [
+        vec![Some("POINT(1 2)"), None, Some("LINESTRING(0 0,3 1)")],
+        vec![None],
+        vec![Some("POINT(-1 5)")],
+    ]
+    .into_iter()
+    .map(|geometries| {
+        let geometries = geometries
+            .into_iter()
+            .map(|wkt| wkt.map(|wkt| parse_to_ewkb(wkt, None)).transpose())
+            .collect::<databend_common_exception::Result<Vec<_>>>()?;
+        Ok(DataBlock::new_from_columns(vec![
+            GeometryType::from_opt_data(geometries),
+        ]))
+    })
+    .collect::<databend_common_exception::Result<Vec<_>>>()?;
+
+    let col_stats = blocks
+        .iter()
+        .map(|block| gen_columns_statistics(block, None, &schema))
+        .collect::<databend_common_exception::Result<Vec<_>>>()?;
+
+    let bbox_scalars = |xmin: f64, ymin: f64, xmax: f64, ymax: f64| {
+        (
+            Scalar::Tuple(vec![
+                Scalar::Number(NumberScalar::Float64(xmin.into())),
+                Scalar::Number(NumberScalar::Float64(ymin.into())),
+            ]),
+            Scalar::Tuple(vec![
+                Scalar::Number(NumberScalar::Float64(xmax.into())),
+                Scalar::Number(NumberScalar::Float64(ymax.into())),
+            ]),
+        )
+    };
+
+    let stats = col_stats[0].get(&0).unwrap();
+    let (min, max) = bbox_scalars(0.0, 0.0, 3.0, 2.0);
+    assert_eq!(stats.min(), &min);
+    assert_eq!(stats.max(), &max);
+    assert_eq!(stats.null_count, 1);
+
+    // Only NULL values, no bounding box
+    let stats = col_stats[1].get(&0).unwrap();
+    assert_eq!(stats.min(), &Scalar::Null);
+    assert_eq!(stats.max(), &Scalar::Null);
+
+    let r = reducers::reduce_block_statistics(&col_stats);
+    let stats = r.get(&0).unwrap();
+    let (min, max) = bbox_scalars(-1.0, 0.0, 3.0, 5.0);
+    assert_eq!(stats.min(), &min);
+    assert_eq!(stats.max(), &max);
+    assert_eq!(stats.null_count, 2);
+    Ok(())
+}
+
 #[test]
 fn test_ft_stats_col_stats_reduce() -> databend_common_exception::Result<()> {
     let num_of_blocks = 10;
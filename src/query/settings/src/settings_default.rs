@@ -444,6 +444,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("table_data_cache_admission_max_scan_bytes", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(0),
+                    desc: "Table scans estimated to read more bytes than this do not populate the table data caches, to keep large scans from evicting the hot data. 0 means all scans populate the caches.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=u64::MAX)),
+                }),
                 ("enable_hive_parquet_predict_pushdown", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables hive parquet predict pushdown  by setting this variable to 1, default value: 1",
@@ -621,6 +627,12 @@ impl DefaultSettings {
                     mode: SettingMode::Both,
                     range: Some(SettingRange::Numeric(0..=1)),
                 }),
+                ("enable_analyze_most_common_values", DefaultSettingValue {
+                    value: UserSettingValue::UInt64(1),
+                    desc: "Enables analyze the most common values of column groups for query optimization during analyzing table.",
+                    mode: SettingMode::Both,
+                    range: Some(SettingRange::Numeric(0..=1)),
+                }),
                 ("enable_aggregating_index_scan", DefaultSettingValue {
                     value: UserSettingValue::UInt64(1),
                     desc: "Enables scanning aggregating index data while querying.",
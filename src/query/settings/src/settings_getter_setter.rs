@@ -416,6 +416,10 @@ impl Settings {
         Ok(self.try_get_u64("query_result_cache_max_bytes")? as usize)
     }
 
+    pub fn get_table_data_cache_admission_max_scan_bytes(&self) -> Result<usize> {
+        Ok(self.try_get_u64("table_data_cache_admission_max_scan_bytes")? as usize)
+    }
+
     pub fn get_query_result_cache_min_execute_secs(&self) -> Result<usize> {
         Ok(self.try_get_u64("query_result_cache_min_execute_secs")? as usize)
     }
@@ -565,6 +569,10 @@ impl Settings {
         Ok(self.try_get_u64("enable_analyze_histogram")? != 0)
     }
 
+    pub fn get_enable_analyze_most_common_values(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_analyze_most_common_values")? != 0)
+    }
+
     pub fn get_enable_aggregating_index_scan(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_aggregating_index_scan")? != 0)
     }
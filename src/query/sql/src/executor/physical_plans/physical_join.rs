@@ -15,6 +15,8 @@
 use std::collections::HashSet;
 
 use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_expression::Scalar;
 
 use crate::binder::JoinPredicate;
 use crate::executor::explain::PlanStatsInfo;
@@ -23,6 +25,7 @@ use crate::executor::PhysicalPlanBuilder;
 use crate::optimizer::RelExpr;
 use crate::optimizer::RelationalProperty;
 use crate::optimizer::SExpr;
+use crate::plans::ConstantExpr;
 use crate::plans::Join;
 use crate::plans::JoinType;
 use crate::ColumnSet;
@@ -32,6 +35,8 @@ pub enum PhysicalJoinType {
     Hash,
     // The first arg is range conditions, the second arg is other conditions
     RangeJoin(Vec<ScalarExpr>, Vec<ScalarExpr>),
+    // The first arg is spatial condition, the second arg is other conditions
+    SpatialJoin(ScalarExpr, Vec<ScalarExpr>),
 }
 
 // Choose physical join type by join conditions
@@ -67,6 +72,21 @@ pub fn physical_join(join: &Join, s_expr: &SExpr) -> Result<PhysicalJoinType> {
         ));
     }
 
+    if matches!(join.join_type, JoinType::Inner | JoinType::Cross) {
+        if let Some(spatial_condition) = join
+            .non_equi_conditions
+            .iter()
+            .find(|condition| is_spatial_condition(condition, &left_prop, &right_prop))
+        {
+            // The spatial join only matches bounding boxes, so the spatial condition
+            // is evaluated again together with the other conditions.
+            return Ok(PhysicalJoinType::SpatialJoin(
+                spatial_condition.clone(),
+                join.non_equi_conditions.clone(),
+            ));
+        }
+    }
+
     // Leverage hash join to execute nested loop join
     Ok(PhysicalJoinType::Hash)
 }
@@ -105,6 +125,51 @@ fn check_condition(
     other_conditions.push(expr.clone());
 }
 
+// Spatial predicates whose matching rows have intersecting bounding boxes,
+// with one geometry from each side of the join.
+fn is_spatial_condition(
+    expr: &ScalarExpr,
+    left_prop: &RelationalProperty,
+    right_prop: &RelationalProperty,
+) -> bool {
+    let ScalarExpr::FunctionCall(func) = expr else {
+        return false;
+    };
+    let geometries = match (func.func_name.as_str(), func.arguments.as_slice()) {
+        ("st_intersects" | "st_within" | "st_contains" | "st_covers", [arg1, arg2]) => [arg1, arg2],
+        (
+            "st_dwithin",
+            [
+                arg1,
+                arg2,
+                ScalarExpr::ConstantExpr(ConstantExpr {
+                    value: Scalar::Number(_),
+                    ..
+                }),
+            ],
+        ) => [arg1, arg2],
+        _ => return false,
+    };
+    let mut left = false;
+    let mut right = false;
+    for arg in geometries {
+        if !matches!(
+            arg.data_type().map(|ty| ty.remove_nullable()),
+            Ok(DataType::Geometry)
+        ) {
+            return false;
+        }
+        match JoinPredicate::new(arg, left_prop, right_prop) {
+            JoinPredicate::Left(_) => left = true,
+            JoinPredicate::Right(_) => right = true,
+            JoinPredicate::Both { .. } | JoinPredicate::Other(_) | JoinPredicate::ALL(_) => {
+                return false;
+            }
+        }
+    }
+    left && right
+}
+
 impl PhysicalPlanBuilder {
     pub(crate) async fn build_join(
         &mut self,
@@ -168,6 +233,10 @@ impl PhysicalPlanBuilder {
                 self.build_range_join(s_expr, left_required, right_required, range, other)
                     .await
             }
+            PhysicalJoinType::SpatialJoin(spatial, other) => {
+                self.build_spatial_join(s_expr, left_required, right_required, spatial, other)
+                    .await
+            }
         }
     }
 }
@@ -127,9 +127,10 @@ impl PhysicalPlanBuilder {
             .ctx
             .get_table(catalog_name, database_name, table_name)
             .await?;
-        let table_info = table.get_table_info();
         let table_name = table_name.clone();
         let mutation_build_info = self.mutation_build_info.clone().unwrap();
+        // The table info of the build info also carries the branch that is mutated, if any.
+        let table_info = mutation_build_info.table_info.clone();
 
         if *strategy == MutationStrategy::Direct {
             // MutationStrategy::Direct: If the mutation filter is a simple expression,
@@ -18,6 +18,7 @@ use databend_common_expression::type_check::common_super_type;
 use databend_common_expression::DataSchemaRef;
 use databend_common_expression::DataSchemaRefExt;
 use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 
 use crate::binder::wrap_cast;
@@ -29,6 +30,7 @@ use crate::optimizer::ColumnSet;
 use crate::optimizer::RelExpr;
 use crate::optimizer::RelationalProperty;
 use crate::optimizer::SExpr;
+use crate::plans::ConstantExpr;
 use crate::plans::JoinType;
 use crate::ScalarExpr;
 use crate::TypeCheck;
@@ -64,14 +66,18 @@ impl RangeJoin {
 pub enum RangeJoinType {
     IEJoin,
     Merge,
+    // Probe an R-tree built on the bounding boxes of the left side
+    Spatial,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct RangeJoinCondition {
     pub left_expr: RemoteExpr,
     pub right_expr: RemoteExpr,
-    // "gt" | "lt" | "gte" | "lte"
+    // "gt" | "lt" | "gte" | "lte", or the spatial predicate for spatial join
     pub operator: String,
+    // The distance of `st_dwithin` for spatial join
+    pub distance: Option<f64>,
 }
 
 impl PhysicalPlanBuilder {
@@ -140,6 +146,53 @@ impl PhysicalPlanBuilder {
             stat_info: Some(self.build_plan_stat_info(s_expr)?),
         }))
     }
+
+    pub async fn build_spatial_join(
+        &mut self,
+        s_expr: &SExpr,
+        left_required: ColumnSet,
+        right_required: ColumnSet,
+        spatial_condition: ScalarExpr,
+        other_conditions: Vec<ScalarExpr>,
+    ) -> Result<PhysicalPlan> {
+        let left_prop = RelExpr::with_s_expr(s_expr.child(1)?).derive_relational_prop()?;
+        let right_prop = RelExpr::with_s_expr(s_expr.child(0)?).derive_relational_prop()?;
+
+        let left_side = self.build(s_expr.child(1)?, left_required).await?;
+        let right_side = self.build(s_expr.child(0)?, right_required).await?;
+
+        let left_schema = left_side.output_schema()?;
+        let right_schema = right_side.output_schema()?;
+
+        let merged_schema = DataSchemaRefExt::create(
+            left_schema
+                .fields()
+                .iter()
+                .chain(right_schema.fields())
+                .cloned()
+                .collect::<Vec<_>>(),
+        );
+
+        Ok(PhysicalPlan::RangeJoin(RangeJoin {
+            plan_id: 0,
+            left: Box::new(left_side),
+            right: Box::new(right_side),
+            conditions: vec![resolve_spatial_condition(
+                &spatial_condition,
+                &left_schema,
+                &right_schema,
+                &left_prop,
+                &right_prop,
+            )?],
+            other_conditions: other_conditions
+                .iter()
+                .map(|scalar| resolve_scalar(scalar, &merged_schema))
+                .collect::<Result<_>>()?,
+            join_type: JoinType::Inner,
+            range_join_type: RangeJoinType::Spatial,
+            stat_info: Some(self.build_plan_stat_info(s_expr)?),
+        }))
+    }
 }
 
 fn resolve_range_condition(
@@ -209,6 +262,53 @@ fn resolve_range_condition(
                 left_expr: left.unwrap().as_remote_expr(),
                 right_expr: right.unwrap().as_remote_expr(),
                 operator: op.to_string(),
+                distance: None,
+            })
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn resolve_spatial_condition(
+    expr: &ScalarExpr,
+    left_schema: &DataSchemaRef,
+    right_schema: &DataSchemaRef,
+    left_prop: &RelationalProperty,
+    right_prop: &RelationalProperty,
+) -> Result<RangeJoinCondition> {
+    match expr {
+        ScalarExpr::FunctionCall(func) => {
+            let mut left = None;
+            let mut right = None;
+            for arg in func.arguments[0..2].iter() {
+                match JoinPredicate::new(arg, left_prop, right_prop) {
+                    JoinPredicate::Left(_) => {
+                        left = Some(arg.type_check(left_schema.as_ref())?.project_column_ref(
+                            |index| left_schema.index_of(&index.to_string()).unwrap(),
+                        ));
+                    }
+                    JoinPredicate::Right(_) => {
+                        right = Some(arg.type_check(right_schema.as_ref())?.project_column_ref(
+                            |index| right_schema.index_of(&index.to_string()).unwrap(),
+                        ));
+                    }
+                    JoinPredicate::ALL(_)
+                    | JoinPredicate::Both { .. }
+                    | JoinPredicate::Other(_) => unreachable!(),
+                }
+            }
+            let distance = match func.arguments.get(2) {
+                Some(ScalarExpr::ConstantExpr(ConstantExpr {
+                    value: Scalar::Number(distance),
+                    ..
+                })) => Some(distance.to_f64().into_inner()),
+                _ => None,
+            };
+            Ok(RangeJoinCondition {
+                left_expr: left.unwrap().as_remote_expr(),
+                right_expr: right.unwrap().as_remote_expr(),
+                operator: func.func_name.clone(),
+                distance,
             })
         }
         _ => unreachable!(),
@@ -18,6 +18,7 @@ use std::collections::HashSet;
 use std::sync::Arc;
 
 use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::plan::sample_rng;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::Filters;
 use databend_common_catalog::plan::InternalColumn;
@@ -252,11 +253,20 @@ impl PhysicalPlanBuilder {
                 let probability = block_sample_value / 100.0;
                 let original_parts = source.parts.partitions.len();
                 let mut sample_parts = Vec::with_capacity(original_parts);
-                let mut rng = thread_rng();
                 let bernoulli = Bernoulli::new(probability).unwrap();
-                for part in source.parts.partitions.iter() {
-                    if bernoulli.sample(&mut rng) {
-                        sample_parts.push(part.clone());
+                if let Some(seed) = sample.seed {
+                    // Decide by the part itself, the order of partitions is not stable.
+                    for part in source.parts.partitions.iter() {
+                        if bernoulli.sample(&mut sample_rng(seed, &part.sample_key())) {
+                            sample_parts.push(part.clone());
+                        }
+                    }
+                } else {
+                    let mut rng = thread_rng();
+                    for part in source.parts.partitions.iter() {
+                        if bernoulli.sample(&mut rng) {
+                            sample_parts.push(part.clone());
+                        }
                     }
                 }
                 source.parts.partitions = sample_parts;
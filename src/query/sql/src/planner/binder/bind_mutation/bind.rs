@@ -171,6 +171,9 @@ impl Binder {
             .get_table(&catalog_name, &database_name, &table_name)
             .await?;
         let table_schema = table.schema();
+        let branch = expression
+            .target_branch()
+            .map(|branch| normalize_identifier(branch, &self.name_resolution_ctx).name);
 
         let bind_result = expression
             .bind(
@@ -309,6 +312,7 @@ impl Binder {
             truncate_table,
             predicate_column_index,
             direct_filter,
+            branch,
         };
 
         if mutation_strategy == MutationStrategy::NotMatchedOnly && !insert_only(&mutation) {
@@ -17,9 +17,13 @@ use std::collections::HashSet;
 use std::sync::Arc;
 
 use databend_common_ast::ast::Expr;
+use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::JoinCondition;
 use databend_common_ast::ast::JoinOperator;
+use databend_common_ast::ast::TableRefType;
 use databend_common_ast::ast::TableReference;
+use databend_common_ast::ast::TemporalClause;
+use databend_common_ast::ast::TimeTravelPoint;
 use databend_common_catalog::plan::InternalColumn;
 use databend_common_catalog::plan::InternalColumnType;
 use databend_common_catalog::table::Table;
@@ -39,6 +43,7 @@ use crate::optimizer::SExpr;
 use crate::optimizer::SubqueryRewriter;
 use crate::plans::BoundColumnRef;
 use crate::plans::Filter;
+use crate::plans::FunctionCall;
 use crate::plans::MutationSource;
 use crate::plans::RelOperator;
 use crate::plans::SubqueryExpr;
@@ -71,6 +76,24 @@ pub enum MutationExpression {
 }
 
 impl MutationExpression {
+    /// The branch of the target table that is mutated, `AT (BRANCH => <name>)`.
+    pub fn target_branch(&self) -> Option<&Identifier> {
+        let (MutationExpression::Merge { target, .. }
+        | MutationExpression::Update { target, .. }
+        | MutationExpression::Delete { target, .. }) = self;
+        match target {
+            TableReference::Table {
+                temporal:
+                    Some(TemporalClause::TimeTravel(TimeTravelPoint::Ref {
+                        typ: TableRefType::Branch,
+                        name,
+                    })),
+                ..
+            } => Some(name),
+            _ => None,
+        }
+    }
+
     pub async fn bind(
         &self,
         binder: &mut Binder,
@@ -201,9 +224,41 @@ impl MutationExpression {
                     .ok_or_else(|| ErrorCode::Internal("Can't get target table index"))?;
 
                 // If the filter is a simple expression, change the mutation strategy to MutationStrategy::Direct.
-                let (mutation_strategy, filter) =
+                let (mut mutation_strategy, mut filter) =
                     binder.process_filter(&mut bind_context, filter)?;
 
+                // The row access policy of the target table is bound as a filter over the scan,
+                // merge it into the mutation filter so that only the visible rows are mutated.
+                if target_table
+                    .get_table_info()
+                    .meta
+                    .row_access_policy
+                    .is_some()
+                {
+                    if let RelOperator::Filter(policy_filter) = s_expr.plan() {
+                        let mut predicates = policy_filter.predicates.clone();
+                        s_expr = s_expr.child(0)?.clone();
+                        if let Some(filter) = &filter {
+                            predicates.extend(Binder::flatten_and_scalar_expr(filter));
+                        }
+                        let policy_filter = predicates
+                            .into_iter()
+                            .reduce(|left, right| {
+                                ScalarExpr::FunctionCall(FunctionCall {
+                                    span: None,
+                                    func_name: "and".to_string(),
+                                    params: vec![],
+                                    arguments: vec![left, right],
+                                })
+                            })
+                            .unwrap();
+                        if binder.has_subquery(&policy_filter)? {
+                            mutation_strategy = MutationStrategy::MatchedOnly;
+                        }
+                        filter = Some(policy_filter);
+                    }
+                }
+
                 // Build bind result according to mutation strategy.
                 if mutation_strategy == MutationStrategy::Direct {
                     let mut truncate_table = false;
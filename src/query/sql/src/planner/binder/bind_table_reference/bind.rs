@@ -78,7 +78,8 @@ impl Binder {
                 location,
                 options,
                 alias,
-            } => self.bind_location(bind_context, location, options, alias),
+                sample,
+            } => self.bind_location(bind_context, location, options, alias, sample),
             TableReference::Join { join, .. } => self.bind_join(bind_context, join),
         }
     }
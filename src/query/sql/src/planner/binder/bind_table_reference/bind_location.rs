@@ -16,6 +16,7 @@ use std::str::FromStr;
 
 use databend_common_ast::ast::Connection;
 use databend_common_ast::ast::FileLocation;
+use databend_common_ast::ast::SampleConfig;
 use databend_common_ast::ast::SelectStageOptions;
 use databend_common_ast::ast::TableAlias;
 use databend_common_ast::ast::UriLocation;
@@ -37,6 +38,7 @@ impl Binder {
         location: &FileLocation,
         options: &SelectStageOptions,
         alias: &Option<TableAlias>,
+        sample: &Option<SampleConfig>,
     ) -> Result<(SExpr, BindContext)> {
         databend_common_base::runtime::block_on(async move {
             let location = match location {
@@ -67,8 +69,16 @@ impl Binder {
                 files: options.files.clone(),
             };
             let table_ctx = self.ctx.clone();
-            self.bind_stage_table(table_ctx, bind_context, stage_info, files_info, alias, None)
-                .await
+            self.bind_stage_table(
+                table_ctx,
+                bind_context,
+                stage_info,
+                files_info,
+                alias,
+                None,
+                sample,
+            )
+            .await
         })
     }
 }
@@ -12,28 +12,45 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::sync::Arc;
+
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::SampleConfig;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::ast::TableAlias;
 use databend_common_ast::ast::TemporalClause;
 use databend_common_ast::ast::WithOptions;
+use databend_common_ast::parser::parse_expr;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::Span;
+use databend_common_catalog::table::Table;
 use databend_common_catalog::table::TimeNavigation;
 use databend_common_catalog::table_with_options::check_with_opt_valid;
 use databend_common_catalog::table_with_options::get_with_opt_consume;
 use databend_common_catalog::table_with_options::get_with_opt_max_batch_size;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_license::license::Feature;
+use databend_common_license::license_manager::LicenseManagerSwitch;
+use databend_common_meta_app::schema::TableRowAccessPolicy;
 use databend_common_storages_view::view_table::QUERY;
+use databend_common_users::UserApiProvider;
+use databend_enterprise_row_access_policy_feature::get_row_access_policy_handler;
 use databend_storages_common_table_meta::table::get_change_type;
+use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_TABLE_ID;
 
+use crate::binder::scalar_common::split_conjunctions;
 use crate::binder::util::TableIdentifier;
 use crate::binder::Binder;
+use crate::binder::Finder;
 use crate::optimizer::SExpr;
+use crate::plans::Filter;
+use crate::plans::Visitor as _;
 use crate::BindContext;
+use crate::ScalarBinder;
+use crate::ScalarExpr;
 
 impl Binder {
     /// Bind a base table.
@@ -143,6 +160,7 @@ impl Binder {
         {
             let change_type = get_change_type(&table_name_alias);
             if change_type.is_some() {
+                let row_access_policy = self.table_row_access_policy(&table_meta)?;
                 let table_index = self.metadata.write().add_table(
                     catalog,
                     database.clone(),
@@ -160,6 +178,8 @@ impl Binder {
                     change_type,
                     sample,
                 )?;
+                let s_expr =
+                    self.bind_row_access_policy(&bind_context, row_access_policy, s_expr)?;
 
                 if let Some(alias) = alias {
                     bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
@@ -256,6 +276,7 @@ impl Binder {
                 }
             }
             _ => {
+                let row_access_policy = self.table_row_access_policy(&table_meta)?;
                 let table_index = self.metadata.write().add_table(
                     catalog,
                     database.clone(),
@@ -274,6 +295,8 @@ impl Binder {
                     None,
                     sample,
                 )?;
+                let s_expr =
+                    self.bind_row_access_policy(&bind_context, row_access_policy, s_expr)?;
                 if let Some(alias) = alias {
                     bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
                 }
@@ -282,6 +305,121 @@ impl Binder {
         }
     }
 
+    /// Returns the row access policy that guards the rows of `table`.
+    ///
+    /// A stream reads the rows of its source table, so it is guarded by the
+    /// policy of the source table.
+    fn table_row_access_policy(
+        &self,
+        table: &Arc<dyn Table>,
+    ) -> Result<Option<TableRowAccessPolicy>> {
+        if !table.is_stream() {
+            return Ok(table.get_table_info().meta.row_access_policy.clone());
+        }
+
+        let Some(source_table_id) = table.options().get(OPT_KEY_SOURCE_TABLE_ID) else {
+            return Ok(None);
+        };
+        let source_table_id = source_table_id.parse::<u64>()?;
+        let source_meta = databend_common_base::runtime::block_on(async {
+            let catalog = self
+                .ctx
+                .get_catalog(table.get_table_info().catalog())
+                .await?;
+            catalog.get_table_meta_by_id(source_table_id).await
+        })?;
+        Ok(source_meta.and_then(|meta| meta.data.row_access_policy))
+    }
+
+    /// Filter the scan of a table with the predicate of its row access policy.
+    ///
+    /// The policy arguments are bound to the attached table columns, so the
+    /// policy body can only see the columns it is declared over.
+    fn bind_row_access_policy(
+        &mut self,
+        bind_context: &BindContext,
+        row_access_policy: Option<TableRowAccessPolicy>,
+        s_expr: SExpr,
+    ) -> Result<SExpr> {
+        let Some(TableRowAccessPolicy { policy, columns }) = row_access_policy else {
+            return Ok(s_expr);
+        };
+
+        // The policy is an access control, fail the query rather than expose
+        // the rows when it can not be applied.
+        LicenseManagerSwitch::instance()
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::RowAccessPolicy)?;
+        let meta_api = UserApiProvider::instance().get_meta_store_client();
+        let policy_meta = databend_common_base::runtime::block_on(
+            get_row_access_policy_handler().get_row_access_policy(
+                meta_api,
+                &self.ctx.get_tenant(),
+                policy.clone(),
+            ),
+        )?;
+        if policy_meta.args.len() != columns.len() {
+            return Err(ErrorCode::SemanticError(format!(
+                "row access policy {} takes {} arguments, but {} columns are attached",
+                policy,
+                policy_meta.args.len(),
+                columns.len()
+            )));
+        }
+
+        let mut policy_context = BindContext::new();
+        for ((arg_name, _), column) in policy_meta.args.iter().zip(columns.iter()) {
+            let mut column_binding = bind_context
+                .columns
+                .iter()
+                .find(|c| &c.column_name == column)
+                .cloned()
+                .ok_or_else(|| {
+                    ErrorCode::UnknownColumn(format!(
+                        "column {} of row access policy {} does not exist",
+                        column, policy
+                    ))
+                })?;
+            column_binding.database_name = None;
+            column_binding.table_name = None;
+            column_binding.column_name = arg_name.clone();
+            policy_context.add_column_binding(column_binding);
+        }
+
+        let tokens = tokenize_sql(&policy_meta.body)?;
+        let ast_expr = parse_expr(&tokens, self.dialect)?;
+        let mut scalar_binder = ScalarBinder::new(
+            &mut policy_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+        );
+        let (scalar, data_type) = scalar_binder.bind(&ast_expr)?;
+
+        let f = |scalar: &ScalarExpr| {
+            matches!(
+                scalar,
+                ScalarExpr::AggregateFunction(_) | ScalarExpr::WindowFunction(_)
+            )
+        };
+        let mut finder = Finder::new(&f);
+        finder.visit(&scalar)?;
+        if !finder.scalars().is_empty() || data_type.remove_nullable() != DataType::Boolean {
+            return Err(ErrorCode::SemanticError(format!(
+                "row access policy {} must be a boolean expression without aggregate or window functions",
+                policy
+            )));
+        }
+
+        let filter = Filter {
+            predicates: split_conjunctions(&scalar),
+        };
+        Ok(SExpr::create_unary(
+            Arc::new(filter.into()),
+            Arc::new(s_expr),
+        ))
+    }
+
     pub(crate) fn check_view_dep(
         bind_context: &BindContext,
         database: &str,
@@ -18,6 +18,7 @@ use databend_common_exception::Result;
 
 use crate::binder::Binder;
 use crate::plans::Plan;
+use crate::plans::SendNotificationPlan;
 use crate::BindContext;
 
 impl Binder {
@@ -30,6 +31,24 @@ impl Binder {
     ) -> Result<Plan> {
         let table_function_name = stmt.name.split('$').last().unwrap();
 
+        // Sending a notification has side effects, so it is a statement of its own
+        // instead of a table function that any query could scan.
+        if table_function_name.eq_ignore_ascii_case("send_notification") {
+            if stmt.args.len() != 2 {
+                return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                    "Incorrect number of arguments to function {}. Expected 2, got {}",
+                    stmt.name,
+                    stmt.args.len()
+                )));
+            }
+            let plan = SendNotificationPlan {
+                tenant: self.ctx.get_tenant(),
+                name: stmt.args[0].clone(),
+                message: stmt.args[1].clone(),
+            };
+            return Ok(Plan::SendNotification(Box::new(plan)));
+        }
+
         let query = if table_function_name.eq_ignore_ascii_case("search_tables") {
             if stmt.args.len() != 1 {
                 return Err(ErrorCode::NumberArgumentsNotMatch(format!(
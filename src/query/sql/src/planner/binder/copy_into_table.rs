@@ -399,6 +399,7 @@ impl<'a> Binder {
                 plan.stage_table_info.files_info.clone(),
                 alias,
                 plan.stage_table_info.files_to_copy.clone(),
+                &None,
             )
             .await?;
 
@@ -602,6 +603,7 @@ fn check_transform_query(
                     location,
                     options,
                     alias,
+                    sample: None,
                 } = &select.from[0]
                 {
                     if options.is_empty() {
@@ -156,6 +156,11 @@ impl Binder {
             .collect::<Result<Vec<_>>>()?;
 
         let (schema, field_comments) = match source {
+            Some(CreateTableSource::Clone { .. }) => {
+                return Err(ErrorCode::BadArguments(
+                    "CLONE is not supported by dynamic table",
+                ));
+            }
             Some(source) => {
                 let (source_schema, source_comments, _) =
                     self.analyze_create_table_schema(source).await?;
@@ -90,6 +90,7 @@ impl Binder {
                         url: opts.url.clone(),
                         method: Some(method),
                         authorization_header: opts.authorization_header.clone(),
+                        signing_secret: opts.signing_secret.clone(),
                     });
 
                 let tenant = self.ctx.get_tenant();
@@ -44,6 +44,7 @@ use databend_common_ast::ast::ShowLimit;
 use databend_common_ast::ast::ShowTablesStatusStmt;
 use databend_common_ast::ast::ShowTablesStmt;
 use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableRefType;
 use databend_common_ast::ast::TableReference;
 use databend_common_ast::ast::TableType;
 use databend_common_ast::ast::TruncateTableStmt;
@@ -78,6 +79,7 @@ use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::SnapshotRefType;
 use databend_common_meta_app::schema::TableIndex;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_storage::DataOperator;
@@ -92,10 +94,13 @@ use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_COMPRESSION;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
+use databend_storages_common_table_meta::table::OPT_KEY_TIERING_COLD_CONNECTION_NAME;
+use databend_storages_common_table_meta::table::OPT_KEY_TIERING_COLD_LOCATION;
 use derive_visitor::DriveMut;
 use log::debug;
 use opendal::Operator;
 
+use crate::binder::get_storage_params_from_location;
 use crate::binder::get_storage_params_from_options;
 use crate::binder::parse_storage_params_from_uri;
 use crate::binder::scalar::ScalarBinder;
@@ -111,13 +116,18 @@ use crate::planner::semantic::resolve_type_name;
 use crate::planner::semantic::IdentifierNormalizer;
 use crate::plans::AddColumnOption;
 use crate::plans::AddTableColumnPlan;
+use crate::plans::AddTableRowAccessPolicyPlan;
 use crate::plans::AlterTableClusterKeyPlan;
 use crate::plans::AnalyzeTablePlan;
+use crate::plans::CloneTableSource;
 use crate::plans::CreateTablePlan;
+use crate::plans::CreateTableRefPlan;
 use crate::plans::DescribeTablePlan;
 use crate::plans::DropTableClusterKeyPlan;
 use crate::plans::DropTableColumnPlan;
 use crate::plans::DropTablePlan;
+use crate::plans::DropTableRefPlan;
+use crate::plans::DropTableRowAccessPolicyPlan;
 use crate::plans::ExistsTablePlan;
 use crate::plans::ModifyColumnAction as ModifyColumnActionInPlan;
 use crate::plans::ModifyTableColumnPlan;
@@ -479,6 +489,27 @@ impl Binder {
             _ => None,
         };
 
+        // Blocks moved by the tiering policy are stored in the cold storage.
+        let mut cold_storage_params = match options.get(OPT_KEY_TIERING_COLD_LOCATION) {
+            Some(location) if engine == Engine::Fuse => {
+                let sp = get_storage_params_from_location(
+                    self.ctx.as_ref(),
+                    location,
+                    options.get(OPT_KEY_TIERING_COLD_CONNECTION_NAME),
+                    "when create TABLE with tiering cold location",
+                )
+                .await?;
+                let data_operator = DataOperator::try_create(&sp).await?;
+                verify_external_location_privileges(data_operator.operator()).await?;
+                Some(sp)
+            }
+            Some(_) => Err(ErrorCode::BadArguments(format!(
+                "Incorrect CREATE query: table option {} is only supported for FUSE engine, but got {:?}",
+                OPT_KEY_TIERING_COLD_LOCATION, engine
+            )))?,
+            None => None,
+        };
+
         match table_type {
             TableType::Normal => {}
             TableType::Transient => {
@@ -512,6 +543,75 @@ impl Binder {
             }
         }
 
+        let mut cluster_key = None;
+        let clone_source = if let Some(CreateTableSource::Clone {
+            catalog: source_catalog,
+            database: source_database,
+            table: source_table,
+            travel_point,
+        }) = &source
+        {
+            if engine != Engine::Fuse || as_query.is_some() || uri_location.is_some() {
+                return Err(ErrorCode::BadArguments(
+                    "Incorrect CREATE query: CREATE TABLE ... CLONE only supports FUSE engine, without AS SELECT or external location",
+                ));
+            }
+            let (source_catalog, source_database, source_table) = self
+                .normalize_object_identifier_triple(source_catalog, source_database, source_table);
+            let table_info = self
+                .ctx
+                .get_table(&source_catalog, &source_database, &source_table)
+                .await?
+                .get_table_info()
+                .clone();
+            if table_info.engine() != "FUSE" {
+                return Err(ErrorCode::TableEngineNotSupported(format!(
+                    "{source_database}.{source_table} is not a FUSE table, it can not be cloned",
+                )));
+            }
+
+            // The clone shares blocks with the source table, it must read them the same way.
+            for key in [OPT_KEY_STORAGE_FORMAT, OPT_KEY_TABLE_COMPRESSION] {
+                if let Some(value) = table_info.options().get(key) {
+                    if options
+                        .get(key)
+                        .is_some_and(|v| !v.eq_ignore_ascii_case(value))
+                    {
+                        return Err(ErrorCode::TableOptionInvalid(format!(
+                            "table option {key} of the clone must be the same as the source table: {value}",
+                        )));
+                    }
+                    options.insert(key.to_string(), value.clone());
+                }
+            }
+            storage_params = table_info.meta.storage_params.clone();
+            cold_storage_params = table_info.meta.cold_storage_params.clone();
+            if cluster_by.is_none() {
+                if let Some(source_cluster_key) = &table_info.meta.default_cluster_key {
+                    if let Some(cluster_type) = table_info.options().get(OPT_KEY_CLUSTER_TYPE) {
+                        options.insert(OPT_KEY_CLUSTER_TYPE.to_owned(), cluster_type.clone());
+                    }
+                    cluster_key = Some(source_cluster_key.clone());
+                }
+            }
+
+            let point = match travel_point {
+                Some(point) => {
+                    let mut bind_context = BindContext::new();
+                    Some(self.resolve_data_travel_point(&mut bind_context, point)?)
+                }
+                None => None,
+            };
+            Some(CloneTableSource {
+                catalog: source_catalog,
+                database: source_database,
+                table: source_table,
+                point,
+            })
+        } else {
+            None
+        };
+
         // Build table schema
         let (schema, field_comments, inverted_indexes) = match (&source, &as_query) {
             (Some(source), None) => {
@@ -662,7 +762,6 @@ impl Binder {
             )));
         }
 
-        let mut cluster_key = None;
         if let Some(cluster_opt) = cluster_by {
             let keys = self
                 .analyze_cluster_keys(cluster_opt, schema.clone())
@@ -686,6 +785,7 @@ impl Binder {
             engine,
             engine_options,
             storage_params,
+            cold_storage_params,
             options,
             field_comments,
             cluster_key,
@@ -698,6 +798,7 @@ impl Binder {
                 None
             },
             inverted_indexes,
+            clone_source,
         };
         Ok(Plan::CreateTable(Box::new(plan)))
     }
@@ -757,11 +858,13 @@ impl Binder {
             engine: Engine::Fuse,
             engine_options: BTreeMap::new(),
             storage_params: Some(sp),
+            cold_storage_params: None,
             options,
             field_comments: vec![],
             cluster_key: None,
             as_select: None,
             inverted_indexes: None,
+            clone_source: None,
         })))
     }
 
@@ -1075,6 +1178,86 @@ impl Binder {
                     table,
                 })))
             }
+            AlterTableAction::CreateRef {
+                typ,
+                if_not_exists,
+                name,
+                travel_point,
+            } => {
+                let point = match travel_point {
+                    Some(point) => Some(self.resolve_data_travel_point(bind_context, point)?),
+                    None => None,
+                };
+                Ok(Plan::CreateTableRef(Box::new(CreateTableRefPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    typ: Self::snapshot_ref_type(typ),
+                    name: self.normalize_object_identifier(name),
+                    if_not_exists: *if_not_exists,
+                    point,
+                })))
+            }
+            AlterTableAction::DropRef {
+                typ,
+                if_exists,
+                name,
+            } => Ok(Plan::DropTableRef(Box::new(DropTableRefPlan {
+                tenant,
+                catalog,
+                database,
+                table,
+                typ: Self::snapshot_ref_type(typ),
+                name: self.normalize_object_identifier(name),
+                if_exists: *if_exists,
+            }))),
+            AlterTableAction::AddRowAccessPolicy { policy, columns } => {
+                let schema = self
+                    .ctx
+                    .get_table(&catalog, &database, &table)
+                    .await?
+                    .schema();
+                let columns = columns
+                    .iter()
+                    .map(|column| {
+                        let column = self.normalize_object_identifier(column);
+                        if !schema.has_field(&column) {
+                            return Err(ErrorCode::UnknownColumn(format!(
+                                "Cannot find column {}",
+                                column
+                            )));
+                        }
+                        Ok(column)
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(Plan::AddTableRowAccessPolicy(Box::new(
+                    AddTableRowAccessPolicyPlan {
+                        tenant,
+                        catalog,
+                        database,
+                        table,
+                        policy: policy.to_string(),
+                        columns,
+                    },
+                )))
+            }
+            AlterTableAction::DropRowAccessPolicy { policy } => Ok(Plan::DropTableRowAccessPolicy(
+                Box::new(DropTableRowAccessPolicyPlan {
+                    tenant,
+                    catalog,
+                    database,
+                    table,
+                    policy: policy.to_string(),
+                }),
+            )),
+        }
+    }
+
+    pub(in crate::planner::binder) fn snapshot_ref_type(typ: &TableRefType) -> SnapshotRefType {
+        match typ {
+            TableRefType::Branch => SnapshotRefType::Branch,
+            TableRefType::Tag => SnapshotRefType::Tag,
         }
     }
 
@@ -1303,15 +1486,32 @@ impl Binder {
             catalog,
             database,
             table,
+            column_groups,
         } = stmt;
 
         let (catalog, database, table) =
             self.normalize_object_identifier_triple(catalog, database, table);
 
+        let mut groups = Vec::with_capacity(column_groups.len());
+        for group in column_groups {
+            let columns = group
+                .iter()
+                .map(|column| self.normalize_object_identifier(column))
+                .collect::<Vec<_>>();
+            if columns.len() < 2 || columns.iter().collect::<HashSet<_>>().len() != columns.len() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "column group ({}) must contain at least two distinct columns",
+                    columns.join(", ")
+                )));
+            }
+            groups.push(columns);
+        }
+
         Ok(Plan::AnalyzeTable(Box::new(AnalyzeTablePlan {
             catalog,
             database,
             table,
+            column_groups: groups,
         })))
     }
 
@@ -1550,6 +1750,12 @@ impl Binder {
                 catalog,
                 database,
                 table,
+            }
+            | CreateTableSource::Clone {
+                catalog,
+                database,
+                table,
+                ..
             } => {
                 let (catalog, database, table) =
                     self.normalize_object_identifier_triple(catalog, database, table);
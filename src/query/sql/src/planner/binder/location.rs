@@ -34,6 +34,8 @@ use databend_common_meta_app::storage::StorageObsConfig;
 use databend_common_meta_app::storage::StorageOssConfig;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_meta_app::storage::StorageS3Config;
+use databend_common_meta_app::storage::StorageSftpConfig;
+use databend_common_meta_app::storage::StorageWebdavConfig;
 use databend_common_meta_app::storage::StorageWebhdfsConfig;
 use databend_common_meta_app::storage::STORAGE_GCS_DEFAULT_ENDPOINT;
 use databend_common_meta_app::storage::STORAGE_IPFS_DEFAULT_ENDPOINT;
@@ -449,6 +451,79 @@ fn parse_huggingface_params(l: &mut UriLocation, root: String) -> Result<Storage
     Ok(sp)
 }
 
+/// SFTP uri looks like `sftp://<HOST>[:<PORT>]/<PATH>`.
+///
+/// Authenticates with `private_key`, the content of the private key, or with `password`.
+fn parse_sftp_params(l: &mut UriLocation, root: String) -> Result<StorageParams> {
+    let key = l.connection.get("private_key").cloned().unwrap_or_default();
+    if !key.is_empty() && !key.trim_start().starts_with("-----BEGIN ") {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            anyhow!("private_key for sftp must be the content of the private key, not a path"),
+        ));
+    }
+
+    let password = l.connection.get("password").cloned().unwrap_or_default();
+    if !key.is_empty() && !password.is_empty() {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            anyhow!("sftp accepts either private_key or password, but not both"),
+        ));
+    }
+
+    let sp = StorageParams::Sftp(StorageSftpConfig {
+        endpoint: format!("ssh://{}", l.name),
+        root,
+        user: l.connection.get("user").cloned().unwrap_or_default(),
+        key,
+        password,
+        known_hosts_strategy: l
+            .connection
+            .get("known_hosts_strategy")
+            .cloned()
+            .unwrap_or_default(),
+    });
+
+    l.connection
+        .check()
+        .map_err(|err| Error::new(ErrorKind::InvalidInput, err.to_string()))?;
+
+    Ok(sp)
+}
+
+/// WebDAV uri looks like `webdav://<HOST>[:<PORT>]/<PATH>`, it's accessed via HTTPS unless `https` is `FALSE`.
+fn parse_webdav_params(l: &mut UriLocation, root: String) -> Result<StorageParams> {
+    let is_https = l
+        .connection
+        .get("https")
+        .map(|s| s.to_lowercase().parse::<bool>())
+        .unwrap_or(Ok(true))
+        .map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!(
+                    "HTTPS should be `TRUE` or `FALSE`, parse error with: {:?}",
+                    e,
+                ),
+            )
+        })?;
+    let prefix = if is_https { "https" } else { "http" };
+
+    let sp = StorageParams::Webdav(StorageWebdavConfig {
+        endpoint_url: format!("{prefix}://{}", l.name),
+        root,
+        username: l.connection.get("username").cloned().unwrap_or_default(),
+        password: l.connection.get("password").cloned().unwrap_or_default(),
+        token: l.connection.get("token").cloned().unwrap_or_default(),
+    });
+
+    l.connection
+        .check()
+        .map_err(|err| Error::new(ErrorKind::InvalidInput, err.to_string()))?;
+
+    Ok(sp)
+}
+
 pub async fn parse_storage_params_from_uri(
     l: &mut UriLocation,
     ctx: Option<&dyn TableContext>,
@@ -565,6 +640,8 @@ pub async fn parse_uri_location(
         }
         Scheme::Webhdfs => parse_webhdfs_params(l, root)?,
         Scheme::Huggingface => parse_huggingface_params(l, root)?,
+        Scheme::Sftp => parse_sftp_params(l, root)?,
+        Scheme::Webdav => parse_webdav_params(l, root)?,
         v => {
             return Err(Error::new(
                 ErrorKind::InvalidInput,
@@ -591,7 +668,23 @@ pub async fn get_storage_params_from_options(
         .get("location")
         .ok_or_else(|| ErrorCode::BadArguments("missing option 'location'".to_string()))?;
     let connection = options.get("connection_name");
+    get_storage_params_from_location(
+        ctx,
+        location,
+        connection,
+        "when loading/creating ICEBERG/DELTA table",
+    )
+    .await
+}
 
+/// Resolves the storage params of a location uri, with the credentials of the named connection
+/// if `connection` is given.
+pub async fn get_storage_params_from_location(
+    ctx: &dyn TableContext,
+    location: &str,
+    connection: Option<&String>,
+    op: &str,
+) -> databend_common_exception::Result<StorageParams> {
     let mut location = if let Some(connection) = connection {
         let connection = ctx.get_connection(connection).await?;
         let location = UriLocation::from_uri(location.to_string(), connection.storage_params)?;
@@ -605,11 +698,6 @@ pub async fn get_storage_params_from_options(
     } else {
         UriLocation::from_uri(location.to_string(), BTreeMap::new())?
     };
-    let sp = parse_storage_params_from_uri(
-        &mut location,
-        None,
-        "when loading/creating ICEBERG/DELTA table",
-    )
-    .await?;
+    let sp = parse_storage_params_from_uri(&mut location, None, op).await?;
     Ok(sp)
 }
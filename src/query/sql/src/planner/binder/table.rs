@@ -114,6 +114,7 @@ impl Binder {
         files_info: StageFilesInfo,
         alias: &Option<TableAlias>,
         files_to_copy: Option<Vec<StageFileInfo>>,
+        sample: &Option<SampleConfig>,
     ) -> Result<(SExpr, BindContext)> {
         let start = std::time::Instant::now();
         let max_column_position = self.metadata.read().get_max_column_position();
@@ -139,7 +140,7 @@ impl Binder {
         );
 
         let (s_expr, mut bind_context) =
-            self.bind_base_table(bind_context, "system", table_index, None, &None)?;
+            self.bind_base_table(bind_context, "system", table_index, None, sample)?;
         if let Some(alias) = alias {
             bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
         }
@@ -651,6 +652,10 @@ impl Binder {
                 database,
                 name,
             } => self.resolve_stream_data_travel_point(catalog, database, name),
+            TimeTravelPoint::Ref { typ, name } => Ok(NavigationPoint::SnapshotRef {
+                typ: Self::snapshot_ref_type(typ),
+                name: self.normalize_object_identifier(name),
+            }),
         }
     }
 
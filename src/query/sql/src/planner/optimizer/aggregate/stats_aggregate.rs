@@ -187,6 +187,8 @@ impl RuleStatsAggregateOptimizer {
             DataType::Number(_)
                 | DataType::Date
                 | DataType::Timestamp
+                | DataType::Time
+                | DataType::Uuid
                 | DataType::String
                 | DataType::Decimal(_)
         )
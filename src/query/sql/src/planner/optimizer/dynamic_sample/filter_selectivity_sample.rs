@@ -64,6 +64,7 @@ pub async fn filter_selectivity_sample(
             let sample_conf = SampleConfig {
                 row_level: Some(SampleRowLevel::RowsNum(sample_size)),
                 block_level: Some(50.0),
+                seed: None,
             };
             scan.sample = Some(sample_conf);
             let new_child = SExpr::create_leaf(Arc::new(RelOperator::Scan(scan)));
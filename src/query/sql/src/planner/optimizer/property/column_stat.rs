@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cmp::Ordering;
 use std::collections::HashMap;
 
 use databend_common_storage::Datum;
@@ -40,6 +41,38 @@ pub struct ColumnStat {
     pub histogram: Option<Histogram>,
 }
 
+#[derive(Debug, Clone)]
+/// Joint statistics information of a group of correlated columns
+pub struct ColumnGroupStat {
+    /// Indexes of the columns in the group
+    pub columns: Vec<IndexType>,
+
+    /// Number of distinct combinations of the column values
+    pub ndv: f64,
+
+    /// Most common combinations of the column values, and their frequencies
+    pub most_common_values: Vec<(Vec<Datum>, f64)>,
+}
+
+impl ColumnGroupStat {
+    /// Estimate the selectivity of `columns[0] = values[0] AND columns[1] = values[1] ...`.
+    pub fn equality_selectivity(&self, values: &[Datum]) -> f64 {
+        let matched = self.most_common_values.iter().find(|(mcv, _)| {
+            mcv.len() == values.len()
+                && mcv.iter().zip(values).all(|(l, r)| {
+                    l.type_comparable(r) && l.compare(r).is_ok_and(|o| o == Ordering::Equal)
+                })
+        });
+        if let Some((_, frequency)) = matched {
+            return *frequency;
+        }
+        // The rest rows are assumed to be distributed uniformly on the rest combinations.
+        let mcv_frequency: f64 = self.most_common_values.iter().map(|(_, f)| f).sum();
+        let rest_ndv = (self.ndv - self.most_common_values.len() as f64).max(1.0);
+        (1.0 - mcv_frequency).max(0.0) / rest_ndv
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct NewStatistic {
     pub min: Option<Datum>,
@@ -16,6 +16,7 @@ use std::collections::HashSet;
 use std::fmt::Display;
 use std::fmt::Formatter;
 
+use super::column_stat::ColumnGroupStat;
 use super::column_stat::ColumnStatSet;
 use crate::plans::ScalarExpr;
 use crate::plans::ScalarItem;
@@ -49,6 +50,8 @@ pub struct Statistics {
     pub precise_cardinality: Option<u64>,
     /// Statistics of columns, column index -> column stat
     pub column_stats: ColumnStatSet,
+    /// Joint statistics of the groups of correlated columns
+    pub column_groups: Vec<ColumnGroupStat>,
 }
 
 #[derive(Default, Clone, Debug)]
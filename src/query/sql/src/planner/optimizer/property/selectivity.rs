@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::collections::HashSet;
 
 use databend_common_exception::ErrorCode;
@@ -127,6 +128,79 @@ impl<'a> SelectivityEstimator<'a> {
         })
     }
 
+    /// Compute the selectivity of the conjunction of predicates.
+    ///
+    /// The equality predicates on a group of correlated columns are estimated together by the
+    /// statistics of the column group, instead of being estimated separately.
+    pub fn compute_conjunction_selectivity(&mut self, predicates: &[ScalarExpr]) -> Result<f64> {
+        let (group_selectivity, covered_predicates) =
+            self.compute_column_group_selectivity(predicates);
+        let mut selectivity = MAX_SELECTIVITY;
+        for (i, pred) in predicates.iter().enumerate() {
+            // Compute selectivity for each conjunction
+            let pred_selectivity = self.compute_selectivity(pred, true)?;
+            if !covered_predicates.contains(&i) {
+                selectivity = selectivity.min(pred_selectivity);
+            }
+        }
+        if let Some(group_selectivity) = group_selectivity {
+            selectivity = selectivity.min(group_selectivity);
+        }
+        Ok(selectivity)
+    }
+
+    // Returns the selectivity of the predicates covered by column groups, and the
+    // positions of the covered predicates.
+    fn compute_column_group_selectivity(
+        &self,
+        predicates: &[ScalarExpr],
+    ) -> (Option<f64>, HashSet<usize>) {
+        let mut covered_predicates = HashSet::new();
+        if self.input_stat.column_groups.is_empty() {
+            return (None, covered_predicates);
+        }
+
+        // column index -> (predicate position, constant value) of `column = constant`
+        let mut equalities = HashMap::new();
+        for (i, pred) in predicates.iter().enumerate() {
+            let ScalarExpr::FunctionCall(func) = pred else {
+                continue;
+            };
+            if func.func_name != "eq" {
+                continue;
+            }
+            let (column_ref, constant) = match (&func.arguments[0], &func.arguments[1]) {
+                (ScalarExpr::BoundColumnRef(column_ref), ScalarExpr::ConstantExpr(constant))
+                | (ScalarExpr::ConstantExpr(constant), ScalarExpr::BoundColumnRef(column_ref)) => {
+                    (column_ref, constant)
+                }
+                _ => continue,
+            };
+            if let Some(value) = Datum::from_scalar(constant.value.clone()) {
+                equalities
+                    .entry(column_ref.column.index)
+                    .or_insert((i, value));
+            }
+        }
+
+        let mut selectivity = None;
+        for group in self.input_stat.column_groups.iter() {
+            let Some(values) = group
+                .columns
+                .iter()
+                .map(|column| equalities.get(column).map(|(_, value)| value.clone()))
+                .collect::<Option<Vec<_>>>()
+            else {
+                continue;
+            };
+            let group_selectivity = group.equality_selectivity(&values);
+            selectivity =
+                Some(selectivity.map_or(group_selectivity, |s: f64| s.min(group_selectivity)));
+            covered_predicates.extend(group.columns.iter().map(|column| equalities[column].0));
+        }
+        (selectivity, covered_predicates)
+    }
+
     // The method uses probability predication to compute like selectivity.
     // The core idea is from postgresql.
     fn compute_like_selectivity(&mut self, func: &FunctionCall) -> Result<f64> {
@@ -15,13 +15,16 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use databend_common_ast::ast::SampleRowLevel;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::NumberScalar;
 use databend_common_expression::types::F64;
 use databend_common_expression::ColumnId;
 use databend_common_expression::Scalar;
 
+use crate::optimizer::ColumnGroupStat;
 use crate::optimizer::RelExpr;
 use crate::optimizer::SExpr;
 use crate::optimizer::StatInfo;
@@ -76,6 +79,7 @@ impl CollectStatisticsOptimizer {
 
                 let mut column_stats = HashMap::new();
                 let mut histograms = HashMap::new();
+                let mut column_indexes = HashMap::new();
                 for column in columns.iter() {
                     if let ColumnEntry::BaseTableColumn(BaseTableColumn {
                         column_index,
@@ -86,6 +90,7 @@ impl CollectStatisticsOptimizer {
                     {
                         if virtual_computed_expr.is_none() {
                             if let Some(column_id) = *column_id {
+                                column_indexes.insert(column_id as ColumnId, *column_index);
                                 let col_stat = column_statistics_provider
                                     .column_statistics(column_id as ColumnId);
                                 column_stats.insert(*column_index, col_stat.cloned());
@@ -97,52 +102,85 @@ impl CollectStatisticsOptimizer {
                     }
                 }
 
+                let column_groups = column_statistics_provider
+                    .column_groups()
+                    .iter()
+                    .filter_map(|group| {
+                        let columns = group
+                            .column_ids
+                            .iter()
+                            .map(|column_id| column_indexes.get(column_id).cloned())
+                            .collect::<Option<Vec<_>>>()?;
+                        Some(ColumnGroupStat {
+                            columns,
+                            ndv: group.ndv as f64,
+                            most_common_values: group.most_common_values.clone(),
+                        })
+                    })
+                    .collect();
+
                 let mut scan = scan.clone();
                 scan.statistics = Arc::new(Statistics {
                     table_stats,
                     column_stats,
                     histograms,
+                    column_groups,
                 });
-                let mut s_expr = s_expr.replace_plan(Arc::new(RelOperator::Scan(scan.clone())));
-                if let Some(sample) = &scan.sample {
+                let mut row_sample_probability = None;
+                if let Some(sample) = &mut scan.sample {
                     // Only process row-level sampling in optimizer phase.
                     if let Some(row_level) = &sample.row_level {
-                        if let Some(stats) = &table_stats
-                            && let Some(probability) =
-                                row_level.sample_probability(stats.num_rows)?
-                        {
-                            let rand_expr = ScalarExpr::FunctionCall(FunctionCall {
-                                span: None,
-                                func_name: "rand".to_string(),
-                                params: vec![],
-                                arguments: vec![],
-                            });
-                            let filter = ScalarExpr::FunctionCall(FunctionCall {
-                                span: None,
-                                func_name: "lte".to_string(),
-                                params: vec![],
-                                arguments: vec![
-                                    rand_expr,
-                                    ScalarExpr::ConstantExpr(ConstantExpr {
-                                        span: None,
-                                        value: Scalar::Number(NumberScalar::Float64(F64::from(
-                                            probability,
-                                        ))),
-                                    }),
-                                ],
-                            });
-                            s_expr = SExpr::create_unary(
-                                Arc::new(
-                                    Filter {
-                                        predicates: vec![filter],
-                                    }
-                                    .into(),
-                                ),
-                                Arc::new(s_expr),
-                            );
+                        let num_rows = table_stats.as_ref().and_then(|stats| stats.num_rows);
+                        if let Some(probability) = row_level.sample_probability(num_rows)? {
+                            if sample.seed.is_some() {
+                                // Seeded row-level sampling is evaluated by the table engine
+                                // while reading, so that it is repeatable.
+                                if !table.support_seeded_row_sample() {
+                                    return Err(ErrorCode::Unimplemented(format!(
+                                        "Table engine {} does not support REPEATABLE row-level sampling",
+                                        table.engine()
+                                    )));
+                                }
+                                sample.row_level =
+                                    Some(SampleRowLevel::Probability(probability * 100.0));
+                            } else {
+                                row_sample_probability = Some(probability);
+                            }
                         }
                     }
                 }
+                let mut s_expr = s_expr.replace_plan(Arc::new(RelOperator::Scan(scan.clone())));
+                if let Some(probability) = row_sample_probability {
+                    let rand_expr = ScalarExpr::FunctionCall(FunctionCall {
+                        span: None,
+                        func_name: "rand".to_string(),
+                        params: vec![],
+                        arguments: vec![],
+                    });
+                    let filter = ScalarExpr::FunctionCall(FunctionCall {
+                        span: None,
+                        func_name: "lte".to_string(),
+                        params: vec![],
+                        arguments: vec![
+                            rand_expr,
+                            ScalarExpr::ConstantExpr(ConstantExpr {
+                                span: None,
+                                value: Scalar::Number(NumberScalar::Float64(F64::from(
+                                    probability,
+                                ))),
+                            }),
+                        ],
+                    });
+                    s_expr = SExpr::create_unary(
+                        Arc::new(
+                            Filter {
+                                predicates: vec![filter],
+                            }
+                            .into(),
+                        ),
+                        Arc::new(s_expr),
+                    );
+                }
                 Ok(s_expr)
             }
             RelOperator::MaterializedCte(materialized_cte) => {
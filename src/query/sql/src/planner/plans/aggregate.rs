@@ -154,6 +154,7 @@ impl Aggregate {
             statistics: Statistics {
                 precise_cardinality,
                 column_stats: statistics.column_stats,
+                column_groups: vec![],
             },
         }))
     }
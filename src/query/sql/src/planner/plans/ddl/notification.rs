@@ -121,3 +121,18 @@ impl DescNotificationPlan {
         notification_schema()
     }
 }
+
+/// Send a message through a notification integration, e.g.
+/// `CALL system$send_notification('my_webhook', '{"text": "hello"}')`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SendNotificationPlan {
+    pub tenant: Tenant,
+    pub name: String,
+    pub message: String,
+}
+
+impl SendNotificationPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
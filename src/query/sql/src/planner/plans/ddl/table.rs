@@ -17,6 +17,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use databend_common_ast::ast::Engine;
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::DataField;
@@ -27,6 +28,7 @@ use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::SnapshotRefType;
 use databend_common_meta_app::schema::TableIndex;
 use databend_common_meta_app::schema::TableNameIdent;
 use databend_common_meta_app::schema::UndropTableReq;
@@ -50,11 +52,22 @@ pub struct CreateTablePlan {
     pub engine: Engine,
     pub engine_options: TableOptions,
     pub storage_params: Option<StorageParams>,
+    pub cold_storage_params: Option<StorageParams>,
     pub options: TableOptions,
     pub field_comments: Vec<String>,
     pub cluster_key: Option<String>,
     pub as_select: Option<Box<Plan>>,
     pub inverted_indexes: Option<BTreeMap<String, TableIndex>>,
+    pub clone_source: Option<CloneTableSource>,
+}
+
+/// The table and point in its history that `CREATE TABLE ... CLONE` shares data with.
+#[derive(Clone, Debug)]
+pub struct CloneTableSource {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub point: Option<NavigationPoint>,
 }
 
 impl CreateTablePlan {
@@ -200,6 +213,8 @@ pub struct AnalyzeTablePlan {
     pub catalog: String,
     pub database: String,
     pub table: String,
+    /// Groups of columns to collect joint statistics for.
+    pub column_groups: Vec<Vec<String>>,
 }
 
 impl AnalyzeTablePlan {
@@ -241,6 +256,76 @@ impl ModifyTableCommentPlan {
     }
 }
 
+/// Create a branch or tag of a table.
+#[derive(Clone, Debug)]
+pub struct CreateTableRefPlan {
+    pub tenant: Tenant,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub typ: SnapshotRefType,
+    pub name: String,
+    pub if_not_exists: bool,
+    pub point: Option<NavigationPoint>,
+}
+
+impl CreateTableRefPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+/// Drop a branch or tag of a table.
+#[derive(Clone, Debug)]
+pub struct DropTableRefPlan {
+    pub tenant: Tenant,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub typ: SnapshotRefType,
+    pub name: String,
+    pub if_exists: bool,
+}
+
+impl DropTableRefPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+/// Attach a row access policy to a table.
+#[derive(Clone, Debug)]
+pub struct AddTableRowAccessPolicyPlan {
+    pub tenant: Tenant,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub policy: String,
+    pub columns: Vec<String>,
+}
+
+impl AddTableRowAccessPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
+/// Detach a row access policy from a table.
+#[derive(Clone, Debug)]
+pub struct DropTableRowAccessPolicyPlan {
+    pub tenant: Tenant,
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    pub policy: String,
+}
+
+impl DropTableRowAccessPolicyPlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        Arc::new(DataSchema::empty())
+    }
+}
+
 /// SetOptions
 #[derive(Clone, Debug)]
 pub struct SetOptionsPlan {
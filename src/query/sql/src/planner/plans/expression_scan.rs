@@ -96,6 +96,7 @@ impl Operator for ExpressionScan {
             statistics: Statistics {
                 precise_cardinality: None,
                 column_stats: Default::default(),
+                column_groups: vec![],
             },
         }))
     }
@@ -57,6 +57,8 @@ pub struct Insert {
     // it should be provided as some `table_info`.
     // otherwise, the table being inserted will be resolved by using `catalog`.`database`.`table`
     pub table_info: Option<TableInfo>,
+    // the branch of the table that the rows are inserted into, if any.
+    pub branch: Option<String>,
 }
 
 impl PartialEq for Insert {
@@ -92,6 +94,7 @@ impl Insert {
             overwrite,
             // table_info only used create table as select.
             table_info: _,
+            branch,
             source,
         } = self;
 
@@ -103,11 +106,14 @@ impl Insert {
             .collect::<Vec<_>>()
             .join(",");
 
-        let children = vec![
+        let mut children = vec![
             FormatTreeNode::new(format!("table: {table_name}")),
             FormatTreeNode::new(format!("inserted columns: [{inserted_columns}]")),
             FormatTreeNode::new(format!("overwrite: {overwrite}")),
         ];
+        if let Some(branch) = branch {
+            children.push(FormatTreeNode::new(format!("branch: {branch}")));
+        }
 
         let formatted_plan = format_insert_source("InsertPlan", source, verbose, children)?;
 
@@ -214,6 +220,7 @@ impl std::fmt::Debug for Insert {
             .field("table", &self.table)
             .field("schema", &self.schema)
             .field("overwrite", &self.overwrite)
+            .field("branch", &self.branch)
             .finish()
     }
 }
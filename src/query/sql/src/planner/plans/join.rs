@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::cmp::max;
+use std::cmp::Reverse;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::fmt::Display;
@@ -28,6 +29,7 @@ use databend_common_storage::HistogramBucket;
 use databend_common_storage::DEFAULT_HISTOGRAM_BUCKETS;
 
 use crate::optimizer::histogram_from_ndv;
+use crate::optimizer::ColumnGroupStat;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::ColumnStat;
 use crate::optimizer::Distribution;
@@ -352,6 +354,18 @@ impl Join {
                 _ => continue,
             }
         }
+        if join_card != 0.0 {
+            // The per-key estimation above assumes the join keys are independent, use the
+            // joint ndv of the keys instead if there are column group statistics.
+            if let Some(card) = self.composite_keys_cardinality(
+                *left_cardinality,
+                *right_cardinality,
+                left_statistics,
+                right_statistics,
+            ) {
+                join_card = join_card.min(card);
+            }
+        }
         if join_card_updated {
             for (idx, left) in left_statistics.column_stats.iter_mut() {
                 if *idx == left_column_index {
@@ -414,6 +428,40 @@ impl Join {
         Ok(join_card)
     }
 
+    fn composite_keys_cardinality(
+        &self,
+        left_cardinality: f64,
+        right_cardinality: f64,
+        left_statistics: &Statistics,
+        right_statistics: &Statistics,
+    ) -> Option<f64> {
+        let mut left_keys = Vec::new();
+        let mut right_keys = Vec::new();
+        for condition in self.equi_conditions.iter() {
+            if let (ScalarExpr::BoundColumnRef(left), ScalarExpr::BoundColumnRef(right)) =
+                (&condition.left, &condition.right)
+            {
+                left_keys.push(left.column.index);
+                right_keys.push(right.column.index);
+            }
+        }
+        if left_keys.len() < 2 {
+            return None;
+        }
+        let (left_ndv, left_by_group) = joint_ndv(&left_keys, left_statistics, left_cardinality)?;
+        let (right_ndv, right_by_group) =
+            joint_ndv(&right_keys, right_statistics, right_cardinality)?;
+        if !left_by_group && !right_by_group {
+            return None;
+        }
+        let max_ndv = f64::max(left_ndv, right_ndv);
+        if max_ndv == 0.0 {
+            Some(0.0)
+        } else {
+            Some(left_cardinality * right_cardinality / max_ndv)
+        }
+    }
+
     pub fn has_null_equi_condition(&self) -> bool {
         self.equi_conditions
             .iter()
@@ -456,19 +504,22 @@ impl Join {
             JoinType::RightSingle | JoinType::LeftMark | JoinType::RightAnti => right_cardinality,
         };
         // Derive column statistics
-        let column_stats = if cardinality == 0.0 {
-            HashMap::new()
+        let (column_stats, column_groups) = if cardinality == 0.0 {
+            (HashMap::new(), vec![])
         } else {
             let mut column_stats = HashMap::new();
             column_stats.extend(left_statistics.column_stats);
             column_stats.extend(right_statistics.column_stats);
-            column_stats
+            let mut column_groups = left_statistics.column_groups;
+            column_groups.extend(right_statistics.column_groups);
+            (column_stats, column_groups)
         };
         Ok(Arc::new(StatInfo {
             cardinality,
             statistics: Statistics {
                 precise_cardinality: None,
                 column_stats,
+                column_groups,
             },
         }))
     }
@@ -879,6 +930,38 @@ fn evaluate_by_ndv(
     }
 }
 
+// Returns the joint ndv of the columns, and whether column group statistics are used.
+// Columns not covered by any column group are assumed to be independent.
+fn joint_ndv(
+    columns: &[IndexType],
+    statistics: &Statistics,
+    cardinality: f64,
+) -> Option<(f64, bool)> {
+    let mut remaining: HashSet<IndexType> = columns.iter().cloned().collect();
+    let mut groups: Vec<&ColumnGroupStat> = statistics
+        .column_groups
+        .iter()
+        .filter(|group| group.columns.iter().all(|c| remaining.contains(c)))
+        .collect();
+    groups.sort_by_key(|group| Reverse(group.columns.len()));
+
+    let mut ndv = 1.0;
+    let mut by_group = false;
+    for group in groups {
+        if group.columns.iter().all(|c| remaining.contains(c)) {
+            ndv *= group.ndv;
+            by_group = true;
+            for column in group.columns.iter() {
+                remaining.remove(column);
+            }
+        }
+    }
+    for column in remaining {
+        ndv *= statistics.column_stats.get(&column)?.ndv;
+    }
+    Some((ndv.min(cardinality.max(1.0)), by_group))
+}
+
 fn update_statistic(
     left_statistics: &mut Statistics,
     right_statistics: &mut Statistics,
@@ -51,6 +51,7 @@ impl Limit {
             statistics: Statistics {
                 precise_cardinality,
                 column_stats: Default::default(),
+                column_groups: vec![],
             },
         }))
     }
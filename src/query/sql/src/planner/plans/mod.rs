@@ -44,6 +44,7 @@ mod r_cte_scan;
 mod recluster;
 mod replace;
 mod revert_table;
+mod row_access_policy;
 mod scalar_expr;
 mod scan;
 mod set;
@@ -91,6 +92,7 @@ pub use r_cte_scan::*;
 pub use recluster::Recluster;
 pub use replace::Replace;
 pub use revert_table::RevertTablePlan;
+pub use row_access_policy::*;
 pub use scalar_expr::*;
 pub use scan::*;
 pub use set::*;
@@ -83,6 +83,9 @@ pub struct Mutation {
     pub predicate_column_index: Option<usize>,
     pub truncate_table: bool,
     pub direct_filter: Option<ScalarExpr>,
+
+    // The branch of the target table that is mutated, if any.
+    pub branch: Option<String>,
 }
 
 impl std::fmt::Debug for Mutation {
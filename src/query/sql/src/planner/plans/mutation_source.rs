@@ -94,6 +94,7 @@ impl Operator for MutationSource {
             statistics: OpStatistics {
                 precise_cardinality: None,
                 column_stats: Default::default(),
+                column_groups: vec![],
             },
         }))
     }
@@ -31,6 +31,7 @@ use itertools::Itertools;
 
 use super::ScalarItem;
 use crate::optimizer::histogram_from_ndv;
+use crate::optimizer::ColumnGroupStat;
 use crate::optimizer::ColumnSet;
 use crate::optimizer::ColumnStat;
 use crate::optimizer::ColumnStatSet;
@@ -42,7 +43,6 @@ use crate::optimizer::RequiredProperty;
 use crate::optimizer::SelectivityEstimator;
 use crate::optimizer::StatInfo;
 use crate::optimizer::Statistics as OpStatistics;
-use crate::optimizer::MAX_SELECTIVITY;
 use crate::plans::Operator;
 use crate::plans::RelOp;
 use crate::plans::ScalarExpr;
@@ -89,6 +89,7 @@ pub struct Statistics {
     // statistics will be ignored in comparison and hashing
     pub column_stats: HashMap<IndexType, Option<BasicColumnStatistics>>,
     pub histograms: HashMap<IndexType, Option<Histogram>>,
+    pub column_groups: Vec<ColumnGroupStat>,
 }
 
 #[derive(Clone, Debug, Default)]
@@ -129,6 +130,14 @@ impl Scan {
             .map(|(col, hist)| (*col, hist.clone()))
             .collect();
 
+        let column_groups = self
+            .statistics
+            .column_groups
+            .iter()
+            .filter(|group| group.columns.iter().all(|col| columns.contains(col)))
+            .cloned()
+            .collect();
+
         Scan {
             table_index: self.table_index,
             columns,
@@ -139,6 +148,7 @@ impl Scan {
                 table_stats: self.statistics.table_stats,
                 column_stats,
                 histograms,
+                column_groups,
             }),
             prewhere,
             agg_index: self.agg_index.clone(),
@@ -258,6 +268,14 @@ impl Operator for Scan {
             }
         }
 
+        let mut column_groups = self
+            .statistics
+            .column_groups
+            .iter()
+            .filter(|group| group.columns.iter().all(|col| used_columns.contains(col)))
+            .cloned()
+            .collect();
+
         let precise_cardinality = self
             .statistics
             .table_stats
@@ -269,6 +287,7 @@ impl Operator for Scan {
                 let mut statistics = OpStatistics {
                     precise_cardinality: Some(precise_cardinality),
                     column_stats,
+                    column_groups,
                 };
                 // Derive cardinality
                 let mut sb = SelectivityEstimator::new(
@@ -276,14 +295,11 @@ impl Operator for Scan {
                     precise_cardinality as f64,
                     HashSet::new(),
                 );
-                let mut selectivity = MAX_SELECTIVITY;
-                for pred in prewhere.predicates.iter() {
-                    // Compute selectivity for each conjunction
-                    selectivity = selectivity.min(sb.compute_selectivity(pred, true)?);
-                }
+                let selectivity = sb.compute_conjunction_selectivity(&prewhere.predicates)?;
                 // Update other columns's statistic according to selectivity.
                 sb.update_other_statistic_by_selectivity(selectivity);
                 column_stats = statistics.column_stats;
+                column_groups = statistics.column_groups;
                 (precise_cardinality as f64) * selectivity
             }
             (Some(precise_cardinality), None) => precise_cardinality as f64,
@@ -301,6 +317,7 @@ impl Operator for Scan {
             statistics: OpStatistics {
                 precise_cardinality,
                 column_stats,
+                column_groups,
             },
         }))
     }
@@ -78,6 +78,7 @@ impl UnionAll {
             statistics: Statistics {
                 precise_cardinality,
                 column_stats: Default::default(),
+                column_groups: vec![],
             },
         }))
     }
@@ -68,6 +68,7 @@ use databend_common_expression::types::decimal::DecimalSize;
 use databend_common_expression::types::decimal::MAX_DECIMAL128_PRECISION;
 use databend_common_expression::types::decimal::MAX_DECIMAL256_PRECISION;
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::EnumItems;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberScalar;
 use databend_common_expression::types::F32;
@@ -1006,12 +1007,18 @@ impl<'a> TypeChecker<'a> {
                 span, kind, expr, ..
             } => self.resolve_extract_expr(*span, kind, expr)?,
 
-            Expr::Interval { span, .. } => {
-                return Err(ErrorCode::SemanticError(
-                    "Unsupported interval expression yet".to_string(),
-                )
-                .set_span(*span));
-            }
+            Expr::Interval { span, expr, unit } => match unit {
+                ASTIntervalKind::Doy | ASTIntervalKind::Dow => {
+                    return Err(ErrorCode::SemanticError(format!(
+                        "Unsupported interval unit {unit}"
+                    ))
+                    .set_span(*span));
+                }
+                _ => {
+                    let func_name = format!("to_{}s", unit.to_string().to_lowercase());
+                    self.resolve_function(*span, &func_name, vec![], &[expr.as_ref()])?
+                }
+            },
             Expr::DateAdd {
                 span,
                 unit,
@@ -1716,6 +1723,31 @@ impl<'a> TypeChecker<'a> {
             params
         };
 
+        // Convert the k of approx_top_k to params, also for its `_state` combinator,
+        // so that the states can be merged by `approx_top_k_merge(k)`.
+        let params = if matches!(
+            func_name.to_lowercase().as_str(),
+            "approx_top_k" | "approx_top_k_state"
+        ) && arguments.len() == 2
+            && params.is_empty()
+        {
+            let k = ConstantExpr::try_from(arguments[1].clone());
+
+            let is_positive_integer = match &k {
+                Ok(v) => v.value.is_positive(),
+                Err(_) => false,
+            } && arg_types[1].is_integer();
+            if !is_positive_integer {
+                return Err(ErrorCode::SemanticError(
+                    "The k of `approx_top_k` must be a constant positive int",
+                ));
+            }
+
+            vec![k.unwrap().value]
+        } else {
+            params
+        };
+
         // Rewrite `xxx(distinct)` to `xxx_distinct(...)`
         let (func_name, distinct) = if func_name.eq_ignore_ascii_case("count") && distinct {
             ("count_distinct", false)
@@ -4968,6 +5000,11 @@ pub fn resolve_type_name(type_name: &TypeName, not_null: bool) -> Result<TableDa
         TypeName::Variant => TableDataType::Variant,
         TypeName::Geometry => TableDataType::Geometry,
         TypeName::Geography => TableDataType::Geography,
+        TypeName::Interval => TableDataType::Interval,
+        TypeName::TimestampTz => TableDataType::TimestampTz,
+        TypeName::Time => TableDataType::Time,
+        TypeName::Uuid => TableDataType::Uuid,
+        TypeName::Enum(items) => TableDataType::Enum(EnumItems::try_create(items.clone())?),
         TypeName::NotNull(inner_type) => {
             let data_type = resolve_type_name(inner_type, not_null)?;
             data_type.remove_nullable()
@@ -22,6 +22,7 @@ mod manager;
 mod providers;
 mod read;
 mod temp_dir;
+mod warm_up;
 
 pub use cache::CacheAccessor;
 pub use cache::Unit;
@@ -37,6 +38,7 @@ pub use providers::InMemoryLruCache;
 pub use providers::LruDiskCache;
 pub use providers::LruDiskCacheBuilder;
 pub use providers::LruDiskCacheHolder;
+pub use providers::PolicyCache;
 pub use providers::TableDataCache;
 pub use providers::TableDataCacheBuilder;
 pub use providers::TableDataCacheKey;
@@ -48,3 +50,5 @@ pub use read::InMemoryItemCacheReader;
 pub use read::LoadParams;
 pub use read::Loader;
 pub use temp_dir::*;
+pub use warm_up::CacheWarmUpRecorder;
+pub use warm_up::HotTable;
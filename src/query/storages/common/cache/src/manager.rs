@@ -17,6 +17,7 @@ use std::sync::Arc;
 
 use databend_common_base::base::GlobalInstance;
 use databend_common_config::CacheConfig;
+use databend_common_config::CacheEvictionPolicy;
 use databend_common_config::CacheStorageTypeInnerConfig;
 use databend_common_config::DiskCacheKeyReloadPolicy;
 use databend_common_exception::Result;
@@ -34,9 +35,11 @@ use crate::caches::ParquetMetaDataCache;
 use crate::caches::PrunePartitionsCache;
 use crate::caches::TableSnapshotCache;
 use crate::caches::TableSnapshotStatisticCache;
+use crate::CacheWarmUpRecorder;
 use crate::InMemoryLruCache;
 use crate::TableDataCache;
 use crate::TableDataCacheBuilder;
+use crate::DISK_TABLE_DATA_CACHE_NAME;
 
 static DEFAULT_PARQUET_META_DATA_CACHE_ITEMS: usize = 3000;
 
@@ -54,6 +57,7 @@ pub struct CacheManager {
     table_data_cache: Option<TableDataCache>,
     in_memory_table_data_cache: Option<ColumnArrayCache>,
     block_meta_cache: Option<BlockMetaCache>,
+    warm_up_recorder: Option<Arc<CacheWarmUpRecorder>>,
 }
 
 impl CacheManager {
@@ -94,6 +98,7 @@ impl CacheManager {
                         config.disk_cache_config.max_bytes as usize,
                         config.data_cache_key_reload_policy.clone(),
                         config.disk_cache_config.sync_data,
+                        config.eviction_policy_of(DISK_TABLE_DATA_CACHE_NAME),
                     )?
                 }
             }
@@ -109,8 +114,20 @@ impl CacheManager {
         };
 
         // Cache of deserialized table data
-        let in_memory_table_data_cache =
-            Self::new_named_bytes_cache(MEMORY_CACHE_TABLE_DATA, memory_cache_capacity);
+        let in_memory_table_data_cache = Self::new_named_bytes_cache(
+            MEMORY_CACHE_TABLE_DATA,
+            memory_cache_capacity,
+            config.eviction_policy_of(MEMORY_CACHE_TABLE_DATA),
+        );
+
+        // setup the recorder of hot tables, whose caches will be warmed up after restart
+        let warm_up_recorder = if config.warm_up.enable {
+            Some(Arc::new(CacheWarmUpRecorder::new(
+                config.warm_up.max_tables as usize,
+            )))
+        } else {
+            None
+        };
 
         // setup in-memory table meta cache
         if !config.enable_table_meta_cache {
@@ -127,31 +144,38 @@ impl CacheManager {
                 table_data_cache,
                 in_memory_table_data_cache,
                 block_meta_cache: None,
+                warm_up_recorder,
             }));
         } else {
             let table_snapshot_cache = Self::new_named_items_cache(
                 config.table_meta_snapshot_count as usize,
                 MEMORY_CACHE_TABLE_SNAPSHOT,
+                config.eviction_policy_of(MEMORY_CACHE_TABLE_SNAPSHOT),
             );
             let table_statistic_cache = Self::new_named_items_cache(
                 config.table_meta_statistic_count as usize,
                 MEMORY_CACHE_TABLE_STATISTICS,
+                config.eviction_policy_of(MEMORY_CACHE_TABLE_STATISTICS),
             );
             let compact_segment_info_cache = Self::new_named_bytes_cache(
                 MEMORY_CACHE_COMPACT_SEGMENT_INFO,
                 config.table_meta_segment_bytes as usize,
+                config.eviction_policy_of(MEMORY_CACHE_COMPACT_SEGMENT_INFO),
             );
             let bloom_index_filter_cache = Self::new_named_bytes_cache(
                 MEMORY_CACHE_BLOOM_INDEX_FILTER,
                 config.table_bloom_index_filter_size as usize,
+                config.eviction_policy_of(MEMORY_CACHE_BLOOM_INDEX_FILTER),
             );
             let bloom_index_meta_cache = Self::new_named_items_cache(
                 config.table_bloom_index_meta_count as usize,
                 MEMORY_CACHE_BLOOM_INDEX_FILE_META_DATA,
+                config.eviction_policy_of(MEMORY_CACHE_BLOOM_INDEX_FILE_META_DATA),
             );
             let inverted_index_meta_cache = Self::new_named_items_cache(
                 config.inverted_index_meta_count as usize,
                 MEMORY_CACHE_INVERTED_INDEX_FILE_META_DATA,
+                config.eviction_policy_of(MEMORY_CACHE_INVERTED_INDEX_FILE_META_DATA),
             );
 
             // setup in-memory inverted index filter cache
@@ -165,20 +189,24 @@ impl CacheManager {
             let inverted_index_file_cache = Self::new_named_bytes_cache(
                 MEMORY_CACHE_INVERTED_INDEX_FILE,
                 inverted_index_file_size,
+                config.eviction_policy_of(MEMORY_CACHE_INVERTED_INDEX_FILE),
             );
             let prune_partitions_cache = Self::new_named_items_cache(
                 config.table_prune_partitions_count as usize,
                 MEMORY_CACHE_PRUNE_PARTITIONS,
+                config.eviction_policy_of(MEMORY_CACHE_PRUNE_PARTITIONS),
             );
 
             let parquet_meta_data_cache = Self::new_named_items_cache(
                 DEFAULT_PARQUET_META_DATA_CACHE_ITEMS,
                 MEMORY_CACHE_PARQUET_META_DATA,
+                config.eviction_policy_of(MEMORY_CACHE_PARQUET_META_DATA),
             );
 
             let block_meta_cache = Self::new_named_items_cache(
                 config.block_meta_count as usize,
                 MEMORY_CACHE_BLOCK_META,
+                config.eviction_policy_of(MEMORY_CACHE_BLOCK_META),
             );
 
             GlobalInstance::set(Arc::new(Self {
@@ -194,6 +222,7 @@ impl CacheManager {
                 in_memory_table_data_cache,
                 block_meta_cache,
                 parquet_meta_data_cache,
+                warm_up_recorder,
             }));
         }
 
@@ -252,25 +281,36 @@ impl CacheManager {
         self.in_memory_table_data_cache.clone()
     }
 
+    pub fn get_warm_up_recorder(&self) -> Option<Arc<CacheWarmUpRecorder>> {
+        self.warm_up_recorder.clone()
+    }
+
     pub fn new_named_items_cache<V: Into<CacheValue<V>>>(
         capacity: usize,
         name: impl Into<String>,
+        policy: CacheEvictionPolicy,
     ) -> Option<InMemoryLruCache<V>> {
         match capacity {
             0 => None,
-            _ => Some(InMemoryLruCache::with_items_capacity(name.into(), capacity)),
+            _ => Some(InMemoryLruCache::with_items_capacity_and_policy(
+                name.into(),
+                capacity,
+                policy,
+            )),
         }
     }
 
     fn new_named_bytes_cache<V: Into<CacheValue<V>>>(
         name: impl Into<String>,
         bytes_capacity: usize,
+        policy: CacheEvictionPolicy,
     ) -> Option<InMemoryLruCache<V>> {
         match bytes_capacity {
             0 => None,
-            _ => Some(InMemoryLruCache::with_bytes_capacity(
+            _ => Some(InMemoryLruCache::with_bytes_capacity_and_policy(
                 name.into(),
                 bytes_capacity,
+                policy,
             )),
         }
     }
@@ -281,6 +321,7 @@ impl CacheManager {
         disk_cache_bytes_size: usize,
         disk_cache_key_reload_policy: DiskCacheKeyReloadPolicy,
         sync_data: bool,
+        eviction_policy: CacheEvictionPolicy,
     ) -> Result<Option<TableDataCache>> {
         if disk_cache_bytes_size > 0 {
             let cache_holder = TableDataCacheBuilder::new_table_data_disk_cache(
@@ -289,6 +330,7 @@ impl CacheManager {
                 disk_cache_bytes_size,
                 disk_cache_key_reload_policy,
                 sync_data,
+                eviction_policy,
             )?;
             Ok(Some(cache_holder))
         } else {
@@ -24,7 +24,7 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use databend_common_cache::Cache;
-use databend_common_cache::LruCache;
+use databend_common_config::CacheEvictionPolicy;
 use databend_common_config::DiskCacheKeyReloadPolicy;
 use databend_common_exception::Result;
 use log::error;
@@ -36,9 +36,10 @@ use rayon::ThreadPoolBuilder;
 
 use crate::CacheValue;
 use crate::DiskCacheKey;
+use crate::PolicyCache;
 
 pub struct DiskCache {
-    cache: LruCache<String, CacheValue<FileSize>>,
+    cache: PolicyCache<String, CacheValue<FileSize>>,
     root: PathBuf,
     sync_data: bool,
 }
@@ -59,11 +60,31 @@ impl DiskCache {
         disk_cache_key_reload_policy: DiskCacheKeyReloadPolicy,
         sync_data: bool,
     ) -> self::io_result::Result<Self>
+    where
+        PathBuf: From<T>,
+    {
+        Self::new_with_policy(
+            path,
+            size,
+            disk_cache_key_reload_policy,
+            sync_data,
+            CacheEvictionPolicy::Lru,
+        )
+    }
+
+    /// Create an `DiskCache` like [`DiskCache::new`], which evicts files by `eviction_policy`.
+    pub fn new_with_policy<T>(
+        path: T,
+        size: usize,
+        disk_cache_key_reload_policy: DiskCacheKeyReloadPolicy,
+        sync_data: bool,
+        eviction_policy: CacheEvictionPolicy,
+    ) -> self::io_result::Result<Self>
     where
         PathBuf: From<T>,
     {
         DiskCache {
-            cache: LruCache::<String, CacheValue<FileSize>>::with_bytes_capacity(size),
+            cache: PolicyCache::with_bytes_capacity(eviction_policy, size),
             root: PathBuf::from(path),
             sync_data,
         }
@@ -97,6 +118,15 @@ impl DiskCache {
         self.cache.bytes_capacity()
     }
 
+    pub fn eviction_policy(&self) -> CacheEvictionPolicy {
+        self.cache.policy()
+    }
+
+    /// Return the number of admitted and rejected files.
+    pub fn admission_stats(&self) -> (u64, u64) {
+        self.cache.admission_stats()
+    }
+
     /// Return the path in which the cache is stored.
     pub fn path(&self) -> &Path {
         self.root.as_path()
@@ -18,6 +18,7 @@ use std::path::PathBuf;
 use std::sync::Arc;
 
 use bytes::Bytes;
+use databend_common_config::CacheEvictionPolicy;
 use databend_common_config::DiskCacheKeyReloadPolicy;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -173,12 +174,14 @@ impl LruDiskCacheBuilder {
         disk_cache_bytes_size: usize,
         disk_cache_reload_policy: DiskCacheKeyReloadPolicy,
         sync_data: bool,
+        eviction_policy: CacheEvictionPolicy,
     ) -> Result<LruDiskCacheHolder> {
-        let external_cache = DiskCache::new(
+        let external_cache = DiskCache::new_with_policy(
             path,
             disk_cache_bytes_size,
             disk_cache_reload_policy,
             sync_data,
+            eviction_policy,
         )
         .map_err(|e| ErrorCode::StorageOther(format!("create disk cache failed, {e}")))?;
         Ok(Arc::new(RwLock::new(external_cache)))
@@ -15,16 +15,17 @@
 use std::sync::Arc;
 
 use databend_common_cache::Cache;
-use databend_common_cache::LruCache;
+use databend_common_config::CacheEvictionPolicy;
 use parking_lot::RwLock;
 
 use crate::caches::CacheValue;
+use crate::providers::policy_cache::PolicyCache;
 use crate::Unit;
 
 pub struct InMemoryLruCache<V: Into<CacheValue<V>>> {
     unit: Unit,
     name: String,
-    inner: Arc<RwLock<LruCache<String, CacheValue<V>>>>,
+    inner: Arc<RwLock<PolicyCache<String, CacheValue<V>>>>,
 }
 
 impl<V: Into<CacheValue<V>>> Clone for InMemoryLruCache<V> {
@@ -39,18 +40,40 @@ impl<V: Into<CacheValue<V>>> Clone for InMemoryLruCache<V> {
 
 impl<V: Into<CacheValue<V>>> InMemoryLruCache<V> {
     pub fn with_items_capacity(name: String, items_capacity: usize) -> Self {
+        Self::with_items_capacity_and_policy(name, items_capacity, CacheEvictionPolicy::Lru)
+    }
+
+    pub fn with_bytes_capacity(name: String, bytes_capacity: usize) -> Self {
+        Self::with_bytes_capacity_and_policy(name, bytes_capacity, CacheEvictionPolicy::Lru)
+    }
+
+    pub fn with_items_capacity_and_policy(
+        name: String,
+        items_capacity: usize,
+        policy: CacheEvictionPolicy,
+    ) -> Self {
         Self {
             name,
             unit: Unit::Count,
-            inner: Arc::new(RwLock::new(LruCache::with_items_capacity(items_capacity))),
+            inner: Arc::new(RwLock::new(PolicyCache::with_items_capacity(
+                policy,
+                items_capacity,
+            ))),
         }
     }
 
-    pub fn with_bytes_capacity(name: String, bytes_capacity: usize) -> Self {
+    pub fn with_bytes_capacity_and_policy(
+        name: String,
+        bytes_capacity: usize,
+        policy: CacheEvictionPolicy,
+    ) -> Self {
         Self {
             unit: Unit::Bytes,
             name,
-            inner: Arc::new(RwLock::new(LruCache::with_bytes_capacity(bytes_capacity))),
+            inner: Arc::new(RwLock::new(PolicyCache::with_bytes_capacity(
+                policy,
+                bytes_capacity,
+            ))),
         }
     }
 
@@ -61,6 +84,15 @@ impl<V: Into<CacheValue<V>>> InMemoryLruCache<V> {
     pub fn unit(&self) -> Unit {
         self.unit
     }
+
+    pub fn eviction_policy(&self) -> CacheEvictionPolicy {
+        self.inner.read().policy()
+    }
+
+    /// Returns the number of admitted and rejected items.
+    pub fn admission_stats(&self) -> (u64, u64) {
+        self.inner.read().admission_stats()
+    }
 }
 
 // default impls
@@ -0,0 +1,174 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Borrow;
+use std::hash::Hash;
+
+use databend_common_cache::Cache;
+use databend_common_cache::LruCache;
+use databend_common_cache::MemSized;
+use databend_common_cache::S3FifoCache;
+use databend_common_config::CacheEvictionPolicy;
+
+/// A cache that evicts items by the configured [`CacheEvictionPolicy`].
+pub enum PolicyCache<K: Eq + Hash + MemSized + Clone, V: MemSized> {
+    Lru(LruCache<K, V>),
+    S3Fifo(S3FifoCache<K, V>),
+}
+
+impl<K: Eq + Hash + MemSized + Clone, V: MemSized> PolicyCache<K, V> {
+    pub fn with_items_capacity(policy: CacheEvictionPolicy, items_capacity: usize) -> Self {
+        match policy {
+            CacheEvictionPolicy::Lru => Self::Lru(LruCache::with_items_capacity(items_capacity)),
+            CacheEvictionPolicy::S3Fifo => {
+                Self::S3Fifo(S3FifoCache::with_items_capacity(items_capacity))
+            }
+        }
+    }
+
+    pub fn with_bytes_capacity(policy: CacheEvictionPolicy, bytes_capacity: usize) -> Self {
+        match policy {
+            CacheEvictionPolicy::Lru => Self::Lru(LruCache::with_bytes_capacity(bytes_capacity)),
+            CacheEvictionPolicy::S3Fifo => {
+                Self::S3Fifo(S3FifoCache::with_bytes_capacity(bytes_capacity))
+            }
+        }
+    }
+
+    pub fn policy(&self) -> CacheEvictionPolicy {
+        match self {
+            PolicyCache::Lru(_) => CacheEvictionPolicy::Lru,
+            PolicyCache::S3Fifo(_) => CacheEvictionPolicy::S3Fifo,
+        }
+    }
+
+    /// Returns the number of admitted and rejected items.
+    ///
+    /// Caches without admission control admit all the items.
+    pub fn admission_stats(&self) -> (u64, u64) {
+        match self {
+            PolicyCache::Lru(_) => (0, 0),
+            PolicyCache::S3Fifo(cache) => (cache.admitted(), cache.rejected()),
+        }
+    }
+}
+
+impl<K: Eq + Hash + MemSized + Clone, V: MemSized> Cache<K, V> for PolicyCache<K, V> {
+    fn get<Q>(&mut self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self {
+            PolicyCache::Lru(cache) => cache.get(k),
+            PolicyCache::S3Fifo(cache) => cache.get(k),
+        }
+    }
+
+    fn peek<Q>(&self, k: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self {
+            PolicyCache::Lru(cache) => cache.peek(k),
+            PolicyCache::S3Fifo(cache) => cache.peek(k),
+        }
+    }
+
+    fn peek_by_policy(&self) -> Option<(&K, &V)> {
+        match self {
+            PolicyCache::Lru(cache) => cache.peek_by_policy(),
+            PolicyCache::S3Fifo(cache) => cache.peek_by_policy(),
+        }
+    }
+
+    fn insert(&mut self, k: K, v: V) -> Option<V> {
+        match self {
+            PolicyCache::Lru(cache) => cache.insert(k, v),
+            PolicyCache::S3Fifo(cache) => cache.insert(k, v),
+        }
+    }
+
+    fn pop<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self {
+            PolicyCache::Lru(cache) => cache.pop(k),
+            PolicyCache::S3Fifo(cache) => cache.pop(k),
+        }
+    }
+
+    fn pop_by_policy(&mut self) -> Option<(K, V)> {
+        match self {
+            PolicyCache::Lru(cache) => cache.pop_by_policy(),
+            PolicyCache::S3Fifo(cache) => cache.pop_by_policy(),
+        }
+    }
+
+    fn contains<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self {
+            PolicyCache::Lru(cache) => cache.contains(k),
+            PolicyCache::S3Fifo(cache) => cache.contains(k),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            PolicyCache::Lru(cache) => cache.len(),
+            PolicyCache::S3Fifo(cache) => cache.len(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            PolicyCache::Lru(cache) => cache.is_empty(),
+            PolicyCache::S3Fifo(cache) => cache.is_empty(),
+        }
+    }
+
+    fn bytes_capacity(&self) -> u64 {
+        match self {
+            PolicyCache::Lru(cache) => cache.bytes_capacity(),
+            PolicyCache::S3Fifo(cache) => cache.bytes_capacity(),
+        }
+    }
+
+    fn items_capacity(&self) -> u64 {
+        match self {
+            PolicyCache::Lru(cache) => cache.items_capacity(),
+            PolicyCache::S3Fifo(cache) => cache.items_capacity(),
+        }
+    }
+
+    fn bytes_size(&self) -> u64 {
+        match self {
+            PolicyCache::Lru(cache) => cache.bytes_size(),
+            PolicyCache::S3Fifo(cache) => cache.bytes_size(),
+        }
+    }
+
+    fn clear(&mut self) {
+        match self {
+            PolicyCache::Lru(cache) => cache.clear(),
+            PolicyCache::S3Fifo(cache) => cache.clear(),
+        }
+    }
+}
@@ -20,6 +20,7 @@ use bytes::Bytes;
 use crossbeam_channel::TrySendError;
 use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_config::CacheEvictionPolicy;
 use databend_common_config::DiskCacheKeyReloadPolicy;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -79,12 +80,14 @@ impl TableDataCacheBuilder {
         disk_cache_bytes_size: usize,
         disk_cache_reload_policy: DiskCacheKeyReloadPolicy,
         sync_data: bool,
+        eviction_policy: CacheEvictionPolicy,
     ) -> Result<TableDataCache<LruDiskCacheHolder>> {
         let disk_cache = LruDiskCacheBuilder::new_disk_cache(
             path,
             disk_cache_bytes_size,
             disk_cache_reload_policy,
             sync_data,
+            eviction_policy,
         )?;
         let (tx, rx) = crossbeam_channel::bounded(population_queue_size as usize);
         let num_population_thread = 1;
@@ -96,6 +99,17 @@ impl TableDataCacheBuilder {
     }
 }
 
+impl TableDataCache {
+    pub fn eviction_policy(&self) -> CacheEvictionPolicy {
+        self.external_cache.read().eviction_policy()
+    }
+
+    /// Returns the number of admitted and rejected items.
+    pub fn admission_stats(&self) -> (u64, u64) {
+        self.external_cache.read().admission_stats()
+    }
+}
+
 impl CacheAccessor for TableDataCache {
     type V = Bytes;
 
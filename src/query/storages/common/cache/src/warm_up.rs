@@ -0,0 +1,149 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
+
+use parking_lot::Mutex;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// A recently queried table, whose caches are worth warming up after restart.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HotTable {
+    pub catalog: String,
+    pub database: String,
+    pub table: String,
+    /// Ids of the columns read from the table.
+    pub column_ids: BTreeSet<u32>,
+    pub access_count: u64,
+    /// Unix timestamp in seconds of the last access.
+    pub last_access_secs: u64,
+}
+
+/// Records the recently queried tables, and persists them to a local file periodically,
+/// so that the caches of these tables can be warmed up after the query node restarts.
+pub struct CacheWarmUpRecorder {
+    max_tables: usize,
+    tables: Mutex<HashMap<(String, String, String), HotTable>>,
+}
+
+impl CacheWarmUpRecorder {
+    pub fn new(max_tables: usize) -> Self {
+        Self {
+            max_tables,
+            tables: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Records an access of the table, which reads the columns of `column_ids`.
+    pub fn record(
+        &self,
+        catalog: &str,
+        database: &str,
+        table: &str,
+        column_ids: impl IntoIterator<Item = u32>,
+    ) {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        let mut tables = self.tables.lock();
+        let key = (catalog.to_string(), database.to_string(), table.to_string());
+        let hot_table = tables.entry(key).or_insert_with(|| HotTable {
+            catalog: catalog.to_string(),
+            database: database.to_string(),
+            table: table.to_string(),
+            column_ids: BTreeSet::new(),
+            access_count: 0,
+            last_access_secs: 0,
+        });
+        hot_table.column_ids.extend(column_ids);
+        hot_table.access_count += 1;
+        hot_table.last_access_secs = now;
+
+        // keep the memory usage bounded, by forgetting the least recently queried tables
+        if tables.len() > self.max_tables * 2 {
+            let mut hot_tables = tables.values().cloned().collect::<Vec<_>>();
+            sort_hot_tables(&mut hot_tables);
+            for t in hot_tables.into_iter().skip(self.max_tables) {
+                tables.remove(&(t.catalog, t.database, t.table));
+            }
+        }
+    }
+
+    /// Restores the hot tables loaded from the persisted file, so they won't be forgotten
+    /// before they are queried again.
+    pub fn restore(&self, hot_tables: impl IntoIterator<Item = HotTable>) {
+        let mut tables = self.tables.lock();
+        for hot_table in hot_tables.into_iter().take(self.max_tables) {
+            let key = (
+                hot_table.catalog.clone(),
+                hot_table.database.clone(),
+                hot_table.table.clone(),
+            );
+            tables.entry(key).or_insert(hot_table);
+        }
+    }
+
+    /// Returns the most recently queried tables, at most `max_tables` of them.
+    pub fn hot_tables(&self) -> Vec<HotTable> {
+        let mut tables = self.tables.lock().values().cloned().collect::<Vec<_>>();
+        sort_hot_tables(&mut tables);
+        tables.truncate(self.max_tables);
+        tables
+    }
+
+    /// Persists the hot tables to the file of `path`.
+    pub fn persist(&self, path: &Path) -> io::Result<()> {
+        let data = serde_json::to_vec(&self.hot_tables())?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        // write to a temp file first, so a crash won't leave a partially written file
+        let tmp_path = path.with_extension("tmp");
+        fs::write(&tmp_path, data)?;
+        fs::rename(&tmp_path, path)
+    }
+
+    /// Loads the hot tables persisted to the file of `path`, the most recently queried first.
+    ///
+    /// Returns an empty list if the file does not exist.
+    pub fn load(path: &Path) -> io::Result<Vec<HotTable>> {
+        let data = match fs::read(path) {
+            Ok(data) => data,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(e),
+        };
+        let mut tables: Vec<HotTable> = serde_json::from_slice(&data)?;
+        sort_hot_tables(&mut tables);
+        Ok(tables)
+    }
+}
+
+fn sort_hot_tables(tables: &mut [HotTable]) {
+    tables.sort_by(|a, b| {
+        b.last_access_secs
+            .cmp(&a.last_access_secs)
+            .then(b.access_count.cmp(&a.access_count))
+    });
+}
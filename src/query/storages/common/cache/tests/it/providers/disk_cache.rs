@@ -20,6 +20,7 @@ use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
 
+use databend_common_config::CacheEvictionPolicy;
 use databend_common_config::DiskCacheKeyReloadPolicy;
 use databend_storages_common_cache::DiskCacheError;
 use databend_storages_common_cache::DiskCacheKey;
@@ -134,6 +135,32 @@ fn test_add_get_lru() {
     }
 }
 
+#[test]
+fn test_add_get_s3fifo() {
+    let f = TestFixture::new();
+    let mut c = DiskCache::new_with_policy(
+        f.tmp(),
+        30,
+        DiskCacheKeyReloadPolicy::Reset,
+        false,
+        CacheEvictionPolicy::S3Fifo,
+    )
+    .unwrap();
+    c.insert_single_slice("file1", &[1; 10]).unwrap();
+    // Access the file, so it will be admitted into the main queue.
+    assert!(c.get_cache_path("file1").is_some());
+    c.insert_single_slice("file2", &[2; 10]).unwrap();
+    c.insert_single_slice("file3", &[3; 10]).unwrap();
+    // Adding this file should put the cache above the limit.
+    c.insert_single_slice("file4", &[4; 10]).unwrap();
+    assert_eq!(c.size(), 30);
+    // The file that has never been accessed should have been removed.
+    assert!(c.contains_key("file1"));
+    assert!(!c.contains_key("file2"));
+    assert_eq!(c.admission_stats(), (1, 1));
+    assert_eq!(c.eviction_policy(), CacheEvictionPolicy::S3Fifo);
+}
+
 #[test]
 fn test_insert_bytes_too_large() {
     let f = TestFixture::new();
@@ -0,0 +1,56 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_storages_common_cache::CacheWarmUpRecorder;
+
+#[test]
+fn test_record_hot_tables() {
+    let recorder = CacheWarmUpRecorder::new(2);
+    recorder.record("default", "db", "t1", [1, 2]);
+    recorder.record("default", "db", "t1", [3]);
+
+    let hot_tables = recorder.hot_tables();
+    assert_eq!(hot_tables.len(), 1);
+    assert_eq!(hot_tables[0].table, "t1");
+    assert_eq!(hot_tables[0].access_count, 2);
+    assert_eq!(
+        hot_tables[0].column_ids.iter().copied().collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+
+    for i in 2..10 {
+        recorder.record("default", "db", &format!("t{i}"), []);
+    }
+    assert_eq!(recorder.hot_tables().len(), 2);
+}
+
+#[test]
+fn test_persist_and_load_hot_tables() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("warm_up").join("hot_tables.json");
+
+    // nothing persisted yet
+    assert!(CacheWarmUpRecorder::load(&path).unwrap().is_empty());
+
+    let recorder = CacheWarmUpRecorder::new(8);
+    recorder.record("default", "db", "t1", [1]);
+    recorder.record("default", "db", "t2", [1, 2]);
+    recorder.persist(&path).unwrap();
+
+    let loaded = CacheWarmUpRecorder::load(&path).unwrap();
+    assert_eq!(loaded.len(), 2);
+    let mut tables = loaded.iter().map(|t| t.table.as_str()).collect::<Vec<_>>();
+    tables.sort();
+    assert_eq!(tables, vec!["t1", "t2"]);
+}
@@ -146,7 +146,12 @@ impl Index for Xor8Filter {
         }
         matches!(
             inner_type,
-            DataType::Number(_) | DataType::String | DataType::Timestamp | DataType::Date
+            DataType::Number(_)
+                | DataType::String
+                | DataType::Timestamp
+                | DataType::Date
+                | DataType::Time
+                | DataType::Uuid
         )
     }
 }
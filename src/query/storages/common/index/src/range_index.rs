@@ -28,7 +28,9 @@ use databend_common_expression::types::DataType;
 use databend_common_expression::types::DateType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberType;
+use databend_common_expression::types::TimeType;
 use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UuidType;
 use databend_common_expression::types::ValueType;
 use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::ColumnId;
@@ -39,6 +41,9 @@ use databend_common_expression::FunctionContext;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableSchemaRef;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_io::BoundingBox;
+use databend_storages_common_table_meta::meta::bounding_box_from_scalars;
+use databend_storages_common_table_meta::meta::supported_bounding_box_type;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
 use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 
@@ -79,6 +84,10 @@ impl RangeIndex {
 
     pub fn apply<F>(&self, stats: &StatisticsOfColumns, column_is_default: F) -> Result<bool>
     where F: Fn(&ColumnId) -> bool {
+        if !self.apply_bounding_boxes(&self.expr, stats) {
+            return Ok(false);
+        }
+
         let input_domains = self
             .expr
             .column_refs()
@@ -135,6 +144,89 @@ impl RangeIndex {
         }))
     }
 
+    /// Checks spatial predicates in the conjuncts of `expr` against the bounding
+    /// boxes recorded for geometry columns. Returns false if no row of the block
+    /// can satisfy them.
+    fn apply_bounding_boxes(&self, expr: &Expr<String>, stats: &StatisticsOfColumns) -> bool {
+        let Expr::FunctionCall { function, args, .. } = expr else {
+            return true;
+        };
+        let name = function.signature.name.as_str();
+        match name {
+            "and" | "and_filters" => args.iter().all(|arg| self.apply_bounding_boxes(arg, stats)),
+            "st_intersects" | "st_within" | "st_contains" | "st_covers" if args.len() == 2 => {
+                match (&args[0], &args[1]) {
+                    (Expr::ColumnRef { id, data_type, .. }, Expr::Constant { scalar, .. })
+                    | (Expr::Constant { scalar, .. }, Expr::ColumnRef { id, data_type, .. }) => {
+                        self.bounding_box_may_match(id, data_type, scalar, 0.0, stats)
+                    }
+                    _ => true,
+                }
+            }
+            "st_dwithin" if args.len() == 3 => match (&args[0], &args[1], &args[2]) {
+                (
+                    Expr::ColumnRef { id, data_type, .. },
+                    Expr::Constant { scalar, .. },
+                    Expr::Constant {
+                        scalar: distance, ..
+                    },
+                )
+                | (
+                    Expr::Constant { scalar, .. },
+                    Expr::ColumnRef { id, data_type, .. },
+                    Expr::Constant {
+                        scalar: distance, ..
+                    },
+                ) => match distance {
+                    Scalar::Number(num) => self.bounding_box_may_match(
+                        id,
+                        data_type,
+                        scalar,
+                        num.to_f64().into_inner(),
+                        stats,
+                    ),
+                    _ => true,
+                },
+                _ => true,
+            },
+            _ => true,
+        }
+    }
+
+    fn bounding_box_may_match(
+        &self,
+        name: &str,
+        data_type: &DataType,
+        geometry: &Scalar,
+        distance: f64,
+        stats: &StatisticsOfColumns,
+    ) -> bool {
+        if !supported_bounding_box_type(data_type) {
+            return true;
+        }
+        let Scalar::Geometry(geometry) = geometry else {
+            return true;
+        };
+        let Ok(Some(bbox)) = BoundingBox::from_ewkb(geometry) else {
+            return true;
+        };
+        let column_ids = self.schema.leaf_columns_of(&name.to_string());
+        let [column_id] = column_ids.as_slice() else {
+            return true;
+        };
+        let Some(stat) = stats.get(column_id) else {
+            return true;
+        };
+        if stat.min.is_null() || stat.max.is_null() {
+            // The block holds only NULL or empty geometries.
+            return false;
+        }
+        match bounding_box_from_scalars(&stat.min, &stat.max) {
+            Some(block_bbox) => block_bbox.intersects(&bbox.expand(distance)),
+            None => true,
+        }
+    }
+
     #[fastrace::trace]
     pub fn apply_with_partition_columns(
         &self,
@@ -153,7 +245,8 @@ impl RangeIndex {
 }
 
 pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataType) -> Domain {
-    if stats.len() != data_type.num_leaf_columns() {
+    // Geometry statistics hold bounding boxes rather than min/max values.
+    if stats.len() != data_type.num_leaf_columns() || supported_bounding_box_type(data_type) {
         return Domain::full(data_type);
     }
     match data_type {
@@ -224,6 +317,14 @@ pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataT
                     min: DateType::try_downcast_scalar(&min.as_ref()).unwrap(),
                     max: DateType::try_downcast_scalar(&max.as_ref()).unwrap(),
                 }),
+                DataType::Time => TimeType::upcast_domain(SimpleDomain {
+                    min: TimeType::try_downcast_scalar(&min.as_ref()).unwrap(),
+                    max: TimeType::try_downcast_scalar(&max.as_ref()).unwrap(),
+                }),
+                DataType::Uuid => UuidType::upcast_domain(SimpleDomain {
+                    min: UuidType::try_downcast_scalar(&min.as_ref()).unwrap(),
+                    max: UuidType::try_downcast_scalar(&max.as_ref()).unwrap(),
+                }),
                 DataType::Decimal(dec) => match dec {
                     DecimalDataType::Decimal128(sz) => Domain::Decimal(DecimalDomain::Decimal128(
                         SimpleDomain {
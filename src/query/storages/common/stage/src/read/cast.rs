@@ -123,6 +123,24 @@ pub fn load_can_auto_cast_to(from_type: &DataType, to_type: &DataType) -> bool {
         (String | Variant | Timestamp | Date, Timestamp | Date) => true,
         (_, Timestamp | Date) => false,
 
+        // [specificity]
+        (String, Interval) => true,
+        (_, Interval) => false,
+
+        // [specificity] keep the offset written in the file
+        (String | Timestamp, TimestampTz) => true,
+        (_, TimestampTz) => false,
+
+        // [specificity]
+        (String | Timestamp, Time) => true,
+        (_, Time) => false,
+        (String, Uuid) => true,
+        (_, Uuid) => false,
+        (String, Enum(_)) => true,
+        (_, Enum(_)) => false,
+        // [useless] states are only produced by the `_state` aggregate functions
+        (_, AggregateState(_)) => false,
+
         // [useless]
         (_, Binary) => false,
 
@@ -37,9 +37,13 @@ pub use statistics::*;
 // export legacy versioned table meta types locally,
 // currently, used by versioned readers only
 pub(crate) use testing::*;
+pub use utils::cold_tier_location;
 pub use utils::parse_storage_prefix;
+pub use utils::split_tiered_location;
 pub use utils::trim_vacuum2_object_prefix;
 pub use utils::try_extract_uuid_str_from_path;
+pub use utils::StorageTier;
+pub use utils::COLD_TIER_LOCATION_PREFIX;
 pub use utils::TEMP_TABLE_STORAGE_PREFIX;
 pub use utils::VACUUM2_OBJECT_KEY_PREFIX;
 pub(crate) use utils::*;
@@ -15,8 +15,11 @@
 use std::collections::HashMap;
 
 use databend_common_base::base::uuid::Uuid;
+use databend_common_expression::types::number::NumberScalar;
 use databend_common_expression::types::DataType;
 use databend_common_expression::ColumnId;
+use databend_common_expression::Scalar;
+use databend_common_io::BoundingBox;
 
 use crate::meta::ColumnStatistics;
 
@@ -45,7 +48,46 @@ pub fn supported_stat_type(data_type: &DataType) -> bool {
         DataType::Number(_)
             | DataType::Date
             | DataType::Timestamp
+            | DataType::Time
+            | DataType::Uuid
             | DataType::String
             | DataType::Decimal(_)
     )
 }
+
+/// The statistics of GEOMETRY columns hold a bounding box instead of min/max values.
+pub fn supported_bounding_box_type(data_type: &DataType) -> bool {
+    matches!(data_type.remove_nullable(), DataType::Geometry)
+}
+
+/// Encodes a bounding box as the `(xmin, ymin)` and `(xmax, ymax)` corners.
+pub fn bounding_box_to_scalars(bbox: &BoundingBox) -> (Scalar, Scalar) {
+    let point = |x: f64, y: f64| {
+        Scalar::Tuple(vec![
+            Scalar::Number(NumberScalar::Float64(x.into())),
+            Scalar::Number(NumberScalar::Float64(y.into())),
+        ])
+    };
+    (point(bbox.xmin, bbox.ymin), point(bbox.xmax, bbox.ymax))
+}
+
+pub fn bounding_box_from_scalars(min: &Scalar, max: &Scalar) -> Option<BoundingBox> {
+    let point = |scalar: &Scalar| match scalar {
+        Scalar::Tuple(fields) => match fields.as_slice() {
+            [
+                Scalar::Number(NumberScalar::Float64(x)),
+                Scalar::Number(NumberScalar::Float64(y)),
+            ] => Some((x.0, y.0)),
+            _ => None,
+        },
+        _ => None,
+    };
+    let (xmin, ymin) = point(min)?;
+    let (xmax, ymax) = point(max)?;
+    Some(BoundingBox {
+        xmin,
+        ymin,
+        xmax,
+        ymax,
+    })
+}
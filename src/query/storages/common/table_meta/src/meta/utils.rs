@@ -13,6 +13,8 @@
 // limitations under the License.
 
 use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
 use std::ops::Add;
 use std::path::Path;
 
@@ -31,6 +33,39 @@ use crate::table::OPT_KEY_TEMP_PREFIX;
 
 pub const TEMP_TABLE_STORAGE_PREFIX: &str = "_tmp_tbl";
 pub const VACUUM2_OBJECT_KEY_PREFIX: &str = "g";
+/// Block locations with this prefix are stored in the cold storage of the table.
+pub const COLD_TIER_LOCATION_PREFIX: &str = "cold:";
+
+/// The storage tier that a block of a fuse table is stored in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StorageTier {
+    Hot,
+    Cold,
+}
+
+impl Display for StorageTier {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            StorageTier::Hot => write!(f, "hot"),
+            StorageTier::Cold => write!(f, "cold"),
+        }
+    }
+}
+
+/// Splits a block location into the storage tier of the block and its path in that tier.
+#[inline]
+pub fn split_tiered_location(location: &str) -> (StorageTier, &str) {
+    match location.strip_prefix(COLD_TIER_LOCATION_PREFIX) {
+        Some(path) => (StorageTier::Cold, path),
+        None => (StorageTier::Hot, location),
+    }
+}
+
+/// Returns the location of a block stored at `path` of the cold storage.
+#[inline]
+pub fn cold_tier_location(path: &str) -> String {
+    format!("{}{}", COLD_TIER_LOCATION_PREFIX, path)
+}
 
 pub fn trim_timestamp_to_micro_second(ts: DateTime<Utc>) -> DateTime<Utc> {
     Utc.with_ymd_and_hms(
@@ -144,4 +179,17 @@ mod tests {
             assert_eq!(try_extract_uuid_str_from_path(input).unwrap(), expected);
         }
     }
+
+    #[test]
+    fn test_split_tiered_location() {
+        let path = "1/2/_b/0191114d30fd78b89fae8e5c88327725_v2.parquet";
+        assert_eq!(split_tiered_location(path), (StorageTier::Hot, path));
+
+        let location = cold_tier_location(path);
+        assert_eq!(split_tiered_location(&location), (StorageTier::Cold, path));
+        assert_eq!(
+            try_extract_uuid_str_from_path(&location).unwrap(),
+            "0191114d30fd78b89fae8e5c88327725"
+        );
+    }
 }
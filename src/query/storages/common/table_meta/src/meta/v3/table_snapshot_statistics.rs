@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 
 use databend_common_expression::ColumnId;
+use databend_common_storage::Datum;
 use databend_common_storage::Histogram;
 use serde::Deserialize;
 use serde::Serialize;
@@ -36,12 +37,34 @@ pub struct TableSnapshotStatistics {
     pub snapshot_id: SnapshotId,
     pub hll: HashMap<ColumnId, MetaHLL>,
     pub histograms: HashMap<ColumnId, Histogram>,
+    /// joint statistics of the column groups declared by `ANALYZE TABLE ... COLUMNS`
+    #[serde(default)]
+    pub column_groups: Vec<ColumnGroupStatistics>,
+}
+
+/// Joint statistics of a group of correlated columns.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ColumnGroupStatistics {
+    pub column_ids: Vec<ColumnId>,
+    /// hll of the combinations of the column values
+    pub hll: MetaHLL,
+    /// number of rows that the most common values are collected from
+    pub row_count: u64,
+    /// the most common combinations of the column values, and their row counts
+    pub most_common_values: Vec<(Vec<Datum>, u64)>,
+}
+
+impl ColumnGroupStatistics {
+    pub fn distinct_values(&self) -> u64 {
+        self.hll.count() as u64
+    }
 }
 
 impl TableSnapshotStatistics {
     pub fn new(
         hll: HashMap<ColumnId, MetaHLL>,
         histograms: HashMap<ColumnId, Histogram>,
+        column_groups: Vec<ColumnGroupStatistics>,
         snapshot_id: SnapshotId,
     ) -> Self {
         Self {
@@ -49,6 +72,7 @@ impl TableSnapshotStatistics {
             snapshot_id,
             hll,
             histograms,
+            column_groups,
         }
     }
 
@@ -71,6 +95,7 @@ impl From<v2::TableSnapshotStatistics> for TableSnapshotStatistics {
             snapshot_id: value.snapshot_id,
             hll: HashMap::new(),
             histograms: HashMap::new(),
+            column_groups: vec![],
         }
     }
 }
@@ -82,6 +107,7 @@ impl From<v1::TableSnapshotStatistics> for TableSnapshotStatistics {
             snapshot_id: value.snapshot_id,
             hll: HashMap::new(),
             histograms: HashMap::new(),
+            column_groups: vec![],
         }
     }
 }
@@ -27,10 +27,18 @@ pub const OPT_KEY_ENGINE: &str = "engine";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
 pub const OPT_KEY_CHANGE_TRACKING: &str = "change_tracking";
 pub const OPT_KEY_CHANGE_TRACKING_BEGIN_VER: &str = "begin_version";
+// The branch that a fuse table handle reads from and commits to, instead of the table itself.
+// It is only set on the handles of a branch and never stored in the meta server.
+pub const OPT_KEY_BRANCH: &str = "branch";
 
 // Attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_DATA_URI: &str = "table_data_uri";
 
+// The location and the connection of the storage that the tiering policy of a fuse table
+// moves cold blocks to.
+pub const OPT_KEY_TIERING_COLD_LOCATION: &str = "tiering_cold_location";
+pub const OPT_KEY_TIERING_COLD_CONNECTION_NAME: &str = "tiering_cold_connection_name";
+
 // the following are used in for delta and iceberg engine
 pub const OPT_KEY_LOCATION: &str = "location";
 pub const OPT_KEY_CONNECTION_NAME: &str = "connection_name";
@@ -61,6 +69,7 @@ pub static RESERVED_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_BRANCH);
     r
 });
 
@@ -72,6 +81,7 @@ pub static INTERNAL_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     r.insert(OPT_KEY_ENGINE_META);
     r.insert(OPT_KEY_CHANGE_TRACKING_BEGIN_VER);
     r.insert(OPT_KEY_TEMP_PREFIX);
+    r.insert(OPT_KEY_BRANCH);
     r
 });
 
@@ -51,4 +51,8 @@ impl PartInfo for DeltaPartInfo {
     fn hash(&self) -> u64 {
         self.data.hash()
     }
+
+    fn sample_key(&self) -> Vec<u8> {
+        self.data.sample_key()
+    }
 }
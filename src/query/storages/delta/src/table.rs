@@ -26,6 +26,7 @@ use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::plan::RowSampler;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::TableContext;
@@ -256,6 +257,7 @@ impl DeltaTable {
         let parquet_reader = Arc::new(builder.build_full_reader()?);
 
         let output_schema = Arc::new(DataSchema::from(plan.schema()));
+        let row_sampler = RowSampler::try_create(&plan.push_downs);
         pipeline.add_source(
             |output| {
                 DeltaTableSource::create(
@@ -264,6 +266,7 @@ impl DeltaTable {
                     output_schema.clone(),
                     parquet_reader.clone(),
                     self.get_partition_fields()?,
+                    row_sampler,
                 )
             },
             max_threads.max(1),
@@ -411,6 +414,10 @@ impl Table for DeltaTable {
     fn support_prewhere(&self) -> bool {
         true
     }
+
+    fn support_seeded_row_sample(&self) -> bool {
+        true
+    }
 }
 
 pub fn get_partition_values(add: &Add, fields: &[TableField]) -> Result<Vec<Scalar>> {
@@ -19,6 +19,8 @@ use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
 use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_catalog::plan::PartRowSampler;
+use databend_common_catalog::plan::RowSampler;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -66,6 +68,10 @@ pub struct DeltaTableSource {
     // Per partition
     stream: Option<ParquetRecordBatchStream<ParquetFileReader>>,
     partition_block_entries: Vec<BlockEntry>,
+    part_sampler: Option<PartRowSampler>,
+
+    // Used for `REPEATABLE (seed)` row-level sample.
+    row_sampler: Option<RowSampler>,
 }
 
 impl DeltaTableSource {
@@ -75,6 +81,7 @@ impl DeltaTableSource {
         output_schema: DataSchemaRef,
         parquet_reader: Arc<ParquetRSFullReader>,
         partition_fields: Vec<TableField>,
+        row_sampler: Option<RowSampler>,
     ) -> Result<ProcessorPtr> {
         let output_partition_columns = output_schema
             .fields()
@@ -100,6 +107,8 @@ impl DeltaTableSource {
             generated_data: None,
             is_finished: false,
             partition_block_entries: vec![],
+            part_sampler: None,
+            row_sampler,
         })))
     }
 }
@@ -163,6 +172,10 @@ impl Processor for DeltaTableSource {
                 .map(|b| check_block_schema(&self.output_schema, b))
                 .transpose()?
             {
+                let block = match &mut self.part_sampler {
+                    Some(part_sampler) => part_sampler.sample_block(block)?,
+                    None => block,
+                };
                 self.generated_data = Some(block);
                 self.stream = Some(stream);
             }
@@ -186,6 +199,10 @@ impl Processor for DeltaTableSource {
                             BlockEntry::new(f.data_type().into(), Value::Scalar(v.clone()))
                         })
                         .collect::<Vec<_>>();
+                    self.part_sampler = self
+                        .row_sampler
+                        .as_ref()
+                        .map(|row_sampler| row_sampler.part_sampler(files.files[0].0.as_bytes()));
                     let stream = self
                         .parquet_reader
                         .prepare_data_stream(
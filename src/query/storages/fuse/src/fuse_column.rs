@@ -14,11 +14,13 @@
 
 use std::collections::HashMap;
 
+use databend_common_catalog::statistics::BasicColumnGroupStatistics;
 use databend_common_catalog::statistics::BasicColumnStatistics;
 use databend_common_catalog::table::ColumnStatisticsProvider;
 use databend_common_expression::ColumnId;
 use databend_common_storage::Datum;
 use databend_common_storage::Histogram;
+use databend_storages_common_table_meta::meta::ColumnGroupStatistics;
 use databend_storages_common_table_meta::meta::ColumnStatistics as FuseColumnStatistics;
 
 /// A column statistics provider for fuse table.
@@ -26,6 +28,7 @@ use databend_storages_common_table_meta::meta::ColumnStatistics as FuseColumnSta
 pub struct FuseTableColumnStatisticsProvider {
     column_stats: HashMap<ColumnId, Option<BasicColumnStatistics>>,
     histograms: HashMap<ColumnId, Histogram>,
+    column_groups: Vec<BasicColumnGroupStatistics>,
 }
 
 impl FuseTableColumnStatisticsProvider {
@@ -53,8 +56,28 @@ impl FuseTableColumnStatisticsProvider {
         Self {
             column_stats,
             histograms,
+            column_groups: vec![],
         }
     }
+
+    pub fn with_column_groups(mut self, column_groups: &[ColumnGroupStatistics]) -> Self {
+        self.column_groups = column_groups
+            .iter()
+            .map(|group| {
+                let row_count = group.row_count.max(1) as f64;
+                BasicColumnGroupStatistics {
+                    column_ids: group.column_ids.clone(),
+                    ndv: group.distinct_values(),
+                    most_common_values: group
+                        .most_common_values
+                        .iter()
+                        .map(|(values, count)| (values.clone(), *count as f64 / row_count))
+                        .collect(),
+                }
+            })
+            .collect();
+        self
+    }
 }
 
 impl ColumnStatisticsProvider for FuseTableColumnStatisticsProvider {
@@ -69,4 +92,8 @@ impl ColumnStatisticsProvider for FuseTableColumnStatisticsProvider {
     fn histogram(&self, column_id: ColumnId) -> Option<Histogram> {
         self.histograms.get(&column_id).cloned()
     }
+
+    fn column_groups(&self) -> &[BasicColumnGroupStatistics] {
+        &self.column_groups
+    }
 }
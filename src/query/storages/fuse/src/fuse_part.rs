@@ -68,6 +68,10 @@ impl PartInfo for FuseBlockPartInfo {
         s.finish()
     }
 
+    fn sample_key(&self) -> Vec<u8> {
+        self.location.as_bytes().to_vec()
+    }
+
     fn part_type(&self) -> PartInfoType {
         PartInfoType::BlockLevel
     }
@@ -150,6 +154,10 @@ impl PartInfo for FuseLazyPartInfo {
         s.finish()
     }
 
+    fn sample_key(&self) -> Vec<u8> {
+        self.segment_location.0.as_bytes().to_vec()
+    }
+
     fn part_type(&self) -> PartInfoType {
         PartInfoType::LazyLevel
     }
@@ -53,6 +53,7 @@ use databend_common_expression::SEARCH_SCORE_COLUMN_ID;
 use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use databend_common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use databend_common_meta_app::schema::DatabaseType;
+use databend_common_meta_app::schema::SnapshotRefType;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::UpdateStreamMetaReq;
 use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
@@ -78,6 +79,7 @@ use databend_storages_common_table_meta::table::ChangeType;
 use databend_storages_common_table_meta::table::ClusterType;
 use databend_storages_common_table_meta::table::TableCompression;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
+use databend_storages_common_table_meta::table::OPT_KEY_BRANCH;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
 use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
 use databend_storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
@@ -93,6 +95,7 @@ use uuid::Uuid;
 
 use crate::fuse_column::FuseTableColumnStatisticsProvider;
 use crate::fuse_type::FuseTableType;
+use crate::io::tiered_operator;
 use crate::io::MetaReaders;
 use crate::io::SegmentsIO;
 use crate::io::TableMetaLocationGenerator;
@@ -127,6 +130,8 @@ pub struct FuseTable {
     pub(crate) bloom_index_cols: BloomIndexColumns,
 
     pub(crate) operator: Operator,
+    // The storage that the tiering policy moves cold blocks to.
+    pub(crate) cold_operator: Option<Operator>,
     pub(crate) data_metrics: Arc<StorageMetrics>,
 
     table_type: FuseTableType,
@@ -204,6 +209,14 @@ impl FuseTable {
 
         let data_metrics = Arc::new(StorageMetrics::default());
         operator = operator.layer(StorageMetricsLayer::new(data_metrics.clone()));
+        let cold_operator = table_info
+            .meta
+            .cold_storage_params
+            .as_ref()
+            .map(|sp| {
+                init_operator(sp).map(|op| op.layer(StorageMetricsLayer::new(data_metrics.clone())))
+            })
+            .transpose()?;
 
         let storage_format = table_info
             .options()
@@ -237,6 +250,7 @@ impl FuseTable {
             cluster_key_meta,
             bloom_index_cols,
             operator,
+            cold_operator,
             data_metrics,
             storage_format: FuseStorageFormat::from_str(storage_format.as_str())?,
             table_compression: table_compression.as_str().try_into()?,
@@ -372,7 +386,11 @@ impl FuseTable {
             DatabaseType::NormalDB => {
                 let options = self.table_info.options();
 
-                if let Some(storage_prefix) = options.get(OPT_KEY_STORAGE_PREFIX) {
+                if let Some(branch) = self.branch() {
+                    // A handle of a branch reads the head of the branch.
+                    let snapshot_ref = self.get_snapshot_ref(SnapshotRefType::Branch, branch)?;
+                    Ok(Some(snapshot_ref.snapshot_location.clone()))
+                } else if let Some(storage_prefix) = options.get(OPT_KEY_STORAGE_PREFIX) {
                     // If the table is attaching to someone else,
                     // parse the snapshot location from the hint file.
                     //
@@ -411,6 +429,17 @@ impl FuseTable {
         &self.operator
     }
 
+    /// The operator of the storage that the tiering policy moves cold blocks to, if any.
+    pub fn get_cold_operator(&self) -> Option<Operator> {
+        self.cold_operator.clone()
+    }
+
+    /// Returns the operator of the storage tier that the block at `location` is stored in,
+    /// and the path of the block in that storage.
+    pub fn get_block_operator<'a>(&self, location: &'a str) -> Result<(Operator, &'a str)> {
+        tiered_operator(&self.operator, self.cold_operator.as_ref(), location)
+    }
+
     pub fn try_from_table(tbl: &dyn Table) -> Result<&FuseTable> {
         tbl.as_any().downcast_ref::<FuseTable>().ok_or_else(|| {
             ErrorCode::Internal(format!(
@@ -420,6 +449,27 @@ impl FuseTable {
         })
     }
 
+    /// The branch that this table handle reads from and commits to, if any,
+    /// see [`FuseTable::switch_to_branch`].
+    pub fn branch(&self) -> Option<&str> {
+        self.table_info
+            .options()
+            .get(OPT_KEY_BRANCH)
+            .map(|branch| branch.as_str())
+    }
+
+    fn snapshot_table_statistics(snapshot: &TableSnapshot) -> TableStatistics {
+        let summary = &snapshot.summary;
+        TableStatistics {
+            num_rows: Some(summary.row_count),
+            data_size: Some(summary.uncompressed_byte_size),
+            data_size_compressed: Some(summary.compressed_byte_size),
+            index_size: Some(summary.index_size),
+            number_of_blocks: Some(summary.block_count),
+            number_of_segments: Some(snapshot.segments.len() as u64),
+        }
+    }
+
     pub fn is_transient(&self) -> bool {
         self.table_info.meta.options.contains_key("TRANSIENT")
     }
@@ -798,16 +848,13 @@ impl Table for FuseTable {
                     // `Internal` error is used.
                     ErrorCode::Internal("Failed to load snapshot of read_only attach table")
                 })?;
-                let summary = &snapshot.summary;
-                TableStatistics {
-                    num_rows: Some(summary.row_count),
-                    data_size: Some(summary.uncompressed_byte_size),
-                    data_size_compressed: Some(summary.compressed_byte_size),
-                    index_size: Some(summary.index_size),
-                    number_of_blocks: Some(summary.block_count),
-                    number_of_segments: Some(snapshot.segments.len() as u64),
-                }
+                Self::snapshot_table_statistics(&snapshot)
             }
+            // The statistics in the table meta are those of the table, not of the branch.
+            _ if self.branch().is_some() => match self.read_table_snapshot().await? {
+                Some(snapshot) => Self::snapshot_table_statistics(&snapshot),
+                None => TableStatistics::default(),
+            },
             _ => {
                 let s = &self.table_info.meta.statistics;
                 TableStatistics {
@@ -838,6 +885,7 @@ impl Table for FuseTable {
                     Some(table_statistics.column_distinct_values()),
                     snapshot.summary.row_count,
                 )
+                .with_column_groups(&table_statistics.column_groups)
             } else {
                 FuseTableColumnStatisticsProvider::new(
                     stats.clone(),
@@ -1065,4 +1113,8 @@ impl Table for FuseTable {
     fn use_own_sample_block(&self) -> bool {
         true
     }
+
+    fn support_seeded_row_sample(&self) -> bool {
+        !self.is_native()
+    }
 }
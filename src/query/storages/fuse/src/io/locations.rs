@@ -16,6 +16,7 @@ use std::marker::PhantomData;
 
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
+use databend_storages_common_table_meta::meta::split_tiered_location;
 use databend_storages_common_table_meta::meta::trim_vacuum2_object_prefix;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::SegmentInfo;
@@ -157,7 +158,10 @@ impl TableMetaLocationGenerator {
         format!("{}/{}", &self.prefix, FUSE_TBL_LAST_SNAPSHOT_HINT)
     }
 
+    // The indexes and the virtual columns of a block are kept in the hot storage,
+    // even if the block is moved to the cold storage.
     pub fn gen_virtual_block_location(location: &str) -> String {
+        let (_, location) = split_tiered_location(location);
         location.replace(FUSE_TBL_BLOCK_PREFIX, FUSE_TBL_VIRTUAL_BLOCK_PREFIX)
     }
 
@@ -178,6 +182,7 @@ impl TableMetaLocationGenerator {
     }
 
     pub fn gen_agg_index_location_from_block_location(loc: &str, index_id: u64) -> String {
+        let (_, loc) = split_tiered_location(loc);
         let splits = loc.split('/').collect::<Vec<_>>();
         let len = splits.len();
         let prefix = splits[..len - 2].join("/");
@@ -190,6 +195,7 @@ impl TableMetaLocationGenerator {
         index_name: &str,
         index_version: &str,
     ) -> String {
+        let (_, loc) = split_tiered_location(loc);
         let splits = loc.split('/').collect::<Vec<_>>();
         let len = splits.len();
         let prefix = splits[..len - 2].join("/");
@@ -19,6 +19,7 @@ mod snapshots;
 mod write;
 
 pub use locations::TableMetaLocationGenerator;
+pub use read::tiered_operator;
 pub use read::AggIndexReader;
 pub use read::BlockReadResult;
 pub use read::BlockReader;
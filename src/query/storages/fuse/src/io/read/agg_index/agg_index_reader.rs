@@ -63,6 +63,7 @@ impl AggIndexReader {
         let reader = BlockReader::create(
             ctx.clone(),
             dal,
+            None,
             agg.schema.clone(),
             agg.projection.clone(),
             false,
@@ -34,6 +34,7 @@ use databend_common_storage::ColumnNode;
 use databend_common_storage::ColumnNodes;
 use opendal::Operator;
 
+use crate::io::read::tiered_operator;
 use crate::BlockReadResult;
 
 // TODO: make BlockReader as a trait.
@@ -41,6 +42,8 @@ use crate::BlockReadResult;
 pub struct BlockReader {
     pub(crate) ctx: Arc<dyn TableContext>,
     pub(crate) operator: Operator,
+    // The operator of the cold storage, used to read the blocks moved by the tiering policy.
+    pub(crate) cold_operator: Option<Operator>,
     pub(crate) projection: Projection,
     pub(crate) projected_schema: TableSchemaRef,
     pub(crate) project_indices: BTreeMap<FieldIndex, (ColumnId, Field, DataType)>,
@@ -86,6 +89,7 @@ impl BlockReader {
     pub fn create(
         ctx: Arc<dyn TableContext>,
         operator: Operator,
+        cold_operator: Option<Operator>,
         schema: TableSchemaRef,
         projection: Projection,
         query_internal_columns: bool,
@@ -141,6 +145,7 @@ impl BlockReader {
         Ok(Arc::new(BlockReader {
             ctx,
             operator,
+            cold_operator,
             projection,
             projected_schema,
             project_indices,
@@ -154,6 +159,12 @@ impl BlockReader {
         }))
     }
 
+    /// Returns the operator of the storage tier that the block at `location` is stored in,
+    /// and the path of the block in that storage.
+    pub(crate) fn tiered_operator<'a>(&self, location: &'a str) -> Result<(Operator, &'a str)> {
+        tiered_operator(&self.operator, self.cold_operator.as_ref(), location)
+    }
+
     pub fn support_blocking_api(&self) -> bool {
         self.operator.info().native_capability().blocking
     }
@@ -89,9 +89,9 @@ impl BlockReader {
             }
         }
 
+        let (operator, path) = self.tiered_operator(location)?;
         let merge_io_result =
-            MergeIOReader::merge_io_read(settings, self.operator.clone(), location, &ranges)
-                .await?;
+            MergeIOReader::merge_io_read(settings, operator, path, &ranges).await?;
 
         if self.put_cache {
             // add raw data (compressed raw bytes) to column cache
@@ -59,12 +59,8 @@ impl BlockReader {
             }
         }
 
-        let merge_io_result = MergeIOReader::sync_merge_io_read(
-            settings,
-            self.operator.clone(),
-            &part.location,
-            &ranges,
-        )?;
+        let (operator, path) = self.tiered_operator(&part.location)?;
+        let merge_io_result = MergeIOReader::sync_merge_io_read(settings, operator, path, &ranges)?;
 
         // for sync read, we disable table data cache
         let cached_column_data = vec![];
@@ -154,7 +154,7 @@ impl BlockReader {
                 }
             }
 
-            let op = self.operator.clone();
+            let (op, path) = self.tiered_operator(&part.location)?;
             let metas: Vec<ColumnMeta> = column_node
                 .leaf_column_ids
                 .iter()
@@ -162,8 +162,7 @@ impl BlockReader {
                 .cloned()
                 .collect::<Vec<_>>();
 
-            let readers =
-                Self::sync_read_native_column(op.clone(), &part.location, metas, part.range())?;
+            let readers = Self::sync_read_native_column(op, path, metas, part.range())?;
             results.insert(index, readers);
         }
 
@@ -14,8 +14,13 @@
 
 use std::collections::HashMap;
 
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_storages_common_table_meta::meta::split_tiered_location;
 use databend_storages_common_table_meta::meta::ColumnMeta;
 use databend_storages_common_table_meta::meta::SingleColumnMeta;
+use databend_storages_common_table_meta::meta::StorageTier;
+use opendal::Operator;
 use parquet::file::metadata::RowGroupMetaData;
 
 pub fn build_columns_meta(row_group: &RowGroupMetaData) -> HashMap<u32, ColumnMeta> {
@@ -33,3 +38,22 @@ pub fn build_columns_meta(row_group: &RowGroupMetaData) -> HashMap<u32, ColumnMe
     }
     columns_meta
 }
+
+/// Returns the operator of the storage tier that the block at `location` is stored in,
+/// and the path of the block in that storage.
+pub fn tiered_operator<'a>(
+    operator: &Operator,
+    cold_operator: Option<&Operator>,
+    location: &'a str,
+) -> Result<(Operator, &'a str)> {
+    match split_tiered_location(location) {
+        (StorageTier::Hot, path) => Ok((operator.clone(), path)),
+        (StorageTier::Cold, path) => match cold_operator {
+            Some(cold_operator) => Ok((cold_operator.clone(), path)),
+            None => Err(ErrorCode::StorageOther(format!(
+                "block {} is stored in the cold storage, which is not configured",
+                location
+            ))),
+        },
+    }
+}
@@ -173,6 +173,7 @@ impl BloomIndexBuilder {
         let block_reader = BlockReader::create(
             ctx,
             self.table_dal.clone(),
+            None,
             self.table_schema.clone(),
             projection,
             false,
@@ -40,6 +40,7 @@ use databend_common_storage::Datum;
 use databend_common_storage::Histogram;
 use databend_common_storage::HistogramBucket;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
+use databend_storages_common_table_meta::meta::ColumnGroupStatistics;
 use databend_storages_common_table_meta::meta::MetaHLL;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::SnapshotId;
@@ -56,9 +57,23 @@ use crate::FuseTable;
 enum AnalyzeStep {
     CollectNDV,
     CollectHistogram,
+    CollectMostCommonValues,
     CommitStatistics,
 }
 
+/// A group of columns to collect joint statistics for.
+///
+/// The joint ndv of the group `i` is collected as the column `group_ndv_{i}` of the
+/// analyze query, and the most common values, if enabled, are received from a separate
+/// query that outputs the column values followed by their row counts.
+#[derive(Clone)]
+pub struct ColumnGroupAnalyzeInfo {
+    pub column_ids: Vec<ColumnId>,
+    /// number of rows of the analyzed snapshot
+    pub row_count: u64,
+    pub most_common_values_receiver: Option<Receiver<DataBlock>>,
+}
+
 impl FuseTable {
     #[allow(clippy::too_many_arguments)]
     pub fn do_analyze(
@@ -70,6 +85,7 @@ impl FuseTable {
         snapshot_id: SnapshotId,
         pipeline: &mut Pipeline,
         histogram_info_receivers: HashMap<u32, Receiver<DataBlock>>,
+        column_groups: Vec<ColumnGroupAnalyzeInfo>,
     ) -> Result<()> {
         pipeline.add_sink(|input| {
             SinkAnalyzeState::create(
@@ -81,6 +97,7 @@ impl FuseTable {
                 snapshot_id,
                 input,
                 histogram_info_receivers.clone(),
+                column_groups.clone(),
             )
         })?;
         Ok(())
@@ -97,10 +114,13 @@ struct SinkAnalyzeState {
     table: String,
     snapshot_id: SnapshotId,
     histogram_info_receivers: HashMap<u32, Receiver<DataBlock>>,
+    column_groups: Vec<ColumnGroupAnalyzeInfo>,
     input_data: Option<DataBlock>,
     committed: bool,
     ndv_states: HashMap<ColumnId, MetaHLL>,
     histograms: HashMap<ColumnId, Histogram>,
+    group_ndv_states: HashMap<usize, MetaHLL>,
+    group_most_common_values: HashMap<usize, Vec<(Vec<Datum>, u64)>>,
     step: AnalyzeStep,
 }
 
@@ -115,6 +135,7 @@ impl SinkAnalyzeState {
         snapshot_id: SnapshotId,
         input: Arc<InputPort>,
         histogram_info_receivers: HashMap<u32, Receiver<DataBlock>>,
+        column_groups: Vec<ColumnGroupAnalyzeInfo>,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(SinkAnalyzeState {
             ctx,
@@ -125,10 +146,13 @@ impl SinkAnalyzeState {
             table: table.to_string(),
             snapshot_id,
             histogram_info_receivers,
+            column_groups,
             input_data: None,
             committed: false,
             ndv_states: Default::default(),
             histograms: Default::default(),
+            group_ndv_states: Default::default(),
+            group_most_common_values: Default::default(),
             step: AnalyzeStep::CollectNDV,
         })))
     }
@@ -172,7 +196,11 @@ impl SinkAnalyzeState {
 
         let is_full = is_full.as_boolean().unwrap();
 
-        let mut ndv_states = table_statistics.map(|s| s.hll.clone()).unwrap_or_default();
+        let mut ndv_states = table_statistics
+            .as_ref()
+            .map(|s| s.hll.clone())
+            .unwrap_or_default();
+        let mut group_ndv_states = HashMap::new();
 
         let index_num = self.output_schema.num_fields() - 1;
 
@@ -184,12 +212,28 @@ impl SinkAnalyzeState {
             .zip(data_block.columns())
         {
             let name = f.name();
-            let index: u32 = name.strip_prefix("ndv_").unwrap().parse().unwrap();
-
             let col = col.value.index(0).unwrap();
             let col = col.as_binary().unwrap();
             let hll: MetaHLL = borsh_deserialize_from_slice(col)?;
 
+            if let Some(group_index) = name.strip_prefix("group_ndv_") {
+                let group_index: usize = group_index.parse().unwrap();
+                let column_ids = &self.column_groups[group_index].column_ids;
+                let mut hll = hll;
+                if !is_full {
+                    if let Some(prev) = table_statistics.as_ref().and_then(|s| {
+                        s.column_groups
+                            .iter()
+                            .find(|group| &group.column_ids == column_ids)
+                    }) {
+                        hll.merge(&prev.hll);
+                    }
+                }
+                group_ndv_states.insert(group_index, hll);
+                continue;
+            }
+
+            let index: u32 = name.strip_prefix("ndv_").unwrap().parse().unwrap();
             if !is_full {
                 ndv_states
                     .entry(index)
@@ -201,9 +245,31 @@ impl SinkAnalyzeState {
         }
 
         self.ndv_states = ndv_states;
+        self.group_ndv_states = group_ndv_states;
         Ok(())
     }
 
+    fn collect_most_common_values(&mut self, group_index: usize, data_block: DataBlock) {
+        let num_columns = self.column_groups[group_index].column_ids.len();
+        let most_common_values = self
+            .group_most_common_values
+            .entry(group_index)
+            .or_default();
+        'rows: for row in 0..data_block.num_rows() {
+            let mut values = Vec::with_capacity(num_columns);
+            for column in data_block.columns().iter().take(num_columns) {
+                // combinations containing NULL can't be matched by equality predicates
+                match Datum::from_scalar(column.value.index(row).unwrap().to_owned()) {
+                    Some(value) => values.push(value),
+                    None => continue 'rows,
+                }
+            }
+            let count = data_block.columns()[num_columns].value.index(row).unwrap();
+            let count = *count.as_number().unwrap().as_u_int64().unwrap();
+            most_common_values.push((values, count));
+        }
+    }
+
     #[async_backtrace::framed]
     async fn create_histogram(&mut self, col_id: u32, data_block: DataBlock) -> Result<()> {
         if data_block.num_rows() == 0 {
@@ -246,9 +312,28 @@ impl SinkAnalyzeState {
         }
         let snapshot = snapshot.unwrap();
         // 3. Generate new table statistics
+        let column_groups = self
+            .column_groups
+            .iter()
+            .enumerate()
+            .filter_map(|(index, group)| {
+                let hll = self.group_ndv_states.get(&index)?;
+                Some(ColumnGroupStatistics {
+                    column_ids: group.column_ids.clone(),
+                    hll: hll.clone(),
+                    row_count: group.row_count,
+                    most_common_values: self
+                        .group_most_common_values
+                        .get(&index)
+                        .cloned()
+                        .unwrap_or_default(),
+                })
+            })
+            .collect();
         let table_statistics = TableSnapshotStatistics::new(
             self.ndv_states.clone(),
             self.histograms.clone(),
+            column_groups,
             self.snapshot_id,
         );
         let table_statistics_location = table
@@ -303,7 +388,7 @@ impl Processor for SinkAnalyzeState {
                     self.step = AnalyzeStep::CollectHistogram;
                     return Ok(Event::Async);
                 }
-                AnalyzeStep::CollectHistogram => {
+                AnalyzeStep::CollectHistogram | AnalyzeStep::CollectMostCommonValues => {
                     return Ok(Event::Async);
                 }
                 AnalyzeStep::CommitStatistics => {
@@ -344,6 +429,27 @@ impl Processor for SinkAnalyzeState {
                     }
                 }
                 if finished_count == self.histogram_info_receivers.len() {
+                    self.step = AnalyzeStep::CollectMostCommonValues;
+                }
+            }
+            AnalyzeStep::CollectMostCommonValues => {
+                let mut finished_count = 0;
+                let receivers = self
+                    .column_groups
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, group)| {
+                        Some((index, group.most_common_values_receiver.clone()?))
+                    })
+                    .collect::<Vec<_>>();
+                for (index, receiver) in receivers.iter() {
+                    if let Ok(res) = receiver.recv().await {
+                        self.collect_most_common_values(*index, res);
+                    } else {
+                        finished_count += 1;
+                    }
+                }
+                if finished_count == receivers.len() {
                     self.step = AnalyzeStep::CommitStatistics;
                 }
             }
@@ -25,6 +25,7 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::TableSchemaRef;
+use databend_common_meta_app::schema::SnapshotRefType;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::TableStatistics;
@@ -47,6 +48,7 @@ use databend_storages_common_table_meta::meta::Statistics;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::TableSnapshotStatistics;
 use databend_storages_common_table_meta::meta::Versioned;
+use databend_storages_common_table_meta::table::OPT_KEY_BRANCH;
 use databend_storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_TEMP_PREFIX;
@@ -184,6 +186,23 @@ impl FuseTable {
         new_snapshot: &TableSnapshot,
     ) -> Result<TableMeta> {
         let mut new_table_meta = old_meta.clone();
+        // A commit through the handle of a branch only moves the head of the branch,
+        // the snapshot location and the statistics of the table are kept.
+        if let Some(branch) = new_table_meta.options.remove(OPT_KEY_BRANCH) {
+            match new_table_meta.refs.get_mut(&branch) {
+                Some(snapshot_ref) if snapshot_ref.typ == SnapshotRefType::Branch => {
+                    snapshot_ref.snapshot_location = new_snapshot_location.to_owned();
+                }
+                _ => {
+                    return Err(ErrorCode::UnknownTableRef(format!(
+                        "Unknown BRANCH '{}', it may have been dropped",
+                        branch
+                    )));
+                }
+            }
+            new_table_meta.updated_on = Utc::now();
+            return Ok(new_table_meta);
+        }
         // 1.1 set new snapshot location
         new_table_meta.options.insert(
             OPT_KEY_SNAPSHOT_LOCATION.to_owned(),
@@ -222,6 +241,7 @@ impl FuseTable {
         deduplicated_label: Option<String>,
     ) -> Result<()> {
         // 1. prepare table meta
+        let is_branch = table_info.meta.options.contains_key(OPT_KEY_BRANCH);
         let new_table_meta =
             Self::build_new_table_meta(&table_info.meta, &snapshot_location, &snapshot)?;
         // 2. prepare the request
@@ -265,7 +285,11 @@ impl FuseTable {
 
         // update_table_meta succeed, populate the snapshot cache item and try keeping a hit file of last snapshot
         TableSnapshot::cache().insert(snapshot_location.clone(), snapshot);
-        Self::write_last_snapshot_hint(ctx, operator, location_generator, &snapshot_location).await;
+        // the hint is read by the tables attached to this one, it never points to a branch
+        if !is_branch {
+            Self::write_last_snapshot_hint(ctx, operator, location_generator, &snapshot_location)
+                .await;
+        }
 
         Ok(())
     }
@@ -177,6 +177,11 @@ where F: SnapshotGenerator + Send + 'static
     }
 
     fn do_purge(table: &FuseTable, snapshot_gen: &F) -> bool {
+        // The history of the table must not be purged based on the head of a branch.
+        if table.branch().is_some() {
+            return false;
+        }
+
         if table.is_transient() {
             return true;
         }
@@ -17,12 +17,16 @@ use std::collections::HashSet;
 use std::sync::Arc;
 use std::time::Instant;
 
+use chrono::Duration;
+use chrono::Utc;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_common_meta_app::schema::TableIndex;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use databend_common_meta_types::MatchSeq;
 use databend_storages_common_cache::CacheAccessor;
 use databend_storages_common_cache::CachedObject;
 use databend_storages_common_cache::LoadParams;
@@ -30,9 +34,12 @@ use databend_storages_common_index::BloomIndexMeta;
 use databend_storages_common_index::InvertedIndexFile;
 use databend_storages_common_index::InvertedIndexMeta;
 use databend_storages_common_io::Files;
+use databend_storages_common_table_meta::meta::cold_tier_location;
+use databend_storages_common_table_meta::meta::split_tiered_location;
 use databend_storages_common_table_meta::meta::CompactSegmentInfo;
 use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::SegmentInfo;
+use databend_storages_common_table_meta::meta::StorageTier;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::TableSnapshotStatistics;
 use log::error;
@@ -117,6 +124,11 @@ impl FuseTable {
 
         let inverted_indexes = &self.table_info.meta.indexes;
 
+        // Files shared with other tables, or pinned by branches, tags and clones must be kept.
+        // A dry run must not change the table meta, so dropped clones are only released
+        // by a real purge.
+        let protected = self.read_protected_locations(ctx, !dry_run).await?;
+
         // 2. Read snapshot fields by chunk size.
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
         for chunk in snapshot_files.chunks(chunk_size).rev() {
@@ -188,6 +200,10 @@ impl FuseTable {
                 ctx.set_status_info(&status);
             }
 
+            protected.retain_purgeable(&mut snapshots_to_be_purged);
+            protected.retain_purgeable(&mut ts_to_be_purged);
+            segments_to_be_purged.retain(|loc| !protected.is_protected(&loc.0));
+
             if !snapshots_to_be_purged.is_empty() {
                 if dry_run {
                     debug_assert!(num_snapshot_limit.is_some());
@@ -195,6 +211,7 @@ impl FuseTable {
                         ctx,
                         &mut dry_run_purge_files,
                         &root_snapshot_info.referenced_locations,
+                        &protected,
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
@@ -210,6 +227,7 @@ impl FuseTable {
                         ctx,
                         counter,
                         &root_snapshot_info.referenced_locations,
+                        &protected,
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
@@ -246,11 +264,15 @@ impl FuseTable {
                     ts_to_be_purged.insert(s.table_statistics_location.unwrap());
                 }
             }
+            protected.retain_purgeable(&mut snapshots_to_be_purged);
+            protected.retain_purgeable(&mut ts_to_be_purged);
+            segments_to_be_purged.retain(|loc| !protected.is_protected(&loc.0));
             if dry_run {
                 self.dry_run_purge(
                     ctx,
                     &mut dry_run_purge_files,
                     &root_snapshot_info.referenced_locations,
+                    &protected,
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
@@ -262,6 +284,7 @@ impl FuseTable {
                     ctx,
                     counter,
                     &root_snapshot_info.referenced_locations,
+                    &protected,
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
@@ -284,6 +307,7 @@ impl FuseTable {
                 root_snapshot_info.snapshot_lite,
                 root_snapshot_info.referenced_locations,
                 root_snapshot_info.snapshot_location,
+                &protected,
                 &table_agg_index_ids,
                 inverted_indexes,
             )
@@ -349,6 +373,7 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         purge_files: &mut Vec<String>,
         locations_referenced_by_root: &LocationTuple,
+        protected: &ProtectedLocations,
         segments_to_be_purged: HashSet<Location>,
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
@@ -364,10 +389,15 @@ impl FuseTable {
                 .await?;
 
             for loc in &locations.block_location {
-                if locations_referenced_by_root.block_location.contains(loc) {
+                if locations_referenced_by_root.block_location.contains(loc)
+                    || protected.is_protected(loc)
+                {
                     continue;
                 }
                 purge_files.push(loc.to_string());
+                if is_moved_to_cold_tier(loc, locations_referenced_by_root) {
+                    continue;
+                }
                 for index_id in table_agg_index_ids {
                     purge_files.push(
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
@@ -378,7 +408,9 @@ impl FuseTable {
             }
 
             for loc in &locations.bloom_location {
-                if locations_referenced_by_root.bloom_location.contains(loc) {
+                if locations_referenced_by_root.bloom_location.contains(loc)
+                    || protected.is_protected(loc)
+                {
                     continue;
                 }
                 purge_files.push(loc.to_string())
@@ -398,6 +430,7 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
         locations_referenced_by_root: &LocationTuple,
+        protected: &ProtectedLocations,
         segments_to_be_purged: HashSet<Location>,
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
@@ -418,10 +451,16 @@ impl FuseTable {
             let mut agg_indexes_to_be_purged = HashSet::new();
             let mut inverted_indexes_to_be_purged = HashSet::new();
             for loc in &locations.block_location {
-                if locations_referenced_by_root.block_location.contains(loc) {
+                if locations_referenced_by_root.block_location.contains(loc)
+                    || protected.is_protected(loc)
+                {
                     continue;
                 }
                 blocks_to_be_purged.insert(loc.to_string());
+                // the indexes of a block moved to the cold storage are still in use
+                if is_moved_to_cold_tier(loc, locations_referenced_by_root) {
+                    continue;
+                }
                 for index_id in table_agg_index_ids {
                     agg_indexes_to_be_purged.insert(
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
@@ -443,7 +482,9 @@ impl FuseTable {
 
             let mut blooms_to_be_purged = HashSet::new();
             for loc in &locations.bloom_location {
-                if locations_referenced_by_root.bloom_location.contains(loc) {
+                if locations_referenced_by_root.bloom_location.contains(loc)
+                    || protected.is_protected(loc)
+                {
                     continue;
                 }
                 blooms_to_be_purged.insert(loc.to_string());
@@ -489,18 +530,22 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
         root_snapshot: Arc<SnapshotLiteExtended>,
-        root_location_tuple: LocationTuple,
+        mut root_location_tuple: LocationTuple,
         root_snapshot_location: String,
+        protected: &ProtectedLocations,
         table_agg_index_ids: &[u64],
         inverted_indexes: &BTreeMap<String, TableIndex>,
     ) -> Result<()> {
-        let segment_locations_to_be_purged = HashSet::from_iter(
+        let mut segment_locations_to_be_purged = HashSet::from_iter(
             root_snapshot
                 .segments
                 .iter()
                 .map(|loc| loc.0.clone())
                 .collect::<Vec<_>>(),
         );
+        protected.retain_purgeable(&mut segment_locations_to_be_purged);
+        protected.retain_purgeable(&mut root_location_tuple.block_location);
+        protected.retain_purgeable(&mut root_location_tuple.bloom_location);
 
         let mut agg_indexes_to_be_purged = HashSet::new();
         let mut inverted_indexes_to_be_purged = HashSet::new();
@@ -543,13 +588,11 @@ impl FuseTable {
         if let Some(ts) = root_snapshot.table_statistics_location.clone() {
             ts_to_be_purged.insert(ts);
         }
-        self.purge_ts_snapshots(
-            ctx,
-            counter,
-            ts_to_be_purged,
-            HashSet::from([root_snapshot_location]),
-        )
-        .await
+        protected.retain_purgeable(&mut ts_to_be_purged);
+        let mut snapshots_to_be_purged = HashSet::from([root_snapshot_location]);
+        protected.retain_purgeable(&mut snapshots_to_be_purged);
+        self.purge_ts_snapshots(ctx, counter, ts_to_be_purged, snapshots_to_be_purged)
+            .await
     }
 
     async fn purge_block_segments(
@@ -566,7 +609,7 @@ impl FuseTable {
         let blocks_count = blocks_to_be_purged.len();
         if blocks_count > 0 {
             counter.blocks += blocks_count;
-            self.try_purge_location_files(ctx.clone(), blocks_to_be_purged)
+            self.try_purge_block_files(ctx.clone(), blocks_to_be_purged)
                 .await?;
         }
 
@@ -679,6 +722,40 @@ impl FuseTable {
         fuse_file.remove_file_in_batch(locations_to_be_purged).await
     }
 
+    // Purge block files, each from the storage of the tier that it is stored in.
+    #[async_backtrace::framed]
+    pub async fn try_purge_block_files(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        locations_to_be_purged: HashSet<String>,
+    ) -> Result<()> {
+        let mut hot_locations = HashSet::new();
+        let mut cold_paths = HashSet::new();
+        for location in locations_to_be_purged {
+            let (tier, path) = split_tiered_location(&location);
+            if tier == StorageTier::Cold {
+                cold_paths.insert(path.to_string());
+            } else {
+                hot_locations.insert(location);
+            }
+        }
+
+        if !cold_paths.is_empty() {
+            match &self.cold_operator {
+                Some(cold_operator) => {
+                    let fuse_file = Files::create(ctx.clone(), cold_operator.clone());
+                    fuse_file.remove_file_in_batch(cold_paths).await?;
+                }
+                None => warn!(
+                    "{} blocks of table {} are not purged, since the cold storage is not configured",
+                    cold_paths.len(),
+                    self.table_info.desc
+                ),
+            }
+        }
+        self.try_purge_location_files(ctx, hot_locations).await
+    }
+
     // Purge file by location chunks.
     #[async_backtrace::framed]
     pub async fn try_purge_location_files_and_cache<T, C>(
@@ -740,6 +817,159 @@ impl FuseTable {
         })
     }
 
+    /// Collects the files that a purge of this table must keep, although they may
+    /// not be referenced by the current snapshot.
+    ///
+    /// If `release_dropped_clones` is set, the clones that are gone are removed from
+    /// the table meta.
+    #[async_backtrace::framed]
+    pub async fn read_protected_locations(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        release_dropped_clones: bool,
+    ) -> Result<ProtectedLocations> {
+        let mut protected = ProtectedLocations {
+            owned_prefix: format!("{}/", self.meta_location_generator().prefix()),
+            snapshots: HashSet::new(),
+            segments: HashSet::new(),
+            blocks: HashSet::new(),
+            blooms: HashSet::new(),
+        };
+        let reader = MetaReaders::table_snapshot_reader(self.get_operator());
+        for snapshot_location in self
+            .pinned_snapshot_locations(ctx, release_dropped_clones)
+            .await?
+        {
+            let params = LoadParams {
+                ver: TableMetaLocationGenerator::snapshot_version(snapshot_location.as_str()),
+                location: snapshot_location.clone(),
+                len_hint: None,
+                put_cache: false,
+            };
+            let snapshot = match reader.read(&params).await {
+                Err(e) if e.code() == ErrorCode::STORAGE_NOT_FOUND => {
+                    warn!(
+                        "gc: pinned snapshot {} not found. table: {}, ident {}",
+                        snapshot_location, self.table_info.desc, self.table_info.ident,
+                    );
+                    continue;
+                }
+                Err(e) => return Err(e),
+                Ok(v) => v,
+            };
+            let locations = self
+                .get_block_locations(ctx.clone(), &snapshot.segments, false, false)
+                .await?;
+            protected.blocks.extend(locations.block_location);
+            protected.blooms.extend(locations.bloom_location);
+            protected
+                .segments
+                .extend(snapshot.segments.iter().map(|loc| loc.0.clone()));
+            protected
+                .snapshots
+                .extend(snapshot.table_statistics_location.clone());
+            protected.snapshots.insert(snapshot_location);
+        }
+
+        Ok(protected)
+    }
+
+    /// Locations of the snapshots pinned by the branches and tags of this table,
+    /// and by the clones of it that still exist.
+    ///
+    /// If `release_dropped_clones` is set, the clones that are gone are removed from
+    /// the table meta.
+    #[async_backtrace::framed]
+    pub async fn pinned_snapshot_locations(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        release_dropped_clones: bool,
+    ) -> Result<Vec<String>> {
+        let meta = &self.table_info.meta;
+        let mut locations: Vec<String> = meta
+            .refs
+            .values()
+            .map(|r| r.snapshot_location.clone())
+            .collect();
+        let live_clones = self.live_clones(ctx).await?;
+        if release_dropped_clones && live_clones.len() < meta.clones.len() {
+            let released = meta
+                .clones
+                .keys()
+                .filter(|id| !live_clones.iter().any(|(live_id, _)| live_id == *id))
+                .copied()
+                .collect::<Vec<_>>();
+            self.release_clones(ctx, &released).await?;
+        }
+        locations.extend(live_clones.into_iter().map(|(_, location)| location));
+        Ok(locations)
+    }
+
+    /// The clones of this table which still pin a snapshot of it, with the location
+    /// of that snapshot.
+    ///
+    /// A dropped clone keeps its snapshot pinned while it can still be undropped.
+    #[async_backtrace::framed]
+    pub async fn live_clones(&self, ctx: &Arc<dyn TableContext>) -> Result<Vec<(u64, String)>> {
+        let meta = &self.table_info.meta;
+        if meta.clones.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        let retention =
+            Duration::days(ctx.get_settings().get_data_retention_time_in_days()? as i64);
+        let now = Utc::now();
+        let mut live_clones = vec![];
+        for (clone_id, snapshot_location) in &meta.clones {
+            if let Some(seq_meta) = catalog.get_table_meta_by_id(*clone_id).await? {
+                if seq_meta
+                    .data
+                    .drop_on
+                    .map_or(true, |drop_on| drop_on + retention > now)
+                {
+                    live_clones.push((*clone_id, snapshot_location.clone()));
+                }
+            }
+        }
+        Ok(live_clones)
+    }
+
+    /// Removes the clones which no longer pin a snapshot from the table meta.
+    ///
+    /// This is best effort, if the table meta was changed concurrently, the
+    /// clones are removed by the next gc.
+    #[async_backtrace::framed]
+    async fn release_clones(&self, ctx: &Arc<dyn TableContext>, clone_ids: &[u64]) -> Result<()> {
+        let catalog = ctx.get_catalog(self.table_info.catalog()).await?;
+        let table_id = self.table_info.ident.table_id;
+        let Some(seq_meta) = catalog.get_table_meta_by_id(table_id).await? else {
+            return Ok(());
+        };
+        let mut new_table_meta = seq_meta.data;
+        for clone_id in clone_ids {
+            new_table_meta.clones.remove(clone_id);
+        }
+        let req = UpdateTableMetaReq {
+            table_id,
+            seq: MatchSeq::Exact(seq_meta.seq),
+            new_table_meta,
+        };
+        match catalog
+            .update_single_table_meta(req, &self.table_info)
+            .await
+        {
+            Err(e) if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED => {
+                info!(
+                    "gc: release clones {:?} of table {} conflicted, retry in next gc",
+                    clone_ids, self.table_info.desc
+                );
+                Ok(())
+            }
+            res => res.map(|_| ()),
+        }
+    }
+
     pub async fn list_snapshot_files(&self) -> Result<Vec<String>> {
         let prefix = format!(
             "{}/{}/",
@@ -756,6 +986,43 @@ struct RootSnapshotInfo {
     snapshot_lite: Arc<SnapshotLiteExtended>,
 }
 
+/// Files that must survive a purge: files outside of the table's own storage
+/// prefix, which are shared with the table it was cloned from, and files
+/// reachable from snapshots pinned by branches, tags or clones.
+pub struct ProtectedLocations {
+    owned_prefix: String,
+    /// Pinned snapshots and their table statistics files.
+    pub snapshots: HashSet<String>,
+    pub segments: HashSet<String>,
+    pub blocks: HashSet<String>,
+    pub blooms: HashSet<String>,
+}
+
+impl ProtectedLocations {
+    pub fn is_protected(&self, location: &str) -> bool {
+        // Blocks moved to the cold storage keep their path under the table prefix.
+        let (_, path) = split_tiered_location(location);
+        !path.starts_with(&self.owned_prefix)
+            || self.snapshots.contains(location)
+            || self.segments.contains(location)
+            || self.blocks.contains(location)
+            || self.blooms.contains(location)
+    }
+
+    pub fn retain_purgeable(&self, locations: &mut HashSet<String>) {
+        locations.retain(|loc| !self.is_protected(loc));
+    }
+}
+
+/// Whether the hot block at `location` has been moved to the cold storage, and the moved
+/// block is referenced by the root snapshot.
+fn is_moved_to_cold_tier(location: &str, referenced_by_root: &LocationTuple) -> bool {
+    matches!(split_tiered_location(location), (StorageTier::Hot, _))
+        && referenced_by_root
+            .block_location
+            .contains(&cold_tier_location(location))
+}
+
 #[derive(Default)]
 pub struct LocationTuple {
     pub block_location: HashSet<String>,
@@ -113,6 +113,7 @@ impl MatchedAggregator {
             BlockReader::create(
                 ctx.clone(),
                 data_accessor.clone(),
+                table.get_cold_operator(),
                 target_table_schema.clone(),
                 projection,
                 false,
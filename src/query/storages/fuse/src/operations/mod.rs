@@ -33,21 +33,27 @@ mod recluster;
 mod replace;
 mod replace_into;
 mod revert;
+mod tiering;
 mod truncate;
 mod util;
+mod warm_up;
 
 pub use agg_index_sink::AggIndexSink;
+pub use analyze::ColumnGroupAnalyzeInfo;
 pub use analyze::HistogramInfoSink;
 pub use changes::ChangesDesc;
 pub use common::*;
 pub use compact::CompactOptions;
+pub use gc::ProtectedLocations;
 pub use merge_into::*;
 pub use mutation::*;
 pub use mutation_source::*;
 pub use read::need_reserve_block_info;
 pub use read::row_fetch_processor;
 pub use replace_into::*;
+pub use tiering::TieringStats;
 pub use util::acquire_task_permit;
 pub use util::column_parquet_metas;
 pub use util::read_block;
 pub use util::set_backoff;
+pub use warm_up::CacheWarmUpStats;
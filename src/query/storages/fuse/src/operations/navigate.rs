@@ -22,15 +22,19 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_exception::ResultExt;
+use databend_common_meta_app::schema::SnapshotRef;
+use databend_common_meta_app::schema::SnapshotRefType;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableStatistics;
 use databend_storages_common_table_meta::meta::TableSnapshot;
+use databend_storages_common_table_meta::table::OPT_KEY_BRANCH;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_SOURCE_TABLE_ID;
 use futures::TryStreamExt;
 use log::warn;
 use opendal::EntryMode;
 use opendal::Metakey;
+use opendal::Operator;
 
 use crate::io::MetaReaders;
 use crate::io::SnapshotHistoryReader;
@@ -62,6 +66,48 @@ impl FuseTable {
                     .await
             }
             NavigationPoint::StreamInfo(info) => self.navigate_to_stream(info).await,
+            NavigationPoint::SnapshotRef { typ, name } => self.navigate_to_ref(*typ, name).await,
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn navigate_to_ref(
+        &self,
+        typ: SnapshotRefType,
+        name: &str,
+    ) -> Result<Arc<FuseTable>> {
+        if typ == SnapshotRefType::Branch {
+            return self.switch_to_branch(name);
+        }
+        let snapshot_ref = self.get_snapshot_ref(typ, name)?;
+        let (snapshot, format_version) =
+            SnapshotsIO::read_snapshot(snapshot_ref.snapshot_location.clone(), self.get_operator())
+                .await?;
+        self.load_table_by_snapshot(snapshot.as_ref(), format_version)
+    }
+
+    /// Returns a handle of this table that reads from and commits to the branch `name`.
+    ///
+    /// The branch is kept in the table options of the handle, a commit through it only
+    /// moves the head of the branch, see [`FuseTable::build_new_table_meta`].
+    pub fn switch_to_branch(&self, name: &str) -> Result<Arc<FuseTable>> {
+        self.get_snapshot_ref(SnapshotRefType::Branch, name)?;
+        let mut table_info = self.table_info.clone();
+        table_info
+            .meta
+            .options
+            .insert(OPT_KEY_BRANCH.to_owned(), name.to_owned());
+        let table = FuseTable::do_create(table_info)?;
+        Ok(table.into())
+    }
+
+    pub fn get_snapshot_ref(&self, typ: SnapshotRefType, name: &str) -> Result<&SnapshotRef> {
+        match self.table_info.meta.refs.get(name) {
+            Some(snapshot_ref) if snapshot_ref.typ == typ => Ok(snapshot_ref),
+            _ => Err(ErrorCode::UnknownTableRef(format!(
+                "Unknown {} '{}' of table '{}'",
+                typ, name, self.table_info.desc
+            ))),
         }
     }
 
@@ -181,6 +227,8 @@ impl FuseTable {
         // The `seq` of ident that we cloned here is JUST a place holder
         // we should NOT use it other than a pure place holder.
         let mut table_info = self.table_info.clone();
+        // The snapshot is read as is, even if it was found in the history of a branch.
+        table_info.meta.options.remove(OPT_KEY_BRANCH);
 
         // There are more to be kept in snapshot, like engine_options, ordering keys...
         // or we could just keep a clone of TableMeta in the snapshot.
@@ -243,6 +291,11 @@ impl FuseTable {
                     .await
             }
             Some(NavigationPoint::StreamInfo(info)) => self.list_by_stream(info, time_point).await,
+            Some(NavigationPoint::SnapshotRef { typ, name }) => {
+                let snapshot_ref = self.get_snapshot_ref(typ, &name)?;
+                self.list_by_snapshot_location(snapshot_ref.snapshot_location.clone(), time_point)
+                    .await
+            }
             None => self.list_by_time_point(time_point).await,
         }?;
 
@@ -308,6 +361,36 @@ impl FuseTable {
         Ok((location, files))
     }
 
+    #[async_backtrace::framed]
+    pub async fn list_by_snapshot_location(
+        &self,
+        snapshot_loc: String,
+        retention_point: DateTime<Utc>,
+    ) -> Result<(String, Vec<String>)> {
+        let mut found = false;
+        let prefix = format!(
+            "{}/{}/",
+            self.meta_location_generator().prefix(),
+            FUSE_TBL_SNAPSHOT_PREFIX,
+        );
+
+        let files = self
+            .list_files(prefix, |loc, modified| {
+                if loc == snapshot_loc {
+                    found = true;
+                }
+                modified <= retention_point
+            })
+            .await?;
+
+        if !found {
+            return Err(ErrorCode::TableHistoricalDataNotFound(
+                "No historical data found at given point",
+            ));
+        }
+        Ok((snapshot_loc, files))
+    }
+
     #[async_backtrace::framed]
     pub async fn list_by_stream(
         &self,
@@ -333,35 +416,31 @@ impl FuseTable {
             })?
             .parse::<String>()?;
 
-        let mut found = false;
-        let prefix = format!(
-            "{}/{}/",
-            self.meta_location_generator().prefix(),
-            FUSE_TBL_SNAPSHOT_PREFIX,
-        );
+        self.list_by_snapshot_location(snapshot_loc, retention_point)
+            .await
+    }
 
-        let files = self
-            .list_files(prefix, |loc, modified| {
-                if loc == snapshot_loc {
-                    found = true;
-                }
-                modified <= retention_point
-            })
-            .await?;
+    #[async_backtrace::framed]
+    pub async fn list_files<F>(&self, prefix: String, f: F) -> Result<Vec<String>>
+    where F: FnMut(String, DateTime<Utc>) -> bool {
+        Self::list_files_of(self.operator.clone(), prefix, f).await
+    }
 
-        if !found {
-            return Err(ErrorCode::TableHistoricalDataNotFound(
-                "No historical data found at given point",
-            ));
+    /// Same as [`Self::list_files`], but lists the files in the cold storage of the table.
+    /// Returns an empty list if the cold storage is not configured.
+    #[async_backtrace::framed]
+    pub async fn list_cold_files<F>(&self, prefix: String, f: F) -> Result<Vec<String>>
+    where F: FnMut(String, DateTime<Utc>) -> bool {
+        match &self.cold_operator {
+            Some(cold_operator) => Self::list_files_of(cold_operator.clone(), prefix, f).await,
+            None => Ok(vec![]),
         }
-        Ok((snapshot_loc, files))
     }
 
     #[async_backtrace::framed]
-    pub async fn list_files<F>(&self, prefix: String, mut f: F) -> Result<Vec<String>>
+    async fn list_files_of<F>(op: Operator, prefix: String, mut f: F) -> Result<Vec<String>>
     where F: FnMut(String, DateTime<Utc>) -> bool {
         let mut file_list = vec![];
-        let op = self.operator.clone();
         let mut ds = op
             .lister_with(&prefix)
             .metakey(Metakey::Mode | Metakey::LastModified)
@@ -25,6 +25,7 @@ use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
 use databend_common_catalog::plan::DataSourcePlan;
 use databend_common_catalog::plan::PartInfoPtr;
+use databend_common_catalog::plan::RowSampler;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
@@ -72,6 +73,8 @@ pub struct DeserializeDataTransform {
 
     base_block_ids: Option<Scalar>,
     cached_runtime_filter: Option<Vec<(FieldIndex, BinaryFuse16)>>,
+    // for `REPEATABLE (seed)` row-level sample.
+    row_sampler: Option<RowSampler>,
     // for merge_into target build.
     need_reserve_block_info: bool,
 }
@@ -123,6 +126,7 @@ impl DeserializeDataTransform {
             virtual_reader,
             base_block_ids: plan.base_block_ids.clone(),
             cached_runtime_filter: None,
+            row_sampler: RowSampler::try_create(&plan.push_downs),
             need_reserve_block_info,
         })))
     }
@@ -265,10 +269,19 @@ impl Processor for DeserializeDataTransform {
 
                     let mut filter = None;
                     if self.ctx.has_bloom_runtime_filters(self.table_index) {
-                        if let Some(bitmap) = self.runtime_filter(data_block.clone())? {
-                            data_block = data_block.filter_with_bitmap(&bitmap)?;
-                            filter = Some(bitmap);
-                        }
+                        filter = self.runtime_filter(data_block.clone())?;
+                    }
+                    if let Some(row_sampler) = &self.row_sampler {
+                        let sample = row_sampler
+                            .part_sampler(part.location.as_bytes())
+                            .sample_bitmap(origin_num_rows);
+                        filter = Some(match filter {
+                            Some(bitmap) => (&bitmap).bitand(&sample),
+                            None => sample,
+                        });
+                    }
+                    if let Some(bitmap) = &filter {
+                        data_block = data_block.filter_with_bitmap(bitmap)?;
                     }
 
                     // Add optional virtual columns
@@ -54,6 +54,7 @@ impl FuseTable {
         BlockReader::create(
             ctx,
             self.operator.clone(),
+            self.cold_operator.clone(),
             table_schema,
             projection,
             query_internal_columns,
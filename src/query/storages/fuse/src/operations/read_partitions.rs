@@ -33,6 +33,7 @@ use databend_common_expression::TableSchemaRef;
 use databend_common_sql::field_default_value;
 use databend_common_storage::ColumnNodes;
 use databend_storages_common_cache::CacheAccessor;
+use databend_storages_common_cache::CacheManager;
 use databend_storages_common_cache::CachedObject;
 use databend_storages_common_index::BloomIndex;
 use databend_storages_common_pruner::BlockMetaIndex;
@@ -75,6 +76,10 @@ impl FuseTable {
         let snapshot = self.read_table_snapshot().await?;
         match snapshot {
             Some(snapshot) => {
+                if !dry_run {
+                    self.record_hot_table(push_downs.as_ref());
+                }
+
                 let snapshot_loc = self
                     .meta_location_generator
                     .snapshot_location_from_uuid(&snapshot.snapshot_id, snapshot.format_version)?;
@@ -123,6 +128,33 @@ impl FuseTable {
         }
     }
 
+    // Record the table and the columns it reads, so the caches can be warmed up after restart.
+    fn record_hot_table(&self, push_downs: Option<&PushDownInfo>) {
+        let Some(recorder) = CacheManager::instance().get_warm_up_recorder() else {
+            return;
+        };
+
+        // tables without a database name, e.g., the ones created by table functions, are skipped
+        if !self.table_info.desc.starts_with('\'') {
+            return;
+        }
+        let Ok(database) = self.table_info.database_name() else {
+            return;
+        };
+
+        let schema = self.schema();
+        let column_ids = match push_downs.and_then(|p| p.projection.as_ref()) {
+            Some(projection) => projection.project_schema(&schema).to_leaf_column_ids(),
+            None => schema.to_leaf_column_ids(),
+        };
+        recorder.record(
+            self.table_info.catalog(),
+            database,
+            &self.table_info.name,
+            column_ids,
+        );
+    }
+
     #[fastrace::trace]
     #[async_backtrace::framed]
     pub async fn prune_snapshot_blocks(
@@ -123,6 +123,7 @@ impl MergeIntoOperationAggregator {
         io_request_semaphore: Arc<Semaphore>,
     ) -> Result<Self> {
         let data_accessor = table.get_operator();
+        let cold_data_accessor = table.get_cold_operator();
         let table_schema = table.schema_with_stream();
         let write_settings = table.get_write_settings();
         let update_stream_columns = table.change_tracking_enabled();
@@ -155,6 +156,7 @@ impl MergeIntoOperationAggregator {
             BlockReader::create(
                 ctx.clone(),
                 data_accessor.clone(),
+                cold_data_accessor.clone(),
                 table_schema.clone(),
                 projection,
                 false,
@@ -171,6 +173,7 @@ impl MergeIntoOperationAggregator {
                 let reader = BlockReader::create(
                     ctx.clone(),
                     data_accessor.clone(),
+                    cold_data_accessor.clone(),
                     table_schema.clone(),
                     projection,
                     false,
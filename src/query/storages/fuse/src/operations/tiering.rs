@@ -0,0 +1,225 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Duration;
+use chrono::Utc;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::cast_scalar;
+use databend_common_expression::Scalar;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_storages_common_table_meta::meta::cold_tier_location;
+use databend_storages_common_table_meta::meta::split_tiered_location;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::SegmentInfo;
+use databend_storages_common_table_meta::meta::StorageTier;
+use databend_storages_common_table_meta::table::ClusterType;
+use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_TYPE;
+use futures_util::stream;
+use futures_util::StreamExt;
+use futures_util::TryStreamExt;
+use log::info;
+
+use crate::io::CachedMetaWriter;
+use crate::io::SegmentsIO;
+use crate::FuseTable;
+use crate::FUSE_OPT_KEY_TIERING_AFTER_DAYS;
+use crate::FUSE_OPT_KEY_TIERING_CLUSTER_KEY_MAX;
+
+/// Statistics of moving the cold blocks of a table to the cold storage.
+#[derive(Debug, Default)]
+pub struct TieringStats {
+    pub segments: usize,
+    pub blocks: usize,
+    pub bytes: u64,
+}
+
+/// Decides which blocks of a table are cold.
+///
+/// A block is cold if it was created `tiering_after_days` days before the snapshot
+/// timestamp, or if the max value of the first cluster key in the block is less than
+/// `tiering_cluster_key_max`.
+struct TieringPolicy {
+    cold_before: Option<DateTime<Utc>>,
+    cluster_key_id: Option<u32>,
+    cluster_key_max: Option<Scalar>,
+}
+
+impl TieringPolicy {
+    fn is_cold(&self, block_meta: &BlockMeta) -> bool {
+        let expired = match (self.cold_before, block_meta.create_on) {
+            (Some(cold_before), Some(create_on)) => create_on < cold_before,
+            _ => false,
+        };
+        let below_key_range = match (&self.cluster_key_max, &block_meta.cluster_stats) {
+            (Some(bound), Some(stats)) if Some(stats.cluster_key_id) == self.cluster_key_id => {
+                stats
+                    .max
+                    .first()
+                    .is_some_and(|max| !max.is_null() && max < bound)
+            }
+            _ => false,
+        };
+        expired || below_key_range
+    }
+}
+
+impl FuseTable {
+    /// Moves the blocks that the tiering policy of the table regards as cold to the cold
+    /// storage, and commits a new snapshot that references the moved blocks.
+    ///
+    /// Blocks are copied, the original files are left to be purged by gc.
+    #[async_backtrace::framed]
+    pub async fn do_tiering(&self, ctx: &Arc<dyn TableContext>) -> Result<TieringStats> {
+        let mut stats = TieringStats::default();
+        let Some(cold_operator) = &self.cold_operator else {
+            return Err(ErrorCode::StorageOther(format!(
+                "table {} has no cold storage, set table option tiering_cold_location when creating it",
+                self.table_info.name
+            )));
+        };
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(stats);
+        };
+        let Some(policy) = self.tiering_policy(ctx, snapshot.timestamp)? else {
+            return Ok(stats);
+        };
+
+        let max_concurrency = ctx.get_settings().get_max_threads()? as usize;
+        let mut new_segments = Vec::with_capacity(snapshot.segments.len());
+        for segment_location in &snapshot.segments {
+            let segment = SegmentsIO::read_compact_segment(
+                self.operator.clone(),
+                segment_location.clone(),
+                self.schema(),
+                false,
+            )
+            .await?;
+            let block_metas = segment.block_metas()?;
+            let cold_blocks = block_metas
+                .iter()
+                .enumerate()
+                .filter(|(_, block_meta)| {
+                    let (tier, _) = split_tiered_location(&block_meta.location.0);
+                    tier == StorageTier::Hot && policy.is_cold(block_meta)
+                })
+                .map(|(index, _)| index)
+                .collect::<Vec<_>>();
+            if cold_blocks.is_empty() {
+                new_segments.push(segment_location.clone());
+                continue;
+            }
+
+            // copy the cold blocks to the cold storage
+            stream::iter(cold_blocks.iter().map(|index| &block_metas[*index]))
+                .map(|block_meta| async move {
+                    let path = &block_meta.location.0;
+                    let data = self.operator.read(path).await?;
+                    cold_operator.write(path, data).await?;
+                    Ok::<_, ErrorCode>(())
+                })
+                .buffer_unordered(max_concurrency.max(1))
+                .try_collect::<Vec<_>>()
+                .await?;
+
+            let mut blocks = block_metas;
+            for index in &cold_blocks {
+                let mut block_meta = blocks[*index].as_ref().clone();
+                stats.bytes += block_meta.file_size;
+                block_meta.location.0 = cold_tier_location(&block_meta.location.0);
+                blocks[*index] = Arc::new(block_meta);
+            }
+            stats.segments += 1;
+            stats.blocks += cold_blocks.len();
+
+            let new_segment = SegmentInfo::new(blocks, segment.summary.clone());
+            let location = self.meta_location_generator.gen_segment_info_location();
+            new_segment
+                .write_meta_through_cache(&self.operator, &location)
+                .await?;
+            new_segments.push((location, SegmentInfo::VERSION));
+        }
+
+        if stats.blocks > 0 {
+            self.commit_mutation(
+                ctx,
+                snapshot.clone(),
+                &new_segments,
+                snapshot.summary.clone(),
+                None,
+            )
+            .await?;
+        }
+        info!(
+            "tiering of table {} moved {} blocks ({} bytes) of {} segments to the cold storage",
+            self.table_info.desc, stats.blocks, stats.bytes, stats.segments
+        );
+        Ok(stats)
+    }
+
+    fn tiering_policy(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        snapshot_timestamp: Option<DateTime<Utc>>,
+    ) -> Result<Option<TieringPolicy>> {
+        let after_days = self.get_option(FUSE_OPT_KEY_TIERING_AFTER_DAYS, 0u64);
+        let cold_before = match snapshot_timestamp {
+            Some(ts) if after_days > 0 => Some(ts - Duration::days(after_days as i64)),
+            _ => None,
+        };
+
+        let (cluster_key_id, cluster_key_max) = match self
+            .table_info
+            .options()
+            .get(FUSE_OPT_KEY_TIERING_CLUSTER_KEY_MAX)
+        {
+            Some(bound) => {
+                let cluster_type = self.get_option(OPT_KEY_CLUSTER_TYPE, ClusterType::Linear);
+                let data_type = match self.cluster_key_types(ctx.clone()).first() {
+                    Some(data_type) if cluster_type == ClusterType::Linear => {
+                        data_type.remove_nullable()
+                    }
+                    _ => {
+                        return Err(ErrorCode::TableOptionInvalid(format!(
+                            "table option {} requires a linear cluster key",
+                            FUSE_OPT_KEY_TIERING_CLUSTER_KEY_MAX
+                        )));
+                    }
+                };
+                let bound = cast_scalar(
+                    None,
+                    Scalar::String(bound.clone()),
+                    data_type,
+                    &BUILTIN_FUNCTIONS,
+                )?;
+                (self.cluster_key_id(), Some(bound))
+            }
+            None => (None, None),
+        };
+
+        if cold_before.is_none() && cluster_key_max.is_none() {
+            return Ok(None);
+        }
+        Ok(Some(TieringPolicy {
+            cold_before,
+            cluster_key_id,
+            cluster_key_max,
+        }))
+    }
+}
@@ -0,0 +1,172 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::ColumnId;
+use databend_storages_common_cache::CacheAccessor;
+use databend_storages_common_cache::CacheManager;
+use databend_storages_common_cache::TableDataCacheKey;
+use databend_storages_common_index::BloomIndex;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use futures_util::stream;
+use futures_util::StreamExt;
+use futures_util::TryStreamExt;
+
+use crate::io::BloomBlockFilterReader;
+use crate::io::SegmentsIO;
+use crate::FuseTable;
+
+/// Statistics of warming up the caches of a table.
+#[derive(Debug, Default)]
+pub struct CacheWarmUpStats {
+    pub segments: usize,
+    pub blocks: usize,
+    pub column_data_bytes: u64,
+}
+
+impl FuseTable {
+    /// Warms up the caches of the table, by loading the snapshot, the segments and the bloom
+    /// indexes, and prefetching at most `max_column_data_bytes` bytes of the data of the
+    /// columns in `column_ids` into the table data cache.
+    ///
+    /// Segments are loaded from the most recent one, and at most `max_concurrency` objects
+    /// are read concurrently.
+    #[async_backtrace::framed]
+    pub async fn warm_up_caches(
+        &self,
+        column_ids: &BTreeSet<ColumnId>,
+        max_column_data_bytes: u64,
+        max_concurrency: usize,
+    ) -> Result<CacheWarmUpStats> {
+        let mut stats = CacheWarmUpStats::default();
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(stats);
+        };
+
+        let dal = self.get_operator();
+        let schema = self.schema_with_stream();
+        let bloom_fields = self
+            .bloom_index_cols()
+            .bloom_index_fields(self.schema(), BloomIndex::supported_type)?
+            .into_values()
+            .filter(|f| column_ids.contains(&f.column_id()))
+            .collect::<Vec<_>>();
+        let table_data_cache = CacheManager::instance().get_table_data_cache();
+        let max_concurrency = max_concurrency.max(1);
+
+        for segment_location in &snapshot.segments {
+            let segment = SegmentsIO::read_compact_segment(
+                dal.clone(),
+                segment_location.clone(),
+                schema.clone(),
+                true,
+            )
+            .await?;
+            let block_metas = segment.block_metas()?;
+            stats.segments += 1;
+            stats.blocks += block_metas.len();
+
+            // load the bloom index meta and the filters of the hot columns
+            stream::iter(block_metas.iter().cloned())
+                .map(|block_meta| {
+                    let dal = dal.clone();
+                    let bloom_fields = &bloom_fields;
+                    async move {
+                        let Some(location) = &block_meta.bloom_filter_index_location else {
+                            return Ok(());
+                        };
+                        let columns = bloom_fields
+                            .iter()
+                            .filter(|f| block_meta.col_metas.contains_key(&f.column_id()))
+                            .map(|f| BloomIndex::build_filter_column_name(location.1, f))
+                            .collect::<Result<Vec<_>>>()?;
+                        location
+                            .read_block_filter(dal, &columns, block_meta.bloom_filter_index_size)
+                            .await?;
+                        Ok::<_, ErrorCode>(())
+                    }
+                })
+                .buffer_unordered(max_concurrency)
+                .try_collect::<Vec<_>>()
+                .await?;
+
+            // prefetch the data of the hot columns into the table data cache
+            let Some(cache) = &table_data_cache else {
+                continue;
+            };
+            let ranges = column_ranges(
+                &block_metas,
+                column_ids,
+                max_column_data_bytes - stats.column_data_bytes,
+            );
+            stats.column_data_bytes += ranges.iter().map(|(_, _, _, len)| len).sum::<u64>();
+            stream::iter(ranges)
+                .map(|(location, column_id, offset, len)| async move {
+                    if cache.contains_key(
+                        TableDataCacheKey::new(&location, column_id, offset, len).as_ref(),
+                    ) {
+                        return Ok(());
+                    }
+                    let (dal, path) = self.get_block_operator(&location)?;
+                    let data = dal
+                        .read_with(path)
+                        .range(offset..offset + len)
+                        .await?
+                        .to_bytes();
+                    let key = TableDataCacheKey::new(&location, column_id, offset, len);
+                    cache.insert(key.into(), data);
+                    Ok::<_, ErrorCode>(())
+                })
+                .buffer_unordered(max_concurrency)
+                .try_collect::<Vec<_>>()
+                .await?;
+
+            if stats.column_data_bytes >= max_column_data_bytes {
+                break;
+            }
+        }
+
+        Ok(stats)
+    }
+}
+
+/// Returns the (block location, column id, offset, length) of the columns to prefetch,
+/// whose total length does not exceed `max_bytes`.
+fn column_ranges(
+    block_metas: &[Arc<BlockMeta>],
+    column_ids: &BTreeSet<ColumnId>,
+    max_bytes: u64,
+) -> Vec<(String, ColumnId, u64, u64)> {
+    let mut ranges = vec![];
+    let mut total = 0;
+    for block_meta in block_metas {
+        for column_id in column_ids {
+            let Some(column_meta) = block_meta.col_metas.get(column_id) else {
+                continue;
+            };
+            let (offset, len) = column_meta.offset_length();
+            if total + len > max_bytes {
+                return ranges;
+            }
+            total += len;
+            ranges.push((block_meta.location.0.clone(), *column_id, offset, len));
+        }
+    }
+    ranges
+}
@@ -18,6 +18,7 @@ use std::sync::Arc;
 use databend_common_base::base::tokio::sync::Semaphore;
 use databend_common_base::runtime::Runtime;
 use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::plan::sample_rng;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
@@ -52,7 +53,8 @@ use opendal::Operator;
 use rand::distributions::Bernoulli;
 use rand::distributions::Distribution;
 use rand::prelude::SliceRandom;
-use rand::thread_rng;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
 
 use crate::io::BloomIndexBuilder;
 use crate::operations::DeletedSegmentInfo;
@@ -379,10 +381,20 @@ impl FusePruner {
                         }
                     } else {
                         let sample_probability = table_sample(&push_down)?;
+                        let sample_seed = push_down
+                            .as_ref()
+                            .and_then(|info| info.sample.as_ref())
+                            .and_then(|sample| sample.seed);
                         for (location, info) in pruned_segments {
                             let mut block_metas =
                                 Self::extract_block_metas(&location.location.0, &info, true)?;
                             if let Some(probability) = sample_probability {
+                                // With `REPEATABLE (seed)`, the blocks of a segment are always
+                                // sampled in the same way.
+                                let mut rng = match sample_seed {
+                                    Some(seed) => sample_rng(seed, location.location.0.as_bytes()),
+                                    None => ChaCha8Rng::from_entropy(),
+                                };
                                 if block_metas.len() <= SMALL_DATASET_SAMPLE_THRESHOLD {
                                     // Deterministic sampling for small datasets
                                     // Ensure at least one block is sampled for small datasets
@@ -390,7 +402,6 @@ impl FusePruner {
                                         1,
                                         (block_metas.len() as f64 * probability).round() as usize,
                                     );
-                                    let mut rng = thread_rng();
                                     block_metas = Arc::new(
                                         block_metas
                                             .choose_multiple(&mut rng, sample_size)
@@ -401,7 +412,6 @@ impl FusePruner {
                                     // Random sampling for larger datasets
                                     let mut sample_block_metas =
                                         Vec::with_capacity(block_metas.len());
-                                    let mut rng = thread_rng();
                                     let bernoulli = Bernoulli::new(probability).unwrap();
                                     for block in block_metas.iter() {
                                         if bernoulli.sample(&mut rng) {
@@ -15,7 +15,9 @@
 use std::collections::HashMap;
 
 use databend_common_exception::Result;
+use databend_common_expression::types::AnyType;
 use databend_common_expression::types::DataType;
+use databend_common_expression::types::GeometryType;
 use databend_common_expression::types::NumberScalar;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::ValueType;
@@ -27,8 +29,11 @@ use databend_common_expression::TableSchemaRef;
 use databend_common_expression::Value;
 use databend_common_expression::ORIGIN_BLOCK_ROW_NUM_COLUMN_ID;
 use databend_common_functions::aggregates::eval_aggr;
+use databend_common_io::BoundingBox;
 use databend_storages_common_index::Index;
 use databend_storages_common_index::RangeIndex;
+use databend_storages_common_table_meta::meta::bounding_box_to_scalars;
+use databend_storages_common_table_meta::meta::supported_bounding_box_type;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
 use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 
@@ -64,6 +69,12 @@ pub fn gen_columns_statistics(
     let leaves = get_traverse_columns_dfs(data_block)?;
     let leaf_column_ids = schema.to_leaf_column_ids();
     for ((col_idx, col, data_type), column_id) in leaves.iter().zip(leaf_column_ids) {
+        if supported_bounding_box_type(data_type) {
+            let col_stats = gen_bounding_box_statistics(col, data_type, rows)?;
+            statistics.insert(column_id, col_stats);
+            continue;
+        }
+
         // Ignore the range index does not supported type.
         if !RangeIndex::supported_type(data_type) {
             continue;
@@ -177,6 +188,47 @@ pub fn gen_columns_statistics(
     Ok(statistics)
 }
 
+/// Geometry columns record the bounding box of all their values, which is left
+/// as NULL if the block only holds NULL or empty geometries.
+fn gen_bounding_box_statistics(
+    col: &Value<AnyType>,
+    data_type: &DataType,
+    rows: usize,
+) -> Result<ColumnStatistics> {
+    let column = col.convert_to_full_column(data_type, rows);
+    let (column, validity) = match column {
+        Column::Nullable(nullable) => (nullable.column, Some(nullable.validity)),
+        column => (column, None),
+    };
+    let column = GeometryType::try_downcast_column(&column).unwrap();
+
+    let mut bbox: Option<BoundingBox> = None;
+    for (row, geometry) in column.iter().enumerate() {
+        if validity
+            .as_ref()
+            .is_some_and(|validity| !validity.get_bit(row))
+        {
+            continue;
+        }
+        if let Some(row_bbox) = BoundingBox::from_ewkb(geometry)? {
+            bbox = Some(bbox.map_or(row_bbox, |bbox| bbox.merge(&row_bbox)));
+        }
+    }
+
+    let (min, max) = bbox
+        .as_ref()
+        .map(bounding_box_to_scalars)
+        .unwrap_or((Scalar::Null, Scalar::Null));
+    let null_count = validity.map_or(0, |validity| validity.unset_bits());
+    Ok(ColumnStatistics::new(
+        min,
+        max,
+        null_count as u64,
+        column.memory_size() as u64,
+        None,
+    ))
+}
+
 pub fn scalar_min_max(data_type: &DataType, scalar: Scalar) -> Option<(Scalar, Scalar)> {
     if RangeIndex::supported_type(data_type) {
         if let Some((min, Some(max))) = scalar
@@ -18,6 +18,9 @@ use std::collections::HashMap;
 use databend_common_expression::BlockThresholds;
 use databend_common_expression::ColumnId;
 use databend_common_expression::Scalar;
+use databend_common_io::BoundingBox;
+use databend_storages_common_table_meta::meta::bounding_box_from_scalars;
+use databend_storages_common_table_meta::meta::bounding_box_to_scalars;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
@@ -62,17 +65,23 @@ pub fn reduce_block_statistics<T: Borrow<StatisticsOfColumns>>(
                 in_memory_size += col_stats.in_memory_size;
             }
 
-            let min = min_stats
-                .into_iter()
-                .filter(|s| !s.is_null())
-                .min_by(|x, y| x.cmp(y))
-                .unwrap_or(Scalar::Null);
+            let (min, max) = match reduce_bounding_boxes(&min_stats, &max_stats) {
+                Some(bbox) => bounding_box_to_scalars(&bbox),
+                None => {
+                    let min = min_stats
+                        .into_iter()
+                        .filter(|s| !s.is_null())
+                        .min_by(|x, y| x.cmp(y))
+                        .unwrap_or(Scalar::Null);
 
-            let max = max_stats
-                .into_iter()
-                .filter(|s| !s.is_null())
-                .max_by(|x, y| x.cmp(y))
-                .unwrap_or(Scalar::Null);
+                    let max = max_stats
+                        .into_iter()
+                        .filter(|s| !s.is_null())
+                        .max_by(|x, y| x.cmp(y))
+                        .unwrap_or(Scalar::Null);
+                    (min, max)
+                }
+            };
 
             acc.insert(
                 *id,
@@ -82,6 +91,22 @@ pub fn reduce_block_statistics<T: Borrow<StatisticsOfColumns>>(
         })
 }
 
+/// Bounding boxes of geometry columns are merged corner by corner, as the tuple
+/// ordering of the corners does not give the enclosing box.
+///
+/// Returns `None` if the statistics do not hold any bounding box.
+fn reduce_bounding_boxes(mins: &[Scalar], maxs: &[Scalar]) -> Option<BoundingBox> {
+    let mut merged: Option<BoundingBox> = None;
+    for (min, max) in mins.iter().zip(maxs.iter()) {
+        if min.is_null() || max.is_null() {
+            continue;
+        }
+        let bbox = bounding_box_from_scalars(min, max)?;
+        merged = Some(merged.map_or(bbox, |merged| merged.merge(&bbox)));
+    }
+    merged
+}
+
 pub fn reduce_cluster_statistics<T: Borrow<Option<ClusterStatistics>>>(
     blocks_cluster_stats: &[T],
     default_cluster_key_id: Option<u32>,
@@ -31,6 +31,7 @@ use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_expression::Value;
+use databend_storages_common_table_meta::meta::split_tiered_location;
 use databend_storages_common_table_meta::meta::SegmentInfo;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 
@@ -65,6 +66,7 @@ impl TableMetaFunc for FuseBlock {
                 "inverted_index_size",
                 TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
             ),
+            TableField::new("storage_tier", TableDataType::String),
         ])
     }
 
@@ -86,6 +88,7 @@ impl TableMetaFunc for FuseBlock {
         let mut bloom_filter_location = vec![];
         let mut bloom_filter_size = Vec::with_capacity(len);
         let mut inverted_index_size = Vec::with_capacity(len);
+        let mut storage_tier = Vec::with_capacity(len);
 
         let segments_io = SegmentsIO::create(ctx.clone(), tbl.operator.clone(), tbl.schema());
 
@@ -102,7 +105,9 @@ impl TableMetaFunc for FuseBlock {
 
                 for block in segment.blocks.iter() {
                     let block = block.as_ref();
-                    block_location.put_and_commit(&block.location.0);
+                    let (tier, path) = split_tiered_location(&block.location.0);
+                    block_location.put_and_commit(path);
+                    storage_tier.push(tier.to_string());
                     block_size.push(block.block_size);
                     file_size.push(block.file_size);
                     row_count.push(block.row_count);
@@ -163,6 +168,10 @@ impl TableMetaFunc for FuseBlock {
                     DataType::Nullable(Box::new(DataType::Number(NumberDataType::UInt64))),
                     Value::Column(UInt64Type::from_opt_data(inverted_index_size)),
                 ),
+                BlockEntry::new(
+                    DataType::String,
+                    Value::Column(StringType::from_data(storage_tier)),
+                ),
             ],
             row_num,
         ))
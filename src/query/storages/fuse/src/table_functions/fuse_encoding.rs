@@ -180,10 +180,11 @@ impl<'a> FuseEncodingImpl<'a> {
                             let (offset, len) = column_meta.offset_length();
                             let ranges = vec![(column_id, offset..(offset + len))];
                             let read_settings = ReadSettings::from_ctx(&self.ctx)?;
+                            let (operator, path) = table.get_block_operator(&block.location.0)?;
                             let merge_io_result = MergeIOReader::merge_io_read(
                                 &read_settings,
-                                table.operator.clone(),
-                                &block.location.0,
+                                operator,
+                                path,
                                 &ranges,
                             )
                             .await?;
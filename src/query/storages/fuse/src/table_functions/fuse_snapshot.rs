@@ -12,10 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use databend_common_catalog::catalog_kind::CATALOG_DEFAULT;
 use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::table::Table;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -59,11 +61,33 @@ impl From<&FuseSnapshotArgs> for TableArgs {
 }
 
 impl FuseSnapshotFunc {
+    /// Maps the location of each pinned snapshot to the branches, tags and
+    /// live clones which pin it, e.g. `TAG v1, CLONE 42`.
+    async fn snapshot_pins(
+        ctx: &Arc<dyn TableContext>,
+        table: &FuseTable,
+    ) -> Result<HashMap<String, Vec<String>>> {
+        let meta = &table.get_table_info().meta;
+        let mut pins: HashMap<String, Vec<String>> = HashMap::new();
+        for (name, snapshot_ref) in &meta.refs {
+            pins.entry(snapshot_ref.snapshot_location.clone())
+                .or_default()
+                .push(format!("{} {}", snapshot_ref.typ, name));
+        }
+        for (clone_id, snapshot_location) in table.live_clones(ctx).await? {
+            pins.entry(snapshot_location)
+                .or_default()
+                .push(format!("CLONE {}", clone_id));
+        }
+        Ok(pins)
+    }
+
     fn to_block(
         &self,
         location_generator: &TableMetaLocationGenerator,
         snapshots: &[TableSnapshotLite],
         latest_snapshot_version: u64,
+        pins: &HashMap<String, Vec<String>>,
     ) -> Result<DataBlock> {
         let len = snapshots.len();
         let mut snapshot_ids: Vec<String> = Vec::with_capacity(len);
@@ -77,13 +101,14 @@ impl FuseSnapshotFunc {
         let mut uncompressed: Vec<u64> = Vec::with_capacity(len);
         let mut index_size: Vec<u64> = Vec::with_capacity(len);
         let mut timestamps: Vec<Option<i64>> = Vec::with_capacity(len);
+        let mut pinned_by: Vec<Option<String>> = Vec::with_capacity(len);
         let mut current_snapshot_version = latest_snapshot_version;
         for s in snapshots {
             snapshot_ids.push(s.snapshot_id.simple().to_string());
-            snapshot_locations.push(
-                location_generator
-                    .snapshot_location_from_uuid(&s.snapshot_id, current_snapshot_version)?,
-            );
+            let snapshot_location = location_generator
+                .snapshot_location_from_uuid(&s.snapshot_id, current_snapshot_version)?;
+            pinned_by.push(pins.get(&snapshot_location).map(|v| v.join(", ")));
+            snapshot_locations.push(snapshot_location);
             let (id, ver) = s
                 .prev_snapshot_id
                 .map_or((None, 0), |(id, v)| (Some(id.simple().to_string()), v));
@@ -111,6 +136,7 @@ impl FuseSnapshotFunc {
             UInt64Type::from_data(compressed),
             UInt64Type::from_data(index_size),
             TimestampType::from_opt_data(timestamps),
+            StringType::from_opt_data(pinned_by),
         ]))
     }
 }
@@ -149,6 +175,7 @@ impl SimpleTableFunc for FuseSnapshotFunc {
             ),
             TableField::new("index_size", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("timestamp", TableDataType::Timestamp.wrap_nullable()),
+            TableField::new("pinned_by", TableDataType::String.wrap_nullable()),
         ])
     }
 
@@ -218,10 +245,12 @@ impl SimpleTableFunc for FuseSnapshotFunc {
             }?;
 
             info!("got {} snapshots", snapshot_lite.len());
+            let pins = Self::snapshot_pins(ctx, table).await?;
             return Ok(Some(self.to_block(
                 &meta_location_generator,
                 &snapshot_lite,
                 snapshot_version,
+                &pins,
             )?));
         }
         Ok(Some(DataBlock::empty_with_schema(Arc::new(
@@ -0,0 +1,110 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+
+use crate::sessions::TableContext;
+use crate::table_functions::parse_db_tb_args;
+use crate::table_functions::string_literal;
+use crate::table_functions::SimpleTableFunc;
+use crate::table_functions::TableArgs;
+use crate::FuseTable;
+
+struct TieringTableArgs {
+    database_name: String,
+    table_name: String,
+}
+
+impl From<&TieringTableArgs> for TableArgs {
+    fn from(args: &TieringTableArgs) -> Self {
+        TableArgs::new_positioned(vec![
+            string_literal(args.database_name.as_str()),
+            string_literal(args.table_name.as_str()),
+        ])
+    }
+}
+
+/// Moves the cold blocks of a table to its cold storage, according to the tiering policy
+/// of the table.
+pub struct FuseTieringTable {
+    args: TieringTableArgs,
+}
+
+#[async_trait::async_trait]
+impl SimpleTableFunc for FuseTieringTable {
+    fn table_args(&self) -> Option<TableArgs> {
+        Some((&self.args).into())
+    }
+
+    fn schema(&self) -> TableSchemaRef {
+        TableSchemaRefExt::create(vec![
+            TableField::new("segments", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("blocks", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("bytes", TableDataType::Number(NumberDataType::UInt64)),
+        ])
+    }
+
+    async fn apply(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+    ) -> Result<Option<DataBlock>> {
+        let tenant_id = ctx.get_tenant();
+        let tbl = ctx
+            .get_catalog(&ctx.get_current_catalog())
+            .await?
+            .get_table(
+                &tenant_id,
+                self.args.database_name.as_str(),
+                self.args.table_name.as_str(),
+            )
+            .await?;
+
+        let tbl = FuseTable::try_from_table(tbl.as_ref()).map_err(|_| {
+            ErrorCode::StorageOther("Invalid table engine, only fuse table is supported")
+        })?;
+        tbl.check_mutable()?;
+
+        let stats = tbl.do_tiering(ctx).await?;
+
+        Ok(Some(DataBlock::new_from_columns(vec![
+            UInt64Type::from_data(vec![stats.segments as u64]),
+            UInt64Type::from_data(vec![stats.blocks as u64]),
+            UInt64Type::from_data(vec![stats.bytes]),
+        ])))
+    }
+
+    fn create(func_name: &str, table_args: TableArgs) -> Result<Self>
+    where Self: Sized {
+        let (arg_database_name, arg_table_name) = parse_db_tb_args(&table_args, func_name)?;
+        Ok(Self {
+            args: TieringTableArgs {
+                database_name: arg_database_name,
+                table_name: arg_table_name,
+            },
+        })
+    }
+}
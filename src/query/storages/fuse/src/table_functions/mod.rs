@@ -22,6 +22,7 @@ mod fuse_encoding;
 mod fuse_segment;
 mod fuse_snapshot;
 mod fuse_statistic;
+mod fuse_tiering;
 mod fuse_time_travel_size;
 mod fuse_vacuum_temporary_table;
 mod table_args;
@@ -40,6 +41,7 @@ pub use fuse_encoding::FuseEncodingFunc;
 pub use fuse_segment::FuseSegmentFunc;
 pub use fuse_snapshot::FuseSnapshotFunc;
 pub use fuse_statistic::FuseStatisticsFunc;
+pub use fuse_tiering::FuseTieringTable;
 pub use fuse_time_travel_size::FuseTimeTravelSize;
 pub use fuse_time_travel_size::FuseTimeTravelSizeFunc;
 pub use fuse_vacuum_temporary_table::FuseVacuumTemporaryTable;
@@ -52,6 +52,10 @@ impl PartInfo for HivePartInfo {
         self.filename.hash(&mut s);
         s.finish()
     }
+
+    fn sample_key(&self) -> Vec<u8> {
+        self.filename.as_bytes().to_vec()
+    }
 }
 
 impl HivePartInfo {
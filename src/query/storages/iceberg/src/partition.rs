@@ -71,4 +71,13 @@ impl PartInfo for IcebergPartInfo {
         self.0.length.hash(&mut s);
         s.finish()
     }
+
+    /// Identifies the part by the range of the data file it reads.
+    fn sample_key(&self) -> Vec<u8> {
+        format!(
+            "{}:{}:{}",
+            self.0.data_file_path, self.0.start, self.0.length
+        )
+        .into_bytes()
+    }
 }
@@ -26,6 +26,7 @@ use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::plan::RowSampler;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table::TableStatistics;
 use databend_common_catalog::table_args::TableArgs;
@@ -174,9 +175,16 @@ impl IcebergTable {
         let max_threads = std::cmp::min(parts_len, max_threads);
 
         let output_schema = Arc::new(DataSchema::from(plan.schema()));
+        let row_sampler = RowSampler::try_create(&plan.push_downs);
         pipeline.add_source(
             |output| {
-                IcebergTableSource::create(ctx.clone(), output, output_schema.clone(), self.clone())
+                IcebergTableSource::create(
+                    ctx.clone(),
+                    output,
+                    output_schema.clone(),
+                    self.clone(),
+                    row_sampler,
+                )
             },
             max_threads.max(1),
         )
@@ -298,4 +306,8 @@ impl Table for IcebergTable {
     fn support_prewhere(&self) -> bool {
         false
     }
+
+    fn support_seeded_row_sample(&self) -> bool {
+        true
+    }
 }
@@ -19,6 +19,9 @@ use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
 use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_catalog::plan::PartInfo;
+use databend_common_catalog::plan::PartRowSampler;
+use databend_common_catalog::plan::RowSampler;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -51,6 +54,10 @@ pub struct IcebergTableSource {
     // Used to read parquet.
     output_schema: DataSchemaRef,
     stream: Option<ArrowRecordBatchStream>,
+
+    // Used for `REPEATABLE (seed)` row-level sample.
+    row_sampler: Option<RowSampler>,
+    part_sampler: Option<PartRowSampler>,
 }
 
 impl IcebergTableSource {
@@ -59,6 +66,7 @@ impl IcebergTableSource {
         output: Arc<OutputPort>,
         output_schema: DataSchemaRef,
         table: IcebergTable,
+        row_sampler: Option<RowSampler>,
     ) -> Result<ProcessorPtr> {
         let scan_progress = ctx.get_scan_progress();
         Ok(ProcessorPtr::create(Box::new(IcebergTableSource {
@@ -70,6 +78,8 @@ impl IcebergTableSource {
             stream: None,
             generated_data: None,
             is_finished: false,
+            row_sampler,
+            part_sampler: None,
         })))
     }
 }
@@ -125,7 +135,10 @@ impl Processor for IcebergTableSource {
                 })?
             {
                 let block = transform_record_batch(&self.output_schema, &batch, &None)?;
-                let block = check_block_schema(&self.output_schema, block)?;
+                let mut block = check_block_schema(&self.output_schema, block)?;
+                if let Some(part_sampler) = &mut self.part_sampler {
+                    block = part_sampler.sample_block(block)?;
+                }
 
                 self.generated_data = Some(block);
                 self.stream = Some(stream);
@@ -135,6 +148,10 @@ impl Processor for IcebergTableSource {
             // And we should try to build another stream (in next event loop).
         } else if let Some(part) = self.ctx.get_partition() {
             let part = IcebergPartInfo::from_part(&part)?;
+            self.part_sampler = self
+                .row_sampler
+                .as_ref()
+                .map(|row_sampler| row_sampler.part_sampler(&part.sample_key()));
             // TODO: enable row filter?
             let reader = self.table.table().await?.reader_builder().build();
             // TODO: don't use stream here.
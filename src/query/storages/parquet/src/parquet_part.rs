@@ -35,6 +35,13 @@ pub enum ParquetPart {
 }
 
 impl ParquetPart {
+    fn location(&self) -> &str {
+        match self {
+            ParquetPart::ParquetFiles(p) => &p.files[0].0,
+            ParquetPart::ParquetRSRowGroup(p) => &p.location,
+        }
+    }
+
     pub fn uncompressed_size(&self) -> u64 {
         match self {
             ParquetPart::ParquetFiles(p) => p.uncompressed_size(),
@@ -78,14 +85,14 @@ impl PartInfo for ParquetPart {
     }
 
     fn hash(&self) -> u64 {
-        let path = match self {
-            ParquetPart::ParquetFiles(p) => &p.files[0].0,
-            ParquetPart::ParquetRSRowGroup(p) => &p.location,
-        };
         let mut s = DefaultHasher::new();
-        path.hash(&mut s);
+        self.location().hash(&mut s);
         s.finish()
     }
+
+    fn sample_key(&self) -> Vec<u8> {
+        self.location().as_bytes().to_vec()
+    }
 }
 
 impl ParquetPart {
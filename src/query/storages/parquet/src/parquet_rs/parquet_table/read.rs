@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::RowSampler;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
@@ -86,6 +87,7 @@ impl ParquetRSTable {
         };
 
         let topk = Arc::new(topk);
+        let row_sampler = RowSampler::try_create(&plan.push_downs);
         pipeline.add_source(
             |output| {
                 ParquetSource::create(
@@ -94,6 +96,7 @@ impl ParquetRSTable {
                     row_group_reader.clone(),
                     full_file_reader.clone(),
                     topk.clone(),
+                    row_sampler,
                 )
             },
             num_threads,
@@ -19,6 +19,8 @@ use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
 use databend_common_base::runtime::profile::Profile;
 use databend_common_base::runtime::profile::ProfileStatisticsName;
+use databend_common_catalog::plan::PartRowSampler;
+use databend_common_catalog::plan::RowSampler;
 use databend_common_catalog::plan::TopK;
 use databend_common_catalog::query_kind::QueryKind;
 use databend_common_catalog::table_context::TableContext;
@@ -67,6 +69,10 @@ pub struct ParquetSource {
     copy_status: Arc<CopyStatus>,
     /// Pushed-down topk sorter.
     topk_sorter: Option<TopKSorter>,
+    /// Pushed-down `REPEATABLE (seed)` row-level sample.
+    row_sampler: Option<RowSampler>,
+    /// Row sampler of the row group being read.
+    part_sampler: Option<PartRowSampler>,
 }
 
 impl ParquetSource {
@@ -76,6 +82,7 @@ impl ParquetSource {
         row_group_reader: Arc<ParquetRSRowGroupReader>,
         full_file_reader: Option<Arc<ParquetRSFullReader>>,
         topk: Arc<Option<TopK>>,
+        row_sampler: Option<RowSampler>,
     ) -> Result<ProcessorPtr> {
         let scan_progress = ctx.get_scan_progress();
         let is_copy = matches!(ctx.get_query_kind(), QueryKind::CopyIntoTable);
@@ -98,6 +105,8 @@ impl ParquetSource {
             copy_status,
             topk_sorter,
             full_file_reader,
+            row_sampler,
+            part_sampler: None,
         })))
     }
 }
@@ -151,7 +160,10 @@ impl Processor for ParquetSource {
     fn process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Init) {
             State::ReadRowGroup(mut reader) => {
-                if let Some(block) = reader.as_mut().read_block()? {
+                if let Some(mut block) = reader.as_mut().read_block()? {
+                    if let Some(part_sampler) = &mut self.part_sampler {
+                        block = part_sampler.sample_block(block)?;
+                    }
                     self.generated_data = Some(block);
                     self.state = State::ReadRowGroup(reader);
                 }
@@ -175,13 +187,21 @@ impl Processor for ParquetSource {
                         blocks.extend(bs);
                     }
                 } else {
-                    for (_, buffer) in buffers {
-                        blocks.extend(
-                            self.full_file_reader
-                                .as_ref()
-                                .unwrap()
-                                .read_blocks_from_binary(buffer)?,
-                        );
+                    for (path, buffer) in buffers {
+                        let bs = self
+                            .full_file_reader
+                            .as_ref()
+                            .unwrap()
+                            .read_blocks_from_binary(buffer)?;
+                        match &self.row_sampler {
+                            Some(row_sampler) => {
+                                let mut part_sampler = row_sampler.part_sampler(path.as_bytes());
+                                for block in bs {
+                                    blocks.push(part_sampler.sample_block(block)?);
+                                }
+                            }
+                            None => blocks.extend(bs),
+                        }
                     }
                 }
 
@@ -202,6 +222,9 @@ impl Processor for ParquetSource {
                 if let Some(part) = self.ctx.get_partition() {
                     match ParquetPart::from_part(&part)? {
                         ParquetPart::ParquetRSRowGroup(part) => {
+                            self.part_sampler = self.row_sampler.as_ref().map(|row_sampler| {
+                                row_sampler.part_sampler(part.location.as_bytes())
+                            });
                             if let Some(reader) = self
                                 .row_group_reader
                                 .create_read_policy(
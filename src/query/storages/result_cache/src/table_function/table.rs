@@ -169,6 +169,7 @@ impl Table for ResultScan {
                     row_group_reader.clone(),
                     full_file_reader.clone(),
                     Arc::new(None),
+                    None,
                 )
             },
             1,
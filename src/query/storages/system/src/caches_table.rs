@@ -56,6 +56,9 @@ struct CachesTableColumns {
     access: Vec<u64>,
     hit: Vec<u64>,
     miss: Vec<u64>,
+    eviction_policy: Vec<String>,
+    admitted: Vec<u64>,
+    rejected: Vec<u64>,
 }
 
 impl SyncSystemTable for CachesTable {
@@ -139,6 +142,12 @@ impl SyncSystemTable for CachesTable {
             columns.access.push(access);
             columns.hit.push(hit);
             columns.miss.push(miss);
+            let (admitted, rejected) = cache.admission_stats();
+            columns
+                .eviction_policy
+                .push(cache.eviction_policy().to_string());
+            columns.admitted.push(admitted);
+            columns.rejected.push(rejected);
         }
 
         if let Some(table_column_array_cache) = table_column_array_cache {
@@ -155,6 +164,9 @@ impl SyncSystemTable for CachesTable {
             UInt64Type::from_data(columns.access),
             UInt64Type::from_data(columns.hit),
             UInt64Type::from_data(columns.miss),
+            StringType::from_data(columns.eviction_policy),
+            UInt64Type::from_data(columns.admitted),
+            UInt64Type::from_data(columns.rejected),
         ]))
     }
 }
@@ -171,6 +183,9 @@ impl CachesTable {
             TableField::new("access", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("hit", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("miss", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("eviction_policy", TableDataType::String),
+            TableField::new("admitted", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("rejected", TableDataType::Number(NumberDataType::UInt64)),
         ]);
 
         let table_info = TableInfo {
@@ -216,5 +231,12 @@ impl CachesTable {
         columns.access.push(access);
         columns.hit.push(hit);
         columns.miss.push(miss);
+
+        let (admitted, rejected) = cache.admission_stats();
+        columns
+            .eviction_policy
+            .push(cache.eviction_policy().to_string());
+        columns.admitted.push(admitted);
+        columns.rejected.push(rejected);
     }
 }
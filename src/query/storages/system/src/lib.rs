@@ -99,7 +99,9 @@ pub use malloc_stats_table::MallocStatsTable;
 #[cfg(feature = "jemalloc")]
 pub use malloc_stats_totals_table::MallocStatsTotalsTable;
 pub use metrics_table::MetricsTable;
+pub use notification_history_table::notification_history_to_pb;
 pub use notification_history_table::NotificationHistoryTable;
+pub use notifications_table::notification_to_pb;
 pub use notifications_table::parse_notifications_to_datablock;
 pub use notifications_table::NotificationsTable;
 pub use one_table::OneTable;
@@ -128,8 +130,10 @@ pub use tables_table::TablesTableWithoutHistory;
 pub use tables_table::ViewsTableWithHistory;
 pub use tables_table::ViewsTableWithoutHistory;
 pub use task_history_table::parse_task_runs_to_datablock;
+pub use task_history_table::task_run_to_pb;
 pub use task_history_table::TaskHistoryTable;
 pub use tasks_table::parse_tasks_to_datablock;
+pub use tasks_table::task_to_pb;
 pub use tasks_table::TasksTable;
 pub use temp_files_table::TempFilesTable;
 pub use temporary_tables_table::TemporaryTablesTable;
@@ -24,7 +24,6 @@ use databend_common_cloud_control::notification_utils;
 use databend_common_cloud_control::pb::ListNotificationHistoryRequest;
 use databend_common_cloud_control::pb::NotificationHistory;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::infer_table_schema;
 use databend_common_expression::types::StringType;
@@ -33,11 +32,14 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
 use databend_common_expression::Scalar;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::principal;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_sql::plans::notification_history_schema;
+use databend_common_users::UserApiProvider;
 
+use crate::notifications_table::to_pb_timestamp;
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
 use crate::util::find_eq_filter;
@@ -75,6 +77,20 @@ fn parse_history_to_block(
     ]))
 }
 
+/// Convert a delivery recorded by the built-in notification sender into the message
+/// returned by cloud control.
+pub fn notification_history_to_pb(history: principal::NotificationHistory) -> NotificationHistory {
+    NotificationHistory {
+        created_time: Some(to_pb_timestamp(history.created_at)),
+        processed_time: history.processed_at.map(to_pb_timestamp),
+        message_source: history.message_source,
+        name: history.name,
+        message: history.message,
+        status: history.status.to_string(),
+        error_message: history.error_message,
+    }
+}
+
 pub struct NotificationHistoryTable {
     table_info: TableInfo,
 }
@@ -93,13 +109,6 @@ impl AsyncSystemTable for NotificationHistoryTable {
         ctx: Arc<dyn TableContext>,
         push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
-        let config = GlobalConfig::instance();
-        if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot view system.notification_history table without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
-        }
-
         let tenant = ctx.get_tenant();
         let query_id = ctx.get_id();
         let user = ctx.get_current_user()?.identity().display().to_string();
@@ -123,6 +132,25 @@ impl AsyncSystemTable for NotificationHistoryTable {
             }
         }
 
+        let config = GlobalConfig::instance();
+        if config.query.cloud_control_grpc_server_address.is_none() {
+            // Deliveries are recorded by the built-in notification sender in meta-service.
+            let mut histories = UserApiProvider::instance()
+                .notification_api(&tenant)
+                .list_histories(notification_name.as_deref())
+                .await?;
+            // Latest deliveries first, as cloud control does.
+            histories.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+            if let Some(limit) = result_limit {
+                histories.truncate(limit as usize);
+            }
+            let histories = histories
+                .into_iter()
+                .map(notification_history_to_pb)
+                .collect();
+            return parse_history_to_block(histories);
+        }
+
         let req = ListNotificationHistoryRequest {
             tenant_id: tenant.tenant_name().to_string(),
             result_limit,
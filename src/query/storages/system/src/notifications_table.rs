@@ -14,6 +14,8 @@
 
 use std::sync::Arc;
 
+use chrono::DateTime;
+use chrono::Utc;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
@@ -21,10 +23,11 @@ use databend_common_cloud_control::client_config::build_client_config;
 use databend_common_cloud_control::client_config::make_request;
 use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::notification_utils::NotificationParams;
+use databend_common_cloud_control::pb;
 use databend_common_cloud_control::pb::ListNotificationRequest;
 use databend_common_cloud_control::pb::Notification;
+use databend_common_cloud_control::utils::Timestamp;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::infer_table_schema;
 use databend_common_expression::types::BooleanType;
@@ -34,10 +37,12 @@ use databend_common_expression::types::UInt64Type;
 use databend_common_expression::types::VariantType;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
+use databend_common_meta_app::principal;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_sql::plans::notification_schema;
+use databend_common_users::UserApiProvider;
 
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
@@ -78,6 +83,39 @@ pub fn parse_notifications_to_datablock(notification: Vec<Notification>) -> Resu
     ]))
 }
 
+pub fn to_pb_timestamp(t: DateTime<Utc>) -> Timestamp {
+    Timestamp {
+        seconds: t.timestamp(),
+        nanos: t.timestamp_subsec_nanos() as i32,
+    }
+}
+
+/// Convert a notification integration stored in meta-service into the message returned
+/// by cloud control, so that both kinds of integrations are displayed in the same way.
+///
+/// The secrets are stored encrypted and never displayed, a set authorization header
+/// is displayed masked.
+pub fn notification_to_pb(notification: principal::Notification) -> Notification {
+    Notification {
+        notification_id: notification.notification_id,
+        tenant_id: "".to_string(),
+        name: notification.name,
+        notification_type: pb::NotificationType::Webhook as i32,
+        enabled: notification.enabled,
+        webhook_url: notification.webhook.url,
+        webhook_method: notification.webhook.method,
+        webhook_authorization_header: notification
+            .webhook
+            .authorization_header
+            .map(|_| "******".to_string()),
+        comments: notification.comments,
+        created_time: Some(to_pb_timestamp(notification.created_at)),
+        created_by: "".to_string(),
+        updated_time: Some(to_pb_timestamp(notification.updated_at)),
+        updated_by: "".to_string(),
+    }
+}
+
 pub struct NotificationsTable {
     table_info: TableInfo,
 }
@@ -96,14 +134,21 @@ impl AsyncSystemTable for NotificationsTable {
         ctx: Arc<dyn TableContext>,
         _push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot view system.notifications table without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            // Notification integrations are managed by the query nodes and stored in meta-service.
+            let notifications = UserApiProvider::instance()
+                .notification_api(&tenant)
+                .list_notifications()
+                .await?
+                .into_iter()
+                .map(notification_to_pb)
+                .collect();
+            return parse_notifications_to_datablock(notifications);
         }
 
-        let tenant = ctx.get_tenant();
         let query_id = ctx.get_id();
         let user = ctx.get_current_user()?.identity().display().to_string();
         let req = ListNotificationRequest {
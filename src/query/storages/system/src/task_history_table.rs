@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use chrono::DateTime;
 use chrono_tz::Tz::UTC;
 use databend_common_catalog::plan::PushDownInfo;
 use databend_common_catalog::table::Table;
@@ -23,7 +24,6 @@ use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::pb::ShowTaskRunsRequest;
 use databend_common_cloud_control::pb::TaskRun;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::date_helper::DateConverter;
 use databend_common_expression::infer_table_schema;
@@ -37,13 +37,16 @@ use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
 use databend_common_expression::Scalar;
 use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::principal;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_sql::plans::task_run_schema;
+use databend_common_users::UserApiProvider;
 
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
+use crate::tasks_table::task_to_pb;
 use crate::util::find_eq_filter;
 use crate::util::find_gt_filter;
 use crate::util::find_lt_filter;
@@ -112,6 +115,31 @@ pub fn parse_task_runs_to_datablock(task_runs: Vec<TaskRun>) -> Result<DataBlock
     ]))
 }
 
+/// Convert a task run recorded by the built-in scheduler into the message returned by cloud control.
+pub fn task_run_to_pb(task_run: principal::TaskRun) -> TaskRun {
+    let task = task_to_pb(task_run.task);
+    TaskRun {
+        task_id: task.task_id,
+        task_name: task.task_name,
+        query_text: task.query_text,
+        comment: task.comment,
+        owner: task.owner,
+        schedule_options: task.schedule_options,
+        run_id: task_run.run_id.to_string(),
+        attempt_number: task_run.attempt_number,
+        warehouse_options: task.warehouse_options,
+        state: task_run.state as i32,
+        error_code: task_run.error_code,
+        error_message: task_run.error_message,
+        scheduled_time: task_run.scheduled_at.to_rfc3339(),
+        completed_time: task_run.completed_at.map(|t| t.to_rfc3339()),
+        query_id: task_run.query_id,
+        condition_text: task.when_condition.unwrap_or_default(),
+        root_task_id: task_run.root_task_id.to_string(),
+        session_parameters: task.session_parameters,
+    }
+}
+
 pub struct TaskHistoryTable {
     table_info: TableInfo,
 }
@@ -130,13 +158,6 @@ impl AsyncSystemTable for TaskHistoryTable {
         ctx: Arc<dyn TableContext>,
         push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
-        let config = GlobalConfig::instance();
-        if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot view system.task_history table without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
-        }
-
         let tenant = ctx.get_tenant();
         let query_id = ctx.get_id();
         let user = ctx.get_current_user()?.identity().display().to_string();
@@ -176,6 +197,39 @@ impl AsyncSystemTable for TaskHistoryTable {
                 });
             }
         }
+
+        let config = GlobalConfig::instance();
+        if config.query.cloud_control_grpc_server_address.is_none() {
+            // Task runs are recorded by the built-in scheduler in meta-service.
+            let owners = available_roles
+                .into_iter()
+                .map(|x| x.identity().to_string())
+                .collect::<Vec<_>>();
+            let mut runs = UserApiProvider::instance()
+                .task_api(&tenant)
+                .list_task_runs(task_name.as_deref())
+                .await?
+                .into_iter()
+                .filter(|r| owners.contains(&r.task.owner))
+                .filter(|r| {
+                    let after_start = scheduled_time_start.as_ref().map_or(true, |start| {
+                        DateTime::parse_from_rfc3339(start).map_or(true, |t| r.scheduled_at > t)
+                    });
+                    let before_end = scheduled_time_end.as_ref().map_or(true, |end| {
+                        DateTime::parse_from_rfc3339(end).map_or(true, |t| r.scheduled_at < t)
+                    });
+                    after_start && before_end
+                })
+                .collect::<Vec<_>>();
+            // Latest runs first, as cloud control does.
+            runs.sort_by(|a, b| b.scheduled_at.cmp(&a.scheduled_at));
+            if let Some(limit) = result_limit {
+                runs.truncate(limit as usize);
+            }
+            let trs = runs.into_iter().map(task_run_to_pb).collect();
+            return parse_task_runs_to_datablock(trs);
+        }
+
         let req = ShowTaskRunsRequest {
             tenant_id: tenant.tenant_name().to_string(),
             scheduled_time_start: scheduled_time_start.unwrap_or("".to_string()),
@@ -20,10 +20,10 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_cloud_control::client_config::build_client_config;
 use databend_common_cloud_control::client_config::make_request;
 use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
+use databend_common_cloud_control::pb;
 use databend_common_cloud_control::pb::ShowTasksRequest;
 use databend_common_cloud_control::pb::Task;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::infer_table_schema;
 use databend_common_expression::types::StringType;
@@ -32,10 +32,12 @@ use databend_common_expression::types::UInt64Type;
 use databend_common_expression::types::VariantType;
 use databend_common_expression::DataBlock;
 use databend_common_expression::FromData;
+use databend_common_meta_app::principal;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_sql::plans::task_schema;
+use databend_common_users::UserApiProvider;
 
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
@@ -102,6 +104,41 @@ pub fn parse_tasks_to_datablock(tasks: Vec<Task>) -> Result<DataBlock> {
     ]))
 }
 
+/// Convert a task stored in meta-service into the message returned by cloud control,
+/// so that both kinds of tasks are displayed in the same way.
+pub fn task_to_pb(task: principal::Task) -> Task {
+    let schedule_options = task.schedule_options.map(|s| pb::ScheduleOptions {
+        interval: s.interval,
+        cron: s.cron,
+        time_zone: s.time_zone,
+        schedule_type: s.schedule_type as i32,
+        milliseconds_interval: s.milliseconds_interval,
+    });
+    let warehouse_options = task.warehouse_options.map(|w| pb::WarehouseOptions {
+        warehouse: w.warehouse,
+        using_warehouse_size: w.using_warehouse_size,
+    });
+    Task {
+        task_id: task.task_id,
+        task_name: task.task_name,
+        query_text: task.query_text,
+        comment: task.comment,
+        owner: task.owner,
+        schedule_options,
+        warehouse_options,
+        next_scheduled_at: task.next_scheduled_at.map(|t| t.to_rfc3339()),
+        suspend_task_after_num_failures: task.suspend_task_after_num_failures.map(|v| v as i32),
+        status: task.status as i32,
+        created_at: task.created_at.to_rfc3339(),
+        updated_at: task.updated_at.to_rfc3339(),
+        last_suspended_at: task.last_suspended_at.map(|t| t.to_rfc3339()),
+        after: task.after,
+        when_condition: task.when_condition,
+        session_parameters: task.session_params,
+        error_integration: task.error_integration,
+    }
+}
+
 pub struct TasksTable {
     table_info: TableInfo,
 }
@@ -120,17 +157,29 @@ impl AsyncSystemTable for TasksTable {
         ctx: Arc<dyn TableContext>,
         _push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let available_roles = ctx.get_available_roles().await?;
+
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot view system.tasks table without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            // Tasks are managed by the built-in scheduler and stored in meta-service.
+            let owners = available_roles
+                .into_iter()
+                .map(|x| x.identity().to_string())
+                .collect::<Vec<_>>();
+            let tasks = UserApiProvider::instance()
+                .task_api(&tenant)
+                .list_tasks()
+                .await?
+                .into_iter()
+                .filter(|t| owners.contains(&t.owner))
+                .map(task_to_pb)
+                .collect();
+            return parse_tasks_to_datablock(tasks);
         }
 
-        let tenant = ctx.get_tenant();
         let query_id = ctx.get_id();
         let user = ctx.get_current_user()?.identity().display().to_string();
-        let available_roles = ctx.get_available_roles().await?;
         let req = ShowTasksRequest {
             tenant_id: tenant.tenant_name().to_string(),
             name_like: "".to_string(),
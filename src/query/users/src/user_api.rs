@@ -24,6 +24,7 @@ use databend_common_management::ClientSessionMgr;
 use databend_common_management::ConnectionMgr;
 use databend_common_management::FileFormatMgr;
 use databend_common_management::NetworkPolicyMgr;
+use databend_common_management::NotificationMgr;
 use databend_common_management::PasswordPolicyMgr;
 use databend_common_management::ProcedureMgr;
 use databend_common_management::QuotaApi;
@@ -34,6 +35,7 @@ use databend_common_management::SettingApi;
 use databend_common_management::SettingMgr;
 use databend_common_management::StageApi;
 use databend_common_management::StageMgr;
+use databend_common_management::TaskMgr;
 use databend_common_management::UserApi;
 use databend_common_management::UserMgr;
 use databend_common_meta_app::principal::AuthInfo;
@@ -171,6 +173,14 @@ impl UserApiProvider {
         ClientSessionMgr::create(self.client.clone(), tenant)
     }
 
+    pub fn task_api(&self, tenant: &Tenant) -> TaskMgr {
+        TaskMgr::create(self.client.clone(), tenant)
+    }
+
+    pub fn notification_api(&self, tenant: &Tenant) -> NotificationMgr {
+        NotificationMgr::create(self.client.clone(), tenant)
+    }
+
     pub fn get_meta_store_client(&self) -> Arc<MetaStore> {
         Arc::new(self.meta.clone())
     }
@@ -33,7 +33,7 @@ use crate::sql_gen::SqlGenerator;
 
 const BASE_TABLE_NAMES: [&str; 4] = ["t1", "t2", "t3", "t4"];
 
-const SIMPLE_COLUMN_TYPES: [TypeName; 21] = [
+const SIMPLE_COLUMN_TYPES: [TypeName; 25] = [
     TypeName::Boolean,
     TypeName::UInt8,
     TypeName::UInt16,
@@ -61,6 +61,10 @@ const SIMPLE_COLUMN_TYPES: [TypeName; 21] = [
     TypeName::Binary,
     TypeName::Geometry,
     TypeName::Geography,
+    TypeName::Interval,
+    TypeName::TimestampTz,
+    TypeName::Time,
+    TypeName::Uuid,
 ];
 
 impl<'a, R: Rng> SqlGenerator<'a, R> {
@@ -290,6 +294,26 @@ fn gen_default_expr(type_name: &TypeName) -> Expr {
             span: None,
             value: Literal::String("POINT(0 0)".to_string()),
         },
+        TypeName::Interval => Expr::Literal {
+            span: None,
+            value: Literal::String("00:00:00".to_string()),
+        },
+        TypeName::TimestampTz => Expr::Literal {
+            span: None,
+            value: Literal::String("1970-01-01 00:00:00+00:00".to_string()),
+        },
+        TypeName::Time => Expr::Literal {
+            span: None,
+            value: Literal::String("00:00:00".to_string()),
+        },
+        TypeName::Uuid => Expr::Literal {
+            span: None,
+            value: Literal::String("00000000-0000-0000-0000-000000000000".to_string()),
+        },
+        TypeName::Enum(items) => Expr::Literal {
+            span: None,
+            value: Literal::String(items[0].clone()),
+        },
         TypeName::Nullable(_) => Expr::Literal {
             span: None,
             value: Literal::Null,
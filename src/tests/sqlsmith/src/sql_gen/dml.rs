@@ -83,6 +83,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
             catalog: None,
             database: None,
             table: table_name,
+            branch: None,
             // TODO
             columns: vec![],
             source,
@@ -488,6 +489,7 @@ impl<'a, R: Rng + 'a> SqlGenerator<'a, R> {
                 catalog: None,
                 database: None,
                 table: table_name,
+                branch: None,
                 columns,
                 source,
                 overwrite: false,
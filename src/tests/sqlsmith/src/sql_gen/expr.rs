@@ -29,6 +29,7 @@ use databend_common_ast::ast::UnaryOperator;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::DecimalDataType;
 use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::TimestampTz;
 use ethnum::I256;
 use rand::distributions::Alphanumeric;
 use rand::Rng;
@@ -304,6 +305,91 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                     },
                 }
             }
+            DataType::Interval => {
+                let days: i32 = self.rng.gen_range(-1000..=1000);
+                let secs: u32 = self.rng.gen_range(0..86400);
+                let arg = Expr::Literal {
+                    span: None,
+                    value: Literal::String(format!("{} days {} seconds", days, secs)),
+                };
+                Expr::FunctionCall {
+                    span: None,
+                    func: FunctionCall {
+                        distinct: false,
+                        name: Identifier::from_name(None, "to_interval".to_string()),
+                        args: vec![arg],
+                        params: vec![],
+                        window: None,
+                        lambda: None,
+                    },
+                }
+            }
+            DataType::TimestampTz => {
+                let micros: i64 = self.rng.gen_range(0..=10000000000000);
+                let offset_hours: i32 = self.rng.gen_range(-12..=14);
+                let ts = TimestampTz::new(micros, offset_hours * 3600);
+                let arg = Expr::Literal {
+                    span: None,
+                    value: Literal::String(ts.to_string()),
+                };
+                Expr::FunctionCall {
+                    span: None,
+                    func: FunctionCall {
+                        distinct: false,
+                        name: Identifier::from_name(None, "to_timestamp_tz".to_string()),
+                        args: vec![arg],
+                        params: vec![],
+                        window: None,
+                        lambda: None,
+                    },
+                }
+            }
+            DataType::Time => {
+                let secs: u32 = self.rng.gen_range(0..86400);
+                let arg = Expr::Literal {
+                    span: None,
+                    value: Literal::String(format!(
+                        "{:02}:{:02}:{:02}",
+                        secs / 3600,
+                        secs / 60 % 60,
+                        secs % 60
+                    )),
+                };
+                Expr::FunctionCall {
+                    span: None,
+                    func: FunctionCall {
+                        distinct: false,
+                        name: Identifier::from_name(None, "to_time".to_string()),
+                        args: vec![arg],
+                        params: vec![],
+                        window: None,
+                        lambda: None,
+                    },
+                }
+            }
+            DataType::Uuid => Expr::FunctionCall {
+                span: None,
+                func: FunctionCall {
+                    distinct: false,
+                    name: Identifier::from_name(None, "gen_random_uuid".to_string()),
+                    args: vec![],
+                    params: vec![],
+                    window: None,
+                    lambda: None,
+                },
+            },
+            DataType::Enum(items) => {
+                let code = self.rng.gen_range(0..=items.max_code());
+                Expr::Cast {
+                    span: None,
+                    expr: Box::new(Expr::Literal {
+                        span: None,
+                        value: Literal::String(items.item(code).to_string()),
+                    }),
+                    target_type: TypeName::Enum(items.items().to_vec()),
+                    pg_style: false,
+                }
+            }
             _ => Expr::Literal {
                 span: None,
                 value: Literal::Null,
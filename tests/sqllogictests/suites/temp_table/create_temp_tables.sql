@@ -560,4 +560,4 @@ statement error 1081.*is not deterministic
 create temp table t(a int) cluster by (a+rand())
 
 statement error 1081.*is not deterministic
-create temp table t(a string) cluster by (a+uuid())
+create temp table t(a string) cluster by (a+uuid()::STRING)